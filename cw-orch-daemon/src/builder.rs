@@ -3,12 +3,20 @@ use std::sync::Arc;
 use crate::{
     log::print_if_log_disabled,
     network_config,
-    senders::{builder::SenderBuilder, CosmosOptions, CosmosWalletKey},
-    DaemonAsyncBase, DaemonBuilder, DaemonStateFile, TxSender, Wallet,
+    profile::Profile,
+    queriers::QueryCache,
+    senders::{
+        builder::SenderBuilder, CosmosOptions, CosmosWalletKey, SecretProvider, TxGuard, TxSigner,
+    },
+    DaemonAsyncBase, DaemonBuilder, DaemonStateFile, MemoTemplate, PollingConfig, RateLimits,
+    TxSender, Wallet,
 };
 
 use super::{error::DaemonError, state::DaemonState};
-use cw_orch_core::environment::ChainInfoOwned;
+use cw_orch_core::{
+    contract::UploadPrecheck,
+    environment::{ChainInfoOwned, ChainKind},
+};
 /// The default deployment id if none is provided
 pub const DEFAULT_DEPLOYMENT: &str = "default";
 
@@ -37,6 +45,14 @@ pub struct DaemonAsyncBuilder {
     pub(crate) load_network: bool,
 
     pub(crate) mnemonic: Option<String>,
+    pub(crate) secret_provider: Option<Arc<dyn SecretProvider>>,
+    pub(crate) signer: Option<Arc<dyn TxSigner>>,
+    pub(crate) polling: Option<PollingConfig>,
+    pub(crate) tx_guard: Option<TxGuard>,
+    pub(crate) rate_limits: Option<RateLimits>,
+    pub(crate) query_cache: Option<QueryCache>,
+    pub(crate) memo: Option<MemoTemplate>,
+    pub(crate) upload_precheck: Option<UploadPrecheck>,
 }
 
 impl DaemonAsyncBuilder {
@@ -48,11 +64,42 @@ impl DaemonAsyncBuilder {
             state: None,
             write_on_change: None,
             mnemonic: None,
+            secret_provider: None,
+            signer: None,
+            polling: None,
+            tx_guard: None,
+            rate_limits: None,
+            query_cache: None,
+            memo: None,
+            upload_precheck: None,
             is_test: false,
             load_network: true,
         }
     }
 
+    /// Creates a builder from the profile `name` of the `cw-orch.toml` file of the project.
+    /// The builder can still be customized afterwards.
+    /// See [`Profile`] for the format of the file.
+    pub fn from_profile(name: &str) -> Result<Self, DaemonError> {
+        Self::with_profile(&Profile::load(name)?)
+    }
+
+    /// Creates a builder from an already loaded profile.
+    /// The artifacts dir of the profile is not applied, see [`Profile::artifacts_dir`].
+    pub fn with_profile(profile: &Profile) -> Result<Self, DaemonError> {
+        let mut builder = Self::new(profile.chain_info()?);
+        if let Some(mnemonic) = profile.mnemonic()? {
+            builder.mnemonic(mnemonic);
+        }
+        if let Some(deployment_id) = &profile.deployment_id {
+            builder.deployment_id(deployment_id);
+        }
+        if let Some(state_file) = &profile.state_file {
+            builder.state_path(state_file.display());
+        }
+        Ok(builder)
+    }
+
     /// Set the deployment id to use for the daemon interactions
     /// Defaults to `default`
     pub fn deployment_id(&mut self, deployment_id: impl Into<String>) -> &mut Self {
@@ -82,6 +129,70 @@ impl DaemonAsyncBuilder {
         self
     }
 
+    /// Set the source of the mnemonic used for the default Cosmos wallet
+    /// Ignored if a mnemonic is set with [`Self::mnemonic`]
+    pub fn secret_provider(&mut self, provider: impl SecretProvider + 'static) -> &mut Self {
+        self.secret_provider = Some(Arc::new(provider));
+        self
+    }
+
+    /// Set a custom signer of the transactions of the default Cosmos wallet (KMS, remote signer...)
+    /// Takes precedence over the mnemonic and the secret provider
+    pub fn signer(&mut self, signer: impl TxSigner + 'static) -> &mut Self {
+        self.signer = Some(Arc::new(signer));
+        self
+    }
+
+    /// Set the timings of the wait loops of the daemon and of its default wallet
+    /// Defaults to [`PollingConfig::default`]
+    pub fn polling(&mut self, polling: PollingConfig) -> &mut Self {
+        self.polling = Some(polling);
+        self
+    }
+
+    /// Require a confirmation before the default wallet broadcasts transactions on chains of `kind`
+    /// Defaults to the `CW_ORCH_CONFIRM_TXS_ON` env variable
+    pub fn confirm_txs_on(&mut self, kind: ChainKind) -> &mut Self {
+        let guard = self.tx_guard.take().unwrap_or_default();
+        self.tx_guard = Some(guard.confirm_on(kind));
+        self
+    }
+
+    /// Set the confirmation required before the default wallet broadcasts transactions, with its allowlist
+    /// See [`TxGuard`] for more details
+    pub fn tx_guard(&mut self, guard: TxGuard) -> &mut Self {
+        self.tx_guard = Some(guard);
+        self
+    }
+
+    /// Cache the expensive queries (code hashes, contract infos, chain params) of the daemon and its queriers
+    /// See [`QueryCache`] for more details
+    pub fn query_cache(&mut self, cache: QueryCache) -> &mut Self {
+        self.query_cache = Some(cache);
+        self
+    }
+
+    /// Limit the requests sent to the gRPC endpoints by the default wallet
+    /// Defaults to the `CW_ORCH_GRPC_RATE_LIMIT` env variable
+    pub fn rate_limits(&mut self, rate_limits: RateLimits) -> &mut Self {
+        self.rate_limits = Some(rate_limits);
+        self
+    }
+
+    /// Attach a memo rendered from `memo` to all the transactions of the daemon
+    /// See [`MemoTemplate`] for the available placeholders
+    pub fn memo(&mut self, memo: MemoTemplate) -> &mut Self {
+        self.memo = Some(memo);
+        self
+    }
+
+    /// Check the wasm files with `precheck` before uploading them
+    /// No check is done by default
+    pub fn upload_precheck(&mut self, precheck: UploadPrecheck) -> &mut Self {
+        self.upload_precheck = Some(precheck);
+        self
+    }
+
     /// Overwrite the chain info
     pub fn chain(&mut self, chain: impl Into<ChainInfoOwned>) -> &mut Self {
         self.chain = chain.into();
@@ -106,7 +217,6 @@ impl DaemonAsyncBuilder {
     /// Defaults to env variable.
     ///
     /// Variable: STATE_FILE_ENV_NAME.
-    #[allow(unused)]
     pub(crate) fn state_path(&mut self, path: impl ToString) -> &mut Self {
         self.state_path = Some(path.to_string());
         self
@@ -128,9 +238,15 @@ impl DaemonAsyncBuilder {
         // if mnemonic provided, use it. Else use env variables to retrieve mnemonic
 
         let options = CosmosOptions {
-            key: self.mnemonic.as_ref().map_or(CosmosWalletKey::Env, |m| {
-                CosmosWalletKey::Mnemonic(m.clone())
-            }),
+            key: match (&self.signer, &self.mnemonic, &self.secret_provider) {
+                (Some(signer), _, _) => CosmosWalletKey::Signer(signer.clone()),
+                (None, Some(mnemonic), _) => CosmosWalletKey::Mnemonic(mnemonic.clone()),
+                (None, None, Some(provider)) => CosmosWalletKey::Secret(provider.clone()),
+                (None, None, None) => CosmosWalletKey::Env,
+            },
+            polling: self.polling.clone(),
+            tx_guard: self.tx_guard.clone(),
+            rate_limits: self.rate_limits.clone(),
             ..Default::default()
         };
         let sender = options.build(&chain_info).await?;
@@ -140,13 +256,20 @@ impl DaemonAsyncBuilder {
             sender.address()
         );
 
-        let daemon = DaemonAsyncBase::new(sender, state);
+        let mut daemon = DaemonAsyncBase::new(sender, state);
+        daemon.polling = self.polling.clone().unwrap_or_default();
+        daemon.query_cache = self.query_cache.clone();
+        daemon.memo = self.memo.clone();
+        daemon.upload_precheck = self.upload_precheck.clone();
+        daemon.tx_guard = self.tx_guard.clone();
+        daemon.rate_limits = self.rate_limits.clone();
 
         print_if_log_disabled()?;
         Ok(daemon)
     }
 
     /// Build a daemon
+    /// The polling timings only apply to the daemon, custom senders are configured through their own options
     pub async fn build_sender<T: SenderBuilder>(
         &self,
         sender_options: T,
@@ -164,11 +287,17 @@ impl DaemonAsyncBuilder {
         let state = self.build_state(chain_info.clone())?;
 
         let sender = sender_options
-            .build(&chain_info)
+            .build_with_rate_limits(&chain_info, &self.rate_limits.clone().unwrap_or_default())
             .await
             .map_err(Into::into)?;
 
-        let daemon = DaemonAsyncBase::new(sender, state);
+        let mut daemon = DaemonAsyncBase::new(sender, state);
+        daemon.polling = self.polling.clone().unwrap_or_default();
+        daemon.query_cache = self.query_cache.clone();
+        daemon.memo = self.memo.clone();
+        daemon.upload_precheck = self.upload_precheck.clone();
+        daemon.tx_guard = self.tx_guard.clone();
+        daemon.rate_limits = self.rate_limits.clone();
 
         print_if_log_disabled()?;
         Ok(daemon)
@@ -236,6 +365,14 @@ impl From<DaemonBuilder> for DaemonAsyncBuilder {
             state_path: value.state_path,
             write_on_change: value.write_on_change,
             mnemonic: value.mnemonic,
+            secret_provider: value.secret_provider,
+            signer: value.signer,
+            polling: value.polling,
+            tx_guard: value.tx_guard,
+            rate_limits: value.rate_limits,
+            query_cache: value.query_cache,
+            memo: value.memo,
+            upload_precheck: value.upload_precheck,
             is_test: value.is_test,
             load_network: value.load_network,
         }
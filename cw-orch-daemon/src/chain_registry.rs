@@ -0,0 +1,212 @@
+//! Loads network definitions from the [Cosmos Chain Registry](https://github.com/cosmos/chain-registry).
+//!
+//! Registry files are cached in `~/.cw-orchestrator/chain-registry` for a day.
+//! When the registry can't be reached, the cached file is used regardless of its age.
+use std::{path::PathBuf, time::Duration};
+
+use cw_orch_core::{
+    environment::{ChainInfoOwned, ChainKind, NetworkInfoOwned},
+    log::connectivity_target,
+};
+use serde::Deserialize;
+
+use crate::{env::default_state_folder, DaemonError, RUNTIME};
+
+const CHAIN_REGISTRY_URL: &str = "https://raw.githubusercontent.com/cosmos/chain-registry/master";
+const CACHE_FOLDER: &str = "chain-registry";
+const CACHE_DURATION: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Subset of the chain registry `chain.json` schema needed to build a [`ChainInfoOwned`]
+#[derive(Deserialize)]
+struct RegistryChain {
+    chain_name: String,
+    chain_id: String,
+    bech32_prefix: String,
+    #[serde(default)]
+    network_type: String,
+    #[serde(default = "default_slip44")]
+    slip44: u32,
+    fees: RegistryFees,
+    #[serde(default)]
+    apis: RegistryApis,
+}
+
+#[derive(Deserialize)]
+struct RegistryFees {
+    fee_tokens: Vec<RegistryFeeToken>,
+}
+
+#[derive(Deserialize)]
+struct RegistryFeeToken {
+    denom: String,
+    fixed_min_gas_price: Option<f64>,
+    low_gas_price: Option<f64>,
+    average_gas_price: Option<f64>,
+}
+
+#[derive(Deserialize, Default)]
+struct RegistryApis {
+    #[serde(default)]
+    grpc: Vec<RegistryEndpoint>,
+    #[serde(default)]
+    rest: Vec<RegistryEndpoint>,
+}
+
+#[derive(Deserialize)]
+struct RegistryEndpoint {
+    address: String,
+}
+
+fn default_slip44() -> u32 {
+    118
+}
+
+/// Loads a network from the chain registry, see [`_from_registry`].
+pub fn from_registry(chain_name: &str) -> Result<ChainInfoOwned, DaemonError> {
+    RUNTIME.block_on(_from_registry(chain_name))
+}
+
+/// Loads a network from the chain registry.
+/// `chain_name` is the path of the chain in the registry, i.e. `osmosis` or `testnets/osmosistestnet`.
+///
+/// ```rust,no_run
+/// use cw_orch_daemon::{networks::from_registry, Daemon};
+///
+/// let osmosis = from_registry("osmosis").unwrap();
+/// let daemon = Daemon::builder(osmosis).build().unwrap();
+/// ```
+pub async fn _from_registry(chain_name: &str) -> Result<ChainInfoOwned, DaemonError> {
+    let cache_file = cache_path(chain_name)?;
+
+    let cached = std::fs::metadata(&cache_file)
+        .and_then(|m| m.modified())
+        .ok()
+        .and_then(|modified| modified.elapsed().ok());
+    let content = match cached {
+        Some(age) if age < CACHE_DURATION => std::fs::read_to_string(&cache_file)?,
+        _ => match fetch_chain_json(chain_name).await {
+            Ok(content) => {
+                if let Some(parent) = cache_file.parent() {
+                    std::fs::create_dir_all(parent)?;
+                }
+                std::fs::write(&cache_file, &content)?;
+                content
+            }
+            Err(e) if cached.is_some() => {
+                log::warn!(
+                    target: &connectivity_target(),
+                    "Couldn't reach the chain registry ({}), using the cached definition of {}",
+                    e,
+                    chain_name
+                );
+                std::fs::read_to_string(&cache_file)?
+            }
+            Err(e) => return Err(e),
+        },
+    };
+
+    let chain: RegistryChain = serde_json::from_str(&content)?;
+    chain.try_into()
+}
+
+async fn fetch_chain_json(chain_name: &str) -> Result<String, DaemonError> {
+    let url = format!("{CHAIN_REGISTRY_URL}/{chain_name}/chain.json");
+    let response = reqwest::get(url).await?.error_for_status()?;
+    Ok(response.text().await?)
+}
+
+fn cache_path(chain_name: &str) -> Result<PathBuf, DaemonError> {
+    let file_name = format!("{}.json", chain_name.replace('/', "_"));
+    Ok(default_state_folder()?.join(CACHE_FOLDER).join(file_name))
+}
+
+/// Registry gRPC endpoints are mostly listed without scheme
+fn with_scheme(address: String) -> String {
+    if address.contains("://") {
+        address
+    } else if address.ends_with(":443") {
+        format!("https://{address}")
+    } else {
+        format!("http://{address}")
+    }
+}
+
+impl TryFrom<RegistryChain> for ChainInfoOwned {
+    type Error = DaemonError;
+
+    fn try_from(chain: RegistryChain) -> Result<Self, Self::Error> {
+        let fee_token = chain.fees.fee_tokens.into_iter().next().ok_or_else(|| {
+            DaemonError::StdErr(format!(
+                "No fee token registered for {} in the chain registry",
+                chain.chain_id
+            ))
+        })?;
+        let gas_price = fee_token
+            .average_gas_price
+            .or(fee_token.low_gas_price)
+            .or(fee_token.fixed_min_gas_price)
+            .unwrap_or_default();
+
+        let kind = match chain.network_type.as_str() {
+            "mainnet" => ChainKind::Mainnet,
+            "testnet" | "devnet" => ChainKind::Testnet,
+            _ => ChainKind::Unspecified,
+        };
+
+        Ok(ChainInfoOwned {
+            kind,
+            chain_id: chain.chain_id,
+            gas_denom: fee_token.denom,
+            gas_price,
+            grpc_urls: chain
+                .apis
+                .grpc
+                .into_iter()
+                .map(|e| with_scheme(e.address))
+                .collect(),
+            lcd_url: chain.apis.rest.into_iter().next().map(|e| e.address),
+            fcd_url: None,
+            network_info: NetworkInfoOwned {
+                chain_name: chain.chain_name,
+                pub_address_prefix: chain.bech32_prefix,
+                coin_type: chain.slip44,
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parses_registry_chain() {
+        let content = r#"{
+            "chain_name": "osmosis",
+            "chain_id": "osmosis-1",
+            "bech32_prefix": "osmo",
+            "network_type": "mainnet",
+            "slip44": 118,
+            "fees": { "fee_tokens": [{ "denom": "uosmo", "fixed_min_gas_price": 0.0025, "average_gas_price": 0.025 }] },
+            "apis": {
+                "grpc": [{ "address": "grpc.osmosis.zone:443" }, { "address": "http://osmosis-grpc.polkachu.com:12590" }],
+                "rest": [{ "address": "https://lcd.osmosis.zone" }]
+            }
+        }"#;
+        let chain: RegistryChain = serde_json::from_str(content).unwrap();
+        let chain_info = ChainInfoOwned::try_from(chain).unwrap();
+
+        assert_eq!(chain_info.kind, ChainKind::Mainnet);
+        assert_eq!(chain_info.chain_id, "osmosis-1");
+        assert_eq!(chain_info.gas_denom, "uosmo");
+        assert_eq!(chain_info.gas_price, 0.025);
+        assert_eq!(
+            chain_info.grpc_urls,
+            vec![
+                "https://grpc.osmosis.zone:443".to_string(),
+                "http://osmosis-grpc.polkachu.com:12590".to_string()
+            ]
+        );
+        assert_eq!(chain_info.network_info.pub_address_prefix, "osmo");
+    }
+}
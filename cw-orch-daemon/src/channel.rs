@@ -3,81 +3,231 @@ use cosmrs::proto::cosmos::base::tendermint::v1beta1::{
 };
 use cw_orch_core::{environment::ChainInfoOwned, log::connectivity_target};
 use http::Uri;
+use std::time::{Duration, Instant};
 use tonic::transport::{Channel, ClientTlsConfig, Endpoint};
 
 use super::error::DaemonError;
+use crate::{RateLimit, RateLimits};
 
 /// A helper for constructing a gRPC channel
 pub struct GrpcChannel {}
 
+/// Version of the Cosmos SDK run by a node, used to pick the protos supported by the chain
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct SdkVersion {
+    pub major: u64,
+    pub minor: u64,
+    pub patch: u64,
+}
+
+impl SdkVersion {
+    /// Version assumed when the node doesn't report a parsable version
+    pub const LATEST: SdkVersion = SdkVersion::new(0, 50, 0);
+
+    pub const fn new(major: u64, minor: u64, patch: u64) -> Self {
+        Self {
+            major,
+            minor,
+            patch,
+        }
+    }
+
+    /// Parses the version reported by the nodes, e.g. `v0.50.3` or `v0.47.5-ics-lsm`
+    pub fn parse(version: &str) -> Option<Self> {
+        let version = version.trim().trim_start_matches('v');
+        let mut parts = version
+            .split(['.', '-', '+'])
+            .map(|part| part.parse::<u64>());
+        Some(Self::new(
+            parts.next()?.ok()?,
+            parts.next()?.ok()?,
+            parts.next().and_then(Result::ok).unwrap_or_default(),
+        ))
+    }
+
+    /// Whether the chain serves the gov v1 module (SDK 0.46+)
+    pub fn has_gov_v1(&self) -> bool {
+        *self >= SdkVersion::new(0, 46, 0)
+    }
+
+    /// Whether the chain simulates the encoded transactions (SDK 0.43+).
+    /// The older chains only accept the decoded transaction, which was removed in SDK 0.50.
+    pub fn simulates_tx_bytes(&self) -> bool {
+        *self >= SdkVersion::new(0, 43, 0)
+    }
+}
+
+/// Result of probing a gRPC endpoint
+#[derive(Clone, Debug, PartialEq)]
+pub struct EndpointStatus {
+    /// Url of the endpoint
+    pub url: String,
+    /// Whether the endpoint answered and is connected to the expected chain
+    pub healthy: bool,
+    /// Time it took to connect and query the node info
+    pub latency: Option<Duration>,
+    /// Reason why the endpoint is not healthy
+    pub error: Option<String>,
+}
+
 impl GrpcChannel {
-    /// Connect to any of the provided gRPC endpoints
+    /// Connect to the healthy gRPC endpoint with the lowest latency among the provided endpoints.
+    /// An endpoint is healthy if it answers and is connected to the expected chain.
     pub async fn connect(grpc: &[String], chain_id: &str) -> Result<Channel, DaemonError> {
+        Self::connect_with_limits(grpc, chain_id, &RateLimits::default()).await
+    }
+
+    /// Same as [`GrpcChannel::connect`], the requests sent through the channel being limited by `rate_limits`
+    pub async fn connect_with_limits(
+        grpc: &[String],
+        chain_id: &str,
+        rate_limits: &RateLimits,
+    ) -> Result<Channel, DaemonError> {
         if grpc.is_empty() {
             return Err(DaemonError::GRPCListIsEmpty);
         }
 
-        let mut successful_connections = vec![];
+        let mut probes = vec![];
+        for address in grpc.iter() {
+            probes.push(Self::probe(address, chain_id, rate_limits.limit_of(address)).await);
+        }
 
+        probes
+            .into_iter()
+            .filter_map(|(channel, status)| Some((channel?, status.latency?)))
+            .min_by_key(|(_, latency)| *latency)
+            .map(|(channel, _)| channel)
+            // we could not get any succesful connections
+            .ok_or(DaemonError::CannotConnectGRPC)
+    }
+
+    /// Probes all the provided gRPC endpoints for liveness and chain-id match
+    pub async fn endpoint_status(grpc: &[String], chain_id: &str) -> Vec<EndpointStatus> {
+        let mut statuses = vec![];
         for address in grpc.iter() {
-            log::debug!(target: &connectivity_target(), "Trying to connect to endpoint: {}", address);
+            statuses.push(Self::probe(address, chain_id, None).await.1);
+        }
+        statuses
+    }
 
-            let uri = Uri::from_maybe_shared(address.clone()).expect("Invalid URI");
+    async fn probe(
+        address: &str,
+        chain_id: &str,
+        rate_limit: Option<RateLimit>,
+    ) -> (Option<Channel>, EndpointStatus) {
+        log::debug!(target: &connectivity_target(), "Trying to connect to endpoint: {}", address);
 
-            let maybe_channel = Endpoint::from(uri)
-                .tls_config(
-                    ClientTlsConfig::new()
-                        .with_enabled_roots()
-                        // grpcs are http/2 by spec
-                        .assume_http2(true),
-                )
-                .unwrap()
-                .connect()
-                .await;
+        let start = Instant::now();
+        let result = Self::connect_and_check(address, chain_id, rate_limit).await;
+        let latency = start.elapsed();
 
-            if maybe_channel.is_err() {
+        match result {
+            Ok(channel) => (
+                Some(channel),
+                EndpointStatus {
+                    url: address.to_string(),
+                    healthy: true,
+                    latency: Some(latency),
+                    error: None,
+                },
+            ),
+            Err(e) => {
                 log::warn!(
-                    "Cannot connect to gRPC endpoint: {}, {:?}",
+                    target: &connectivity_target(),
+                    "Cannot use gRPC endpoint: {}, {}",
                     address,
-                    maybe_channel.unwrap_err()
-                );
-                continue;
-            };
-            let channel = maybe_channel.unwrap();
-
-            let mut client = ServiceClient::new(channel.clone());
-
-            // Verify that node is the expected network
-            let node_info = client
-                .get_node_info(GetNodeInfoRequest {})
-                .await?
-                .into_inner();
-
-            if node_info.default_node_info.as_ref().unwrap().network != chain_id {
-                log::error!(
-                    "Network mismatch: connection:{} != config:{}",
-                    node_info.default_node_info.as_ref().unwrap().network,
-                    chain_id
+                    e
                 );
-                continue;
+                (
+                    None,
+                    EndpointStatus {
+                        url: address.to_string(),
+                        healthy: false,
+                        latency: None,
+                        error: Some(e.to_string()),
+                    },
+                )
             }
+        }
+    }
 
-            // add endpoint to succesful connections
-            successful_connections.push(channel);
+    async fn connect_and_check(
+        address: &str,
+        chain_id: &str,
+        rate_limit: Option<RateLimit>,
+    ) -> Result<Channel, DaemonError> {
+        let uri = Uri::from_maybe_shared(address.to_string())
+            .map_err(|e| DaemonError::StdErr(format!("Invalid URI: {e}")))?;
+
+        let mut endpoint = Endpoint::from(uri).tls_config(
+            ClientTlsConfig::new()
+                .with_enabled_roots()
+                // grpcs are http/2 by spec
+                .assume_http2(true),
+        )?;
+        if let Some(rate_limit) = rate_limit {
+            endpoint = rate_limit.apply(endpoint);
         }
+        let channel = endpoint.connect().await?;
+
+        let mut client = ServiceClient::new(channel.clone());
+
+        // Verify that node is the expected network
+        let node_info = client
+            .get_node_info(GetNodeInfoRequest {})
+            .await?
+            .into_inner();
 
-        // we could not get any succesful connections
-        if successful_connections.is_empty() {
-            return Err(DaemonError::CannotConnectGRPC);
+        let network = node_info
+            .default_node_info
+            .map(|info| info.network)
+            .unwrap_or_default();
+        if network != chain_id {
+            return Err(DaemonError::StdErr(format!(
+                "Network mismatch: connection:{} != config:{}",
+                network, chain_id
+            )));
         }
 
-        Ok(successful_connections.pop().unwrap())
+        Ok(channel)
+    }
+
+    /// Queries the version of the Cosmos SDK run by the node.
+    /// Defaults to [`SdkVersion::LATEST`] when the node doesn't report a parsable version.
+    pub async fn sdk_version(channel: Channel) -> Result<SdkVersion, DaemonError> {
+        let node_info = ServiceClient::new(channel)
+            .get_node_info(GetNodeInfoRequest {})
+            .await?
+            .into_inner();
+
+        let reported = node_info
+            .application_version
+            .map(|version| version.cosmos_sdk_version)
+            .unwrap_or_default();
+        Ok(SdkVersion::parse(&reported).unwrap_or_else(|| {
+            log::debug!(
+                target: &connectivity_target(),
+                "Unknown Cosmos SDK version `{}`, assuming {:?}",
+                reported,
+                SdkVersion::LATEST
+            );
+            SdkVersion::LATEST
+        }))
     }
 
     /// Create a gRPC channel from the chain info
     pub async fn from_chain_info(chain_info: &ChainInfoOwned) -> Result<Channel, DaemonError> {
         GrpcChannel::connect(&chain_info.grpc_urls, &chain_info.chain_id).await
     }
+
+    /// Create a gRPC channel from the chain info, limiting the requests sent to the endpoints
+    pub async fn from_chain_info_with_limits(
+        chain_info: &ChainInfoOwned,
+        rate_limits: &RateLimits,
+    ) -> Result<Channel, DaemonError> {
+        GrpcChannel::connect_with_limits(&chain_info.grpc_urls, &chain_info.chain_id, rate_limits)
+            .await
+    }
 }
 
 #[cfg(test)]
@@ -86,9 +236,27 @@ mod tests {
         This test asserts breaking issues around the GRPC connection
     */
 
-    use crate::DaemonAsync;
+    use crate::{DaemonAsync, SdkVersion};
     use speculoos::prelude::*;
 
+    #[test]
+    fn parse_sdk_versions() {
+        assert_eq!(
+            SdkVersion::parse("v0.50.3"),
+            Some(SdkVersion::new(0, 50, 3))
+        );
+        assert_eq!(
+            SdkVersion::parse("v0.47.5-ics-lsm"),
+            Some(SdkVersion::new(0, 47, 5))
+        );
+        assert_eq!(SdkVersion::parse("0.45"), Some(SdkVersion::new(0, 45, 0)));
+        assert_eq!(SdkVersion::parse(""), None);
+        assert!(!SdkVersion::new(0, 45, 16).has_gov_v1());
+        assert!(SdkVersion::new(0, 50, 0).has_gov_v1());
+        assert!(!SdkVersion::new(0, 42, 10).simulates_tx_bytes());
+        assert!(SdkVersion::new(0, 50, 0).simulates_tx_bytes());
+    }
+
     #[tokio::test]
     #[serial_test::serial]
     async fn no_connection() {
@@ -108,6 +276,21 @@ mod tests {
             ))
     }
 
+    #[tokio::test]
+    #[serial_test::serial]
+    async fn unhealthy_endpoint_status() {
+        let grpcs = vec!["https://127.0.0.1:99999".to_string()];
+
+        let statuses = super::GrpcChannel::endpoint_status(&grpcs, "juno-1").await;
+
+        asserting!("the endpoint is reported as unhealthy")
+            .that(&statuses[0].healthy)
+            .is_false();
+        asserting!("the error is reported")
+            .that(&statuses[0].error)
+            .is_some();
+    }
+
     #[tokio::test]
     #[serial_test::serial]
     async fn network_grpcs_list_is_empty() {
@@ -0,0 +1,113 @@
+//! In-process locks on contract addresses.
+//!
+//! Bots running multiple async tasks can end up executing the same contract concurrently (e.g. two cranks in the same block).
+//! When a [`ContractLockPolicy`] is set on a daemon, executes on a contract are serialized across all the daemons of the process.
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use cosmwasm_std::Addr;
+use tokio::sync::{Mutex as AsyncMutex, OwnedMutexGuard};
+
+use crate::DaemonError;
+
+lazy_static::lazy_static! {
+    static ref CONTRACT_LOCKS: Mutex<HashMap<(String, Addr), Arc<AsyncMutex<()>>>> = Mutex::new(HashMap::new());
+}
+
+/// How executes on a contract that is already being executed by another task are handled
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ContractLockPolicy {
+    /// Executes are not guarded
+    #[default]
+    Disabled,
+    /// Executes wait for the previous ones on the same contract, in the order they were queued
+    Queue,
+    /// Executes wait for the previous ones on the same contract and error if they waited longer than the duration
+    QueueWithTimeout(Duration),
+    /// Executes error right away if the contract is being executed
+    Reject,
+}
+
+impl ContractLockPolicy {
+    /// Acquires the lock of `contract` on `chain_id` following the policy.
+    /// The lock is released when the returned guard is dropped.
+    pub(crate) async fn acquire(
+        &self,
+        chain_id: &str,
+        contract: &Addr,
+    ) -> Result<Option<OwnedMutexGuard<()>>, DaemonError> {
+        if *self == ContractLockPolicy::Disabled {
+            return Ok(None);
+        }
+
+        let lock = CONTRACT_LOCKS
+            .lock()
+            .unwrap()
+            .entry((chain_id.to_string(), contract.clone()))
+            .or_default()
+            .clone();
+
+        let guard = match self {
+            ContractLockPolicy::Disabled => unreachable!(),
+            ContractLockPolicy::Queue => lock.lock_owned().await,
+            ContractLockPolicy::QueueWithTimeout(timeout) => {
+                tokio::time::timeout(*timeout, lock.lock_owned())
+                    .await
+                    .map_err(|_| DaemonError::ContractLocked(contract.to_string()))?
+            }
+            ContractLockPolicy::Reject => lock
+                .try_lock_owned()
+                .map_err(|_| DaemonError::ContractLocked(contract.to_string()))?,
+        };
+        Ok(Some(guard))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn reject_when_locked() -> anyhow::Result<()> {
+        let contract = Addr::unchecked("contract");
+        let guard = ContractLockPolicy::Queue
+            .acquire("chain-1", &contract)
+            .await?;
+        assert!(guard.is_some());
+
+        let rejected = ContractLockPolicy::Reject
+            .acquire("chain-1", &contract)
+            .await;
+        assert!(matches!(rejected, Err(DaemonError::ContractLocked(_))));
+
+        // Other chains are not impacted
+        let other_chain = ContractLockPolicy::Reject
+            .acquire("chain-2", &contract)
+            .await?;
+        assert!(other_chain.is_some());
+
+        drop(guard);
+        let released = ContractLockPolicy::Reject
+            .acquire("chain-1", &contract)
+            .await?;
+        assert!(released.is_some());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn queue_with_timeout() -> anyhow::Result<()> {
+        let contract = Addr::unchecked("timeout_contract");
+        let _guard = ContractLockPolicy::Queue
+            .acquire("chain-1", &contract)
+            .await?;
+
+        let timed_out = ContractLockPolicy::QueueWithTimeout(Duration::from_millis(10))
+            .acquire("chain-1", &contract)
+            .await;
+        assert!(matches!(timed_out, Err(DaemonError::ContractLocked(_))));
+        Ok(())
+    }
+}
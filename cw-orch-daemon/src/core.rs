@@ -1,29 +1,55 @@
 use super::{
-    cosmos_modules, error::DaemonError, queriers::Node, senders::Wallet, tx_resp::CosmTxResponse,
+    cosmos_modules,
+    error::DaemonError,
+    queriers::{ChainParams, Node, Params, QueryCache, VestingPeriod},
+    senders::Wallet,
+    tx_resp::CosmTxResponse,
 };
 use crate::{
+    memo::MemoTemplate,
+    polling::PollingConfig,
     queriers::CosmWasm,
-    senders::{builder::SenderBuilder, query::QuerySender, tx::TxSender},
-    DaemonAsyncBuilder, DaemonState,
+    senders::{builder::SenderBuilder, query::QuerySender, sign::Signer, tx::TxSender, TxGuard},
+    ContractLockPolicy, DaemonAsyncBuilder, DaemonState, EndpointStatus, GrpcChannel, RateLimits,
 };
 use cosmrs::{
-    cosmwasm::{MsgExecuteContract, MsgInstantiateContract, MsgMigrateContract},
-    proto::cosmwasm::wasm::v1::MsgInstantiateContract2,
+    bank::MsgSend,
+    cosmwasm::{
+        MsgClearAdmin, MsgExecuteContract, MsgInstantiateContract, MsgMigrateContract,
+        MsgUpdateAdmin,
+    },
+    proto::{
+        cosmos::{
+            gov::v1::MsgSubmitProposal,
+            vesting::v1beta1::{MsgCreatePeriodicVestingAccount, MsgCreateVestingAccount, Period},
+        },
+        cosmwasm::wasm::v1::{
+            MsgInstantiateContract2, MsgPinCodes, MsgSudoContract, MsgUnpinCodes,
+            MsgUpdateInstantiateConfig,
+        },
+    },
     tendermint::Time,
     AccountId, Any, Denom,
 };
 use cosmwasm_std::{Addr, Binary, Coin};
 use cw_orch_core::{
-    contract::{interface_traits::Uploadable, WasmPath},
+    contract::{
+        interface_traits::{ContractInstance, Uploadable},
+        UploadPrecheck, WasmPath,
+    },
     environment::{
-        AccessConfig, AsyncWasmQuerier, ChainInfoOwned, ChainState, IndexResponse, Querier,
+        AccessConfig, AsyncTxHandler, AsyncWasmQuerier, ChainInfoOwned, ChainState, IndexResponse,
+        InstantiateHooks, InstantiatedContract, MaybeSend, Querier, TxContext, TxHooks, TxKind,
     },
     log::transaction_target,
+    CoreEnvVars, CwEnvError,
 };
 use flate2::{write, Compression};
+use futures_util::future::try_join_all;
 use prost::Message;
 use serde::{de::DeserializeOwned, Serialize};
 use serde_json::from_str;
+use sha2::{Digest, Sha256};
 use std::{
     fmt::Debug,
     io::Write,
@@ -34,6 +60,14 @@ use std::{
 use tonic::transport::Channel;
 
 pub const INSTANTIATE_2_TYPE_URL: &str = "/cosmwasm.wasm.v1.MsgInstantiateContract2";
+pub const PIN_CODES_TYPE_URL: &str = "/cosmwasm.wasm.v1.MsgPinCodes";
+pub const UNPIN_CODES_TYPE_URL: &str = "/cosmwasm.wasm.v1.MsgUnpinCodes";
+pub const UPDATE_INSTANTIATE_CONFIG_TYPE_URL: &str = "/cosmwasm.wasm.v1.MsgUpdateInstantiateConfig";
+pub const SUDO_CONTRACT_TYPE_URL: &str = "/cosmwasm.wasm.v1.MsgSudoContract";
+pub const SUBMIT_PROPOSAL_TYPE_URL: &str = "/cosmos.gov.v1.MsgSubmitProposal";
+pub const CREATE_VESTING_ACCOUNT_TYPE_URL: &str = "/cosmos.vesting.v1beta1.MsgCreateVestingAccount";
+pub const CREATE_PERIODIC_VESTING_ACCOUNT_TYPE_URL: &str =
+    "/cosmos.vesting.v1beta1.MsgCreatePeriodicVestingAccount";
 
 #[derive(Clone)]
 /**
@@ -70,19 +104,56 @@ pub struct DaemonAsyncBase<Sender = Wallet> {
     sender: Sender,
     /// State of the daemon
     pub(crate) state: DaemonState,
+    /// Policy for concurrent executes on the same contract
+    pub(crate) lock_policy: ContractLockPolicy,
+    /// Hooks fired after each instantiation
+    pub(crate) instantiate_hooks: InstantiateHooks,
+    /// Hooks fired around each transaction
+    pub(crate) tx_hooks: TxHooks,
+    /// Timings of the wait loops
+    pub(crate) polling: PollingConfig,
+    /// Cache of the expensive queries, shared with the queriers built from the daemon
+    pub(crate) query_cache: Option<QueryCache>,
+    /// Template of the memo attached to the transactions
+    pub(crate) memo: Option<MemoTemplate>,
+    /// Checks run on the wasm files before uploading them
+    pub(crate) upload_precheck: Option<UploadPrecheck>,
+    /// Confirmation guard the daemon was built with, kept on rebuild
+    pub(crate) tx_guard: Option<TxGuard>,
+    /// Rate limits of the gRPC endpoints the daemon was built with, kept on rebuild
+    pub(crate) rate_limits: Option<RateLimits>,
 }
 
 pub type DaemonAsync = DaemonAsyncBase<Wallet>;
 
 impl<Sender> DaemonAsyncBase<Sender> {
     pub(crate) fn new(sender: Sender, state: DaemonState) -> Self {
-        Self { sender, state }
+        Self {
+            sender,
+            state,
+            lock_policy: ContractLockPolicy::default(),
+            instantiate_hooks: InstantiateHooks::default(),
+            tx_hooks: TxHooks::default(),
+            polling: PollingConfig::default(),
+            query_cache: None,
+            memo: None,
+            upload_precheck: None,
+            tx_guard: None,
+            rate_limits: None,
+        }
     }
 
     pub fn chain_info(&self) -> &ChainInfoOwned {
         self.state.chain_data.as_ref()
     }
 
+    /// Probes every gRPC endpoint of the chain for liveness and chain-id match.
+    /// The daemon connects to the healthy endpoint with the lowest latency.
+    pub async fn endpoint_status(&self) -> Vec<EndpointStatus> {
+        let chain_info = self.chain_info();
+        GrpcChannel::endpoint_status(&chain_info.grpc_urls, &chain_info.chain_id).await
+    }
+
     /// Get the daemon builder
     pub fn builder(chain: impl Into<ChainInfoOwned>) -> DaemonAsyncBuilder {
         DaemonAsyncBuilder::new(chain)
@@ -95,15 +166,108 @@ impl<Sender> DaemonAsyncBase<Sender> {
         sender_options: T,
     ) -> DaemonAsyncBase<T::Sender> {
         let sender = sender_options
-            .build(&self.state.chain_data)
+            .build_with_rate_limits(
+                &self.state.chain_data,
+                &self.rate_limits.clone().unwrap_or_default(),
+            )
             .await
             .expect("Failed to build sender");
         DaemonAsyncBase {
             sender,
             state: self.state,
+            lock_policy: self.lock_policy,
+            instantiate_hooks: self.instantiate_hooks,
+            tx_hooks: self.tx_hooks,
+            polling: self.polling,
+            query_cache: self.query_cache,
+            memo: self.memo,
+            upload_precheck: self.upload_precheck,
+            tx_guard: self.tx_guard,
+            rate_limits: self.rate_limits,
         }
     }
 
+    /// Sets how concurrent executes on the same contract are handled.
+    /// The locks are shared by all the daemons of the process.
+    pub fn set_contract_lock_policy(&mut self, policy: ContractLockPolicy) {
+        self.lock_policy = policy;
+    }
+
+    /// Timings of the wait loops of the daemon
+    pub fn polling(&self) -> &PollingConfig {
+        &self.polling
+    }
+
+    /// Sets the timings of the wait loops of the daemon.
+    /// The sender keeps the timings it was built with for its own tx polling.
+    pub fn set_polling(&mut self, polling: PollingConfig) {
+        self.polling = polling;
+    }
+
+    /// Cache of the expensive queries, if enabled
+    pub fn query_cache(&self) -> Option<&QueryCache> {
+        self.query_cache.as_ref()
+    }
+
+    /// Template of the memo attached to the transactions of the daemon, if any
+    pub fn memo(&self) -> Option<&MemoTemplate> {
+        self.memo.as_ref()
+    }
+
+    /// Sets the template of the memo attached to the transactions of the daemon.
+    /// See [`MemoTemplate`] for the available placeholders.
+    pub fn set_memo(&mut self, memo: MemoTemplate) {
+        self.memo = Some(memo);
+    }
+
+    /// Checks run on the wasm files before uploading them, if any
+    pub fn upload_precheck(&self) -> Option<&UploadPrecheck> {
+        self.upload_precheck.as_ref()
+    }
+
+    /// Sets the checks run on the wasm files before uploading them
+    pub fn set_upload_precheck(&mut self, precheck: UploadPrecheck) {
+        self.upload_precheck = Some(precheck);
+    }
+
+    /// Copy of the daemon attaching `memo` to its transactions instead of its memo template.
+    /// Used to override the memo of a single call.
+    pub fn with_memo(&self, memo: impl Into<String>) -> Self
+    where
+        Sender: Clone,
+    {
+        let mut daemon = self.clone();
+        daemon.memo = Some(MemoTemplate::new(memo));
+        daemon
+    }
+
+    /// Registers a hook fired after every instantiation, to register the contracts it created in the state.
+    /// See [`InstantiateHooks`] for an example.
+    pub fn add_instantiate_hook(
+        &mut self,
+        hook: impl Fn(&[InstantiatedContract]) -> Vec<(String, Addr)> + Send + Sync + 'static,
+    ) {
+        self.instantiate_hooks.add(hook);
+    }
+
+    /// Registers a hook run before every transaction is broadcast. Returning an error aborts the transaction.
+    /// See [`TxHooks`] for an example.
+    pub fn add_pre_broadcast_hook(
+        &mut self,
+        hook: impl Fn(&TxContext) -> Result<(), CwEnvError> + Send + Sync + 'static,
+    ) {
+        self.tx_hooks.add_pre_broadcast(hook);
+    }
+
+    /// Registers a hook run after every successful transaction, with its events.
+    /// See [`TxHooks`] for an example.
+    pub fn add_post_response_hook(
+        &mut self,
+        hook: impl Fn(&TxContext) -> Result<(), CwEnvError> + Send + Sync + 'static,
+    ) {
+        self.tx_hooks.add_post_response(hook);
+    }
+
     /// Get a mutable Sender
     pub fn sender_mut(&mut self) -> &mut Sender {
         &mut self.sender
@@ -130,6 +294,14 @@ impl<Sender> DaemonAsyncBase<Sender> {
             state_path: None,
             write_on_change: None,
             mnemonic: None,
+            secret_provider: None,
+            signer: None,
+            polling: Some(self.polling.clone()),
+            tx_guard: self.tx_guard.clone(),
+            rate_limits: self.rate_limits.clone(),
+            query_cache: self.query_cache.clone(),
+            memo: self.memo.clone(),
+            upload_precheck: self.upload_precheck.clone(),
             // If it was test it will just use same tempfile as state
             is_test: false,
             // Uses same ChainInfo
@@ -144,6 +316,11 @@ impl<Sender: QuerySender> DaemonAsyncBase<Sender> {
         self.sender().channel()
     }
 
+    /// Node querier using the timings of the daemon
+    fn node(&self) -> Node {
+        Node::new_async(self.channel()).with_polling(self.polling.clone())
+    }
+
     /// Query a contract.
     pub async fn query<Q: Serialize + Debug, T: Serialize + DeserializeOwned>(
         &self,
@@ -163,34 +340,33 @@ impl<Sender: QuerySender> DaemonAsyncBase<Sender> {
 
     /// Wait for a given amount of blocks.
     pub async fn wait_blocks(&self, amount: u64) -> Result<(), DaemonError> {
-        let mut last_height = Node::new_async(self.channel())._block_height().await?;
+        let node = self.node();
+        let mut last_height = node._block_height().await?;
         let end_height = last_height + amount;
 
-        let average_block_speed = Node::new_async(self.channel())
-            ._average_block_speed(Some(0.9))
-            .await?;
+        let average_block_speed = self
+            .polling
+            .clamp_block_time(node._average_block_speed(Some(0.9)).await?);
 
         let wait_time = average_block_speed.mul_f64(amount as f64);
 
         // now wait for that amount of time
-        tokio::time::sleep(wait_time).await;
+        self.polling.sleep(wait_time).await?;
         // now check every block until we hit the target
         while last_height < end_height {
             // wait
 
-            tokio::time::sleep(average_block_speed).await;
+            self.polling.sleep(average_block_speed).await?;
 
             // ping latest block
-            last_height = Node::new_async(self.channel())._block_height().await?;
+            last_height = node._block_height().await?;
         }
         Ok(())
     }
 
     /// Wait for a given amount of seconds.
     pub async fn wait_seconds(&self, secs: u64) -> Result<(), DaemonError> {
-        tokio::time::sleep(Duration::from_secs(secs)).await;
-
-        Ok(())
+        self.polling.sleep(Duration::from_secs(secs)).await
     }
 
     /// Wait for the next block.
@@ -200,7 +376,7 @@ impl<Sender: QuerySender> DaemonAsyncBase<Sender> {
 
     /// Get the current block info.
     pub async fn block_info(&self) -> Result<cosmwasm_std::BlockInfo, DaemonError> {
-        let block = Node::new_async(self.channel())._latest_block().await?;
+        let block = self.node()._latest_block().await?;
         let since_epoch = block.header.time.duration_since(Time::unix_epoch())?;
         let time = cosmwasm_std::Timestamp::from_nanos(since_epoch.as_nanos() as u64);
         Ok(cosmwasm_std::BlockInfo {
@@ -209,6 +385,11 @@ impl<Sender: QuerySender> DaemonAsyncBase<Sender> {
             chain_id: block.header.chain_id.to_string(),
         })
     }
+
+    /// Get the key parameters of the staking, gov, slashing and wasm modules.
+    pub async fn chain_params(&self) -> Result<ChainParams, DaemonError> {
+        Params::from_async(self)._chain_params().await
+    }
 }
 
 impl<Sender> ChainState for DaemonAsyncBase<Sender> {
@@ -226,6 +407,31 @@ impl<Sender: TxSender> DaemonAsyncBase<Sender> {
         self.sender().address()
     }
 
+    fn tx_context(&self, kind: TxKind) -> TxContext {
+        TxContext::new(&self.chain_info().chain_id, self.sender_addr(), kind)
+    }
+
+    /// Memo of the transactions of `step`, rendered from the memo template if any
+    pub(crate) fn render_memo(&self, step: &str) -> Option<String> {
+        let template = self.memo.as_ref()?;
+        let sender = self.sender_addr();
+        Some(template.render([
+            ("chain_id", self.chain_info().chain_id.as_str()),
+            ("deployment_id", self.state.deployment_id.as_str()),
+            ("sender", sender.as_str()),
+            ("step", step),
+        ]))
+    }
+
+    /// Invalidates the cached queries and runs the post-response hooks
+    fn after_tx(&self, context: &TxContext, result: &CosmTxResponse) -> Result<(), DaemonError> {
+        if let Some(cache) = &self.query_cache {
+            cache.observe_height(result.height);
+        }
+        self.tx_hooks.post_response(context, result)?;
+        Ok(())
+    }
+
     /// Execute a message on a contract.
     pub async fn execute<E: Serialize>(
         &self,
@@ -239,12 +445,23 @@ impl<Sender: TxSender> DaemonAsyncBase<Sender> {
             msg: serde_json::to_vec(&exec_msg)?,
             funds: parse_cw_coins(coins)?,
         };
+        let context = self
+            .tx_context(TxKind::Execute)
+            .contract(contract_address)
+            .msg(exec_msg)
+            .funds(coins);
+        self.tx_hooks.pre_broadcast(&context)?;
+        let _lock = self
+            .lock_policy
+            .acquire(&self.chain_info().chain_id, contract_address)
+            .await?;
         let result = self
             .sender()
-            .commit_tx(vec![exec_msg], None)
+            .commit_tx(vec![exec_msg], self.render_memo("execute").as_deref())
             .await
             .map_err(Into::into)?;
         log::info!(target: &transaction_target(), "Execution done: {:?}", result.txhash);
+        self.after_tx(&context, &result)?;
 
         Ok(result)
     }
@@ -266,14 +483,22 @@ impl<Sender: TxSender> DaemonAsyncBase<Sender> {
             msg: serde_json::to_vec(&init_msg)?,
             funds: parse_cw_coins(coins)?,
         };
+        let context = self
+            .tx_context(TxKind::Instantiate { code_id })
+            .msg(init_msg)
+            .funds(coins);
+        self.tx_hooks.pre_broadcast(&context)?;
 
         let result = self
             .sender()
-            .commit_tx(vec![init_msg], None)
+            .commit_tx(vec![init_msg], self.render_memo("instantiate").as_deref())
             .await
             .map_err(Into::into)?;
 
         log::info!(target: &transaction_target(), "Instantiation done: {:?}", result.txhash);
+        self.instantiate_hooks
+            .apply(&result, &mut self.state.clone());
+        self.after_tx(&context, &result)?;
 
         Ok(result)
     }
@@ -298,6 +523,11 @@ impl<Sender: TxSender> DaemonAsyncBase<Sender> {
             salt: salt.to_vec(),
             fix_msg: false,
         };
+        let context = self
+            .tx_context(TxKind::Instantiate2 { code_id })
+            .msg(init_msg)
+            .funds(coins);
+        self.tx_hooks.pre_broadcast(&context)?;
 
         let result = self
             .sender()
@@ -306,12 +536,15 @@ impl<Sender: TxSender> DaemonAsyncBase<Sender> {
                     type_url: INSTANTIATE_2_TYPE_URL.to_string(),
                     value: init_msg.encode_to_vec(),
                 }],
-                None,
+                self.render_memo("instantiate2").as_deref(),
             )
             .await
             .map_err(Into::into)?;
 
         log::info!(target: &transaction_target(), "Instantiation done: {:?}", result.txhash);
+        self.instantiate_hooks
+            .apply(&result, &mut self.state.clone());
+        self.after_tx(&context, &result)?;
 
         Ok(result)
     }
@@ -329,11 +562,17 @@ impl<Sender: TxSender> DaemonAsyncBase<Sender> {
             msg: serde_json::to_vec(&migrate_msg)?,
             code_id: new_code_id,
         };
+        let context = self
+            .tx_context(TxKind::Migrate { new_code_id })
+            .contract(contract_address)
+            .msg(migrate_msg);
+        self.tx_hooks.pre_broadcast(&context)?;
         let result = self
             .sender()
-            .commit_tx(vec![exec_msg], None)
+            .commit_tx(vec![exec_msg], self.render_memo("migrate").as_deref())
             .await
             .map_err(Into::into)?;
+        self.after_tx(&context, &result)?;
         Ok(result)
     }
 
@@ -352,43 +591,503 @@ impl<Sender: TxSender> DaemonAsyncBase<Sender> {
         access: Option<AccessConfig>,
     ) -> Result<CosmTxResponse, DaemonError> {
         let wasm_path = <T as Uploadable>::wasm(self.chain_info());
+        self.upload_wasm_path(wasm_path, access).await
+    }
 
+    async fn upload_wasm_path(
+        &self,
+        wasm_path: WasmPath,
+        access: Option<AccessConfig>,
+    ) -> Result<CosmTxResponse, DaemonError> {
         log::debug!(target: &transaction_target(), "Uploading file at {:?}", wasm_path);
 
-        let result = upload_wasm(self.sender(), wasm_path, access).await?;
+        let context = self.tx_context(TxKind::Upload);
+        self.tx_hooks.pre_broadcast(&context)?;
+        let store_msg = store_code_msg(
+            self.sender(),
+            wasm_path,
+            access,
+            self.upload_precheck.as_ref(),
+        )?;
+        let result = self
+            .sender()
+            .commit_tx(vec![store_msg], self.render_memo("upload").as_deref())
+            .await
+            .map_err(Into::into)?;
 
         log::info!(target: &transaction_target(), "Uploading done: {:?}", result.txhash);
 
-        let code_id = result.uploaded_code_id().unwrap();
+        // Dry runs only simulate the upload, no code is stored
+        if !CoreEnvVars::dry_run() {
+            self.wait_for_code(result.uploaded_code_id()?).await?;
+        }
+        self.after_tx(&context, &result)?;
+        Ok(result)
+    }
 
-        // wait for the node to return the contract information for this upload
+    /// Waits for the node to return the contract information of an upload, for at most [`PollingConfig::tx_query_retries`] blocks
+    async fn wait_for_code(&self, code_id: u64) -> Result<(), DaemonError> {
         let wasm = CosmWasm::new_async(self.channel());
+        let mut blocks = 0;
         while wasm._code(code_id).await.is_err() {
+            if blocks == self.polling.tx_query_retries {
+                return Err(DaemonError::CodeNotFound(code_id, blocks));
+            }
+            blocks += 1;
             self.next_block().await?;
         }
+        Ok(())
+    }
+
+    /// Send funds to an address.
+    pub async fn bank_send(
+        &self,
+        receiver: &Addr,
+        amount: &[Coin],
+    ) -> Result<CosmTxResponse, DaemonError> {
+        let context = self
+            .tx_context(TxKind::BankSend)
+            .contract(receiver)
+            .funds(amount);
+        self.tx_hooks.pre_broadcast(&context)?;
+        let msg = MsgSend {
+            from_address: self.sender().msg_sender().map_err(Into::into)?,
+            to_address: AccountId::from_str(receiver.as_str())?,
+            amount: parse_cw_coins(amount)?,
+        };
+        let memo = self.render_memo("bank_send");
+        let result = self
+            .sender()
+            .commit_tx(vec![msg], memo.as_deref().or(Some("sending tokens")))
+            .await
+            .map_err(Into::into)?;
+        self.after_tx(&context, &result)?;
+        Ok(result)
+    }
+
+    /// Sets `new_admin` as the admin of a contract.
+    pub async fn update_admin(
+        &self,
+        contract_address: &Addr,
+        new_admin: &Addr,
+    ) -> Result<CosmTxResponse, DaemonError> {
+        let msg = MsgUpdateAdmin {
+            sender: self.sender().msg_sender().map_err(Into::into)?,
+            new_admin: AccountId::from_str(new_admin.as_str())?,
+            contract: AccountId::from_str(contract_address.as_str())?,
+        };
+        let context = self
+            .tx_context(TxKind::UpdateAdmin)
+            .contract(contract_address);
+        self.tx_hooks.pre_broadcast(&context)?;
+        let result = self
+            .sender()
+            .commit_tx(vec![msg], self.render_memo("update_admin").as_deref())
+            .await
+            .map_err(Into::into)?;
+        log::info!(target: &transaction_target(), "Admin updated: {:?}", result.txhash);
+        self.after_tx(&context, &result)?;
+        Ok(result)
+    }
+
+    /// Removes the admin of a contract.
+    pub async fn clear_admin(
+        &self,
+        contract_address: &Addr,
+    ) -> Result<CosmTxResponse, DaemonError> {
+        let msg = MsgClearAdmin {
+            sender: self.sender().msg_sender().map_err(Into::into)?,
+            contract: AccountId::from_str(contract_address.as_str())?,
+        };
+        let context = self
+            .tx_context(TxKind::ClearAdmin)
+            .contract(contract_address);
+        self.tx_hooks.pre_broadcast(&context)?;
+        let result = self
+            .sender()
+            .commit_tx(vec![msg], self.render_memo("clear_admin").as_deref())
+            .await
+            .map_err(Into::into)?;
+        log::info!(target: &transaction_target(), "Admin cleared: {:?}", result.txhash);
+        self.after_tx(&context, &result)?;
+        Ok(result)
+    }
+
+    /// Submits a governance proposal pinning `code_ids` in the wasm cache of the nodes, which lowers the cost of executing them.
+    /// Returns the response of the proposal submission, the proposal id is in its `submit_proposal` event.
+    pub async fn propose_pin_codes(
+        &self,
+        code_ids: Vec<u64>,
+        title: &str,
+        deposit: &[Coin],
+    ) -> Result<CosmTxResponse, DaemonError> {
+        let msg = MsgPinCodes {
+            authority: self.gov_module_address()?,
+            code_ids,
+        };
+        self.submit_proposal(PIN_CODES_TYPE_URL, msg.encode_to_vec(), title, deposit)
+            .await
+    }
+
+    /// Submits a governance proposal removing `code_ids` from the wasm cache of the nodes.
+    /// Returns the response of the proposal submission, the proposal id is in its `submit_proposal` event.
+    pub async fn propose_unpin_codes(
+        &self,
+        code_ids: Vec<u64>,
+        title: &str,
+        deposit: &[Coin],
+    ) -> Result<CosmTxResponse, DaemonError> {
+        let msg = MsgUnpinCodes {
+            authority: self.gov_module_address()?,
+            code_ids,
+        };
+        self.submit_proposal(UNPIN_CODES_TYPE_URL, msg.encode_to_vec(), title, deposit)
+            .await
+    }
+
+    /// Changes who can instantiate the code `code_id`. Only the creator of the code can update its access config.
+    pub async fn update_instantiate_config(
+        &self,
+        code_id: u64,
+        access: AccessConfig,
+    ) -> Result<CosmTxResponse, DaemonError> {
+        let msg = MsgUpdateInstantiateConfig {
+            sender: self.sender().msg_sender().map_err(Into::into)?.to_string(),
+            code_id,
+            new_instantiate_permission: Some(access_config_to_cosmrs(access)?.into()),
+        };
+        let context = self.tx_context(TxKind::UpdateInstantiateConfig { code_id });
+        self.tx_hooks.pre_broadcast(&context)?;
+        let result = self
+            .sender()
+            .commit_tx_any(
+                vec![Any {
+                    type_url: UPDATE_INSTANTIATE_CONFIG_TYPE_URL.to_string(),
+                    value: msg.encode_to_vec(),
+                }],
+                self.render_memo("update_instantiate_config").as_deref(),
+            )
+            .await
+            .map_err(Into::into)?;
+        log::info!(target: &transaction_target(), "Instantiate config of code {} updated: {:?}", code_id, result.txhash);
+        self.after_tx(&context, &result)?;
+        Ok(result)
+    }
+
+    /// Calls the `sudo` entry point of a contract.
+    /// Only allowed when the sender is the authority of the wasm module, which is the case on some permissioned chains.
+    /// Use [`DaemonAsyncBase::propose_sudo`] on the chains where the authority is the gov module.
+    pub async fn sudo<S: Serialize + Debug>(
+        &self,
+        sudo_msg: &S,
+        contract_address: &Addr,
+    ) -> Result<CosmTxResponse, DaemonError> {
+        let msg = MsgSudoContract {
+            authority: self.sender().msg_sender().map_err(Into::into)?.to_string(),
+            contract: contract_address.to_string(),
+            msg: serde_json::to_vec(sudo_msg)?,
+        };
+        let context = self
+            .tx_context(TxKind::Sudo)
+            .contract(contract_address)
+            .msg(sudo_msg);
+        self.tx_hooks.pre_broadcast(&context)?;
+        let result = self
+            .sender()
+            .commit_tx_any(
+                vec![Any {
+                    type_url: SUDO_CONTRACT_TYPE_URL.to_string(),
+                    value: msg.encode_to_vec(),
+                }],
+                self.render_memo("sudo").as_deref(),
+            )
+            .await
+            .map_err(Into::into)?;
+        log::info!(target: &transaction_target(), "Sudo executed: {:?}", result.txhash);
+        self.after_tx(&context, &result)?;
+        Ok(result)
+    }
+
+    /// Submits a governance proposal calling the `sudo` entry point of a contract.
+    /// Returns the response of the proposal submission, the proposal id is in its `submit_proposal` event.
+    pub async fn propose_sudo<S: Serialize + Debug>(
+        &self,
+        sudo_msg: &S,
+        contract_address: &Addr,
+        title: &str,
+        deposit: &[Coin],
+    ) -> Result<CosmTxResponse, DaemonError> {
+        let msg = MsgSudoContract {
+            authority: self.gov_module_address()?,
+            contract: contract_address.to_string(),
+            msg: serde_json::to_vec(sudo_msg)?,
+        };
+        self.submit_proposal(SUDO_CONTRACT_TYPE_URL, msg.encode_to_vec(), title, deposit)
+            .await
+    }
+
+    /// Submits a gov v1 proposal executing a single message as the gov module
+    async fn submit_proposal(
+        &self,
+        type_url: &str,
+        value: Vec<u8>,
+        title: &str,
+        deposit: &[Coin],
+    ) -> Result<CosmTxResponse, DaemonError> {
+        let proposal = MsgSubmitProposal {
+            messages: vec![Any {
+                type_url: type_url.to_string(),
+                value,
+            }],
+            initial_deposit: proto_parse_cw_coins(deposit)?,
+            proposer: self.sender().msg_sender().map_err(Into::into)?.to_string(),
+            metadata: String::new(),
+            title: title.to_string(),
+            summary: title.to_string(),
+            expedited: false,
+        };
+        let context = self.tx_context(TxKind::SubmitProposal {
+            type_url: type_url.to_string(),
+        });
+        self.tx_hooks.pre_broadcast(&context)?;
+        let result = self
+            .sender()
+            .commit_tx_any(
+                vec![Any {
+                    type_url: SUBMIT_PROPOSAL_TYPE_URL.to_string(),
+                    value: proposal.encode_to_vec(),
+                }],
+                self.render_memo("proposal").as_deref(),
+            )
+            .await
+            .map_err(Into::into)?;
+        log::info!(target: &transaction_target(), "Proposal submitted: {:?}", result.txhash);
+        self.after_tx(&context, &result)?;
+        Ok(result)
+    }
+
+    /// Address of the gov module, the authority of the messages executed by proposals
+    fn gov_module_address(&self) -> Result<String, DaemonError> {
+        let hash = Sha256::digest(b"gov");
+        let address = AccountId::new(
+            &self.chain_info().network_info.pub_address_prefix,
+            &hash[..20],
+        )?;
+        Ok(address.to_string())
+    }
+}
+
+// Vesting accounts, the schedule of the created accounts can be queried with [`crate::queriers::Vesting`].
+impl<Sender: TxSender> DaemonAsyncBase<Sender> {
+    /// Creates a vesting account for `recipient`, unlocking `amount` linearly from the block time until `end_time` (unix seconds)
+    pub async fn create_continuous_vesting_account(
+        &self,
+        recipient: &Addr,
+        amount: &[Coin],
+        end_time: i64,
+    ) -> Result<CosmTxResponse, DaemonError> {
+        self.create_vesting_account(recipient, amount, end_time, false)
+            .await
+    }
+
+    /// Creates a vesting account for `recipient`, unlocking `amount` all at once at `end_time` (unix seconds)
+    pub async fn create_delayed_vesting_account(
+        &self,
+        recipient: &Addr,
+        amount: &[Coin],
+        end_time: i64,
+    ) -> Result<CosmTxResponse, DaemonError> {
+        self.create_vesting_account(recipient, amount, end_time, true)
+            .await
+    }
+
+    /// Creates a vesting account for `recipient`, unlocking the amount of each period at its end.
+    /// The first period starts at `start_time` (unix seconds), the vested amount is the sum of the periods amounts.
+    pub async fn create_periodic_vesting_account(
+        &self,
+        recipient: &Addr,
+        start_time: i64,
+        periods: &[VestingPeriod],
+    ) -> Result<CosmTxResponse, DaemonError> {
+        let msg = periodic_vesting_account_msg(
+            &self.sender().msg_sender().map_err(Into::into)?,
+            recipient,
+            start_time,
+            periods,
+        )?;
+        let mut funds: Vec<Coin> = vec![];
+        for coin in periods.iter().flat_map(|period| &period.amount) {
+            match funds.iter_mut().find(|c| c.denom == coin.denom) {
+                Some(c) => c.amount += coin.amount,
+                None => funds.push(coin.clone()),
+            }
+        }
+        self.commit_vesting(
+            CREATE_PERIODIC_VESTING_ACCOUNT_TYPE_URL,
+            msg.encode_to_vec(),
+            recipient,
+            &funds,
+        )
+        .await
+    }
+
+    async fn create_vesting_account(
+        &self,
+        recipient: &Addr,
+        amount: &[Coin],
+        end_time: i64,
+        delayed: bool,
+    ) -> Result<CosmTxResponse, DaemonError> {
+        let msg = vesting_account_msg(
+            &self.sender().msg_sender().map_err(Into::into)?,
+            recipient,
+            amount,
+            end_time,
+            delayed,
+        )?;
+        self.commit_vesting(
+            CREATE_VESTING_ACCOUNT_TYPE_URL,
+            msg.encode_to_vec(),
+            recipient,
+            amount,
+        )
+        .await
+    }
+
+    async fn commit_vesting(
+        &self,
+        type_url: &str,
+        value: Vec<u8>,
+        recipient: &Addr,
+        funds: &[Coin],
+    ) -> Result<CosmTxResponse, DaemonError> {
+        let context = self
+            .tx_context(TxKind::CreateVestingAccount {
+                type_url: type_url.to_string(),
+            })
+            .contract(recipient)
+            .funds(funds);
+        self.tx_hooks.pre_broadcast(&context)?;
+        let result = self
+            .sender()
+            .commit_tx_any(
+                vec![Any {
+                    type_url: type_url.to_string(),
+                    value,
+                }],
+                self.render_memo("create_vesting_account").as_deref(),
+            )
+            .await
+            .map_err(Into::into)?;
+        log::info!(target: &transaction_target(), "Vesting account created: {:?}", result.txhash);
+        self.after_tx(&context, &result)?;
         Ok(result)
     }
 }
 
+/// Message creating a continuous or delayed vesting account
+fn vesting_account_msg(
+    sender: &AccountId,
+    recipient: &Addr,
+    amount: &[Coin],
+    end_time: i64,
+    delayed: bool,
+) -> Result<MsgCreateVestingAccount, DaemonError> {
+    Ok(MsgCreateVestingAccount {
+        from_address: sender.to_string(),
+        to_address: recipient.to_string(),
+        amount: proto_parse_cw_coins(amount)?,
+        end_time,
+        delayed,
+    })
+}
+
+/// Message creating a periodic vesting account
+fn periodic_vesting_account_msg(
+    sender: &AccountId,
+    recipient: &Addr,
+    start_time: i64,
+    periods: &[VestingPeriod],
+) -> Result<MsgCreatePeriodicVestingAccount, DaemonError> {
+    Ok(MsgCreatePeriodicVestingAccount {
+        from_address: sender.to_string(),
+        to_address: recipient.to_string(),
+        start_time,
+        vesting_periods: periods
+            .iter()
+            .map(|period| {
+                Ok(Period {
+                    length: period.length,
+                    amount: proto_parse_cw_coins(&period.amount)?,
+                })
+            })
+            .collect::<Result<_, DaemonError>>()?,
+    })
+}
+
+// Concurrent uploads, the sequence of each tx is allocated by the sequence manager of the signer.
+impl<Sender: Signer> DaemonAsyncBase<Sender> {
+    /// Uploads multiple wasm files at once.
+    /// Each upload goes through the same path as [`DaemonAsyncBase::upload`], in its own transaction,
+    /// so that they can all be included in the same block.
+    /// Returns the responses in the order of `wasm_paths`, once all the uploads are confirmed.
+    pub async fn upload_concurrently(
+        &self,
+        wasm_paths: Vec<WasmPath>,
+    ) -> Result<Vec<CosmTxResponse>, DaemonError> {
+        try_join_all(
+            wasm_paths
+                .into_iter()
+                .map(|wasm_path| self.upload_wasm_path(wasm_path, None)),
+        )
+        .await
+    }
+}
+
+/// Contract that can be uploaded along with others with [`DaemonBase::upload_concurrently`](crate::DaemonBase::upload_concurrently).
+/// Implemented for all the uploadable contract interfaces.
+pub trait ConcurrentUploadable<Chain: ChainState>: ContractInstance<Chain> {
+    /// Path to the wasm file of the contract
+    fn wasm_path(&self, chain_info: &ChainInfoOwned) -> WasmPath;
+}
+
+impl<Chain: ChainState, T: ContractInstance<Chain> + Uploadable> ConcurrentUploadable<Chain> for T {
+    fn wasm_path(&self, chain_info: &ChainInfoOwned) -> WasmPath {
+        <T as Uploadable>::wasm(chain_info)
+    }
+}
+
 pub async fn upload_wasm<T: TxSender>(
     sender: &T,
     wasm_path: WasmPath,
     access: Option<AccessConfig>,
 ) -> Result<CosmTxResponse, DaemonError> {
+    let store_msg = store_code_msg(sender, wasm_path, access, None)?;
+
+    sender
+        .commit_tx(vec![store_msg], None)
+        .await
+        .map_err(Into::into)
+}
+
+fn store_code_msg<T: TxSender>(
+    sender: &T,
+    wasm_path: WasmPath,
+    access: Option<AccessConfig>,
+    precheck: Option<&UploadPrecheck>,
+) -> Result<cosmrs::cosmwasm::MsgStoreCode, DaemonError> {
     let file_contents = std::fs::read(wasm_path.path())?;
+    if let Some(precheck) = precheck {
+        precheck.check(&file_contents).map_err(CwEnvError::from)?;
+    }
     let mut e = write::GzEncoder::new(Vec::new(), Compression::default());
     e.write_all(&file_contents)?;
     let wasm_byte_code = e.finish()?;
-    let store_msg = cosmrs::cosmwasm::MsgStoreCode {
+    Ok(cosmrs::cosmwasm::MsgStoreCode {
         sender: sender.msg_sender().map_err(Into::into)?,
         wasm_byte_code,
         instantiate_permission: access.map(access_config_to_cosmrs).transpose()?,
-    };
-
-    sender
-        .commit_tx(vec![store_msg], None)
-        .await
-        .map_err(Into::into)
+    })
 }
 
 pub(crate) fn access_config_to_cosmrs(
@@ -428,7 +1127,7 @@ impl AsyncWasmQuerier for DaemonAsync {
         &self,
         address: &Addr,
         query_msg: &Q,
-    ) -> impl std::future::Future<Output = Result<T, DaemonError>> + Send {
+    ) -> impl std::future::Future<Output = Result<T, DaemonError>> + MaybeSend {
         let query_data = serde_json::to_vec(query_msg).unwrap();
         async {
             let mut client =
@@ -444,6 +1143,64 @@ impl AsyncWasmQuerier for DaemonAsync {
     }
 }
 
+impl AsyncTxHandler for DaemonAsync {
+    type Response = CosmTxResponse;
+    type Error = DaemonError;
+
+    fn sender_addr(&self) -> Addr {
+        self.sender().address()
+    }
+
+    fn upload_with_access_config<T: Uploadable + Sync>(
+        &self,
+        contract_source: &T,
+        access_config: Option<AccessConfig>,
+    ) -> impl std::future::Future<Output = Result<CosmTxResponse, DaemonError>> + Send {
+        DaemonAsyncBase::upload_with_access_config(self, contract_source, access_config)
+    }
+
+    fn instantiate<I: Serialize + Debug + Sync>(
+        &self,
+        code_id: u64,
+        init_msg: &I,
+        label: Option<&str>,
+        admin: Option<&Addr>,
+        coins: &[Coin],
+    ) -> impl std::future::Future<Output = Result<CosmTxResponse, DaemonError>> + Send {
+        DaemonAsyncBase::instantiate(self, code_id, init_msg, label, admin, coins)
+    }
+
+    fn instantiate2<I: Serialize + Debug + Sync>(
+        &self,
+        code_id: u64,
+        init_msg: &I,
+        label: Option<&str>,
+        admin: Option<&Addr>,
+        coins: &[Coin],
+        salt: Binary,
+    ) -> impl std::future::Future<Output = Result<CosmTxResponse, DaemonError>> + Send {
+        DaemonAsyncBase::instantiate2(self, code_id, init_msg, label, admin, coins, salt)
+    }
+
+    fn execute<E: Serialize + Debug + Sync>(
+        &self,
+        exec_msg: &E,
+        coins: &[Coin],
+        contract_address: &Addr,
+    ) -> impl std::future::Future<Output = Result<CosmTxResponse, DaemonError>> + Send {
+        DaemonAsyncBase::execute(self, exec_msg, coins, contract_address)
+    }
+
+    fn migrate<M: Serialize + Debug + Sync>(
+        &self,
+        migrate_msg: &M,
+        new_code_id: u64,
+        contract_address: &Addr,
+    ) -> impl std::future::Future<Output = Result<CosmTxResponse, DaemonError>> + Send {
+        DaemonAsyncBase::migrate(self, migrate_msg, new_code_id, contract_address)
+    }
+}
+
 pub fn parse_cw_coins(coins: &[cosmwasm_std::Coin]) -> Result<Vec<cosmrs::Coin>, DaemonError> {
     coins
         .iter()
@@ -469,3 +1226,83 @@ pub(crate) fn proto_parse_cw_coins(
         })
         .collect::<Result<Vec<_>, DaemonError>>()
 }
+
+#[cfg(test)]
+mod test {
+    use cosmwasm_std::coins;
+
+    use super::*;
+
+    fn proto_coin(amount: &str, denom: &str) -> cosmrs::proto::cosmos::base::v1beta1::Coin {
+        cosmrs::proto::cosmos::base::v1beta1::Coin {
+            amount: amount.to_string(),
+            denom: denom.to_string(),
+        }
+    }
+
+    fn sender() -> AccountId {
+        AccountId::new("juno", &[1; 20]).unwrap()
+    }
+
+    fn recipient() -> Addr {
+        Addr::unchecked(AccountId::new("juno", &[2; 20]).unwrap().to_string())
+    }
+
+    #[test]
+    fn continuous_vesting_account_msg() {
+        let msg = vesting_account_msg(&sender(), &recipient(), &coins(100, "ujuno"), 1_000, false)
+            .unwrap();
+        assert_eq!(
+            msg,
+            MsgCreateVestingAccount {
+                from_address: sender().to_string(),
+                to_address: recipient().to_string(),
+                amount: vec![proto_coin("100", "ujuno")],
+                end_time: 1_000,
+                delayed: false,
+            }
+        );
+    }
+
+    #[test]
+    fn delayed_vesting_account_msg() {
+        let msg = vesting_account_msg(&sender(), &recipient(), &coins(100, "ujuno"), 1_000, true)
+            .unwrap();
+        assert!(msg.delayed);
+        assert_eq!(msg.end_time, 1_000);
+        assert_eq!(msg.amount, vec![proto_coin("100", "ujuno")]);
+    }
+
+    #[test]
+    fn periodic_vesting_account_msg_keeps_the_periods() {
+        let periods = [
+            VestingPeriod {
+                length: 10,
+                amount: coins(30, "ujuno"),
+            },
+            VestingPeriod {
+                length: 20,
+                amount: coins(70, "ujuno"),
+            },
+        ];
+        let msg = periodic_vesting_account_msg(&sender(), &recipient(), 500, &periods).unwrap();
+        assert_eq!(
+            msg,
+            MsgCreatePeriodicVestingAccount {
+                from_address: sender().to_string(),
+                to_address: recipient().to_string(),
+                start_time: 500,
+                vesting_periods: vec![
+                    Period {
+                        length: 10,
+                        amount: vec![proto_coin("30", "ujuno")],
+                    },
+                    Period {
+                        length: 20,
+                        amount: vec![proto_coin("70", "ujuno")],
+                    },
+                ],
+            }
+        );
+    }
+}
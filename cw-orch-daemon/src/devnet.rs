@@ -0,0 +1,509 @@
+//! Snapshots of local devnets running in docker (wasmd, localjuno, ...).
+//!
+//! Nodes don't expose a way to roll back their state, so [`DockerDevnet`] snapshots the whole container with `docker commit`
+//! and restores it by re-creating the container from the snapshot image, with the same name, ports, environment and command.
+//! Integration suites can then start every test from the same chain state without manual docker choreography.
+//! The command of the container must start the node on its existing data, as it runs again on restore.
+//!
+//! ```rust,no_run
+//! use cw_orch_daemon::{devnet::DockerDevnet, networks, Daemon};
+//!
+//! let devnet = DockerDevnet::new("juno_node_1");
+//! let daemon = Daemon::builder(networks::LOCAL_JUNO).build().unwrap();
+//! // ... upload and instantiate the contracts
+//! devnet.snapshot("deployed").unwrap();
+//!
+//! // ... run a test
+//! devnet.restore("deployed").unwrap();
+//! daemon.next_block().unwrap();
+//! ```
+//!
+//! [`LocalDaemon`] starts such a devnet from scratch, with accounts funded at genesis, and returns a [`Daemon`] once the chain produces blocks.
+//!
+//! ```rust,no_run
+//! use cw_orch_daemon::devnet::LocalDaemon;
+//!
+//! // Accounts are derived from the `LOCAL_MNEMONIC` environment variable
+//! let local = LocalDaemon::juno().accounts(3);
+//! let daemon = local.start().unwrap();
+//! // ... run the tests
+//! local.stop().unwrap();
+//! ```
+use std::{
+    process::Command,
+    time::{Duration, Instant},
+};
+
+use bitcoin::secp256k1::Secp256k1;
+use cosmwasm_std::{Addr, Coin};
+use cw_orch_core::environment::{
+    ChainInfoOwned, ChainKind, NetworkInfoOwned, QueryHandler, TxHandler,
+};
+use cw_orch_networks::networks;
+use serde::Deserialize;
+
+use crate::{env::DaemonEnvVars, keys::private::PrivateKey, Daemon, DaemonError};
+
+/// Repository of the snapshot images
+pub const SNAPSHOT_REPOSITORY: &str = "cw-orch-snapshot";
+/// Label holding the image the container was originally created from
+const BASE_IMAGE_LABEL: &str = "cw-orch.base-image";
+
+/// Local devnet running in a docker container
+#[derive(Clone, Debug)]
+pub struct DockerDevnet {
+    container: String,
+}
+
+impl DockerDevnet {
+    /// Devnet running in the container `container`
+    pub fn new(container: impl Into<String>) -> Self {
+        Self {
+            container: container.into(),
+        }
+    }
+
+    /// Saves the current state of the chain as `name`, overwriting any snapshot with the same name.
+    /// The container is paused while the snapshot is taken.
+    pub fn snapshot(&self, name: &str) -> Result<(), DaemonError> {
+        docker([
+            "commit",
+            "--pause",
+            &self.container,
+            &self.snapshot_image(name),
+        ])?;
+        Ok(())
+    }
+
+    /// Restores the state of the chain saved as `name`
+    pub fn restore(&self, name: &str) -> Result<(), DaemonError> {
+        self.recreate(Some(self.snapshot_image(name)))
+    }
+
+    /// Resets the chain to its genesis, by re-creating the container from its original image
+    pub fn reset(&self) -> Result<(), DaemonError> {
+        self.recreate(None)
+    }
+
+    /// Names of the snapshots of this devnet
+    pub fn snapshots(&self) -> Result<Vec<String>, DaemonError> {
+        let prefix = format!("{}-", self.container);
+        let tags = docker(["image", "ls", SNAPSHOT_REPOSITORY, "--format", "{{.Tag}}"])?;
+        Ok(tags
+            .lines()
+            .filter_map(|tag| tag.strip_prefix(&prefix))
+            .map(ToString::to_string)
+            .collect())
+    }
+
+    /// Deletes the snapshot `name`
+    pub fn remove_snapshot(&self, name: &str) -> Result<(), DaemonError> {
+        docker(["image", "rm", &self.snapshot_image(name)])?;
+        Ok(())
+    }
+
+    fn snapshot_image(&self, name: &str) -> String {
+        format!("{SNAPSHOT_REPOSITORY}:{}-{name}", self.container)
+    }
+
+    /// Replaces the container with a new one created from `image`, or from the original image
+    fn recreate(&self, image: Option<String>) -> Result<(), DaemonError> {
+        let inspect = docker(["container", "inspect", &self.container])?;
+        let mut containers: Vec<ContainerInspect> = serde_json::from_str(&inspect)?;
+        let container = containers.pop().ok_or_else(|| {
+            DaemonError::StdErr(format!("Container {} not found", self.container))
+        })?;
+        let args = run_args(&self.container, &container, image);
+
+        docker(["rm", "--force", &self.container])?;
+        docker(args)?;
+        Ok(())
+    }
+}
+
+/// How the accounts of a [`LocalDaemon`] are funded
+#[derive(Clone, Debug)]
+pub enum Funding {
+    /// The setup command of the image funds the addresses passed as its last arguments in the genesis
+    Genesis,
+    /// An account funded by the image sends `amount` to each address once the chain is started
+    Faucet {
+        /// Mnemonic of the funded account
+        mnemonic: String,
+        /// Funds sent to each address
+        amount: Vec<Coin>,
+    },
+}
+
+/// Single-node local chain started in docker, with accounts derived from a mnemonic and funded at startup.
+///
+/// The presets run the images of the chains with their default setup scripts, other chains are configured with [`LocalDaemon::new`].
+#[derive(Clone, Debug)]
+pub struct LocalDaemon {
+    chain: ChainInfoOwned,
+    image: String,
+    container: String,
+    command: Vec<String>,
+    env: Vec<(String, String)>,
+    ports: Vec<u16>,
+    funding: Funding,
+    mnemonic: Option<String>,
+    accounts: u32,
+    startup_timeout: Duration,
+}
+
+impl LocalDaemon {
+    /// Chain `chain` started from `image`, running `command` followed by the addresses to fund.
+    /// The gRPC port of the chain (9090) is published on the host.
+    pub fn new(
+        chain: impl Into<ChainInfoOwned>,
+        image: impl Into<String>,
+        command: Vec<String>,
+    ) -> Self {
+        let chain = chain.into();
+        Self {
+            container: format!("cw-orch-{}", chain.chain_id),
+            chain,
+            image: image.into(),
+            command,
+            env: vec![],
+            ports: vec![9090],
+            funding: Funding::Genesis,
+            mnemonic: None,
+            accounts: 1,
+            startup_timeout: Duration::from_secs(60),
+        }
+    }
+
+    /// Juno node, see [`networks::LOCAL_JUNO`]
+    pub fn juno() -> Self {
+        Self::new(
+            networks::LOCAL_JUNO,
+            "ghcr.io/cosmoscontracts/juno:v21.0.0",
+            vec!["./setup_and_run.sh".to_string()],
+        )
+        .env("STAKE_TOKEN", "ujunox")
+        .env("UNSAFE_CORS", "true")
+    }
+
+    /// Wasmd node, with the `wasm` address prefix and the `ustake` denom
+    pub fn wasmd() -> Self {
+        let chain = ChainInfoOwned {
+            kind: ChainKind::Local,
+            chain_id: "testing".to_string(),
+            gas_denom: "ustake".to_string(),
+            gas_price: 0.025,
+            grpc_urls: vec!["http://localhost:9090".to_string()],
+            network_info: NetworkInfoOwned {
+                chain_name: "wasmd".to_string(),
+                pub_address_prefix: "wasm".to_string(),
+                coin_type: 118,
+            },
+            lcd_url: None,
+            fcd_url: None,
+        };
+        Self::new(
+            chain,
+            "cosmwasm/wasmd:v0.53.0",
+            vec!["/opt/setup_and_run.sh".to_string()],
+        )
+    }
+
+    /// Osmosis node funding the accounts from `faucet_mnemonic`, an account of the genesis of `image`.
+    /// `image` must start a single node on its own, like the LocalOsmosis images.
+    pub fn osmosis(image: impl Into<String>, faucet_mnemonic: impl Into<String>) -> Self {
+        Self::new(networks::LOCAL_OSMO, image, vec![]).funding(Funding::Faucet {
+            mnemonic: faucet_mnemonic.into(),
+            amount: vec![Coin::new(1_000_000_000_000u128, "uosmo")],
+        })
+    }
+
+    /// Sets the name of the container, `cw-orch-<chain-id>` by default
+    pub fn container(mut self, container: impl Into<String>) -> Self {
+        self.container = container.into();
+        self
+    }
+
+    /// Sets an environment variable of the container
+    pub fn env(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.env.push((key.into(), value.into()));
+        self
+    }
+
+    /// Publishes `port` of the container on the same port of the host
+    pub fn port(mut self, port: u16) -> Self {
+        self.ports.push(port);
+        self
+    }
+
+    /// Sets how the accounts are funded, [`Funding::Genesis`] by default
+    pub fn funding(mut self, funding: Funding) -> Self {
+        self.funding = funding;
+        self
+    }
+
+    /// Sets the mnemonic of the accounts, read from the `LOCAL_MNEMONIC` environment variable by default
+    pub fn mnemonic(mut self, mnemonic: impl Into<String>) -> Self {
+        self.mnemonic = Some(mnemonic.into());
+        self
+    }
+
+    /// Sets the number of accounts funded, derived on the hd indexes `0..accounts` of the mnemonic. 1 by default.
+    pub fn accounts(mut self, accounts: u32) -> Self {
+        self.accounts = accounts.max(1);
+        self
+    }
+
+    /// Sets how long to wait for the first block, 60 seconds by default
+    pub fn startup_timeout(mut self, timeout: Duration) -> Self {
+        self.startup_timeout = timeout;
+        self
+    }
+
+    /// Addresses of the funded accounts
+    pub fn addresses(&self) -> Result<Vec<Addr>, DaemonError> {
+        let mnemonic = self.get_mnemonic()?;
+        let secp = Secp256k1::new();
+        (0..self.accounts)
+            .map(|index| {
+                let key = PrivateKey::from_words(
+                    &secp,
+                    &mnemonic,
+                    0,
+                    index,
+                    self.chain.network_info.coin_type,
+                )?;
+                let address = key
+                    .public_key(&secp)
+                    .account(&self.chain.network_info.pub_address_prefix)?;
+                Ok(Addr::unchecked(address))
+            })
+            .collect()
+    }
+
+    /// Starts the chain, replacing any container with the same name, and waits for its first block.
+    /// Returns a [`Daemon`] whose sender is the account of hd index 0.
+    pub fn start(&self) -> Result<Daemon, DaemonError> {
+        let addresses = self.addresses()?;
+        // The previous container may not exist
+        let _ = docker(["rm", "--force", &self.container]);
+        docker(self.run_args(&addresses))?;
+
+        let daemon = self.wait_for_first_block(self.get_mnemonic()?)?;
+        if let Funding::Faucet { mnemonic, amount } = &self.funding {
+            let faucet = self.wait_for_first_block(mnemonic.clone())?;
+            for address in &addresses {
+                faucet.bank_send(address, amount)?;
+            }
+        }
+        Ok(daemon)
+    }
+
+    /// Stops and removes the container of the chain
+    pub fn stop(&self) -> Result<(), DaemonError> {
+        docker(["rm", "--force", &self.container])?;
+        Ok(())
+    }
+
+    /// Snapshots of the chain, see [`DockerDevnet`]
+    pub fn devnet(&self) -> DockerDevnet {
+        DockerDevnet::new(&self.container)
+    }
+
+    fn get_mnemonic(&self) -> Result<String, DaemonError> {
+        self.mnemonic
+            .clone()
+            .or_else(DaemonEnvVars::local_mnemonic)
+            .ok_or(DaemonError::MissingPhrase)
+    }
+
+    /// Arguments of the `docker run` command starting the chain
+    fn run_args(&self, addresses: &[Addr]) -> Vec<String> {
+        let mut args: Vec<String> = vec![
+            "run".into(),
+            "--detach".into(),
+            "--name".into(),
+            self.container.clone(),
+        ];
+        for port in &self.ports {
+            args.extend(["--publish".into(), format!("{port}:{port}")]);
+        }
+        for (key, value) in &self.env {
+            args.extend(["--env".into(), format!("{key}={value}")]);
+        }
+        args.push(self.image.clone());
+        args.extend(self.command.iter().cloned());
+        if let Funding::Genesis = self.funding {
+            args.extend(addresses.iter().map(ToString::to_string));
+        }
+        args
+    }
+
+    /// Connects to the chain with `mnemonic` once it produced its first block
+    fn wait_for_first_block(&self, mnemonic: String) -> Result<Daemon, DaemonError> {
+        let deadline = Instant::now() + self.startup_timeout;
+        loop {
+            let daemon = Daemon::builder(self.chain.clone())
+                .mnemonic(mnemonic.clone())
+                .build();
+            match daemon {
+                Ok(daemon) if daemon.block_info().is_ok_and(|block| block.height >= 1) => {
+                    return Ok(daemon)
+                }
+                _ if Instant::now() > deadline => {
+                    return Err(DaemonError::StdErr(format!(
+                        "Chain {} didn't produce a block after {:?}",
+                        self.chain.chain_id, self.startup_timeout
+                    )))
+                }
+                _ => std::thread::sleep(Duration::from_secs(1)),
+            }
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct ContainerInspect {
+    config: ContainerConfig,
+    host_config: HostConfig,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct ContainerConfig {
+    image: String,
+    #[serde(default)]
+    env: Option<Vec<String>>,
+    #[serde(default)]
+    cmd: Option<Vec<String>>,
+    #[serde(default)]
+    entrypoint: Option<Vec<String>>,
+    #[serde(default)]
+    labels: Option<std::collections::BTreeMap<String, String>>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct HostConfig {
+    #[serde(default)]
+    port_bindings: Option<std::collections::BTreeMap<String, Option<Vec<PortBinding>>>>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct PortBinding {
+    #[serde(default)]
+    host_ip: String,
+    host_port: String,
+}
+
+/// Arguments of the `docker run` command re-creating `container` from `image`
+fn run_args(name: &str, container: &ContainerInspect, image: Option<String>) -> Vec<String> {
+    let config = &container.config;
+    let base_image = config
+        .labels
+        .as_ref()
+        .and_then(|labels| labels.get(BASE_IMAGE_LABEL))
+        .cloned()
+        .unwrap_or_else(|| config.image.clone());
+
+    let mut args: Vec<String> = vec![
+        "run".into(),
+        "--detach".into(),
+        "--name".into(),
+        name.into(),
+    ];
+    args.extend(["--label".into(), format!("{BASE_IMAGE_LABEL}={base_image}")]);
+    for (container_port, bindings) in container.host_config.port_bindings.iter().flatten() {
+        for binding in bindings.iter().flatten() {
+            let host = if binding.host_ip.is_empty() {
+                binding.host_port.clone()
+            } else {
+                format!("{}:{}", binding.host_ip, binding.host_port)
+            };
+            args.extend(["--publish".into(), format!("{host}:{container_port}")]);
+        }
+    }
+    for env in config.env.iter().flatten() {
+        args.extend(["--env".into(), env.clone()]);
+    }
+    let entrypoint = config.entrypoint.clone().unwrap_or_default();
+    if let Some(executable) = entrypoint.first() {
+        args.extend(["--entrypoint".into(), executable.clone()]);
+    }
+    args.push(image.unwrap_or(base_image));
+    args.extend(entrypoint.into_iter().skip(1));
+    args.extend(config.cmd.clone().unwrap_or_default());
+    args
+}
+
+/// Runs a docker command and returns its output
+fn docker<I, S>(args: I) -> Result<String, DaemonError>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<std::ffi::OsStr>,
+{
+    let output = Command::new("docker").args(args).output()?;
+    if !output.status.success() {
+        return Err(DaemonError::StdErr(format!(
+            "docker command failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn recreates_container_config() {
+        let inspect = r#"[{
+            "Config": {
+                "Image": "ghcr.io/cosmoscontracts/juno:v12.0.0",
+                "Env": ["STAKE_TOKEN=ujunox"],
+                "Cmd": ["./setup_and_run.sh", "juno16g2rahf5846rxzp3fwlswy08fz8ccuwk03k57y"],
+                "Entrypoint": null,
+                "Labels": {}
+            },
+            "HostConfig": {
+                "PortBindings": {
+                    "9090/tcp": [{"HostIp": "", "HostPort": "9090"}]
+                }
+            }
+        }]"#;
+        let mut containers: Vec<ContainerInspect> = serde_json::from_str(inspect).unwrap();
+        let container = containers.pop().unwrap();
+        let devnet = DockerDevnet::new("juno_node_1");
+
+        let args = run_args(
+            "juno_node_1",
+            &container,
+            Some(devnet.snapshot_image("deployed")),
+        );
+        assert_eq!(
+            args.join(" "),
+            "run --detach --name juno_node_1 \
+            --label cw-orch.base-image=ghcr.io/cosmoscontracts/juno:v12.0.0 \
+            --publish 9090:9090/tcp --env STAKE_TOKEN=ujunox \
+            cw-orch-snapshot:juno_node_1-deployed \
+            ./setup_and_run.sh juno16g2rahf5846rxzp3fwlswy08fz8ccuwk03k57y"
+        );
+    }
+
+    #[test]
+    fn local_daemon_run_args() {
+        let local = LocalDaemon::juno().container("juno_node_1");
+        let address = Addr::unchecked("juno16g2rahf5846rxzp3fwlswy08fz8ccuwk03k57y");
+
+        assert_eq!(
+            local.run_args(&[address]).join(" "),
+            "run --detach --name juno_node_1 --publish 9090:9090 \
+            --env STAKE_TOKEN=ujunox --env UNSAFE_CORS=true \
+            ghcr.io/cosmoscontracts/juno:v21.0.0 \
+            ./setup_and_run.sh juno16g2rahf5846rxzp3fwlswy08fz8ccuwk03k57y"
+        );
+    }
+}
@@ -11,6 +11,9 @@
 use std::{env, path::PathBuf, str::FromStr};
 
 use cosmwasm_std::StdError;
+use cw_orch_core::environment::ChainKind;
+
+use crate::DaemonError;
 use std::time::Duration;
 
 const DEFAULT_TX_QUERY_RETRIES: usize = 50;
@@ -26,6 +29,9 @@ pub const MIN_GAS_ENV_NAME: &str = "CW_ORCH_MIN_GAS";
 pub const MAX_TX_QUERIES_RETRY_ENV_NAME: &str = "CW_ORCH_MAX_TX_QUERY_RETRIES";
 pub const WALLET_BALANCE_ASSERTION_ENV_NAME: &str = "CW_ORCH_WALLET_BALANCE_ASSERTION";
 pub const LOGS_ACTIVATION_MESSAGE_ENV_NAME: &str = "CW_ORCH_LOGS_ACTIVATION_MESSAGE";
+pub const CONFIRM_TXS_ON_ENV_NAME: &str = "CW_ORCH_CONFIRM_TXS_ON";
+pub const TX_ALLOWLIST_ENV_NAME: &str = "CW_ORCH_TX_ALLOWLIST";
+pub const GRPC_RATE_LIMIT_ENV_NAME: &str = "CW_ORCH_GRPC_RATE_LIMIT";
 
 pub const MAIN_MNEMONIC_ENV_NAME: &str = "MAIN_MNEMONIC";
 pub const TEST_MNEMONIC_ENV_NAME: &str = "TEST_MNEMONIC";
@@ -126,6 +132,33 @@ impl DaemonEnvVars {
         }
     }
 
+    /// Optional - Comma separated chain kinds (`mainnet,testnet`)
+    /// Defaults to none
+    /// Kinds of chains on which the transactions of the default wallet need a confirmation before being broadcast
+    /// Unknown kinds are an error, so that a typo doesn't silently disable the confirmations
+    pub fn confirm_txs_on() -> Result<Vec<ChainKind>, DaemonError> {
+        env::var(CONFIRM_TXS_ON_ENV_NAME)
+            .map(|kinds| parse_chain_kinds(&kinds))
+            .unwrap_or(Ok(vec![]))
+    }
+
+    /// Optional - Path
+    /// Allowlist of the transactions broadcast without confirmation, see [`TxGuard`](crate::senders::TxGuard)
+    pub fn tx_allowlist() -> Option<PathBuf> {
+        env::var(TX_ALLOWLIST_ENV_NAME).ok().map(PathBuf::from)
+    }
+
+    /// Optional - Integer
+    /// Defaults to no limit
+    /// Maximum number of requests per second sent to every gRPC endpoint, see [`RateLimits`](crate::RateLimits)
+    pub fn grpc_rate_limit() -> Option<u64> {
+        if let Ok(str_value) = env::var(GRPC_RATE_LIMIT_ENV_NAME) {
+            Some(parse_with_log(str_value, GRPC_RATE_LIMIT_ENV_NAME))
+        } else {
+            None
+        }
+    }
+
     /// Optional - String
     /// Mandatory when interacting with a daemon on mainnet
     /// Mnemonic of the address interacting with a mainnet
@@ -171,6 +204,19 @@ fn parse_with_log<F: FromStr<Err = E>, E: std::fmt::Display>(
     }
 }
 
+/// Parses comma separated chain kinds
+fn parse_chain_kinds(kinds: &str) -> Result<Vec<ChainKind>, DaemonError> {
+    kinds
+        .split(',')
+        .map(|kind| {
+            kind.trim()
+                .to_lowercase()
+                .parse()
+                .map_err(|e| DaemonError::StdErr(format!("Invalid {CONFIRM_TXS_ON_ENV_NAME}: {e}")))
+        })
+        .collect()
+}
+
 /// Parse block time duration from duration string
 /// Takes duration in format `{integer}{duration_specifier}`
 ///
@@ -230,6 +276,15 @@ mod test_parse {
         );
     }
 
+    #[test]
+    fn test_parse_chain_kinds() {
+        assert_eq!(
+            parse_chain_kinds("mainnet, Testnet").unwrap(),
+            vec![ChainKind::Mainnet, ChainKind::Testnet]
+        );
+        assert!(parse_chain_kinds("mainet").is_err());
+    }
+
     #[test]
     #[should_panic]
     fn test_parse_empty_block_time_duration() {
@@ -1,9 +1,13 @@
 #![allow(missing_docs)]
 
+use std::time::Duration;
+
 use cosmwasm_std::{Coin, Instantiate2AddressError};
 use cw_orch_core::CwEnvError;
 use thiserror::Error;
 
+use crate::TxError;
+
 #[derive(Error, Debug)]
 pub enum DaemonError {
     #[error(transparent)]
@@ -30,9 +34,17 @@ pub enum DaemonError {
     TransportError(#[from] ::tonic::transport::Error),
     #[error(transparent)]
     TendermintError(#[from] ::cosmrs::tendermint::Error),
+    #[cfg(feature = "websocket")]
+    #[error(transparent)]
+    TendermintRpcError(#[from] ::tendermint_rpc::Error),
+    #[cfg(feature = "indexer")]
+    #[error(transparent)]
+    Sqlite(#[from] ::rusqlite::Error),
     #[error(transparent)]
     CwEnvError(#[from] ::cw_orch_core::CwEnvError),
     #[error(transparent)]
+    WebError(#[from] ::cw_orch_web::WebError),
+    #[error(transparent)]
     StripPrefixPath(#[from] std::path::StripPrefixError),
     #[error("Bech32 Decode Error")]
     Bech32DecodeErr,
@@ -44,6 +56,8 @@ pub enum DaemonError {
     Phrasing,
     #[error("Mnemonic - Missing Phrase")]
     MissingPhrase,
+    #[error("Encrypted key error: {0}")]
+    KeyEncryption(String),
     #[error("Bad Implementation. Missing Component")]
     Implementation,
     #[error("Unable to convert into public key `{key}`: {source}")]
@@ -75,6 +89,10 @@ pub enum DaemonError {
     ConversionLengthED25519Hex(usize),
     #[error("Expected ED25519 key of length 32 with a BECH32 ED25519 prefix of 5 chars - Len {0} - Hex {1}")]
     ConversionPrefixED25519(usize, String),
+    #[error("Expected an address of 20 bytes, got {0} bytes")]
+    ConversionAddressLength(usize),
+    #[error("Coin type {0} not supported without the eth feature")]
+    UnsupportedCoinType(u32),
     #[error("Can't call Transactions without some gas rules")]
     NoGasOpts,
     #[error("Can't parse `{parse}` into a coin")]
@@ -101,8 +119,8 @@ pub enum DaemonError {
     NewNetwork(String),
     #[error("Can not connect to any grpc endpoint that was provided.")]
     CannotConnectGRPC,
-    #[error("tx failed: {reason} with code {code}")]
-    TxFailed { code: usize, reason: String },
+    #[error("tx failed: {0}")]
+    TxFailed(TxError),
     #[error("The list of grpc endpoints is empty")]
     GRPCListIsEmpty,
     #[error("no wasm path provided for contract.")]
@@ -127,12 +145,32 @@ pub enum DaemonError {
     OpenFile(String, String),
     #[error("State file {0} already locked, use another state file, clone daemon which holds the lock, or use `state` method of Builder")]
     StateAlreadyLocked(String),
+    #[error("Contract {0} is already being executed by another task")]
+    ContractLocked(String),
+    #[error("Execution budget exceeded: {0}")]
+    BudgetExceeded(String),
+    #[error("Transaction not confirmed: {0}")]
+    TxNotConfirmed(String),
+    #[error("Operation cancelled")]
+    Cancelled,
+    #[error("Code {0} not returned by the node after {1} blocks")]
+    CodeNotFound(u64, usize),
+    #[error("Proposal {0} still not decided after {1:?}")]
+    ProposalNotDecided(u64, Duration),
 }
 
 impl DaemonError {
     pub fn ibc_err(msg: impl ToString) -> Self {
         Self::IbcError(msg.to_string())
     }
+
+    /// Structured error of the transaction, if it failed on chain
+    pub fn tx_error(&self) -> Option<&TxError> {
+        match self {
+            Self::TxFailed(tx_error) => Some(tx_error),
+            _ => None,
+        }
+    }
 }
 
 impl From<DaemonError> for CwEnvError {
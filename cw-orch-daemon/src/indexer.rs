@@ -0,0 +1,429 @@
+//! Indexer of the wasm events emitted by a set of contracts, stored in an embedded sqlite database.
+//!
+//! The past events are backfilled from the tx search of the node, the new ones are pushed by the websocket
+//! [`Subscriptions`]. The events are deduplicated, so the backfill and the tail can overlap and an indexer can be
+//! restarted by backfilling from its [`Indexer::last_indexed_height`].
+//!
+//! ```rust,no_run
+//! # tokio_test::block_on(async {
+//! use cosmwasm_std::Addr;
+//! use cw_orch_daemon::{
+//!     indexer::{Indexer, WasmEvent},
+//!     queriers::Node,
+//!     subscription::Subscriptions,
+//!     networks, DaemonAsync,
+//! };
+//!
+//! #[derive(serde::Deserialize)]
+//! struct MintEvent {
+//!     to: String,
+//!     amount: String,
+//! }
+//!
+//! impl WasmEvent for MintEvent {
+//!     const EVENT_TYPE: &'static str = "wasm";
+//!     const ACTION: Option<&'static str> = Some("mint");
+//! }
+//!
+//! let daemon = DaemonAsync::builder(networks::LOCAL_JUNO).build().await.unwrap();
+//! let indexer = Indexer::open("events.db", vec![Addr::unchecked("juno1...")]).unwrap();
+//! let from_height = indexer.last_indexed_height().unwrap();
+//! indexer
+//!     .backfill(&Node::new_async(daemon.channel()), from_height)
+//!     .await
+//!     .unwrap();
+//!
+//! let mints = indexer.events_of::<MintEvent>(1000..).unwrap();
+//!
+//! // Index the new events until the connection is closed
+//! let subscriptions = Subscriptions::connect("ws://localhost:26657/websocket").await.unwrap();
+//! indexer.tail(&subscriptions).await.unwrap();
+//! # })
+//! ```
+use std::{
+    ops::{Bound, RangeBounds},
+    path::Path,
+    sync::Mutex,
+};
+
+use cosmrs::proto::cosmos::tx::v1beta1::OrderBy;
+use cosmwasm_std::{Addr, Event};
+use cw_orch_core::environment::IndexResponse;
+use futures_util::{stream::select_all, Stream, StreamExt};
+use rusqlite::{params, Connection};
+use serde::de::DeserializeOwned;
+use sha2::{Digest, Sha256};
+
+use crate::{
+    queriers::{is_last_tx_page, Node, TX_SEARCH_PAGE_LIMIT},
+    subscription::{Subscriptions, TxEvent},
+    DaemonError,
+};
+
+const SCHEMA: &str = "CREATE TABLE IF NOT EXISTS wasm_events (
+    contract    TEXT    NOT NULL,
+    height      INTEGER NOT NULL,
+    tx_hash     TEXT    NOT NULL,
+    event_index INTEGER NOT NULL,
+    event_type  TEXT    NOT NULL,
+    attributes  TEXT    NOT NULL,
+    UNIQUE (contract, tx_hash, event_index)
+);
+CREATE INDEX IF NOT EXISTS wasm_events_type_height ON wasm_events (event_type, height);";
+
+/// Event emitted by a contract, deserialized from its attributes (the `_contract_address` attribute excluded).
+/// All the attribute values are strings.
+pub trait WasmEvent: DeserializeOwned {
+    /// Type of the event, `wasm` for the attributes of the contract response or `wasm-{type}` for custom events
+    const EVENT_TYPE: &'static str;
+    /// Value of the `action` attribute of the events, if they are distinguished by action
+    const ACTION: Option<&'static str> = None;
+
+    /// Deserializes the event, `None` if it has another action
+    fn from_event(event: &Event) -> Result<Option<Self>, DaemonError> {
+        if let Some(action) = Self::ACTION {
+            if !event
+                .attributes
+                .iter()
+                .any(|attr| attr.key == "action" && attr.value == action)
+            {
+                return Ok(None);
+            }
+        }
+        let attributes: serde_json::Map<String, serde_json::Value> = event
+            .attributes
+            .iter()
+            .filter(|attr| attr.key != "_contract_address")
+            .map(|attr| (attr.key.clone(), attr.value.clone().into()))
+            .collect();
+        Ok(Some(serde_json::from_value(attributes.into())?))
+    }
+}
+
+/// Event stored by the [`Indexer`]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Indexed<E> {
+    /// Contract that emitted the event
+    pub contract: Addr,
+    /// Height of the block including the transaction
+    pub height: u64,
+    /// Hash of the transaction
+    pub tx_hash: String,
+    pub event: E,
+}
+
+/// Indexes the wasm events of `contracts` into a sqlite database
+pub struct Indexer {
+    db: Mutex<Connection>,
+    contracts: Vec<Addr>,
+}
+
+impl Indexer {
+    /// Opens (or creates) the database at `path`
+    pub fn open(path: impl AsRef<Path>, contracts: Vec<Addr>) -> Result<Self, DaemonError> {
+        Self::with_connection(Connection::open(path)?, contracts)
+    }
+
+    /// Indexes into a database kept in memory, dropped with the indexer
+    pub fn open_in_memory(contracts: Vec<Addr>) -> Result<Self, DaemonError> {
+        Self::with_connection(Connection::open_in_memory()?, contracts)
+    }
+
+    fn with_connection(db: Connection, contracts: Vec<Addr>) -> Result<Self, DaemonError> {
+        db.execute_batch(SCHEMA)?;
+        Ok(Self {
+            db: Mutex::new(db),
+            contracts,
+        })
+    }
+
+    /// Contracts whose events are indexed
+    pub fn contracts(&self) -> &[Addr] {
+        &self.contracts
+    }
+
+    /// Indexes the past events of the contracts found by the tx search of the node,
+    /// from the block at `from_height` (included) or from genesis if `None`.
+    /// Returns the number of new events.
+    pub async fn backfill(
+        &self,
+        node: &Node,
+        from_height: Option<u64>,
+    ) -> Result<usize, DaemonError> {
+        let mut indexed = 0;
+        for contract in &self.contracts {
+            let mut query = vec![format!("wasm._contract_address='{contract}'")];
+            if let Some(height) = from_height {
+                query.push(format!("tx.height>={height}"));
+            }
+            let mut fetched = 0;
+            // Tx search pages start at 1
+            for page in 1.. {
+                let (txs, total) = node
+                    ._find_tx_page(query.clone(), page, TX_SEARCH_PAGE_LIMIT, OrderBy::Asc)
+                    .await?;
+                let received = txs.len() as u64;
+                fetched += received;
+                for tx in &txs {
+                    indexed += self.insert(contract, tx.height, &tx.txhash, tx.events())?;
+                }
+                if is_last_tx_page(received, fetched, total) {
+                    break;
+                }
+            }
+        }
+        log::info!("Backfilled {indexed} wasm events");
+        Ok(indexed)
+    }
+
+    /// Indexes the new events of the contracts pushed by `subscriptions`, until the connection is closed
+    pub async fn tail(&self, subscriptions: &Subscriptions) -> Result<(), DaemonError> {
+        let mut streams = vec![];
+        for contract in &self.contracts {
+            let events = subscriptions.subscribe_contract_events(contract).await?;
+            let contract = contract.clone();
+            streams.push(Box::pin(
+                events.map(move |tx| tx.map(|tx| (contract.clone(), tx))),
+            ));
+        }
+        self.index_txs(select_all(streams)).await
+    }
+
+    /// Indexes the events of the txs of `txs`, until the stream ends or errors
+    async fn index_txs(
+        &self,
+        txs: impl Stream<Item = Result<(Addr, TxEvent), DaemonError>>,
+    ) -> Result<(), DaemonError> {
+        let mut txs = Box::pin(txs);
+        while let Some(tx) = txs.next().await {
+            let (contract, tx) = tx?;
+            let tx_hash = tx_hash(&tx);
+            let indexed = self.insert(&contract, tx.height, &tx_hash, tx.events)?;
+            log::debug!(
+                "Indexed {indexed} wasm events of {contract} at height {}",
+                tx.height
+            );
+        }
+        Ok(())
+    }
+
+    /// Height of the latest indexed event
+    pub fn last_indexed_height(&self) -> Result<Option<u64>, DaemonError> {
+        let db = self.db.lock().unwrap();
+        let height: Option<i64> =
+            db.query_row("SELECT MAX(height) FROM wasm_events", [], |row| row.get(0))?;
+        Ok(height.map(|height| height as u64))
+    }
+
+    /// Events of type `event_type` emitted in the blocks of `heights`
+    pub fn events(
+        &self,
+        event_type: &str,
+        heights: impl RangeBounds<u64>,
+    ) -> Result<Vec<Indexed<Event>>, DaemonError> {
+        let min = match heights.start_bound() {
+            Bound::Included(height) => *height as i64,
+            Bound::Excluded(height) => *height as i64 + 1,
+            Bound::Unbounded => 0,
+        };
+        let max = match heights.end_bound() {
+            Bound::Included(height) => *height as i64,
+            Bound::Excluded(height) => *height as i64 - 1,
+            Bound::Unbounded => i64::MAX,
+        };
+        let db = self.db.lock().unwrap();
+        let mut statement = db.prepare(
+            "SELECT contract, height, tx_hash, attributes FROM wasm_events
+            WHERE event_type = ?1 AND height BETWEEN ?2 AND ?3
+            ORDER BY height, tx_hash, event_index",
+        )?;
+        let rows = statement.query_map(params![event_type, min, max], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, i64>(1)?,
+                row.get::<_, String>(2)?,
+                row.get::<_, String>(3)?,
+            ))
+        })?;
+        rows.map(|row| {
+            let (contract, height, tx_hash, attributes) = row?;
+            let attributes: Vec<(String, String)> = serde_json::from_str(&attributes)?;
+            Ok(Indexed {
+                contract: Addr::unchecked(contract),
+                height: height as u64,
+                tx_hash,
+                event: Event::new(event_type).add_attributes(attributes),
+            })
+        })
+        .collect()
+    }
+
+    /// Events `E` emitted in the blocks of `heights`
+    pub fn events_of<E: WasmEvent>(
+        &self,
+        heights: impl RangeBounds<u64>,
+    ) -> Result<Vec<Indexed<E>>, DaemonError> {
+        let mut events = vec![];
+        for indexed in self.events(E::EVENT_TYPE, heights)? {
+            if let Some(event) = E::from_event(&indexed.event)? {
+                events.push(Indexed {
+                    contract: indexed.contract,
+                    height: indexed.height,
+                    tx_hash: indexed.tx_hash,
+                    event,
+                });
+            }
+        }
+        Ok(events)
+    }
+
+    /// Stores the wasm events emitted by `contract`, returns the number of new events
+    fn insert(
+        &self,
+        contract: &Addr,
+        height: u64,
+        tx_hash: &str,
+        events: Vec<Event>,
+    ) -> Result<usize, DaemonError> {
+        let mut db = self.db.lock().unwrap();
+        let db_tx = db.transaction()?;
+        let mut inserted = 0;
+        for (index, event) in events
+            .into_iter()
+            .filter(|event| {
+                event.ty.starts_with("wasm")
+                    && event.attributes.iter().any(|attr| {
+                        attr.key == "_contract_address" && attr.value == contract.as_str()
+                    })
+            })
+            .enumerate()
+        {
+            let attributes: Vec<(String, String)> = event
+                .attributes
+                .into_iter()
+                .map(|attr| (attr.key, attr.value))
+                .collect();
+            inserted += db_tx.execute(
+                "INSERT OR IGNORE INTO wasm_events
+                (contract, height, tx_hash, event_index, event_type, attributes)
+                VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+                params![
+                    contract.as_str(),
+                    height as i64,
+                    tx_hash,
+                    index as i64,
+                    event.ty,
+                    serde_json::to_string(&attributes)?
+                ],
+            )?;
+        }
+        db_tx.commit()?;
+        Ok(inserted)
+    }
+}
+
+/// Hash of a pushed transaction, computed from its bytes if the node didn't provide it
+fn tx_hash(tx: &TxEvent) -> String {
+    tx.hash
+        .clone()
+        .unwrap_or_else(|| hex::encode_upper(Sha256::digest(&tx.tx)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(serde::Deserialize, Debug, PartialEq)]
+    struct MintEvent {
+        to: String,
+        amount: String,
+    }
+
+    impl WasmEvent for MintEvent {
+        const EVENT_TYPE: &'static str = "wasm";
+        const ACTION: Option<&'static str> = Some("mint");
+    }
+
+    fn wasm_event(contract: &str, action: &str) -> Event {
+        Event::new("wasm").add_attributes([
+            ("_contract_address", contract),
+            ("action", action),
+            ("to", "receiver"),
+            ("amount", "10"),
+        ])
+    }
+
+    #[test]
+    fn stores_and_queries_typed_events() -> Result<(), DaemonError> {
+        let contract = Addr::unchecked("contract");
+        let indexer = Indexer::open_in_memory(vec![contract.clone()])?;
+        let events = vec![
+            wasm_event("contract", "mint"),
+            wasm_event("other", "mint"),
+            wasm_event("contract", "burn"),
+            Event::new("message").add_attribute("action", "mint"),
+        ];
+        assert_eq!(indexer.insert(&contract, 5, "HASH", events.clone())?, 2);
+        // Indexing the same tx again is a no-op
+        assert_eq!(indexer.insert(&contract, 5, "HASH", events)?, 0);
+        indexer.insert(&contract, 8, "HASH2", vec![wasm_event("contract", "mint")])?;
+
+        assert_eq!(indexer.last_indexed_height()?, Some(8));
+        assert_eq!(indexer.events("wasm", ..)?.len(), 3);
+
+        let mints = indexer.events_of::<MintEvent>(..8)?;
+        assert_eq!(
+            mints,
+            vec![Indexed {
+                contract,
+                height: 5,
+                tx_hash: "HASH".to_string(),
+                event: MintEvent {
+                    to: "receiver".to_string(),
+                    amount: "10".to_string(),
+                },
+            }]
+        );
+        assert_eq!(indexer.events_of::<MintEvent>(5..=8)?.len(), 2);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn indexes_the_pushed_txs() -> Result<(), DaemonError> {
+        let contract = Addr::unchecked("contract");
+        let indexer = Indexer::open_in_memory(vec![contract.clone()])?;
+        let pushed = |height: u64, hash: Option<&str>| -> Result<_, DaemonError> {
+            Ok((
+                contract.clone(),
+                TxEvent {
+                    height,
+                    hash: hash.map(str::to_string),
+                    tx: vec![height as u8],
+                    events: vec![wasm_event("contract", "mint")],
+                },
+            ))
+        };
+        let txs = futures_util::stream::iter(vec![
+            pushed(3, Some("HASH")),
+            // Pushed twice by overlapping subscriptions
+            pushed(3, Some("HASH")),
+            pushed(4, None),
+        ]);
+        indexer.index_txs(txs).await?;
+
+        let mints = indexer.events_of::<MintEvent>(..)?;
+        assert_eq!(mints.len(), 2);
+        assert_eq!(mints[0].tx_hash, "HASH");
+        // Without a hash from the node, the hash of the tx bytes is stored
+        assert_eq!(mints[1].tx_hash, hex::encode_upper(Sha256::digest([4u8])));
+
+        // The tail stops on the first error of the subscriptions
+        let txs = futures_util::stream::iter(vec![
+            Err(DaemonError::StdErr("closed".to_string())),
+            pushed(5, Some("HASH3")),
+        ]);
+        assert!(indexer.index_txs(txs).await.is_err());
+        assert_eq!(indexer.last_indexed_height()?, Some(4));
+        Ok(())
+    }
+}
@@ -0,0 +1,160 @@
+//! Password-encrypted key files.
+//!
+//! Allows moving a mnemonic between machines where no system keyring is available (CI runners for instance).
+//! The key is derived from the password with PBKDF2-HMAC-SHA256 and the mnemonic is encrypted with AES-256-GCM.
+use std::{num::NonZeroU32, path::Path};
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use rand_core::{OsRng, RngCore};
+use ring::{aead, pbkdf2};
+use serde::{Deserialize, Serialize};
+
+use super::private::PrivateKey;
+use crate::DaemonError;
+
+/// Current version of the key file format
+pub const ENCRYPTED_KEY_VERSION: u8 = 1;
+const KDF_NAME: &str = "pbkdf2-hmac-sha256";
+const DEFAULT_ITERATIONS: u32 = 600_000;
+const SALT_LEN: usize = 16;
+
+/// A mnemonic encrypted with a password, serializable to a JSON file
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct EncryptedKeyFile {
+    /// Version of the file format
+    pub version: u8,
+    /// Key derivation function used to derive the encryption key from the password
+    pub kdf: String,
+    /// Amount of iterations of the key derivation function
+    pub iterations: u32,
+    /// Base64 encoded salt of the key derivation function
+    pub salt: String,
+    /// Base64 encoded AES-256-GCM nonce
+    pub nonce: String,
+    /// Base64 encoded encrypted mnemonic
+    pub ciphertext: String,
+}
+
+impl EncryptedKeyFile {
+    /// Encrypts a mnemonic with the given password
+    pub fn encrypt(mnemonic: &str, password: &str) -> Result<Self, DaemonError> {
+        let mut salt = [0u8; SALT_LEN];
+        OsRng.fill_bytes(&mut salt);
+        let mut nonce = [0u8; aead::NONCE_LEN];
+        OsRng.fill_bytes(&mut nonce);
+
+        let key = derive_key(password, &salt, DEFAULT_ITERATIONS)?;
+        let mut in_out = mnemonic.as_bytes().to_vec();
+        key.seal_in_place_append_tag(
+            aead::Nonce::assume_unique_for_key(nonce),
+            aead::Aad::from([ENCRYPTED_KEY_VERSION]),
+            &mut in_out,
+        )
+        .map_err(|_| DaemonError::KeyEncryption("encryption failed".to_string()))?;
+
+        Ok(Self {
+            version: ENCRYPTED_KEY_VERSION,
+            kdf: KDF_NAME.to_string(),
+            iterations: DEFAULT_ITERATIONS,
+            salt: STANDARD.encode(salt),
+            nonce: STANDARD.encode(nonce),
+            ciphertext: STANDARD.encode(in_out),
+        })
+    }
+
+    /// Decrypts the mnemonic contained in the file
+    pub fn decrypt(&self, password: &str) -> Result<String, DaemonError> {
+        if self.version != ENCRYPTED_KEY_VERSION || self.kdf != KDF_NAME {
+            return Err(DaemonError::KeyEncryption(format!(
+                "unsupported key file version {} with kdf {}",
+                self.version, self.kdf
+            )));
+        }
+        let salt = STANDARD.decode(&self.salt)?;
+        let nonce: [u8; aead::NONCE_LEN] = STANDARD
+            .decode(&self.nonce)?
+            .try_into()
+            .map_err(|_| DaemonError::KeyEncryption("invalid nonce length".to_string()))?;
+        let mut in_out = STANDARD.decode(&self.ciphertext)?;
+
+        let key = derive_key(password, &salt, self.iterations)?;
+        let plaintext = key
+            .open_in_place(
+                aead::Nonce::assume_unique_for_key(nonce),
+                aead::Aad::from([self.version]),
+                &mut in_out,
+            )
+            .map_err(|_| {
+                DaemonError::KeyEncryption("wrong password or corrupted key file".to_string())
+            })?;
+
+        String::from_utf8(plaintext.to_vec())
+            .map_err(|_| DaemonError::KeyEncryption("decrypted key is not valid utf8".to_string()))
+    }
+
+    /// Writes the encrypted key to a JSON file
+    pub fn write(&self, path: impl AsRef<Path>) -> Result<(), DaemonError> {
+        std::fs::write(path, serde_json::to_vec_pretty(self)?)?;
+        Ok(())
+    }
+
+    /// Reads an encrypted key from a JSON file
+    pub fn read(path: impl AsRef<Path>) -> Result<Self, DaemonError> {
+        Ok(serde_json::from_slice(&std::fs::read(path)?)?)
+    }
+}
+
+impl PrivateKey {
+    /// Exports the mnemonic of this key, encrypted with the given password.
+    /// Errors if the key was not created from a mnemonic.
+    pub fn export_encrypted(&self, password: &str) -> Result<EncryptedKeyFile, DaemonError> {
+        let words = self.words().ok_or(DaemonError::MissingPhrase)?;
+        EncryptedKeyFile::encrypt(&words, password)
+    }
+}
+
+fn derive_key(
+    password: &str,
+    salt: &[u8],
+    iterations: u32,
+) -> Result<aead::LessSafeKey, DaemonError> {
+    let iterations = NonZeroU32::new(iterations).ok_or(DaemonError::KeyEncryption(
+        "iterations can't be 0".to_string(),
+    ))?;
+    let mut key = [0u8; 32];
+    pbkdf2::derive(
+        pbkdf2::PBKDF2_HMAC_SHA256,
+        iterations,
+        salt,
+        password.as_bytes(),
+        &mut key,
+    );
+    let unbound = aead::UnboundKey::new(&aead::AES_256_GCM, &key)
+        .map_err(|_| DaemonError::KeyEncryption("invalid key length".to_string()))?;
+    Ok(aead::LessSafeKey::new(unbound))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MNEMONIC: &str = "notice oak worry limit wrap speak medal online prefer cluster roof addict wrist behave treat actual wasp year salad speed social layer crew genius";
+
+    #[test]
+    fn encrypt_decrypt_roundtrip() -> anyhow::Result<()> {
+        let file = EncryptedKeyFile::encrypt(MNEMONIC, "password")?;
+        assert_eq!(file.decrypt("password")?, MNEMONIC);
+
+        let serialized = serde_json::to_string(&file)?;
+        let deserialized: EncryptedKeyFile = serde_json::from_str(&serialized)?;
+        assert_eq!(deserialized.decrypt("password")?, MNEMONIC);
+        Ok(())
+    }
+
+    #[test]
+    fn wrong_password_fails() -> anyhow::Result<()> {
+        let file = EncryptedKeyFile::encrypt(MNEMONIC, "password")?;
+        assert!(file.decrypt("not-the-password").is_err());
+        Ok(())
+    }
+}
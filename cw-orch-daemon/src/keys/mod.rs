@@ -1,4 +1,5 @@
 #![allow(unused)]
+pub mod encrypted;
 pub mod private;
 pub mod public;
 pub mod signature;
@@ -144,6 +144,28 @@ impl PublicKey {
         }
     }
 
+    /// Generate Public key from a 0x prefixed hex address, as used on EVM-compatible chains
+    pub fn from_eth_address(eth_address: &str) -> Result<PublicKey, DaemonError> {
+        let raw = hex::decode(eth_address.trim_start_matches("0x"))?;
+        if raw.len() != 20 {
+            return Err(DaemonError::ConversionAddressLength(raw.len()));
+        }
+        Ok(PublicKey {
+            raw_pub_key: None,
+            raw_address: Some(raw),
+        })
+    }
+
+    /// Generate Public key from a secp256k1 public key (compressed or not), using the ethereum address derivation
+    #[cfg(feature = "eth")]
+    pub fn from_eth_public_key(public_key: &[u8]) -> Result<PublicKey, DaemonError> {
+        let verifying_key = ethers_core::k256::ecdsa::VerifyingKey::from_sec1_bytes(public_key)
+            .map_err(|_| DaemonError::ConversionSECP256k1)?;
+        Ok(PublicKey::from_ethers_address_bytes(
+            ethers_core::utils::public_key_to_address(&verifying_key),
+        ))
+    }
+
     fn check_prefix_and_length(
         prefix: &str,
         data: &str,
@@ -271,6 +293,14 @@ impl PublicKey {
         }
     }
 
+    /// The 0x prefixed hex representation of the account, as used on EVM-compatible chains
+    pub fn eth_address(&self) -> Result<String, DaemonError> {
+        match &self.raw_address {
+            Some(raw) => Ok(format!("0x{}", hex::encode(raw))),
+            None => Err(DaemonError::Implementation),
+        }
+    }
+
     /// The operator address used for validators
     pub fn operator_address(&self, prefix: &str) -> Result<String, DaemonError> {
         let valoper_prefix = format!("{}{}", prefix, "valoper");
@@ -310,6 +340,23 @@ impl PublicKey {
     }
 }
 
+/// Converts a bech32 account address to the 0x prefixed hex representation of the same account
+pub fn bech32_to_eth_address(address: &str) -> Result<String, DaemonError> {
+    let (_, raw) = decode(address).map_err(|source| DaemonError::Conversion {
+        key: address.into(),
+        source,
+    })?;
+    if raw.len() != 20 {
+        return Err(DaemonError::ConversionAddressLength(raw.len()));
+    }
+    Ok(format!("0x{}", hex::encode(raw)))
+}
+
+/// Converts a 0x prefixed hex address to the bech32 representation of the same account with `prefix`
+pub fn eth_to_bech32_address(eth_address: &str, prefix: &str) -> Result<String, DaemonError> {
+    PublicKey::from_eth_address(eth_address)?.account(prefix)
+}
+
 fn key_to_addr(data: &[u8], prefix: &str) -> Result<String, DaemonError> {
     let hrp_result = bech32::Hrp::parse(prefix);
     if let Ok(hrp) = hrp_result {
@@ -470,6 +517,28 @@ mod tst {
         Ok(())
     }
     #[test]
+    pub fn test_eth_address_conversions() -> anyhow::Result<()> {
+        let account = "terra1jnzv225hwl3uxc5wtnlgr8mwy6nlt0vztv3qqm";
+        let eth_address = "0x94c4c52a9777e3c3628e5cfe819f6e26a7f5bd82";
+
+        assert_eq!(bech32_to_eth_address(account)?, eth_address);
+        assert_eq!(eth_to_bech32_address(eth_address, PREFIX)?, account);
+        // Checksummed addresses are accepted
+        assert_eq!(
+            eth_to_bech32_address("0x94C4C52A9777E3C3628E5CFE819F6E26A7F5BD82", PREFIX)?,
+            account
+        );
+        assert_eq!(
+            PublicKey::from_account(account, PREFIX)?.eth_address()?,
+            eth_address
+        );
+        assert!(matches!(
+            PublicKey::from_eth_address("0x94c4c52a"),
+            Err(DaemonError::ConversionAddressLength(4))
+        ));
+        Ok(())
+    }
+    #[test]
     pub fn test_proposer() -> anyhow::Result<()> {
         //   dotenv().ok();
         //   env_logger::init();
@@ -3,33 +3,61 @@
 //! `Daemon` and `DaemonAsync` execution environments.
 //!
 //! The `Daemon` type is a synchronous wrapper around the `DaemonAsync` type and can be used as a contract execution environment.
+pub mod chain_registry;
+pub mod devnet;
 pub mod json_lock;
 /// Proto types for different blockchains
 pub mod proto;
 // expose these as mods as they can grow
 pub mod env;
+#[cfg(feature = "indexer")]
+pub mod indexer;
 pub mod keys;
 pub mod live_mock;
+pub mod metrics;
+pub mod profile;
 pub mod queriers;
 pub mod senders;
+#[cfg(feature = "websocket")]
+pub mod subscription;
+pub mod transport;
 pub mod tx_broadcaster;
 pub mod tx_builder;
 
 mod builder;
 mod channel;
+mod contract_lock;
 mod core;
 mod error;
 mod log;
+mod memo;
 mod network_config;
+mod polling;
+mod rate_limit;
 mod state;
 mod sync;
+mod test_accounts;
+mod tx_error;
 mod tx_resp;
 
 pub use self::{builder::*, channel::*, core::*, error::*, state::*, sync::*, tx_resp::*};
-pub use cw_orch_networks::networks;
-pub use network_config::read_network_config;
+pub use contract_lock::ContractLockPolicy;
+pub use memo::{MemoTemplate, MAX_MEMO_LEN};
+pub use network_config::{read_all_network_configs, read_network_config, write_network_config};
+pub use polling::{BroadcastMode, PollingConfig, TxConfirmation};
+#[doc(hidden)]
+pub use rate_limit::wait_rate_limited;
+pub use rate_limit::{RateLimit, RateLimits};
 pub use senders::{query::QuerySender, tx::TxSender, CosmosOptions, Wallet};
-pub use tx_builder::TxBuilder;
+pub use test_accounts::TestAccounts;
+pub use tx_builder::{SignedTx, TxBuilder, UnsignedTx};
+pub use tx_error::{TxError, SDK_CODESPACE, WASM_CODESPACE};
+
+/// Network definitions, compiled-in or loaded from the chain registry
+pub mod networks {
+    pub use crate::chain_registry::from_registry;
+    pub use cw_orch_networks::networks::*;
+}
 
 pub(crate) mod cosmos_modules {
     pub use cosmrs::proto::{
@@ -39,7 +67,10 @@ pub(crate) mod cosmos_modules {
             bank::v1beta1 as bank,
             base::{abci::v1beta1 as abci, tendermint::v1beta1 as tendermint},
             feegrant::v1beta1 as feegrant,
+            gov::v1 as gov_v1,
             gov::v1beta1 as gov,
+            params::v1beta1 as params,
+            slashing::v1beta1 as slashing,
             staking::v1beta1 as staking,
             tx::v1beta1 as tx,
             vesting::v1beta1 as vesting,
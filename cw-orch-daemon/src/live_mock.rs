@@ -4,6 +4,7 @@
 use crate::queriers::Bank;
 use crate::queriers::CosmWasm;
 use crate::queriers::Staking;
+use crate::RateLimits;
 use crate::RUNTIME;
 use cosmwasm_std::testing::{MockApi, MockStorage};
 use cosmwasm_std::Addr;
@@ -189,12 +190,17 @@ impl WasmMockQuerier {
 }
 
 impl WasmMockQuerier {
-    /// Creates a querier from chain information
+    /// Creates a querier from chain information, limited by the default [`RateLimits`]
     pub fn new(chain: ChainInfoOwned) -> Self {
+        Self::with_rate_limits(chain, &RateLimits::default())
+    }
+
+    /// Creates a querier from chain information, the queries sent to the chain being limited by `rate_limits`
+    pub fn with_rate_limits(chain: ChainInfoOwned, rate_limits: &RateLimits) -> Self {
         let channel = RUNTIME
-            .block_on(GrpcChannel::connect(
-                &chain.grpc_urls,
-                chain.chain_id.as_str(),
+            .block_on(GrpcChannel::from_chain_info_with_limits(
+                &chain,
+                rate_limits,
             ))
             .unwrap();
 
@@ -0,0 +1,94 @@
+//! Memo attached to the transactions of a daemon, to trace deployments on chain.
+//!
+//! ```rust,no_run
+//! use cw_orch_daemon::{networks, Daemon, MemoTemplate};
+//!
+//! let daemon = Daemon::builder(networks::LOCAL_JUNO)
+//!     .memo(
+//!         MemoTemplate::new("cw-orch:{project}:{version}:{step}")
+//!             .var("project", "my-protocol")
+//!             .var("version", env!("CARGO_PKG_VERSION")),
+//!     )
+//!     .build()
+//!     .unwrap();
+//!
+//! // Overrides the memo of a single call
+//! # use cw_orch_core::environment::TxHandler;
+//! # let (contract, msg) = (cosmwasm_std::Addr::unchecked("juno1..."), cosmwasm_std::Empty {});
+//! daemon.with_memo("hotfix #42").execute(&msg, &[], &contract).unwrap();
+//! ```
+use std::collections::BTreeMap;
+
+/// Maximum memo length accepted by the default configuration of the Cosmos SDK
+pub const MAX_MEMO_LEN: usize = 256;
+
+/// Template of the memo attached to all the transactions of a daemon.
+///
+/// The `{placeholders}` are replaced by the variables set with [`MemoTemplate::var`] and by the following built-in ones:
+/// - `{chain_id}`: chain the transaction is sent to
+/// - `{deployment_id}`: deployment id of the daemon state
+/// - `{sender}`: address of the sender
+/// - `{step}`: kind of transaction, e.g. `upload`, `instantiate`, `execute`, `migrate` or `bank_send`
+///
+/// Unknown placeholders are kept as is. The rendered memo is truncated to [`MAX_MEMO_LEN`] bytes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MemoTemplate {
+    template: String,
+    vars: BTreeMap<String, String>,
+}
+
+impl MemoTemplate {
+    /// Template rendering `template`
+    pub fn new(template: impl Into<String>) -> Self {
+        Self {
+            template: template.into(),
+            vars: BTreeMap::new(),
+        }
+    }
+
+    /// Sets the value of the `{key}` placeholder
+    pub fn var(mut self, key: impl Into<String>, value: impl ToString) -> Self {
+        self.vars.insert(key.into(), value.to_string());
+        self
+    }
+
+    /// Renders the memo with the variables of the template and the `builtins` variables
+    pub fn render<'a>(&'a self, builtins: impl IntoIterator<Item = (&'a str, &'a str)>) -> String {
+        let mut memo = self.template.clone();
+        let vars = self
+            .vars
+            .iter()
+            .map(|(key, value)| (key.as_str(), value.as_str()));
+        for (key, value) in vars.chain(builtins) {
+            memo = memo.replace(&format!("{{{key}}}"), value);
+        }
+        if memo.len() > MAX_MEMO_LEN {
+            log::warn!("Memo `{memo}` is longer than {MAX_MEMO_LEN} bytes, truncating it");
+            let mut end = MAX_MEMO_LEN;
+            while !memo.is_char_boundary(end) {
+                end -= 1;
+            }
+            memo.truncate(end);
+        }
+        memo
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_placeholders() {
+        let template = MemoTemplate::new("cw-orch:{project}:{version}:{step}:{unknown}")
+            .var("project", "abstract")
+            .var("version", "0.23.0");
+        assert_eq!(
+            template.render([("step", "instantiate")]),
+            "cw-orch:abstract:0.23.0:instantiate:{unknown}"
+        );
+
+        let long = MemoTemplate::new("é".repeat(200));
+        assert_eq!(long.render([]).len(), MAX_MEMO_LEN);
+    }
+}
@@ -0,0 +1,346 @@
+//! Metrics populated from the events of live blocks.
+//!
+//! Metrics are declared with [`EventMetric`] and registered in [`EventMetrics`], which is fed either manually with [`EventMetrics::record`]
+//! or by a monitoring task with [`EventMetrics::_monitor`].
+//!
+//! ```rust,no_run
+//! use cw_orch_daemon::{metrics::{EventMetric, EventMetrics}, networks, queriers::Node, Daemon};
+//!
+//! let daemon = Daemon::builder(networks::LOCAL_JUNO).build().unwrap();
+//! let metrics = EventMetrics::new(vec![
+//!     // Number of swaps, per pool
+//!     EventMetric::counter("swaps").when("action", "swap").label("pool"),
+//!     // Last reported price, per pool
+//!     EventMetric::gauge("price", "price").when("action", "swap").label("pool"),
+//! ]);
+//!
+//! let monitor = metrics.clone();
+//! // The cancellation token of the polling config stops the monitoring
+//! let node = Node::new_async(daemon.channel()).with_polling(daemon.polling().clone());
+//! daemon.rt_handle.spawn(async move { monitor._monitor(&node, None, None).await });
+//!
+//! println!("{}", metrics.to_prometheus());
+//! ```
+use std::{
+    collections::BTreeMap,
+    fmt::Write,
+    sync::{Arc, Mutex},
+};
+
+use cosmrs::proto::cosmos::tx::v1beta1::OrderBy;
+use cosmwasm_std::Event;
+use cw_orch_core::{environment::IndexResponse, log::query_target};
+
+use crate::{queriers::Node, DaemonError};
+
+/// Kind of metric
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MetricKind {
+    /// Accumulates over matching events
+    Counter,
+    /// Holds the value of the last matching event
+    Gauge,
+}
+
+/// Declaration of a metric populated from events
+#[derive(Clone, Debug)]
+pub struct EventMetric {
+    name: String,
+    kind: MetricKind,
+    event_type: String,
+    filters: Vec<(String, String)>,
+    value_attribute: Option<String>,
+    labels: Vec<String>,
+}
+
+impl EventMetric {
+    /// Counter incremented by 1 for each matching `wasm` event
+    pub fn counter(name: impl Into<String>) -> Self {
+        Self::new(name, MetricKind::Counter, None)
+    }
+
+    /// Gauge set to the (numeric) value of `value_attribute` for each matching `wasm` event
+    pub fn gauge(name: impl Into<String>, value_attribute: impl Into<String>) -> Self {
+        Self::new(name, MetricKind::Gauge, Some(value_attribute.into()))
+    }
+
+    fn new(name: impl Into<String>, kind: MetricKind, value_attribute: Option<String>) -> Self {
+        Self {
+            name: name.into(),
+            kind,
+            event_type: "wasm".to_string(),
+            filters: vec![],
+            value_attribute,
+            labels: vec![],
+        }
+    }
+
+    /// Sets the type of events to match. Defaults to `wasm`
+    pub fn on_event(mut self, event_type: impl Into<String>) -> Self {
+        self.event_type = event_type.into();
+        self
+    }
+
+    /// Only matches events that have an attribute `key` equal to `value`
+    pub fn when(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.filters.push((key.into(), value.into()));
+        self
+    }
+
+    /// Only matches events emitted by `contract`
+    pub fn contract(self, contract: impl Into<String>) -> Self {
+        self.when("_contract_address", contract)
+    }
+
+    /// Adds a label whose value is taken from the event attribute of the same name
+    pub fn label(mut self, attribute: impl Into<String>) -> Self {
+        self.labels.push(attribute.into());
+        self
+    }
+
+    /// Increments the counter by the (numeric) value of `attribute` instead of 1
+    pub fn increment_by(mut self, attribute: impl Into<String>) -> Self {
+        self.value_attribute = Some(attribute.into());
+        self
+    }
+
+    fn attribute<'a>(event: &'a Event, key: &str) -> Option<&'a str> {
+        event
+            .attributes
+            .iter()
+            .find(|attr| attr.key == key)
+            .map(|attr| attr.value.as_str())
+    }
+
+    fn matches(&self, event: &Event) -> bool {
+        event.ty == self.event_type
+            && self
+                .filters
+                .iter()
+                .all(|(key, value)| Self::attribute(event, key) == Some(value.as_str()))
+    }
+
+    fn value(&self, event: &Event) -> Option<f64> {
+        match &self.value_attribute {
+            Some(attribute) => Self::attribute(event, attribute)?.parse().ok(),
+            None => Some(1.0),
+        }
+    }
+
+    fn label_values(&self, event: &Event) -> Vec<(String, String)> {
+        self.labels
+            .iter()
+            .map(|label| {
+                let value = Self::attribute(event, label).unwrap_or_default();
+                (label.clone(), value.to_string())
+            })
+            .collect()
+    }
+}
+
+/// Current value of a metric for a set of labels
+#[derive(Clone, Debug, PartialEq)]
+pub struct MetricSample {
+    /// Name of the metric
+    pub name: String,
+    /// Kind of the metric
+    pub kind: MetricKind,
+    /// Labels and their values
+    pub labels: Vec<(String, String)>,
+    /// Current value
+    pub value: f64,
+}
+
+type MetricKey = (String, Vec<(String, String)>);
+
+/// Set of metrics populated from events.
+/// Clones share the same values, so a clone can be moved to a monitoring task.
+#[derive(Clone, Default)]
+pub struct EventMetrics {
+    definitions: Vec<EventMetric>,
+    values: Arc<Mutex<BTreeMap<MetricKey, f64>>>,
+}
+
+impl EventMetrics {
+    /// Registers the metrics
+    pub fn new(definitions: Vec<EventMetric>) -> Self {
+        Self {
+            definitions,
+            values: Default::default(),
+        }
+    }
+
+    /// Updates the metrics with the given events
+    pub fn record(&self, events: &[Event]) {
+        let mut values = self.values.lock().unwrap();
+        for event in events {
+            for metric in self.definitions.iter().filter(|m| m.matches(event)) {
+                let Some(value) = metric.value(event) else {
+                    continue;
+                };
+                let entry = values
+                    .entry((metric.name.clone(), metric.label_values(event)))
+                    .or_default();
+                match metric.kind {
+                    MetricKind::Counter => *entry += value,
+                    MetricKind::Gauge => *entry = value,
+                }
+            }
+        }
+    }
+
+    /// Returns the current value of all the metrics
+    pub fn samples(&self) -> Vec<MetricSample> {
+        let values = self.values.lock().unwrap();
+        values
+            .iter()
+            .filter_map(|((name, labels), value)| {
+                let metric = self.definitions.iter().find(|m| &m.name == name)?;
+                Some(MetricSample {
+                    name: name.clone(),
+                    kind: metric.kind,
+                    labels: labels.clone(),
+                    value: *value,
+                })
+            })
+            .collect()
+    }
+
+    /// Renders the metrics in the Prometheus text format
+    pub fn to_prometheus(&self) -> String {
+        let mut output = String::new();
+        let mut last_name = None;
+        for sample in self.samples() {
+            if last_name.as_ref() != Some(&sample.name) {
+                let kind = match sample.kind {
+                    MetricKind::Counter => "counter",
+                    MetricKind::Gauge => "gauge",
+                };
+                writeln!(output, "# TYPE {} {}", sample.name, kind).unwrap();
+                last_name = Some(sample.name.clone());
+            }
+            let labels = sample
+                .labels
+                .iter()
+                .map(|(key, value)| format!("{key}=\"{}\"", escape_label_value(value)))
+                .collect::<Vec<_>>()
+                .join(",");
+            if labels.is_empty() {
+                writeln!(output, "{} {}", sample.name, sample.value).unwrap();
+            } else {
+                writeln!(output, "{}{{{}}} {}", sample.name, labels, sample.value).unwrap();
+            }
+        }
+        output
+    }
+
+    /// Records the events of every new block, starting after `from_height` (or the latest block if `None`)
+    /// and up to `to_height` included (or forever if `None`).
+    /// The blocks are polled every [`PollingConfig::min_block_time`](crate::PollingConfig::min_block_time) of the node querier,
+    /// whose cancellation token stops the monitoring with [`DaemonError::Cancelled`].
+    pub async fn _monitor(
+        &self,
+        node: &Node,
+        from_height: Option<u64>,
+        to_height: Option<u64>,
+    ) -> Result<(), DaemonError> {
+        let mut last_height = match from_height {
+            Some(height) => height,
+            None => node._block_height().await?,
+        };
+
+        loop {
+            let mut latest_height = node._block_height().await?;
+            if let Some(to_height) = to_height {
+                latest_height = latest_height.min(to_height);
+            }
+            for height in last_height + 1..=latest_height {
+                let txs = node
+                    ._find_all_tx_by_events(vec![format!("tx.height={height}")], OrderBy::Asc)
+                    .await?;
+                log::debug!(
+                    target: &query_target(),
+                    "Recording metrics of {} txs at height {}",
+                    txs.len(),
+                    height
+                );
+                for tx in txs {
+                    self.record(&tx.events());
+                }
+            }
+            last_height = latest_height;
+            if to_height.is_some_and(|to_height| last_height >= to_height) {
+                break;
+            }
+            node.polling.sleep(node.polling.min_block_time).await?;
+        }
+        Ok(())
+    }
+}
+
+/// Escapes a label value for the Prometheus text format
+fn escape_label_value(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn swap(pool: &str, price: &str) -> Event {
+        Event::new("wasm")
+            .add_attribute("_contract_address", "dex")
+            .add_attribute("action", "swap")
+            .add_attribute("pool", pool)
+            .add_attribute("price", price)
+    }
+
+    #[test]
+    fn populates_counters_and_gauges() {
+        let metrics = EventMetrics::new(vec![
+            EventMetric::counter("swaps")
+                .contract("dex")
+                .when("action", "swap")
+                .label("pool"),
+            EventMetric::gauge("price", "price").label("pool"),
+        ]);
+
+        metrics.record(&[
+            swap("atom", "10"),
+            swap("atom", "11"),
+            swap("osmo", "1.5"),
+            Event::new("wasm").add_attribute("action", "withdraw"),
+        ]);
+
+        let value = |name: &str, pool: &str| {
+            metrics
+                .samples()
+                .into_iter()
+                .find(|s| s.name == name && s.labels == vec![("pool".into(), pool.into())])
+                .map(|s| s.value)
+        };
+        assert_eq!(value("swaps", "atom"), Some(2.0));
+        assert_eq!(value("swaps", "osmo"), Some(1.0));
+        assert_eq!(value("price", "atom"), Some(11.0));
+        assert_eq!(value("price", "osmo"), Some(1.5));
+        // The withdraw event has no price, so it's not recorded
+        assert_eq!(value("price", ""), None);
+
+        let prometheus = metrics.to_prometheus();
+        assert!(prometheus.contains("# TYPE swaps counter"));
+        assert!(prometheus.contains("swaps{pool=\"atom\"} 2"));
+    }
+
+    #[test]
+    fn escapes_label_values() {
+        let metrics = EventMetrics::new(vec![EventMetric::counter("swaps").label("pool")]);
+        metrics.record(&[swap("a\"b\\c\nd", "1")]);
+
+        assert!(metrics
+            .to_prometheus()
+            .contains(r#"swaps{pool="a\"b\\c\nd"} 1"#));
+    }
+}
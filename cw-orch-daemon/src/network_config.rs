@@ -1,17 +1,75 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, path::PathBuf};
 
 use cw_orch_core::environment::ChainInfoOwned;
 
-use crate::env::default_state_folder;
+use crate::{env::default_state_folder, DaemonError};
+
+const NETWORK_CONFIG_FILE: &str = "networks.toml";
 
 /// Reads network config from `~/.cw-orchestrator/networks.toml` and returns the config for the chain id
 pub fn read_network_config(chain_id: &str) -> Option<ChainInfoOwned> {
-    let mut state_folder = default_state_folder().ok()?;
-    state_folder.push("networks.toml");
-    let file_content = std::fs::read_to_string(state_folder).ok()?;
+    read_all_network_configs().remove(chain_id)
+}
+
+/// Reads all the networks defined in `~/.cw-orchestrator/networks.toml`, indexed by chain id
+/// Returns an empty map if the file doesn't exist. The file or the networks that can't be parsed are skipped with a warning
+pub fn read_all_network_configs() -> HashMap<String, ChainInfoOwned> {
+    let Ok(path) = network_config_path() else {
+        return HashMap::new();
+    };
+    let Ok(file_content) = std::fs::read_to_string(&path) else {
+        return HashMap::new();
+    };
+    let network_config: toml::Table = match toml::from_str(&file_content) {
+        Ok(network_config) => network_config,
+        Err(e) => {
+            log::warn!("Ignoring {}, it can't be parsed: {e}", path.display());
+            return HashMap::new();
+        }
+    };
+
+    network_config
+        .into_iter()
+        .filter_map(
+            |(chain_id, entry)| match entry.try_into::<ChainInfoOwned>() {
+                Ok(chain_info) => Some((chain_id, chain_info)),
+                Err(e) => {
+                    log::warn!(
+                        "Ignoring the network `{chain_id}` of {}, it can't be parsed: {e}",
+                        path.display()
+                    );
+                    None
+                }
+            },
+        )
+        .collect()
+}
+
+/// Adds a user-defined network to `~/.cw-orchestrator/networks.toml`, replacing any existing entry for the same chain id
+/// The network can then be loaded with `ChainInfo::config(chain_id)`
+pub fn write_network_config(chain_info: &ChainInfoOwned) -> Result<(), DaemonError> {
+    let path = network_config_path()?;
+    let mut network_config: toml::Table = match std::fs::read_to_string(&path) {
+        Ok(file_content) => toml::from_str(&file_content)
+            .map_err(|e| DaemonError::StdErr(format!("Invalid {NETWORK_CONFIG_FILE}: {e}")))?,
+        Err(_) => toml::Table::new(),
+    };
+
+    let entry = toml::Value::try_from(chain_info)
+        .map_err(|e| DaemonError::StdErr(format!("Can't serialize network config: {e}")))?;
+    network_config.insert(chain_info.chain_id.clone(), entry);
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let file_content = toml::to_string_pretty(&network_config)
+        .map_err(|e| DaemonError::StdErr(format!("Can't serialize network config: {e}")))?;
+    std::fs::write(path, file_content)?;
+    Ok(())
+}
 
-    let mut network_config: HashMap<String, ChainInfoOwned> = toml::from_str(&file_content).ok()?;
-    network_config.remove(chain_id)
+fn network_config_path() -> Result<PathBuf, DaemonError> {
+    Ok(default_state_folder()?.join(NETWORK_CONFIG_FILE))
 }
 
 #[cfg(test)]
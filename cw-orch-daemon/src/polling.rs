@@ -0,0 +1,335 @@
+use std::time::Duration;
+
+use tokio_util::sync::CancellationToken;
+
+use crate::{env::DaemonEnvVars, DaemonError};
+
+/// Timings of all the wait loops of the daemon: tx polling, block waits, broadcast retries and interchain lookups.
+///
+/// The defaults are read from the environment variables (see [`DaemonEnvVars`]), so that slow devnets and fast chains can be tuned without code changes.
+/// Set it with [`DaemonBuilder::polling`](crate::DaemonBuilder::polling).
+///
+/// ```rust,no_run
+/// use std::time::Duration;
+/// use cw_orch_daemon::{networks, Daemon, PollingConfig};
+///
+/// let daemon = Daemon::builder(networks::LOCAL_JUNO)
+///     .polling(
+///         PollingConfig::default()
+///             .tx_query_retries(100)
+///             .min_block_time(Duration::from_millis(200)),
+///     )
+///     .build()
+///     .unwrap();
+/// ```
+///
+/// A [`CancellationToken`] interrupts the wait loops, so that services embedding cw-orch can shut down gracefully:
+/// the pending waits return [`DaemonError::Cancelled`] instead of the thread being aborted mid-broadcast.
+///
+/// ```rust,no_run
+/// use cw_orch_daemon::{networks, Daemon, PollingConfig};
+/// use tokio_util::sync::CancellationToken;
+///
+/// let token = CancellationToken::new();
+/// let daemon = Daemon::builder(networks::LOCAL_JUNO)
+///     .polling(PollingConfig::default().cancellation(token.clone()))
+///     .build()
+///     .unwrap();
+///
+/// // On shutdown
+/// token.cancel();
+/// ```
+///
+/// On slow chains, the inclusion of the transactions can be awaited for a fixed time instead of a number of retries,
+/// or pushed by the websocket of the node (with the `websocket` feature):
+///
+/// ```rust,no_run
+/// use std::time::Duration;
+/// use cw_orch_daemon::{networks, BroadcastMode, Daemon, PollingConfig, TxConfirmation};
+///
+/// let daemon = Daemon::builder(networks::LOCAL_JUNO)
+///     .polling(
+///         PollingConfig::default()
+///             .broadcast_mode(BroadcastMode::Async)
+///             .confirmation(TxConfirmation::Interval {
+///                 interval: Duration::from_secs(2),
+///                 max_wait: Duration::from_secs(180),
+///             }),
+///     )
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Clone, Debug)]
+pub struct PollingConfig {
+    /// Number of queries before a tx is considered not found
+    pub tx_query_retries: usize,
+    /// Factor applied to the wait time after every failed tx query
+    pub tx_query_backoff: f64,
+    /// Lower bound of the estimated block time
+    pub min_block_time: Duration,
+    /// Upper bound of the estimated block time
+    pub max_block_time: Option<Duration>,
+    /// Wait time between the queries of txs by events
+    pub events_query_interval: Duration,
+    /// Number of queries of interchain txs (channel creation, packets) before giving up
+    pub ibc_query_retries: usize,
+    /// Wait time between the queries of interchain txs
+    pub ibc_query_interval: Duration,
+    /// Wait time between the queries of a proposal status
+    pub proposal_query_interval: Duration,
+    /// Time after which a proposal still in its deposit or voting period is considered stuck
+    pub proposal_max_wait: Duration,
+    /// Interrupts the wait loops once cancelled
+    pub cancellation: Option<CancellationToken>,
+    /// Mode used to broadcast the transactions
+    pub broadcast_mode: BroadcastMode,
+    /// Strategy used to wait for the inclusion of the broadcasted transactions
+    pub confirmation: TxConfirmation,
+}
+
+/// Mode used to broadcast the transactions
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BroadcastMode {
+    /// Returns once the transaction passed the mempool checks (`CheckTx`).
+    /// The errors detected by the checks are retried by the broadcast strategies.
+    #[default]
+    Sync,
+    /// Returns as soon as the transaction is sent to the node, without waiting for the mempool checks.
+    /// The failures are only detected once the transaction is confirmed.
+    Async,
+}
+
+/// Strategy used to wait for the inclusion of the broadcasted transactions
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum TxConfirmation {
+    /// Queries the transaction after the estimated block time, increased by [`PollingConfig::tx_query_backoff`]
+    /// after every failed query, up to [`PollingConfig::tx_query_retries`] queries
+    #[default]
+    Backoff,
+    /// Queries the transaction every `interval`, until `max_wait` elapsed
+    Interval {
+        /// Wait time between the queries
+        interval: Duration,
+        /// Time after which the transaction is considered not found
+        max_wait: Duration,
+    },
+    /// Waits for the transaction to be pushed by the websocket of the node's Tendermint RPC, until `max_wait` elapsed
+    #[cfg(feature = "websocket")]
+    Websocket {
+        /// Websocket endpoint of the node, i.e. `ws://localhost:26657/websocket`
+        rpc_url: String,
+        /// Time after which the transaction is considered not found
+        max_wait: Duration,
+    },
+}
+
+impl Default for PollingConfig {
+    fn default() -> Self {
+        Self {
+            tx_query_retries: DaemonEnvVars::max_tx_query_retries(),
+            tx_query_backoff: 1.6,
+            min_block_time: DaemonEnvVars::min_block_time(),
+            max_block_time: DaemonEnvVars::max_block_time(),
+            events_query_interval: Duration::from_secs(10),
+            ibc_query_retries: 5,
+            ibc_query_interval: Duration::from_secs(20),
+            proposal_query_interval: Duration::from_secs(10),
+            proposal_max_wait: Duration::from_secs(60 * 60),
+            cancellation: None,
+            broadcast_mode: BroadcastMode::default(),
+            confirmation: TxConfirmation::default(),
+        }
+    }
+}
+
+/// [`CancellationToken`] can't be compared, so the configs only compare whether they have a token and whether it was cancelled
+impl PartialEq for PollingConfig {
+    fn eq(&self, other: &Self) -> bool {
+        let Self {
+            tx_query_retries,
+            tx_query_backoff,
+            min_block_time,
+            max_block_time,
+            events_query_interval,
+            ibc_query_retries,
+            ibc_query_interval,
+            proposal_query_interval,
+            proposal_max_wait,
+            cancellation,
+            broadcast_mode,
+            confirmation,
+        } = self;
+        *tx_query_retries == other.tx_query_retries
+            && *tx_query_backoff == other.tx_query_backoff
+            && *min_block_time == other.min_block_time
+            && *max_block_time == other.max_block_time
+            && *events_query_interval == other.events_query_interval
+            && *ibc_query_retries == other.ibc_query_retries
+            && *ibc_query_interval == other.ibc_query_interval
+            && *proposal_query_interval == other.proposal_query_interval
+            && *proposal_max_wait == other.proposal_max_wait
+            && cancellation.is_some() == other.cancellation.is_some()
+            && self.is_cancelled() == other.is_cancelled()
+            && *broadcast_mode == other.broadcast_mode
+            && *confirmation == other.confirmation
+    }
+}
+
+impl PollingConfig {
+    pub fn tx_query_retries(mut self, retries: usize) -> Self {
+        self.tx_query_retries = retries;
+        self
+    }
+
+    pub fn tx_query_backoff(mut self, backoff: f64) -> Self {
+        self.tx_query_backoff = backoff;
+        self
+    }
+
+    pub fn min_block_time(mut self, block_time: Duration) -> Self {
+        self.min_block_time = block_time;
+        self
+    }
+
+    pub fn max_block_time(mut self, block_time: Duration) -> Self {
+        self.max_block_time = Some(block_time);
+        self
+    }
+
+    pub fn events_query_interval(mut self, interval: Duration) -> Self {
+        self.events_query_interval = interval;
+        self
+    }
+
+    pub fn ibc_query_retries(mut self, retries: usize) -> Self {
+        self.ibc_query_retries = retries;
+        self
+    }
+
+    pub fn ibc_query_interval(mut self, interval: Duration) -> Self {
+        self.ibc_query_interval = interval;
+        self
+    }
+
+    pub fn proposal_query_interval(mut self, interval: Duration) -> Self {
+        self.proposal_query_interval = interval;
+        self
+    }
+
+    pub fn proposal_max_wait(mut self, max_wait: Duration) -> Self {
+        self.proposal_max_wait = max_wait;
+        self
+    }
+
+    pub fn cancellation(mut self, token: CancellationToken) -> Self {
+        self.cancellation = Some(token);
+        self
+    }
+
+    pub fn broadcast_mode(mut self, mode: BroadcastMode) -> Self {
+        self.broadcast_mode = mode;
+        self
+    }
+
+    pub fn confirmation(mut self, confirmation: TxConfirmation) -> Self {
+        self.confirmation = confirmation;
+        self
+    }
+
+    /// Returns true if the cancellation token was cancelled
+    pub fn is_cancelled(&self) -> bool {
+        self.cancellation
+            .as_ref()
+            .is_some_and(CancellationToken::is_cancelled)
+    }
+
+    /// Waits for `duration`, or until the cancellation token is cancelled.
+    /// Errors with [`DaemonError::Cancelled`] if the wait was interrupted.
+    pub async fn sleep(&self, duration: Duration) -> Result<(), DaemonError> {
+        let Some(token) = &self.cancellation else {
+            tokio::time::sleep(duration).await;
+            return Ok(());
+        };
+        tokio::select! {
+            _ = token.cancelled() => Err(DaemonError::Cancelled),
+            _ = tokio::time::sleep(duration) => Ok(()),
+        }
+    }
+
+    /// Bounds an estimated block time.
+    /// The maximum takes precedence when set, otherwise the minimum applies.
+    pub fn clamp_block_time(&self, block_time: Duration) -> Duration {
+        if let Some(max_time) = self.max_block_time {
+            block_time.min(max_time)
+        } else {
+            block_time.max(self.min_block_time)
+        }
+    }
+
+    /// Wait time before the next tx query, after waiting `block_time`
+    pub(crate) fn next_tx_query_wait(&self, block_time: Duration) -> Duration {
+        let wait = block_time.mul_f64(self.tx_query_backoff);
+        match self.max_block_time {
+            Some(max_time) => wait.min(max_time),
+            None => wait,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bounds_block_time() {
+        let config = PollingConfig::default()
+            .min_block_time(Duration::from_secs(1))
+            .tx_query_backoff(2.0);
+        assert_eq!(
+            config.clamp_block_time(Duration::from_millis(300)),
+            Duration::from_secs(1)
+        );
+        assert_eq!(
+            config.next_tx_query_wait(Duration::from_secs(3)),
+            Duration::from_secs(6)
+        );
+
+        let config = config.max_block_time(Duration::from_secs(4));
+        assert_eq!(
+            config.clamp_block_time(Duration::from_millis(300)),
+            Duration::from_millis(300)
+        );
+        assert_eq!(
+            config.next_tx_query_wait(Duration::from_secs(3)),
+            Duration::from_secs(4)
+        );
+    }
+
+    #[tokio::test]
+    async fn cancels_waits() {
+        let token = CancellationToken::new();
+        let config = PollingConfig::default().cancellation(token.clone());
+        config.sleep(Duration::from_millis(1)).await.unwrap();
+
+        let handle = tokio::spawn(async move { config.sleep(Duration::from_secs(600)).await });
+        token.cancel();
+        assert!(matches!(handle.await.unwrap(), Err(DaemonError::Cancelled)));
+    }
+
+    #[test]
+    fn compares_configs() {
+        let config = PollingConfig::default();
+        assert_eq!(config, PollingConfig::default());
+        assert_ne!(config, config.clone().tx_query_backoff(2.0));
+
+        let token = CancellationToken::new();
+        let with_token = config.clone().cancellation(token.clone());
+        assert_ne!(config, with_token);
+        assert_eq!(
+            with_token,
+            config.clone().cancellation(CancellationToken::new())
+        );
+        token.cancel();
+        assert_ne!(with_token, config.cancellation(CancellationToken::new()));
+    }
+}
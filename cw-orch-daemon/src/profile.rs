@@ -0,0 +1,215 @@
+//! Named configuration profiles read from the `cw-orch.toml` file of a project.
+//!
+//! A profile groups the settings otherwise passed through environment variables, so that switching between
+//! a local, testnet or mainnet setup is a matter of picking a profile name:
+//! ```toml
+//! [profiles.testnet]
+//! chain = "pion-1"
+//! mnemonic_env = "TEST_MNEMONIC"
+//! artifacts_dir = "artifacts"
+//! state_file = "state/testnet.json"
+//!
+//! [profiles.mainnet]
+//! chain = "neutron-1"
+//! mnemonic_env = "MAINNET_DEPLOYER_MNEMONIC"
+//! gas_price = 0.075
+//! state_file = "state/mainnet.json"
+//! deployment_id = "v1"
+//! ```
+//! Relative paths are resolved from the directory of the `cw-orch.toml` file.
+//!
+//! Profiles are loaded with [`DaemonBuilder::from_profile`](crate::DaemonBuilder::from_profile).
+//! The artifacts dir is not applied to the daemon, look up the wasm files with [`Profile::artifacts_dir`].
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
+
+use cw_orch_core::{contract::ArtifactsDir, environment::ChainInfoOwned};
+use serde::Deserialize;
+
+use crate::{network_config, networks::parse_network, DaemonError};
+
+/// Name of the project configuration file
+pub const PROFILE_FILE: &str = "cw-orch.toml";
+
+#[derive(Deserialize)]
+struct ProfileFile {
+    #[serde(default)]
+    profiles: HashMap<String, Profile>,
+}
+
+/// Settings of a named environment
+#[derive(Deserialize, Clone, Debug, Default, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct Profile {
+    /// Chain id, of a known network or of a network defined in `~/.cw-orchestrator/networks.toml`
+    pub chain: String,
+    /// Environment variable holding the mnemonic of the default wallet
+    pub mnemonic_env: Option<String>,
+    /// Directory of the wasm files
+    pub artifacts_dir: Option<PathBuf>,
+    /// Denom used to pay for gas
+    pub gas_denom: Option<String>,
+    /// Gas price
+    pub gas_price: Option<f64>,
+    /// Path of the state file
+    pub state_file: Option<PathBuf>,
+    /// Deployment id
+    pub deployment_id: Option<String>,
+}
+
+impl Profile {
+    /// Loads the profile `name` from the `cw-orch.toml` file of the current directory or one of its parents
+    pub fn load(name: &str) -> Result<Self, DaemonError> {
+        let mut dir = std::env::current_dir()?;
+        loop {
+            let path = dir.join(PROFILE_FILE);
+            if path.is_file() {
+                return Self::load_from(&path, name);
+            }
+            if !dir.pop() {
+                return Err(DaemonError::StdErr(format!(
+                    "No {PROFILE_FILE} found in the current directory or its parents"
+                )));
+            }
+        }
+    }
+
+    /// Loads the profile `name` from the configuration file at `path`
+    pub fn load_from(path: &Path, name: &str) -> Result<Self, DaemonError> {
+        let content = std::fs::read_to_string(path)?;
+        let config_dir = path.parent().unwrap_or(Path::new("."));
+        Self::parse(&content, name, config_dir)
+    }
+
+    fn parse(content: &str, name: &str, config_dir: &Path) -> Result<Self, DaemonError> {
+        let mut file: ProfileFile = toml::from_str(content)
+            .map_err(|e| DaemonError::StdErr(format!("Invalid {PROFILE_FILE}: {e}")))?;
+        let mut profile = file.profiles.remove(name).ok_or_else(|| {
+            DaemonError::StdErr(format!("Profile {name} not found in {PROFILE_FILE}"))
+        })?;
+
+        let resolve = |path: &mut Option<PathBuf>| {
+            if let Some(path) = path.as_mut().filter(|path| path.is_relative()) {
+                *path = config_dir.join(&*path);
+            }
+        };
+        resolve(&mut profile.artifacts_dir);
+        resolve(&mut profile.state_file);
+        Ok(profile)
+    }
+
+    /// Chain of the profile, with its gas settings
+    pub fn chain_info(&self) -> Result<ChainInfoOwned, DaemonError> {
+        let mut chain_info: ChainInfoOwned = match parse_network(&self.chain) {
+            Ok(chain_info) => chain_info.into(),
+            Err(_) => network_config::read_network_config(&self.chain).ok_or_else(|| {
+                DaemonError::StdErr(format!("Unknown chain {} in profile", self.chain))
+            })?,
+        };
+        if let Some(gas_denom) = &self.gas_denom {
+            chain_info.gas_denom = gas_denom.clone();
+        }
+        if let Some(gas_price) = self.gas_price {
+            chain_info.gas_price = gas_price;
+        }
+        Ok(chain_info)
+    }
+
+    /// Mnemonic of the default wallet, read from the environment variable of the profile
+    pub fn mnemonic(&self) -> Result<Option<String>, DaemonError> {
+        self.mnemonic_env
+            .as_ref()
+            .map(|var| {
+                std::env::var(var).map_err(|_| {
+                    DaemonError::StdErr(format!(
+                        "Environment variable {var} of the profile not set"
+                    ))
+                })
+            })
+            .transpose()
+    }
+
+    /// Artifacts dir of the profile, to look up the wasm files of the contracts
+    pub fn artifacts_dir(&self) -> Result<Option<ArtifactsDir>, DaemonError> {
+        self.artifacts_dir
+            .as_ref()
+            .map(|dir| {
+                if dir.is_dir() {
+                    Ok(ArtifactsDir::new(dir))
+                } else {
+                    Err(DaemonError::StdErr(format!(
+                        "Artifacts dir {} of the profile not found",
+                        dir.display()
+                    )))
+                }
+            })
+            .transpose()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{DaemonAsyncBuilder, DaemonBuilder};
+
+    const CONFIG: &str = r#"
+        [profiles.local]
+        chain = "testing"
+
+        [profiles.mainnet]
+        chain = "juno-1"
+        mnemonic_env = "MAINNET_DEPLOYER"
+        artifacts_dir = "artifacts"
+        gas_price = 0.1
+        state_file = "/tmp/state.json"
+        deployment_id = "v1"
+    "#;
+
+    #[test]
+    fn parses_profiles() {
+        let profile = Profile::parse(CONFIG, "mainnet", Path::new("/project")).unwrap();
+        assert_eq!(
+            profile,
+            Profile {
+                chain: "juno-1".to_string(),
+                mnemonic_env: Some("MAINNET_DEPLOYER".to_string()),
+                artifacts_dir: Some(PathBuf::from("/project/artifacts")),
+                gas_denom: None,
+                gas_price: Some(0.1),
+                state_file: Some(PathBuf::from("/tmp/state.json")),
+                deployment_id: Some("v1".to_string()),
+            }
+        );
+
+        let chain_info = profile.chain_info().unwrap();
+        assert_eq!(chain_info.chain_id, "juno-1");
+        assert_eq!(chain_info.gas_price, 0.1);
+
+        assert!(Profile::parse(CONFIG, "testnet", Path::new("/project")).is_err());
+    }
+
+    #[test]
+    fn configures_builders() {
+        let profile = Profile {
+            chain: "juno-1".to_string(),
+            gas_price: Some(0.1),
+            state_file: Some(PathBuf::from("/tmp/state.json")),
+            deployment_id: Some("v1".to_string()),
+            ..Default::default()
+        };
+
+        let builder = DaemonAsyncBuilder::with_profile(&profile).unwrap();
+        assert_eq!(builder.chain.chain_id, "juno-1");
+        assert_eq!(builder.chain.gas_price, 0.1);
+        assert_eq!(builder.deployment_id.as_deref(), Some("v1"));
+        assert_eq!(builder.state_path.as_deref(), Some("/tmp/state.json"));
+        assert_eq!(builder.mnemonic, None);
+
+        let builder = DaemonBuilder::with_profile(&profile).unwrap();
+        assert_eq!(builder.chain.chain_id, "juno-1");
+        assert_eq!(builder.deployment_id.as_deref(), Some("v1"));
+        assert_eq!(builder.state_path.as_deref(), Some("/tmp/state.json"));
+    }
+}
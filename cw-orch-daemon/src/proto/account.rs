@@ -0,0 +1,246 @@
+//! Unpacking of the accounts returned by the auth module.
+//!
+//! The auth module returns the account as a protobuf `Any` whose type depends on the chain:
+//! vesting accounts, module accounts or the `EthAccount` of the EVM chains (Injective, Evmos...).
+//! [`Account`] decodes them based on their type url, along with their public key, which can be a multisig or a secp256r1 key.
+use cosmrs::proto::{cosmos::crypto::multisig::LegacyAminoPubKey, traits::Message, Any};
+
+use crate::{
+    cosmos_modules::{
+        auth::{BaseAccount, ModuleAccount},
+        vesting::{
+            BaseVestingAccount, ContinuousVestingAccount, DelayedVestingAccount,
+            PeriodicVestingAccount, PermanentLockedAccount,
+        },
+    },
+    DaemonError,
+};
+
+use super::injective::InjectiveEthAccount;
+
+/// Account returned by the auth module
+#[derive(Clone, Debug, PartialEq)]
+pub enum Account {
+    Base(BaseAccount),
+    Module(ModuleAccount),
+    ContinuousVesting(ContinuousVestingAccount),
+    DelayedVesting(DelayedVestingAccount),
+    PeriodicVesting(PeriodicVestingAccount),
+    PermanentLocked(PermanentLockedAccount),
+    /// Account of the EVM chains (Injective, Evmos and the other ethermint chains)
+    Eth(InjectiveEthAccount),
+    /// Account of an unknown type, wrapping its base account in its first field
+    Other {
+        type_url: String,
+        base_account: BaseAccount,
+    },
+}
+
+/// Public key of an account
+#[derive(Clone, Debug, PartialEq)]
+pub enum AccountPubKey {
+    Secp256k1(Vec<u8>),
+    Secp256r1(Vec<u8>),
+    Ed25519(Vec<u8>),
+    /// Key of the EVM chains accounts
+    EthSecp256k1(Vec<u8>),
+    /// Legacy amino multisig, signing with `threshold` of the `public_keys`
+    Multisig {
+        threshold: u32,
+        public_keys: Vec<AccountPubKey>,
+    },
+    /// Key of an unknown type
+    Other {
+        type_url: String,
+        value: Vec<u8>,
+    },
+}
+
+/// Layout shared by all the single public keys
+#[derive(Clone, PartialEq, ::prost::Message)]
+struct SinglePubKey {
+    #[prost(bytes, tag = "1")]
+    key: Vec<u8>,
+}
+
+impl Account {
+    /// Decodes an account returned by the auth module, based on its type url
+    pub fn decode(account: Any) -> Result<Self, DaemonError> {
+        let value = account.value.as_slice();
+        let account = match account.type_url.as_str() {
+            "/cosmos.auth.v1beta1.BaseAccount" => Account::Base(BaseAccount::decode(value)?),
+            "/cosmos.auth.v1beta1.ModuleAccount" => Account::Module(ModuleAccount::decode(value)?),
+            "/cosmos.vesting.v1beta1.ContinuousVestingAccount" => {
+                Account::ContinuousVesting(ContinuousVestingAccount::decode(value)?)
+            }
+            "/cosmos.vesting.v1beta1.DelayedVestingAccount" => {
+                Account::DelayedVesting(DelayedVestingAccount::decode(value)?)
+            }
+            "/cosmos.vesting.v1beta1.PeriodicVestingAccount" => {
+                Account::PeriodicVesting(PeriodicVestingAccount::decode(value)?)
+            }
+            "/cosmos.vesting.v1beta1.PermanentLockedAccount" => {
+                Account::PermanentLocked(PermanentLockedAccount::decode(value)?)
+            }
+            // Injective and the ethermint chains share the same layout
+            "/injective.types.v1beta1.EthAccount" | "/ethermint.types.v1.EthAccount" => {
+                Account::Eth(InjectiveEthAccount::decode(value)?)
+            }
+            type_url => {
+                log::debug!(
+                    "Unknown account type {type_url}, decoding its first field as the base account"
+                );
+                let base_account = InjectiveEthAccount::decode(value)
+                    .ok()
+                    .and_then(|acc| acc.base_account)
+                    .ok_or_else(|| {
+                        DaemonError::StdErr(format!(
+                            "Unknown account type returned from QueryAccountRequest: {type_url}"
+                        ))
+                    })?;
+                Account::Other {
+                    type_url: type_url.to_string(),
+                    base_account,
+                }
+            }
+        };
+        Ok(account)
+    }
+
+    /// Base account, holding the address, the account number and the sequence
+    pub fn base_account(&self) -> Result<&BaseAccount, DaemonError> {
+        let base_account = match self {
+            Account::Base(acc) => Some(acc),
+            Account::Module(acc) => acc.base_account.as_ref(),
+            Account::ContinuousVesting(acc) => vesting_base(&acc.base_vesting_account),
+            Account::DelayedVesting(acc) => vesting_base(&acc.base_vesting_account),
+            Account::PeriodicVesting(acc) => vesting_base(&acc.base_vesting_account),
+            Account::PermanentLocked(acc) => vesting_base(&acc.base_vesting_account),
+            Account::Eth(acc) => acc.base_account.as_ref(),
+            Account::Other { base_account, .. } => Some(base_account),
+        };
+        base_account.ok_or_else(|| DaemonError::StdErr("Account without base account".into()))
+    }
+
+    /// Public key of the account, if it already signed a transaction
+    pub fn pub_key(&self) -> Result<Option<AccountPubKey>, DaemonError> {
+        self.base_account()?
+            .pub_key
+            .clone()
+            .map(AccountPubKey::decode)
+            .transpose()
+    }
+}
+
+impl AccountPubKey {
+    /// Decodes a public key based on its type url
+    pub fn decode(key: Any) -> Result<Self, DaemonError> {
+        let single_key = || SinglePubKey::decode(key.value.as_slice()).map(|pk| pk.key);
+        let key = match key.type_url.as_str() {
+            "/cosmos.crypto.secp256k1.PubKey" => AccountPubKey::Secp256k1(single_key()?),
+            "/cosmos.crypto.secp256r1.PubKey" => AccountPubKey::Secp256r1(single_key()?),
+            "/cosmos.crypto.ed25519.PubKey" => AccountPubKey::Ed25519(single_key()?),
+            "/injective.crypto.v1beta1.ethsecp256k1.PubKey"
+            | "/ethermint.crypto.v1.ethsecp256k1.PubKey" => {
+                AccountPubKey::EthSecp256k1(single_key()?)
+            }
+            "/cosmos.crypto.multisig.LegacyAminoPubKey" => {
+                let multisig = LegacyAminoPubKey::decode(key.value.as_slice())?;
+                AccountPubKey::Multisig {
+                    threshold: multisig.threshold,
+                    public_keys: multisig
+                        .public_keys
+                        .into_iter()
+                        .map(AccountPubKey::decode)
+                        .collect::<Result<_, _>>()?,
+                }
+            }
+            type_url => AccountPubKey::Other {
+                type_url: type_url.to_string(),
+                value: key.value,
+            },
+        };
+        Ok(key)
+    }
+}
+
+/// Extracts the base account of an account returned by the auth module
+pub fn base_account(account: Any) -> Result<BaseAccount, DaemonError> {
+    Account::decode(account)?.base_account().cloned()
+}
+
+fn vesting_base(account: &Option<BaseVestingAccount>) -> Option<&BaseAccount> {
+    account.as_ref()?.base_account.as_ref()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn single_key(type_url: &str, key: &[u8]) -> Any {
+        Any {
+            type_url: type_url.to_string(),
+            value: SinglePubKey { key: key.to_vec() }.encode_to_vec(),
+        }
+    }
+
+    fn base(pub_key: Option<Any>) -> BaseAccount {
+        BaseAccount {
+            address: "juno1vesting".to_string(),
+            pub_key,
+            account_number: 12,
+            sequence: 4,
+        }
+    }
+
+    #[test]
+    fn unpacks_vesting_accounts() -> Result<(), DaemonError> {
+        let vesting = ContinuousVestingAccount {
+            base_vesting_account: Some(BaseVestingAccount {
+                base_account: Some(base(None)),
+                ..Default::default()
+            }),
+            start_time: 1,
+        };
+        let account = base_account(Any {
+            type_url: "/cosmos.vesting.v1beta1.ContinuousVestingAccount".to_string(),
+            value: vesting.encode_to_vec(),
+        })?;
+        assert_eq!(account, base(None));
+
+        let account = base_account(Any {
+            type_url: "/cosmos.auth.v1beta1.BaseAccount".to_string(),
+            value: base(None).encode_to_vec(),
+        })?;
+        assert_eq!(account, base(None));
+        Ok(())
+    }
+
+    #[test]
+    fn decodes_multisig_pub_keys() -> Result<(), DaemonError> {
+        let multisig = Any {
+            type_url: "/cosmos.crypto.multisig.LegacyAminoPubKey".to_string(),
+            value: LegacyAminoPubKey {
+                threshold: 2,
+                public_keys: vec![
+                    single_key("/cosmos.crypto.secp256k1.PubKey", &[1; 33]),
+                    single_key("/cosmos.crypto.secp256r1.PubKey", &[2; 33]),
+                ],
+            }
+            .encode_to_vec(),
+        };
+        let account = Account::Base(base(Some(multisig)));
+
+        assert_eq!(
+            account.pub_key()?,
+            Some(AccountPubKey::Multisig {
+                threshold: 2,
+                public_keys: vec![
+                    AccountPubKey::Secp256k1(vec![1; 33]),
+                    AccountPubKey::Secp256r1(vec![2; 33]),
+                ],
+            })
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,303 @@
+//! EIP-712 signing for Injective.
+//!
+//! Accounts created through Metamask-based flows can only sign EIP-712 typed data.
+//! Injective wraps the transaction into typed data (format v2) made of two strings:
+//! - `context`: the sorted JSON of the sign doc without its messages.
+//! - `msgs`: the JSON of the messages.
+//!
+//! The transaction carries an `ExtensionOptionsWeb3Tx` with the Ethereum chain id used in the typed data domain,
+//! and its signer infos use the EIP-712 sign mode.
+#![allow(missing_docs)]
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use cosmrs::{
+    proto::cosmos::tx::v1beta1::{mode_info, AuthInfo, ModeInfo, TxBody, TxRaw},
+    tx::{Raw, SignDoc},
+    Any,
+};
+use ethers_core::utils::keccak256;
+use prost::{Message, Name};
+use serde_json::{json, Value};
+
+use crate::{cosmos_modules, keys::private::PrivateKey, DaemonError};
+
+/// Sign mode of EIP-712 (v2) signatures on Injective
+pub const SIGN_MODE_EIP712_V2: i32 = 128;
+
+const DOMAIN_TYPE: &str =
+    "EIP712Domain(string name,string version,uint256 chainId,string verifyingContract,string salt)";
+const TX_TYPE: &str = "Tx(string context,string msgs)";
+const DOMAIN_NAME: &str = "Injective Web3";
+const DOMAIN_VERSION: &str = "1.0.0";
+const DOMAIN_VERIFYING_CONTRACT: &str = "cosmos";
+const DOMAIN_SALT: &str = "0";
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExtensionOptionsWeb3Tx {
+    #[prost(uint64, tag = "1")]
+    pub typed_data_chain_id: u64,
+    #[prost(string, tag = "2")]
+    pub fee_payer: String,
+    #[prost(bytes, tag = "3")]
+    pub fee_payer_sig: Vec<u8>,
+}
+
+impl Name for ExtensionOptionsWeb3Tx {
+    const NAME: &'static str = "ExtensionOptionsWeb3Tx";
+    const PACKAGE: &'static str = "injective.types.v1beta1";
+}
+
+pub trait Eip712Signer {
+    /// Signs the transaction as EIP-712 typed data, for the given Ethereum chain id
+    fn sign_eip712(&self, sign_doc: SignDoc, eth_chain_id: u64) -> Result<Raw, DaemonError>;
+}
+
+impl Eip712Signer for PrivateKey {
+    fn sign_eip712(&self, sign_doc: SignDoc, eth_chain_id: u64) -> Result<Raw, DaemonError> {
+        let mut body = TxBody::decode(sign_doc.body_bytes.as_slice())?;
+        body.extension_options = vec![Any {
+            type_url: ExtensionOptionsWeb3Tx::type_url(),
+            value: ExtensionOptionsWeb3Tx {
+                typed_data_chain_id: eth_chain_id,
+                fee_payer: String::new(),
+                fee_payer_sig: vec![],
+            }
+            .encode_to_vec(),
+        }];
+
+        let mut auth_info = AuthInfo::decode(sign_doc.auth_info_bytes.as_slice())?;
+        for signer_info in auth_info.signer_infos.iter_mut() {
+            signer_info.mode_info = Some(ModeInfo {
+                sum: Some(mode_info::Sum::Single(mode_info::Single {
+                    mode: SIGN_MODE_EIP712_V2,
+                })),
+            });
+        }
+
+        let digest = typed_data_digest(
+            &body,
+            &auth_info,
+            &sign_doc.chain_id,
+            sign_doc.account_number,
+            eth_chain_id,
+        )?;
+        let eth_signature = ethers_signers::Wallet::from_bytes(&self.raw_key())
+            .map_err(|e| DaemonError::StdErr(e.to_string()))?
+            .sign_hash(digest.into())
+            .map_err(|e| DaemonError::StdErr(e.to_string()))?;
+
+        Ok(TxRaw {
+            body_bytes: body.encode_to_vec(),
+            auth_info_bytes: auth_info.encode_to_vec(),
+            signatures: vec![eth_signature.to_vec()],
+        }
+        .into())
+    }
+}
+
+/// Hash signed by the account: `keccak256(0x1901 || domainSeparator || hashStruct(tx))`
+fn typed_data_digest(
+    body: &TxBody,
+    auth_info: &AuthInfo,
+    chain_id: &str,
+    account_number: u64,
+    eth_chain_id: u64,
+) -> Result<[u8; 32], DaemonError> {
+    let (context, msgs) = typed_data_message(body, auth_info, chain_id, account_number)?;
+
+    let mut chain_id_word = [0u8; 32];
+    chain_id_word[24..].copy_from_slice(&eth_chain_id.to_be_bytes());
+    let domain_separator = keccak256(
+        [
+            keccak256(DOMAIN_TYPE),
+            keccak256(DOMAIN_NAME),
+            keccak256(DOMAIN_VERSION),
+            chain_id_word,
+            keccak256(DOMAIN_VERIFYING_CONTRACT),
+            keccak256(DOMAIN_SALT),
+        ]
+        .concat(),
+    );
+    let struct_hash = keccak256([keccak256(TX_TYPE), keccak256(context), keccak256(msgs)].concat());
+
+    Ok(keccak256(
+        [&[0x19u8, 0x01][..], &domain_separator, &struct_hash].concat(),
+    ))
+}
+
+/// Returns the `context` and `msgs` strings of the typed data
+fn typed_data_message(
+    body: &TxBody,
+    auth_info: &AuthInfo,
+    chain_id: &str,
+    account_number: u64,
+) -> Result<(String, String), DaemonError> {
+    let fee = auth_info.fee.clone().unwrap_or_default();
+    let sequence = auth_info
+        .signer_infos
+        .first()
+        .map(|signer_info| signer_info.sequence)
+        .unwrap_or_default();
+
+    let mut fee_json = json!({
+        "amount": fee.amount.iter().map(|coin| json!({"denom": coin.denom, "amount": coin.amount})).collect::<Vec<_>>(),
+        "gas": fee.gas_limit.to_string(),
+    });
+    if !fee.granter.is_empty() {
+        fee_json["granter"] = fee.granter.into();
+    }
+    if !fee.payer.is_empty() {
+        fee_json["payer"] = fee.payer.into();
+    }
+
+    // `serde_json` sorts the keys, as the chain does
+    let context = json!({
+        "account_number": account_number.to_string(),
+        "chain_id": chain_id,
+        "fee": fee_json,
+        "memo": body.memo,
+        "sequence": sequence.to_string(),
+        "timeout_height": body.timeout_height.to_string(),
+    });
+    let msgs = body
+        .messages
+        .iter()
+        .map(msg_json)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok((
+        serde_json::to_string(&context)?,
+        serde_json::to_string(&msgs)?,
+    ))
+}
+
+/// Proto JSON of the messages supported in EIP-712 transactions
+fn msg_json(any: &Any) -> Result<Value, DaemonError> {
+    use cosmos_modules::{
+        bank::MsgSend,
+        cosmwasm::{
+            MsgExecuteContract, MsgInstantiateContract, MsgInstantiateContract2,
+            MsgMigrateContract, MsgStoreCode,
+        },
+    };
+
+    let coins = |coins: &[cosmrs::proto::cosmos::base::v1beta1::Coin]| {
+        coins
+            .iter()
+            .map(|coin| json!({"denom": coin.denom, "amount": coin.amount}))
+            .collect::<Vec<_>>()
+    };
+    let inner_msg = |msg: &[u8]| -> Result<Value, DaemonError> { Ok(serde_json::from_slice(msg)?) };
+
+    let mut json = if any.type_url == MsgExecuteContract::type_url() {
+        let msg = MsgExecuteContract::decode(any.value.as_slice())?;
+        json!({
+            "sender": msg.sender,
+            "contract": msg.contract,
+            "msg": inner_msg(&msg.msg)?,
+            "funds": coins(&msg.funds),
+        })
+    } else if any.type_url == MsgInstantiateContract::type_url() {
+        let msg = MsgInstantiateContract::decode(any.value.as_slice())?;
+        json!({
+            "sender": msg.sender,
+            "admin": msg.admin,
+            "code_id": msg.code_id.to_string(),
+            "label": msg.label,
+            "msg": inner_msg(&msg.msg)?,
+            "funds": coins(&msg.funds),
+        })
+    } else if any.type_url == MsgInstantiateContract2::type_url() {
+        let msg = MsgInstantiateContract2::decode(any.value.as_slice())?;
+        json!({
+            "sender": msg.sender,
+            "admin": msg.admin,
+            "code_id": msg.code_id.to_string(),
+            "label": msg.label,
+            "msg": inner_msg(&msg.msg)?,
+            "funds": coins(&msg.funds),
+            "salt": STANDARD.encode(&msg.salt),
+            "fix_msg": msg.fix_msg,
+        })
+    } else if any.type_url == MsgMigrateContract::type_url() {
+        let msg = MsgMigrateContract::decode(any.value.as_slice())?;
+        json!({
+            "sender": msg.sender,
+            "contract": msg.contract,
+            "code_id": msg.code_id.to_string(),
+            "msg": inner_msg(&msg.msg)?,
+        })
+    } else if any.type_url == MsgStoreCode::type_url() {
+        let msg = MsgStoreCode::decode(any.value.as_slice())?;
+        json!({
+            "sender": msg.sender,
+            "wasm_byte_code": STANDARD.encode(&msg.wasm_byte_code),
+            "instantiate_permission": Value::Null,
+        })
+    } else if any.type_url == MsgSend::type_url() {
+        let msg = MsgSend::decode(any.value.as_slice())?;
+        json!({
+            "from_address": msg.from_address,
+            "to_address": msg.to_address,
+            "amount": coins(&msg.amount),
+        })
+    } else {
+        return Err(DaemonError::StdErr(format!(
+            "Message {} is not supported in EIP-712 transactions",
+            any.type_url
+        )));
+    };
+    json["@type"] = any.type_url.clone().into();
+    Ok(json)
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmrs::proto::cosmos::{
+        base::v1beta1::Coin,
+        tx::v1beta1::{Fee, SignerInfo},
+    };
+
+    use super::*;
+
+    #[test]
+    fn builds_typed_data_message() {
+        let execute = cosmos_modules::cosmwasm::MsgExecuteContract {
+            sender: "inj1sender".to_string(),
+            contract: "inj1contract".to_string(),
+            msg: br#"{"increment":{}}"#.to_vec(),
+            funds: vec![],
+        };
+        let body = TxBody {
+            messages: vec![Any::from_msg(&execute).unwrap()],
+            memo: "memo".to_string(),
+            timeout_height: 0,
+            ..Default::default()
+        };
+        let auth_info = AuthInfo {
+            signer_infos: vec![SignerInfo {
+                sequence: 4,
+                ..Default::default()
+            }],
+            fee: Some(Fee {
+                amount: vec![Coin {
+                    denom: "inj".to_string(),
+                    amount: "100".to_string(),
+                }],
+                gas_limit: 200_000,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let (context, msgs) = typed_data_message(&body, &auth_info, "injective-1", 12).unwrap();
+        assert_eq!(
+            context,
+            r#"{"account_number":"12","chain_id":"injective-1","fee":{"amount":[{"amount":"100","denom":"inj"}],"gas":"200000"},"memo":"memo","sequence":"4","timeout_height":"0"}"#
+        );
+        assert_eq!(
+            msgs,
+            r#"[{"@type":"/cosmwasm.wasm.v1.MsgExecuteContract","contract":"inj1contract","funds":[],"msg":{"increment":{}},"sender":"inj1sender"}]"#
+        );
+    }
+}
@@ -1 +1,4 @@
+pub mod account;
+#[cfg(feature = "eth")]
+pub mod eip712;
 pub mod injective;
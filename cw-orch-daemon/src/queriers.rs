@@ -22,6 +22,7 @@
 //! ```
 
 /// macro for constructing and performing a query on a CosmosSDK module.
+/// Queries rejected by the rate limiting of the provider are retried after the wait it asks for.
 #[macro_export]
 macro_rules! cosmos_query {
     ($self:ident, $module:ident, $func_name:ident, $request_type:ident { $($field:ident : $value:expr),* $(,)?  }) => {
@@ -32,7 +33,17 @@ macro_rules! cosmos_query {
         let mut client = QueryClient::new($self.channel.clone());
         #[allow(clippy::redundant_field_names)]
         let request = $request_type { $($field : $value),* };
-        let response = client.$func_name(request.clone()).await?.into_inner();
+        let mut attempt = 0;
+        let response = loop {
+            match client.$func_name(request.clone()).await {
+                Ok(response) => break response.into_inner(),
+                Err(status) => {
+                    if !$crate::wait_rate_limited(&status, &mut attempt).await {
+                        return Err(status.into());
+                    }
+                }
+            }
+        };
         ::log::trace!(
             "cosmos_query: {:?} resulted in: {:?}",
             request,
@@ -43,23 +54,85 @@ macro_rules! cosmos_query {
 };
 }
 
+mod auth;
 mod authz;
 mod bank;
+mod cache;
 mod cosmwasm;
 mod env;
 mod feegrant;
 mod gov;
 mod ibc;
+#[cfg(feature = "injective")]
+mod injective;
+#[cfg(feature = "neutron")]
+mod neutron;
 mod node;
+#[cfg(feature = "osmosis")]
+mod osmosis;
+mod params;
+mod slinky;
 mod staking;
+mod vesting;
 
+pub use auth::Auth;
 pub use authz::Authz;
 pub use bank::{cosmrs_to_cosmwasm_coins, Bank};
-pub use cosmwasm::{CosmWasm, CosmWasmBase};
+pub use cache::{Freshness, QueryCache};
+pub use cosmwasm::{BatchResponse, CosmWasm, CosmWasmBase, QueryBatch};
 pub use feegrant::FeeGrant;
-pub use ibc::Ibc;
-pub use node::Node;
+pub use ibc::{Ibc, LightClientStatus};
+#[cfg(feature = "injective")]
+pub use injective::{proto as injective_proto, Wasmx};
+#[cfg(feature = "neutron")]
+pub use neutron::{proto as neutron_proto, Dex, InterchainQueries};
+#[cfg(feature = "indexer")]
+pub(crate) use node::{is_last_tx_page, TX_SEARCH_PAGE_LIMIT};
+pub use node::{NetInfo, Node, NodePeer};
+#[cfg(feature = "osmosis")]
+pub use osmosis::{proto as osmosis_proto, Gamm, PoolManager};
+pub use params::{ChainParams, Params};
+pub use slinky::{proto as slinky_proto, MarketMap, Oracle};
+pub use vesting::{Vesting, VestingAccount, VestingPeriod, VestingSchedule};
 
 // this two containt structs that are helpers for the queries
 pub use gov::*;
 pub use staking::*;
+
+use crate::error::DaemonError;
+use prost::Message;
+use tonic::{codec::ProstCodec, codegen::http::uri::PathAndQuery, transport::Channel};
+
+/// Sends a unary gRPC query to `path`
+pub(crate) async fn grpc_query<Req, Res>(
+    channel: &Channel,
+    path: &'static str,
+    request: Req,
+) -> Result<Res, DaemonError>
+where
+    Req: Message + Clone + Send + Sync + 'static,
+    Res: Message + Default + Send + Sync + 'static,
+{
+    let mut grpc = tonic::client::Grpc::new(channel.clone());
+    let mut attempt = 0;
+    let response = loop {
+        grpc.ready().await?;
+        match grpc
+            .unary(
+                tonic::Request::new(request.clone()),
+                PathAndQuery::from_static(path),
+                ProstCodec::default(),
+            )
+            .await
+        {
+            Ok(response) => break response.into_inner(),
+            Err(status) => {
+                if !crate::wait_rate_limited(&status, &mut attempt).await {
+                    return Err(status.into());
+                }
+            }
+        }
+    };
+    ::log::trace!("grpc_query: {} resulted in: {:?}", path, response);
+    Ok(response)
+}
@@ -0,0 +1,94 @@
+use crate::{
+    cosmos_modules, error::DaemonError, proto::account::Account, senders::query::QuerySender,
+    DaemonBase,
+};
+use cosmrs::proto::cosmos::base::query::v1beta1::PageRequest;
+use cosmwasm_std::Addr;
+use cw_orch_core::environment::{Querier, QuerierGetter};
+use tokio::runtime::Handle;
+use tonic::transport::Channel;
+
+/// Querier for the Cosmos Auth module
+/// All the async function are prefixed with `_`
+pub struct Auth {
+    pub channel: Channel,
+    pub rt_handle: Option<Handle>,
+}
+
+impl Auth {
+    pub fn new<Sender: QuerySender>(daemon: &DaemonBase<Sender>) -> Self {
+        Self {
+            channel: daemon.channel(),
+            rt_handle: Some(daemon.rt_handle.clone()),
+        }
+    }
+
+    pub fn new_async(channel: Channel) -> Self {
+        Self {
+            channel,
+            rt_handle: None,
+        }
+    }
+}
+
+impl Querier for Auth {
+    type Error = DaemonError;
+}
+
+impl<Sender: QuerySender> QuerierGetter<Auth> for DaemonBase<Sender> {
+    fn querier(&self) -> Auth {
+        Auth::new(self)
+    }
+}
+
+impl Auth {
+    /// Query the account of an address, decoded based on its type (vesting, module, eth account...)
+    pub fn account(&self, address: &Addr) -> Result<Account, DaemonError> {
+        self.rt_handle
+            .as_ref()
+            .ok_or(DaemonError::QuerierNeedRuntime)?
+            .block_on(self._account(address))
+    }
+
+    /// Query the account of an address, decoded based on its type (vesting, module, eth account...)
+    pub async fn _account(&self, address: &Addr) -> Result<Account, DaemonError> {
+        let resp: cosmos_modules::auth::QueryAccountResponse = cosmos_query!(
+            self,
+            auth,
+            account,
+            QueryAccountRequest {
+                address: address.to_string()
+            }
+        );
+        let account = resp.account.ok_or(DaemonError::StdErr(format!(
+            "No account found for {address}"
+        )))?;
+        Account::decode(account)
+    }
+
+    /// Synchronous version of [`Auth::_accounts`]
+    pub fn accounts(&self, pagination: Option<PageRequest>) -> Result<Vec<Account>, DaemonError> {
+        self.rt_handle
+            .as_ref()
+            .ok_or(DaemonError::QuerierNeedRuntime)?
+            .block_on(self._accounts(pagination))
+    }
+
+    /// Query all the accounts of the chain with a given pagination
+    ///
+    /// see [PageRequest] for pagination
+    pub async fn _accounts(
+        &self,
+        pagination: Option<PageRequest>,
+    ) -> Result<Vec<Account>, DaemonError> {
+        let resp: cosmos_modules::auth::QueryAccountsResponse = cosmos_query!(
+            self,
+            auth,
+            accounts,
+            QueryAccountsRequest {
+                pagination: pagination
+            }
+        );
+        resp.accounts.into_iter().map(Account::decode).collect()
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{cosmos_modules, error::DaemonError, Daemon};
+use crate::{cosmos_modules, error::DaemonError, senders::query::QuerySender, DaemonBase};
 use cosmrs::proto::cosmos::base::query::v1beta1::PageRequest;
 use cosmwasm_std::Addr;
 use cw_orch_core::environment::{Querier, QuerierGetter};
@@ -13,7 +13,7 @@ pub struct Authz {
 }
 
 impl Authz {
-    pub fn new(daemon: &Daemon) -> Self {
+    pub fn new<Sender: QuerySender>(daemon: &DaemonBase<Sender>) -> Self {
         Self {
             channel: daemon.channel(),
             rt_handle: Some(daemon.rt_handle.clone()),
@@ -32,7 +32,7 @@ impl Querier for Authz {
     type Error = DaemonError;
 }
 
-impl QuerierGetter<Authz> for Daemon {
+impl<Sender: QuerySender> QuerierGetter<Authz> for DaemonBase<Sender> {
     fn querier(&self) -> Authz {
         Authz::new(self)
     }
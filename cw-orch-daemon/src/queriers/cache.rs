@@ -0,0 +1,172 @@
+use std::{
+    any::Any,
+    collections::HashMap,
+    future::Future,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use crate::DaemonError;
+
+/// Cache of the responses of expensive queries that are repeated during deployments (code hashes, contract infos, chain params).
+///
+/// The cache is shared by the clones of the daemon it is set on and by all the queriers built from them.
+/// Entries expire after the TTL. The entries that can change on chain are also dropped once the daemon broadcasts a transaction,
+/// so that a contract migrated by the daemon is never served from the cache.
+///
+/// ```rust,no_run
+/// use std::time::Duration;
+/// use cw_orch_daemon::{networks, queriers::QueryCache, Daemon};
+///
+/// let daemon = Daemon::builder(networks::PION_1)
+///     .query_cache(QueryCache::new(Duration::from_secs(60)))
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Clone, Debug)]
+pub struct QueryCache {
+    ttl: Duration,
+    state: Arc<Mutex<CacheState>>,
+}
+
+#[derive(Debug, Default)]
+struct CacheState {
+    entries: HashMap<String, CacheEntry>,
+    /// Height of the last transaction observed
+    height: u64,
+}
+
+#[derive(Debug)]
+struct CacheEntry {
+    value: Arc<dyn Any + Send + Sync>,
+    inserted_at: Instant,
+    /// Height observed when the entry was inserted, `None` for responses that never change
+    height: Option<u64>,
+}
+
+/// How long a cached response stays valid
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Freshness {
+    /// The response never changes, e.g. the hash of a code
+    Immutable,
+    /// The response can change with any transaction, it expires after the TTL or once the daemon sends a transaction
+    UntilNextTx,
+}
+
+impl QueryCache {
+    /// Cache whose mutable entries expire after `ttl`
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            state: Arc::default(),
+        }
+    }
+
+    /// Drops the mutable entries cached before the transaction included at `height`.
+    /// Entries cached at the same height are dropped too, as the transaction may be later in the same block.
+    pub fn observe_height(&self, height: u64) {
+        let mut state = self.state.lock().unwrap();
+        if height < state.height {
+            return;
+        }
+        state.height = height;
+        state
+            .entries
+            .retain(|_, entry| !matches!(entry.height, Some(cached) if cached <= height));
+    }
+
+    /// Drops all the entries
+    pub fn clear(&self) {
+        self.state.lock().unwrap().entries.clear();
+    }
+
+    /// Number of entries, expired ones included
+    pub fn len(&self) -> usize {
+        self.state.lock().unwrap().entries.len()
+    }
+
+    /// Returns true if the cache holds no entry
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the cached response of `key`, or runs `fetch` and caches its response
+    pub async fn get_or_fetch<T, F>(
+        &self,
+        key: impl Into<String>,
+        freshness: Freshness,
+        fetch: F,
+    ) -> Result<T, DaemonError>
+    where
+        T: Clone + Send + Sync + 'static,
+        F: Future<Output = Result<T, DaemonError>>,
+    {
+        let key = key.into();
+        if let Some(value) = self.get(&key) {
+            return Ok(value);
+        }
+        // The lock is not held while querying, concurrent misses query the chain twice
+        let value = fetch.await?;
+        let mut state = self.state.lock().unwrap();
+        let height = match freshness {
+            Freshness::Immutable => None,
+            Freshness::UntilNextTx => Some(state.height),
+        };
+        state.entries.insert(
+            key,
+            CacheEntry {
+                value: Arc::new(value.clone()),
+                inserted_at: Instant::now(),
+                height,
+            },
+        );
+        Ok(value)
+    }
+
+    fn get<T: Clone + 'static>(&self, key: &str) -> Option<T> {
+        let mut state = self.state.lock().unwrap();
+        let entry = state.entries.get(key)?;
+        if entry.height.is_some() && entry.inserted_at.elapsed() > self.ttl {
+            state.entries.remove(key);
+            return None;
+        }
+        entry.value.downcast_ref::<T>().cloned()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[tokio::test]
+    async fn invalidates_mutable_entries() {
+        let cache = QueryCache::new(Duration::from_secs(60));
+        let fetch = |value: u64| async move { Ok::<_, DaemonError>(value) };
+
+        let hash = cache.get_or_fetch("code_hash/1", Freshness::Immutable, fetch(1));
+        assert_eq!(hash.await.unwrap(), 1);
+        let info = cache.get_or_fetch("contract_info/a", Freshness::UntilNextTx, fetch(2));
+        assert_eq!(info.await.unwrap(), 2);
+
+        // Served from the cache
+        let info = cache.get_or_fetch("contract_info/a", Freshness::UntilNextTx, fetch(3));
+        assert_eq!(info.await.unwrap(), 2);
+
+        cache.observe_height(10);
+        let info = cache.get_or_fetch("contract_info/a", Freshness::UntilNextTx, fetch(4));
+        assert_eq!(info.await.unwrap(), 4);
+        let hash = cache.get_or_fetch("code_hash/1", Freshness::Immutable, fetch(5));
+        assert_eq!(hash.await.unwrap(), 1);
+        assert_eq!(cache.len(), 2);
+
+        // Another transaction in the same block
+        cache.observe_height(10);
+        let info = cache.get_or_fetch("contract_info/a", Freshness::UntilNextTx, fetch(6));
+        assert_eq!(info.await.unwrap(), 6);
+
+        // Older heights are ignored
+        cache.observe_height(9);
+        let info = cache.get_or_fetch("contract_info/a", Freshness::UntilNextTx, fetch(7));
+        assert_eq!(info.await.unwrap(), 6);
+    }
+}
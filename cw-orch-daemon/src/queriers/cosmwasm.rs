@@ -1,19 +1,28 @@
-use std::{marker::PhantomData, str::FromStr};
+use std::{future::Future, marker::PhantomData, str::FromStr};
 
 use crate::senders::query::QuerySender;
 use crate::senders::QueryOnlySender;
-use crate::{cosmos_modules, error::DaemonError, DaemonBase};
+use crate::{
+    cosmos_modules,
+    error::DaemonError,
+    queriers::{params::to_access_config, Freshness, Node, QueryCache},
+    DaemonAsyncBase, DaemonBase,
+};
 use cosmrs::proto::cosmos::base::query::v1beta1::PageRequest;
 use cosmrs::AccountId;
 use cosmwasm_std::{
-    from_json, instantiate2_address, to_json_binary, Addr, Checksum, CodeInfoResponse,
+    from_json, instantiate2_address, to_json_binary, Addr, Binary, Checksum, CodeInfoResponse,
     ContractInfoResponse,
 };
 use cw_orch_core::environment::Environment;
 use cw_orch_core::{
     contract::interface_traits::Uploadable,
-    environment::{Querier, QuerierGetter, WasmQuerier},
+    environment::{
+        AccessConfig, ContractHistoryEntry, ContractHistoryOperation, Querier, QuerierGetter,
+        WasmQuerier,
+    },
 };
+use serde::{de::DeserializeOwned, Serialize};
 use tokio::runtime::Handle;
 use tonic::transport::Channel;
 
@@ -22,6 +31,8 @@ use tonic::transport::Channel;
 pub struct CosmWasmBase<Sender = QueryOnlySender> {
     pub channel: Channel,
     pub rt_handle: Option<Handle>,
+    /// Cache of the code and contract infos, see [`QueryCache`]
+    pub cache: Option<QueryCache>,
     _sender: PhantomData<Sender>,
 }
 
@@ -32,6 +43,7 @@ impl<Sender: QuerySender> CosmWasmBase<Sender> {
         Self {
             channel: daemon.channel(),
             rt_handle: Some(daemon.rt_handle.clone()),
+            cache: daemon.daemon.query_cache.clone(),
             _sender: PhantomData,
         }
     }
@@ -39,6 +51,16 @@ impl<Sender: QuerySender> CosmWasmBase<Sender> {
         Self {
             channel,
             rt_handle: None,
+            cache: None,
+            _sender: PhantomData,
+        }
+    }
+    /// Async querier sharing the [`QueryCache`] of the daemon
+    pub fn from_async(daemon: &DaemonAsyncBase<Sender>) -> Self {
+        Self {
+            channel: daemon.channel(),
+            rt_handle: None,
+            cache: daemon.query_cache.clone(),
             _sender: PhantomData,
         }
     }
@@ -46,9 +68,28 @@ impl<Sender: QuerySender> CosmWasmBase<Sender> {
         Self {
             channel,
             rt_handle: Some(handle.clone()),
+            cache: None,
             _sender: PhantomData,
         }
     }
+
+    /// Caches the code and contract infos in `cache`
+    pub fn with_cache(mut self, cache: QueryCache) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    async fn cached<T: Clone + Send + Sync + 'static>(
+        &self,
+        key: String,
+        freshness: Freshness,
+        query: impl Future<Output = Result<T, DaemonError>>,
+    ) -> Result<T, DaemonError> {
+        match &self.cache {
+            Some(cache) => cache.get_or_fetch(key, freshness, query).await,
+            None => query.await,
+        }
+    }
 }
 
 impl<Sender: QuerySender> QuerierGetter<CosmWasmBase<Sender>> for DaemonBase<Sender> {
@@ -64,6 +105,15 @@ impl<Sender> Querier for CosmWasmBase<Sender> {
 impl<Sender: QuerySender> CosmWasmBase<Sender> {
     /// Query code_id by hash
     pub async fn _code_id_hash(&self, code_id: u64) -> Result<Checksum, DaemonError> {
+        self.cached(
+            format!("code_id_hash/{code_id}"),
+            Freshness::Immutable,
+            self.query_code_id_hash(code_id),
+        )
+        .await
+    }
+
+    async fn query_code_id_hash(&self, code_id: u64) -> Result<Checksum, DaemonError> {
         use cosmos_modules::cosmwasm::{query_client::*, QueryCodeRequest};
         let mut client: QueryClient<Channel> = QueryClient::new(self.channel.clone());
         let request = QueryCodeRequest { code_id };
@@ -76,6 +126,18 @@ impl<Sender: QuerySender> CosmWasmBase<Sender> {
     pub async fn _contract_info(
         &self,
         address: &Addr,
+    ) -> Result<ContractInfoResponse, DaemonError> {
+        self.cached(
+            format!("contract_info/{address}"),
+            Freshness::UntilNextTx,
+            self.query_contract_info(address),
+        )
+        .await
+    }
+
+    async fn query_contract_info(
+        &self,
+        address: &Addr,
     ) -> Result<ContractInfoResponse, DaemonError> {
         use cosmos_modules::cosmwasm::{query_client::*, QueryContractInfoRequest};
         let mut client: QueryClient<Channel> = QueryClient::new(self.channel.clone());
@@ -118,6 +180,56 @@ impl<Sender: QuerySender> CosmWasmBase<Sender> {
         Ok(client.contract_history(request).await?.into_inner())
     }
 
+    /// Query the full code history of a contract, oldest first
+    pub async fn _all_contract_history(
+        &self,
+        address: &Addr,
+    ) -> Result<Vec<ContractHistoryEntry>, DaemonError> {
+        use cosmos_modules::cosmwasm::ContractCodeHistoryOperationType as Operation;
+        let mut entries = vec![];
+        let mut next_key = vec![];
+        loop {
+            let response = self
+                ._contract_history(
+                    address,
+                    Some(PageRequest {
+                        key: next_key,
+                        offset: 0,
+                        limit: STATE_PAGE_LIMIT,
+                        count_total: false,
+                        reverse: false,
+                    }),
+                )
+                .await?;
+            for entry in response.entries {
+                let operation = match Operation::try_from(entry.operation) {
+                    Ok(Operation::Init) => ContractHistoryOperation::Init,
+                    Ok(Operation::Migrate) => ContractHistoryOperation::Migrate,
+                    Ok(Operation::Genesis) => ContractHistoryOperation::Genesis,
+                    _ => {
+                        return Err(DaemonError::StdErr(format!(
+                            "Unknown contract history operation {}",
+                            entry.operation
+                        )))
+                    }
+                };
+                entries.push(ContractHistoryEntry {
+                    operation,
+                    code_id: entry.code_id,
+                    block_height: entry.updated.map(|position| position.block_height),
+                    msg: Binary::from(entry.msg),
+                });
+            }
+            match response.pagination {
+                Some(pagination) if !pagination.next_key.is_empty() => {
+                    next_key = pagination.next_key
+                }
+                _ => break,
+            }
+        }
+        Ok(entries)
+    }
+
     /// Query contract state
     pub async fn _contract_state(
         &self,
@@ -152,8 +264,97 @@ impl<Sender: QuerySender> CosmWasmBase<Sender> {
         Ok(client.all_contract_state(request).await?.into_inner())
     }
 
+    /// Query the full raw state of a contract, going through all the pages.
+    /// Returns the key-value pairs in the store order.
+    pub async fn _all_contract_state_entries(
+        &self,
+        address: &Addr,
+    ) -> Result<Vec<(Binary, Binary)>, DaemonError> {
+        self.all_contract_state_entries(address, None).await
+    }
+
+    /// Query the full raw state of a contract at a given block height, going through all the pages.
+    /// The node needs to have the state of that height (archive node for old heights).
+    pub async fn _all_contract_state_entries_at_height(
+        &self,
+        address: &Addr,
+        height: u64,
+    ) -> Result<Vec<(Binary, Binary)>, DaemonError> {
+        self.all_contract_state_entries(address, Some(height)).await
+    }
+
+    async fn all_contract_state_entries(
+        &self,
+        address: &Addr,
+        height: Option<u64>,
+    ) -> Result<Vec<(Binary, Binary)>, DaemonError> {
+        use cosmos_modules::cosmwasm::{query_client::*, QueryAllContractStateRequest};
+        let mut client: QueryClient<Channel> = QueryClient::new(self.channel.clone());
+        let mut entries = vec![];
+        let mut next_key = vec![];
+        loop {
+            let request = QueryAllContractStateRequest {
+                address: address.into(),
+                pagination: Some(PageRequest {
+                    key: next_key,
+                    offset: 0,
+                    limit: STATE_PAGE_LIMIT,
+                    count_total: false,
+                    reverse: false,
+                }),
+            };
+            let request = match height {
+                Some(height) => at_height(request, height)?,
+                None => tonic::Request::new(request),
+            };
+            let response = client.all_contract_state(request).await?.into_inner();
+            entries.extend(
+                response
+                    .models
+                    .into_iter()
+                    .map(|model| (Binary::from(model.key), Binary::from(model.value))),
+            );
+            match response.pagination {
+                Some(pagination) if !pagination.next_key.is_empty() => {
+                    next_key = pagination.next_key
+                }
+                _ => break,
+            }
+        }
+        Ok(entries)
+    }
+
+    /// Synchronous version of [`CosmWasmBase::_all_contract_state_entries`]
+    pub fn all_contract_state(&self, address: &Addr) -> Result<Vec<(Binary, Binary)>, DaemonError> {
+        self.rt_handle
+            .as_ref()
+            .ok_or(DaemonError::QuerierNeedRuntime)?
+            .block_on(self._all_contract_state_entries(address))
+    }
+
+    /// Writes the full raw state of a contract to `path`, as a JSON array of base64 encoded `[key, value]` pairs
+    pub fn dump_to_file(
+        &self,
+        address: &Addr,
+        path: impl AsRef<std::path::Path>,
+    ) -> Result<(), DaemonError> {
+        let entries = self.all_contract_state(address)?;
+        let file = std::fs::File::create(path)?;
+        serde_json::to_writer_pretty(file, &entries)?;
+        Ok(())
+    }
+
     /// Query code
     pub async fn _code(&self, code_id: u64) -> Result<CodeInfoResponse, DaemonError> {
+        self.cached(
+            format!("code/{code_id}"),
+            Freshness::Immutable,
+            self.query_code(code_id),
+        )
+        .await
+    }
+
+    async fn query_code(&self, code_id: u64) -> Result<CodeInfoResponse, DaemonError> {
         use cosmos_modules::cosmwasm::{query_client::*, QueryCodeRequest};
         let mut client: QueryClient<Channel> = QueryClient::new(self.channel.clone());
         let request = QueryCodeRequest { code_id };
@@ -196,6 +397,38 @@ impl<Sender: QuerySender> CosmWasmBase<Sender> {
         Ok(client.pinned_codes(request).await?.into_inner())
     }
 
+    /// Ids of the codes pinned in the wasm cache of the nodes
+    pub fn pinned_codes(&self) -> Result<Vec<u64>, DaemonError> {
+        let response = self
+            .rt_handle
+            .as_ref()
+            .ok_or(DaemonError::QuerierNeedRuntime)?
+            .block_on(self._pinned_codes())?;
+        Ok(response.code_ids)
+    }
+
+    /// Query who can instantiate the code `code_id`
+    pub async fn _code_access_config(&self, code_id: u64) -> Result<AccessConfig, DaemonError> {
+        use cosmos_modules::cosmwasm::{query_client::*, QueryCodeRequest};
+        let mut client: QueryClient<Channel> = QueryClient::new(self.channel.clone());
+        let request = QueryCodeRequest { code_id };
+        let code_info = client
+            .code(request)
+            .await?
+            .into_inner()
+            .code_info
+            .ok_or(DaemonError::StdErr(format!("Code {code_id} not found")))?;
+        Ok(to_access_config(code_info.instantiate_permission))
+    }
+
+    /// Synchronous version of [`CosmWasmBase::_code_access_config`]
+    pub fn code_access_config(&self, code_id: u64) -> Result<AccessConfig, DaemonError> {
+        self.rt_handle
+            .as_ref()
+            .ok_or(DaemonError::QuerierNeedRuntime)?
+            .block_on(self._code_access_config(code_id))
+    }
+
     /// Query contracts by code
     pub async fn _contract_by_codes(
         &self,
@@ -210,6 +443,49 @@ impl<Sender: QuerySender> CosmWasmBase<Sender> {
         Ok(client.contracts_by_code(request).await?.into_inner())
     }
 
+    /// Query the contract instantiated by `creator` with the label `label`, if any
+    pub async fn _contract_by_label(
+        &self,
+        creator: &Addr,
+        label: &str,
+    ) -> Result<Option<Addr>, DaemonError> {
+        use cosmos_modules::cosmwasm::{
+            query_client::*, QueryContractInfoRequest, QueryContractsByCreatorRequest,
+        };
+        let mut client: QueryClient<Channel> = QueryClient::new(self.channel.clone());
+        let mut next_key = vec![];
+        loop {
+            let request = QueryContractsByCreatorRequest {
+                creator_address: creator.to_string(),
+                pagination: Some(PageRequest {
+                    key: next_key,
+                    offset: 0,
+                    limit: STATE_PAGE_LIMIT,
+                    count_total: false,
+                    reverse: false,
+                }),
+            };
+            let response = client.contracts_by_creator(request).await?.into_inner();
+            for address in response.contract_addresses {
+                let info = client
+                    .contract_info(QueryContractInfoRequest {
+                        address: address.clone(),
+                    })
+                    .await?
+                    .into_inner();
+                if info.contract_info.is_some_and(|info| info.label == label) {
+                    return Ok(Some(Addr::unchecked(address)));
+                }
+            }
+            match response.pagination {
+                Some(pagination) if !pagination.next_key.is_empty() => {
+                    next_key = pagination.next_key
+                }
+                _ => return Ok(None),
+            }
+        }
+    }
+
     /// Query raw contract state
     pub async fn _contract_raw_state(
         &self,
@@ -225,6 +501,67 @@ impl<Sender: QuerySender> CosmWasmBase<Sender> {
         Ok(client.raw_contract_state(request).await?.into_inner())
     }
 
+    /// Query contract state at a given block height.
+    /// The node needs to have the state of that height (archive node for old heights).
+    pub async fn _contract_state_at_height(
+        &self,
+        address: &Addr,
+        query_data: Vec<u8>,
+        height: u64,
+    ) -> Result<Vec<u8>, DaemonError> {
+        use cosmos_modules::cosmwasm::{query_client::*, QuerySmartContractStateRequest};
+        let mut client: QueryClient<Channel> = QueryClient::new(self.channel.clone());
+        let request = at_height(
+            QuerySmartContractStateRequest {
+                address: address.into(),
+                query_data,
+            },
+            height,
+        )?;
+        Ok(client
+            .smart_contract_state(request)
+            .await?
+            .into_inner()
+            .data)
+    }
+
+    /// Executes all the queries of the batch at the same block height, so that the results form a consistent snapshot.
+    /// Uses the latest block height if `height` is `None`.
+    pub async fn _query_batch(
+        &self,
+        batch: &QueryBatch,
+        height: Option<u64>,
+    ) -> Result<BatchResponse, DaemonError> {
+        let height = match height {
+            Some(height) => height,
+            None => {
+                Node::new_async(self.channel.clone())
+                    ._block_height()
+                    .await?
+            }
+        };
+        let mut responses = Vec::with_capacity(batch.queries.len());
+        for (address, query_data) in &batch.queries {
+            responses.push(
+                self._contract_state_at_height(address, query_data.clone(), height)
+                    .await?,
+            );
+        }
+        Ok(BatchResponse { height, responses })
+    }
+
+    /// Synchronous version of [`CosmWasmBase::_query_batch`]
+    pub fn query_batch(
+        &self,
+        batch: &QueryBatch,
+        height: Option<u64>,
+    ) -> Result<BatchResponse, DaemonError> {
+        self.rt_handle
+            .as_ref()
+            .ok_or(DaemonError::QuerierNeedRuntime)?
+            .block_on(self._query_batch(batch, height))
+    }
+
     /// Query params
     pub async fn _params(
         &self,
@@ -254,6 +591,20 @@ impl<Sender: QuerySender> WasmQuerier for CosmWasmBase<Sender> {
             .block_on(self._contract_info(address))
     }
 
+    fn contract_by_label(&self, creator: &Addr, label: &str) -> Result<Option<Addr>, Self::Error> {
+        self.rt_handle
+            .as_ref()
+            .ok_or(DaemonError::QuerierNeedRuntime)?
+            .block_on(self._contract_by_label(creator, label))
+    }
+
+    fn contract_history(&self, address: &Addr) -> Result<Vec<ContractHistoryEntry>, Self::Error> {
+        self.rt_handle
+            .as_ref()
+            .ok_or(DaemonError::QuerierNeedRuntime)?
+            .block_on(self._all_contract_history(address))
+    }
+
     fn raw_query(&self, address: &Addr, query_data: Vec<u8>) -> Result<Vec<u8>, Self::Error> {
         let response = self
             .rt_handle
@@ -311,6 +662,63 @@ impl<Sender: QuerySender> WasmQuerier for CosmWasmBase<Sender> {
     }
 }
 
+/// Number of entries queried per page when dumping a contract state
+const STATE_PAGE_LIMIT: u64 = 100;
+
+/// gRPC header used to query the state at a specific height
+const BLOCK_HEIGHT_HEADER: &str = "x-cosmos-block-height";
+
+fn at_height<T>(request: T, height: u64) -> Result<tonic::Request<T>, DaemonError> {
+    let mut request = tonic::Request::new(request);
+    let height = height
+        .to_string()
+        .parse()
+        .map_err(|_| DaemonError::StdErr(format!("Invalid block height {height}")))?;
+    request.metadata_mut().insert(BLOCK_HEIGHT_HEADER, height);
+    Ok(request)
+}
+
+/// Smart queries to execute at the same block height with [`CosmWasmBase::query_batch`]
+#[derive(Clone, Debug, Default)]
+pub struct QueryBatch {
+    queries: Vec<(Addr, Vec<u8>)>,
+}
+
+impl QueryBatch {
+    /// Creates an empty batch
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a smart query to the batch and returns its index in the [`BatchResponse`]
+    pub fn add(&mut self, address: &Addr, query: &impl Serialize) -> Result<usize, DaemonError> {
+        self.queries
+            .push((address.clone(), to_json_binary(query)?.to_vec()));
+        Ok(self.queries.len() - 1)
+    }
+}
+
+/// Responses of a [`QueryBatch`], all queried at `height`
+#[derive(Clone, Debug)]
+pub struct BatchResponse {
+    /// Block height at which all the queries were executed
+    pub height: u64,
+    responses: Vec<Vec<u8>>,
+}
+
+impl BatchResponse {
+    /// Deserializes the response of the query at `index`
+    pub fn get<T: DeserializeOwned>(&self, index: usize) -> Result<T, DaemonError> {
+        let response = self
+            .responses
+            .get(index)
+            .ok_or(DaemonError::StdErr(format!(
+                "No query at index {index} in the batch"
+            )))?;
+        Ok(from_json(response)?)
+    }
+}
+
 pub fn cosmrs_to_cosmwasm_code_info(
     code_info: cosmrs::proto::cosmwasm::wasm::v1::CodeInfoResponse,
 ) -> CodeInfoResponse {
@@ -1,11 +1,11 @@
-use crate::{cosmos_modules, error::DaemonError, Daemon};
+use crate::{cosmos_modules, error::DaemonError, senders::query::QuerySender, DaemonBase};
 use cosmrs::proto::cosmos::base::query::v1beta1::PageRequest;
 use cosmwasm_std::Addr;
 use cw_orch_core::environment::{Querier, QuerierGetter};
 use tokio::runtime::Handle;
 use tonic::transport::Channel;
 
-/// Querier for the Cosmos Gov module
+/// Querier for the Cosmos FeeGrant module
 /// All the async function are prefixed with `_`
 pub struct FeeGrant {
     pub channel: Channel,
@@ -13,7 +13,7 @@ pub struct FeeGrant {
 }
 
 impl FeeGrant {
-    pub fn new(daemon: &Daemon) -> Self {
+    pub fn new<Sender: QuerySender>(daemon: &DaemonBase<Sender>) -> Self {
         Self {
             channel: daemon.channel(),
             rt_handle: Some(daemon.rt_handle.clone()),
@@ -32,14 +32,14 @@ impl Querier for FeeGrant {
     type Error = DaemonError;
 }
 
-impl QuerierGetter<FeeGrant> for Daemon {
+impl<Sender: QuerySender> QuerierGetter<FeeGrant> for DaemonBase<Sender> {
     fn querier(&self) -> FeeGrant {
         FeeGrant::new(self)
     }
 }
 
 impl FeeGrant {
-    /// Query all allowances granted to the grantee address by a granter address
+    /// Query the allowance granted to the grantee address by a granter address
     pub async fn _allowance(
         &self,
         granter: &Addr,
@@ -54,7 +54,9 @@ impl FeeGrant {
                 grantee: grantee.to_string(),
             }
         );
-        Ok(allowance.allowance.unwrap())
+        allowance.allowance.ok_or(DaemonError::StdErr(format!(
+            "No fee allowance from {granter} to {grantee}"
+        )))
     }
 
     /// Query allowances for grantee address with a given pagination
@@ -76,4 +78,24 @@ impl FeeGrant {
         );
         Ok(allowances.allowances)
     }
+
+    /// Query all the allowances granted by a granter address with a given pagination
+    ///
+    /// see [PageRequest] for pagination
+    pub async fn _allowances_by_granter(
+        &self,
+        granter: &Addr,
+        pagination: Option<PageRequest>,
+    ) -> Result<Vec<cosmos_modules::feegrant::Grant>, DaemonError> {
+        let allowances: cosmos_modules::feegrant::QueryAllowancesByGranterResponse = cosmos_query!(
+            self,
+            feegrant,
+            allowances_by_granter,
+            QueryAllowancesByGranterRequest {
+                granter: granter.to_string(),
+                pagination: pagination
+            }
+        );
+        Ok(allowances.allowances)
+    }
 }
@@ -1,4 +1,12 @@
-use crate::{cosmos_modules, error::DaemonError, Daemon};
+use std::{
+    sync::{Arc, OnceLock},
+    time::Instant,
+};
+
+use crate::{
+    cosmos_modules, error::DaemonError, polling::PollingConfig, senders::query::QuerySender,
+    DaemonBase, GrpcChannel, SdkVersion,
+};
 use cosmrs::proto::cosmos::base::query::v1beta1::PageRequest;
 use cosmwasm_std::Addr;
 use cw_orch_core::environment::{Querier, QuerierGetter};
@@ -10,13 +18,18 @@ use tonic::transport::Channel;
 pub struct Gov {
     pub channel: Channel,
     pub rt_handle: Option<Handle>,
+    pub polling: PollingConfig,
+    /// Cosmos SDK version of the node, resolved with the first status query
+    sdk_version: Arc<OnceLock<SdkVersion>>,
 }
 
 impl Gov {
-    pub fn new(daemon: &Daemon) -> Self {
+    pub fn new<Sender: QuerySender>(daemon: &DaemonBase<Sender>) -> Self {
         Self {
             channel: daemon.channel(),
             rt_handle: Some(daemon.rt_handle.clone()),
+            polling: daemon.polling().clone(),
+            sdk_version: Arc::default(),
         }
     }
 
@@ -24,15 +37,23 @@ impl Gov {
         Self {
             channel,
             rt_handle: None,
+            polling: PollingConfig::default(),
+            sdk_version: Arc::default(),
         }
     }
+
+    /// Sets the timings of the wait loops of the querier
+    pub fn with_polling(mut self, polling: PollingConfig) -> Self {
+        self.polling = polling;
+        self
+    }
 }
 
 impl Querier for Gov {
     type Error = DaemonError;
 }
 
-impl QuerierGetter<Gov> for Daemon {
+impl<Sender: QuerySender> QuerierGetter<Gov> for DaemonBase<Sender> {
     fn querier(&self) -> Gov {
         Gov::new(self)
     }
@@ -55,6 +76,45 @@ impl Gov {
         Ok(proposal.proposal.unwrap())
     }
 
+    /// Query proposal details by proposal id with the gov v1 module (SDK 0.46+).
+    /// Unlike [`Gov::_proposal`], it also returns the proposals executing messages, such as the ones submitted by the daemon.
+    pub async fn _proposal_v1(
+        &self,
+        proposal_id: u64,
+    ) -> Result<cosmos_modules::gov_v1::Proposal, DaemonError> {
+        let proposal: cosmos_modules::gov_v1::QueryProposalResponse = cosmos_query!(
+            self,
+            gov_v1,
+            proposal,
+            QueryProposalRequest {
+                proposal_id: proposal_id,
+            }
+        );
+        Ok(proposal.proposal.unwrap())
+    }
+
+    /// Version of the Cosmos SDK run by the node, queried once per querier
+    async fn sdk_version(&self) -> Result<SdkVersion, DaemonError> {
+        if let Some(version) = self.sdk_version.get() {
+            return Ok(*version);
+        }
+        let version = GrpcChannel::sdk_version(self.channel.clone()).await?;
+        Ok(*self.sdk_version.get_or_init(|| version))
+    }
+
+    /// Query the status of a proposal, with the gov module version supported by the node
+    pub async fn _proposal_status(
+        &self,
+        proposal_id: u64,
+    ) -> Result<GovProposalStatus, DaemonError> {
+        let status = if self.sdk_version().await?.has_gov_v1() {
+            self._proposal_v1(proposal_id).await?.status
+        } else {
+            self._proposal(proposal_id).await?.status
+        };
+        Ok(GovProposalStatus::from_i32(status))
+    }
+
     /// Query proposals based on given status
     ///
     /// see [PageRequest] for pagination
@@ -79,6 +139,71 @@ impl Gov {
         Ok(proposals)
     }
 
+    /// Query all the proposals with the given status
+    ///
+    /// see [PageRequest] for pagination
+    pub async fn _proposals_by_status(
+        &self,
+        proposal_status: GovProposalStatus,
+        pagination: Option<PageRequest>,
+    ) -> Result<cosmos_modules::gov::QueryProposalsResponse, DaemonError> {
+        let proposals: cosmos_modules::gov::QueryProposalsResponse = cosmos_query!(
+            self,
+            gov,
+            proposals,
+            QueryProposalsRequest {
+                proposal_status: proposal_status as i32,
+                voter: String::new(),
+                depositor: String::new(),
+                pagination: pagination
+            }
+        );
+        Ok(proposals)
+    }
+
+    /// Polls the proposal every [`PollingConfig::proposal_query_interval`] until its voting period is over.
+    /// Returns once the proposal passed and errors if it was rejected or failed,
+    /// or if it is still undecided after [`PollingConfig::proposal_max_wait`].
+    ///
+    /// This allows deployment scripts to wait for a store-code proposal before continuing.
+    pub async fn _wait_for_proposal(&self, proposal_id: u64) -> Result<(), DaemonError> {
+        let started = Instant::now();
+        loop {
+            match self._proposal_status(proposal_id).await? {
+                GovProposalStatus::Passed => return Ok(()),
+                status @ (GovProposalStatus::Rejected | GovProposalStatus::Failed) => {
+                    return Err(DaemonError::StdErr(format!(
+                        "Proposal {proposal_id} didn't pass, status: {status:?}"
+                    )))
+                }
+                status => {
+                    if started.elapsed() >= self.polling.proposal_max_wait {
+                        return Err(DaemonError::ProposalNotDecided(
+                            proposal_id,
+                            self.polling.proposal_max_wait,
+                        ));
+                    }
+                    log::debug!(
+                        "Proposal {} is in status {:?}, waiting",
+                        proposal_id,
+                        status
+                    );
+                    self.polling
+                        .sleep(self.polling.proposal_query_interval)
+                        .await?;
+                }
+            }
+        }
+    }
+
+    /// Synchronous version of [`Gov::_wait_for_proposal`]
+    pub fn wait_for_proposal(&self, proposal_id: u64) -> Result<(), DaemonError> {
+        self.rt_handle
+            .as_ref()
+            .ok_or(DaemonError::QuerierNeedRuntime)?
+            .block_on(self._wait_for_proposal(proposal_id))
+    }
+
     /// Query voted information based on proposal_id for voter address
     pub async fn _vote(
         &self,
@@ -173,7 +298,7 @@ impl Gov {
 
     /// TallyResult queries the tally of a proposal vote.
     pub async fn _tally_result(
-        &mut self,
+        &self,
         proposal_id: u64,
     ) -> Result<cosmos_modules::gov::TallyResult, DaemonError> {
         let tally_result: cosmos_modules::gov::QueryTallyResultResponse = cosmos_query!(
@@ -190,6 +315,7 @@ impl Gov {
 
 /// Proposal status
 #[allow(missing_docs)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum GovProposalStatus {
     Unspecified = 0,
     DepositPeriod = 1,
@@ -198,3 +324,17 @@ pub enum GovProposalStatus {
     Rejected = 4,
     Failed = 5,
 }
+
+impl GovProposalStatus {
+    /// Converts the proto status, unknown values are [`GovProposalStatus::Unspecified`]
+    pub fn from_i32(status: i32) -> Self {
+        match status {
+            1 => GovProposalStatus::DepositPeriod,
+            2 => GovProposalStatus::VotingPeriod,
+            3 => GovProposalStatus::Passed,
+            4 => GovProposalStatus::Rejected,
+            5 => GovProposalStatus::Failed,
+            _ => GovProposalStatus::Unspecified,
+        }
+    }
+}
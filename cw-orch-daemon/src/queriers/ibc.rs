@@ -8,10 +8,11 @@ use cosmrs::proto::ibc::{
         client::v1::{IdentifiedClientState, QueryClientStatesResponse},
         connection::v1::{ConnectionEnd, IdentifiedConnection, State},
     },
-    lightclients::tendermint::v1::ClientState,
+    lightclients::tendermint::v1::{ClientState, ConsensusState},
 };
 use cw_orch_core::environment::{Querier, QuerierGetter};
 use prost::Message;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use tokio::runtime::Handle;
 use tonic::transport::Channel;
 
@@ -38,6 +39,30 @@ impl Ibc {
     }
 }
 
+/// Status of a (tendermint) light client
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LightClientStatus {
+    /// Id of the client on the chain queried
+    pub client_id: String,
+    /// Chain tracked by the client
+    pub counterparty_chain_id: String,
+    /// Status reported by the chain (`Active`, `Expired`, `Frozen`, ...)
+    pub status: String,
+    /// Latest counterparty height known by the client
+    pub latest_height: u64,
+    /// Period during which the latest consensus state of the client can be trusted
+    pub trusting_period: Duration,
+    /// Time left before the client expires if it isn't updated
+    pub trusting_period_remaining: Duration,
+}
+
+impl LightClientStatus {
+    /// Returns true if the client is active and won't expire within `margin`
+    pub fn is_fresh(&self, margin: Duration) -> bool {
+        self.status == "Active" && self.trusting_period_remaining > margin
+    }
+}
+
 impl Querier for Ibc {
     type Error = DaemonError;
 }
@@ -138,6 +163,88 @@ impl Ibc {
         Ok(response)
     }
 
+    /// Get the (tendermint) consensus state of a client at its latest height
+    pub async fn _latest_consensus_state(
+        &self,
+        client_id: impl ToString,
+    ) -> Result<ConsensusState, DaemonError> {
+        let client_id = client_id.to_string();
+        let response: cosmos_modules::ibc_client::QueryConsensusStateResponse = cosmos_query!(
+            self,
+            ibc_client,
+            consensus_state,
+            QueryConsensusStateRequest {
+                client_id: client_id.clone(),
+                revision_number: 0,
+                revision_height: 0,
+                latest_height: true,
+            }
+        );
+        let consensus_state = response
+            .consensus_state
+            .ok_or(DaemonError::ibc_err(format!(
+                "no consensus state for client {}",
+                client_id
+            )))?;
+        ConsensusState::decode(consensus_state.value.as_slice())
+            .map_err(|e| DaemonError::ibc_err(format!("error decoding consensus state: {}", e)))
+    }
+
+    /// Get the status of a (tendermint) light client: latest height and time left before it expires
+    pub async fn _light_client_status(
+        &self,
+        client_id: impl ToString,
+    ) -> Result<LightClientStatus, DaemonError> {
+        let client_id = client_id.to_string();
+        let client_state =
+            self._client_state(&client_id)
+                .await?
+                .client_state
+                .ok_or(DaemonError::ibc_err(format!(
+                    "no client state for client {}",
+                    client_id
+                )))?;
+        let client_state = ClientState::decode(client_state.value.as_slice())
+            .map_err(|e| DaemonError::ibc_err(format!("error decoding client state: {}", e)))?;
+        let status = self._client_status(&client_id).await?.status;
+        let consensus_state = self._latest_consensus_state(&client_id).await?;
+
+        let trusting_period = client_state
+            .trusting_period
+            .map(|period| Duration::new(period.seconds as u64, period.nanos as u32))
+            .unwrap_or_default();
+        let last_update = consensus_state
+            .timestamp
+            .map(|timestamp| Duration::new(timestamp.seconds as u64, timestamp.nanos as u32))
+            .unwrap_or_default();
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+
+        Ok(LightClientStatus {
+            client_id,
+            counterparty_chain_id: client_state.chain_id,
+            status,
+            latest_height: client_state
+                .latest_height
+                .map(|height| height.revision_height)
+                .unwrap_or_default(),
+            trusting_period,
+            trusting_period_remaining: trusting_period
+                .saturating_sub(now.saturating_sub(last_update)),
+        })
+    }
+
+    /// Get the status of the light client underlying a specific channel and port
+    pub async fn _channel_light_client_status(
+        &self,
+        port_id: impl Into<String>,
+        channel_id: impl Into<String>,
+    ) -> Result<LightClientStatus, DaemonError> {
+        let client = self._channel_client_state(port_id, channel_id).await?;
+        self._light_client_status(client.client_id).await
+    }
+
     /// Get the ibc client parameters
     pub async fn _client_params(
         &self,
@@ -0,0 +1,260 @@
+//! Client for the Injective wasmx module (`x/wasmx`), which registers contracts for begin blocker execution.
+//!
+//! [`Wasmx`] reads the module parameters and the begin blocker registration of a contract,
+//! and builds `MsgExecuteContractCompat`, the execution Injective's EIP-712 signing accepts,
+//! to be committed with `Stargate::commit_any`.
+use super::grpc_query;
+use crate::{error::DaemonError, senders::query::QuerySender, DaemonBase};
+use cw_orch_core::environment::{Querier, QuerierGetter};
+use prost::Message;
+use tokio::runtime::Handle;
+use tonic::transport::Channel;
+
+use proto::{
+    MsgExecuteContractCompat, QueryContractRegistrationInfoRequest,
+    QueryContractRegistrationInfoResponse, QueryWasmxParamsRequest, QueryWasmxParamsResponse,
+    RegisteredContract, WasmxParams,
+};
+
+/// Client for the Injective wasmx module
+/// All the async function are prefixed with `_`
+pub struct Wasmx {
+    pub channel: Channel,
+    pub rt_handle: Option<Handle>,
+}
+
+impl Wasmx {
+    pub fn new<Sender: QuerySender>(daemon: &DaemonBase<Sender>) -> Self {
+        Self {
+            channel: daemon.channel(),
+            rt_handle: Some(daemon.rt_handle.clone()),
+        }
+    }
+
+    pub fn new_async(channel: Channel) -> Self {
+        Self {
+            channel,
+            rt_handle: None,
+        }
+    }
+
+    /// Message executing a contract with the funds encoded as a string (e.g. `10inj,5peggy0x..`),
+    /// the form expected by the wallets and the EIP-712 signing of Injective
+    pub fn execute_contract_compat_msg(
+        sender: impl ToString,
+        contract: impl ToString,
+        msg: &impl serde::Serialize,
+        funds: &[cosmwasm_std::Coin],
+    ) -> Result<prost_types::Any, DaemonError> {
+        let funds = if funds.is_empty() {
+            "0".to_string()
+        } else {
+            funds
+                .iter()
+                .map(|coin| coin.to_string())
+                .collect::<Vec<_>>()
+                .join(",")
+        };
+        let msg = MsgExecuteContractCompat {
+            sender: sender.to_string(),
+            contract: contract.to_string(),
+            msg: serde_json::to_string(msg)?,
+            funds,
+        };
+        Ok(prost_types::Any {
+            type_url: "/injective.wasmx.v1.MsgExecuteContractCompat".to_string(),
+            value: msg.encode_to_vec(),
+        })
+    }
+}
+
+impl Querier for Wasmx {
+    type Error = DaemonError;
+}
+
+impl<Sender: QuerySender> QuerierGetter<Wasmx> for DaemonBase<Sender> {
+    fn querier(&self) -> Wasmx {
+        Wasmx::new(self)
+    }
+}
+
+impl Wasmx {
+    /// Query the parameters of the module
+    pub async fn _params(&self) -> Result<WasmxParams, DaemonError> {
+        let response: QueryWasmxParamsResponse = grpc_query(
+            &self.channel,
+            "/injective.wasmx.v1.Query/WasmxParams",
+            QueryWasmxParamsRequest {},
+        )
+        .await?;
+        Ok(response.params.unwrap_or_default())
+    }
+
+    /// Synchronous version of [`Wasmx::_params`]
+    pub fn params(&self) -> Result<WasmxParams, DaemonError> {
+        self.rt_handle
+            .as_ref()
+            .ok_or(DaemonError::QuerierNeedRuntime)?
+            .block_on(self._params())
+    }
+
+    /// Query the begin blocker registration of a contract, `None` if it isn't registered
+    pub async fn _contract_registration_info(
+        &self,
+        contract_address: impl Into<String>,
+    ) -> Result<Option<RegisteredContract>, DaemonError> {
+        let response: QueryContractRegistrationInfoResponse = grpc_query(
+            &self.channel,
+            "/injective.wasmx.v1.Query/ContractRegistrationInfo",
+            QueryContractRegistrationInfoRequest {
+                contract_address: contract_address.into(),
+            },
+        )
+        .await?;
+        Ok(response.contract)
+    }
+
+    /// Synchronous version of [`Wasmx::_contract_registration_info`]
+    pub fn contract_registration_info(
+        &self,
+        contract_address: impl Into<String>,
+    ) -> Result<Option<RegisteredContract>, DaemonError> {
+        self.rt_handle
+            .as_ref()
+            .ok_or(DaemonError::QuerierNeedRuntime)?
+            .block_on(self._contract_registration_info(contract_address))
+    }
+}
+
+/// Messages of the Injective `wasmx` module
+pub mod proto {
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct MsgExecuteContractCompat {
+        #[prost(string, tag = "1")]
+        pub sender: String,
+        #[prost(string, tag = "2")]
+        pub contract: String,
+        /// Json encoded message
+        #[prost(string, tag = "3")]
+        pub msg: String,
+        /// Coins as `10inj,5usdt`, `0` for no funds
+        #[prost(string, tag = "4")]
+        pub funds: String,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct WasmxParams {
+        /// Whether the begin blocker contracts are executed
+        #[prost(bool, tag = "1")]
+        pub is_execution_enabled: bool,
+        #[prost(uint64, tag = "2")]
+        pub max_begin_block_total_gas: u64,
+        #[prost(uint64, tag = "3")]
+        pub max_contract_gas_limit: u64,
+        #[prost(uint64, tag = "4")]
+        pub min_gas_price: u64,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct QueryWasmxParamsRequest {}
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct QueryWasmxParamsResponse {
+        #[prost(message, optional, tag = "1")]
+        pub params: Option<WasmxParams>,
+    }
+
+    /// Contract executed in the begin blocker
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct RegisteredContract {
+        #[prost(uint64, tag = "1")]
+        pub gas_limit: u64,
+        #[prost(uint64, tag = "2")]
+        pub gas_price: u64,
+        #[prost(bool, tag = "3")]
+        pub is_executable: bool,
+        #[prost(uint64, tag = "4")]
+        pub code_id: u64,
+        #[prost(string, tag = "5")]
+        pub admin_address: String,
+        #[prost(string, tag = "6")]
+        pub granter_address: String,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct QueryContractRegistrationInfoRequest {
+        #[prost(string, tag = "1")]
+        pub contract_address: String,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct QueryContractRegistrationInfoResponse {
+        #[prost(message, optional, tag = "1")]
+        pub contract: Option<RegisteredContract>,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use cosmwasm_std::{coin, Binary};
+    use injective_std::types::injective::wasmx::v1 as upstream;
+
+    #[test]
+    fn execute_contract_compat_msg_matches_injective_std() {
+        let any = Wasmx::execute_contract_compat_msg(
+            "inj1sender",
+            "inj1contract",
+            &serde_json::json!({ "increment": {} }),
+            &[coin(10, "inj"), coin(5, "peggy0xdAC1")],
+        )
+        .unwrap();
+        let msg = upstream::MsgExecuteContractCompat {
+            sender: "inj1sender".to_string(),
+            contract: "inj1contract".to_string(),
+            msg: r#"{"increment":{}}"#.to_string(),
+            funds: "10inj,5peggy0xdAC1".to_string(),
+        };
+        assert_eq!(any.type_url, upstream::MsgExecuteContractCompat::TYPE_URL);
+        assert_eq!(Binary::new(any.value), Binary::from(msg));
+
+        let any = Wasmx::execute_contract_compat_msg(
+            "inj1sender",
+            "inj1contract",
+            &serde_json::json!({}),
+            &[],
+        )
+        .unwrap();
+        let msg = MsgExecuteContractCompat::decode(any.value.as_slice()).unwrap();
+        assert_eq!(msg.funds, "0");
+    }
+
+    #[test]
+    fn decodes_injective_std_registrations() {
+        let response = upstream::QueryContractRegistrationInfoResponse {
+            contract: Some(upstream::RegisteredContract {
+                gas_limit: 1,
+                gas_price: 2,
+                is_executable: true,
+                code_id: 3,
+                admin_address: "inj1admin".to_string(),
+                ..Default::default()
+            }),
+        };
+        let contract =
+            QueryContractRegistrationInfoResponse::decode(Binary::from(response).as_slice())
+                .unwrap()
+                .contract;
+        assert_eq!(
+            contract,
+            Some(RegisteredContract {
+                gas_limit: 1,
+                gas_price: 2,
+                is_executable: true,
+                code_id: 3,
+                admin_address: "inj1admin".to_string(),
+                granter_address: String::new(),
+            })
+        );
+    }
+}
@@ -0,0 +1,427 @@
+//! Clients for the Neutron DEX (`x/dex`) and interchain queries (`x/interchainqueries`) modules.
+//!
+//! [`Dex`] looks up the pools of Neutron's concentrated liquidity order book by id or by pair, tick and fee,
+//! and builds the limit orders that trade against them, to be committed with `Stargate::commit_any`.
+//! [`InterchainQueries`] reads the queries registered by contracts, with the result last submitted by the relayer.
+use super::grpc_query;
+use crate::{error::DaemonError, senders::query::QuerySender, DaemonBase};
+use cw_orch_core::environment::{Querier, QuerierGetter};
+use prost::Message;
+use tokio::runtime::Handle;
+use tonic::transport::Channel;
+
+use proto::{
+    LimitOrderType, MsgPlaceLimitOrder, Pool, QueryGetPoolRequest, QueryPoolByIdRequest,
+    QueryPoolResponse, QueryRegisteredQueryRequest, QueryRegisteredQueryResponse, RegisteredQuery,
+};
+
+/// Client for the Neutron DEX module
+/// All the async function are prefixed with `_`
+pub struct Dex {
+    pub channel: Channel,
+    pub rt_handle: Option<Handle>,
+}
+
+impl Dex {
+    pub fn new<Sender: QuerySender>(daemon: &DaemonBase<Sender>) -> Self {
+        Self {
+            channel: daemon.channel(),
+            rt_handle: Some(daemon.rt_handle.clone()),
+        }
+    }
+
+    pub fn new_async(channel: Channel) -> Self {
+        Self {
+            channel,
+            rt_handle: None,
+        }
+    }
+
+    /// Message placing a limit order selling `amount_in` of `token_in` for `token_out`,
+    /// at the price of `tick_index_in_to_out` (price = 1.0001^-tick)
+    pub fn place_limit_order_msg(
+        creator: impl ToString,
+        token_in: impl Into<String>,
+        token_out: impl Into<String>,
+        tick_index_in_to_out: i64,
+        amount_in: impl ToString,
+        order_type: LimitOrderType,
+    ) -> prost_types::Any {
+        let creator = creator.to_string();
+        let msg = MsgPlaceLimitOrder {
+            receiver: creator.clone(),
+            creator,
+            token_in: token_in.into(),
+            token_out: token_out.into(),
+            tick_index_in_to_out,
+            amount_in: amount_in.to_string(),
+            order_type: order_type as i32,
+        };
+        prost_types::Any {
+            type_url: "/neutron.dex.MsgPlaceLimitOrder".to_string(),
+            value: msg.encode_to_vec(),
+        }
+    }
+}
+
+impl Querier for Dex {
+    type Error = DaemonError;
+}
+
+impl<Sender: QuerySender> QuerierGetter<Dex> for DaemonBase<Sender> {
+    fn querier(&self) -> Dex {
+        Dex::new(self)
+    }
+}
+
+impl Dex {
+    /// Query a pool by id
+    pub async fn _pool_by_id(&self, pool_id: u64) -> Result<Pool, DaemonError> {
+        let response: QueryPoolResponse = grpc_query(
+            &self.channel,
+            "/neutron.dex.Query/PoolByID",
+            QueryPoolByIdRequest { pool_id },
+        )
+        .await?;
+        response
+            .pool
+            .ok_or(DaemonError::StdErr(format!("No pool with id {pool_id}")))
+    }
+
+    /// Synchronous version of [`Dex::_pool_by_id`]
+    pub fn pool_by_id(&self, pool_id: u64) -> Result<Pool, DaemonError> {
+        self.rt_handle
+            .as_ref()
+            .ok_or(DaemonError::QuerierNeedRuntime)?
+            .block_on(self._pool_by_id(pool_id))
+    }
+
+    /// Query the pool of a pair (`tokenA<>tokenB`) at a tick index and fee
+    pub async fn _pool(
+        &self,
+        pair_id: impl Into<String>,
+        tick_index: i64,
+        fee: u64,
+    ) -> Result<Pool, DaemonError> {
+        let pair_id = pair_id.into();
+        let response: QueryPoolResponse = grpc_query(
+            &self.channel,
+            "/neutron.dex.Query/Pool",
+            QueryGetPoolRequest {
+                pair_id: pair_id.clone(),
+                tick_index,
+                fee,
+            },
+        )
+        .await?;
+        response.pool.ok_or(DaemonError::StdErr(format!(
+            "No pool for {pair_id} at tick {tick_index} with fee {fee}"
+        )))
+    }
+
+    /// Synchronous version of [`Dex::_pool`]
+    pub fn pool(
+        &self,
+        pair_id: impl Into<String>,
+        tick_index: i64,
+        fee: u64,
+    ) -> Result<Pool, DaemonError> {
+        self.rt_handle
+            .as_ref()
+            .ok_or(DaemonError::QuerierNeedRuntime)?
+            .block_on(self._pool(pair_id, tick_index, fee))
+    }
+}
+
+/// Client for the Neutron interchain queries module
+/// All the async function are prefixed with `_`
+pub struct InterchainQueries {
+    pub channel: Channel,
+    pub rt_handle: Option<Handle>,
+}
+
+impl InterchainQueries {
+    pub fn new<Sender: QuerySender>(daemon: &DaemonBase<Sender>) -> Self {
+        Self {
+            channel: daemon.channel(),
+            rt_handle: Some(daemon.rt_handle.clone()),
+        }
+    }
+
+    pub fn new_async(channel: Channel) -> Self {
+        Self {
+            channel,
+            rt_handle: None,
+        }
+    }
+}
+
+impl Querier for InterchainQueries {
+    type Error = DaemonError;
+}
+
+impl<Sender: QuerySender> QuerierGetter<InterchainQueries> for DaemonBase<Sender> {
+    fn querier(&self) -> InterchainQueries {
+        InterchainQueries::new(self)
+    }
+}
+
+impl InterchainQueries {
+    /// Query a registered interchain query by id
+    pub async fn _registered_query(&self, query_id: u64) -> Result<RegisteredQuery, DaemonError> {
+        let response: QueryRegisteredQueryResponse = grpc_query(
+            &self.channel,
+            "/neutron.interchainqueries.Query/RegisteredQuery",
+            QueryRegisteredQueryRequest { query_id },
+        )
+        .await?;
+        response.registered_query.ok_or(DaemonError::StdErr(format!(
+            "Interchain query {query_id} not found"
+        )))
+    }
+
+    /// Synchronous version of [`InterchainQueries::_registered_query`]
+    pub fn registered_query(&self, query_id: u64) -> Result<RegisteredQuery, DaemonError> {
+        self.rt_handle
+            .as_ref()
+            .ok_or(DaemonError::QuerierNeedRuntime)?
+            .block_on(self._registered_query(query_id))
+    }
+}
+
+/// Messages of the Neutron `dex` and `interchainqueries` modules
+pub mod proto {
+    use cosmrs::proto::ibc::core::client::v1::Height;
+
+    /// Execution of a limit order
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, prost::Enumeration)]
+    #[repr(i32)]
+    pub enum LimitOrderType {
+        /// Rests on the book until filled or cancelled
+        GoodTilCancelled = 0,
+        /// Fills completely or fails
+        FillOrKill = 1,
+        /// Fills as much as possible, the rest is cancelled
+        ImmediateOrCancel = 2,
+        /// Fills as much as possible, the rest is cancelled at the end of the block
+        JustInTime = 3,
+        /// Rests on the book until its expiration time
+        GoodTilTime = 4,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct MsgPlaceLimitOrder {
+        #[prost(string, tag = "1")]
+        pub creator: String,
+        #[prost(string, tag = "2")]
+        pub receiver: String,
+        #[prost(string, tag = "3")]
+        pub token_in: String,
+        #[prost(string, tag = "4")]
+        pub token_out: String,
+        #[prost(int64, tag = "5")]
+        pub tick_index_in_to_out: i64,
+        #[prost(string, tag = "7")]
+        pub amount_in: String,
+        #[prost(enumeration = "LimitOrderType", tag = "8")]
+        pub order_type: i32,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct TradePairId {
+        #[prost(string, tag = "2")]
+        pub maker_denom: String,
+        #[prost(string, tag = "3")]
+        pub taker_denom: String,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct PoolReservesKey {
+        #[prost(message, optional, tag = "1")]
+        pub trade_pair_id: Option<TradePairId>,
+        #[prost(int64, tag = "2")]
+        pub tick_index_taker_to_maker: i64,
+        #[prost(uint64, tag = "3")]
+        pub fee: u64,
+    }
+
+    /// Liquidity of one side of a pool
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct PoolReserves {
+        #[prost(message, optional, tag = "1")]
+        pub key: Option<PoolReservesKey>,
+        #[prost(string, tag = "2")]
+        pub reserves_maker_denom: String,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct Pool {
+        #[prost(uint64, tag = "1")]
+        pub id: u64,
+        #[prost(message, optional, tag = "2")]
+        pub lower_tick0: Option<PoolReserves>,
+        #[prost(message, optional, tag = "3")]
+        pub upper_tick1: Option<PoolReserves>,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct QueryGetPoolRequest {
+        /// Pair as `tokenA<>tokenB`
+        #[prost(string, tag = "1")]
+        pub pair_id: String,
+        #[prost(int64, tag = "2")]
+        pub tick_index: i64,
+        #[prost(uint64, tag = "3")]
+        pub fee: u64,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct QueryPoolByIdRequest {
+        #[prost(uint64, tag = "1")]
+        pub pool_id: u64,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct QueryPoolResponse {
+        #[prost(message, optional, tag = "1")]
+        pub pool: Option<Pool>,
+    }
+
+    /// Storage key of a KV interchain query
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct KvKey {
+        /// Store of the remote chain, e.g. `bank`
+        #[prost(string, tag = "1")]
+        pub path: String,
+        #[prost(bytes = "vec", tag = "2")]
+        pub key: Vec<u8>,
+    }
+
+    /// Interchain query registered by a contract
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct RegisteredQuery {
+        #[prost(uint64, tag = "1")]
+        pub id: u64,
+        #[prost(string, tag = "2")]
+        pub owner: String,
+        /// `kv` or `tx`
+        #[prost(string, tag = "3")]
+        pub query_type: String,
+        #[prost(message, repeated, tag = "4")]
+        pub keys: Vec<KvKey>,
+        #[prost(string, tag = "5")]
+        pub transactions_filter: String,
+        #[prost(string, tag = "6")]
+        pub connection_id: String,
+        #[prost(uint64, tag = "7")]
+        pub update_period: u64,
+        #[prost(uint64, tag = "8")]
+        pub last_submitted_result_local_height: u64,
+        #[prost(message, optional, tag = "9")]
+        pub last_submitted_result_remote_height: Option<Height>,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct QueryRegisteredQueryRequest {
+        #[prost(uint64, tag = "1")]
+        pub query_id: u64,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct QueryRegisteredQueryResponse {
+        #[prost(message, optional, tag = "1")]
+        pub registered_query: Option<RegisteredQuery>,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use cosmwasm_std::Binary;
+    use neutron_std::types::neutron::{dex as upstream, interchainqueries as upstream_icq};
+
+    #[test]
+    fn place_limit_order_msg_matches_neutron_std() {
+        let any = Dex::place_limit_order_msg(
+            "neutron1creator",
+            "untrn",
+            "uatom",
+            -10,
+            1000u128,
+            LimitOrderType::ImmediateOrCancel,
+        );
+        let msg = upstream::MsgPlaceLimitOrder {
+            creator: "neutron1creator".to_string(),
+            receiver: "neutron1creator".to_string(),
+            token_in: "untrn".to_string(),
+            token_out: "uatom".to_string(),
+            tick_index_in_to_out: -10,
+            amount_in: "1000".to_string(),
+            order_type: upstream::LimitOrderType::ImmediateOrCancel as i32,
+            ..Default::default()
+        };
+        assert_eq!(any.type_url, upstream::MsgPlaceLimitOrder::TYPE_URL);
+        assert_eq!(Binary::new(any.value), Binary::from(msg));
+    }
+
+    #[test]
+    fn decodes_neutron_std_pools() {
+        let response = upstream::QueryPoolResponse {
+            pool: Some(upstream::Pool {
+                id: 3,
+                lower_tick0: Some(upstream::PoolReserves {
+                    key: Some(upstream::PoolReservesKey {
+                        trade_pair_id: Some(upstream::TradePairId {
+                            maker_denom: "untrn".to_string(),
+                            taker_denom: "uatom".to_string(),
+                        }),
+                        tick_index_taker_to_maker: -1,
+                        fee: 1,
+                    }),
+                    reserves_maker_denom: "100".to_string(),
+                    ..Default::default()
+                }),
+                upper_tick1: None,
+            }),
+        };
+        let pool = QueryPoolResponse::decode(Binary::from(response).as_slice())
+            .unwrap()
+            .pool
+            .unwrap();
+        assert_eq!(pool.id, 3);
+        let reserves = pool.lower_tick0.unwrap();
+        assert_eq!(reserves.reserves_maker_denom, "100");
+        let key = reserves.key.unwrap();
+        assert_eq!(key.tick_index_taker_to_maker, -1);
+        assert_eq!(key.trade_pair_id.unwrap().maker_denom, "untrn");
+        assert_eq!(pool.upper_tick1, None);
+    }
+
+    #[test]
+    fn decodes_neutron_std_registered_queries() {
+        let response = upstream_icq::QueryRegisteredQueryResponse {
+            registered_query: Some(upstream_icq::RegisteredQuery {
+                id: 1,
+                owner: "neutron1owner".to_string(),
+                query_type: "kv".to_string(),
+                keys: vec![upstream_icq::KvKey {
+                    path: "bank".to_string(),
+                    key: b"balances".to_vec(),
+                }],
+                connection_id: "connection-0".to_string(),
+                update_period: 10,
+                ..Default::default()
+            }),
+        };
+        let query = QueryRegisteredQueryResponse::decode(Binary::from(response).as_slice())
+            .unwrap()
+            .registered_query
+            .unwrap();
+        assert_eq!(query.id, 1);
+        assert_eq!(query.query_type, "kv");
+        assert_eq!(query.keys[0].path, "bank");
+        assert_eq!(query.keys[0].key, b"balances");
+        assert_eq!(query.connection_id, "connection-0");
+        assert_eq!(query.update_period, 10);
+    }
+}
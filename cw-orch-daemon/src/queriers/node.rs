@@ -1,14 +1,21 @@
 use std::{cmp::min, time::Duration};
 
 use crate::{
-    cosmos_modules, env::DaemonEnvVars, error::DaemonError, senders::query::QuerySender,
-    tx_resp::CosmTxResponse, DaemonBase,
+    cosmos_modules,
+    error::DaemonError,
+    polling::{PollingConfig, TxConfirmation},
+    senders::query::QuerySender,
+    tx_resp::{CosmTxResponse, DecodedTxMsg, TxInspection},
+    DaemonBase, GrpcChannel, SdkVersion,
 };
 
 use cosmrs::{
-    proto::cosmos::{
-        base::query::v1beta1::PageRequest,
-        tx::v1beta1::{OrderBy, SimulateResponse},
+    proto::{
+        cosmos::{
+            base::query::v1beta1::PageRequest,
+            tx::v1beta1::{OrderBy, SimulateResponse},
+        },
+        traits::Message,
     },
     tendermint::{Block, Time},
 };
@@ -20,12 +27,16 @@ use cw_orch_core::{
 use tokio::runtime::Handle;
 use tonic::transport::Channel;
 
+/// Maximum number of TXs returned by a page of the tx search
+pub(crate) const TX_SEARCH_PAGE_LIMIT: u64 = 100;
+
 /// Querier for the Tendermint node.
 /// Supports queries for block and tx information
 /// All the async function are prefixed with `_`
 pub struct Node {
     pub channel: Channel,
     pub rt_handle: Option<Handle>,
+    pub polling: PollingConfig,
 }
 
 impl Node {
@@ -33,14 +44,22 @@ impl Node {
         Self {
             channel: daemon.channel(),
             rt_handle: Some(daemon.rt_handle.clone()),
+            polling: daemon.polling().clone(),
         }
     }
     pub fn new_async(channel: Channel) -> Self {
         Self {
             channel,
             rt_handle: None,
+            polling: PollingConfig::default(),
         }
     }
+
+    /// Sets the timings of the wait loops of the querier
+    pub fn with_polling(mut self, polling: PollingConfig) -> Self {
+        self.polling = polling;
+        self
+    }
 }
 
 impl<Sender: QuerySender> QuerierGetter<Node> for DaemonBase<Sender> {
@@ -69,6 +88,11 @@ impl Node {
         Ok(resp)
     }
 
+    /// Returns the version of the Cosmos SDK run by the node, see [`GrpcChannel::sdk_version`]
+    pub async fn _sdk_version(&self) -> Result<SdkVersion, DaemonError> {
+        GrpcChannel::sdk_version(self.channel.clone()).await
+    }
+
     /// Queries node syncing
     pub async fn _syncing(&self) -> Result<bool, DaemonError> {
         let mut client =
@@ -123,7 +147,7 @@ impl Node {
 
         while latest_block_height <= 1 {
             // wait to get some blocks
-            tokio::time::sleep(Duration::from_secs(1)).await;
+            self.polling.sleep(self.polling.min_block_time).await?;
             latest_block = self._latest_block().await?;
             latest_block_height = latest_block.header.height.value();
         }
@@ -205,13 +229,35 @@ impl Node {
 
     /// Simulate TX
     pub async fn _simulate_tx(&self, tx_bytes: Vec<u8>) -> Result<u64, DaemonError> {
+        self._simulate_tx_for_version(tx_bytes, SdkVersion::LATEST)
+            .await
+    }
+
+    /// Simulate TX, encoded as expected by the nodes running the Cosmos SDK `version`
+    pub async fn _simulate_tx_for_version(
+        &self,
+        tx_bytes: Vec<u8>,
+        version: SdkVersion,
+    ) -> Result<u64, DaemonError> {
         let mut client =
             cosmos_modules::tx::service_client::ServiceClient::new(self.channel.clone());
-        #[allow(deprecated)]
-        let resp: SimulateResponse = client
-            .simulate(cosmos_modules::tx::SimulateRequest { tx: None, tx_bytes })
-            .await?
-            .into_inner();
+        let request = if version.simulates_tx_bytes() {
+            #[allow(deprecated)]
+            cosmos_modules::tx::SimulateRequest { tx: None, tx_bytes }
+        } else {
+            let raw = cosmos_modules::tx::TxRaw::decode(tx_bytes.as_slice())?;
+            let tx = cosmos_modules::tx::Tx {
+                body: Some(Message::decode(raw.body_bytes.as_slice())?),
+                auth_info: Some(Message::decode(raw.auth_info_bytes.as_slice())?),
+                signatures: raw.signatures,
+            };
+            #[allow(deprecated)]
+            cosmos_modules::tx::SimulateRequest {
+                tx: Some(tx),
+                tx_bytes: vec![],
+            }
+        };
+        let resp: SimulateResponse = client.simulate(request).await?.into_inner();
         let gas_used = resp.gas_info.unwrap().gas_used;
         Ok(gas_used)
     }
@@ -225,7 +271,7 @@ impl Node {
 
     /// Find TX by hash
     pub async fn _find_tx(&self, hash: String) -> Result<CosmTxResponse, DaemonError> {
-        self._find_tx_with_retries(hash, DaemonEnvVars::max_tx_query_retries())
+        self._find_tx_with_retries(hash, self.polling.tx_query_retries)
             .await
     }
 
@@ -239,14 +285,8 @@ impl Node {
             cosmos_modules::tx::service_client::ServiceClient::new(self.channel.clone());
 
         let request = cosmos_modules::tx::GetTxRequest { hash: hash.clone() };
-        let mut block_speed = self._average_block_speed(Some(0.7)).await?;
-        let max_block_time = DaemonEnvVars::max_block_time();
-        if let Some(max_time) = max_block_time {
-            block_speed = block_speed.min(max_time);
-        } else {
-            let min_block_time = DaemonEnvVars::min_block_time();
-            block_speed = block_speed.max(min_block_time);
-        }
+        let block_speed = self._average_block_speed(Some(0.7)).await?;
+        let mut block_speed = self.polling.clamp_block_time(block_speed);
 
         for _ in 0..retries {
             match client.get_tx(request.clone()).await {
@@ -257,13 +297,10 @@ impl Node {
                 }
                 Err(err) => {
                     // increase wait time
-                    block_speed = block_speed.mul_f64(1.6);
-                    if let Some(max_time) = max_block_time {
-                        block_speed = block_speed.min(max_time)
-                    }
+                    block_speed = self.polling.next_tx_query_wait(block_speed);
                     log::debug!(target: &query_target(), "TX not found with error: {:?}", err);
                     log::debug!(target: &query_target(), "Waiting {} milli-seconds", block_speed.as_millis());
-                    tokio::time::sleep(block_speed).await;
+                    self.polling.sleep(block_speed).await?;
                 }
             }
         }
@@ -272,6 +309,136 @@ impl Node {
         Err(DaemonError::TXNotFound(hash, retries))
     }
 
+    /// Waits for the inclusion of a broadcasted TX, following the [`TxConfirmation`] strategy of the polling config
+    pub async fn _confirm_tx(&self, hash: String) -> Result<CosmTxResponse, DaemonError> {
+        match &self.polling.confirmation {
+            TxConfirmation::Backoff => self._find_tx(hash).await,
+            TxConfirmation::Interval { interval, max_wait } => {
+                self._find_tx_until(hash, *interval, *max_wait).await
+            }
+            #[cfg(feature = "websocket")]
+            TxConfirmation::Websocket { rpc_url, max_wait } => {
+                self._find_tx_from_websocket(hash, rpc_url, *max_wait).await
+            }
+        }
+    }
+
+    /// Find TX by hash, querying it every `interval` until `max_wait` elapsed
+    pub async fn _find_tx_until(
+        &self,
+        hash: String,
+        interval: Duration,
+        max_wait: Duration,
+    ) -> Result<CosmTxResponse, DaemonError> {
+        let mut client =
+            cosmos_modules::tx::service_client::ServiceClient::new(self.channel.clone());
+
+        let request = cosmos_modules::tx::GetTxRequest { hash: hash.clone() };
+        let start = tokio::time::Instant::now();
+        let mut attempts = 0;
+
+        loop {
+            attempts += 1;
+            match client.get_tx(request.clone()).await {
+                Ok(tx) => {
+                    let resp = tx.into_inner().tx_response.unwrap().into();
+                    log::debug!(target: &query_target(), "TX found: {:?}", resp);
+                    return Ok(resp);
+                }
+                Err(err) => {
+                    log::debug!(target: &query_target(), "TX not found with error: {:?}", err);
+                }
+            }
+            if start.elapsed() + interval > max_wait {
+                return Err(DaemonError::TXNotFound(hash, attempts));
+            }
+            self.polling.sleep(interval).await?;
+        }
+    }
+
+    /// Find TX by hash, waiting for it to be pushed by the websocket at `rpc_url` until `max_wait` elapsed
+    #[cfg(feature = "websocket")]
+    pub async fn _find_tx_from_websocket(
+        &self,
+        hash: String,
+        rpc_url: &str,
+        max_wait: Duration,
+    ) -> Result<CosmTxResponse, DaemonError> {
+        use crate::subscription::{EventType, Query, Subscriptions};
+        use futures_util::StreamExt;
+
+        // Subscribing before the first query, so that the tx can't be included in between
+        let subscriptions = Subscriptions::connect(rpc_url).await?;
+        let mut txs = Box::pin(
+            subscriptions
+                .subscribe_txs(Query::from(EventType::Tx).and_eq("tx.hash", hash.as_str()))
+                .await?,
+        );
+        if let Ok(resp) = self._find_tx_with_retries(hash.clone(), 1).await {
+            return Ok(resp);
+        }
+
+        let pushed = async {
+            match &self.polling.cancellation {
+                Some(token) => tokio::select! {
+                    _ = token.cancelled() => Err(DaemonError::Cancelled),
+                    tx = txs.next() => Ok(tx),
+                },
+                None => Ok(txs.next().await),
+            }
+        };
+        match tokio::time::timeout(max_wait, pushed).await {
+            Ok(Ok(Some(tx))) => {
+                log::debug!(target: &query_target(), "TX pushed at height {}", tx?.height);
+            }
+            Ok(Ok(None)) => {
+                return Err(DaemonError::StdErr(format!(
+                    "Websocket closed while waiting for TX {hash}"
+                )))
+            }
+            Ok(Err(e)) => return Err(e),
+            Err(_) => return Err(DaemonError::TXNotFound(hash, 1)),
+        }
+        // The node may index the tx slightly after pushing it
+        self._find_tx_until(hash, self.polling.min_block_time, max_wait)
+            .await
+    }
+
+    /// Find TX by hash and decode its messages.
+    /// Wasm messages (execute, instantiate, migrate) have their inner message decoded to JSON.
+    pub async fn _inspect_tx(&self, hash: String) -> Result<TxInspection, DaemonError> {
+        let mut client =
+            cosmos_modules::tx::service_client::ServiceClient::new(self.channel.clone());
+
+        let resp = client
+            .get_tx(cosmos_modules::tx::GetTxRequest { hash: hash.clone() })
+            .await?
+            .into_inner();
+
+        let response: CosmTxResponse = resp
+            .tx_response
+            .ok_or(DaemonError::TXNotFound(hash, 1))?
+            .into();
+        let body = resp.tx.and_then(|tx| tx.body).unwrap_or_default();
+
+        let messages = body
+            .messages
+            .into_iter()
+            .map(|any| {
+                DecodedTxMsg::decode(cosmrs::Any {
+                    type_url: any.type_url,
+                    value: any.value,
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(TxInspection {
+            response,
+            memo: body.memo,
+            messages,
+        })
+    }
+
     /// Find TX by events
     pub async fn _find_tx_by_events(
         &self,
@@ -284,7 +451,7 @@ impl Node {
             page,
             order_by,
             false,
-            DaemonEnvVars::max_tx_query_retries(),
+            self.polling.tx_query_retries,
         )
         .await
     }
@@ -303,7 +470,7 @@ impl Node {
             page,
             order_by,
             true,
-            DaemonEnvVars::max_tx_query_retries(),
+            self.polling.tx_query_retries,
         )
         .await
     }
@@ -339,8 +506,10 @@ impl Node {
                     let resp = tx.into_inner().tx_responses;
                     if retry_on_empty && resp.is_empty() {
                         log::debug!(target: &query_target(), "No TX found with events {:?}", events);
-                        log::debug!(target: &query_target(), "Waiting 10s");
-                        tokio::time::sleep(Duration::from_secs(10)).await;
+                        log::debug!(target: &query_target(), "Waiting {:?}", self.polling.events_query_interval);
+                        self.polling
+                            .sleep(self.polling.events_query_interval)
+                            .await?;
                     } else {
                         log::debug!(
                             target: &query_target(),
@@ -352,17 +521,265 @@ impl Node {
                 }
                 Err(err) => {
                     log::debug!(target: &query_target(), "TX not found with error: {:?}", err);
-                    log::debug!(target: &query_target(), "Waiting 10s");
-                    tokio::time::sleep(Duration::from_secs(10)).await;
+                    log::debug!(target: &query_target(), "Waiting {:?}", self.polling.events_query_interval);
+                    self.polling
+                        .sleep(self.polling.events_query_interval)
+                        .await?;
                 }
             }
         }
         // return error if tx not found by now
         Err(DaemonError::TXNotFound(
             format!("with events {:?}", events),
-            DaemonEnvVars::max_tx_query_retries(),
+            retries,
         ))
     }
+
+    /// Find the page `page` (starting at 1) of the TXs matching `events`, along with the total number of matching TXs.
+    /// The total is 0 if the node doesn't report it.
+    pub async fn _find_tx_page(
+        &self,
+        events: Vec<String>,
+        page: u64,
+        limit: u64,
+        order_by: OrderBy,
+    ) -> Result<(Vec<CosmTxResponse>, u64), DaemonError> {
+        let mut client = cosmrs::proto::cosmos::tx::v1beta1::service_client::ServiceClient::new(
+            self.channel.clone(),
+        );
+
+        #[allow(deprecated)]
+        let request = cosmrs::proto::cosmos::tx::v1beta1::GetTxsEventRequest {
+            events: events.clone(),
+            pagination: None,
+            order_by: order_by.into(),
+            page,
+            limit,
+            query: events.join(" AND "),
+        };
+        let response = client.get_txs_event(request).await?.into_inner();
+
+        #[allow(deprecated)]
+        let total = response.total.max(
+            response
+                .pagination
+                .map(|pagination| pagination.total)
+                .unwrap_or_default(),
+        );
+        let txs = response.tx_responses.into_iter().map(Into::into).collect();
+        Ok((txs, total))
+    }
+
+    /// Find all the TXs matching `events`, querying all the pages of the search
+    pub async fn _find_all_tx_by_events(
+        &self,
+        events: Vec<String>,
+        order_by: OrderBy,
+    ) -> Result<Vec<CosmTxResponse>, DaemonError> {
+        let mut txs = vec![];
+        // Tx search pages start at 1
+        for page in 1.. {
+            let (page_txs, total) = self
+                ._find_tx_page(events.clone(), page, TX_SEARCH_PAGE_LIMIT, order_by)
+                .await?;
+            let received = page_txs.len() as u64;
+            txs.extend(page_txs);
+            if is_last_tx_page(received, txs.len() as u64, total) {
+                break;
+            }
+        }
+        Ok(txs)
+    }
+}
+
+/// Whether a page of [`TX_SEARCH_PAGE_LIMIT`] txs is the last one of the tx search,
+/// `fetched` counting the txs of all the pages so far and `total` being 0 if unknown.
+/// Nodes on SDK 0.47+ reject the pages past the last one, so a full last page must be detected with `total`.
+pub(crate) fn is_last_tx_page(received: u64, fetched: u64, total: u64) -> bool {
+    received < TX_SEARCH_PAGE_LIMIT || (total > 0 && fetched >= total)
+}
+
+/// Peer of a node, see [`Node::_net_info`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NodePeer {
+    pub node_id: String,
+    pub moniker: String,
+    pub remote_ip: String,
+    /// Whether the node dialed the peer
+    pub is_outbound: bool,
+}
+
+/// Network state of a node, see [`Node::_net_info`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NetInfo {
+    /// Whether the node accepts incoming connections
+    pub listening: bool,
+    pub listeners: Vec<String>,
+    pub peers: Vec<NodePeer>,
+}
+
+/// Health checks of the node.
+/// The consensus params, network and mempool are only exposed by the Tendermint RPC of the node,
+/// whose endpoint (i.e. `http://localhost:26657`) isn't part of the chain info and has to be provided.
+impl Node {
+    /// Returns the whole validator set at `height`, the latest one if `None`
+    pub async fn _validator_set(
+        &self,
+        height: Option<u64>,
+    ) -> Result<Vec<cosmos_modules::tendermint::Validator>, DaemonError> {
+        let mut validators = vec![];
+        let mut next_key = vec![];
+        loop {
+            let pagination = Some(PageRequest {
+                key: next_key,
+                offset: 0,
+                limit: 100,
+                count_total: false,
+                reverse: false,
+            });
+            let (page, pagination) = match height {
+                Some(height) => {
+                    let resp = self
+                        ._validator_set_by_height(height as i64, pagination)
+                        .await?;
+                    (resp.validators, resp.pagination)
+                }
+                None => {
+                    let resp = self._latest_validator_set(pagination).await?;
+                    (resp.validators, resp.pagination)
+                }
+            };
+            validators.extend(page);
+            match pagination {
+                Some(pagination) if !pagination.next_key.is_empty() => {
+                    next_key = pagination.next_key
+                }
+                _ => break,
+            }
+        }
+        Ok(validators)
+    }
+
+    /// Returns the consensus params at `height`, the latest ones if `None`
+    pub async fn _consensus_params(
+        &self,
+        rpc_url: &str,
+        height: Option<u64>,
+    ) -> Result<cosmrs::tendermint::consensus::Params, DaemonError> {
+        let params: Vec<(&str, String)> = height
+            .map(|height| vec![("height", height.to_string())])
+            .unwrap_or_default();
+        let result = rpc_get(rpc_url, "consensus_params", &params).await?;
+        Ok(serde_json::from_value(result["consensus_params"].clone())?)
+    }
+
+    /// Returns the peers of the node
+    pub async fn _net_info(&self, rpc_url: &str) -> Result<NetInfo, DaemonError> {
+        let result = rpc_get(rpc_url, "net_info", &[]).await?;
+        let string = |value: &serde_json::Value| value.as_str().unwrap_or_default().to_string();
+        Ok(NetInfo {
+            listening: result["listening"].as_bool().unwrap_or_default(),
+            listeners: result["listeners"]
+                .as_array()
+                .map(|listeners| listeners.iter().map(string).collect())
+                .unwrap_or_default(),
+            peers: result["peers"]
+                .as_array()
+                .map(|peers| {
+                    peers
+                        .iter()
+                        .map(|peer| NodePeer {
+                            node_id: string(&peer["node_info"]["id"]),
+                            moniker: string(&peer["node_info"]["moniker"]),
+                            remote_ip: string(&peer["remote_ip"]),
+                            is_outbound: peer["is_outbound"].as_bool().unwrap_or_default(),
+                        })
+                        .collect()
+                })
+                .unwrap_or_default(),
+        })
+    }
+
+    /// Returns the number of transactions waiting in the mempool of the node
+    pub async fn _num_unconfirmed_txs(&self, rpc_url: &str) -> Result<u64, DaemonError> {
+        let result = rpc_get(rpc_url, "num_unconfirmed_txs", &[]).await?;
+        result["total"]
+            .as_str()
+            .and_then(|total| total.parse().ok())
+            .ok_or_else(|| DaemonError::StdErr(format!("Invalid num_unconfirmed_txs: {result}")))
+    }
+
+    /// Synchronous version of [`Node::_inspect_tx`]
+    pub fn inspect_tx(&self, hash: String) -> Result<TxInspection, DaemonError> {
+        self.rt_handle
+            .as_ref()
+            .ok_or(DaemonError::QuerierNeedRuntime)?
+            .block_on(self._inspect_tx(hash))
+    }
+
+    /// Synchronous version of [`Node::_validator_set`]
+    pub fn validator_set(
+        &self,
+        height: Option<u64>,
+    ) -> Result<Vec<cosmos_modules::tendermint::Validator>, DaemonError> {
+        self.rt_handle
+            .as_ref()
+            .ok_or(DaemonError::QuerierNeedRuntime)?
+            .block_on(self._validator_set(height))
+    }
+
+    /// Synchronous version of [`Node::_consensus_params`]
+    pub fn consensus_params(
+        &self,
+        rpc_url: &str,
+        height: Option<u64>,
+    ) -> Result<cosmrs::tendermint::consensus::Params, DaemonError> {
+        self.rt_handle
+            .as_ref()
+            .ok_or(DaemonError::QuerierNeedRuntime)?
+            .block_on(self._consensus_params(rpc_url, height))
+    }
+
+    /// Synchronous version of [`Node::_net_info`]
+    pub fn net_info(&self, rpc_url: &str) -> Result<NetInfo, DaemonError> {
+        self.rt_handle
+            .as_ref()
+            .ok_or(DaemonError::QuerierNeedRuntime)?
+            .block_on(self._net_info(rpc_url))
+    }
+
+    /// Synchronous version of [`Node::_num_unconfirmed_txs`]
+    pub fn num_unconfirmed_txs(&self, rpc_url: &str) -> Result<u64, DaemonError> {
+        self.rt_handle
+            .as_ref()
+            .ok_or(DaemonError::QuerierNeedRuntime)?
+            .block_on(self._num_unconfirmed_txs(rpc_url))
+    }
+}
+
+/// Result of a GET request on a Tendermint RPC endpoint
+async fn rpc_get(
+    rpc_url: &str,
+    endpoint: &str,
+    params: &[(&str, String)],
+) -> Result<serde_json::Value, DaemonError> {
+    let response: serde_json::Value = reqwest::Client::new()
+        .get(format!("{}/{endpoint}", rpc_url.trim_end_matches('/')))
+        .query(params)
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await?;
+    if let Some(error) = response.get("error") {
+        return Err(DaemonError::StdErr(format!(
+            "RPC error on {endpoint}: {error}"
+        )));
+    }
+    response
+        .get("result")
+        .cloned()
+        .ok_or_else(|| DaemonError::StdErr(format!("No result returned by {endpoint}")))
 }
 
 // Now we define traits
@@ -425,3 +842,72 @@ fn block_to_block_info(block: Block) -> Result<BlockInfo, DaemonError> {
         chain_id: block.header.chain_id.to_string(),
     })
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use tokio_util::sync::CancellationToken;
+
+    // Nothing listens on this port, the queries fail right away
+    fn unreachable_node(confirmation: TxConfirmation) -> Node {
+        let channel = Channel::from_static("http://127.0.0.1:1").connect_lazy();
+        Node::new_async(channel).with_polling(PollingConfig::default().confirmation(confirmation))
+    }
+
+    #[tokio::test]
+    async fn interval_confirmation_gives_up() {
+        let node = unreachable_node(TxConfirmation::Interval {
+            interval: Duration::from_millis(10),
+            max_wait: Duration::from_millis(50),
+        });
+
+        let err = node._confirm_tx("HASH".to_string()).await.unwrap_err();
+        let DaemonError::TXNotFound(hash, attempts) = err else {
+            panic!("unexpected error {err:?}");
+        };
+        assert_eq!(hash, "HASH");
+        assert!((1..=6).contains(&attempts));
+    }
+
+    #[tokio::test]
+    async fn interval_confirmation_is_cancelled() {
+        let token = CancellationToken::new();
+        let node = unreachable_node(TxConfirmation::Interval {
+            interval: Duration::from_secs(600),
+            max_wait: Duration::from_secs(3600),
+        });
+        let node = Node {
+            polling: node.polling.clone().cancellation(token.clone()),
+            ..node
+        };
+
+        let confirmation = tokio::spawn(async move { node._confirm_tx("HASH".to_string()).await });
+        token.cancel();
+        assert!(matches!(
+            confirmation.await.unwrap(),
+            Err(DaemonError::Cancelled)
+        ));
+    }
+
+    #[test]
+    fn last_tx_page() {
+        assert!(is_last_tx_page(3, 103, 103));
+        assert!(is_last_tx_page(0, 0, 0));
+        // A full page is the last one if all the txs are fetched
+        assert!(is_last_tx_page(100, 200, 200));
+        assert!(!is_last_tx_page(100, 100, 200));
+        // Without a total, only a partial page ends the search
+        assert!(!is_last_tx_page(100, 100, 0));
+    }
+
+    #[cfg(feature = "websocket")]
+    #[tokio::test]
+    async fn websocket_confirmation_needs_the_rpc() {
+        let node = unreachable_node(TxConfirmation::Websocket {
+            rpc_url: "ws://127.0.0.1:1/websocket".to_string(),
+            max_wait: Duration::from_millis(50),
+        });
+
+        assert!(node._confirm_tx("HASH".to_string()).await.is_err());
+    }
+}
@@ -0,0 +1,503 @@
+//! Clients for the Osmosis pool manager (`x/poolmanager`) and GAMM (`x/gamm`) modules.
+//!
+//! [`PoolManager`] estimates and builds swaps routed through any pool type, [`Gamm`] queries
+//! the shares of the balancer and stableswap pools and builds the messages joining or exiting them.
+//! The `_msg` functions return the messages as `Any`, to be committed with `Stargate::commit_any`:
+//!
+//! ```no_run
+//! use cw_orch_daemon::{queriers::{osmosis_proto::SwapAmountInRoute, PoolManager}, Daemon, networks};
+//! use cw_orch_core::environment::TxHandler;
+//! use cw_orch_traits::Stargate;
+//!
+//! let daemon = Daemon::builder(networks::OSMO_5).build().unwrap();
+//! let routes = vec![SwapAmountInRoute { pool_id: 1, token_out_denom: "uion".to_string() }];
+//! let pool_manager = PoolManager::new(&daemon);
+//! let token_out = pool_manager
+//!     .estimate_swap_exact_amount_in("1000uosmo", routes.clone())
+//!     .unwrap();
+//! daemon
+//!     .commit_any(
+//!         vec![PoolManager::swap_exact_amount_in_msg(
+//!             daemon.sender_addr(),
+//!             routes,
+//!             cosmwasm_std::coin(1000, "uosmo"),
+//!             token_out,
+//!         )],
+//!         None,
+//!     )
+//!     .unwrap();
+//! ```
+use super::grpc_query;
+use crate::{error::DaemonError, senders::query::QuerySender, DaemonBase};
+use cosmrs::proto::cosmos::base::v1beta1::Coin;
+use cw_orch_core::environment::{Querier, QuerierGetter};
+use prost::Message;
+use tokio::runtime::Handle;
+use tonic::transport::Channel;
+
+use proto::{
+    EstimateSwapExactAmountInRequest, EstimateSwapExactAmountInResponse, MsgExitPool, MsgJoinPool,
+    MsgSwapExactAmountIn, NumPoolsRequest, NumPoolsResponse, PoolRequest, PoolResponse,
+    QueryTotalSharesRequest, QueryTotalSharesResponse, SpotPriceRequest, SpotPriceResponse,
+    SwapAmountInRoute,
+};
+
+/// Client for the Osmosis pool manager module, routing the swaps of all the pool types
+/// All the async function are prefixed with `_`
+pub struct PoolManager {
+    pub channel: Channel,
+    pub rt_handle: Option<Handle>,
+}
+
+impl PoolManager {
+    pub fn new<Sender: QuerySender>(daemon: &DaemonBase<Sender>) -> Self {
+        Self {
+            channel: daemon.channel(),
+            rt_handle: Some(daemon.rt_handle.clone()),
+        }
+    }
+
+    pub fn new_async(channel: Channel) -> Self {
+        Self {
+            channel,
+            rt_handle: None,
+        }
+    }
+
+    /// Message swapping `token_in` along `routes`, failing if less than `token_out_min_amount` is received
+    pub fn swap_exact_amount_in_msg(
+        sender: impl ToString,
+        routes: Vec<SwapAmountInRoute>,
+        token_in: cosmwasm_std::Coin,
+        token_out_min_amount: impl ToString,
+    ) -> prost_types::Any {
+        let msg = MsgSwapExactAmountIn {
+            sender: sender.to_string(),
+            routes,
+            token_in: Some(to_proto_coin(token_in)),
+            token_out_min_amount: token_out_min_amount.to_string(),
+        };
+        prost_types::Any {
+            type_url: "/osmosis.poolmanager.v1beta1.MsgSwapExactAmountIn".to_string(),
+            value: msg.encode_to_vec(),
+        }
+    }
+}
+
+impl Querier for PoolManager {
+    type Error = DaemonError;
+}
+
+impl<Sender: QuerySender> QuerierGetter<PoolManager> for DaemonBase<Sender> {
+    fn querier(&self) -> PoolManager {
+        PoolManager::new(self)
+    }
+}
+
+impl PoolManager {
+    /// Query the number of pools
+    pub async fn _num_pools(&self) -> Result<u64, DaemonError> {
+        let response: NumPoolsResponse = grpc_query(
+            &self.channel,
+            "/osmosis.poolmanager.v1beta1.Query/NumPools",
+            NumPoolsRequest {},
+        )
+        .await?;
+        Ok(response.num_pools)
+    }
+
+    /// Synchronous version of [`PoolManager::_num_pools`]
+    pub fn num_pools(&self) -> Result<u64, DaemonError> {
+        self.rt_handle
+            .as_ref()
+            .ok_or(DaemonError::QuerierNeedRuntime)?
+            .block_on(self._num_pools())
+    }
+
+    /// Query a pool, encoded as its pool type (balancer, stableswap, concentrated liquidity, cosmwasm)
+    pub async fn _pool(&self, pool_id: u64) -> Result<prost_types::Any, DaemonError> {
+        let response: PoolResponse = grpc_query(
+            &self.channel,
+            "/osmosis.poolmanager.v1beta1.Query/Pool",
+            PoolRequest { pool_id },
+        )
+        .await?;
+        response
+            .pool
+            .ok_or(DaemonError::StdErr(format!("No pool with id {pool_id}")))
+    }
+
+    /// Synchronous version of [`PoolManager::_pool`]
+    pub fn pool(&self, pool_id: u64) -> Result<prost_types::Any, DaemonError> {
+        self.rt_handle
+            .as_ref()
+            .ok_or(DaemonError::QuerierNeedRuntime)?
+            .block_on(self._pool(pool_id))
+    }
+
+    /// Query the spot price of `base_asset_denom` in `quote_asset_denom` in a pool, as a decimal string
+    pub async fn _spot_price(
+        &self,
+        pool_id: u64,
+        base_asset_denom: impl Into<String>,
+        quote_asset_denom: impl Into<String>,
+    ) -> Result<String, DaemonError> {
+        let response: SpotPriceResponse = grpc_query(
+            &self.channel,
+            "/osmosis.poolmanager.v1beta1.Query/SpotPrice",
+            SpotPriceRequest {
+                pool_id,
+                base_asset_denom: base_asset_denom.into(),
+                quote_asset_denom: quote_asset_denom.into(),
+            },
+        )
+        .await?;
+        Ok(response.spot_price)
+    }
+
+    /// Synchronous version of [`PoolManager::_spot_price`]
+    pub fn spot_price(
+        &self,
+        pool_id: u64,
+        base_asset_denom: impl Into<String>,
+        quote_asset_denom: impl Into<String>,
+    ) -> Result<String, DaemonError> {
+        self.rt_handle
+            .as_ref()
+            .ok_or(DaemonError::QuerierNeedRuntime)?
+            .block_on(self._spot_price(pool_id, base_asset_denom, quote_asset_denom))
+    }
+
+    /// Estimate the amount received when swapping `token_in` (e.g. `1000uosmo`) along `routes`
+    pub async fn _estimate_swap_exact_amount_in(
+        &self,
+        token_in: impl Into<String>,
+        routes: Vec<SwapAmountInRoute>,
+    ) -> Result<String, DaemonError> {
+        let response: EstimateSwapExactAmountInResponse = grpc_query(
+            &self.channel,
+            "/osmosis.poolmanager.v1beta1.Query/EstimateSwapExactAmountIn",
+            EstimateSwapExactAmountInRequest {
+                token_in: token_in.into(),
+                routes,
+            },
+        )
+        .await?;
+        Ok(response.token_out_amount)
+    }
+
+    /// Synchronous version of [`PoolManager::_estimate_swap_exact_amount_in`]
+    pub fn estimate_swap_exact_amount_in(
+        &self,
+        token_in: impl Into<String>,
+        routes: Vec<SwapAmountInRoute>,
+    ) -> Result<String, DaemonError> {
+        self.rt_handle
+            .as_ref()
+            .ok_or(DaemonError::QuerierNeedRuntime)?
+            .block_on(self._estimate_swap_exact_amount_in(token_in, routes))
+    }
+}
+
+/// Client for the Osmosis GAMM module, managing the liquidity of the balancer and stableswap pools
+/// All the async function are prefixed with `_`
+pub struct Gamm {
+    pub channel: Channel,
+    pub rt_handle: Option<Handle>,
+}
+
+impl Gamm {
+    pub fn new<Sender: QuerySender>(daemon: &DaemonBase<Sender>) -> Self {
+        Self {
+            channel: daemon.channel(),
+            rt_handle: Some(daemon.rt_handle.clone()),
+        }
+    }
+
+    pub fn new_async(channel: Channel) -> Self {
+        Self {
+            channel,
+            rt_handle: None,
+        }
+    }
+
+    /// Message minting `share_out_amount` shares of a pool, depositing at most `token_in_maxs`
+    pub fn join_pool_msg(
+        sender: impl ToString,
+        pool_id: u64,
+        share_out_amount: impl ToString,
+        token_in_maxs: Vec<cosmwasm_std::Coin>,
+    ) -> prost_types::Any {
+        let msg = MsgJoinPool {
+            sender: sender.to_string(),
+            pool_id,
+            share_out_amount: share_out_amount.to_string(),
+            token_in_maxs: token_in_maxs.into_iter().map(to_proto_coin).collect(),
+        };
+        prost_types::Any {
+            type_url: "/osmosis.gamm.v1beta1.MsgJoinPool".to_string(),
+            value: msg.encode_to_vec(),
+        }
+    }
+
+    /// Message burning `share_in_amount` shares of a pool, withdrawing at least `token_out_mins`
+    pub fn exit_pool_msg(
+        sender: impl ToString,
+        pool_id: u64,
+        share_in_amount: impl ToString,
+        token_out_mins: Vec<cosmwasm_std::Coin>,
+    ) -> prost_types::Any {
+        let msg = MsgExitPool {
+            sender: sender.to_string(),
+            pool_id,
+            share_in_amount: share_in_amount.to_string(),
+            token_out_mins: token_out_mins.into_iter().map(to_proto_coin).collect(),
+        };
+        prost_types::Any {
+            type_url: "/osmosis.gamm.v1beta1.MsgExitPool".to_string(),
+            value: msg.encode_to_vec(),
+        }
+    }
+}
+
+impl Querier for Gamm {
+    type Error = DaemonError;
+}
+
+impl<Sender: QuerySender> QuerierGetter<Gamm> for DaemonBase<Sender> {
+    fn querier(&self) -> Gamm {
+        Gamm::new(self)
+    }
+}
+
+impl Gamm {
+    /// Query the total shares of a pool
+    pub async fn _total_shares(&self, pool_id: u64) -> Result<cosmwasm_std::Coin, DaemonError> {
+        let response: QueryTotalSharesResponse = grpc_query(
+            &self.channel,
+            "/osmosis.gamm.v1beta1.Query/TotalShares",
+            QueryTotalSharesRequest { pool_id },
+        )
+        .await?;
+        let shares = response
+            .total_shares
+            .ok_or(DaemonError::StdErr(format!("No pool with id {pool_id}")))?;
+        Ok(cosmwasm_std::Coin {
+            amount: shares.amount.parse()?,
+            denom: shares.denom,
+        })
+    }
+
+    /// Synchronous version of [`Gamm::_total_shares`]
+    pub fn total_shares(&self, pool_id: u64) -> Result<cosmwasm_std::Coin, DaemonError> {
+        self.rt_handle
+            .as_ref()
+            .ok_or(DaemonError::QuerierNeedRuntime)?
+            .block_on(self._total_shares(pool_id))
+    }
+}
+
+fn to_proto_coin(coin: cosmwasm_std::Coin) -> Coin {
+    Coin {
+        denom: coin.denom,
+        amount: coin.amount.to_string(),
+    }
+}
+
+/// Messages of the Osmosis `poolmanager` and `gamm` modules
+pub mod proto {
+    use cosmrs::proto::cosmos::base::v1beta1::Coin;
+
+    /// Hop of a swap, through `pool_id` to `token_out_denom`
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct SwapAmountInRoute {
+        #[prost(uint64, tag = "1")]
+        pub pool_id: u64,
+        #[prost(string, tag = "2")]
+        pub token_out_denom: String,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct NumPoolsRequest {}
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct NumPoolsResponse {
+        #[prost(uint64, tag = "1")]
+        pub num_pools: u64,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct PoolRequest {
+        #[prost(uint64, tag = "1")]
+        pub pool_id: u64,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct PoolResponse {
+        #[prost(message, optional, tag = "1")]
+        pub pool: Option<prost_types::Any>,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct SpotPriceRequest {
+        #[prost(uint64, tag = "1")]
+        pub pool_id: u64,
+        #[prost(string, tag = "2")]
+        pub base_asset_denom: String,
+        #[prost(string, tag = "3")]
+        pub quote_asset_denom: String,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct SpotPriceResponse {
+        /// Price as a decimal string
+        #[prost(string, tag = "1")]
+        pub spot_price: String,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct EstimateSwapExactAmountInRequest {
+        /// Coin swapped, e.g. `1000uosmo`
+        #[prost(string, tag = "3")]
+        pub token_in: String,
+        #[prost(message, repeated, tag = "4")]
+        pub routes: Vec<SwapAmountInRoute>,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct EstimateSwapExactAmountInResponse {
+        #[prost(string, tag = "1")]
+        pub token_out_amount: String,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct MsgSwapExactAmountIn {
+        #[prost(string, tag = "1")]
+        pub sender: String,
+        #[prost(message, repeated, tag = "2")]
+        pub routes: Vec<SwapAmountInRoute>,
+        #[prost(message, optional, tag = "3")]
+        pub token_in: Option<Coin>,
+        #[prost(string, tag = "4")]
+        pub token_out_min_amount: String,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct QueryTotalSharesRequest {
+        #[prost(uint64, tag = "1")]
+        pub pool_id: u64,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct QueryTotalSharesResponse {
+        #[prost(message, optional, tag = "1")]
+        pub total_shares: Option<Coin>,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct MsgJoinPool {
+        #[prost(string, tag = "1")]
+        pub sender: String,
+        #[prost(uint64, tag = "2")]
+        pub pool_id: u64,
+        #[prost(string, tag = "3")]
+        pub share_out_amount: String,
+        #[prost(message, repeated, tag = "4")]
+        pub token_in_maxs: Vec<Coin>,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct MsgExitPool {
+        #[prost(string, tag = "1")]
+        pub sender: String,
+        #[prost(uint64, tag = "2")]
+        pub pool_id: u64,
+        #[prost(string, tag = "3")]
+        pub share_in_amount: String,
+        #[prost(message, repeated, tag = "4")]
+        pub token_out_mins: Vec<Coin>,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use cosmwasm_std::{coin, Binary};
+    use osmosis_std::types::{
+        cosmos::base::v1beta1::Coin as UpstreamCoin,
+        osmosis::{gamm::v1beta1 as gamm, poolmanager::v1beta1 as poolmanager},
+    };
+
+    fn upstream_coin(amount: &str, denom: &str) -> UpstreamCoin {
+        UpstreamCoin {
+            denom: denom.to_string(),
+            amount: amount.to_string(),
+        }
+    }
+
+    #[test]
+    fn msgs_match_osmosis_std() {
+        let any = PoolManager::swap_exact_amount_in_msg(
+            "osmo1sender",
+            vec![SwapAmountInRoute {
+                pool_id: 1,
+                token_out_denom: "uion".to_string(),
+            }],
+            coin(1000, "uosmo"),
+            990u128,
+        );
+        let upstream = poolmanager::MsgSwapExactAmountIn {
+            sender: "osmo1sender".to_string(),
+            routes: vec![poolmanager::SwapAmountInRoute {
+                pool_id: 1,
+                token_out_denom: "uion".to_string(),
+            }],
+            token_in: Some(upstream_coin("1000", "uosmo")),
+            token_out_min_amount: "990".to_string(),
+        };
+        assert_eq!(any.type_url, poolmanager::MsgSwapExactAmountIn::TYPE_URL);
+        assert_eq!(Binary::new(any.value), Binary::from(upstream));
+
+        let any = Gamm::join_pool_msg("osmo1sender", 1, 100u128, vec![coin(10, "uosmo")]);
+        let upstream = gamm::MsgJoinPool {
+            sender: "osmo1sender".to_string(),
+            pool_id: 1,
+            share_out_amount: "100".to_string(),
+            token_in_maxs: vec![upstream_coin("10", "uosmo")],
+        };
+        assert_eq!(any.type_url, gamm::MsgJoinPool::TYPE_URL);
+        assert_eq!(Binary::new(any.value), Binary::from(upstream));
+
+        let any = Gamm::exit_pool_msg("osmo1sender", 1, 100u128, vec![coin(9, "uosmo")]);
+        let upstream = gamm::MsgExitPool {
+            sender: "osmo1sender".to_string(),
+            pool_id: 1,
+            share_in_amount: "100".to_string(),
+            token_out_mins: vec![upstream_coin("9", "uosmo")],
+        };
+        assert_eq!(any.type_url, gamm::MsgExitPool::TYPE_URL);
+        assert_eq!(Binary::new(any.value), Binary::from(upstream));
+    }
+
+    #[test]
+    fn decodes_osmosis_std_responses() {
+        let upstream = gamm::QueryTotalSharesResponse {
+            total_shares: Some(upstream_coin("100", "gamm/pool/1")),
+        };
+        let response = QueryTotalSharesResponse::decode(Binary::from(upstream).as_slice()).unwrap();
+        assert_eq!(
+            response.total_shares,
+            Some(Coin {
+                denom: "gamm/pool/1".to_string(),
+                amount: "100".to_string(),
+            })
+        );
+
+        let upstream = poolmanager::EstimateSwapExactAmountInResponse {
+            token_out_amount: "990".to_string(),
+        };
+        let response =
+            EstimateSwapExactAmountInResponse::decode(Binary::from(upstream).as_slice()).unwrap();
+        assert_eq!(response.token_out_amount, "990");
+    }
+}
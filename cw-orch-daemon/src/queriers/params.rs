@@ -0,0 +1,230 @@
+use std::time::Duration;
+
+use crate::{
+    cosmos_modules, error::DaemonError, senders::query::QuerySender, DaemonAsyncBase, DaemonBase,
+};
+use cosmwasm_std::Coin;
+use cw_orch_core::environment::{AccessConfig, Querier, QuerierGetter};
+use tokio::runtime::Handle;
+use tonic::transport::Channel;
+
+use super::{bank::cosmrs_to_cosmwasm_coins, CosmWasm, Freshness, Gov, QueryCache, Staking};
+
+/// Key module parameters of a chain, as needed by deployment preflights and reports
+#[derive(Clone, Debug, PartialEq)]
+pub struct ChainParams {
+    /// Time before unbonded tokens are released
+    pub unbonding_time: Duration,
+    /// Denom of the staking token
+    pub bond_denom: String,
+    /// Maximum number of validators in the active set
+    pub max_validators: u32,
+    /// Duration of the voting period of governance proposals
+    pub voting_period: Duration,
+    /// Minimum deposit for a proposal to enter the voting period
+    pub min_deposit: Vec<Coin>,
+    /// Number of blocks over which the validator liveness is tracked
+    pub signed_blocks_window: i64,
+    /// Time a validator is jailed for after missing too many blocks
+    pub downtime_jail_duration: Duration,
+    /// Who can upload wasm codes
+    pub code_upload_access: AccessConfig,
+    /// Default instantiate permission of the uploaded codes
+    pub instantiate_default_permission: AccessConfig,
+    /// Maximum size of a wasm code, `None` if the chain sets it in its node configuration instead of the params module
+    pub max_wasm_size: Option<usize>,
+    /// Maximum gas of a block, `None` if the chain doesn't expose its consensus params through the params module or has no limit
+    pub max_block_gas: Option<u64>,
+}
+
+/// Querier aggregating the parameters of the Cosmos modules
+/// All the async function are prefixed with `_`
+pub struct Params {
+    pub channel: Channel,
+    pub rt_handle: Option<Handle>,
+    /// Cache of the chain params, see [`QueryCache`]
+    pub cache: Option<QueryCache>,
+}
+
+impl Params {
+    pub fn new<Sender: QuerySender>(daemon: &DaemonBase<Sender>) -> Self {
+        Self {
+            channel: daemon.channel(),
+            rt_handle: Some(daemon.rt_handle.clone()),
+            cache: daemon.daemon.query_cache.clone(),
+        }
+    }
+
+    pub fn new_async(channel: Channel) -> Self {
+        Self {
+            channel,
+            rt_handle: None,
+            cache: None,
+        }
+    }
+
+    /// Async querier sharing the [`QueryCache`] of the daemon
+    pub fn from_async<Sender: QuerySender>(daemon: &DaemonAsyncBase<Sender>) -> Self {
+        Self {
+            channel: daemon.channel(),
+            rt_handle: None,
+            cache: daemon.query_cache.clone(),
+        }
+    }
+
+    /// Caches the chain params in `cache`
+    pub fn with_cache(mut self, cache: QueryCache) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+}
+
+impl Querier for Params {
+    type Error = DaemonError;
+}
+
+impl<Sender: QuerySender> QuerierGetter<Params> for DaemonBase<Sender> {
+    fn querier(&self) -> Params {
+        Params::new(self)
+    }
+}
+
+impl Params {
+    /// Query the parameters of the staking, gov, slashing and wasm modules at once
+    pub fn chain_params(&self) -> Result<ChainParams, DaemonError> {
+        self.rt_handle
+            .as_ref()
+            .ok_or(DaemonError::QuerierNeedRuntime)?
+            .block_on(self._chain_params())
+    }
+
+    /// Query the parameters of the staking, gov, slashing and wasm modules at once
+    pub async fn _chain_params(&self) -> Result<ChainParams, DaemonError> {
+        match &self.cache {
+            Some(cache) => {
+                cache
+                    .get_or_fetch(
+                        "chain_params",
+                        Freshness::UntilNextTx,
+                        self.query_chain_params(),
+                    )
+                    .await
+            }
+            None => self.query_chain_params().await,
+        }
+    }
+
+    async fn query_chain_params(&self) -> Result<ChainParams, DaemonError> {
+        let staking = Staking::new_async(self.channel.clone())._params().await?;
+        let staking = staking.params.unwrap_or_default();
+        let gov = Gov::new_async(self.channel.clone());
+        let voting = gov._params("voting").await?.voting_params;
+        let deposit = gov._params("deposit").await?.deposit_params;
+        let slashing = self._slashing_params().await?;
+        let wasm = CosmWasm::new_async(self.channel.clone())._params().await?;
+        let wasm = wasm.params.unwrap_or_default();
+
+        Ok(ChainParams {
+            unbonding_time: to_duration(staking.unbonding_time),
+            bond_denom: staking.bond_denom,
+            max_validators: staking.max_validators,
+            voting_period: to_duration(voting.and_then(|voting| voting.voting_period)),
+            min_deposit: cosmrs_to_cosmwasm_coins(
+                deposit
+                    .map(|deposit| deposit.min_deposit)
+                    .unwrap_or_default(),
+            )?,
+            signed_blocks_window: slashing.signed_blocks_window,
+            downtime_jail_duration: to_duration(slashing.downtime_jail_duration),
+            code_upload_access: to_access_config(wasm.code_upload_access),
+            instantiate_default_permission: to_access_config(Some(
+                cosmos_modules::cosmwasm::AccessConfig {
+                    permission: wasm.instantiate_default_permission,
+                    addresses: vec![],
+                },
+            )),
+            max_wasm_size: self._max_wasm_size().await,
+            max_block_gas: self._max_block_gas().await,
+        })
+    }
+
+    /// Query the parameters of the slashing module
+    pub async fn _slashing_params(&self) -> Result<cosmos_modules::slashing::Params, DaemonError> {
+        let params: cosmos_modules::slashing::QueryParamsResponse =
+            cosmos_query!(self, slashing, params, QueryParamsRequest {});
+        Ok(params.params.unwrap_or_default())
+    }
+
+    /// Query the maximum gas of a block from the `baseapp` params subspace.
+    /// Chains that moved the consensus params to their own module don't expose them there.
+    pub async fn _max_block_gas(&self) -> Option<u64> {
+        let params = self._subspace_param("baseapp", "BlockParams").await.ok()?;
+        let block_params: serde_json::Value = serde_json::from_str(&params).ok()?;
+        // -1 means no limit
+        block_params["max_gas"].as_str()?.parse().ok()
+    }
+
+    /// Query the maximum size of a wasm code from the `wasm` params subspace.
+    /// Only the chains running wasmd < 0.29 expose it there, newer ones set it in the node configuration.
+    pub async fn _max_wasm_size(&self) -> Option<usize> {
+        let param = self._subspace_param("wasm", "MaxWasmCodeSize").await.ok()?;
+        parse_amino_uint(&param)
+    }
+
+    /// Query a raw parameter of the params module, as JSON
+    pub async fn _subspace_param(
+        &self,
+        subspace: impl Into<String>,
+        key: impl Into<String>,
+    ) -> Result<String, DaemonError> {
+        let params: cosmos_modules::params::QueryParamsResponse = cosmos_query!(
+            self,
+            params,
+            params,
+            QueryParamsRequest {
+                subspace: subspace.into(),
+                key: key.into()
+            }
+        );
+        Ok(params.param.unwrap_or_default().value)
+    }
+}
+
+/// Amino JSON encodes 64-bit integers as strings
+fn parse_amino_uint(value: &str) -> Option<usize> {
+    serde_json::from_str::<String>(value).ok()?.parse().ok()
+}
+
+/// Converts a protobuf duration, missing or negative durations are zero
+fn to_duration<D: TryInto<Duration>>(duration: Option<D>) -> Duration {
+    duration
+        .and_then(|duration| duration.try_into().ok())
+        .unwrap_or_default()
+}
+
+pub(crate) fn to_access_config(
+    access: Option<cosmos_modules::cosmwasm::AccessConfig>,
+) -> AccessConfig {
+    use cosmos_modules::cosmwasm::AccessType;
+    let Some(access) = access else {
+        return AccessConfig::Unspecified;
+    };
+    match access.permission() {
+        AccessType::Nobody => AccessConfig::Nobody,
+        AccessType::Everybody => AccessConfig::Everybody,
+        AccessType::AnyOfAddresses => AccessConfig::AnyOfAddresses(access.addresses),
+        _ => AccessConfig::Unspecified,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_amino_uints() {
+        assert_eq!(parse_amino_uint("\"1228800\""), Some(1228800));
+        assert_eq!(parse_amino_uint("1228800"), None);
+        assert_eq!(parse_amino_uint(""), None);
+    }
+}
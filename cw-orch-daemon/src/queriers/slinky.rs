@@ -0,0 +1,403 @@
+//! Queriers for the Slinky oracle (`x/oracle`) and market map (`x/marketmap`) modules, used by Neutron and dYdX among others.
+//!
+//! [`Oracle`] reads the latest quote of the tracked currency pairs, [`MarketMap`] the markets configured
+//! for them and builds the upserts that seed a local chain with new markets.
+use super::grpc_query;
+use crate::{error::DaemonError, senders::query::QuerySender, DaemonBase};
+use cw_orch_core::environment::{Querier, QuerierGetter};
+use prost::Message;
+use tokio::runtime::Handle;
+use tonic::transport::Channel;
+
+use proto::{
+    CurrencyPair, GetAllCurrencyPairsRequest, GetAllCurrencyPairsResponse, GetPriceRequest,
+    GetPriceResponse, GetPricesRequest, GetPricesResponse, Market, MarketMapRequest,
+    MarketMapResponse, MarketRequest, MarketResponse, MsgUpsertMarkets,
+};
+
+/// Querier for the Slinky oracle module
+/// All the async function are prefixed with `_`
+pub struct Oracle {
+    pub channel: Channel,
+    pub rt_handle: Option<Handle>,
+}
+
+impl Oracle {
+    pub fn new<Sender: QuerySender>(daemon: &DaemonBase<Sender>) -> Self {
+        Self {
+            channel: daemon.channel(),
+            rt_handle: Some(daemon.rt_handle.clone()),
+        }
+    }
+
+    pub fn new_async(channel: Channel) -> Self {
+        Self {
+            channel,
+            rt_handle: None,
+        }
+    }
+}
+
+impl Querier for Oracle {
+    type Error = DaemonError;
+}
+
+impl<Sender: QuerySender> QuerierGetter<Oracle> for DaemonBase<Sender> {
+    fn querier(&self) -> Oracle {
+        Oracle::new(self)
+    }
+}
+
+impl Oracle {
+    /// Query the price of a currency pair, e.g. `BTC/USD`
+    pub async fn _price(
+        &self,
+        currency_pair: impl ToString,
+    ) -> Result<GetPriceResponse, DaemonError> {
+        grpc_query(
+            &self.channel,
+            "/slinky.oracle.v1.Query/GetPrice",
+            GetPriceRequest {
+                currency_pair: currency_pair.to_string(),
+            },
+        )
+        .await
+    }
+
+    /// Synchronous version of [`Oracle::_price`]
+    pub fn price(&self, currency_pair: impl ToString) -> Result<GetPriceResponse, DaemonError> {
+        self.rt_handle
+            .as_ref()
+            .ok_or(DaemonError::QuerierNeedRuntime)?
+            .block_on(self._price(currency_pair))
+    }
+
+    /// Query the prices of several currency pairs
+    pub async fn _prices(
+        &self,
+        currency_pairs: Vec<String>,
+    ) -> Result<Vec<GetPriceResponse>, DaemonError> {
+        let response: GetPricesResponse = grpc_query(
+            &self.channel,
+            "/slinky.oracle.v1.Query/GetPrices",
+            GetPricesRequest {
+                currency_pair_ids: currency_pairs,
+            },
+        )
+        .await?;
+        Ok(response.prices)
+    }
+
+    /// Synchronous version of [`Oracle::_prices`]
+    pub fn prices(
+        &self,
+        currency_pairs: Vec<String>,
+    ) -> Result<Vec<GetPriceResponse>, DaemonError> {
+        self.rt_handle
+            .as_ref()
+            .ok_or(DaemonError::QuerierNeedRuntime)?
+            .block_on(self._prices(currency_pairs))
+    }
+
+    /// Query all the currency pairs tracked by the oracle
+    pub async fn _currency_pairs(&self) -> Result<Vec<CurrencyPair>, DaemonError> {
+        let response: GetAllCurrencyPairsResponse = grpc_query(
+            &self.channel,
+            "/slinky.oracle.v1.Query/GetAllCurrencyPairs",
+            GetAllCurrencyPairsRequest {},
+        )
+        .await?;
+        Ok(response.currency_pairs)
+    }
+
+    /// Synchronous version of [`Oracle::_currency_pairs`]
+    pub fn currency_pairs(&self) -> Result<Vec<CurrencyPair>, DaemonError> {
+        self.rt_handle
+            .as_ref()
+            .ok_or(DaemonError::QuerierNeedRuntime)?
+            .block_on(self._currency_pairs())
+    }
+}
+
+/// Querier for the Slinky market map module
+/// All the async function are prefixed with `_`
+pub struct MarketMap {
+    pub channel: Channel,
+    pub rt_handle: Option<Handle>,
+}
+
+impl MarketMap {
+    pub fn new<Sender: QuerySender>(daemon: &DaemonBase<Sender>) -> Self {
+        Self {
+            channel: daemon.channel(),
+            rt_handle: Some(daemon.rt_handle.clone()),
+        }
+    }
+
+    pub fn new_async(channel: Channel) -> Self {
+        Self {
+            channel,
+            rt_handle: None,
+        }
+    }
+
+    /// Message creating or updating `markets`, to be committed by one of the market authorities of the chain
+    /// (e.g. with `Stargate::commit_any`). This is how local chains are seeded with the markets the contracts under test depend on.
+    pub fn upsert_markets_msg(authority: impl ToString, markets: Vec<Market>) -> prost_types::Any {
+        let msg = MsgUpsertMarkets {
+            authority: authority.to_string(),
+            markets,
+        };
+        prost_types::Any {
+            type_url: "/slinky.marketmap.v1.MsgUpsertMarkets".to_string(),
+            value: msg.encode_to_vec(),
+        }
+    }
+}
+
+impl Querier for MarketMap {
+    type Error = DaemonError;
+}
+
+impl<Sender: QuerySender> QuerierGetter<MarketMap> for DaemonBase<Sender> {
+    fn querier(&self) -> MarketMap {
+        MarketMap::new(self)
+    }
+}
+
+impl MarketMap {
+    /// Query all the markets, indexed by currency pair
+    pub async fn _market_map(&self) -> Result<MarketMapResponse, DaemonError> {
+        grpc_query(
+            &self.channel,
+            "/slinky.marketmap.v1.Query/MarketMap",
+            MarketMapRequest {},
+        )
+        .await
+    }
+
+    /// Synchronous version of [`MarketMap::_market_map`]
+    pub fn market_map(&self) -> Result<MarketMapResponse, DaemonError> {
+        self.rt_handle
+            .as_ref()
+            .ok_or(DaemonError::QuerierNeedRuntime)?
+            .block_on(self._market_map())
+    }
+
+    /// Query the market of a currency pair
+    pub async fn _market(&self, currency_pair: CurrencyPair) -> Result<Market, DaemonError> {
+        let response: MarketResponse = grpc_query(
+            &self.channel,
+            "/slinky.marketmap.v1.Query/Market",
+            MarketRequest {
+                currency_pair: Some(currency_pair.clone()),
+            },
+        )
+        .await?;
+        response.market.ok_or(DaemonError::StdErr(format!(
+            "No market for {}/{}",
+            currency_pair.base, currency_pair.quote
+        )))
+    }
+
+    /// Synchronous version of [`MarketMap::_market`]
+    pub fn market(&self, currency_pair: CurrencyPair) -> Result<Market, DaemonError> {
+        self.rt_handle
+            .as_ref()
+            .ok_or(DaemonError::QuerierNeedRuntime)?
+            .block_on(self._market(currency_pair))
+    }
+}
+
+/// Messages of the Slinky `oracle` and `marketmap` modules
+pub mod proto {
+    use std::collections::HashMap;
+
+    /// Pair of currencies, `Base/Quote`
+    #[derive(Clone, PartialEq, Eq, Hash, prost::Message)]
+    pub struct CurrencyPair {
+        #[prost(string, tag = "1")]
+        pub base: String,
+        #[prost(string, tag = "2")]
+        pub quote: String,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct GetPriceRequest {
+        /// Currency pair as `Base/Quote`
+        #[prost(string, tag = "1")]
+        pub currency_pair: String,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct QuotePrice {
+        /// Price as an integer, to divide by 10^decimals
+        #[prost(string, tag = "1")]
+        pub price: String,
+        #[prost(message, optional, tag = "2")]
+        pub block_timestamp: Option<prost_types::Timestamp>,
+        #[prost(uint64, tag = "3")]
+        pub block_height: u64,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct GetPriceResponse {
+        #[prost(message, optional, tag = "1")]
+        pub price: Option<QuotePrice>,
+        #[prost(uint64, tag = "2")]
+        pub nonce: u64,
+        #[prost(uint64, tag = "3")]
+        pub decimals: u64,
+        #[prost(uint64, tag = "4")]
+        pub id: u64,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct GetPricesRequest {
+        #[prost(string, repeated, tag = "1")]
+        pub currency_pair_ids: Vec<String>,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct GetPricesResponse {
+        #[prost(message, repeated, tag = "1")]
+        pub prices: Vec<GetPriceResponse>,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct GetAllCurrencyPairsRequest {}
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct GetAllCurrencyPairsResponse {
+        #[prost(message, repeated, tag = "1")]
+        pub currency_pairs: Vec<CurrencyPair>,
+    }
+
+    /// Currency pair tracked by the oracle, with the parameters of its aggregation
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct Ticker {
+        #[prost(message, optional, tag = "1")]
+        pub currency_pair: Option<CurrencyPair>,
+        #[prost(uint64, tag = "2")]
+        pub decimals: u64,
+        #[prost(uint64, tag = "3")]
+        pub min_provider_count: u64,
+        #[prost(bool, tag = "14")]
+        pub enabled: bool,
+        #[prost(string, tag = "15")]
+        pub metadata_json: String,
+    }
+
+    /// Source of the price of a ticker
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct ProviderConfig {
+        #[prost(string, tag = "1")]
+        pub name: String,
+        #[prost(string, tag = "2")]
+        pub off_chain_ticker: String,
+        #[prost(message, optional, tag = "3")]
+        pub normalize_by_pair: Option<CurrencyPair>,
+        #[prost(bool, tag = "4")]
+        pub invert: bool,
+        #[prost(string, tag = "15")]
+        pub metadata_json: String,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct Market {
+        #[prost(message, optional, tag = "1")]
+        pub ticker: Option<Ticker>,
+        #[prost(message, repeated, tag = "2")]
+        pub provider_configs: Vec<ProviderConfig>,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct MarketMapMarkets {
+        /// Markets indexed by currency pair, as `Base/Quote`
+        #[prost(map = "string, message", tag = "1")]
+        pub markets: HashMap<String, Market>,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct MarketMapRequest {}
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct MarketMapResponse {
+        #[prost(message, optional, tag = "1")]
+        pub market_map: Option<MarketMapMarkets>,
+        /// Height of the last update of the market map
+        #[prost(uint64, tag = "2")]
+        pub last_updated: u64,
+        #[prost(string, tag = "3")]
+        pub chain_id: String,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct MarketRequest {
+        #[prost(message, optional, tag = "1")]
+        pub currency_pair: Option<CurrencyPair>,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct MarketResponse {
+        #[prost(message, optional, tag = "1")]
+        pub market: Option<Market>,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct MsgUpsertMarkets {
+        #[prost(string, tag = "1")]
+        pub authority: String,
+        #[prost(message, repeated, tag = "2")]
+        pub markets: Vec<Market>,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use cosmwasm_std::Binary;
+    use neutron_std::types::slinky::{marketmap::v1 as upstream_marketmap, oracle::v1 as upstream};
+    use proto::QuotePrice;
+
+    #[test]
+    fn decodes_neutron_std_prices() {
+        let response = upstream::GetPricesResponse {
+            prices: vec![upstream::GetPriceResponse {
+                price: Some(upstream::QuotePrice {
+                    price: "6500000000000".to_string(),
+                    block_height: 42,
+                    ..Default::default()
+                }),
+                nonce: 3,
+                decimals: 8,
+                id: 1,
+            }],
+        };
+        let decoded = GetPricesResponse::decode(Binary::from(response).as_slice()).unwrap();
+        assert_eq!(
+            decoded.prices,
+            vec![GetPriceResponse {
+                price: Some(QuotePrice {
+                    price: "6500000000000".to_string(),
+                    block_timestamp: None,
+                    block_height: 42,
+                }),
+                nonce: 3,
+                decimals: 8,
+                id: 1,
+            }]
+        );
+    }
+
+    #[test]
+    fn upsert_markets_msg_matches_neutron_std() {
+        let any = MarketMap::upsert_markets_msg("neutron1authority", vec![]);
+        let msg = upstream_marketmap::MsgUpsertMarkets {
+            authority: "neutron1authority".to_string(),
+            markets: vec![],
+        };
+        assert_eq!(any.type_url, upstream_marketmap::MsgUpsertMarkets::TYPE_URL);
+        assert_eq!(Binary::new(any.value), Binary::from(msg));
+    }
+}
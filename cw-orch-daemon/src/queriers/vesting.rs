@@ -0,0 +1,223 @@
+use crate::{
+    cosmos_modules::vesting::{BaseVestingAccount, Period},
+    error::DaemonError,
+    proto::account::Account,
+    senders::query::QuerySender,
+    DaemonBase,
+};
+use cosmwasm_std::{Addr, Coin};
+use cw_orch_core::environment::{Querier, QuerierGetter};
+use tokio::runtime::Handle;
+use tonic::transport::Channel;
+
+use super::{cosmrs_to_cosmwasm_coins, Auth};
+
+/// Unlocking period of a periodic vesting account
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VestingPeriod {
+    /// Duration of the period in seconds
+    pub length: i64,
+    /// Amount unlocked at the end of the period
+    pub amount: Vec<Coin>,
+}
+
+/// How the tokens of a vesting account are unlocked.
+/// All the times are unix timestamps in seconds.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum VestingSchedule {
+    /// Unlocked linearly between `start_time` and `end_time`
+    Continuous { start_time: i64, end_time: i64 },
+    /// Unlocked all at once at `end_time`
+    Delayed { end_time: i64 },
+    /// Unlocked at the end of each period, the first one starting at `start_time`
+    Periodic {
+        start_time: i64,
+        periods: Vec<VestingPeriod>,
+    },
+    /// Never unlocked, the tokens can only be delegated
+    PermanentLocked,
+}
+
+/// Vesting account, with its schedule
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VestingAccount {
+    pub address: Addr,
+    /// Amount locked when the account was created
+    pub original_vesting: Vec<Coin>,
+    /// Delegated amount that was unlocked at the time of the delegation
+    pub delegated_free: Vec<Coin>,
+    /// Delegated amount that was locked at the time of the delegation
+    pub delegated_vesting: Vec<Coin>,
+    pub schedule: VestingSchedule,
+}
+
+impl VestingAccount {
+    /// Vesting account of an [`Account`], `None` if it is not a vesting account
+    pub fn from_account(account: &Account) -> Result<Option<Self>, DaemonError> {
+        let (base, schedule) = match account {
+            Account::ContinuousVesting(acc) => (
+                &acc.base_vesting_account,
+                VestingSchedule::Continuous {
+                    start_time: acc.start_time,
+                    end_time: end_time(&acc.base_vesting_account),
+                },
+            ),
+            Account::DelayedVesting(acc) => (
+                &acc.base_vesting_account,
+                VestingSchedule::Delayed {
+                    end_time: end_time(&acc.base_vesting_account),
+                },
+            ),
+            Account::PeriodicVesting(acc) => (
+                &acc.base_vesting_account,
+                VestingSchedule::Periodic {
+                    start_time: acc.start_time,
+                    periods: acc
+                        .vesting_periods
+                        .iter()
+                        .cloned()
+                        .map(|Period { length, amount }| {
+                            Ok(VestingPeriod {
+                                length,
+                                amount: cosmrs_to_cosmwasm_coins(amount)?,
+                            })
+                        })
+                        .collect::<Result<_, DaemonError>>()?,
+                },
+            ),
+            Account::PermanentLocked(acc) => {
+                (&acc.base_vesting_account, VestingSchedule::PermanentLocked)
+            }
+            _ => return Ok(None),
+        };
+        let base = base
+            .clone()
+            .ok_or_else(|| DaemonError::StdErr("Vesting account without base account".into()))?;
+        Ok(Some(Self {
+            address: Addr::unchecked(account.base_account()?.address.clone()),
+            original_vesting: cosmrs_to_cosmwasm_coins(base.original_vesting)?,
+            delegated_free: cosmrs_to_cosmwasm_coins(base.delegated_free)?,
+            delegated_vesting: cosmrs_to_cosmwasm_coins(base.delegated_vesting)?,
+            schedule,
+        }))
+    }
+}
+
+fn end_time(base: &Option<BaseVestingAccount>) -> i64 {
+    base.as_ref().map(|base| base.end_time).unwrap_or_default()
+}
+
+/// Querier for the vesting accounts, decoding their schedule
+/// All the async function are prefixed with `_`
+pub struct Vesting {
+    pub channel: Channel,
+    pub rt_handle: Option<Handle>,
+}
+
+impl Vesting {
+    pub fn new<Sender: QuerySender>(daemon: &DaemonBase<Sender>) -> Self {
+        Self {
+            channel: daemon.channel(),
+            rt_handle: Some(daemon.rt_handle.clone()),
+        }
+    }
+
+    pub fn new_async(channel: Channel) -> Self {
+        Self {
+            channel,
+            rt_handle: None,
+        }
+    }
+}
+
+impl Querier for Vesting {
+    type Error = DaemonError;
+}
+
+impl<Sender: QuerySender> QuerierGetter<Vesting> for DaemonBase<Sender> {
+    fn querier(&self) -> Vesting {
+        Vesting::new(self)
+    }
+}
+
+impl Vesting {
+    /// Query the vesting account of an address
+    pub fn vesting_account(&self, address: &Addr) -> Result<VestingAccount, DaemonError> {
+        self.rt_handle
+            .as_ref()
+            .ok_or(DaemonError::QuerierNeedRuntime)?
+            .block_on(self._vesting_account(address))
+    }
+
+    /// Query the vesting account of an address, errors if the account is not a vesting account
+    pub async fn _vesting_account(&self, address: &Addr) -> Result<VestingAccount, DaemonError> {
+        let account = Auth::new_async(self.channel.clone())
+            ._account(address)
+            .await?;
+        VestingAccount::from_account(&account)?.ok_or(DaemonError::StdErr(format!(
+            "{address} is not a vesting account"
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::coins;
+
+    use super::*;
+    use crate::cosmos_modules::{auth::BaseAccount, vesting::PeriodicVestingAccount};
+
+    #[test]
+    fn decodes_periodic_schedule() -> Result<(), DaemonError> {
+        let proto_coin = |amount: u128| cosmrs::proto::cosmos::base::v1beta1::Coin {
+            denom: "ujuno".to_string(),
+            amount: amount.to_string(),
+        };
+        let account = Account::PeriodicVesting(PeriodicVestingAccount {
+            base_vesting_account: Some(BaseVestingAccount {
+                base_account: Some(BaseAccount {
+                    address: "juno1vesting".to_string(),
+                    ..Default::default()
+                }),
+                original_vesting: vec![proto_coin(300)],
+                end_time: 1_300,
+                ..Default::default()
+            }),
+            start_time: 1_000,
+            vesting_periods: vec![
+                Period {
+                    length: 100,
+                    amount: vec![proto_coin(100)],
+                },
+                Period {
+                    length: 200,
+                    amount: vec![proto_coin(200)],
+                },
+            ],
+        });
+
+        let vesting = VestingAccount::from_account(&account)?.unwrap();
+        assert_eq!(vesting.address, Addr::unchecked("juno1vesting"));
+        assert_eq!(vesting.original_vesting, coins(300, "ujuno"));
+        assert_eq!(
+            vesting.schedule,
+            VestingSchedule::Periodic {
+                start_time: 1_000,
+                periods: vec![
+                    VestingPeriod {
+                        length: 100,
+                        amount: coins(100, "ujuno"),
+                    },
+                    VestingPeriod {
+                        length: 200,
+                        amount: coins(200, "ujuno"),
+                    },
+                ],
+            }
+        );
+
+        let base = Account::Base(BaseAccount::default());
+        assert_eq!(VestingAccount::from_account(&base)?, None);
+        Ok(())
+    }
+}
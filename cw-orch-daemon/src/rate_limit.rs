@@ -0,0 +1,203 @@
+use std::{collections::HashMap, time::Duration};
+
+use tonic::{transport::Endpoint, Code, Status};
+
+use crate::env::DaemonEnvVars;
+
+/// Number of times a rate limited query is retried before failing
+const MAX_RATE_LIMITED_RETRIES: u32 = 5;
+
+/// Wait before retrying a rate limited query when the provider doesn't send a `Retry-After` header
+const DEFAULT_RETRY_AFTER: Duration = Duration::from_secs(1);
+
+/// Maximum number of requests sent to a gRPC endpoint
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RateLimit {
+    /// Average number of requests per second
+    pub requests_per_second: u64,
+    /// Number of requests that can be sent at once, before the rate applies
+    pub burst: u64,
+}
+
+impl RateLimit {
+    /// Limits the requests to `requests_per_second`, with a burst of the same size
+    pub fn per_second(requests_per_second: u64) -> Self {
+        Self {
+            requests_per_second: requests_per_second.max(1),
+            burst: requests_per_second.max(1),
+        }
+    }
+
+    /// Allows `burst` requests to be sent at once, the average rate being kept
+    pub fn burst(mut self, burst: u64) -> Self {
+        self.burst = burst.max(1);
+        self
+    }
+
+    /// `burst` requests are allowed in every window, the window being sized to keep the average rate
+    pub(crate) fn apply(&self, endpoint: Endpoint) -> Endpoint {
+        let window =
+            Duration::from_secs_f64(self.burst as f64 / self.requests_per_second.max(1) as f64);
+        endpoint.rate_limit(self.burst, window)
+    }
+}
+
+/// Rate limits of the gRPC endpoints, so that long-running scripts stay under the quotas of public providers.
+/// The requests exceeding the limit wait for the next window instead of failing.
+///
+/// The default limit applies to all the endpoints without a specific limit, it defaults to the
+/// `CW_ORCH_GRPC_RATE_LIMIT` env variable (requests per second).
+///
+/// ```rust,no_run
+/// use cw_orch_daemon::{networks, Daemon, RateLimit, RateLimits};
+///
+/// let daemon = Daemon::builder(networks::OSMOSIS_1)
+///     .rate_limits(
+///         RateLimits::default()
+///             .all(RateLimit::per_second(10))
+///             .endpoint("https://grpc.osmosis.zone", RateLimit::per_second(2).burst(5)),
+///     )
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct RateLimits {
+    /// Limit of the endpoints without a specific limit
+    pub default: Option<RateLimit>,
+    /// Limits by endpoint url
+    pub endpoints: HashMap<String, RateLimit>,
+}
+
+impl Default for RateLimits {
+    fn default() -> Self {
+        Self {
+            default: DaemonEnvVars::grpc_rate_limit().map(RateLimit::per_second),
+            endpoints: HashMap::new(),
+        }
+    }
+}
+
+impl RateLimits {
+    /// No limit on any endpoint, ignoring the env variable
+    pub fn unlimited() -> Self {
+        Self {
+            default: None,
+            endpoints: HashMap::new(),
+        }
+    }
+
+    /// Limits the endpoints without a specific limit
+    pub fn all(mut self, limit: RateLimit) -> Self {
+        self.default = Some(limit);
+        self
+    }
+
+    /// Limits the endpoint at `url`
+    pub fn endpoint(mut self, url: impl Into<String>, limit: RateLimit) -> Self {
+        self.endpoints.insert(url.into(), limit);
+        self
+    }
+
+    /// Limit applying to the endpoint at `url`
+    pub fn limit_of(&self, url: &str) -> Option<RateLimit> {
+        self.endpoints.get(url).copied().or(self.default)
+    }
+}
+
+/// Wait before retrying a query rejected by the rate limiting of the provider, `None` if the query
+/// wasn't rate limited or was already retried `attempt` times.
+/// The wait is read from the `Retry-After` header (in seconds), when the provider sends one.
+pub(crate) fn retry_after(status: &Status, attempt: u32) -> Option<Duration> {
+    if attempt >= MAX_RATE_LIMITED_RETRIES {
+        return None;
+    }
+    let header = status
+        .metadata()
+        .get("retry-after")
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.trim().parse::<u64>().ok())
+        .map(Duration::from_secs);
+    let rate_limited = match status.code() {
+        Code::ResourceExhausted => header.is_some(),
+        // Proxies answering HTTP 429 are mapped to `Unavailable`
+        Code::Unavailable => {
+            status.message().contains("429") || status.message().contains("Too Many Requests")
+        }
+        _ => false,
+    };
+    rate_limited.then(|| header.unwrap_or(DEFAULT_RETRY_AFTER * 2u32.pow(attempt)))
+}
+
+/// Waits before retrying a query rejected by the rate limiting of the provider, counting the attempt.
+/// Returns `false` if the query shouldn't be retried. Used by the `cosmos_query!` macro.
+#[doc(hidden)]
+pub async fn wait_rate_limited(status: &Status, attempt: &mut u32) -> bool {
+    match retry_after(status, *attempt) {
+        Some(wait) => {
+            log::warn!("Rate limited by the gRPC endpoint, retrying in {:?}", wait);
+            *attempt += 1;
+            tokio::time::sleep(wait).await;
+            true
+        }
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn limits_by_endpoint() {
+        let limits = RateLimits::unlimited();
+        assert_eq!(limits.limit_of("http://grpc"), None);
+
+        let limits = limits
+            .all(RateLimit::per_second(10))
+            .endpoint("http://public", RateLimit::per_second(2).burst(5));
+        assert_eq!(
+            limits.limit_of("http://grpc"),
+            Some(RateLimit::per_second(10))
+        );
+        assert_eq!(
+            limits.limit_of("http://public"),
+            Some(RateLimit {
+                requests_per_second: 2,
+                burst: 5
+            })
+        );
+    }
+
+    #[test]
+    fn retries_rate_limited_queries() {
+        let mut status = Status::resource_exhausted("quota exceeded");
+        assert_eq!(retry_after(&status, 0), None);
+        status
+            .metadata_mut()
+            .insert("retry-after", "3".parse().unwrap());
+        assert_eq!(retry_after(&status, 0), Some(Duration::from_secs(3)));
+        assert_eq!(retry_after(&status, MAX_RATE_LIMITED_RETRIES), None);
+
+        let status = Status::unavailable("http status 429 Too Many Requests");
+        assert_eq!(retry_after(&status, 2), Some(Duration::from_secs(4)));
+        assert_eq!(
+            retry_after(&Status::unavailable("connection refused"), 0),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn waits_for_the_rate_limited_queries() {
+        let mut attempt = 0;
+        let status = Status::unavailable("connection refused");
+        assert!(!wait_rate_limited(&status, &mut attempt).await);
+        assert_eq!(attempt, 0);
+
+        let mut status = Status::resource_exhausted("quota exceeded");
+        status
+            .metadata_mut()
+            .insert("retry-after", "0".parse().unwrap());
+        assert!(wait_rate_limited(&status, &mut attempt).await);
+        assert_eq!(attempt, 1);
+    }
+}
@@ -0,0 +1,176 @@
+use std::sync::{Arc, Mutex};
+
+use crate::DaemonError;
+
+/// Hard limits on the transactions a sender can broadcast during a run.
+/// Once a limit would be exceeded, further broadcasts error out with [`DaemonError::BudgetExceeded`].
+/// The expected fee of a transaction is reserved while it's broadcasted, and replaced by the fee it actually paid
+/// once it's included in a block. Transactions that fail to broadcast don't consume the budget.
+///
+/// Clones of the budget (and of the senders and daemons that hold it) share the same usage.
+///
+/// ```rust,no_run
+/// use cw_orch_daemon::{networks, senders::ExecutionBudget, CosmosOptions, Daemon};
+///
+/// let budget = ExecutionBudget::default().max_txs(100).max_fee(5_000_000);
+/// let daemon = Daemon::builder(networks::PION_1)
+///     .build_sender(CosmosOptions::default().budget(budget.clone()))
+///     .unwrap();
+///
+/// // ...
+/// println!("{} txs sent, {} spent in fees", budget.txs_sent(), budget.fees_spent());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ExecutionBudget {
+    max_txs: Option<u64>,
+    max_fee: Option<u128>,
+    usage: Arc<Mutex<BudgetUsage>>,
+}
+
+#[derive(Debug, Default)]
+struct BudgetUsage {
+    txs: u64,
+    fees: u128,
+    /// Transactions being broadcasted and their expected fees
+    reserved_txs: u64,
+    reserved_fees: u128,
+}
+
+/// Share of an [`ExecutionBudget`] reserved for a transaction being broadcasted.
+/// Dropping it without calling [`BudgetReservation::commit`] releases it, e.g. when the broadcast fails.
+#[must_use]
+pub(crate) struct BudgetReservation {
+    budget: ExecutionBudget,
+    fee: u128,
+    committed: bool,
+}
+
+impl BudgetReservation {
+    /// Records the transaction as sent, with the fee it actually paid
+    pub(crate) fn commit(mut self, paid_fee: u128) {
+        let mut usage = self.budget.usage.lock().unwrap();
+        usage.reserved_txs -= 1;
+        usage.reserved_fees -= self.fee;
+        usage.txs += 1;
+        usage.fees += paid_fee;
+        self.committed = true;
+    }
+}
+
+impl Drop for BudgetReservation {
+    fn drop(&mut self) {
+        if self.committed {
+            return;
+        }
+        let mut usage = self.budget.usage.lock().unwrap();
+        usage.reserved_txs -= 1;
+        usage.reserved_fees -= self.fee;
+    }
+}
+
+impl ExecutionBudget {
+    /// Limits the number of transactions that can be broadcasted
+    pub fn max_txs(mut self, max_txs: u64) -> Self {
+        self.max_txs = Some(max_txs);
+        self
+    }
+
+    /// Limits the cumulative fee (in the gas denom of the chain) that can be spent
+    pub fn max_fee(mut self, max_fee: u128) -> Self {
+        self.max_fee = Some(max_fee);
+        self
+    }
+
+    /// Returns true if the fee of transactions needs to be estimated before broadcasting
+    pub(crate) fn limits_fee(&self) -> bool {
+        self.max_fee.is_some()
+    }
+
+    /// Number of transactions broadcasted so far
+    pub fn txs_sent(&self) -> u64 {
+        self.usage.lock().unwrap().txs
+    }
+
+    /// Cumulative fee spent so far
+    pub fn fees_spent(&self) -> u128 {
+        self.usage.lock().unwrap().fees
+    }
+
+    /// Reserves a transaction with the given expected fee, or errors if that would exceed the budget.
+    /// The transactions and fees already reserved by other broadcasts count towards the limits.
+    pub(crate) fn reserve(&self, fee: u128) -> Result<BudgetReservation, DaemonError> {
+        let mut usage = self.usage.lock().unwrap();
+        if let Some(max_txs) = self.max_txs {
+            if usage.txs + usage.reserved_txs >= max_txs {
+                return Err(DaemonError::BudgetExceeded(format!(
+                    "{max_txs} transactions already sent"
+                )));
+            }
+        }
+        if let Some(max_fee) = self.max_fee {
+            let committed = usage.fees + usage.reserved_fees;
+            if committed + fee > max_fee {
+                return Err(DaemonError::BudgetExceeded(format!(
+                    "{committed} already spent or reserved in fees, the next transaction needs {fee} but the maximum is {max_fee}"
+                )));
+            }
+        }
+        usage.reserved_txs += 1;
+        usage.reserved_fees += fee;
+        Ok(BudgetReservation {
+            budget: self.clone(),
+            fee,
+            committed: false,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn enforces_limits() {
+        let budget = ExecutionBudget::default().max_txs(3).max_fee(100);
+        let shared = budget.clone();
+
+        budget.reserve(40).unwrap().commit(40);
+        shared.reserve(40).unwrap().commit(35);
+        assert_eq!(budget.txs_sent(), 2);
+        assert_eq!(budget.fees_spent(), 75);
+
+        // Exceeds the fee limit, not recorded
+        assert!(matches!(
+            budget.reserve(30),
+            Err(DaemonError::BudgetExceeded(_))
+        ));
+        assert_eq!(budget.fees_spent(), 75);
+
+        budget.reserve(20).unwrap().commit(20);
+        // Exceeds the tx limit
+        assert!(matches!(
+            budget.reserve(0),
+            Err(DaemonError::BudgetExceeded(_))
+        ));
+    }
+
+    #[test]
+    fn failed_broadcasts_release_the_budget() {
+        let budget = ExecutionBudget::default().max_txs(1).max_fee(100);
+
+        let reservation = budget.reserve(60).unwrap();
+        // The pending broadcast counts towards the limits
+        assert!(matches!(
+            budget.reserve(50),
+            Err(DaemonError::BudgetExceeded(_))
+        ));
+        // The broadcast fails
+        drop(reservation);
+        assert_eq!(budget.txs_sent(), 0);
+        assert_eq!(budget.fees_spent(), 0);
+
+        budget.reserve(100).unwrap().commit(90);
+        assert_eq!(budget.txs_sent(), 1);
+        assert_eq!(budget.fees_spent(), 90);
+    }
+}
@@ -2,7 +2,7 @@ use std::sync::Arc;
 
 use cw_orch_core::environment::ChainInfoOwned;
 
-use crate::DaemonError;
+use crate::{DaemonError, RateLimits};
 
 /// Allows building a `Sender` from `SenderBuilder::Options`
 /// `async`` because it could do network requests during build
@@ -15,4 +15,15 @@ pub trait SenderBuilder {
         &self,
         chain_info: &Arc<ChainInfoOwned>,
     ) -> impl std::future::Future<Output = Result<Self::Sender, Self::Error>> + Send;
+
+    /// Build a new `Sender`, its channel limited by the `rate_limits` of the daemon unless the options set their own.
+    /// Defaults to [`SenderBuilder::build`], for senders that don't connect to the chain.
+    fn build_with_rate_limits(
+        &self,
+        chain_info: &Arc<ChainInfoOwned>,
+        rate_limits: &RateLimits,
+    ) -> impl std::future::Future<Output = Result<Self::Sender, Self::Error>> + Send {
+        let _ = rate_limits;
+        self.build(chain_info)
+    }
 }
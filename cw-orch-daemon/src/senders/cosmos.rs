@@ -1,25 +1,27 @@
 use super::{
+    budget::ExecutionBudget,
     cosmos_options::CosmosWalletKey,
+    guard::TxGuard,
     query::QuerySender,
+    sequence::SequenceManager,
     sign::{Signer, SigningAccount},
     tx::TxSender,
+    tx_signer::{LocalSigner, TxSigner},
 };
 use crate::{
     cosmos_modules::{self, auth::BaseAccount},
     env::{DaemonEnvVars, LOCAL_MNEMONIC_ENV_NAME, MAIN_MNEMONIC_ENV_NAME, TEST_MNEMONIC_ENV_NAME},
     error::DaemonError,
     keys::private::PrivateKey,
-    proto::injective::{InjectiveEthAccount, ETHEREUM_COIN_TYPE},
+    polling::PollingConfig,
+    proto::account::base_account,
     queriers::{Bank, Node},
     tx_builder::TxBuilder,
     tx_resp::CosmTxResponse,
-    upload_wasm, CosmosOptions, GrpcChannel,
+    upload_wasm, CosmosOptions, GrpcChannel, SdkVersion,
 };
 use bitcoin::secp256k1::{All, Secp256k1, Signing};
-use cosmos_modules::vesting::PeriodicVestingAccount;
 use cosmrs::{
-    crypto::secp256k1::SigningKey,
-    proto::traits::Message,
     tendermint::chain::Id,
     tx::{self, Fee, ModeInfo, Msg, Raw, SignDoc, SignMode, SignerInfo, SignerPublicKey},
     AccountId, Any,
@@ -30,12 +32,9 @@ use cw_orch_core::{
     environment::{AccessConfig, ChainInfoOwned, ChainKind},
     CoreEnvVars, CwEnvError,
 };
-use std::sync::Arc;
+use std::sync::{Arc, OnceLock};
 use tonic::transport::Channel;
 
-#[cfg(feature = "eth")]
-use crate::proto::injective::InjectiveSigner;
-
 const GAS_BUFFER: f64 = 1.3;
 const BUFFER_THRESHOLD: u64 = 200_000;
 const SMALL_GAS_BUFFER: f64 = 1.4;
@@ -47,21 +46,33 @@ pub type Wallet = CosmosSender<All>;
 /// This is the main interface for simulating and signing transactions
 #[derive(Clone)]
 pub struct CosmosSender<C: Signing + Clone> {
-    pub private_key: PrivateKey,
+    /// Signer of the transactions, see [`TxSigner`]
+    pub(crate) signer: Arc<dyn TxSigner>,
+    /// Public key of the signer
+    pub(crate) public_key: SignerPublicKey,
+    /// Address of the signer
+    pub(crate) account_id: AccountId,
     /// gRPC channel
     pub grpc_channel: Channel,
     /// Information about the chain
     pub chain_info: Arc<ChainInfoOwned>,
     pub(crate) options: CosmosOptions,
+    /// Tracks the sequences of the broadcasted transactions
+    pub(crate) sequence_manager: SequenceManager,
+    /// Cosmos SDK version of the chain, resolved with the first simulation
+    pub(crate) sdk_version: Arc<OnceLock<SdkVersion>>,
     pub secp: Secp256k1<C>,
 }
 
 impl Wallet {
     pub async fn new(
         chain_info: &Arc<ChainInfoOwned>,
-        options: CosmosOptions,
+        mut options: CosmosOptions,
     ) -> Result<Wallet, DaemonError> {
         let secp = Secp256k1::new();
+        if options.tx_guard.is_none() {
+            options.tx_guard = TxGuard::from_env()?;
+        }
 
         let pk_from_mnemonic = |mnemonic: &str| -> Result<PrivateKey, DaemonError> {
             PrivateKey::from_words(
@@ -73,33 +84,50 @@ impl Wallet {
             )
         };
 
-        let pk: PrivateKey = match &options.key {
-            CosmosWalletKey::Mnemonic(mnemonic) => pk_from_mnemonic(mnemonic)?,
+        let local = |pk: PrivateKey| -> Arc<dyn TxSigner> {
+            Arc::new(LocalSigner::new(pk).eip712(options.eip712_chain_id))
+        };
+
+        let signer = match &options.key {
+            CosmosWalletKey::Signer(signer) => signer.clone(),
+            CosmosWalletKey::Mnemonic(mnemonic) => local(pk_from_mnemonic(mnemonic)?),
             CosmosWalletKey::Env => {
                 let mnemonic = get_mnemonic_env(&chain_info.kind)?;
-                pk_from_mnemonic(&mnemonic)?
+                local(pk_from_mnemonic(&mnemonic)?)
             }
-            CosmosWalletKey::RawKey(bytes) => PrivateKey::from_raw_key(
+            CosmosWalletKey::Secret(provider) => {
+                let mnemonic = provider.mnemonic(chain_info).await?;
+                local(pk_from_mnemonic(&mnemonic)?)
+            }
+            CosmosWalletKey::RawKey(bytes) => local(PrivateKey::from_raw_key(
                 &secp,
                 bytes,
                 0,
                 options.hd_index.unwrap_or(0),
                 chain_info.network_info.coin_type,
-            )?,
+            )?),
         };
 
         // ensure address is valid
-        AccountId::new(
-            &chain_info.network_info.pub_address_prefix,
-            &pk.public_key(&secp).raw_address.unwrap(),
-        )?;
+        let account_id = signer
+            .account_id(&chain_info.network_info.pub_address_prefix)
+            .await?;
+        let public_key = signer.public_key().await?;
 
         Ok(Self {
             chain_info: chain_info.clone(),
-            grpc_channel: GrpcChannel::from_chain_info(chain_info.as_ref()).await?,
-            private_key: pk,
+            grpc_channel: GrpcChannel::from_chain_info_with_limits(
+                chain_info.as_ref(),
+                &options.rate_limits.clone().unwrap_or_default(),
+            )
+            .await?,
+            signer,
+            public_key,
+            account_id,
             secp,
             options,
+            sequence_manager: SequenceManager::default(),
+            sdk_version: Arc::default(),
         })
     }
 
@@ -124,7 +152,12 @@ impl Wallet {
     }
 
     pub fn public_key(&self) -> Option<SignerPublicKey> {
-        self.private_key.get_signer_public_key(&self.secp)
+        Some(self.public_key.clone())
+    }
+
+    /// Private key of the sender, `None` when signing with a remote [`TxSigner`]
+    pub fn private_key(&self) -> Option<&PrivateKey> {
+        self.signer.private_key()
     }
 
     /// Replaces the private key that the [CosmosSender] is using with key derived from the provided 24-word mnemonic.
@@ -139,14 +172,17 @@ impl Wallet {
             self.options.hd_index.unwrap_or(0),
             self.chain_info.network_info.coin_type,
         )?;
-        self.set_private_key(pk);
-        Ok(())
+        self.set_private_key(pk)
     }
 
     /// Replaces the private key the sender is using
     /// You can use a mnemonic to overwrite the key using [Self::set_mnemonic]
-    pub fn set_private_key(&mut self, private_key: PrivateKey) {
-        self.private_key = private_key
+    pub fn set_private_key(&mut self, private_key: PrivateKey) -> Result<(), DaemonError> {
+        let signer = LocalSigner::new(private_key).eip712(self.options.eip712_chain_id);
+        self.account_id = signer.address(&self.chain_info.network_info.pub_address_prefix)?;
+        self.public_key = signer.signer_public_key()?;
+        self.signer = Arc::new(signer);
+        Ok(())
     }
 
     pub fn set_authz_granter(&mut self, granter: &Addr) {
@@ -157,6 +193,11 @@ impl Wallet {
         self.options.fee_granter = Some(granter.to_owned());
     }
 
+    /// Limits the transactions this sender (and its clones) can broadcast
+    pub fn set_budget(&mut self, budget: ExecutionBudget) {
+        self.options.budget = Some(budget);
+    }
+
     pub fn pub_addr_str(&self) -> String {
         Signer::account_id(self).to_string()
     }
@@ -176,7 +217,7 @@ impl Wallet {
         )?;
 
         let auth_info = SignerInfo {
-            public_key: self.private_key.get_signer_public_key(&self.secp),
+            public_key: Some(self.public_key.clone()),
             mode_info: ModeInfo::single(SignMode::Direct),
             sequence,
         }
@@ -189,13 +230,22 @@ impl Wallet {
             account_number,
         )?;
 
-        let tx_raw = self.sign(sign_doc)?;
+        let tx_raw = self.sign(sign_doc).await?;
 
         Node::new_async(self.channel())
-            ._simulate_tx(tx_raw.to_bytes()?)
+            ._simulate_tx_for_version(tx_raw.to_bytes()?, self.sdk_version().await?)
             .await
     }
 
+    /// Cosmos SDK version of the chain, queried once and cached for the clones of the sender
+    pub async fn sdk_version(&self) -> Result<SdkVersion, DaemonError> {
+        if let Some(version) = self.sdk_version.get() {
+            return Ok(*version);
+        }
+        let version = GrpcChannel::sdk_version(self.channel()).await?;
+        Ok(*self.sdk_version.get_or_init(|| version))
+    }
+
     /// Simulates the transaction against an actual node
     /// Returns the gas needed as well as the fee needed for submitting a transaction
     pub async fn simulate(
@@ -246,22 +296,11 @@ impl Wallet {
             .await?
             .into_inner();
 
-        let account = resp.account.unwrap().value;
+        let account = resp.account.ok_or_else(|| {
+            DaemonError::StdErr(format!("Account {} not found on chain", self.address()))
+        })?;
 
-        let acc = if let Ok(acc) = BaseAccount::decode(account.as_ref()) {
-            acc
-        } else if let Ok(acc) = PeriodicVestingAccount::decode(account.as_ref()) {
-            // try vesting account, (used by Terra2)
-            acc.base_vesting_account.unwrap().base_account.unwrap()
-        } else if let Ok(acc) = InjectiveEthAccount::decode(account.as_ref()) {
-            acc.base_account.unwrap()
-        } else {
-            return Err(DaemonError::StdErr(
-                "Unknown account type returned from QueryAccountRequest".into(),
-            ));
-        };
-
-        Ok(acc)
+        base_account(account)
     }
 
     /// Allows for checking wether the sender is able to broadcast a transaction that necessitates the provided `gas`
@@ -335,10 +374,6 @@ impl Wallet {
         self.chain_info.gas_denom.to_string()
     }
 
-    fn cosmos_private_key(&self) -> SigningKey {
-        SigningKey::from_slice(&self.private_key.raw_key()).unwrap()
-    }
-
     /// Compute the gas fee from the expected gas in the transaction
     /// Applies a Gas Buffer for including signature verification
     pub(crate) fn get_fee_from_gas(&self, gas: u64) -> Result<(u64, u128), DaemonError> {
@@ -385,7 +420,7 @@ impl QuerySender for Wallet {
     }
 }
 
-fn get_mnemonic_env(chain_kind: &ChainKind) -> Result<String, CwEnvError> {
+pub(super) fn get_mnemonic_env(chain_kind: &ChainKind) -> Result<String, CwEnvError> {
     match chain_kind {
         ChainKind::Local => DaemonEnvVars::local_mnemonic(),
         ChainKind::Testnet => DaemonEnvVars::test_mnemonic(),
@@ -407,19 +442,8 @@ fn get_mnemonic_env_name(chain_kind: &ChainKind) -> &str {
 }
 
 impl Signer for Wallet {
-    fn sign(&self, sign_doc: SignDoc) -> Result<Raw, DaemonError> {
-        let tx_raw = if self.private_key.coin_type == ETHEREUM_COIN_TYPE {
-            #[cfg(not(feature = "eth"))]
-            panic!(
-                "Coin Type {} not supported without eth feature",
-                ETHEREUM_COIN_TYPE
-            );
-            #[cfg(feature = "eth")]
-            self.private_key.sign_injective(sign_doc)?
-        } else {
-            sign_doc.sign(&self.cosmos_private_key())?
-        };
-        Ok(tx_raw)
+    async fn sign(&self, sign_doc: SignDoc) -> Result<Raw, DaemonError> {
+        self.signer.sign_doc(sign_doc).await
     }
 
     fn chain_id(&self) -> String {
@@ -428,7 +452,7 @@ impl Signer for Wallet {
 
     fn signer_info(&self, sequence: u64) -> SignerInfo {
         SignerInfo {
-            public_key: self.private_key.get_signer_public_key(&self.secp),
+            public_key: Some(self.public_key.clone()),
             mode_info: ModeInfo::single(SignMode::Direct),
             sequence,
         }
@@ -461,15 +485,29 @@ impl Signer for Wallet {
     }
 
     fn account_id(&self) -> AccountId {
-        AccountId::new(
-            &self.chain_info.network_info.pub_address_prefix,
-            &self.private_key.public_key(&self.secp).raw_address.unwrap(),
-        )
-        // unwrap as address is validated on construction
-        .unwrap()
+        self.account_id.clone()
     }
 
     fn authz_granter(&self) -> Option<&Addr> {
         self.options.authz_granter.as_ref()
     }
+
+    fn budget(&self) -> Option<&ExecutionBudget> {
+        self.options.budget.as_ref()
+    }
+
+    fn sequence_manager(&self) -> Option<&SequenceManager> {
+        Some(&self.sequence_manager)
+    }
+
+    fn polling(&self) -> Option<&PollingConfig> {
+        self.options.polling.as_ref()
+    }
+
+    fn tx_guard(&self) -> Option<&TxGuard> {
+        self.options
+            .tx_guard
+            .as_ref()
+            .filter(|guard| guard.applies_to(&self.chain_info.kind))
+    }
 }
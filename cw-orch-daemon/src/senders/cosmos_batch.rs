@@ -4,7 +4,7 @@ use super::query::QuerySender;
 use super::tx::TxSender;
 use crate::parse_cw_coins;
 use crate::{error::DaemonError, tx_resp::CosmTxResponse};
-use crate::{DaemonBase, INSTANTIATE_2_TYPE_URL};
+use crate::{DaemonBase, RateLimits, INSTANTIATE_2_TYPE_URL};
 use cosmrs::bank::MsgSend;
 use cosmrs::proto::cosmwasm::wasm::v1::{MsgInstantiateContract, MsgStoreCode};
 use cosmrs::{AccountId, Any};
@@ -79,6 +79,20 @@ impl SenderBuilder for CosmosBatchOptions {
             sender: self.0.build(chain_info).await?,
         })
     }
+
+    async fn build_with_rate_limits(
+        &self,
+        chain_info: &Arc<ChainInfoOwned>,
+        rate_limits: &RateLimits,
+    ) -> Result<Self::Sender, Self::Error> {
+        Ok(CosmosBatchSender {
+            msgs: Default::default(),
+            sender: self
+                .0
+                .build_with_rate_limits(chain_info, rate_limits)
+                .await?,
+        })
+    }
 }
 
 impl QuerySender for CosmosBatchSender {
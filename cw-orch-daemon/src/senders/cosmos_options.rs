@@ -4,9 +4,11 @@ use cosmrs::AccountId;
 use cosmwasm_std::Addr;
 use cw_orch_core::environment::ChainInfoOwned;
 
-use crate::{DaemonError, Wallet};
+use crate::{polling::PollingConfig, DaemonError, RateLimits, Wallet};
 
-use super::{builder::SenderBuilder, CosmosSender};
+use super::{
+    builder::SenderBuilder, CosmosSender, ExecutionBudget, SecretProvider, TxGuard, TxSigner,
+};
 
 /// Options for how txs should be constructed for this sender.
 #[derive(Default, Clone)]
@@ -15,6 +17,18 @@ pub struct CosmosOptions {
     pub authz_granter: Option<Addr>,
     pub fee_granter: Option<Addr>,
     pub hd_index: Option<u32>,
+    /// Limits on the transactions this sender can broadcast
+    pub budget: Option<ExecutionBudget>,
+    /// Ethereum chain id used to sign EIP-712 typed data (Injective accounts created with Metamask), requires the `eth` feature
+    pub eip712_chain_id: Option<u64>,
+    /// Timings used to poll the chain for the transactions of this sender
+    pub polling: Option<PollingConfig>,
+    /// Confirmation required before broadcasting on sensitive chains.
+    /// Defaults to the guard configured by the `CW_ORCH_CONFIRM_TXS_ON` env variable.
+    pub tx_guard: Option<TxGuard>,
+    /// Limits of the requests sent to the gRPC endpoints.
+    /// Defaults to the limit configured by the `CW_ORCH_GRPC_RATE_LIMIT` env variable.
+    pub rate_limits: Option<RateLimits>,
     /// Used to derive the private key
     pub(crate) key: CosmosWalletKey,
 }
@@ -23,6 +37,10 @@ pub struct CosmosOptions {
 pub enum CosmosWalletKey {
     Mnemonic(String),
     RawKey(Vec<u8>),
+    /// Mnemonic fetched from a secret store when the sender is built
+    Secret(Arc<dyn SecretProvider>),
+    /// Transactions signed by a custom signer, the key never being held by the sender
+    Signer(Arc<dyn TxSigner>),
     #[default]
     Env,
 }
@@ -55,11 +73,46 @@ impl CosmosOptions {
         self
     }
 
+    pub fn budget(mut self, budget: ExecutionBudget) -> Self {
+        self.budget = Some(budget);
+        self
+    }
+
+    pub fn eip712(mut self, eth_chain_id: u64) -> Self {
+        self.eip712_chain_id = Some(eth_chain_id);
+        self
+    }
+
+    pub fn polling(mut self, polling: PollingConfig) -> Self {
+        self.polling = Some(polling);
+        self
+    }
+
+    pub fn tx_guard(mut self, guard: TxGuard) -> Self {
+        self.tx_guard = Some(guard);
+        self
+    }
+
+    pub fn rate_limits(mut self, rate_limits: RateLimits) -> Self {
+        self.rate_limits = Some(rate_limits);
+        self
+    }
+
     pub fn mnemonic(mut self, mnemonic: impl Into<String>) -> Self {
         self.key = CosmosWalletKey::Mnemonic(mnemonic.into());
         self
     }
 
+    pub fn secret_provider(mut self, provider: impl SecretProvider + 'static) -> Self {
+        self.key = CosmosWalletKey::Secret(Arc::new(provider));
+        self
+    }
+
+    pub fn signer(mut self, signer: impl TxSigner + 'static) -> Self {
+        self.key = CosmosWalletKey::Signer(Arc::new(signer));
+        self
+    }
+
     pub fn set_authz_granter(&mut self, granter: &Addr) {
         self.authz_granter = Some(granter.clone());
     }
@@ -72,9 +125,37 @@ impl CosmosOptions {
         self.hd_index = Some(index);
     }
 
+    pub fn set_budget(&mut self, budget: ExecutionBudget) {
+        self.budget = Some(budget);
+    }
+
+    pub fn set_eip712(&mut self, eth_chain_id: u64) {
+        self.eip712_chain_id = Some(eth_chain_id);
+    }
+
+    pub fn set_polling(&mut self, polling: PollingConfig) {
+        self.polling = Some(polling);
+    }
+
+    pub fn set_tx_guard(&mut self, guard: TxGuard) {
+        self.tx_guard = Some(guard);
+    }
+
+    pub fn set_rate_limits(&mut self, rate_limits: RateLimits) {
+        self.rate_limits = Some(rate_limits);
+    }
+
     pub fn set_mnemonic(&mut self, mnemonic: impl Into<String>) {
         self.key = CosmosWalletKey::Mnemonic(mnemonic.into());
     }
+
+    pub fn set_secret_provider(&mut self, provider: impl SecretProvider + 'static) {
+        self.key = CosmosWalletKey::Secret(Arc::new(provider));
+    }
+
+    pub fn set_signer(&mut self, signer: impl TxSigner + 'static) {
+        self.key = CosmosWalletKey::Signer(Arc::new(signer));
+    }
 }
 
 impl SenderBuilder for CosmosOptions {
@@ -84,4 +165,16 @@ impl SenderBuilder for CosmosOptions {
     async fn build(&self, chain_info: &Arc<ChainInfoOwned>) -> Result<Self::Sender, Self::Error> {
         CosmosSender::new(chain_info, self.clone()).await
     }
+
+    async fn build_with_rate_limits(
+        &self,
+        chain_info: &Arc<ChainInfoOwned>,
+        rate_limits: &RateLimits,
+    ) -> Result<Self::Sender, Self::Error> {
+        let mut options = self.clone();
+        options
+            .rate_limits
+            .get_or_insert_with(|| rate_limits.clone());
+        CosmosSender::new(chain_info, options).await
+    }
 }
@@ -0,0 +1,269 @@
+//! Confirmation of the transactions broadcast on sensitive chains.
+//!
+//! A [`TxGuard`] prints a summary of every transaction (contracts, message names, funds and estimated fee)
+//! and waits for a `y/N` confirmation before broadcasting it.
+//! Transactions whose messages all match the allowlist are broadcast without confirmation.
+//!
+//! The allowlist file is a TOML file:
+//! ```toml
+//! # Any message to this contract
+//! [[allow]]
+//! contract = "neutron1..."
+//!
+//! # The `update_price` message of any contract
+//! [[allow]]
+//! msg = "update_price"
+//!
+//! # Non-wasm messages are matched by type url
+//! [[allow]]
+//! msg = "/cosmos.bank.v1beta1.MsgSend"
+//! ```
+use std::path::Path;
+
+use cw_orch_core::{environment::ChainKind, CoreEnvVars};
+use serde::Deserialize;
+
+use crate::{env::DaemonEnvVars, tx_resp::DecodedTxMsg, DaemonError};
+
+/// Requires a confirmation before broadcasting transactions on the given kinds of chains
+///
+/// ```rust,no_run
+/// use cw_orch_daemon::{networks, senders::TxGuard, Daemon};
+/// use cw_orch_core::environment::ChainKind;
+///
+/// let daemon = Daemon::builder(networks::NEUTRON_1)
+///     .tx_guard(
+///         TxGuard::default()
+///             .confirm_on(ChainKind::Mainnet)
+///             .allowlist_file("tx-allowlist.toml")
+///             .unwrap(),
+///     )
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TxGuard {
+    chain_kinds: Vec<ChainKind>,
+    allowlist: Vec<AllowedTx>,
+}
+
+/// Message broadcast without confirmation.
+/// Unset fields match anything.
+#[derive(Deserialize, Clone, Debug, Default, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct AllowedTx {
+    /// Address of the target contract
+    pub contract: Option<String>,
+    /// Name of the wasm message (`update_config`), or type url of other messages
+    pub msg: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct AllowlistFile {
+    #[serde(default)]
+    allow: Vec<AllowedTx>,
+}
+
+impl TxGuard {
+    /// Guard configured by the `CW_ORCH_CONFIRM_TXS_ON` and `CW_ORCH_TX_ALLOWLIST` env variables, if any
+    pub fn from_env() -> Result<Option<Self>, DaemonError> {
+        let chain_kinds = DaemonEnvVars::confirm_txs_on()?;
+        if chain_kinds.is_empty() {
+            return Ok(None);
+        }
+        let mut guard = Self {
+            chain_kinds,
+            allowlist: vec![],
+        };
+        if let Some(path) = DaemonEnvVars::tx_allowlist() {
+            guard = guard.allowlist_file(path)?;
+        }
+        Ok(Some(guard))
+    }
+
+    /// Requires a confirmation on the chains of `kind`
+    pub fn confirm_on(mut self, kind: ChainKind) -> Self {
+        if !self.chain_kinds.contains(&kind) {
+            self.chain_kinds.push(kind);
+        }
+        self
+    }
+
+    /// Broadcasts the messages matching `allowed` without confirmation
+    pub fn allow(mut self, allowed: AllowedTx) -> Self {
+        self.allowlist.push(allowed);
+        self
+    }
+
+    /// Broadcasts the messages matching the allowlist file at `path` without confirmation
+    pub fn allowlist_file(mut self, path: impl AsRef<Path>) -> Result<Self, DaemonError> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path)?;
+        let file: AllowlistFile = toml::from_str(&content).map_err(|e| {
+            DaemonError::StdErr(format!("Invalid allowlist {}: {e}", path.display()))
+        })?;
+        self.allowlist.extend(file.allow);
+        Ok(self)
+    }
+
+    /// Returns true if transactions on chains of `kind` need a confirmation
+    pub fn applies_to(&self, kind: &ChainKind) -> bool {
+        self.chain_kinds.contains(kind)
+    }
+
+    /// Asks for a confirmation of the messages that are not allowlisted.
+    /// Errors if the user declines, or if manual interactions are disabled.
+    pub(crate) async fn confirm(
+        &self,
+        chain_id: &str,
+        msgs: &[DecodedTxMsg],
+        gas: u64,
+        fee: u128,
+    ) -> Result<(), DaemonError> {
+        if self.unconfirmed(msgs).is_empty() {
+            return Ok(());
+        }
+
+        eprintln!("About to broadcast a transaction on {chain_id}:");
+        for msg in msgs {
+            eprintln!("  - {}", summary(msg));
+        }
+        eprintln!("Estimated fee: {fee} (gas {gas})");
+
+        if !CoreEnvVars::manual_interaction() {
+            return Err(DaemonError::TxNotConfirmed(
+                "manual interactions are disabled".to_string(),
+            ));
+        }
+        eprint!("Broadcast? [y/N] ");
+        // Reading stdin blocks, keep it off the runtime threads
+        let input = tokio::task::spawn_blocking(|| {
+            let mut input = String::new();
+            std::io::stdin().read_line(&mut input).map(|_| input)
+        })
+        .await
+        .map_err(|e| DaemonError::StdErr(e.to_string()))??;
+        if input.trim().eq_ignore_ascii_case("y") {
+            Ok(())
+        } else {
+            Err(DaemonError::TxNotConfirmed(
+                "declined by the user".to_string(),
+            ))
+        }
+    }
+
+    /// Messages that don't match the allowlist
+    fn unconfirmed<'a>(&self, msgs: &'a [DecodedTxMsg]) -> Vec<&'a DecodedTxMsg> {
+        let matches = |filter: &Option<String>, value: Option<&str>| {
+            filter.is_none() || filter.as_deref() == value
+        };
+        msgs.iter()
+            .filter(|msg| {
+                let (contract, name) = target(msg);
+                !self.allowlist.iter().any(|allowed| {
+                    matches(&allowed.contract, contract) && matches(&allowed.msg, Some(&name))
+                })
+            })
+            .collect()
+    }
+}
+
+/// Target contract and name of a message
+fn target(msg: &DecodedTxMsg) -> (Option<&str>, String) {
+    match msg {
+        DecodedTxMsg::WasmExecute { contract, msg, .. } => (Some(contract), msg_name(msg)),
+        DecodedTxMsg::WasmInstantiate { .. } => (None, "instantiate".to_string()),
+        DecodedTxMsg::WasmMigrate { contract, .. } => (Some(contract), "migrate".to_string()),
+        DecodedTxMsg::Other { type_url, .. } => (None, type_url.clone()),
+    }
+}
+
+/// Wasm messages are serialized as `{"name": {...}}`, or `"name"` for unit variants
+fn msg_name(msg: &serde_json::Value) -> String {
+    match msg {
+        serde_json::Value::Object(map) if map.len() == 1 => map.keys().next().unwrap().clone(),
+        serde_json::Value::String(name) => name.clone(),
+        _ => "unknown".to_string(),
+    }
+}
+
+fn summary(msg: &DecodedTxMsg) -> String {
+    let funds = |funds: &[cosmwasm_std::Coin]| {
+        if funds.is_empty() {
+            String::new()
+        } else {
+            let funds: Vec<String> = funds.iter().map(ToString::to_string).collect();
+            format!(" with funds {}", funds.join(","))
+        }
+    };
+    match msg {
+        DecodedTxMsg::WasmExecute {
+            contract,
+            msg,
+            funds: coins,
+            ..
+        } => format!("execute `{}` on {contract}{}", msg_name(msg), funds(coins)),
+        DecodedTxMsg::WasmInstantiate {
+            code_id,
+            label,
+            funds: coins,
+            ..
+        } => format!("instantiate code {code_id} as `{label}`{}", funds(coins)),
+        DecodedTxMsg::WasmMigrate {
+            contract, code_id, ..
+        } => format!("migrate {contract} to code {code_id}"),
+        DecodedTxMsg::Other { type_url, .. } => type_url.clone(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn allowlist_matches_messages() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("allowlist.toml");
+        std::fs::write(
+            &file,
+            r#"
+            [[allow]]
+            contract = "oracle"
+            msg = "update_price"
+
+            [[allow]]
+            msg = "/cosmos.bank.v1beta1.MsgSend"
+            "#,
+        )
+        .unwrap();
+        let guard = TxGuard::default()
+            .confirm_on(ChainKind::Mainnet)
+            .allowlist_file(&file)
+            .unwrap();
+        assert!(guard.applies_to(&ChainKind::Mainnet));
+        assert!(!guard.applies_to(&ChainKind::Testnet));
+
+        let execute = |contract: &str, msg: serde_json::Value| DecodedTxMsg::WasmExecute {
+            sender: "sender".to_string(),
+            contract: contract.to_string(),
+            msg,
+            funds: vec![],
+        };
+        let msgs = [
+            execute(
+                "oracle",
+                serde_json::json!({"update_price": {"price": "1"}}),
+            ),
+            DecodedTxMsg::Other {
+                type_url: "/cosmos.bank.v1beta1.MsgSend".to_string(),
+                value: String::new(),
+            },
+            execute("oracle", serde_json::json!("set_owner")),
+            execute("pool", serde_json::json!({"update_price": {}})),
+        ];
+        let unconfirmed = guard.unconfirmed(&msgs);
+        assert_eq!(unconfirmed.len(), 2);
+        assert_eq!(summary(unconfirmed[0]), "execute `set_owner` on oracle");
+        assert_eq!(summary(unconfirmed[1]), "execute `update_price` on pool");
+    }
+}
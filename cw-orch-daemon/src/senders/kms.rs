@@ -0,0 +1,265 @@
+//! [`TxSigner`]s backed by the secp256k1 keys of cloud key management services.
+//!
+//! The private key never leaves the KMS, the transactions are signed by sending the sha256 digest of their `SignDoc`.
+//! - [`AwsKmsSigner`]: an AWS KMS `ECC_SECG_P256K1` key, requires the `aws-kms` feature.
+//! - [`GcpKmsSigner`]: a GCP Cloud KMS `EC_SIGN_SECP256K1_SHA256` key version, called through its REST API.
+use base64::{engine::general_purpose::STANDARD, Engine};
+use bitcoin::secp256k1::{ecdsa::Signature, PublicKey};
+use cosmrs::tx::SignerPublicKey;
+use sha2::{Digest, Sha256};
+use tokio::sync::OnceCell;
+
+use super::{TxSignature, TxSigner};
+use crate::DaemonError;
+
+/// Length of an uncompressed secp256k1 point, ending the DER `SubjectPublicKeyInfo` returned by the KMS
+const UNCOMPRESSED_KEY_LEN: usize = 65;
+
+/// Signs with an AWS KMS asymmetric key of spec `ECC_SECG_P256K1`.
+///
+/// ```no_run
+/// use cw_orch_daemon::{networks, senders::AwsKmsSigner, DaemonBuilder};
+///
+/// let rt = tokio::runtime::Runtime::new().unwrap();
+/// let signer = rt.block_on(AwsKmsSigner::new("alias/deployer"));
+/// let daemon = DaemonBuilder::new(networks::PION_1)
+///     .handle(rt.handle())
+///     .signer(signer)
+///     .build()
+///     .unwrap();
+/// ```
+#[cfg(feature = "aws-kms")]
+pub struct AwsKmsSigner {
+    key_id: String,
+    client: aws_sdk_kms::Client,
+    public_key: OnceCell<SignerPublicKey>,
+}
+
+#[cfg(feature = "aws-kms")]
+impl AwsKmsSigner {
+    /// Signs with the key with the id, ARN or alias `key_id`.
+    /// The credentials are loaded from the environment, see `aws_config::load_defaults`.
+    pub async fn new(key_id: impl Into<String>) -> Self {
+        let config = aws_config::load_defaults(aws_config::BehaviorVersion::latest()).await;
+        Self::with_client(key_id, aws_sdk_kms::Client::new(&config))
+    }
+
+    /// Signs with the key `key_id`, through an already configured `client`
+    pub fn with_client(key_id: impl Into<String>, client: aws_sdk_kms::Client) -> Self {
+        Self {
+            key_id: key_id.into(),
+            client,
+            public_key: OnceCell::new(),
+        }
+    }
+}
+
+#[cfg(feature = "aws-kms")]
+#[tonic::async_trait]
+impl TxSigner for AwsKmsSigner {
+    async fn public_key(&self) -> Result<SignerPublicKey, DaemonError> {
+        self.public_key
+            .get_or_try_init(|| async {
+                let key = self
+                    .client
+                    .get_public_key()
+                    .key_id(&self.key_id)
+                    .send()
+                    .await
+                    .map_err(|e| DaemonError::StdErr(format!("AWS KMS error: {e}")))?;
+                let der = key.public_key().ok_or_else(|| {
+                    DaemonError::StdErr(format!("AWS KMS key {} has no public key", self.key_id))
+                })?;
+                public_key_from_spki(der.as_ref())
+            })
+            .await
+            .cloned()
+    }
+
+    async fn sign(&self, sign_bytes: &[u8]) -> Result<TxSignature, DaemonError> {
+        use aws_sdk_kms::{
+            primitives::Blob,
+            types::{MessageType, SigningAlgorithmSpec},
+        };
+
+        let signed = self
+            .client
+            .sign()
+            .key_id(&self.key_id)
+            .message(Blob::new(Sha256::digest(sign_bytes).to_vec()))
+            .message_type(MessageType::Digest)
+            .signing_algorithm(SigningAlgorithmSpec::EcdsaSha256)
+            .send()
+            .await
+            .map_err(|e| DaemonError::StdErr(format!("AWS KMS error: {e}")))?;
+        let der = signed.signature().ok_or_else(|| {
+            DaemonError::StdErr(format!("AWS KMS key {} returned no signature", self.key_id))
+        })?;
+        Ok(TxSignature {
+            signature: compact_signature(der.as_ref())?,
+            public_key: self.public_key().await?,
+        })
+    }
+}
+
+/// Signs with a GCP Cloud KMS key version of algorithm `EC_SIGN_SECP256K1_SHA256`.
+///
+/// The OAuth access token is, in order of priority, the one set with [`GcpKmsSigner::access_token`],
+/// the `GOOGLE_OAUTH_ACCESS_TOKEN` environment variable (e.g. `gcloud auth print-access-token`)
+/// or the token of the service account of the instance, fetched from the metadata server.
+pub struct GcpKmsSigner {
+    key_version: String,
+    access_token: Option<String>,
+    public_key: OnceCell<SignerPublicKey>,
+}
+
+impl GcpKmsSigner {
+    const API_URL: &'static str = "https://cloudkms.googleapis.com/v1";
+    const METADATA_TOKEN_URL: &'static str =
+        "http://metadata.google.internal/computeMetadata/v1/instance/service-accounts/default/token";
+
+    /// Signs with the key version `projects/*/locations/*/keyRings/*/cryptoKeys/*/cryptoKeyVersions/*`
+    pub fn new(key_version: impl Into<String>) -> Self {
+        Self {
+            key_version: key_version.into(),
+            access_token: None,
+            public_key: OnceCell::new(),
+        }
+    }
+
+    /// Authenticates the requests with `token`
+    pub fn access_token(mut self, token: impl Into<String>) -> Self {
+        self.access_token = Some(token.into());
+        self
+    }
+
+    async fn token(&self) -> Result<String, DaemonError> {
+        if let Some(token) = self
+            .access_token
+            .clone()
+            .or_else(|| std::env::var("GOOGLE_OAUTH_ACCESS_TOKEN").ok())
+        {
+            return Ok(token);
+        }
+        let response: serde_json::Value = reqwest::Client::new()
+            .get(Self::METADATA_TOKEN_URL)
+            .header("Metadata-Flavor", "Google")
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+        response["access_token"]
+            .as_str()
+            .map(ToString::to_string)
+            .ok_or_else(|| DaemonError::StdErr("No GCP access token available".into()))
+    }
+}
+
+#[tonic::async_trait]
+impl TxSigner for GcpKmsSigner {
+    async fn public_key(&self) -> Result<SignerPublicKey, DaemonError> {
+        self.public_key
+            .get_or_try_init(|| async {
+                let response: serde_json::Value = reqwest::Client::new()
+                    .get(format!("{}/{}/publicKey", Self::API_URL, self.key_version))
+                    .bearer_auth(self.token().await?)
+                    .send()
+                    .await?
+                    .error_for_status()?
+                    .json()
+                    .await?;
+                let pem = response["pem"].as_str().ok_or_else(|| {
+                    DaemonError::StdErr(format!(
+                        "GCP KMS key {} has no public key",
+                        self.key_version
+                    ))
+                })?;
+                let der: String = pem
+                    .lines()
+                    .filter(|line| !line.starts_with("-----"))
+                    .collect();
+                public_key_from_spki(&STANDARD.decode(der)?)
+            })
+            .await
+            .cloned()
+    }
+
+    async fn sign(&self, sign_bytes: &[u8]) -> Result<TxSignature, DaemonError> {
+        let digest = STANDARD.encode(Sha256::digest(sign_bytes));
+        let response: serde_json::Value = reqwest::Client::new()
+            .post(format!(
+                "{}/{}:asymmetricSign",
+                Self::API_URL,
+                self.key_version
+            ))
+            .bearer_auth(self.token().await?)
+            .json(&serde_json::json!({ "digest": { "sha256": digest } }))
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+        let der = response["signature"].as_str().ok_or_else(|| {
+            DaemonError::StdErr(format!(
+                "GCP KMS key {} returned no signature",
+                self.key_version
+            ))
+        })?;
+        Ok(TxSignature {
+            signature: compact_signature(&STANDARD.decode(der)?)?,
+            public_key: self.public_key().await?,
+        })
+    }
+}
+
+/// Compressed public key of a DER `SubjectPublicKeyInfo`
+fn public_key_from_spki(der: &[u8]) -> Result<SignerPublicKey, DaemonError> {
+    let point = der
+        .len()
+        .checked_sub(UNCOMPRESSED_KEY_LEN)
+        .map(|start| &der[start..])
+        .ok_or(DaemonError::ConversionLength(der.len()))?;
+    let key = PublicKey::from_slice(point)?.serialize();
+    let public_key = cosmrs::crypto::PublicKey::from_raw_secp256k1(&key)
+        .ok_or(DaemonError::ConversionSECP256k1)?;
+    Ok(public_key.into())
+}
+
+/// The KMS return DER signatures, Cosmos expects 64 bytes `r || s` signatures with a low `s`
+fn compact_signature(der: &[u8]) -> Result<Vec<u8>, DaemonError> {
+    let mut signature = Signature::from_der(der)?;
+    signature.normalize_s();
+    Ok(signature.serialize_compact().to_vec())
+}
+
+#[cfg(test)]
+mod test {
+    use bitcoin::secp256k1::{Message, Secp256k1, SecretKey};
+
+    use super::*;
+
+    #[test]
+    fn converts_kms_outputs() -> Result<(), DaemonError> {
+        let secp = Secp256k1::new();
+        let secret = SecretKey::from_slice(&[7; 32])?;
+        let public = PublicKey::from_secret_key(&secp, &secret);
+
+        // SubjectPublicKeyInfo header of the secp256k1 keys
+        let mut spki = hex::decode("3056301006072a8648ce3d020106052b8104000a034200")?;
+        spki.extend(public.serialize_uncompressed());
+        let expected: SignerPublicKey =
+            cosmrs::crypto::PublicKey::from_raw_secp256k1(&public.serialize())
+                .unwrap()
+                .into();
+        assert_eq!(public_key_from_spki(&spki)?, expected);
+
+        let digest = Sha256::digest(b"sign doc");
+        let signature = secp.sign_ecdsa(&Message::from_digest(digest.into()), &secret);
+        assert_eq!(
+            compact_signature(&signature.serialize_der())?,
+            signature.serialize_compact().to_vec()
+        );
+        Ok(())
+    }
+}
@@ -5,14 +5,33 @@ pub mod sign;
 pub mod tx;
 
 // Senders
+mod budget;
 mod cosmos;
 mod cosmos_batch;
 mod cosmos_options;
+mod guard;
+mod kms;
 mod query_only;
+mod secrets;
+mod sequence;
+mod tx_signer;
 
 pub use {
+    budget::ExecutionBudget,
     cosmos::{CosmosSender, Wallet},
     cosmos_batch::{options::CosmosBatchOptions, BatchDaemon, CosmosBatchSender},
     cosmos_options::{CosmosOptions, CosmosWalletKey},
+    guard::{AllowedTx, TxGuard},
+    kms::GcpKmsSigner,
     query_only::{QueryOnlyDaemon, QueryOnlySender},
+    secrets::{EncryptedFileSecret, EnvSecret, SecretProvider, VaultSecret},
+    sequence::SequenceManager,
+    tx_signer::{LocalSigner, TxSignature, TxSigner},
 };
+
+#[cfg(feature = "aws-kms")]
+pub use kms::AwsKmsSigner;
+#[cfg(feature = "aws")]
+pub use secrets::AwsSecret;
+#[cfg(feature = "keyring")]
+pub use secrets::KeyringSecret;
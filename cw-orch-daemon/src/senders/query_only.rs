@@ -1,6 +1,6 @@
 use std::sync::Arc;
 
-use crate::{error::DaemonError, DaemonBase, GrpcChannel};
+use crate::{error::DaemonError, DaemonBase, GrpcChannel, RateLimits};
 
 use cw_orch_core::environment::ChainInfoOwned;
 
@@ -26,7 +26,17 @@ impl SenderBuilder for () {
     type Sender = QueryOnlySender;
 
     async fn build(&self, chain_info: &Arc<ChainInfoOwned>) -> Result<Self::Sender, Self::Error> {
-        let channel = GrpcChannel::from_chain_info(chain_info.as_ref()).await?;
+        self.build_with_rate_limits(chain_info, &RateLimits::default())
+            .await
+    }
+
+    async fn build_with_rate_limits(
+        &self,
+        chain_info: &Arc<ChainInfoOwned>,
+        rate_limits: &RateLimits,
+    ) -> Result<Self::Sender, Self::Error> {
+        let channel =
+            GrpcChannel::from_chain_info_with_limits(chain_info.as_ref(), rate_limits).await?;
 
         Ok(QueryOnlySender {
             channel,
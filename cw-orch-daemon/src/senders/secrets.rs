@@ -0,0 +1,260 @@
+//! Sources of the mnemonic of the default wallet.
+//!
+//! By default the mnemonic is read from the `MAIN_MNEMONIC`, `TEST_MNEMONIC` or `LOCAL_MNEMONIC` environment variable.
+//! A [`SecretProvider`] fetches it from somewhere else, so that CI deployments don't need plaintext mnemonics in their environment:
+//! - [`EnvSecret`]: an environment variable.
+//! - [`EncryptedFileSecret`]: a password-encrypted key file (see [`EncryptedKeyFile`]).
+//! - [`KeyringSecret`]: the OS keyring, requires the `keyring` feature.
+//! - [`VaultSecret`]: a HashiCorp Vault KV v2 secret.
+//! - [`AwsSecret`]: an AWS Secrets Manager secret, requires the `aws` feature.
+//!
+//! ```no_run
+//! use cw_orch_daemon::{networks, senders::EncryptedFileSecret, DaemonBuilder};
+//!
+//! let daemon = DaemonBuilder::new(networks::PION_1)
+//!     .secret_provider(EncryptedFileSecret::new("deployer.json", "DEPLOYER_PASSWORD"))
+//!     .build()
+//!     .unwrap();
+//! ```
+use std::path::PathBuf;
+
+use cw_orch_core::environment::ChainInfoOwned;
+
+use super::cosmos::get_mnemonic_env;
+use crate::{keys::encrypted::EncryptedKeyFile, DaemonError};
+
+/// Source of the mnemonic of a wallet
+#[tonic::async_trait]
+pub trait SecretProvider: Send + Sync {
+    /// Returns the mnemonic of the wallet used on `chain_info`
+    async fn mnemonic(&self, chain_info: &ChainInfoOwned) -> Result<String, DaemonError>;
+}
+
+/// Reads the mnemonic from an environment variable.
+/// Defaults to the variable matching the kind of the chain (`MAIN_MNEMONIC`, `TEST_MNEMONIC` or `LOCAL_MNEMONIC`).
+#[derive(Clone, Debug, Default)]
+pub struct EnvSecret {
+    var: Option<String>,
+}
+
+impl EnvSecret {
+    /// Reads the mnemonic from the environment variable `var`
+    pub fn new(var: impl Into<String>) -> Self {
+        Self {
+            var: Some(var.into()),
+        }
+    }
+}
+
+#[tonic::async_trait]
+impl SecretProvider for EnvSecret {
+    async fn mnemonic(&self, chain_info: &ChainInfoOwned) -> Result<String, DaemonError> {
+        match &self.var {
+            Some(var) => std::env::var(var)
+                .map_err(|_| DaemonError::StdErr(format!("Environment variable {var} not set"))),
+            None => Ok(get_mnemonic_env(&chain_info.kind)?),
+        }
+    }
+}
+
+/// Decrypts the mnemonic of an [`EncryptedKeyFile`], with the password held by an environment variable
+#[derive(Clone, Debug)]
+pub struct EncryptedFileSecret {
+    path: PathBuf,
+    password_var: String,
+}
+
+impl EncryptedFileSecret {
+    /// Decrypts the key file at `path` with the password of the environment variable `password_var`
+    pub fn new(path: impl Into<PathBuf>, password_var: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            password_var: password_var.into(),
+        }
+    }
+}
+
+#[tonic::async_trait]
+impl SecretProvider for EncryptedFileSecret {
+    async fn mnemonic(&self, _chain_info: &ChainInfoOwned) -> Result<String, DaemonError> {
+        let password = std::env::var(&self.password_var).map_err(|_| {
+            DaemonError::StdErr(format!(
+                "Environment variable {} not set",
+                self.password_var
+            ))
+        })?;
+        EncryptedKeyFile::read(&self.path)?.decrypt(&password)
+    }
+}
+
+/// Reads the mnemonic from the OS keyring (macOS keychain, Windows credential manager, Secret Service on Linux)
+#[cfg(feature = "keyring")]
+#[derive(Clone, Debug)]
+pub struct KeyringSecret {
+    service: String,
+    user: String,
+}
+
+#[cfg(feature = "keyring")]
+impl KeyringSecret {
+    /// Service of the keyring entries created by cw-orchestrator
+    pub const DEFAULT_SERVICE: &'static str = "cw-orch";
+
+    /// Reads the entry `user` of the default service
+    pub fn new(user: impl Into<String>) -> Self {
+        Self::with_service(Self::DEFAULT_SERVICE, user)
+    }
+
+    /// Reads the entry `user` of `service`
+    pub fn with_service(service: impl Into<String>, user: impl Into<String>) -> Self {
+        Self {
+            service: service.into(),
+            user: user.into(),
+        }
+    }
+}
+
+#[cfg(feature = "keyring")]
+#[tonic::async_trait]
+impl SecretProvider for KeyringSecret {
+    async fn mnemonic(&self, _chain_info: &ChainInfoOwned) -> Result<String, DaemonError> {
+        keyring::Entry::new(&self.service, &self.user)
+            .and_then(|entry| entry.get_password())
+            .map_err(|e| DaemonError::StdErr(format!("Keyring error: {e}")))
+    }
+}
+
+/// Reads the mnemonic from a HashiCorp Vault KV v2 secret.
+/// The token is read from the `VAULT_TOKEN` environment variable.
+#[derive(Clone, Debug)]
+pub struct VaultSecret {
+    address: String,
+    mount: String,
+    path: String,
+    field: String,
+}
+
+impl VaultSecret {
+    /// Environment variable holding the Vault token
+    pub const TOKEN_ENV_NAME: &'static str = "VAULT_TOKEN";
+
+    /// Reads the `mnemonic` field of the secret at `path` in the `secret` engine of the Vault at `address`
+    pub fn new(address: impl Into<String>, path: impl Into<String>) -> Self {
+        Self {
+            address: address.into(),
+            mount: "secret".to_string(),
+            path: path.into(),
+            field: "mnemonic".to_string(),
+        }
+    }
+
+    /// Sets the mount point of the KV engine
+    pub fn mount(mut self, mount: impl Into<String>) -> Self {
+        self.mount = mount.into();
+        self
+    }
+
+    /// Sets the field of the secret holding the mnemonic
+    pub fn field(mut self, field: impl Into<String>) -> Self {
+        self.field = field.into();
+        self
+    }
+}
+
+#[tonic::async_trait]
+impl SecretProvider for VaultSecret {
+    async fn mnemonic(&self, _chain_info: &ChainInfoOwned) -> Result<String, DaemonError> {
+        let token = std::env::var(Self::TOKEN_ENV_NAME).map_err(|_| {
+            DaemonError::StdErr(format!(
+                "Environment variable {} not set",
+                Self::TOKEN_ENV_NAME
+            ))
+        })?;
+        let url = format!(
+            "{}/v1/{}/data/{}",
+            self.address.trim_end_matches('/'),
+            self.mount,
+            self.path
+        );
+        let response: serde_json::Value = reqwest::Client::new()
+            .get(url)
+            .header("X-Vault-Token", token)
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+        response["data"]["data"][&self.field]
+            .as_str()
+            .map(ToString::to_string)
+            .ok_or_else(|| {
+                DaemonError::StdErr(format!(
+                    "Field {} not found in Vault secret {}",
+                    self.field, self.path
+                ))
+            })
+    }
+}
+
+/// Reads the mnemonic from an AWS Secrets Manager secret.
+/// The credentials and region are loaded from the environment, as done by the AWS CLI.
+#[cfg(feature = "aws")]
+#[derive(Clone, Debug)]
+pub struct AwsSecret {
+    secret_id: String,
+}
+
+#[cfg(feature = "aws")]
+impl AwsSecret {
+    /// Reads the secret with the name or ARN `secret_id`
+    pub fn new(secret_id: impl Into<String>) -> Self {
+        Self {
+            secret_id: secret_id.into(),
+        }
+    }
+}
+
+#[cfg(feature = "aws")]
+#[tonic::async_trait]
+impl SecretProvider for AwsSecret {
+    async fn mnemonic(&self, _chain_info: &ChainInfoOwned) -> Result<String, DaemonError> {
+        let config = aws_config::load_defaults(aws_config::BehaviorVersion::latest()).await;
+        let secret = aws_sdk_secretsmanager::Client::new(&config)
+            .get_secret_value()
+            .secret_id(&self.secret_id)
+            .send()
+            .await
+            .map_err(|e| DaemonError::StdErr(format!("AWS Secrets Manager error: {e}")))?;
+        secret
+            .secret_string()
+            .map(ToString::to_string)
+            .ok_or_else(|| {
+                DaemonError::StdErr(format!("Secret {} has no string value", self.secret_id))
+            })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::networks::PION_1;
+
+    #[tokio::test]
+    async fn decrypts_key_file() {
+        let mnemonic = "chapter wrist alcohol shine angry noise mercy simple rebel recycle vehicle wrap morning giraffe lazy outdoor noise blood ginger sort reunion boss crowd dutch";
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("key.json");
+        EncryptedKeyFile::encrypt(mnemonic, "password")
+            .unwrap()
+            .write(&path)
+            .unwrap();
+        std::env::set_var("SECRET_PROVIDER_PASSWORD", "password");
+
+        let provider = EncryptedFileSecret::new(&path, "SECRET_PROVIDER_PASSWORD");
+        assert_eq!(provider.mnemonic(&PION_1.into()).await.unwrap(), mnemonic);
+        assert!(EnvSecret::new("SECRET_PROVIDER_UNSET")
+            .mnemonic(&PION_1.into())
+            .await
+            .is_err());
+    }
+}
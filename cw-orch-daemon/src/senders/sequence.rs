@@ -0,0 +1,89 @@
+use std::sync::{Arc, Mutex};
+
+/// Tracks the sequence of an account locally, so that multiple transactions can be broadcasted in the same block
+/// without waiting for the previous ones to be included.
+///
+/// The node only reports the sequence of the last committed block, which doesn't account for the transactions waiting in the mempool.
+/// The manager hands out sequences from its local counter (or from the node if it's ahead)
+/// and resynchronizes when a transaction is rejected.
+///
+/// Clones of the manager (and of the senders that hold it) share the same counter.
+#[derive(Clone, Debug, Default)]
+pub struct SequenceManager {
+    next: Arc<Mutex<Option<u64>>>,
+}
+
+impl SequenceManager {
+    /// Allocates the sequence of the next transaction, given the sequence reported by the node
+    pub fn next_sequence(&self, chain_sequence: u64) -> u64 {
+        self.allocate(chain_sequence, 1)
+    }
+
+    /// Allocates `count` consecutive sequences, given the sequence reported by the node.
+    /// Returns the first allocated sequence.
+    pub fn allocate(&self, chain_sequence: u64, count: u64) -> u64 {
+        let mut next = self.next.lock().unwrap();
+        let sequence = next.map_or(chain_sequence, |next| next.max(chain_sequence));
+        *next = Some(sequence + count);
+        sequence
+    }
+
+    /// Records that the transaction using `sequence` was rejected by the node, with the given error.
+    /// The sequence is reused if no other was allocated since. Otherwise, the counter is resynchronized with the node.
+    pub fn rejected(&self, sequence: u64, error: &str) {
+        let mut next = self.next.lock().unwrap();
+        *next = if let Some(expected) = parse_expected_sequence(error) {
+            Some(expected)
+        } else if *next == Some(sequence + 1) {
+            Some(sequence)
+        } else {
+            None
+        };
+    }
+
+    /// Drops the local counter, the next sequence is taken from the node
+    pub fn resync(&self) {
+        *self.next.lock().unwrap() = None;
+    }
+}
+
+// from logs: "account sequence mismatch, expected 12, got 14: incorrect account sequence"
+fn parse_expected_sequence(error: &str) -> Option<u64> {
+    let (_, expected) = error.split_once("account sequence mismatch, expected ")?;
+    let end = expected
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(expected.len());
+    expected[..end].parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tracks_pending_sequences() {
+        let manager = SequenceManager::default();
+
+        // Two txs in the same block, the node still reports the first sequence
+        assert_eq!(manager.next_sequence(5), 5);
+        assert_eq!(manager.next_sequence(5), 6);
+        assert_eq!(manager.allocate(5, 3), 7);
+
+        // The node is ahead (the account was used elsewhere)
+        assert_eq!(manager.next_sequence(20), 20);
+
+        // The last tx was rejected, its sequence is reused
+        manager.rejected(20, "out of gas");
+        assert_eq!(manager.next_sequence(20), 20);
+
+        // The node tells us which sequence it expects
+        manager.rejected(
+            20,
+            "account sequence mismatch, expected 18, got 20: incorrect account sequence",
+        );
+        assert_eq!(manager.next_sequence(17), 18);
+
+        manager.resync();
+        assert_eq!(manager.next_sequence(17), 17);
+    }
+}
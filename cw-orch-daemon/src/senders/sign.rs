@@ -2,13 +2,16 @@ use std::str::FromStr;
 
 use crate::{
     parse_cw_coins,
+    polling::PollingConfig,
     queriers::Node,
     tx_broadcaster::{
         account_sequence_strategy, assert_broadcast_code_cosm_response, insufficient_fee_strategy,
         TxBroadcaster,
     },
-    CosmTxResponse, DaemonError, QuerySender, TxBuilder, TxSender,
+    CosmTxResponse, DaemonError, DecodedTxMsg, QuerySender, TxBuilder, TxSender,
 };
+
+use super::{tx::broadcast_tx_with_mode, ExecutionBudget, SequenceManager, TxGuard};
 use cosmrs::{
     bank::MsgSend,
     proto::cosmos::authz::v1beta1::MsgExec,
@@ -17,6 +20,7 @@ use cosmrs::{
     AccountId, Any,
 };
 use cosmwasm_std::Addr;
+use cw_orch_core::{log::transaction_target, CoreEnvVars};
 use prost::Message;
 
 pub struct SigningAccount {
@@ -42,9 +46,35 @@ pub trait Signer: QuerySender<Error = DaemonError> + Sync {
         None
     }
 
+    /// Limits on the transactions this signer can broadcast
+    fn budget(&self) -> Option<&ExecutionBudget> {
+        None
+    }
+
+    /// Local tracker of the account sequence, used to broadcast multiple transactions per block.
+    /// When `None`, the sequence is queried from the node for every transaction.
+    fn sequence_manager(&self) -> Option<&SequenceManager> {
+        None
+    }
+
+    /// Timings used to poll the chain for the transactions of this signer.
+    /// When `None`, the default [`PollingConfig`] is used.
+    fn polling(&self) -> Option<&PollingConfig> {
+        None
+    }
+
+    /// Confirmation required before broadcasting the transactions of this signer.
+    /// Only returned when it applies to the connected chain.
+    fn tx_guard(&self) -> Option<&TxGuard> {
+        None
+    }
+
     // --- Related to transaction signing --- //
     /// Transaction signing
-    fn sign(&self, sign_doc: SignDoc) -> Result<Raw, DaemonError>;
+    fn sign(
+        &self,
+        sign_doc: SignDoc,
+    ) -> impl std::future::Future<Output = Result<Raw, DaemonError>> + Send;
 
     fn signer_info(&self, sequence: u64) -> SignerInfo;
 
@@ -71,7 +101,7 @@ pub trait Signer: QuerySender<Error = DaemonError> + Sync {
                 account_number,
             )?;
 
-            let tx_raw = self.sign(sign_doc)?;
+            let tx_raw = self.sign(sign_doc).await?;
 
             Node::new_async(self.channel())
                 ._simulate_tx(tx_raw.to_bytes()?)
@@ -80,11 +110,66 @@ pub trait Signer: QuerySender<Error = DaemonError> + Sync {
     }
 }
 
+/// Fee paid by an included transaction, read from the `fee` attribute of its `tx` event
+fn paid_fee(resp: &CosmTxResponse) -> Option<u128> {
+    let fee = resp
+        .get_events("tx")
+        .into_iter()
+        .find_map(|event| event.get_first_attribute_value("fee"))?;
+    fee.split(',')
+        .filter(|coin| !coin.is_empty())
+        .map(|coin| {
+            cosmwasm_std::Coin::from_str(coin)
+                .ok()
+                .map(|coin| coin.amount.u128())
+        })
+        .sum()
+}
+
+/// Wraps the messages inside an authz `MsgExec` if the signer is using authz
+pub(crate) fn wrap_authz(signer: &impl Signer, msgs: Vec<Any>) -> Vec<Any> {
+    if signer.authz_granter().is_some() {
+        vec![Any {
+            type_url: "/cosmos.authz.v1beta1.MsgExec".to_string(),
+            value: MsgExec {
+                grantee: signer.account_id().to_string(),
+                msgs,
+            }
+            .encode_to_vec(),
+        }]
+    } else {
+        msgs
+    }
+}
+
 impl<T: Signer + Sync> TxSender for T {
     fn account_id(&self) -> cosmrs::AccountId {
         self.account_id()
     }
 
+    async fn broadcast_tx(
+        &self,
+        tx: Raw,
+    ) -> Result<cosmrs::proto::cosmos::base::abci::v1beta1::TxResponse, DaemonError> {
+        let mode = self
+            .polling()
+            .map(|polling| polling.broadcast_mode)
+            .unwrap_or_default();
+        broadcast_tx_with_mode(self.channel(), tx, mode).await
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "commit_tx",
+            skip_all,
+            fields(
+                chain_id = %self.chain_id(),
+                tx_hash = tracing::field::Empty,
+                gas_used = tracing::field::Empty
+            )
+        )
+    )]
     async fn commit_tx_any(
         &self,
         msgs: Vec<Any>,
@@ -92,22 +177,65 @@ impl<T: Signer + Sync> TxSender for T {
     ) -> Result<CosmTxResponse, DaemonError> {
         let timeout_height = Node::new_async(self.channel())._block_height().await? + 10u64;
 
-        let msgs = if self.authz_granter().is_some() {
-            // We wrap authz messages
-            vec![Any {
-                type_url: "/cosmos.authz.v1beta1.MsgExec".to_string(),
-                value: MsgExec {
-                    grantee: self.account_id().to_string(),
-                    msgs,
-                }
-                .encode_to_vec(),
-            }]
-        } else {
-            msgs
-        };
+        let guarded_msgs = self
+            .tx_guard()
+            .map(|_| {
+                msgs.iter()
+                    .cloned()
+                    .map(DecodedTxMsg::decode)
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .transpose()?;
+
+        let msgs = wrap_authz(self, msgs);
 
         let tx_body = TxBuilder::build_body(msgs, memo, timeout_height);
 
+        if CoreEnvVars::dry_run() {
+            let SigningAccount {
+                account_number,
+                sequence,
+            } = self.signing_account().await?;
+            let gas_used = self
+                .calculate_gas(&tx_body, sequence, account_number)
+                .await?;
+            log::info!(
+                target: &transaction_target(),
+                "[DRY RUN] Simulated {} message(s), gas used: {}",
+                tx_body.messages.len(),
+                gas_used
+            );
+            return Ok(CosmTxResponse {
+                gas_wanted: gas_used,
+                gas_used,
+                ..Default::default()
+            });
+        }
+
+        let needs_fee = guarded_msgs.is_some() || self.budget().is_some_and(|b| b.limits_fee());
+        let (expected_gas, expected_fee) = if needs_fee {
+            let SigningAccount {
+                account_number,
+                sequence,
+            } = self.signing_account().await?;
+            let gas_used = self
+                .calculate_gas(&tx_body, sequence, account_number)
+                .await?;
+            TxBuilder::get_fee_from_gas(gas_used, self.gas_price()?)?
+        } else {
+            (0, 0)
+        };
+        if let (Some(guard), Some(msgs)) = (self.tx_guard(), &guarded_msgs) {
+            guard
+                .confirm(&self.chain_id(), msgs, expected_gas, expected_fee)
+                .await?;
+        }
+        // Released if the broadcast fails
+        let reservation = self
+            .budget()
+            .map(|budget| budget.reserve(expected_fee))
+            .transpose()?;
+
         let tx_builder = TxBuilder::new(tx_body);
 
         // We retry broadcasting the tx, with the following strategies
@@ -121,9 +249,21 @@ impl<T: Signer + Sync> TxSender for T {
             .broadcast(tx_builder, self)
             .await?;
 
-        let resp = Node::new_async(self.channel())
-            ._find_tx(tx_response.txhash)
-            .await?;
+        let confirmed = Node::new_async(self.channel())
+            .with_polling(self.polling().cloned().unwrap_or_default())
+            ._confirm_tx(tx_response.txhash)
+            .await;
+        // The tx was accepted by the node, so it may still pay its fee if it can't be confirmed
+        if let Some(reservation) = reservation {
+            let paid_fee = confirmed.as_ref().ok().and_then(paid_fee);
+            reservation.commit(paid_fee.unwrap_or(expected_fee));
+        }
+        let resp = confirmed?;
+
+        #[cfg(feature = "tracing")]
+        tracing::Span::current()
+            .record("tx_hash", resp.txhash.as_str())
+            .record("gas_used", resp.gas_used);
 
         assert_broadcast_code_cosm_response(resp)
     }
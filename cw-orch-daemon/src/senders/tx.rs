@@ -4,7 +4,7 @@ use cosmrs::{
 };
 use cosmwasm_std::Addr;
 
-use crate::{cosmos_modules, CosmTxResponse, DaemonError};
+use crate::{cosmos_modules, BroadcastMode, CosmTxResponse, DaemonError};
 
 use super::query::QuerySender;
 
@@ -53,18 +53,7 @@ pub trait TxSender: QuerySender + Sync {
     ) -> impl std::future::Future<
         Output = Result<cosmrs::proto::cosmos::base::abci::v1beta1::TxResponse, DaemonError>,
     > + Send {
-        async move {
-            let mut client = cosmos_modules::tx::service_client::ServiceClient::new(self.channel());
-            let commit = client
-                .broadcast_tx(cosmos_modules::tx::BroadcastTxRequest {
-                    tx_bytes: tx.to_bytes()?,
-                    mode: cosmos_modules::tx::BroadcastMode::Sync.into(),
-                })
-                .await?;
-
-            let commit = commit.into_inner().tx_response.unwrap();
-            Ok(commit)
-        }
+        broadcast_tx_with_mode(self.channel(), tx, BroadcastMode::Sync)
     }
 
     // Send funds using the bank module
@@ -76,3 +65,25 @@ pub trait TxSender: QuerySender + Sync {
         async { unimplemented!() }
     }
 }
+
+/// Broadcasts a signed transaction with the given `mode`
+pub(crate) async fn broadcast_tx_with_mode(
+    channel: tonic::transport::Channel,
+    tx: Raw,
+    mode: BroadcastMode,
+) -> Result<cosmrs::proto::cosmos::base::abci::v1beta1::TxResponse, DaemonError> {
+    let mode = match mode {
+        BroadcastMode::Sync => cosmos_modules::tx::BroadcastMode::Sync,
+        BroadcastMode::Async => cosmos_modules::tx::BroadcastMode::Async,
+    };
+    let mut client = cosmos_modules::tx::service_client::ServiceClient::new(channel);
+    let commit = client
+        .broadcast_tx(cosmos_modules::tx::BroadcastTxRequest {
+            tx_bytes: tx.to_bytes()?,
+            mode: mode.into(),
+        })
+        .await?;
+
+    let commit = commit.into_inner().tx_response.unwrap();
+    Ok(commit)
+}
@@ -0,0 +1,321 @@
+//! Signers of the transactions of a [`Wallet`](super::Wallet).
+//!
+//! By default a wallet signs with a [`LocalSigner`], holding a private key derived from a mnemonic.
+//! Implementing [`TxSigner`] allows to sign with keys that never leave another service (KMS, threshold signing service, remote signer...).
+//!
+//! ```no_run
+//! use cosmrs::{crypto::PublicKey, tx::SignerPublicKey};
+//! use cw_orch_daemon::{networks, senders::{TxSignature, TxSigner}, DaemonBuilder, DaemonError};
+//!
+//! struct RemoteSigner {
+//!     url: String,
+//!     public_key: PublicKey,
+//! }
+//!
+//! #[tonic::async_trait]
+//! impl TxSigner for RemoteSigner {
+//!     async fn public_key(&self) -> Result<SignerPublicKey, DaemonError> {
+//!         Ok(self.public_key.into())
+//!     }
+//!
+//!     async fn sign(&self, sign_bytes: &[u8]) -> Result<TxSignature, DaemonError> {
+//!         let signature = reqwest::Client::new()
+//!             .post(&self.url)
+//!             .body(sign_bytes.to_vec())
+//!             .send()
+//!             .await?
+//!             .bytes()
+//!             .await?;
+//!         Ok(TxSignature {
+//!             signature: signature.to_vec(),
+//!             public_key: self.public_key.into(),
+//!         })
+//!     }
+//! }
+//!
+//! # let public_key: PublicKey = todo!();
+//! let daemon = DaemonBuilder::new(networks::PION_1)
+//!     .signer(RemoteSigner { url: "https://signer.internal/sign".into(), public_key })
+//!     .build()
+//!     .unwrap();
+//! ```
+use bitcoin::secp256k1::{All, Secp256k1};
+use cosmrs::{
+    crypto::secp256k1::SigningKey,
+    proto::cosmos::tx::v1beta1::{SignDoc as ProtoSignDoc, TxRaw},
+    tx::{Raw, SignDoc, SignerPublicKey},
+    AccountId,
+};
+use prost::{Message, Name};
+
+use crate::{
+    keys::private::PrivateKey,
+    proto::injective::{InjectivePubKey, ETHEREUM_COIN_TYPE},
+    DaemonError,
+};
+
+#[cfg(feature = "eth")]
+use crate::proto::{eip712::Eip712Signer, injective::InjectiveSigner};
+
+/// Signature of a transaction by a [`TxSigner`]
+#[derive(Clone, Debug)]
+pub struct TxSignature {
+    /// Signature of the `SignDoc` bytes
+    pub signature: Vec<u8>,
+    /// Public key verifying the signature
+    pub public_key: SignerPublicKey,
+}
+
+/// Signer of the transactions of a [`Wallet`](super::Wallet)
+#[tonic::async_trait]
+pub trait TxSigner: Send + Sync {
+    /// Public key of the signer, set in the signer infos of the transactions
+    async fn public_key(&self) -> Result<SignerPublicKey, DaemonError>;
+
+    /// Signs the proto encoded `SignDoc` of a transaction (`SIGN_MODE_DIRECT`)
+    async fn sign(&self, sign_bytes: &[u8]) -> Result<TxSignature, DaemonError>;
+
+    /// Address of the signer on a chain using the bech32 `prefix`.
+    /// Defaults to the address derived from a single [`TxSigner::public_key`],
+    /// or from the ethereum public key of Injective signers (requires the `eth` feature).
+    async fn account_id(&self, prefix: &str) -> Result<AccountId, DaemonError> {
+        match self.public_key().await? {
+            SignerPublicKey::Single(public_key) => Ok(public_key.account_id(prefix)?),
+            SignerPublicKey::Any(any) if any.type_url == InjectivePubKey::type_url() => {
+                eth_account_id(&InjectivePubKey::decode(any.value.as_slice())?.key, prefix)
+            }
+            _ => Err(DaemonError::StdErr(
+                "The address of a signer without a single public key can't be derived".into(),
+            )),
+        }
+    }
+
+    /// Signs a transaction. Defaults to signing the bytes of the `SignDoc` with [`TxSigner::sign`].
+    async fn sign_doc(&self, sign_doc: SignDoc) -> Result<Raw, DaemonError> {
+        let TxSignature { signature, .. } = self.sign(&sign_doc.clone().into_bytes()?).await?;
+        Ok(TxRaw {
+            body_bytes: sign_doc.body_bytes,
+            auth_info_bytes: sign_doc.auth_info_bytes,
+            signatures: vec![signature],
+        }
+        .into())
+    }
+
+    /// Private key of the signer, only available for local signers
+    fn private_key(&self) -> Option<&PrivateKey> {
+        None
+    }
+}
+
+/// Signs with a private key held in memory
+#[derive(Clone)]
+pub struct LocalSigner {
+    private_key: PrivateKey,
+    /// Ethereum chain id used to sign EIP-712 typed data, requires the `eth` feature
+    eip712_chain_id: Option<u64>,
+    secp: Secp256k1<All>,
+}
+
+impl LocalSigner {
+    pub fn new(private_key: PrivateKey) -> Self {
+        Self {
+            private_key,
+            eip712_chain_id: None,
+            secp: Secp256k1::new(),
+        }
+    }
+
+    /// Signs with EIP-712 typed data (Injective accounts created with Metamask), requires the `eth` feature
+    pub fn eip712(mut self, eth_chain_id: Option<u64>) -> Self {
+        self.eip712_chain_id = eth_chain_id;
+        self
+    }
+
+    fn signing_key(&self) -> Result<SigningKey, DaemonError> {
+        Ok(SigningKey::from_slice(&self.private_key.raw_key())?)
+    }
+
+    /// Ethereum keys can only be used with the `eth` feature
+    fn check_coin_type(&self) -> Result<(), DaemonError> {
+        if cfg!(not(feature = "eth")) && self.private_key.coin_type == ETHEREUM_COIN_TYPE {
+            return Err(DaemonError::UnsupportedCoinType(ETHEREUM_COIN_TYPE));
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "eth")]
+    fn sign_eth_doc(&self, sign_doc: SignDoc) -> Result<Raw, DaemonError> {
+        if let Some(eth_chain_id) = self.eip712_chain_id {
+            self.private_key.sign_eip712(sign_doc, eth_chain_id)
+        } else {
+            self.private_key.sign_injective(sign_doc)
+        }
+    }
+
+    #[cfg(not(feature = "eth"))]
+    fn sign_eth_doc(&self, _sign_doc: SignDoc) -> Result<Raw, DaemonError> {
+        Err(DaemonError::UnsupportedCoinType(ETHEREUM_COIN_TYPE))
+    }
+
+    pub(crate) fn signer_public_key(&self) -> Result<SignerPublicKey, DaemonError> {
+        self.check_coin_type()?;
+        self.private_key
+            .get_signer_public_key(&self.secp)
+            .ok_or(DaemonError::Implementation)
+    }
+
+    pub(crate) fn address(&self, prefix: &str) -> Result<AccountId, DaemonError> {
+        self.check_coin_type()?;
+        // Ethereum addresses are not derived from the sha256 hash of the public key
+        let raw_address = self
+            .private_key
+            .public_key(&self.secp)
+            .raw_address
+            .ok_or(DaemonError::Implementation)?;
+        Ok(AccountId::new(prefix, &raw_address)?)
+    }
+}
+
+#[tonic::async_trait]
+impl TxSigner for LocalSigner {
+    async fn public_key(&self) -> Result<SignerPublicKey, DaemonError> {
+        self.signer_public_key()
+    }
+
+    async fn sign(&self, sign_bytes: &[u8]) -> Result<TxSignature, DaemonError> {
+        let signature = if self.private_key.coin_type == ETHEREUM_COIN_TYPE {
+            // Ethereum keys don't sign the raw bytes, go through the same path as `sign_doc`
+            let sign_doc = ProtoSignDoc::decode(sign_bytes)?;
+            let tx_raw: TxRaw = self
+                .sign_eth_doc(SignDoc {
+                    body_bytes: sign_doc.body_bytes,
+                    auth_info_bytes: sign_doc.auth_info_bytes,
+                    chain_id: sign_doc.chain_id.parse()?,
+                    account_number: sign_doc.account_number,
+                })?
+                .into();
+            tx_raw
+                .signatures
+                .into_iter()
+                .next()
+                .ok_or(DaemonError::Implementation)?
+        } else {
+            self.signing_key()?.sign(sign_bytes)?.to_vec()
+        };
+        Ok(TxSignature {
+            signature,
+            public_key: self.signer_public_key()?,
+        })
+    }
+
+    async fn account_id(&self, prefix: &str) -> Result<AccountId, DaemonError> {
+        self.address(prefix)
+    }
+
+    async fn sign_doc(&self, sign_doc: SignDoc) -> Result<Raw, DaemonError> {
+        if self.private_key.coin_type == ETHEREUM_COIN_TYPE {
+            return self.sign_eth_doc(sign_doc);
+        }
+        Ok(sign_doc.sign(&self.signing_key()?)?)
+    }
+
+    fn private_key(&self) -> Option<&PrivateKey> {
+        Some(&self.private_key)
+    }
+}
+
+/// Address derived from the ethereum public key of a signer
+#[cfg(feature = "eth")]
+fn eth_account_id(public_key: &[u8], prefix: &str) -> Result<AccountId, DaemonError> {
+    let raw_address = crate::keys::public::PublicKey::from_eth_public_key(public_key)?
+        .raw_address
+        .ok_or(DaemonError::Implementation)?;
+    Ok(AccountId::new(prefix, &raw_address)?)
+}
+
+#[cfg(not(feature = "eth"))]
+fn eth_account_id(_public_key: &[u8], _prefix: &str) -> Result<AccountId, DaemonError> {
+    Err(DaemonError::UnsupportedCoinType(ETHEREUM_COIN_TYPE))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Signer only implementing the required methods, as a remote signer would
+    struct RemoteSigner(LocalSigner);
+
+    #[tonic::async_trait]
+    impl TxSigner for RemoteSigner {
+        async fn public_key(&self) -> Result<SignerPublicKey, DaemonError> {
+            self.0.public_key().await
+        }
+
+        async fn sign(&self, sign_bytes: &[u8]) -> Result<TxSignature, DaemonError> {
+            self.0.sign(sign_bytes).await
+        }
+    }
+
+    fn local_signer(coin_type: u32) -> LocalSigner {
+        LocalSigner::new(PrivateKey::new(&Secp256k1::new(), coin_type).unwrap())
+    }
+
+    fn sign_doc() -> SignDoc {
+        SignDoc {
+            body_bytes: vec![1, 2, 3],
+            auth_info_bytes: vec![4, 5, 6],
+            chain_id: "juno-1".parse().unwrap(),
+            account_number: 7,
+        }
+    }
+
+    fn signature(tx_raw: Raw) -> Vec<u8> {
+        TxRaw::from(tx_raw).signatures.remove(0)
+    }
+
+    #[tokio::test]
+    async fn custom_signer_defaults() -> Result<(), DaemonError> {
+        let local = local_signer(118);
+        let remote = RemoteSigner(local.clone());
+
+        assert_eq!(
+            remote.account_id("juno").await?,
+            local.account_id("juno").await?
+        );
+        // The default `sign_doc` signs the `SignDoc` bytes with `sign`
+        assert_eq!(
+            signature(remote.sign_doc(sign_doc()).await?),
+            signature(local.sign_doc(sign_doc()).await?)
+        );
+        Ok(())
+    }
+
+    #[cfg(feature = "eth")]
+    #[tokio::test]
+    async fn custom_eth_signer_defaults() -> Result<(), DaemonError> {
+        let local = local_signer(ETHEREUM_COIN_TYPE);
+        let remote = RemoteSigner(local.clone());
+
+        assert_eq!(remote.account_id("inj").await?, local.address("inj")?);
+        assert_eq!(
+            signature(remote.sign_doc(sign_doc()).await?),
+            signature(local.sign_doc(sign_doc()).await?)
+        );
+        Ok(())
+    }
+
+    #[cfg(not(feature = "eth"))]
+    #[tokio::test]
+    async fn eth_keys_need_the_feature() {
+        let signer = local_signer(ETHEREUM_COIN_TYPE);
+
+        assert!(matches!(
+            signer.public_key().await,
+            Err(DaemonError::UnsupportedCoinType(ETHEREUM_COIN_TYPE))
+        ));
+        assert!(matches!(
+            signer.sign_doc(sign_doc()).await,
+            Err(DaemonError::UnsupportedCoinType(ETHEREUM_COIN_TYPE))
+        ));
+    }
+}
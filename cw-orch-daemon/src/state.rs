@@ -36,6 +36,19 @@ pub struct DaemonState {
     pub chain_data: Arc<ChainInfoOwned>,
     /// Whether to write on every change of the state
     pub write_on_change: bool,
+    /// Read-only states that address and code-id resolution falls back to, shared by the clones of this state
+    pub(crate) mounted: Arc<Mutex<Vec<MountedState>>>,
+}
+
+/// Read-only state file mounted on a [`DaemonState`], see [`DaemonState::mount`]
+#[derive(Debug, Clone)]
+pub struct MountedState {
+    /// Path of the file
+    pub path: String,
+    /// Deployment id whose addresses are read
+    pub deployment_id: String,
+    /// States of the file, keyed by chain id
+    chains: Value,
 }
 
 impl Drop for DaemonState {
@@ -120,6 +133,7 @@ impl DaemonState {
             deployment_id,
             chain_data: chain_data.clone(),
             write_on_change,
+            mounted: Default::default(),
         })
     }
 
@@ -160,20 +174,7 @@ impl DaemonState {
 
     /// Retrieve a stateful value using the chainId and networkId
     pub fn get(&self, key: &str) -> Result<Value, DaemonError> {
-        let json = match &self.json_state {
-            DaemonStateFile::ReadOnly { path } => {
-                let j = crate::json_lock::read(path)?;
-                let j = patch_state_if_old(j);
-
-                j[&self.chain_data.chain_id].clone()
-            }
-            DaemonStateFile::FullAccess { json_file_state } => json_file_state
-                .lock()
-                .unwrap()
-                .get(&self.chain_data.chain_id)
-                .clone(),
-        };
-        Ok(json[key].clone())
+        Ok(self.chain_json()?[key].clone())
     }
 
     /// Set a stateful value using the chainId and networkId
@@ -258,6 +259,232 @@ impl DaemonState {
     }
 }
 
+/// Current version of the exported state format
+pub const EXPORTED_STATE_VERSION: u8 = 1;
+
+/// How to handle entries that already exist locally when importing a state file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DuplicateResolve {
+    /// Keep the local value
+    Keep,
+    /// Replace the local value with the imported one
+    Overwrite,
+    /// Error when an imported value differs from the local one
+    Error,
+}
+
+/// Shareable state file, keyed by chain-id.
+/// Each chain entry has the same layout as the daemon state file (`code_ids` and one object per deployment id).
+#[derive(Debug, Clone, Serialize, serde::Deserialize, PartialEq)]
+pub struct ExportedState {
+    /// Version of the file format
+    pub version: u8,
+    /// State of each chain, keyed by chain-id
+    pub chains: serde_json::Map<String, Value>,
+}
+
+impl DaemonState {
+    /// Exports the state (code-ids and addresses of all deployments) of the current chain to a shareable JSON file.
+    /// If the file already exists, the current chain entry is added to it.
+    pub fn export(&self, path: impl AsRef<Path>) -> Result<(), DaemonError> {
+        let path = path.as_ref();
+        let mut exported = if path.exists() {
+            serde_json::from_slice::<ExportedState>(&std::fs::read(path)?)?
+        } else {
+            ExportedState {
+                version: EXPORTED_STATE_VERSION,
+                chains: Default::default(),
+            }
+        };
+        exported
+            .chains
+            .insert(self.chain_data.chain_id.clone(), self.chain_json()?);
+        std::fs::write(path, serde_json::to_vec_pretty(&exported)?)?;
+        Ok(())
+    }
+
+    /// Imports the current chain entry of a shared state file into this state.
+    /// Entries that already exist locally are handled according to `resolve`.
+    pub fn import(
+        &mut self,
+        path: impl AsRef<Path>,
+        resolve: DuplicateResolve,
+    ) -> Result<(), DaemonError> {
+        let exported: ExportedState = serde_json::from_slice(&std::fs::read(path)?)?;
+        if exported.version != EXPORTED_STATE_VERSION {
+            return Err(DaemonError::StdErr(format!(
+                "Unsupported exported state version {}",
+                exported.version
+            )));
+        }
+        let Some(Value::Object(imported)) = exported.chains.get(&self.chain_data.chain_id) else {
+            return Ok(());
+        };
+        let local = self.chain_json()?;
+
+        for (key, values) in imported {
+            let Value::Object(values) = values else {
+                continue;
+            };
+            for (contract_id, value) in values {
+                match local.get(key).and_then(|v| v.get(contract_id)) {
+                    Some(existing) if !existing.is_null() && existing != value => match resolve {
+                        DuplicateResolve::Keep => continue,
+                        DuplicateResolve::Overwrite => {}
+                        DuplicateResolve::Error => {
+                            return Err(DaemonError::StdErr(format!(
+                                "Conflict on {}.{}: local {}, imported {}",
+                                key, contract_id, existing, value
+                            )))
+                        }
+                    },
+                    _ => {}
+                }
+                self.set(key, contract_id, value)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Mounts a read-only state file, such as the state artifact published by a protocol.
+    /// Addresses and code-ids that this state doesn't hold are looked up in the mounted files, in mounting order.
+    /// The file is never written to. Both the daemon state file format and the [`ExportedState`] format are accepted.
+    ///
+    /// `deployment_id` defaults to the deployment id of this state.
+    pub fn mount(
+        &self,
+        path: impl AsRef<Path>,
+        deployment_id: Option<&str>,
+    ) -> Result<(), DaemonError> {
+        let path = path.as_ref().to_string_lossy().to_string();
+        let json = crate::json_lock::read(&path)?;
+        self.mount_json(path, json, deployment_id);
+        Ok(())
+    }
+
+    /// Mounts a read-only state file published online, such as the state artifact of a protocol on its GitHub repository.
+    /// See [`DaemonState::mount`] for how the mounted states are used. The file is fetched once and only kept in memory.
+    ///
+    /// `url_or_github` is either a URL (`https://...`), a GitHub file URL (`https://github.com/<owner>/<repo>/blob/<ref>/<path>`)
+    /// or a short GitHub path: `github:<owner>/<repo>/<path>`, optionally with a git ref: `github:<owner>/<repo>@<ref>/<path>`.
+    ///
+    /// ```rust,no_run
+    /// # use cw_orch_daemon::{Daemon, networks::JUNO_1};
+    /// let daemon = Daemon::builder(JUNO_1).build().unwrap();
+    /// daemon
+    ///     .state()
+    ///     .read_remote("github:AbstractSDK/abstract@v0.23.0/scripts/state.json", None)
+    ///     .unwrap();
+    /// ```
+    pub fn read_remote(
+        &self,
+        url_or_github: &str,
+        deployment_id: Option<&str>,
+    ) -> Result<(), DaemonError> {
+        crate::RUNTIME.block_on(self._read_remote(url_or_github, deployment_id))
+    }
+
+    /// Async version of [`DaemonState::read_remote`]
+    pub async fn _read_remote(
+        &self,
+        url_or_github: &str,
+        deployment_id: Option<&str>,
+    ) -> Result<(), DaemonError> {
+        let url = remote_state_url(url_or_github)?;
+        log::debug!(target: &local_target(), "Fetching remote state {}", url);
+        let json = reqwest::get(&url)
+            .await?
+            .error_for_status()?
+            .json::<Value>()
+            .await?;
+        self.mount_json(url, json, deployment_id);
+        Ok(())
+    }
+
+    fn mount_json(&self, path: String, json: Value, deployment_id: Option<&str>) {
+        let chains = match serde_json::from_value::<ExportedState>(json.clone()) {
+            Ok(exported) => Value::Object(exported.chains),
+            Err(_) => patch_state_if_old(json),
+        };
+        if chains[&self.chain_data.chain_id].is_null() {
+            log::warn!(
+                target: &local_target(),
+                "Mounted state {} has no entry for chain {}",
+                path,
+                self.chain_data.chain_id
+            );
+        }
+
+        self.mounted.lock().unwrap().push(MountedState {
+            path,
+            deployment_id: deployment_id.unwrap_or(&self.deployment_id).to_string(),
+            chains,
+        });
+    }
+
+    /// Read-only states mounted on this state
+    pub fn mounted(&self) -> Vec<MountedState> {
+        self.mounted.lock().unwrap().clone()
+    }
+
+    /// Looks up `key.contract_id` in the mounted states
+    fn get_mounted(&self, key: MountedKey, contract_id: &str) -> Option<Value> {
+        self.mounted.lock().unwrap().iter().find_map(|mounted| {
+            let key = match key {
+                MountedKey::Address => mounted.deployment_id.as_str(),
+                MountedKey::CodeId => "code_ids",
+            };
+            mounted.chains[&self.chain_data.chain_id][key]
+                .get(contract_id)
+                .filter(|value| !value.is_null())
+                .cloned()
+        })
+    }
+
+    fn chain_json(&self) -> Result<Value, DaemonError> {
+        Ok(match &self.json_state {
+            DaemonStateFile::ReadOnly { path } => {
+                let j = crate::json_lock::read(path)?;
+                patch_state_if_old(j)[&self.chain_data.chain_id].clone()
+            }
+            DaemonStateFile::FullAccess { json_file_state } => json_file_state
+                .lock()
+                .unwrap()
+                .get(&self.chain_data.chain_id)
+                .clone(),
+        })
+    }
+}
+
+/// Resolves the short GitHub paths and the GitHub file URLs of [`DaemonState::read_remote`] to raw file URLs
+fn remote_state_url(url_or_github: &str) -> Result<String, DaemonError> {
+    if let Some(path) = url_or_github.strip_prefix("github:") {
+        let mut parts = path.splitn(3, '/');
+        let (Some(owner), Some(repo), Some(file)) = (parts.next(), parts.next(), parts.next())
+        else {
+            return Err(DaemonError::StdErr(format!(
+                "Invalid GitHub state path {url_or_github}, expected github:<owner>/<repo>[@<ref>]/<path>"
+            )));
+        };
+        let (repo, git_ref) = repo.split_once('@').unwrap_or((repo, "HEAD"));
+        return Ok(format!(
+            "https://raw.githubusercontent.com/{owner}/{repo}/{git_ref}/{file}"
+        ));
+    }
+    if let Some(path) = url_or_github.strip_prefix("https://github.com/") {
+        if let Some((repo, file)) = path.split_once("/blob/") {
+            return Ok(format!("https://raw.githubusercontent.com/{repo}/{file}"));
+        }
+    }
+    Ok(url_or_github.to_string())
+}
+
+#[derive(Clone, Copy)]
+enum MountedKey {
+    Address,
+    CodeId,
+}
+
 impl StateInterface for DaemonState {
     /// Read address for contract in deployment id from state file
     fn get_address(&self, contract_id: &str) -> Result<Addr, CwEnvError> {
@@ -265,9 +492,15 @@ impl StateInterface for DaemonState {
             .get(&self.deployment_id)
             .ok()
             .and_then(|v| v.get(contract_id).cloned())
-            .ok_or_else(|| CwEnvError::AddrNotInStore(contract_id.to_owned()))?
-            .clone();
-        Ok(Addr::unchecked(value.as_str().unwrap()))
+            .filter(|v| !v.is_null())
+            .or_else(|| self.get_mounted(MountedKey::Address, contract_id))
+            .ok_or_else(|| CwEnvError::AddrNotInStore(contract_id.to_owned()))?;
+        let address = value.as_str().ok_or_else(|| {
+            DaemonError::StdErr(format!(
+                "Address of {contract_id} in state is not a string: {value}"
+            ))
+        })?;
+        Ok(Addr::unchecked(address))
     }
 
     /// Set address for contract in deployment id in state file
@@ -288,9 +521,15 @@ impl StateInterface for DaemonState {
             .get("code_ids")
             .ok()
             .and_then(|v| v.get(contract_id).cloned())
-            .ok_or_else(|| CwEnvError::CodeIdNotInStore(contract_id.to_owned()))?
-            .clone();
-        Ok(value.as_u64().unwrap())
+            .filter(|v| !v.is_null())
+            .or_else(|| self.get_mounted(MountedKey::CodeId, contract_id))
+            .ok_or_else(|| CwEnvError::CodeIdNotInStore(contract_id.to_owned()))?;
+        let code_id = value.as_u64().ok_or_else(|| {
+            DaemonError::StdErr(format!(
+                "Code id of {contract_id} in state is not a number: {value}"
+            ))
+        })?;
+        Ok(code_id)
     }
 
     /// Set the locally-saved version of the contract's latest version on this network
@@ -321,6 +560,33 @@ impl StateInterface for DaemonState {
         }
         Ok(store)
     }
+
+    fn get_admin(&self, contract_id: &str) -> Option<Addr> {
+        self.get("admins")
+            .ok()
+            .and_then(|v| v.get(contract_id).cloned())
+            .and_then(|v| v.as_str().map(Addr::unchecked))
+    }
+
+    fn set_admin(&mut self, contract_id: &str, admin: Option<&Addr>) {
+        let written = match admin {
+            Some(admin) => self.set("admins", contract_id, admin.as_str()),
+            None => self.remove("admins", contract_id),
+        };
+        // The admin is only tracked locally, so admin updates still go through on read-only states
+        match written {
+            Ok(()) => {}
+            Err(DaemonError::StateReadOnly(path)) => log::warn!(
+                target: &local_target(),
+                "Not tracking the admin of {contract_id}, the state {path} is read-only"
+            ),
+            Err(err) => panic!("{err}"),
+        }
+    }
+
+    fn deployment_id(&self) -> String {
+        self.deployment_id.clone()
+    }
 }
 
 pub trait DeployedChains<Chain: CwEnv>: cw_orch_core::contract::Deploy<Chain> {
@@ -437,10 +703,53 @@ mod tempstate {
 
 #[cfg(test)]
 pub mod test {
-    use std::env;
+    use std::{env, sync::Arc};
+
+    use cosmwasm_std::Addr;
+    use cw_orch_core::environment::{ChainInfoOwned, StateInterface};
+    use cw_orch_networks::networks;
 
+    use super::remote_state_url;
     use crate::{env::STATE_FILE_ENV_NAME, DaemonState};
 
+    #[test]
+    fn read_only_state_skips_the_admin() -> anyhow::Result<()> {
+        let chain_info: Arc<ChainInfoOwned> = Arc::new(networks::JUNO_1.into());
+        let mut state = DaemonState::new(
+            "/nonexistent/state.json".to_string(),
+            &chain_info,
+            "default".to_string(),
+            true,
+            false,
+        )?;
+        state.set_admin("contract", Some(&Addr::unchecked("admin")));
+        state.set_admin("contract", None);
+        assert_eq!(state.get_admin("contract"), None);
+        Ok(())
+    }
+
+    #[test]
+    fn remote_state_urls() -> anyhow::Result<()> {
+        assert_eq!(
+            remote_state_url("github:AbstractSDK/abstract@v0.23.0/scripts/state.json")?,
+            "https://raw.githubusercontent.com/AbstractSDK/abstract/v0.23.0/scripts/state.json"
+        );
+        assert_eq!(
+            remote_state_url("github:AbstractSDK/abstract/state.json")?,
+            "https://raw.githubusercontent.com/AbstractSDK/abstract/HEAD/state.json"
+        );
+        assert_eq!(
+            remote_state_url("https://github.com/AbstractSDK/abstract/blob/main/state.json")?,
+            "https://raw.githubusercontent.com/AbstractSDK/abstract/main/state.json"
+        );
+        assert_eq!(
+            remote_state_url("https://example.com/state.json")?,
+            "https://example.com/state.json"
+        );
+        assert!(remote_state_url("github:AbstractSDK/state.json").is_err());
+        Ok(())
+    }
+
     #[test]
     #[serial_test::serial]
     fn test_env_variable_state_path() -> anyhow::Result<()> {
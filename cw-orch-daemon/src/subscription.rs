@@ -0,0 +1,162 @@
+//! Block and transaction subscriptions over the Tendermint RPC websocket.
+//!
+//! Subscriptions push new blocks and transactions as they are committed, instead of polling the node.
+//! The chain info doesn't hold the RPC endpoint, so it needs to be provided when connecting.
+//!
+//! ```rust,no_run
+//! # tokio_test::block_on(async {
+//! use cosmwasm_std::Addr;
+//! use cw_orch_daemon::subscription::Subscriptions;
+//! use futures_util::StreamExt;
+//!
+//! let subscriptions = Subscriptions::connect("ws://localhost:26657/websocket")
+//!     .await
+//!     .unwrap();
+//! let mut events = subscriptions
+//!     .subscribe_contract_events(&Addr::unchecked("juno1..."))
+//!     .await
+//!     .unwrap();
+//! while let Some(tx) = events.next().await {
+//!     println!("{:?}", tx.unwrap().events);
+//! }
+//! # })
+//! ```
+use cosmrs::tendermint::{abci, Block};
+use cosmwasm_std::Addr;
+use futures_util::{future, Stream, StreamExt};
+use tendermint_rpc::{
+    event::{Event, EventData},
+    SubscriptionClient, WebSocketClient,
+};
+use tokio::task::JoinHandle;
+
+pub use tendermint_rpc::query::{EventType, Query};
+
+use crate::DaemonError;
+
+/// Transaction pushed by a subscription
+#[derive(Clone, Debug)]
+pub struct TxEvent {
+    /// Height of the block including the transaction
+    pub height: u64,
+    /// Hash of the transaction, if provided by the node
+    pub hash: Option<String>,
+    /// Raw transaction bytes
+    pub tx: Vec<u8>,
+    /// Events emitted by the transaction
+    pub events: Vec<cosmwasm_std::Event>,
+}
+
+/// Websocket connection to the Tendermint RPC of a node.
+/// The connection is closed when dropped.
+pub struct Subscriptions {
+    client: WebSocketClient,
+    driver: JoinHandle<Result<(), tendermint_rpc::Error>>,
+}
+
+impl Subscriptions {
+    /// Connects to the websocket endpoint of a node, i.e. `ws://localhost:26657/websocket`
+    pub async fn connect(rpc_url: &str) -> Result<Self, DaemonError> {
+        let (client, driver) = WebSocketClient::new(rpc_url).await?;
+        let driver = tokio::spawn(async move { driver.run().await });
+        Ok(Self { client, driver })
+    }
+
+    /// Streams the new blocks
+    pub async fn subscribe_blocks(
+        &self,
+    ) -> Result<impl Stream<Item = Result<Block, DaemonError>> + Send, DaemonError> {
+        let subscription = self.client.subscribe(EventType::NewBlock.into()).await?;
+        Ok(subscription.filter_map(|event| {
+            future::ready(match event {
+                Ok(Event {
+                    data: EventData::NewBlock { block, .. },
+                    ..
+                })
+                | Ok(Event {
+                    data: EventData::LegacyNewBlock { block, .. },
+                    ..
+                }) => block.map(|block| Ok(*block)),
+                Ok(_) => None,
+                Err(e) => Some(Err(e.into())),
+            })
+        }))
+    }
+
+    /// Streams the transactions matching `query`.
+    /// The query should target transactions, i.e. `Query::from(EventType::Tx).and_eq("message.sender", "juno1...")`
+    pub async fn subscribe_txs(
+        &self,
+        query: Query,
+    ) -> Result<impl Stream<Item = Result<TxEvent, DaemonError>> + Send, DaemonError> {
+        let subscription = self.client.subscribe(query).await?;
+        Ok(subscription.filter_map(|event| {
+            future::ready(match event {
+                Ok(event) => tx_event(event).map(Ok),
+                Err(e) => Some(Err(e.into())),
+            })
+        }))
+    }
+
+    /// Streams the transactions that executed `contract`.
+    /// Only the events emitted by the contract are kept.
+    pub async fn subscribe_contract_events(
+        &self,
+        contract: &Addr,
+    ) -> Result<impl Stream<Item = Result<TxEvent, DaemonError>> + Send, DaemonError> {
+        let query = Query::from(EventType::Tx).and_eq("wasm._contract_address", contract.as_str());
+        let contract = contract.to_string();
+        let txs = self.subscribe_txs(query).await?;
+        Ok(txs.map(move |tx| {
+            tx.map(|mut tx| {
+                tx.events.retain(|event| {
+                    event
+                        .attributes
+                        .iter()
+                        .any(|attr| attr.key == "_contract_address" && attr.value == contract)
+                });
+                tx
+            })
+        }))
+    }
+}
+
+impl Drop for Subscriptions {
+    fn drop(&mut self) {
+        // Closing the client stops the driver
+        if self.client.clone().close().is_err() {
+            self.driver.abort();
+        }
+    }
+}
+
+fn tx_event(event: Event) -> Option<TxEvent> {
+    let EventData::Tx { tx_result } = event.data else {
+        return None;
+    };
+    let hash = event
+        .events
+        .and_then(|events| events.get("tx.hash")?.first().cloned());
+    Some(TxEvent {
+        height: tx_result.height as u64,
+        hash,
+        tx: tx_result.tx,
+        events: tx_result
+            .result
+            .events
+            .iter()
+            .map(to_cosmwasm_event)
+            .collect(),
+    })
+}
+
+fn to_cosmwasm_event(event: &abci::Event) -> cosmwasm_std::Event {
+    cosmwasm_std::Event::new(&event.kind).add_attributes(event.attributes.iter().filter_map(
+        |attr| {
+            Some((
+                attr.key_str().ok()?.to_string(),
+                attr.value_str().ok()?.to_string(),
+            ))
+        },
+    ))
+}
@@ -1,7 +1,16 @@
-use crate::senders::builder::SenderBuilder;
+use std::sync::Arc;
 
-use crate::{DaemonAsyncBuilder, DaemonBase, DaemonState, Wallet, RUNTIME};
-use cw_orch_core::environment::ChainInfoOwned;
+use crate::queriers::QueryCache;
+use crate::senders::{builder::SenderBuilder, SecretProvider, TxGuard, TxSigner};
+
+use crate::{
+    profile::Profile, DaemonAsyncBuilder, DaemonBase, DaemonState, MemoTemplate, PollingConfig,
+    RateLimits, Wallet, RUNTIME,
+};
+use cw_orch_core::{
+    contract::UploadPrecheck,
+    environment::{ChainInfoOwned, ChainKind},
+};
 
 use super::super::error::DaemonError;
 
@@ -32,6 +41,14 @@ pub struct DaemonBuilder {
     pub(crate) load_network: bool,
 
     pub(crate) mnemonic: Option<String>,
+    pub(crate) secret_provider: Option<Arc<dyn SecretProvider>>,
+    pub(crate) signer: Option<Arc<dyn TxSigner>>,
+    pub(crate) polling: Option<PollingConfig>,
+    pub(crate) tx_guard: Option<TxGuard>,
+    pub(crate) rate_limits: Option<RateLimits>,
+    pub(crate) query_cache: Option<QueryCache>,
+    pub(crate) memo: Option<MemoTemplate>,
+    pub(crate) upload_precheck: Option<UploadPrecheck>,
 }
 
 impl DaemonBuilder {
@@ -44,6 +61,14 @@ impl DaemonBuilder {
             state: None,
             write_on_change: None,
             mnemonic: None,
+            secret_provider: None,
+            signer: None,
+            polling: None,
+            tx_guard: None,
+            rate_limits: None,
+            query_cache: None,
+            memo: None,
+            upload_precheck: None,
             is_test: false,
             load_network: true,
         }
@@ -67,6 +92,19 @@ impl DaemonBuilder {
         self
     }
 
+    /// Creates a builder from the profile `name` of the `cw-orch.toml` file of the project.
+    /// The builder can still be customized afterwards.
+    /// See [`Profile`] for the format of the file.
+    pub fn from_profile(name: &str) -> Result<Self, DaemonError> {
+        DaemonAsyncBuilder::from_profile(name).map(Into::into)
+    }
+
+    /// Creates a builder from an already loaded profile.
+    /// The artifacts dir of the profile is not applied, see [`Profile::artifacts_dir`].
+    pub fn with_profile(profile: &Profile) -> Result<Self, DaemonError> {
+        DaemonAsyncBuilder::with_profile(profile).map(Into::into)
+    }
+
     /// Set the deployment id to use for the Daemon interactions
     /// Defaults to `default`
     pub fn deployment_id(&mut self, deployment_id: impl Into<String>) -> &mut Self {
@@ -86,6 +124,70 @@ impl DaemonBuilder {
         self
     }
 
+    /// Set the source of the mnemonic used for the default Cosmos wallet
+    /// Ignored if a mnemonic is set with [`Self::mnemonic`]
+    pub fn secret_provider(&mut self, provider: impl SecretProvider + 'static) -> &mut Self {
+        self.secret_provider = Some(Arc::new(provider));
+        self
+    }
+
+    /// Set a custom signer of the transactions of the default Cosmos wallet (KMS, remote signer...)
+    /// Takes precedence over the mnemonic and the secret provider
+    pub fn signer(&mut self, signer: impl TxSigner + 'static) -> &mut Self {
+        self.signer = Some(Arc::new(signer));
+        self
+    }
+
+    /// Set the timings of the wait loops of the daemon and of its default wallet
+    /// Defaults to [`PollingConfig::default`]
+    pub fn polling(&mut self, polling: PollingConfig) -> &mut Self {
+        self.polling = Some(polling);
+        self
+    }
+
+    /// Require a confirmation before the default wallet broadcasts transactions on chains of `kind`
+    /// Defaults to the `CW_ORCH_CONFIRM_TXS_ON` env variable
+    pub fn confirm_txs_on(&mut self, kind: ChainKind) -> &mut Self {
+        let guard = self.tx_guard.take().unwrap_or_default();
+        self.tx_guard = Some(guard.confirm_on(kind));
+        self
+    }
+
+    /// Set the confirmation required before the default wallet broadcasts transactions, with its allowlist
+    /// See [`TxGuard`] for more details
+    pub fn tx_guard(&mut self, guard: TxGuard) -> &mut Self {
+        self.tx_guard = Some(guard);
+        self
+    }
+
+    /// Cache the expensive queries (code hashes, contract infos, chain params) of the daemon and its queriers
+    /// See [`QueryCache`] for more details
+    pub fn query_cache(&mut self, cache: QueryCache) -> &mut Self {
+        self.query_cache = Some(cache);
+        self
+    }
+
+    /// Limit the requests sent to the gRPC endpoints by the default wallet
+    /// Defaults to the `CW_ORCH_GRPC_RATE_LIMIT` env variable
+    pub fn rate_limits(&mut self, rate_limits: RateLimits) -> &mut Self {
+        self.rate_limits = Some(rate_limits);
+        self
+    }
+
+    /// Attach a memo rendered from `memo` to all the transactions of the daemon
+    /// See [`MemoTemplate`] for the available placeholders
+    pub fn memo(&mut self, memo: MemoTemplate) -> &mut Self {
+        self.memo = Some(memo);
+        self
+    }
+
+    /// Check the wasm files with `precheck` before uploading them
+    /// No check is done by default
+    pub fn upload_precheck(&mut self, precheck: UploadPrecheck) -> &mut Self {
+        self.upload_precheck = Some(precheck);
+        self
+    }
+
     /// Overwrites the gas denom used for broadcasting transactions.
     /// Behavior :
     /// - If no gas denom is provided, the first gas denom specified in the `self.chain` is used
@@ -175,13 +277,36 @@ impl DaemonBuilder {
     /// Defaults to env variable.
     ///
     /// Variable: STATE_FILE_ENV_NAME.
-    #[allow(unused)]
     pub(crate) fn state_path(&mut self, path: impl ToString) -> &mut Self {
         self.state_path = Some(path.to_string());
         self
     }
 }
 
+impl From<DaemonAsyncBuilder> for DaemonBuilder {
+    fn from(value: DaemonAsyncBuilder) -> Self {
+        DaemonBuilder {
+            chain: value.chain,
+            handle: None,
+            deployment_id: value.deployment_id,
+            state: value.state,
+            state_path: value.state_path,
+            write_on_change: value.write_on_change,
+            mnemonic: value.mnemonic,
+            secret_provider: value.secret_provider,
+            signer: value.signer,
+            polling: value.polling,
+            tx_guard: value.tx_guard,
+            rate_limits: value.rate_limits,
+            query_cache: value.query_cache,
+            memo: value.memo,
+            upload_precheck: value.upload_precheck,
+            is_test: value.is_test,
+            load_network: value.load_network,
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use cw_orch_core::environment::TxHandler;
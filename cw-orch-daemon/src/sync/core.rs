@@ -2,14 +2,19 @@ use std::{fmt::Debug, ops::DerefMut};
 
 use super::super::senders::Wallet;
 use crate::{
-    queriers::{Bank, CosmWasmBase, Node},
-    senders::{builder::SenderBuilder, query::QuerySender},
-    CosmTxResponse, DaemonAsyncBase, DaemonBuilder, DaemonError, DaemonState,
+    queriers::{Bank, ChainParams, CosmWasmBase, Node, QueryCache, VestingPeriod},
+    senders::{builder::SenderBuilder, query::QuerySender, sign::Signer},
+    ConcurrentUploadable, ContractLockPolicy, CosmTxResponse, DaemonAsyncBase, DaemonBuilder,
+    DaemonError, DaemonState, EndpointStatus, MemoTemplate, PollingConfig,
 };
 use cosmwasm_std::{Addr, Coin};
 use cw_orch_core::{
-    contract::{interface_traits::Uploadable, WasmPath},
-    environment::{ChainInfoOwned, ChainState, DefaultQueriers, QueryHandler, TxHandler},
+    contract::{interface_traits::Uploadable, UploadPrecheck, WasmPath},
+    environment::{
+        AccessConfig, ChainInfoOwned, ChainState, DefaultQueriers, IndexResponse,
+        InstantiatedContract, QueryHandler, TxContext, TxHandler,
+    },
+    CoreEnvVars, CwEnvError,
 };
 use cw_orch_traits::stargate::Stargate;
 use serde::Serialize;
@@ -82,6 +87,88 @@ impl<Sender> DaemonBase<Sender> {
         }
     }
 
+    /// Sets how concurrent executes on the same contract are handled.
+    /// The locks are shared by all the daemons of the process.
+    pub fn set_contract_lock_policy(&mut self, policy: ContractLockPolicy) {
+        self.daemon.set_contract_lock_policy(policy)
+    }
+
+    /// Timings of the wait loops of the daemon
+    pub fn polling(&self) -> &PollingConfig {
+        self.daemon.polling()
+    }
+
+    /// Sets the timings of the wait loops of the daemon.
+    /// The sender keeps the timings it was built with for its own tx polling.
+    pub fn set_polling(&mut self, polling: PollingConfig) {
+        self.daemon.set_polling(polling)
+    }
+
+    /// Cache of the expensive queries, if enabled
+    pub fn query_cache(&self) -> Option<&QueryCache> {
+        self.daemon.query_cache()
+    }
+
+    /// Template of the memo attached to the transactions of the daemon, if any
+    pub fn memo(&self) -> Option<&MemoTemplate> {
+        self.daemon.memo()
+    }
+
+    /// Sets the template of the memo attached to the transactions of the daemon.
+    /// See [`MemoTemplate`] for the available placeholders.
+    pub fn set_memo(&mut self, memo: MemoTemplate) {
+        self.daemon.set_memo(memo)
+    }
+
+    /// Checks run on the wasm files before uploading them, if any
+    pub fn upload_precheck(&self) -> Option<&UploadPrecheck> {
+        self.daemon.upload_precheck()
+    }
+
+    /// Sets the checks run on the wasm files before uploading them
+    pub fn set_upload_precheck(&mut self, precheck: UploadPrecheck) {
+        self.daemon.set_upload_precheck(precheck)
+    }
+
+    /// Copy of the daemon attaching `memo` to its transactions instead of its memo template.
+    /// Used to override the memo of a single call.
+    pub fn with_memo(&self, memo: impl Into<String>) -> Self
+    where
+        Sender: Clone,
+    {
+        Self {
+            daemon: self.daemon.with_memo(memo),
+            rt_handle: self.rt_handle.clone(),
+        }
+    }
+
+    /// Registers a hook fired after every instantiation, to register the contracts it created in the state.
+    /// See [`InstantiateHooks`](cw_orch_core::environment::InstantiateHooks) for an example.
+    pub fn add_instantiate_hook(
+        &mut self,
+        hook: impl Fn(&[InstantiatedContract]) -> Vec<(String, Addr)> + Send + Sync + 'static,
+    ) {
+        self.daemon.add_instantiate_hook(hook)
+    }
+
+    /// Registers a hook run before every transaction is broadcast. Returning an error aborts the transaction.
+    /// See [`TxHooks`](cw_orch_core::environment::TxHooks) for an example.
+    pub fn add_pre_broadcast_hook(
+        &mut self,
+        hook: impl Fn(&TxContext) -> Result<(), CwEnvError> + Send + Sync + 'static,
+    ) {
+        self.daemon.add_pre_broadcast_hook(hook)
+    }
+
+    /// Registers a hook run after every successful transaction, with its events.
+    /// See [`TxHooks`](cw_orch_core::environment::TxHooks) for an example.
+    pub fn add_post_response_hook(
+        &mut self,
+        hook: impl Fn(&TxContext) -> Result<(), CwEnvError> + Send + Sync + 'static,
+    ) {
+        self.daemon.add_post_response_hook(hook)
+    }
+
     /// Flushes all the state related to the current chain
     /// Only works on Local networks
     pub fn flush_state(&mut self) -> Result<(), DaemonError> {
@@ -92,6 +179,12 @@ impl<Sender> DaemonBase<Sender> {
     pub fn chain_info(&self) -> &ChainInfoOwned {
         self.daemon.chain_info()
     }
+
+    /// Probes every gRPC endpoint of the chain for liveness and chain-id match.
+    /// The daemon connects to the healthy endpoint with the lowest latency.
+    pub fn endpoint_status(&self) -> Vec<EndpointStatus> {
+        self.rt_handle.block_on(self.daemon.endpoint_status())
+    }
 }
 
 impl<Sender: QuerySender> DaemonBase<Sender> {
@@ -100,6 +193,12 @@ impl<Sender: QuerySender> DaemonBase<Sender> {
         self.daemon.sender().channel()
     }
 
+    /// Get the key parameters of the staking, gov, slashing and wasm modules.
+    /// See [`ChainParams`].
+    pub fn chain_params(&self) -> Result<ChainParams, DaemonError> {
+        self.rt_handle.block_on(self.daemon.chain_params())
+    }
+
     /// Returns a new [`DaemonBuilder`] with the current configuration.
     /// **Does not copy the `Sender`**
     /// Does not consume the original [`Daemon`].
@@ -112,6 +211,14 @@ impl<Sender: QuerySender> DaemonBase<Sender> {
             write_on_change: None,
             handle: Some(self.rt_handle.clone()),
             mnemonic: None,
+            secret_provider: None,
+            signer: None,
+            polling: Some(self.daemon.polling.clone()),
+            tx_guard: self.daemon.tx_guard.clone(),
+            rate_limits: self.daemon.rate_limits.clone(),
+            query_cache: self.daemon.query_cache.clone(),
+            memo: self.daemon.memo.clone(),
+            upload_precheck: self.daemon.upload_precheck.clone(),
             // If it was test it will just use same tempfile as state
             is_test: false,
             // Uses same ChainInfo
@@ -120,6 +227,134 @@ impl<Sender: QuerySender> DaemonBase<Sender> {
     }
 }
 
+impl<Sender: Signer> DaemonBase<Sender> {
+    /// Uploads the contracts at once, each in its own transaction with a distinct sequence number,
+    /// then waits for all the uploads to be confirmed and stores their code ids.
+    /// In dry-run mode, the uploads are only simulated and no code id is stored.
+    /// Useful in [`Deploy::deploy_on`](cw_orch_core::contract::Deploy::deploy_on) to speed up the upload of independent contracts.
+    ///
+    /// ```rust,no_run
+    /// # use cw_orch_daemon::{networks, Daemon, ConcurrentUploadable};
+    /// # use counter_contract::CounterContract;
+    /// let daemon = Daemon::builder(networks::LOCAL_JUNO).build().unwrap();
+    /// let first = CounterContract::new("first", daemon.clone());
+    /// let second = CounterContract::new("second", daemon.clone());
+    /// daemon.upload_concurrently(&[&first, &second]).unwrap();
+    /// ```
+    pub fn upload_concurrently(
+        &self,
+        contracts: &[&dyn ConcurrentUploadable<Self>],
+    ) -> Result<Vec<CosmTxResponse>, DaemonError> {
+        let wasm_paths = contracts
+            .iter()
+            .map(|contract| contract.wasm_path(self.chain_info()))
+            .collect();
+        let responses = self
+            .rt_handle
+            .block_on(self.daemon.upload_concurrently(wasm_paths))?;
+        if CoreEnvVars::dry_run() {
+            return Ok(responses);
+        }
+        for (contract, response) in contracts.iter().zip(&responses) {
+            contract.set_code_id(response.uploaded_code_id()?);
+        }
+        Ok(responses)
+    }
+}
+
+// Code permissioning, see the async versions on [`DaemonAsyncBase`].
+impl<Sender: TxSender> DaemonBase<Sender> {
+    /// Submits a governance proposal pinning `code_ids` in the wasm cache of the nodes
+    pub fn propose_pin_codes(
+        &self,
+        code_ids: Vec<u64>,
+        title: &str,
+        deposit: &[Coin],
+    ) -> Result<CosmTxResponse, DaemonError> {
+        self.rt_handle
+            .block_on(self.daemon.propose_pin_codes(code_ids, title, deposit))
+    }
+
+    /// Submits a governance proposal removing `code_ids` from the wasm cache of the nodes
+    pub fn propose_unpin_codes(
+        &self,
+        code_ids: Vec<u64>,
+        title: &str,
+        deposit: &[Coin],
+    ) -> Result<CosmTxResponse, DaemonError> {
+        self.rt_handle
+            .block_on(self.daemon.propose_unpin_codes(code_ids, title, deposit))
+    }
+
+    /// Submits a governance proposal calling the `sudo` entry point of a contract
+    pub fn propose_sudo<S: Serialize + Debug>(
+        &self,
+        sudo_msg: &S,
+        contract_address: &Addr,
+        title: &str,
+        deposit: &[Coin],
+    ) -> Result<CosmTxResponse, DaemonError> {
+        self.rt_handle.block_on(self.daemon.propose_sudo(
+            sudo_msg,
+            contract_address,
+            title,
+            deposit,
+        ))
+    }
+
+    /// Changes who can instantiate the code `code_id`, only allowed to the creator of the code
+    pub fn update_instantiate_config(
+        &self,
+        code_id: u64,
+        access: AccessConfig,
+    ) -> Result<CosmTxResponse, DaemonError> {
+        self.rt_handle
+            .block_on(self.daemon.update_instantiate_config(code_id, access))
+    }
+}
+
+// Vesting accounts, see the async versions on [`DaemonAsyncBase`].
+impl<Sender: TxSender> DaemonBase<Sender> {
+    /// Creates a vesting account for `recipient`, unlocking `amount` linearly from the block time until `end_time` (unix seconds)
+    pub fn create_continuous_vesting_account(
+        &self,
+        recipient: &Addr,
+        amount: &[Coin],
+        end_time: i64,
+    ) -> Result<CosmTxResponse, DaemonError> {
+        self.rt_handle.block_on(
+            self.daemon
+                .create_continuous_vesting_account(recipient, amount, end_time),
+        )
+    }
+
+    /// Creates a vesting account for `recipient`, unlocking `amount` all at once at `end_time` (unix seconds)
+    pub fn create_delayed_vesting_account(
+        &self,
+        recipient: &Addr,
+        amount: &[Coin],
+        end_time: i64,
+    ) -> Result<CosmTxResponse, DaemonError> {
+        self.rt_handle.block_on(
+            self.daemon
+                .create_delayed_vesting_account(recipient, amount, end_time),
+        )
+    }
+
+    /// Creates a vesting account for `recipient`, unlocking the amount of each period at its end
+    pub fn create_periodic_vesting_account(
+        &self,
+        recipient: &Addr,
+        start_time: i64,
+        periods: &[VestingPeriod],
+    ) -> Result<CosmTxResponse, DaemonError> {
+        self.rt_handle.block_on(
+            self.daemon
+                .create_periodic_vesting_account(recipient, start_time, periods),
+        )
+    }
+}
+
 // Helpers for Daemon with [`Wallet`] sender.
 impl Daemon {
     #[deprecated = "Use `self.sender_mut().set_authz_granter(granter)` or change the sender builder options instead"]
@@ -243,9 +478,30 @@ impl<Sender: TxSender> TxHandler for DaemonBase<Sender> {
         amount: &[cosmwasm_std::Coin],
     ) -> Result<Self::Response, Self::Error> {
         self.rt_handle
-            .block_on(self.sender().bank_send(receiver, amount))
-            .map_err(Into::into)
-            .map(Into::into)
+            .block_on(self.daemon.bank_send(receiver, amount))
+    }
+
+    fn update_admin(
+        &self,
+        contract_address: &Addr,
+        new_admin: &Addr,
+    ) -> Result<Self::Response, Self::Error> {
+        self.rt_handle
+            .block_on(self.daemon.update_admin(contract_address, new_admin))
+    }
+
+    fn clear_admin(&self, contract_address: &Addr) -> Result<Self::Response, Self::Error> {
+        self.rt_handle
+            .block_on(self.daemon.clear_admin(contract_address))
+    }
+
+    fn sudo<S: Serialize + Debug>(
+        &self,
+        sudo_msg: &S,
+        contract_address: &Addr,
+    ) -> Result<Self::Response, Self::Error> {
+        self.rt_handle
+            .block_on(self.daemon.sudo(sudo_msg, contract_address))
     }
 }
 
@@ -264,7 +520,9 @@ impl<Sender: TxSender> Stargate for DaemonBase<Sender> {
                             value: msg.value.clone(),
                         })
                         .collect(),
-                    memo,
+                    memo.map(str::to_string)
+                        .or_else(|| self.daemon.render_memo("commit_any"))
+                        .as_deref(),
                 ),
             )
             .map_err(Into::into)
@@ -0,0 +1,147 @@
+//! Multiple funded accounts for daemon-based tests.
+//!
+//! Accounts are derived from the mnemonic of a faucet [`Daemon`] on the hd indexes following the faucet's.
+//! When the faucet signs with a raw key or a custom signer, the mnemonic to derive them from is passed explicitly.
+//! They are funded by the faucet in a single transaction and are deterministic across runs.
+use std::str::FromStr;
+
+use cosmrs::{bank::MsgSend, AccountId};
+use cosmwasm_std::{Addr, Coin};
+use cw_orch_core::environment::TxHandler;
+
+use crate::{parse_cw_coins, senders::tx::TxSender, CosmosOptions, Daemon, DaemonError};
+
+/// A set of accounts derived from a faucet mnemonic and funded by the faucet.
+///
+/// ```rust,no_run
+/// use cw_orch_daemon::{networks::LOCAL_JUNO, Daemon, TestAccounts};
+/// use cosmwasm_std::coins;
+///
+/// let faucet = Daemon::builder(LOCAL_JUNO).build().unwrap();
+/// let accounts = TestAccounts::generate_named(&["alice", "bob"], &faucet, &coins(1_000_000, "ujuno")).unwrap();
+///
+/// let alice: &Daemon = accounts.by_name("alice").unwrap();
+/// let bob: &Daemon = accounts.get(1).unwrap();
+/// ```
+#[derive(Clone)]
+pub struct TestAccounts {
+    accounts: Vec<(String, Daemon)>,
+}
+
+impl TestAccounts {
+    /// Derives `n` accounts from the faucet mnemonic and funds each of them with `funds`.
+    /// Accounts are named by their index (`"0"`, `"1"`, ...).
+    pub fn generate(n: u32, faucet: &Daemon, funds: &[Coin]) -> Result<Self, DaemonError> {
+        let names: Vec<String> = (0..n).map(|i| i.to_string()).collect();
+        Self::generate_named(&names, faucet, funds)
+    }
+
+    /// Derives one account per name from the faucet mnemonic and funds each of them with `funds`.
+    ///
+    /// Errors with [`DaemonError::MissingPhrase`] if the faucet doesn't sign with a mnemonic (raw keys and custom signers),
+    /// use [`TestAccounts::generate_named_from_mnemonic`] for those.
+    pub fn generate_named(
+        names: &[impl AsRef<str>],
+        faucet: &Daemon,
+        funds: &[Coin],
+    ) -> Result<Self, DaemonError> {
+        let mnemonic = faucet
+            .sender()
+            .private_key()
+            .and_then(|pk| pk.words())
+            .ok_or(DaemonError::MissingPhrase)?;
+        Self::generate_named_from_mnemonic(&mnemonic, names, faucet, funds)
+    }
+
+    /// Derives one account per name from `mnemonic` and funds each of them with `funds` from the faucet.
+    /// The hd indexes still follow the faucet's, so passing the mnemonic of the faucet never derives the faucet itself.
+    pub fn generate_named_from_mnemonic(
+        mnemonic: &str,
+        names: &[impl AsRef<str>],
+        faucet: &Daemon,
+        funds: &[Coin],
+    ) -> Result<Self, DaemonError> {
+        let first_index = faucet.sender().options().hd_index.unwrap_or(0) + 1;
+
+        let accounts = names
+            .iter()
+            .zip(first_index..)
+            .map(|(name, hd_index)| {
+                let mut account = faucet.clone();
+                let sender = account.sender_mut();
+                sender.options = CosmosOptions::default()
+                    .mnemonic(mnemonic)
+                    .hd_index(hd_index);
+                sender.set_mnemonic(mnemonic)?;
+                Ok((name.as_ref().to_string(), account))
+            })
+            .collect::<Result<Vec<_>, DaemonError>>()?;
+
+        let test_accounts = Self { accounts };
+        if !funds.is_empty() && !test_accounts.accounts.is_empty() {
+            test_accounts.fund(faucet, funds)?;
+        }
+        Ok(test_accounts)
+    }
+
+    /// Sends `funds` from the faucet to every account, in a single transaction
+    pub fn fund(&self, faucet: &Daemon, funds: &[Coin]) -> Result<(), DaemonError> {
+        let from_address = faucet.sender().msg_sender()?;
+        let msgs = self
+            .addresses()
+            .iter()
+            .map(|addr| {
+                Ok(MsgSend {
+                    from_address: from_address.clone(),
+                    to_address: AccountId::from_str(addr.as_str())?,
+                    amount: parse_cw_coins(funds)?,
+                })
+            })
+            .collect::<Result<Vec<_>, DaemonError>>()?;
+
+        faucet.rt_handle.block_on(
+            faucet
+                .sender()
+                .commit_tx(msgs, Some("funding test accounts")),
+        )?;
+        Ok(())
+    }
+
+    /// Returns the account at `index`
+    pub fn get(&self, index: usize) -> Option<&Daemon> {
+        self.accounts.get(index).map(|(_, daemon)| daemon)
+    }
+
+    /// Returns the account named `name`
+    pub fn by_name(&self, name: &str) -> Option<&Daemon> {
+        self.accounts
+            .iter()
+            .find(|(account_name, _)| account_name == name)
+            .map(|(_, daemon)| daemon)
+    }
+
+    /// Returns the addresses of all the accounts, in order
+    pub fn addresses(&self) -> Vec<Addr> {
+        self.accounts
+            .iter()
+            .map(|(_, daemon)| daemon.sender_addr())
+            .collect()
+    }
+
+    /// Iterates over the accounts and their names
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &Daemon)> {
+        self.accounts
+            .iter()
+            .map(|(name, daemon)| (name.as_str(), daemon))
+    }
+
+    /// Number of accounts
+    pub fn len(&self) -> usize {
+        self.accounts.len()
+    }
+
+    /// Returns true if there are no accounts
+    pub fn is_empty(&self) -> bool {
+        self.accounts.is_empty()
+    }
+}
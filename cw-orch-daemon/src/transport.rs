@@ -0,0 +1,159 @@
+//! Fallback query transports, for the networks where the gRPC endpoints (port 9090) are unreachable,
+//! i.e. behind corporate proxies that only let HTTP/1.1 through.
+//!
+//! - [`GrpcWeb`] sends the gRPC queries wrapped in the gRPC-web protocol over HTTP/1.1, served by most public gRPC providers
+//!   and by the nodes on port 9091 (see [`grpc_web_url`]).
+//! - [`Lcd`] queries the REST (LCD) endpoint of the chain, [`ChainInfoOwned::lcd_url`].
+//!
+//! Both come from `cw-orch-web`, which also compiles to `wasm32` for browsers.
+//!
+//! [`QueryTransport::connect`] selects the first transport that reaches the chain, in this order.
+//!
+//! The fallback only applies to the queries sent through a [`QueryTransport`]: the daemon and its queriers
+//! ([`Bank`], [`CosmWasm`], [`Node`], ...) still need a gRPC connection and fail with [`DaemonError::CannotConnectGRPC`]
+//! without one, as do the transactions.
+//!
+//! ```rust,no_run
+//! # tokio_test::block_on(async {
+//! use cosmwasm_std::Addr;
+//! use cw_orch_core::environment::ChainInfoOwned;
+//! use cw_orch_daemon::{networks, transport::QueryTransport};
+//!
+//! let chain: ChainInfoOwned = networks::JUNO_1.into();
+//! let transport = QueryTransport::connect(&chain).await.unwrap();
+//! let height = transport.block_height().await.unwrap();
+//! let balance = transport
+//!     .balance(&Addr::unchecked("juno1..."), "ujuno")
+//!     .await
+//!     .unwrap();
+//! # })
+//! ```
+use cosmwasm_std::{Addr, Coin};
+use cw_orch_core::{environment::ChainInfoOwned, log::connectivity_target};
+use serde::{de::DeserializeOwned, Serialize};
+use tonic::transport::Channel;
+
+pub use cw_orch_web::{grpc_web_url, GrpcWeb, Lcd, WebQuerier};
+
+use crate::{
+    queriers::{Bank, CosmWasm, Node},
+    DaemonError, GrpcChannel,
+};
+
+/// Query transport selected by [`QueryTransport::connect`]
+#[derive(Clone)]
+pub enum QueryTransport {
+    /// Native gRPC, over HTTP/2
+    Grpc(Channel),
+    /// gRPC-web, over HTTP/1.1
+    GrpcWeb(GrpcWeb),
+    /// REST (LCD) endpoint
+    Lcd(Lcd),
+}
+
+impl QueryTransport {
+    /// Connects to the gRPC endpoints of the chain, falling back to their gRPC-web endpoints and then to the LCD endpoint.
+    /// The fallbacks are only used if they are connected to the expected chain.
+    pub async fn connect(chain_info: &ChainInfoOwned) -> Result<Self, DaemonError> {
+        match GrpcChannel::from_chain_info(chain_info).await {
+            Ok(channel) => return Ok(Self::Grpc(channel)),
+            Err(e) => log::warn!(
+                target: &connectivity_target(),
+                "gRPC endpoints unreachable ({}), trying gRPC-web and LCD",
+                e
+            ),
+        }
+        match WebQuerier::connect(chain_info).await? {
+            WebQuerier::GrpcWeb(grpc_web) => Ok(Self::GrpcWeb(grpc_web)),
+            WebQuerier::Lcd(lcd) => Ok(Self::Lcd(lcd)),
+        }
+    }
+
+    /// Height of the latest block
+    pub async fn block_height(&self) -> Result<u64, DaemonError> {
+        match self {
+            Self::Grpc(channel) => Node::new_async(channel.clone())._block_height().await,
+            Self::GrpcWeb(grpc_web) => Ok(grpc_web.block_height().await?),
+            Self::Lcd(lcd) => Ok(lcd.block_height().await?),
+        }
+    }
+
+    /// Balance of `address` in `denom`
+    pub async fn balance(&self, address: &Addr, denom: &str) -> Result<Coin, DaemonError> {
+        match self {
+            Self::Grpc(channel) => Bank::new_async(channel.clone())
+                ._balance(address, Some(denom.to_string()))
+                .await?
+                .pop()
+                .ok_or(DaemonError::StdErr(format!("No {denom} balance returned"))),
+            Self::GrpcWeb(grpc_web) => Ok(grpc_web.balance(address, denom).await?),
+            Self::Lcd(lcd) => Ok(lcd.balance(address, denom).await?),
+        }
+    }
+
+    /// Smart query of a contract
+    pub async fn smart_query<Q: Serialize, T: DeserializeOwned>(
+        &self,
+        contract: &Addr,
+        query: &Q,
+    ) -> Result<T, DaemonError> {
+        match self {
+            Self::Grpc(channel) => {
+                let response = CosmWasm::new_async(channel.clone())
+                    ._contract_state(contract, serde_json::to_vec(query)?)
+                    .await?;
+                Ok(serde_json::from_slice(&response)?)
+            }
+            Self::GrpcWeb(grpc_web) => Ok(grpc_web.smart_query(contract, query).await?),
+            Self::Lcd(lcd) => Ok(lcd.smart_query(contract, query).await?),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{Read, Write},
+        net::TcpListener,
+    };
+
+    use super::*;
+
+    /// Serves the node info of `juno-1` on a local LCD endpoint, returns its url
+    fn serve_lcd() -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let _ = stream.read(&mut [0; 4096]).unwrap();
+                let body = r#"{"default_node_info":{"network":"juno-1"}}"#;
+                write!(
+                    stream,
+                    "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                )
+                .unwrap();
+            }
+        });
+        url
+    }
+
+    #[tokio::test]
+    async fn falls_back_when_grpc_is_unreachable() {
+        // Nothing listens on this port, the connections are refused right away
+        let mut chain = ChainInfoOwned {
+            chain_id: "juno-1".to_string(),
+            grpc_urls: vec!["http://127.0.0.1:1".to_string()],
+            ..Default::default()
+        };
+        assert!(QueryTransport::connect(&chain).await.is_err());
+
+        chain.lcd_url = Some(serve_lcd());
+        let transport = QueryTransport::connect(&chain).await.unwrap();
+        assert!(
+            matches!(transport, QueryTransport::Lcd(lcd) if Some(&lcd.url) == chain.lcd_url.as_ref())
+        );
+    }
+}
@@ -2,7 +2,10 @@ use cosmrs::proto::cosmos::base::abci::v1beta1::TxResponse;
 use cw_orch_core::log::transaction_target;
 
 use crate::senders::tx::TxSender;
-use crate::{queriers::Node, senders::sign::Signer, CosmTxResponse, DaemonError, TxBuilder};
+use crate::{
+    queriers::Node, senders::sign::Signer, CosmTxResponse, DaemonError, PollingConfig, TxBuilder,
+    TxError,
+};
 
 pub type StrategyAction =
     fn(&mut TxBuilder, &Result<TxResponse, DaemonError>) -> Result<(), DaemonError>;
@@ -69,6 +72,10 @@ impl TxBroadcaster {
         mut tx_builder: TxBuilder,
         signer: &impl Signer,
     ) -> Result<TxResponse, DaemonError> {
+        // Don't start a broadcast once shutting down
+        if signer.polling().is_some_and(PollingConfig::is_cancelled) {
+            return Err(DaemonError::Cancelled);
+        }
         let mut tx_retry = true;
 
         // We try and broadcast once
@@ -90,16 +97,18 @@ impl TxBroadcaster {
                     tx_retry = true;
 
                     // We still await for the next block, to avoid spamming retry when an error occurs
+                    let polling = signer.polling().cloned().unwrap_or_default();
                     let block_speed = Node::new_async(signer.channel())
                         ._average_block_speed(None)
                         .await?;
+                    let block_speed = polling.clamp_block_time(block_speed);
                     log::warn!(
                         target: &transaction_target(),
                         "Retrying broadcasting TX in {:?} milliseconds because of {}",
                         block_speed.as_millis(),
                         s.reason
                     );
-                    tokio::time::sleep(block_speed).await;
+                    polling.sleep(block_speed).await?;
 
                     tx_response = broadcast_helper(&mut tx_builder, signer).await;
                     continue;
@@ -124,11 +133,34 @@ async fn broadcast_helper(
     tx_builder: &mut TxBuilder,
     signer: &impl Signer,
 ) -> Result<TxResponse, DaemonError> {
-    let tx = tx_builder.build(signer).await?;
-    let tx_response = signer.broadcast_tx(tx).await?;
-    log::debug!(target: &transaction_target(), "TX broadcast response: {:?}", tx_response);
+    // The sequence is allocated locally, unless it was fixed on the builder
+    let allocated = match (tx_builder.sequence, signer.sequence_manager()) {
+        (None, Some(manager)) => {
+            let chain_sequence = signer.signing_account().await?.sequence;
+            let sequence = manager.next_sequence(chain_sequence);
+            tx_builder.sequence(sequence);
+            Some((manager, sequence))
+        }
+        _ => None,
+    };
 
-    assert_broadcast_code_response(tx_response)
+    let tx_response = async {
+        let tx = tx_builder.build(signer).await?;
+        let tx_response = signer.broadcast_tx(tx).await?;
+        log::debug!(target: &transaction_target(), "TX broadcast response: {:?}", tx_response);
+
+        assert_broadcast_code_response(tx_response)
+    }
+    .await;
+
+    if let Some((manager, sequence)) = allocated {
+        // A retry allocates a new sequence
+        tx_builder.sequence = None;
+        if let Err(e) = &tx_response {
+            manager.rejected(sequence, &e.to_string());
+        }
+    }
+    tx_response
 }
 
 /// Tx Responses with a non 0 code, should also error with the raw loq
@@ -140,10 +172,11 @@ pub(crate) fn assert_broadcast_code_response(
     if tx_response.code == 0 {
         Ok(tx_response)
     } else {
-        Err(DaemonError::TxFailed {
-            code: tx_response.code as usize,
-            reason: tx_response.raw_log,
-        })
+        Err(DaemonError::TxFailed(TxError::new(
+            tx_response.codespace,
+            tx_response.code as usize,
+            tx_response.raw_log,
+        )))
     }
 }
 
@@ -156,10 +189,11 @@ pub fn assert_broadcast_code_cosm_response(
     if tx_response.code == 0 {
         Ok(tx_response)
     } else {
-        Err(DaemonError::TxFailed {
-            code: tx_response.code,
-            reason: tx_response.raw_log,
-        })
+        Err(DaemonError::TxFailed(TxError::new(
+            tx_response.codespace,
+            tx_response.code,
+            tx_response.raw_log,
+        )))
     }
 }
 
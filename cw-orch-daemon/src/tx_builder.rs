@@ -1,5 +1,6 @@
 use std::str::FromStr;
 
+use base64::{engine::general_purpose::STANDARD, Engine};
 use cosmrs::AccountId;
 use cosmrs::{
     tendermint::chain::Id,
@@ -8,6 +9,7 @@ use cosmrs::{
 };
 use cosmwasm_std::Addr;
 use cw_orch_core::log::transaction_target;
+use serde::{Deserialize, Serialize};
 
 use crate::env::DaemonEnvVars;
 use crate::senders::sign::{Signer, SigningAccount};
@@ -153,7 +155,50 @@ impl TxBuilder {
             &Id::try_from(wallet.chain_id())?,
             account_number,
         )?;
-        wallet.sign(sign_doc).map_err(Into::into)
+        wallet.sign(sign_doc).await
+    }
+
+    /// Builds the transaction without signing it, so that it can be signed later (possibly on another machine).
+    /// The account number and sequence are fetched from the node unless the sequence was set on the builder.
+    pub async fn build_unsigned(
+        &mut self,
+        wallet: &impl Signer,
+    ) -> Result<UnsignedTx, DaemonError> {
+        let SigningAccount {
+            account_number,
+            sequence,
+        } = wallet.signing_account().await?;
+        let sequence = self.sequence.unwrap_or(sequence);
+
+        let (tx_fee, gas_limit) =
+            if let (Some(fee), Some(gas_limit)) = (self.fee_amount, self.gas_limit) {
+                (fee, gas_limit)
+            } else {
+                let sim_gas_used = wallet
+                    .calculate_gas(&self.body, sequence, account_number)
+                    .await?;
+                let (gas_expected, fee_amount) =
+                    TxBuilder::get_fee_from_gas(sim_gas_used, wallet.gas_price()?)?;
+                self.gas_limit = Some(gas_expected);
+                (fee_amount, gas_expected)
+            };
+
+        let fee = wallet.build_fee(tx_fee, gas_limit)?;
+        let auth_info = wallet.signer_info(sequence).auth_info(fee);
+        let sign_doc = SignDoc::new(
+            &self.body,
+            &auth_info,
+            &Id::try_from(wallet.chain_id())?,
+            account_number,
+        )?;
+
+        Ok(UnsignedTx {
+            chain_id: sign_doc.chain_id,
+            account_number,
+            sequence,
+            body_bytes: STANDARD.encode(sign_doc.body_bytes),
+            auth_info_bytes: STANDARD.encode(sign_doc.auth_info_bytes),
+        })
     }
 
     /// Compute the gas fee from the expected gas in the transaction
@@ -175,3 +220,60 @@ impl TxBuilder {
         Ok((gas_expected as u64, fee_amount as u128))
     }
 }
+
+/// A sign-ready transaction, serializable to JSON.
+/// Used to sign transactions offline and broadcast them later.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct UnsignedTx {
+    /// Chain id the transaction is meant for
+    pub chain_id: String,
+    /// Account number of the signer
+    pub account_number: u64,
+    /// Sequence of the signer used for this transaction
+    pub sequence: u64,
+    /// Base64 encoded proto `TxBody`
+    pub body_bytes: String,
+    /// Base64 encoded proto `AuthInfo`
+    pub auth_info_bytes: String,
+}
+
+impl UnsignedTx {
+    /// Signs the transaction with the provided signer.
+    /// The signer's chain id needs to match the transaction chain id.
+    pub async fn sign(&self, signer: &impl Signer) -> Result<SignedTx, DaemonError> {
+        if signer.chain_id() != self.chain_id {
+            return Err(DaemonError::StdErr(format!(
+                "Can't sign a transaction for chain {} with a signer connected to {}",
+                self.chain_id,
+                signer.chain_id()
+            )));
+        }
+        let sign_doc = SignDoc {
+            body_bytes: STANDARD.decode(&self.body_bytes)?,
+            auth_info_bytes: STANDARD.decode(&self.auth_info_bytes)?,
+            chain_id: self.chain_id.clone(),
+            account_number: self.account_number,
+        };
+        let raw = signer.sign(sign_doc).await?;
+        Ok(SignedTx {
+            chain_id: self.chain_id.clone(),
+            tx_bytes: STANDARD.encode(raw.to_bytes()?),
+        })
+    }
+}
+
+/// A signed transaction ready to be broadcasted, serializable to JSON.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SignedTx {
+    /// Chain id the transaction is meant for
+    pub chain_id: String,
+    /// Base64 encoded raw transaction bytes
+    pub tx_bytes: String,
+}
+
+impl SignedTx {
+    /// Returns the raw transaction, to be broadcasted with [`crate::TxSender::broadcast_tx`]
+    pub fn raw(&self) -> Result<Raw, DaemonError> {
+        Ok(Raw::from_bytes(&STANDARD.decode(&self.tx_bytes)?)?)
+    }
+}
@@ -0,0 +1,150 @@
+//! Structured errors of the transactions that failed on chain.
+//!
+//! A failed transaction only carries its ABCI codespace, code and raw log.
+//! [`TxError`] keeps them apart, so that scripts match the failures on their codespace and code instead of string-matching the raw log.
+//!
+//! ```rust,no_run
+//! # use cw_orch_daemon::{DaemonError, SDK_CODESPACE};
+//! # fn execute() -> Result<(), DaemonError> { Ok(()) }
+//! if let Err(err) = execute() {
+//!     let tx_error = err.tx_error().expect("the tx failed on chain");
+//!     if tx_error.is_out_of_gas() {
+//!         println!("increase the gas adjustment");
+//!     } else if tx_error.is_contract_failure() {
+//!         println!("contract error: {:?}", tx_error.contract_error);
+//!     } else if tx_error.is(SDK_CODESPACE, 5) {
+//!         println!("insufficient funds");
+//!     }
+//! }
+//! ```
+use std::fmt::Display;
+
+/// Codespace of the errors of the wasm module
+pub const WASM_CODESPACE: &str = "wasm";
+/// Codespace of the errors of the Cosmos SDK
+pub const SDK_CODESPACE: &str = "sdk";
+
+/// Codes of the wasm module errors returned by a contract entry point (instantiate, execute and migrate failed)
+const WASM_CONTRACT_FAILURE_CODES: [usize; 3] = [4, 5, 11];
+
+/// Error of a transaction that failed on chain
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TxError {
+    /// Module that returned the error (`sdk`, `wasm`, ...)
+    pub codespace: String,
+    /// Error code, unique within the codespace
+    pub code: usize,
+    /// Raw log of the transaction
+    pub message: String,
+    /// Message of the error returned by the contract, if the transaction failed in a contract entry point
+    pub contract_error: Option<String>,
+}
+
+impl TxError {
+    /// Parses the raw log of a failed transaction
+    pub fn new(codespace: impl Into<String>, code: usize, raw_log: impl Into<String>) -> Self {
+        let mut error = Self {
+            codespace: codespace.into(),
+            code,
+            message: raw_log.into(),
+            contract_error: None,
+        };
+        if error.is_contract_failure() {
+            error.contract_error = parse_contract_error(&error.message);
+        }
+        error
+    }
+
+    /// Returns true if the error has the given codespace and code
+    pub fn is(&self, codespace: &str, code: usize) -> bool {
+        self.codespace == codespace && self.code == code
+    }
+
+    /// Returns true if the transaction ran out of gas
+    pub fn is_out_of_gas(&self) -> bool {
+        self.is(SDK_CODESPACE, 11)
+    }
+
+    /// Returns true if the fee was too low
+    pub fn is_insufficient_fee(&self) -> bool {
+        self.is(SDK_CODESPACE, 13)
+    }
+
+    /// Returns true if the account sequence didn't match
+    pub fn is_sequence_mismatch(&self) -> bool {
+        self.is(SDK_CODESPACE, 32)
+    }
+
+    /// Returns true if a contract entry point returned an error, see [`TxError::contract_error`] for its message
+    pub fn is_contract_failure(&self) -> bool {
+        self.codespace == WASM_CODESPACE && WASM_CONTRACT_FAILURE_CODES.contains(&self.code)
+    }
+}
+
+impl Display for TxError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} with code {} ({})",
+            self.message, self.code, self.codespace
+        )
+    }
+}
+
+/// Wasmd wraps the contract errors as `failed to execute message; message index: 0: <error>: execute wasm contract failed`
+fn parse_contract_error(raw_log: &str) -> Option<String> {
+    const INDEX_PREFIX: &str = "message index: ";
+    let start = raw_log.find(INDEX_PREFIX)? + INDEX_PREFIX.len();
+    let error = &raw_log[start..];
+    let error = error.split_once(": ").map_or(error, |(_, error)| error);
+    let error = error
+        .find(" wasm contract failed")
+        .and_then(|end| error[..end].rsplit_once(": "))
+        .map_or(error, |(error, _)| error);
+    Some(error.trim().to_string())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parses_contract_errors() {
+        let error = TxError::new(
+            WASM_CODESPACE,
+            5,
+            "failed to execute message; message index: 0: Insufficient funds: 100: execute wasm contract failed",
+        );
+        assert!(error.is_contract_failure());
+        assert_eq!(
+            error.contract_error.as_deref(),
+            Some("Insufficient funds: 100")
+        );
+
+        let error = TxError::new(
+            WASM_CODESPACE,
+            4,
+            "failed to execute message; message index: 1: Unauthorized: instantiate wasm contract failed",
+        );
+        assert!(error.is_contract_failure());
+        assert_eq!(error.contract_error.as_deref(), Some("Unauthorized"));
+    }
+
+    #[test]
+    fn classifies_by_code() {
+        let error = TxError::new(SDK_CODESPACE, 11, "out of gas in location: WriteFlat");
+        assert!(error.is_out_of_gas());
+        assert!(!error.is_contract_failure());
+        assert_eq!(error.contract_error, None);
+
+        // Other wasm module errors are not returned by the contract
+        let error = TxError::new(
+            WASM_CODESPACE,
+            2,
+            "failed to execute message; message index: 0: invalid: create wasm contract failed",
+        );
+        assert!(error.is(WASM_CODESPACE, 2));
+        assert!(!error.is_contract_failure());
+        assert_eq!(error.contract_error, None);
+    }
+}
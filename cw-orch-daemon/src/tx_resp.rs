@@ -192,6 +192,10 @@ impl IndexResponse for CosmTxResponse {
         }
     }
 
+    fn gas_used(&self) -> Option<u64> {
+        Some(self.gas_used)
+    }
+
     fn event_attr_value(&self, event_type: &str, attr_key: &str) -> StdResult<String> {
         for event in &self.events {
             if event.r#type == event_type {
@@ -335,3 +339,174 @@ pub fn parse_timestamp(s: String) -> Result<DateTime<Utc>, DaemonError> {
         Ok(dt) => Ok(Utc.from_utc_datetime(&dt)),
     }
 }
+
+/// A message decoded from a transaction body.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DecodedTxMsg {
+    /// `MsgExecuteContract` with its message decoded to JSON
+    WasmExecute {
+        /// Sender of the message
+        sender: String,
+        /// Contract that was executed
+        contract: String,
+        /// Execute message, decoded from its raw bytes
+        msg: serde_json::Value,
+        /// Funds attached to the message
+        funds: Vec<cosmwasm_std::Coin>,
+    },
+    /// `MsgInstantiateContract` with its message decoded to JSON
+    WasmInstantiate {
+        /// Sender of the message
+        sender: String,
+        /// Code id that was instantiated
+        code_id: u64,
+        /// Label of the new contract
+        label: String,
+        /// Instantiate message, decoded from its raw bytes
+        msg: serde_json::Value,
+        /// Funds attached to the message
+        funds: Vec<cosmwasm_std::Coin>,
+    },
+    /// `MsgMigrateContract` with its message decoded to JSON
+    WasmMigrate {
+        /// Sender of the message
+        sender: String,
+        /// Contract that was migrated
+        contract: String,
+        /// New code id of the contract
+        code_id: u64,
+        /// Migrate message, decoded from its raw bytes
+        msg: serde_json::Value,
+    },
+    /// Any other message, left undecoded
+    Other {
+        /// Type url of the message
+        type_url: String,
+        /// Raw value of the message, base64 encoded
+        value: String,
+    },
+}
+
+impl DecodedTxMsg {
+    /// Decodes a message from a transaction body.
+    /// Wasm messages have their inner message decoded to JSON, other messages are kept as is.
+    pub fn decode(any: cosmrs::Any) -> Result<Self, DaemonError> {
+        use crate::cosmos_modules::cosmwasm::{
+            MsgExecuteContract, MsgInstantiateContract, MsgMigrateContract,
+        };
+        use base64::{engine::general_purpose::STANDARD, Engine};
+        use prost::{Message, Name};
+
+        let decoded = if any.type_url == MsgExecuteContract::type_url() {
+            let msg = MsgExecuteContract::decode(any.value.as_slice())?;
+            Self::WasmExecute {
+                sender: msg.sender,
+                contract: msg.contract,
+                msg: decode_json_msg(&msg.msg),
+                funds: proto_coins_to_cw(msg.funds)?,
+            }
+        } else if any.type_url == MsgInstantiateContract::type_url() {
+            let msg = MsgInstantiateContract::decode(any.value.as_slice())?;
+            Self::WasmInstantiate {
+                sender: msg.sender,
+                code_id: msg.code_id,
+                label: msg.label,
+                msg: decode_json_msg(&msg.msg),
+                funds: proto_coins_to_cw(msg.funds)?,
+            }
+        } else if any.type_url == MsgMigrateContract::type_url() {
+            let msg = MsgMigrateContract::decode(any.value.as_slice())?;
+            Self::WasmMigrate {
+                sender: msg.sender,
+                contract: msg.contract,
+                code_id: msg.code_id,
+                msg: decode_json_msg(&msg.msg),
+            }
+        } else {
+            Self::Other {
+                type_url: any.type_url,
+                value: STANDARD.encode(any.value),
+            }
+        };
+        Ok(decoded)
+    }
+}
+
+/// Falls back to the base64 encoded bytes when the message is not valid JSON
+fn decode_json_msg(msg: &[u8]) -> serde_json::Value {
+    use base64::{engine::general_purpose::STANDARD, Engine};
+
+    serde_json::from_slice(msg).unwrap_or_else(|_| serde_json::Value::String(STANDARD.encode(msg)))
+}
+
+fn proto_coins_to_cw(
+    coins: Vec<cosmrs::proto::cosmos::base::v1beta1::Coin>,
+) -> Result<Vec<cosmwasm_std::Coin>, DaemonError> {
+    coins
+        .into_iter()
+        .map(|c| Ok(cosmwasm_std::Coin::new(c.amount.parse::<u128>()?, c.denom)))
+        .collect()
+}
+
+/// A transaction along with its decoded messages.
+/// Returned by [`crate::queriers::Node::_inspect_tx`]
+#[derive(Debug, Clone)]
+pub struct TxInspection {
+    /// Response of the transaction (events, gas, logs...)
+    pub response: CosmTxResponse,
+    /// Memo of the transaction
+    pub memo: String,
+    /// Messages of the transaction, in order
+    pub messages: Vec<DecodedTxMsg>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cosmos_modules::cosmwasm::MsgExecuteContract;
+    use prost::{Message, Name};
+
+    #[test]
+    fn decodes_wasm_messages() -> Result<(), DaemonError> {
+        let execute = MsgExecuteContract {
+            sender: "juno1sender".to_string(),
+            contract: "juno1contract".to_string(),
+            msg: br#"{"first_message":{}}"#.to_vec(),
+            funds: vec![cosmrs::proto::cosmos::base::v1beta1::Coin {
+                denom: "ujuno".to_string(),
+                amount: "100".to_string(),
+            }],
+        };
+        let decoded = DecodedTxMsg::decode(cosmrs::Any {
+            type_url: MsgExecuteContract::type_url(),
+            value: execute.encode_to_vec(),
+        })?;
+        match decoded {
+            DecodedTxMsg::WasmExecute {
+                sender,
+                contract,
+                msg,
+                funds,
+            } => {
+                assert_eq!(sender, "juno1sender");
+                assert_eq!(contract, "juno1contract");
+                assert_eq!(msg, serde_json::json!({ "first_message": {} }));
+                assert_eq!(funds, vec![cosmwasm_std::coin(100, "ujuno")]);
+            }
+            other => panic!("unexpected message {other:?}"),
+        }
+
+        // Non-JSON messages and unknown messages are kept base64 encoded
+        assert_eq!(decode_json_msg(&[0xff]), serde_json::json!("/w=="));
+        let decoded = DecodedTxMsg::decode(cosmrs::Any {
+            type_url: "/cosmos.bank.v1beta1.MsgSend".to_string(),
+            value: vec![1, 2, 3],
+        })?;
+        assert!(matches!(
+            decoded,
+            DecodedTxMsg::Other { type_url, value } if type_url == "/cosmos.bank.v1beta1.MsgSend" && value == "AQID"
+        ));
+        Ok(())
+    }
+}
@@ -0,0 +1,61 @@
+mod common;
+#[cfg(feature = "node-tests")]
+mod tests {
+    use std::time::Duration;
+
+    use cosmwasm_std::{coins, Addr};
+    use cw_orch_core::environment::TxHandler;
+    use cw_orch_daemon::{BroadcastMode, Daemon, PollingConfig, TxConfirmation};
+    use cw_orch_networks::networks;
+
+    fn send_with(polling: PollingConfig) {
+        super::common::enable_logger();
+        let daemon = Daemon::builder(networks::LOCAL_JUNO)
+            .is_test(true)
+            .polling(polling)
+            .build()
+            .unwrap();
+        let receiver = Addr::unchecked(
+            cosmrs::AccountId::new("juno", &[2; 20])
+                .unwrap()
+                .to_string(),
+        );
+
+        let response = daemon
+            .bank_send(&receiver, &coins(1, networks::LOCAL_JUNO.gas_denom))
+            .unwrap();
+        assert!(response.height > 0);
+        assert_eq!(response.code, 0);
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn async_broadcast() {
+        send_with(PollingConfig::default().broadcast_mode(BroadcastMode::Async));
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn interval_confirmation() {
+        send_with(
+            PollingConfig::default()
+                .broadcast_mode(BroadcastMode::Async)
+                .confirmation(TxConfirmation::Interval {
+                    interval: Duration::from_millis(500),
+                    max_wait: Duration::from_secs(60),
+                }),
+        );
+    }
+
+    #[cfg(feature = "websocket")]
+    #[test]
+    #[serial_test::serial]
+    fn websocket_confirmation() {
+        send_with(
+            PollingConfig::default().confirmation(TxConfirmation::Websocket {
+                rpc_url: "ws://localhost:26657/websocket".to_string(),
+                max_wait: Duration::from_secs(60),
+            }),
+        );
+    }
+}
@@ -5,7 +5,10 @@ mod tests {
         DaemonAsync contract general tests
     */
 
-    use cw_orch_core::{contract::interface_traits::*, environment::TxHandler};
+    use cw_orch_core::{
+        contract::interface_traits::*,
+        environment::{IndexResponse, TxHandler},
+    };
     use cw_orch_daemon::Daemon;
     use mock_contract::{InstantiateMsg, MigrateMsg, QueryMsg};
 
@@ -135,4 +138,177 @@ mod tests {
             .that(&contract.upload_if_needed().unwrap())
             .is_none();
     }
+
+    #[test]
+    #[serial_test::serial]
+    fn async_helper_traits() {
+        use cw_orch_daemon::DaemonAsync;
+        use cw_orch_networks::networks;
+        use mock_contract::{AsyncExecuteMsgFns, ExecuteMsg};
+
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        rt.block_on(async {
+            let daemon = DaemonAsync::builder(networks::LOCAL_JUNO)
+                .is_test(true)
+                .build()
+                .await
+                .unwrap();
+
+            let contract = mock_contract::MockContract::new("test:mock_contract:async", daemon);
+
+            asserting!("async_upload is ok")
+                .that(&contract.async_upload().await)
+                .is_ok();
+            asserting!("async_instantiate is ok")
+                .that(
+                    &contract
+                        .async_instantiate(&InstantiateMsg {}, None, &[])
+                        .await,
+                )
+                .is_ok();
+            asserting!("address is present")
+                .that(&contract.address())
+                .is_ok();
+            asserting!("async_execute is ok")
+                .that(
+                    &contract
+                        .async_execute(&ExecuteMsg::FirstMessage {}, &[])
+                        .await,
+                )
+                .is_ok();
+            asserting!("derived async execute fn is ok")
+                .that(&contract.first_message_async().await)
+                .is_ok();
+            asserting!("async_query is ok")
+                .that(
+                    &contract
+                        .async_query::<String>(&QueryMsg::FirstQuery {})
+                        .await,
+                )
+                .is_ok();
+        });
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn concurrent_uploads() {
+        use cw_orch_core::environment::TxKind;
+        use cw_orch_networks::networks;
+        use std::sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        };
+
+        let mut daemon = Daemon::builder(networks::LOCAL_JUNO)
+            .is_test(true)
+            .build()
+            .unwrap();
+
+        // Each upload goes through the hooks of the normal upload path
+        let uploads = Arc::new(AtomicUsize::new(0));
+        let counter = uploads.clone();
+        daemon.add_post_response_hook(move |context| {
+            if context.kind == TxKind::Upload {
+                counter.fetch_add(1, Ordering::SeqCst);
+            }
+            Ok(())
+        });
+
+        let first = mock_contract::MockContract::new("test:mock_contract:first", daemon.clone());
+        let second = mock_contract::MockContract::new("test:mock_contract:second", daemon.clone());
+
+        let responses = daemon.upload_concurrently(&[&first, &second]).unwrap();
+        assert_eq!(responses.len(), 2);
+        assert_eq!(uploads.load(Ordering::SeqCst), 2);
+
+        let first_code_id = first.code_id().unwrap();
+        let second_code_id = second.code_id().unwrap();
+        assert_ne!(first_code_id, second_code_id);
+        assert_eq!(responses[0].uploaded_code_id().unwrap(), first_code_id);
+        assert_eq!(responses[1].uploaded_code_id().unwrap(), second_code_id);
+
+        asserting!("contracts can be instantiated")
+            .that(&first.instantiate(&InstantiateMsg {}, None, &[]))
+            .is_ok();
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn bank_send_analysis() {
+        use cosmwasm_std::coins;
+        use cw_orch_networks::networks;
+
+        let daemon = Daemon::builder(networks::LOCAL_JUNO)
+            .is_test(true)
+            .build()
+            .unwrap();
+        let receiver = Addr::unchecked(
+            cosmrs::AccountId::new("juno", &[1; 20])
+                .unwrap()
+                .to_string(),
+        );
+        let denom = networks::LOCAL_JUNO.gas_denom;
+
+        let response = daemon.bank_send(&receiver, &coins(42, denom)).unwrap();
+        let analysis = response.analysis();
+
+        // The fee is reported apart from the amount sent
+        analysis.assert_sent(&daemon.sender_addr(), &coins(42, denom));
+        analysis.assert_received(&receiver, &coins(42, denom));
+        assert!(!analysis.fee().is_empty());
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn bank_send_memo() {
+        use cosmwasm_std::coins;
+        use cw_orch_daemon::{queriers::Node, MemoTemplate};
+        use cw_orch_networks::networks;
+
+        let daemon = Daemon::builder(networks::LOCAL_JUNO)
+            .is_test(true)
+            .memo(MemoTemplate::new("{step} on {chain_id}"))
+            .build()
+            .unwrap();
+        let receiver = Addr::unchecked(
+            cosmrs::AccountId::new("juno", &[1; 20])
+                .unwrap()
+                .to_string(),
+        );
+
+        let response = daemon
+            .bank_send(&receiver, &coins(1, networks::LOCAL_JUNO.gas_denom))
+            .unwrap();
+        let inspection = Node::new(&daemon).inspect_tx(response.txhash).unwrap();
+        assert_eq!(
+            inspection.memo,
+            format!("bank_send on {}", networks::LOCAL_JUNO.chain_id)
+        );
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn gas_snapshot() {
+        use cw_orch_core::contract::GasSnapshot;
+        use cw_orch_networks::networks;
+
+        let daemon = Daemon::builder(networks::LOCAL_JUNO)
+            .is_test(true)
+            .build()
+            .unwrap();
+
+        let contract = mock_contract::MockContract::new("test:mock_contract:gas", daemon.clone());
+        contract.upload_if_needed().unwrap();
+        contract.instantiate(&InstantiateMsg {}, None, &[]).unwrap();
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("gas.json");
+        let mut gas = GasSnapshot::new(&path);
+        let response = contract
+            .execute(&mock_contract::ExecuteMsg::FirstMessage {}, &[])
+            .unwrap();
+        gas.record_tx("first_message", &response).unwrap();
+        let report = gas.check().unwrap();
+        assert_eq!(report.deltas[0].current, response.gas_used().unwrap());
+    }
 }
@@ -1,11 +1,11 @@
 use std::sync::Arc;
 
-use cw_orch_core::environment::ChainState;
+use cw_orch_core::environment::{ChainState, StateInterface};
 use cw_orch_daemon::{
     env::STATE_FILE_ENV_NAME,
     json_lock::JsonLockedState,
     networks::{JUNO_1, NEUTRON_1},
-    Daemon, DaemonBuilder, DaemonError, DaemonStateFile,
+    Daemon, DaemonBuilder, DaemonError, DaemonStateFile, DuplicateResolve,
 };
 
 pub const DUMMY_MNEMONIC:&str = "chapter wrist alcohol shine angry noise mercy simple rebel recycle vehicle wrap morning giraffe lazy outdoor noise blood ginger sort reunion boss crowd dutch";
@@ -240,3 +240,95 @@ fn panic_when_someone_holds_json_file() {
         Err(_) => (),
     }
 }
+
+#[test]
+#[serial_test::serial]
+fn export_and_import_state() {
+    let daemon = DaemonBuilder::new(JUNO_1)
+        .mnemonic(DUMMY_MNEMONIC)
+        .is_test(true)
+        .build()
+        .unwrap();
+    let mut state = daemon.state();
+    state.set("code_ids", "counter", 12).unwrap();
+    state.set("default", "counter", "juno1counter").unwrap();
+
+    let dir = tempfile::tempdir().unwrap();
+    let export_path = dir.path().join("export.json");
+    state.export(&export_path).unwrap();
+
+    let other = DaemonBuilder::new(JUNO_1)
+        .mnemonic(DUMMY_MNEMONIC)
+        .is_test(true)
+        .build()
+        .unwrap();
+    let mut other_state = other.state();
+    other_state.set("default", "counter", "juno1other").unwrap();
+
+    // Conflicting address errors
+    assert!(other_state
+        .import(&export_path, DuplicateResolve::Error)
+        .is_err());
+
+    // Local values are kept
+    other_state
+        .import(&export_path, DuplicateResolve::Keep)
+        .unwrap();
+    assert_eq!(other_state.get("default").unwrap()["counter"], "juno1other");
+    assert_eq!(other_state.get("code_ids").unwrap()["counter"], 12);
+
+    // Local values are replaced
+    other_state
+        .import(&export_path, DuplicateResolve::Overwrite)
+        .unwrap();
+    assert_eq!(
+        other_state.get("default").unwrap()["counter"],
+        "juno1counter"
+    );
+
+    std::env::remove_var(STATE_FILE_ENV_NAME);
+}
+
+#[test]
+#[serial_test::serial]
+fn mount_read_only_state() {
+    let daemon = DaemonBuilder::new(JUNO_1)
+        .mnemonic(DUMMY_MNEMONIC)
+        .is_test(true)
+        .build()
+        .unwrap();
+    let mut state = daemon.state();
+
+    let dir = tempfile::tempdir().unwrap();
+    let mounted_path = dir.path().join("mounted.json");
+    std::fs::write(
+        &mounted_path,
+        serde_json::json!({
+            "juno-1": {
+                "code_ids": { "dex:pair": 5, "dex:oracle": "5" },
+                "v1": { "dex:pair": "juno1pair", "dex:router": "juno1router", "dex:oracle": 7 }
+            }
+        })
+        .to_string(),
+    )
+    .unwrap();
+    state.mount(&mounted_path, Some("v1")).unwrap();
+    state
+        .set("default", "dex:router", "juno1localrouter")
+        .unwrap();
+
+    // Mounts are shared with the daemon
+    let state = daemon.state();
+    assert_eq!(state.get_address("dex:pair").unwrap(), "juno1pair");
+    assert_eq!(state.get_code_id("dex:pair").unwrap(), 5);
+    // The local state takes precedence
+    assert_eq!(state.get_address("dex:router").unwrap(), "juno1localrouter");
+    assert!(state.get_address("dex:factory").is_err());
+    // Malformed entries are errors
+    assert!(state.get_address("dex:oracle").is_err());
+    assert!(state.get_code_id("dex:oracle").is_err());
+    // The mounted file is not written to
+    assert!(state.get("v1").unwrap().is_null());
+
+    std::env::remove_var(STATE_FILE_ENV_NAME);
+}
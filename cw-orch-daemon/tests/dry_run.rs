@@ -0,0 +1,33 @@
+mod common;
+
+// Kept in its own test binary, the dry run mode is read from the environment
+#[cfg(feature = "node-tests")]
+mod tests {
+    use cw_orch_core::{
+        contract::interface_traits::{ContractInstance, CwOrchUpload},
+        env::DRY_RUN_ENV_NAME,
+    };
+    use cw_orch_daemon::Daemon;
+    use cw_orch_networks::networks;
+
+    #[test]
+    fn dry_run_upload() -> anyhow::Result<()> {
+        super::common::enable_logger();
+        let daemon = Daemon::builder(networks::LOCAL_JUNO)
+            .is_test(true)
+            .build()?;
+
+        let contract = mock_contract::MockContract::new("test:mock_contract", daemon.clone());
+
+        std::env::set_var(DRY_RUN_ENV_NAME, "true");
+        let response = contract.upload();
+        std::env::remove_var(DRY_RUN_ENV_NAME);
+
+        let response = response?;
+        assert!(response.gas_used > 0);
+        assert!(response.txhash.is_empty());
+        // Nothing was stored
+        assert!(contract.code_id().is_err());
+        Ok(())
+    }
+}
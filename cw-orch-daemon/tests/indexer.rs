@@ -0,0 +1,94 @@
+mod common;
+#[cfg(all(feature = "node-tests", feature = "indexer"))]
+mod tests {
+    use std::{sync::Arc, time::Duration};
+
+    use cw_orch_core::{contract::interface_traits::*, environment::QuerierGetter};
+    use cw_orch_daemon::{indexer::Indexer, queriers::Node, subscription::Subscriptions, Daemon};
+    use cw_orch_networks::networks;
+    use mock_contract::{ExecuteMsg, InstantiateMsg, MockContract};
+
+    const FIRST_MESSAGE: &str = "first message passed";
+
+    fn first_messages(indexer: &Indexer) -> usize {
+        indexer
+            .events("wasm", ..)
+            .unwrap()
+            .into_iter()
+            .filter(|indexed| {
+                indexed
+                    .event
+                    .attributes
+                    .iter()
+                    .any(|attr| attr.key == "action" && attr.value == FIRST_MESSAGE)
+            })
+            .count()
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn backfill() -> anyhow::Result<()> {
+        super::common::enable_logger();
+        let daemon = Daemon::builder(networks::LOCAL_JUNO)
+            .is_test(true)
+            .build()?;
+
+        let contract = MockContract::new("test:mock_contract", daemon.clone());
+        contract.upload()?;
+        contract.instantiate(&InstantiateMsg {}, None, &[])?;
+        contract.execute(&ExecuteMsg::<String>::FirstMessage {}, &[])?;
+        contract.execute(&ExecuteMsg::<String>::FirstMessage {}, &[])?;
+
+        let indexer = Indexer::open_in_memory(vec![contract.address()?])?;
+        let node: Node = daemon.querier();
+        // The instantiation and the 2 executions
+        let indexed = daemon.rt_handle.block_on(indexer.backfill(&node, None))?;
+        assert_eq!(indexed, 3);
+        assert_eq!(first_messages(&indexer), 2);
+
+        // Restarting from the last indexed height only indexes the new events
+        contract.execute(&ExecuteMsg::<String>::FirstMessage {}, &[])?;
+        let from_height = indexer.last_indexed_height()?;
+        let indexed = daemon
+            .rt_handle
+            .block_on(indexer.backfill(&node, from_height))?;
+        assert_eq!(indexed, 1);
+        assert_eq!(first_messages(&indexer), 3);
+        Ok(())
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn tail() -> anyhow::Result<()> {
+        super::common::enable_logger();
+        let daemon = Daemon::builder(networks::LOCAL_JUNO)
+            .is_test(true)
+            .build()?;
+
+        let contract = MockContract::new("test:mock_contract", daemon.clone());
+        contract.upload()?;
+        contract.instantiate(&InstantiateMsg {}, None, &[])?;
+
+        let indexer = Arc::new(Indexer::open_in_memory(vec![contract.address()?])?);
+        let subscriptions = daemon
+            .rt_handle
+            .block_on(Subscriptions::connect("ws://localhost:26657/websocket"))?;
+        let tail = daemon.rt_handle.spawn({
+            let indexer = indexer.clone();
+            async move { indexer.tail(&subscriptions).await }
+        });
+        // Let the tail subscribe before executing
+        std::thread::sleep(Duration::from_secs(1));
+
+        contract.execute(&ExecuteMsg::<String>::FirstMessage {}, &[])?;
+        for _ in 0..20 {
+            if first_messages(&indexer) == 1 {
+                break;
+            }
+            std::thread::sleep(Duration::from_millis(500));
+        }
+        assert_eq!(first_messages(&indexer), 1);
+        tail.abort();
+        Ok(())
+    }
+}
@@ -12,8 +12,8 @@ mod queriers {
     use std::str::FromStr;
 
     use cw_orch_daemon::{
-        queriers::StakingBondStatus,
-        queriers::{CosmWasm, Gov, Ibc, Node, Staking},
+        queriers::{Authz, CosmWasm, FeeGrant, Gov, Ibc, Node, Staking},
+        queriers::{GovProposalStatus, StakingBondStatus},
         Daemon,
     };
     use tokio::runtime::Runtime;
@@ -84,6 +84,48 @@ mod queriers {
 
         let params = rt.block_on(gov._params("voting"));
         asserting!("params is ok").that(&params).is_ok();
+
+        let proposals =
+            rt.block_on(gov._proposals_by_status(GovProposalStatus::VotingPeriod, None));
+        asserting!("proposals is ok").that(&proposals).is_ok();
+    }
+
+    /*
+        Querier - Authz
+    */
+    #[test]
+    fn authz() {
+        let rt = Runtime::new().unwrap();
+        let channel = rt.block_on(build_channel());
+
+        let authz = Authz::new_async(channel);
+        let granter = Addr::unchecked("juno16g2rahf5846rxzp3fwlswy08fz8ccuwk03k57y");
+
+        let grants = rt.block_on(authz._granter_grants(&granter, None));
+        asserting!("granter grants is ok").that(&grants).is_ok();
+
+        let grants = rt.block_on(authz._grantee_grants(&granter, None));
+        asserting!("grantee grants is ok").that(&grants).is_ok();
+    }
+
+    /*
+        Querier - FeeGrant
+    */
+    #[test]
+    fn feegrant() {
+        let rt = Runtime::new().unwrap();
+        let channel = rt.block_on(build_channel());
+
+        let feegrant = FeeGrant::new_async(channel);
+        let granter = Addr::unchecked("juno16g2rahf5846rxzp3fwlswy08fz8ccuwk03k57y");
+
+        let allowances = rt.block_on(feegrant._allowances(&granter, None));
+        asserting!("allowances is ok").that(&allowances).is_ok();
+
+        let allowances = rt.block_on(feegrant._allowances_by_granter(&granter, None));
+        asserting!("allowances by granter is ok")
+            .that(&allowances)
+            .is_ok();
     }
 
     /*
@@ -161,6 +203,24 @@ mod queriers {
 
         let block_time = rt.block_on(node._block_time());
         asserting!("block_time is ok").that(&block_time).is_ok();
+
+        let validator_set = rt.block_on(node._validator_set(None)).unwrap();
+        assert!(!validator_set.is_empty());
+
+        // Tendermint RPC of the local node
+        let rpc_url = "http://localhost:26657";
+        let consensus_params = rt.block_on(node._consensus_params(rpc_url, None));
+        asserting!("consensus_params is ok")
+            .that(&consensus_params)
+            .is_ok();
+
+        let net_info = rt.block_on(node._net_info(rpc_url));
+        asserting!("net_info is ok").that(&net_info).is_ok();
+
+        let unconfirmed_txs = rt.block_on(node._num_unconfirmed_txs(rpc_url));
+        asserting!("num_unconfirmed_txs is ok")
+            .that(&unconfirmed_txs)
+            .is_ok();
     }
 
     #[test]
@@ -239,4 +299,147 @@ mod queriers {
             .that(&contract_info)
             .is_ok();
     }
+
+    #[test]
+    #[serial_test::serial]
+    fn query_batch() {
+        use cw_orch_core::environment::{QuerierGetter, QueryHandler};
+        use cw_orch_daemon::queriers::QueryBatch;
+        use mock_contract::QueryMsg;
+
+        let daemon = Daemon::builder(networks::LOCAL_JUNO)
+            .is_test(true)
+            .build()
+            .unwrap();
+
+        let contract = mock_contract::MockContract::new("test:mock_contract", daemon.clone());
+        contract.upload().unwrap();
+        contract.instantiate(&InstantiateMsg {}, None, &[]).unwrap();
+        let contract_address = contract.address().unwrap();
+
+        let mut batch = QueryBatch::new();
+        let first = batch
+            .add(&contract_address, &QueryMsg::<String>::FirstQuery {})
+            .unwrap();
+        let second = batch
+            .add(
+                &contract_address,
+                &QueryMsg::SecondQuery {
+                    t: "test".to_string(),
+                },
+            )
+            .unwrap();
+
+        let cw: CosmWasm = daemon.querier();
+        let height = daemon.block_info().unwrap().height;
+        let response = cw.query_batch(&batch, Some(height)).unwrap();
+
+        assert_eq!(response.height, height);
+        asserting!("first query is ok")
+            .that(&response.get::<String>(first))
+            .is_ok();
+        asserting!("second query is ok")
+            .that(&response.get::<String>(second))
+            .is_ok();
+        asserting!("out of bounds index errors")
+            .that(&response.get::<String>(2))
+            .is_err();
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn all_contract_state() {
+        use cw_orch_core::environment::QuerierGetter;
+
+        let daemon = Daemon::builder(networks::LOCAL_JUNO)
+            .is_test(true)
+            .build()
+            .unwrap();
+
+        let contract = mock_contract::MockContract::new("test:mock_contract", daemon.clone());
+        contract.upload().unwrap();
+        contract.instantiate(&InstantiateMsg {}, None, &[]).unwrap();
+        let contract_address = contract.address().unwrap();
+
+        let cw: CosmWasm = daemon.querier();
+        let entries = cw.all_contract_state(&contract_address).unwrap();
+        // The contract version is stored on instantiation
+        assert!(entries
+            .iter()
+            .any(|(key, _)| key.as_slice() == b"contract_info"));
+
+        let dir = tempfile::tempdir().unwrap();
+        let dump = dir.path().join("contract-state.json");
+        cw.dump_to_file(&contract_address, &dump).unwrap();
+        let dumped: Vec<(cosmwasm_std::Binary, cosmwasm_std::Binary)> =
+            serde_json::from_reader(std::fs::File::open(&dump).unwrap()).unwrap();
+        assert_eq!(dumped, entries);
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn inspect_tx() {
+        use cw_orch_core::environment::QuerierGetter;
+        use cw_orch_daemon::DecodedTxMsg;
+        use mock_contract::ExecuteMsg;
+
+        let daemon = Daemon::builder(networks::LOCAL_JUNO)
+            .is_test(true)
+            .build()
+            .unwrap();
+
+        let contract = mock_contract::MockContract::new("test:mock_contract", daemon.clone());
+        contract.upload().unwrap();
+        contract.instantiate(&InstantiateMsg {}, None, &[]).unwrap();
+        let response = contract
+            .execute(&ExecuteMsg::<String>::FirstMessage {}, &[])
+            .unwrap();
+
+        let node: Node = daemon.querier();
+        let inspection = node.inspect_tx(response.txhash.clone()).unwrap();
+        assert_eq!(inspection.response.txhash, response.txhash);
+        assert!(matches!(
+            &inspection.messages[..],
+            [DecodedTxMsg::WasmExecute { msg, .. }] if msg == &serde_json::json!({ "first_message": {} })
+        ));
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn contract_history() {
+        use cw_orch_core::environment::{ContractHistoryOperation, TxHandler};
+        use mock_contract::MigrateMsg;
+
+        let daemon = Daemon::builder(networks::LOCAL_JUNO)
+            .is_test(true)
+            .build()
+            .unwrap();
+
+        let contract = mock_contract::MockContract::new("test:mock_contract", daemon.clone());
+        contract.upload().unwrap();
+        let code_id = contract.code_id().unwrap();
+        contract
+            .instantiate(&InstantiateMsg {}, Some(&daemon.sender_addr()), &[])
+            .unwrap();
+        contract
+            .migrate(
+                &MigrateMsg {
+                    t: "success".to_string(),
+                },
+                code_id,
+            )
+            .unwrap();
+
+        let history = contract.as_instance().history().unwrap();
+        let operations: Vec<_> = history.iter().map(|entry| entry.operation).collect();
+        assert_eq!(
+            operations,
+            vec![
+                ContractHistoryOperation::Init,
+                ContractHistoryOperation::Migrate
+            ]
+        );
+        assert!(history.iter().all(|entry| entry.code_id == code_id));
+        assert_eq!(history[1].msg.as_slice(), br#"{"t":"success"}"#);
+    }
 }
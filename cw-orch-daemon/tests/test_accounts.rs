@@ -0,0 +1,59 @@
+mod common;
+#[cfg(feature = "node-tests")]
+mod tests {
+    use bitcoin::secp256k1::Secp256k1;
+    use cosmwasm_std::coins;
+    use cw_orch_core::environment::{BankQuerier, DefaultQueriers, TxHandler};
+    use cw_orch_daemon::{keys::private::PrivateKey, Daemon, DaemonError, TestAccounts};
+    use cw_orch_networks::networks::LOCAL_JUNO;
+
+    const ACCOUNTS_MNEMONIC: &str = "salute trigger antenna west ignore own dance bounce battle soul girl scan test enroll luggage sorry distance traffic brand keen rich syrup wood repair";
+
+    #[test]
+    #[serial_test::serial]
+    fn raw_key_faucet() -> anyhow::Result<()> {
+        super::common::enable_logger();
+        let daemon = Daemon::builder(LOCAL_JUNO).is_test(true).build()?;
+
+        // A faucet signing with a raw key, funded by the test account
+        let mut faucet = daemon.clone();
+        let raw_key = PrivateKey::from_raw_key(
+            &Secp256k1::new(),
+            &[7; 32],
+            0,
+            0,
+            LOCAL_JUNO.network_info.coin_type,
+        )?;
+        faucet.sender_mut().set_private_key(raw_key)?;
+        daemon.bank_send(
+            &faucet.sender_addr(),
+            &coins(1_000_000, LOCAL_JUNO.gas_denom),
+        )?;
+
+        // There is no mnemonic to derive the accounts from
+        assert!(matches!(
+            TestAccounts::generate(1, &faucet, &[]),
+            Err(DaemonError::MissingPhrase)
+        ));
+
+        let accounts = TestAccounts::generate_named_from_mnemonic(
+            ACCOUNTS_MNEMONIC,
+            &["alice"],
+            &faucet,
+            &[],
+        )?;
+        let alice = accounts.by_name("alice").unwrap().sender_addr();
+        let balance = || -> anyhow::Result<u128> {
+            Ok(daemon
+                .bank_querier()
+                .balance(&alice, Some(LOCAL_JUNO.gas_denom.to_string()))?
+                .first()
+                .map(|coin| coin.amount.u128())
+                .unwrap_or_default())
+        };
+        let before = balance()?;
+        accounts.fund(&faucet, &coins(1_000, LOCAL_JUNO.gas_denom))?;
+        assert_eq!(balance()?, before + 1_000);
+        Ok(())
+    }
+}
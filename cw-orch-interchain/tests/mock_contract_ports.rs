@@ -0,0 +1,170 @@
+use cosmwasm_std::{
+    from_json, to_json_binary, to_json_vec, Binary, Deps, DepsMut, Empty, Env,
+    Ibc3ChannelOpenResponse, IbcBasicResponse, IbcChannelCloseMsg, IbcChannelConnectMsg,
+    IbcChannelOpenMsg, IbcChannelOpenResponse, IbcMsg, IbcPacketAckMsg, IbcPacketReceiveMsg,
+    IbcPacketTimeoutMsg, IbcReceiveResponse, IbcTimeout, MessageInfo, Response, StdResult, Storage,
+};
+use cw_orch::{interface, mock::cw_multi_test::ContractWrapper, prelude::*};
+use cw_orch_interchain::prelude::*;
+use serde::{Deserialize, Serialize};
+
+const VERSION: &str = "ping-1";
+
+/// Minimal custom IBC protocol: the contract counts the pings it receives and the acks of the pings it sends
+#[derive(Serialize, Deserialize, Default, Debug, PartialEq)]
+struct PingState {
+    channel: Option<String>,
+    opened: bool,
+    received: u64,
+    acked: u64,
+}
+
+fn load(storage: &dyn Storage) -> PingState {
+    storage
+        .get(b"state")
+        .map(|state| from_json(state).unwrap())
+        .unwrap_or_default()
+}
+
+fn save(storage: &mut dyn Storage, state: &PingState) -> StdResult<()> {
+    storage.set(b"state", &to_json_vec(state)?);
+    Ok(())
+}
+
+fn instantiate(_: DepsMut, _: Env, _: MessageInfo, _: Empty) -> StdResult<Response> {
+    Ok(Response::new())
+}
+
+fn execute(deps: DepsMut, env: Env, _: MessageInfo, _: Empty) -> StdResult<Response> {
+    let state = load(deps.storage);
+    Ok(Response::new().add_message(IbcMsg::SendPacket {
+        channel_id: state.channel.unwrap(),
+        data: Binary::from(b"ping"),
+        timeout: IbcTimeout::with_timestamp(env.block.time.plus_seconds(60)),
+    }))
+}
+
+fn query(deps: Deps, _: Env, _: Empty) -> StdResult<Binary> {
+    to_json_binary(&load(deps.storage))
+}
+
+fn ibc_channel_open(
+    deps: DepsMut,
+    _: Env,
+    msg: IbcChannelOpenMsg,
+) -> StdResult<IbcChannelOpenResponse> {
+    let mut state = load(deps.storage);
+    state.opened = true;
+    save(deps.storage, &state)?;
+    assert_eq!(msg.channel().version, VERSION);
+    Ok(Some(Ibc3ChannelOpenResponse {
+        version: VERSION.to_string(),
+    }))
+}
+
+fn ibc_channel_connect(
+    deps: DepsMut,
+    _: Env,
+    msg: IbcChannelConnectMsg,
+) -> StdResult<IbcBasicResponse> {
+    let mut state = load(deps.storage);
+    state.channel = Some(msg.channel().endpoint.channel_id.clone());
+    save(deps.storage, &state)?;
+    Ok(IbcBasicResponse::new())
+}
+
+fn ibc_channel_close(_: DepsMut, _: Env, _: IbcChannelCloseMsg) -> StdResult<IbcBasicResponse> {
+    Ok(IbcBasicResponse::new())
+}
+
+fn ibc_packet_receive(
+    deps: DepsMut,
+    _: Env,
+    msg: IbcPacketReceiveMsg,
+) -> StdResult<IbcReceiveResponse> {
+    let mut state = load(deps.storage);
+    state.received += 1;
+    save(deps.storage, &state)?;
+    assert_eq!(msg.packet.data, Binary::from(b"ping"));
+    Ok(IbcReceiveResponse::new(Binary::from(b"pong")))
+}
+
+fn ibc_packet_ack(deps: DepsMut, _: Env, msg: IbcPacketAckMsg) -> StdResult<IbcBasicResponse> {
+    let mut state = load(deps.storage);
+    state.acked += 1;
+    save(deps.storage, &state)?;
+    assert_eq!(msg.acknowledgement.data, Binary::from(b"pong"));
+    Ok(IbcBasicResponse::new())
+}
+
+fn ibc_packet_timeout(_: DepsMut, _: Env, _: IbcPacketTimeoutMsg) -> StdResult<IbcBasicResponse> {
+    Ok(IbcBasicResponse::new())
+}
+
+#[interface(Empty, Empty, Empty, Empty)]
+struct Ping;
+
+impl<Chain: CwEnv> Uploadable for Ping<Chain> {
+    fn wrapper() -> Box<dyn MockContract<Empty, Empty>> {
+        Box::new(
+            ContractWrapper::new_with_empty(execute, instantiate, query).with_ibc(
+                ibc_channel_open,
+                ibc_channel_connect,
+                ibc_channel_close,
+                ibc_packet_receive,
+                ibc_packet_ack,
+                ibc_packet_timeout,
+            ),
+        )
+    }
+}
+
+#[test]
+fn contract_ports() -> cw_orch::anyhow::Result<()> {
+    let interchain = MockBech32InterchainEnv::new(vec![("juno-1", "juno"), ("osmosis-1", "osmo")]);
+    let juno = interchain.get_chain("juno-1")?;
+    let osmosis = interchain.get_chain("osmosis-1")?;
+
+    let juno_ping = Ping::new("ping", juno);
+    let osmosis_ping = Ping::new("ping", osmosis);
+    for ping in [&juno_ping, &osmosis_ping] {
+        ping.upload()?;
+        ping.instantiate(&Empty {}, None, &[])?;
+    }
+
+    interchain.create_contract_channel(&juno_ping, &osmosis_ping, VERSION, None)?;
+    let juno_state: PingState = juno_ping.query(&Empty {})?;
+    let osmosis_state: PingState = osmosis_ping.query(&Empty {})?;
+    assert!(juno_state.opened && osmosis_state.opened);
+    assert!(juno_state.channel.is_some() && osmosis_state.channel.is_some());
+
+    let tx = juno_ping.execute(&Empty {}, &[])?;
+    interchain.await_and_check_packets("juno-1", tx)?;
+
+    let juno_state: PingState = juno_ping.query(&Empty {})?;
+    let osmosis_state: PingState = osmosis_ping.query(&Empty {})?;
+    assert_eq!((juno_state.received, juno_state.acked), (0, 1));
+    assert_eq!((osmosis_state.received, osmosis_state.acked), (1, 0));
+    Ok(())
+}
+
+#[test]
+fn missing_contract_port() -> cw_orch::anyhow::Result<()> {
+    let interchain = MockBech32InterchainEnv::new(vec![("juno-1", "juno"), ("osmosis-1", "osmo")]);
+    let osmosis = interchain.get_chain("osmosis-1")?;
+    let port: PortId = format!("wasm.{}", osmosis.addr_make("missing")).parse()?;
+
+    let err = interchain
+        .create_channel(
+            "juno-1",
+            "osmosis-1",
+            &PortId::transfer(),
+            &port,
+            VERSION,
+            None,
+        )
+        .err()
+        .unwrap();
+    assert!(err.to_string().contains("No contract bound to port"));
+    Ok(())
+}
@@ -3,7 +3,7 @@
 #![deny(missing_docs)]
 
 // macros
-pub use cw_orch_contract_derive::interface;
+pub use cw_orch_contract_derive::{interface, uploadable};
 pub use cw_orch_fns_derive::{ExecuteFns, QueryFns};
 
 // prelude
@@ -17,6 +17,10 @@ mod error;
 #[cfg(feature = "daemon")]
 pub mod daemon;
 
+#[cfg(not(target_arch = "wasm32"))]
+#[cfg(feature = "daemon")]
+pub mod script_runner;
+
 #[cfg(not(target_arch = "wasm32"))]
 #[cfg(feature = "snapshot-testing")]
 pub mod snapshots;
@@ -17,18 +17,19 @@ pub use crate::contract::interface_traits::{
     InstantiableContract, MigratableContract, QueryableContract, Uploadable,
 };
 
-pub use cw_orch_core::contract::Deploy;
+pub use cw_orch_core::contract::{AddrOf, Deploy, MigrationTester};
 
 pub use crate::environment::ChainState;
 pub use crate::environment::StateInterface;
 
 // Response trait
-pub use crate::environment::IndexResponse;
+pub use crate::environment::{IndexResponse, TxAnalysis};
 
 // Environment
 pub use crate::environment::{
-    BankQuerier, BankSetter, CwEnv, DefaultQueriers, EnvironmentInfo, EnvironmentQuerier,
-    NodeQuerier, QuerierGetter, QueryHandler, TxHandler, TxResponse, WasmQuerier,
+    AddressBookGetter, BankQuerier, BankSetter, CwEnv, DefaultQueriers, EnvironmentInfo,
+    EnvironmentQuerier, NodeQuerier, QuerierGetter, QueryHandler, TxHandler, TxResponse,
+    WasmQuerier,
 };
 
 pub use cw_orch_core::environment::Environment;
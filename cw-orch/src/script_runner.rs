@@ -0,0 +1,135 @@
+//! Resumable deployment scripts.
+//!
+//! A [`ScriptRunner`] runs named steps in order and records every completed step in the daemon state file.
+//! When a script fails halfway, running it again skips the steps that already completed.
+//!
+//! ```rust,no_run
+//! use cw_orch::{daemon::{networks, Daemon}, script_runner::ScriptRunner};
+//!
+//! let daemon = Daemon::builder(networks::PION_1).build().unwrap();
+//! ScriptRunner::new("deploy-v2", &daemon)
+//!     .step("upload", |_daemon| {
+//!         // upload the contracts
+//!         Ok(())
+//!     })
+//!     .step("instantiate", |_daemon| {
+//!         // instantiate the contracts
+//!         Ok(())
+//!     })
+//!     .run()
+//!     .unwrap();
+//! ```
+use cw_orch_core::{environment::ChainState, log::local_target};
+use cw_orch_daemon::DaemonBase;
+
+use crate::error::CwOrchError;
+
+/// Key of the completed steps in the state file
+const CHECKPOINTS_KEY: &str = "checkpoints";
+const PROGRESS_BAR_WIDTH: usize = 20;
+
+type Step<'a, Sender> = Box<dyn FnOnce(&DaemonBase<Sender>) -> Result<(), CwOrchError> + 'a>;
+
+/// Runs the steps of a deployment script, skipping the ones completed in a previous run.
+/// See the [module documentation](self) for an example.
+pub struct ScriptRunner<'a, Sender> {
+    name: String,
+    daemon: &'a DaemonBase<Sender>,
+    steps: Vec<(String, Step<'a, Sender>)>,
+}
+
+impl<'a, Sender> ScriptRunner<'a, Sender> {
+    /// Creates a new script. The `name` identifies the script in the state file.
+    pub fn new(name: impl Into<String>, daemon: &'a DaemonBase<Sender>) -> Self {
+        Self {
+            name: name.into(),
+            daemon,
+            steps: vec![],
+        }
+    }
+
+    /// Adds a step to the script. Step names must be unique within the script.
+    pub fn step(
+        mut self,
+        name: impl Into<String>,
+        step: impl FnOnce(&DaemonBase<Sender>) -> Result<(), CwOrchError> + 'a,
+    ) -> Self {
+        self.steps.push((name.into(), Box::new(step)));
+        self
+    }
+
+    /// Returns the steps completed in previous runs
+    pub fn completed_steps(&self) -> Vec<String> {
+        let checkpoints = self.daemon.state().get(CHECKPOINTS_KEY).unwrap_or_default();
+        serde_json::from_value(checkpoints[&self.name].clone()).unwrap_or_default()
+    }
+
+    /// Forgets the completed steps, so that the next run starts from the beginning
+    pub fn reset(&self) -> Result<(), CwOrchError> {
+        self.daemon
+            .state()
+            .remove(CHECKPOINTS_KEY, &self.name)
+            .map_err(Into::into)
+    }
+
+    /// Runs the steps that were not completed yet, in order.
+    /// Stops at the first failing step, which is run again on the next run.
+    pub fn run(self) -> Result<(), CwOrchError> {
+        let mut completed = self.completed_steps();
+        let total = self.steps.len();
+
+        for (index, (name, step)) in self.steps.into_iter().enumerate() {
+            if completed.contains(&name) {
+                log::info!(
+                    target: &local_target(),
+                    "{} [{}] skipped, completed in a previous run",
+                    progress_bar(index + 1, total),
+                    name
+                );
+                continue;
+            }
+
+            log::info!(
+                target: &local_target(),
+                "{} [{}] running",
+                progress_bar(index, total),
+                name
+            );
+            step(self.daemon)?;
+
+            completed.push(name.clone());
+            self.daemon
+                .state()
+                .set(CHECKPOINTS_KEY, &self.name, &completed)?;
+            log::info!(
+                target: &local_target(),
+                "{} [{}] done",
+                progress_bar(index + 1, total),
+                name
+            );
+        }
+        Ok(())
+    }
+}
+
+fn progress_bar(done: usize, total: usize) -> String {
+    let filled = (done * PROGRESS_BAR_WIDTH).checked_div(total).unwrap_or(0);
+    format!(
+        "[{}{}] {done}/{total}",
+        "#".repeat(filled),
+        "-".repeat(PROGRESS_BAR_WIDTH - filled)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::progress_bar;
+
+    #[test]
+    fn renders_progress() {
+        assert_eq!(progress_bar(0, 4), "[--------------------] 0/4");
+        assert_eq!(progress_bar(1, 4), "[#####---------------] 1/4");
+        assert_eq!(progress_bar(4, 4), "[####################] 4/4");
+        assert_eq!(progress_bar(0, 0), "[--------------------] 0/0");
+    }
+}
@@ -1,6 +1,13 @@
 //! Defined the snapshot testing macro on the Mock environment
 //! This is included here and not in the mock package because it needs to import traits to work
 
+use cw_orch_core::{
+    contract::interface_traits::{ContractInstance, CwOrchQuery},
+    environment::{ChainState, QueryHandler, StateInterface, TxHandler},
+    CwEnvError,
+};
+use serde_json::Value;
+
 /// Function helper used to parse storage into readable strings
 pub fn parse_storage(storage: &[(Vec<u8>, Vec<u8>)]) -> Vec<(String, String)> {
     storage
@@ -47,6 +54,74 @@ macro_rules! take_storage_snapshot {
     };
 }
 
+/// Queries `contract` and replaces the addresses known by its environment with placeholders,
+/// so that the response doesn't depend on the addresses generated by the environment.
+/// The contract addresses become `[contract:<contract-id>]` and the sender address `[sender]`.
+pub fn normalized_query<Chain: TxHandler + QueryHandler, T: CwOrchQuery<Chain>>(
+    contract: &T,
+    query_msg: &T::QueryMsg,
+) -> Result<Value, CwEnvError> {
+    let response: Value = contract.query(query_msg)?;
+    let chain = contract.environment();
+
+    let mut placeholders: Vec<(String, String)> = chain
+        .state()
+        .get_all_addresses()?
+        .into_iter()
+        .map(|(id, address)| (address.to_string(), format!("[contract:{id}]")))
+        .collect();
+    placeholders.push((chain.sender_addr().to_string(), "[sender]".to_string()));
+    placeholders.retain(|(address, _)| !address.is_empty());
+    // Longest addresses first, in case an address contains another one
+    placeholders.sort_by(|(a, _), (b, _)| b.len().cmp(&a.len()));
+
+    Ok(replace_addresses(response, &placeholders))
+}
+
+/// Replaces the addresses in the keys and string values of `value`
+fn replace_addresses(value: Value, placeholders: &[(String, String)]) -> Value {
+    let replace = |s: String| {
+        placeholders.iter().fold(s, |s, (address, placeholder)| {
+            s.replace(address, placeholder)
+        })
+    };
+    match value {
+        Value::String(s) => Value::String(replace(s)),
+        Value::Array(values) => Value::Array(
+            values
+                .into_iter()
+                .map(|value| replace_addresses(value, placeholders))
+                .collect(),
+        ),
+        Value::Object(entries) => Value::Object(
+            entries
+                .into_iter()
+                .map(|(key, value)| (replace(key), replace_addresses(value, placeholders)))
+                .collect(),
+        ),
+        value => value,
+    }
+}
+
+/// Snapshots the response of a query, with the addresses replaced by placeholders (see [`normalized_query`]).
+/// Works on all the environments, which allows comparing the responses on Mock and on forked state.
+/// This should ONLY be used when cw_orch is in scope
+/// Usage:
+/// ```rust,ignore
+/// take_query_snapshot!(contract, QueryMsg::Config {}, "config");
+/// ```
+/// The name you input to the function should be different from all other snapshots in your repository
+/// This function will panic if the snapshot is different from the reference snapshot
+#[macro_export]
+macro_rules! take_query_snapshot {
+    ($contract: expr, $query_msg: expr, $name: literal) => {
+        ::cw_orch::insta::assert_yaml_snapshot!(
+            ::cw_orch::sanitize_filename::sanitize(format!("{}", $name)),
+            ::cw_orch::snapshots::normalized_query(&$contract, &$query_msg)?
+        )
+    };
+}
+
 #[cfg(test)]
 mod tests {
     use crate::mock::cw_multi_test::ContractWrapper;
@@ -144,4 +219,54 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn query_snapshot() -> anyhow::Result<()> {
+        use counter_contract::CounterExecuteMsgFns;
+        let chain = Mock::new("sender");
+
+        let contract = counter_contract::CounterContract::new(chain.clone());
+        contract.upload()?;
+        contract.instantiate(
+            &counter_contract::msg::InstantiateMsg { count: 0 },
+            None,
+            &[],
+        )?;
+        contract.increment()?;
+
+        take_query_snapshot!(
+            contract,
+            counter_contract::msg::QueryMsg::GetCount {},
+            "query_snapshot_test"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn replaces_addresses() {
+        let placeholders = vec![
+            (
+                "cosmwasm1contract".to_string(),
+                "[contract:counter]".to_string(),
+            ),
+            ("cosmwasm1sender".to_string(), "[sender]".to_string()),
+        ];
+        let value = serde_json::json!({
+            "owner": "cosmwasm1sender",
+            "balances": [["cosmwasm1contract", "10"]],
+            "cosmwasm1sender": "wasm.cosmwasm1contract",
+            "count": 1
+        });
+
+        assert_eq!(
+            super::replace_addresses(value, &placeholders),
+            serde_json::json!({
+                "owner": "[sender]",
+                "balances": [["[contract:counter]", "10"]],
+                "[sender]": "wasm.[contract:counter]",
+                "count": 1
+            })
+        );
+    }
 }
@@ -0,0 +1,73 @@
+use cw_orch::prelude::*;
+use mock_contract::{ExecuteMsg, InstantiateMsg, MigrateMsg, MockContract, QueryMsg};
+use mock_contract_u64::interface::MockContract as MockContractU64;
+
+fn migrate_msg(t: &str) -> MigrateMsg {
+    MigrateMsg { t: t.to_string() }
+}
+
+#[test]
+fn migrates_up_and_down() -> Result<(), CwOrchError> {
+    let chain = Mock::new("sender");
+    let old = MockContract::new("mock-contract-v1", chain.clone());
+    let new = MockContractU64::<_, u64, u64>::new(chain.clone());
+
+    let tester = MigrationTester::new(old, new);
+    tester
+        .deploy(&InstantiateMsg {})?
+        .populate(|old| old.execute(&ExecuteMsg::FirstMessage {}, &[]).map(|_| ()))?
+        .migrate(&migrate_msg("success"))?
+        .check(|new| {
+            let response: String = new.query(&QueryMsg::<u64>::FirstQuery {})?;
+            assert_eq!(response, "first query passed");
+            Ok(())
+        })?
+        .migrate_down(&migrate_msg("success"), |old| {
+            let info = old
+                .environment()
+                .wasm_querier()
+                .contract_info(&old.address()?)?;
+            assert_eq!(info.code_id, 1);
+            Ok(())
+        })?;
+
+    assert_eq!(tester.new_version().address()?, tester.old().address()?);
+    assert_eq!(tester.new_version().code_id()?, 2);
+    Ok(())
+}
+
+#[test]
+fn failed_migration_errors() -> Result<(), CwOrchError> {
+    let chain = Mock::new("sender");
+    let tester = MigrationTester::new(
+        MockContract::new("mock-contract", chain.clone()),
+        MockContractU64::<_, u64, u64>::new(chain),
+    );
+
+    tester.deploy(&InstantiateMsg {})?;
+    assert!(tester.migrate(&migrate_msg("fail")).is_err());
+    Ok(())
+}
+
+#[test]
+fn shared_contract_id() -> Result<(), CwOrchError> {
+    let chain = Mock::new("sender");
+    let tester = MigrationTester::new(
+        MockContract::new("mock-contract", chain.clone()),
+        MockContractU64::<_, u64, u64>::new(chain.clone()),
+    );
+
+    // The code id of the previous version is kept when the new version overwrites the contract id
+    tester.deploy(&InstantiateMsg {})?;
+    tester.migrate(&migrate_msg("success"))?;
+    assert_eq!(tester.new_version().code_id()?, 2);
+    assert_eq!(tester.old_code_id()?, 1);
+
+    tester.migrate_down(&migrate_msg("success"), |old| {
+        let info = chain.wasm_querier().contract_info(&old.address()?)?;
+        assert_eq!(info.code_id, 1);
+        Ok(())
+    })?;
+    assert_eq!(tester.old().code_id()?, 1);
+    Ok(())
+}
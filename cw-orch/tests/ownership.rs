@@ -0,0 +1,115 @@
+use cosmwasm_std::{
+    from_json, to_json_binary, to_json_vec, Binary, Deps, DepsMut, Empty, Env, MessageInfo,
+    Response, StdError, StdResult,
+};
+use cw_orch::core::contract::{Ownership, OwnershipAction};
+use cw_orch::interface;
+use cw_orch::prelude::*;
+
+const OWNERSHIP_KEY: &[u8] = b"ownership";
+
+#[cosmwasm_schema::cw_serde]
+#[derive(cw_orch::ExecuteFns)]
+pub enum ExecuteMsg {
+    UpdateOwnership(OwnershipAction),
+}
+
+#[cosmwasm_schema::cw_serde]
+#[derive(cosmwasm_schema::QueryResponses, cw_orch::QueryFns)]
+pub enum QueryMsg {
+    #[returns(Ownership)]
+    Ownership {},
+}
+
+fn load(deps: Deps) -> StdResult<Ownership> {
+    from_json(deps.storage.get(OWNERSHIP_KEY).unwrap())
+}
+
+fn instantiate(deps: DepsMut, _env: Env, info: MessageInfo, _msg: Empty) -> StdResult<Response> {
+    let ownership = Ownership {
+        owner: Some(info.sender),
+        pending_owner: None,
+        pending_expiry: None,
+    };
+    deps.storage.set(OWNERSHIP_KEY, &to_json_vec(&ownership)?);
+    Ok(Response::new())
+}
+
+// Minimal cw-ownable implementation, without the expirations
+fn execute(deps: DepsMut, _env: Env, info: MessageInfo, msg: ExecuteMsg) -> StdResult<Response> {
+    let ExecuteMsg::UpdateOwnership(action) = msg;
+    let mut ownership = load(deps.as_ref())?;
+    match action {
+        OwnershipAction::TransferOwnership { new_owner, expiry } => {
+            if ownership.owner.as_ref() != Some(&info.sender) {
+                return Err(StdError::generic_err("not the owner"));
+            }
+            ownership.pending_owner = Some(deps.api.addr_validate(&new_owner)?);
+            ownership.pending_expiry = expiry;
+        }
+        OwnershipAction::AcceptOwnership => {
+            if ownership.pending_owner.as_ref() != Some(&info.sender) {
+                return Err(StdError::generic_err("not the pending owner"));
+            }
+            ownership.owner = ownership.pending_owner.take();
+            ownership.pending_expiry = None;
+        }
+        OwnershipAction::RenounceOwnership => {
+            if ownership.owner.as_ref() != Some(&info.sender) {
+                return Err(StdError::generic_err("not the owner"));
+            }
+            ownership.owner = None;
+        }
+    }
+    deps.storage.set(OWNERSHIP_KEY, &to_json_vec(&ownership)?);
+    Ok(Response::new())
+}
+
+fn query(deps: Deps, _env: Env, _msg: QueryMsg) -> StdResult<Binary> {
+    to_json_binary(&load(deps)?)
+}
+
+#[interface(Empty, ExecuteMsg, QueryMsg, Empty)]
+pub struct Ownable;
+
+impl<Chain> Uploadable for Ownable<Chain> {
+    fn wrapper() -> <Mock as TxHandler>::ContractSource {
+        Box::new(ContractWrapper::new_with_empty(execute, instantiate, query))
+    }
+}
+
+#[test]
+fn transfers_ownership() -> anyhow::Result<()> {
+    let chain = Mock::new("owner");
+    let contract = Ownable::new("ownable", chain.clone());
+    contract.upload()?;
+    contract.instantiate(&Empty {}, None, &[])?;
+    assert_eq!(contract.ownership()?.owner, Some(chain.sender_addr()));
+
+    let new_owner = chain.addr_make("new_owner");
+    contract.transfer_ownership(&new_owner, None)?;
+    assert_eq!(contract.ownership()?.pending_owner, Some(new_owner.clone()));
+
+    // Only the proposed owner can accept
+    assert!(contract.accept_ownership().is_err());
+    contract.call_as(&new_owner).accept_ownership()?;
+    let ownership = contract.as_instance().ownership()?;
+    assert_eq!(ownership.owner, Some(new_owner.clone()));
+    assert_eq!(ownership.pending_owner, None);
+
+    contract.call_as(&new_owner).renounce_ownership()?;
+    assert_eq!(contract.ownership()?.owner, None);
+    Ok(())
+}
+
+// The async environments get the `_async` ownership functions
+#[allow(dead_code)]
+async fn transfers_ownership_async<Chain: cw_orch::core::environment::AsyncTxHandler>(
+    contract: &Ownable<Chain>,
+    new_owner: &Addr,
+) -> anyhow::Result<()> {
+    contract.transfer_ownership_async(new_owner, None).await?;
+    contract.accept_ownership_async().await?;
+    contract.renounce_ownership_async().await?;
+    Ok(())
+}
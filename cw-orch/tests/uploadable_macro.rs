@@ -0,0 +1,32 @@
+use cw_orch::prelude::*;
+use cw_orch::{interface, uploadable};
+
+use mock_contract::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
+
+#[uploadable(mock_contract, migrate)]
+#[interface(InstantiateMsg, ExecuteMsg, QueryMsg, MigrateMsg)]
+pub struct MockContract;
+
+#[test]
+fn generated_wrapper() {
+    let chain = Mock::new("Ghazshag");
+    let contract = MockContract::new("test:mock_contract", chain.clone());
+    contract.upload().unwrap();
+
+    contract
+        .instantiate(&InstantiateMsg {}, Some(&chain.sender_addr()), &[])
+        .unwrap();
+    contract.execute(&ExecuteMsg::FirstMessage {}, &[]).unwrap();
+    let response: String = contract.query(&QueryMsg::FirstQuery {}).unwrap();
+    assert_eq!(response, "first query passed");
+
+    // The migrate entry point is registered on the wrapper
+    contract
+        .migrate(
+            &MigrateMsg {
+                t: "success".to_string(),
+            },
+            contract.code_id().unwrap(),
+        )
+        .unwrap();
+}
@@ -7,7 +7,7 @@ use clone_cw_multi_test::{
 };
 use cosmwasm_std::{
     to_json_binary, Addr, BankMsg, Binary, Coin, CosmosMsg, Empty, Event, StdError, StdResult,
-    Uint128, WasmMsg,
+    Storage, Uint128, WasmMsg,
 };
 use cw_orch_core::{
     contract::interface_traits::{ContractInstance, Uploadable},
@@ -82,6 +82,30 @@ impl CloneTesting {
         self.app.borrow_mut().next_address()
     }
 
+    /// Overwrites raw storage entries of a contract, e.g. to restore the state it had at a past height
+    pub fn set_contract_state(&self, contract_address: &Addr, entries: &[(Binary, Binary)]) {
+        let mut app = self.app.borrow_mut();
+        let mut storage = app.contract_storage_mut(contract_address);
+        for (key, value) in entries {
+            storage.set(key, value);
+        }
+    }
+
+    /// Replaces the whole raw storage of a contract with `entries`, removing the entries that are not part of it
+    pub fn replace_contract_state(&self, contract_address: &Addr, entries: &[(Binary, Binary)]) {
+        let mut app = self.app.borrow_mut();
+        let mut storage = app.contract_storage_mut(contract_address);
+        let keys: Vec<Vec<u8>> = storage
+            .range_keys(None, None, cosmwasm_std::Order::Ascending)
+            .collect();
+        for key in keys {
+            storage.remove(&key);
+        }
+        for (key, value) in entries {
+            storage.set(key, value);
+        }
+    }
+
     /// Set the bank balance of an address.
     pub fn set_balance(
         &self,
@@ -430,6 +454,49 @@ impl<S: StateInterface> TxHandler for CloneTesting<S> {
             .map_err(From::from)
             .map(Into::into)
     }
+
+    fn update_admin(
+        &self,
+        contract_address: &Addr,
+        new_admin: &Addr,
+    ) -> Result<Self::Response, Self::Error> {
+        self.app
+            .borrow_mut()
+            .execute(
+                self.sender.clone(),
+                CosmosMsg::Wasm(WasmMsg::UpdateAdmin {
+                    contract_addr: contract_address.to_string(),
+                    admin: new_admin.to_string(),
+                }),
+            )
+            .map_err(From::from)
+            .map(Into::into)
+    }
+
+    fn clear_admin(&self, contract_address: &Addr) -> Result<Self::Response, Self::Error> {
+        self.app
+            .borrow_mut()
+            .execute(
+                self.sender.clone(),
+                CosmosMsg::Wasm(WasmMsg::ClearAdmin {
+                    contract_addr: contract_address.to_string(),
+                }),
+            )
+            .map_err(From::from)
+            .map(Into::into)
+    }
+
+    fn sudo<S: Serialize + Debug>(
+        &self,
+        sudo_msg: &S,
+        contract_address: &Addr,
+    ) -> Result<Self::Response, Self::Error> {
+        self.app
+            .borrow_mut()
+            .wasm_sudo(contract_address.clone(), sudo_msg)
+            .map_err(From::from)
+            .map(Into::into)
+    }
 }
 
 /// Custom AppResponse type for working with the IndexResponse trait
@@ -720,4 +787,39 @@ mod test {
             .contains_all_of(&[&Coin::new(amount, denom_1), &Coin::new(amount, denom_2)]);
         Ok(())
     }
+
+    #[test]
+    fn replace_contract_state() -> anyhow::Result<()> {
+        let chain = CloneTesting::new(JUNO_1)?;
+        let sender = chain.sender_addr();
+
+        chain.upload(&MockCw20)?;
+        let code_id = (1 + LOCAL_RUST_CODE_OFFSET) as u64;
+        let init_msg = cw20_base::msg::InstantiateMsg {
+            name: String::from("Token"),
+            symbol: String::from("TOK"),
+            decimals: 6u8,
+            initial_balances: vec![],
+            mint: None,
+            marketing: None,
+        };
+        let init_res = chain.instantiate(code_id, &init_msg, None, Some(&sender), &[])?;
+        let contract_address = Addr::unchecked(&init_res.events[0].attributes[0].value);
+        assert!(chain
+            .app
+            .borrow()
+            .contract_storage(&contract_address)
+            .get(b"token_info")
+            .is_some());
+
+        let entries = vec![(Binary::from(b"restored"), Binary::from(b"value"))];
+        chain.replace_contract_state(&contract_address, &entries);
+
+        // Only the restored entries are left
+        let app = chain.app.borrow();
+        let storage = app.contract_storage(&contract_address);
+        assert_eq!(storage.get(b"restored"), Some(b"value".to_vec()));
+        assert_eq!(storage.get(b"token_info"), None);
+        Ok(())
+    }
 }
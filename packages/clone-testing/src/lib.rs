@@ -4,6 +4,7 @@
 mod contract_instance;
 mod core;
 pub mod queriers;
+pub mod replay;
 mod state;
 
 pub use self::core::CloneTesting;
@@ -0,0 +1,250 @@
+//! Deterministic replay of historical transactions on a fork.
+//!
+//! [`TxReplay`] fetches the transactions that executed a contract on a live chain and replays their execute messages on a [`CloneTesting`] fork.
+//! Before replaying a transaction, the raw state of the contract is restored to the one it had in the block before the transaction
+//! and the block of the fork is set to the block of the transaction, so that the contract runs like it did on chain,
+//! with all the debugging tools of the local environment (logs, breakpoints, `dbg!`...).
+//!
+//! Restoring the state of past blocks requires an archive node. Only the state of the replayed contract is restored,
+//! the other contracts it queries are read at the height of the fork.
+//! The storage of the contract on the fork is replaced by the restored entries, so the entries created after the replayed block are removed.
+//!
+//! ```rust,no_run
+//! use cosmwasm_std::Addr;
+//! use cw_orch_clone_testing::{replay::TxReplay, CloneTesting};
+//! use cw_orch_daemon::{networks::JUNO_1, Daemon};
+//!
+//! let daemon = Daemon::builder(JUNO_1).build().unwrap();
+//! let replay = TxReplay::fetch(&daemon, &Addr::unchecked("juno1...")).unwrap();
+//!
+//! let fork = CloneTesting::new(JUNO_1).unwrap();
+//! let results = replay.replay_tx(&fork, &replay.txs()[0].txhash).unwrap();
+//! ```
+use cosmrs::proto::cosmos::tx::v1beta1::OrderBy;
+use cosmwasm_std::{Addr, Coin, Timestamp};
+use cw_orch_core::{environment::TxHandler, CwEnvError};
+use cw_orch_daemon::{
+    queriers::{CosmWasm, Node},
+    DecodedTxMsg, QuerySender, TxInspection,
+};
+use tokio::runtime::Handle;
+
+use crate::{core::AppResponse, CloneTesting};
+
+/// Execute message sent to the contract in a recorded transaction
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecordedCall {
+    /// Sender of the message
+    pub sender: Addr,
+    /// Execute message, as sent on chain
+    pub msg: serde_json::Value,
+    /// Funds attached to the message
+    pub funds: Vec<Coin>,
+}
+
+/// Transaction that executed the contract on chain
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecordedTx {
+    /// Hash of the transaction
+    pub txhash: String,
+    /// Height of the block including the transaction
+    pub height: u64,
+    /// Time of the block including the transaction
+    pub time: Timestamp,
+    /// Execute messages sent to the contract, in order
+    pub calls: Vec<RecordedCall>,
+}
+
+impl RecordedTx {
+    /// Keeps the execute messages of `inspection` sent to `contract`
+    pub fn new(inspection: TxInspection, contract: &Addr) -> Self {
+        let timestamp = inspection.response.timestamp;
+        Self {
+            txhash: inspection.response.txhash,
+            height: inspection.response.height,
+            time: Timestamp::from_seconds(timestamp.timestamp() as u64)
+                .plus_nanos(timestamp.timestamp_subsec_nanos() as u64),
+            calls: inspection
+                .messages
+                .into_iter()
+                .filter_map(|msg| match msg {
+                    DecodedTxMsg::WasmExecute {
+                        sender,
+                        contract: executed,
+                        msg,
+                        funds,
+                    } if executed == contract.as_str() => Some(RecordedCall {
+                        sender: Addr::unchecked(sender),
+                        msg,
+                        funds,
+                    }),
+                    _ => None,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Transactions of a contract fetched from a live chain, to replay on a fork
+pub struct TxReplay {
+    contract: Addr,
+    txs: Vec<RecordedTx>,
+    cosmwasm: CosmWasm,
+    rt_handle: Handle,
+}
+
+impl TxReplay {
+    /// Fetches all the successful transactions executing `contract`, oldest first
+    pub fn fetch<Sender: QuerySender>(
+        daemon: &cw_orch_daemon::DaemonBase<Sender>,
+        contract: &Addr,
+    ) -> Result<Self, CwEnvError> {
+        let node = Node::new(daemon);
+        let rt_handle = daemon.rt_handle.clone();
+        let responses = rt_handle.block_on(node._find_all_tx_by_events(
+            vec![format!("execute._contract_address='{contract}'")],
+            OrderBy::Asc,
+        ))?;
+        let hashes = responses
+            .into_iter()
+            .filter(|response| response.code == 0)
+            .map(|response| response.txhash)
+            .collect();
+        Self::from_hashes(daemon, contract, hashes)
+    }
+
+    /// Fetches the transactions `hashes`, keeping their execute messages sent to `contract`.
+    /// The transactions are sorted by height, oldest first.
+    pub fn from_hashes<Sender: QuerySender>(
+        daemon: &cw_orch_daemon::DaemonBase<Sender>,
+        contract: &Addr,
+        hashes: Vec<String>,
+    ) -> Result<Self, CwEnvError> {
+        let node = Node::new(daemon);
+        let rt_handle = daemon.rt_handle.clone();
+        let mut txs = hashes
+            .into_iter()
+            .map(|hash| {
+                let inspection = rt_handle.block_on(node._inspect_tx(hash))?;
+                Ok(RecordedTx::new(inspection, contract))
+            })
+            .collect::<Result<Vec<_>, CwEnvError>>()?;
+        txs.retain(|tx| !tx.calls.is_empty());
+        // Stable sort, the transactions of a block keep their order
+        txs.sort_by_key(|tx| tx.height);
+        Ok(Self {
+            contract: contract.clone(),
+            txs,
+            cosmwasm: CosmWasm::new_sync(daemon.channel(), &rt_handle),
+            rt_handle,
+        })
+    }
+
+    /// Contract whose transactions are replayed
+    pub fn contract(&self) -> &Addr {
+        &self.contract
+    }
+
+    /// Recorded transactions, oldest first
+    pub fn txs(&self) -> &[RecordedTx] {
+        &self.txs
+    }
+
+    /// Replays the transaction `txhash` on `fork`, from the state of the contract in the block before it.
+    /// The senders are impersonated and funded with the coins they attached.
+    /// Returns the result of each execute message of the transaction.
+    pub fn replay_tx(
+        &self,
+        fork: &CloneTesting,
+        txhash: &str,
+    ) -> Result<Vec<Result<AppResponse, CwEnvError>>, CwEnvError> {
+        let tx = self
+            .txs
+            .iter()
+            .find(|tx| tx.txhash == txhash)
+            .ok_or_else(|| CwEnvError::StdErr(format!("Transaction {txhash} wasn't recorded")))?;
+
+        let height = tx.height.checked_sub(1).ok_or_else(|| {
+            CwEnvError::StdErr(format!(
+                "Transaction {txhash} is in the first block, there is no state to restore"
+            ))
+        })?;
+        let state = self.rt_handle.block_on(
+            self.cosmwasm
+                ._all_contract_state_entries_at_height(&self.contract, height),
+        )?;
+        fork.replace_contract_state(&self.contract, &state);
+        self.replay_calls(fork, tx)
+    }
+
+    /// Replays all the recorded transactions on `fork` in order, from the state of the contract before the first one.
+    /// Returns the result of each execute message, by transaction hash.
+    pub fn replay_all(
+        &self,
+        fork: &CloneTesting,
+    ) -> Result<Vec<(String, Vec<Result<AppResponse, CwEnvError>>)>, CwEnvError> {
+        let Some(first) = self.txs.first() else {
+            return Ok(vec![]);
+        };
+        let mut results = vec![(first.txhash.clone(), self.replay_tx(fork, &first.txhash)?)];
+        for tx in &self.txs[1..] {
+            results.push((tx.txhash.clone(), self.replay_calls(fork, tx)?));
+        }
+        Ok(results)
+    }
+
+    fn replay_calls(
+        &self,
+        fork: &CloneTesting,
+        tx: &RecordedTx,
+    ) -> Result<Vec<Result<AppResponse, CwEnvError>>, CwEnvError> {
+        fork.app.borrow_mut().update_block(|block| {
+            block.height = tx.height;
+            block.time = tx.time;
+        });
+        tx.calls
+            .iter()
+            .map(|call| {
+                if !call.funds.is_empty() {
+                    fork.add_balance(&call.sender, call.funds.clone())?;
+                }
+                let mut sender_fork = fork.clone();
+                sender_fork.sender = call.sender.clone();
+                Ok(sender_fork.execute(&call.msg, &call.funds, &self.contract))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use cw_orch_daemon::CosmTxResponse;
+
+    use super::*;
+
+    #[test]
+    fn keeps_the_calls_to_the_contract() {
+        let contract = Addr::unchecked("contract");
+        let call = |executed: &str| DecodedTxMsg::WasmExecute {
+            sender: "sender".to_string(),
+            contract: executed.to_string(),
+            msg: serde_json::json!({ "increment": {} }),
+            funds: vec![],
+        };
+        let inspection = TxInspection {
+            response: CosmTxResponse {
+                txhash: "HASH".to_string(),
+                height: 10,
+                ..Default::default()
+            },
+            memo: String::new(),
+            messages: vec![call("contract"), call("other"), call("contract")],
+        };
+
+        let tx = RecordedTx::new(inspection, &contract);
+        assert_eq!(tx.txhash, "HASH");
+        assert_eq!(tx.height, 10);
+        assert_eq!(tx.calls.len(), 2);
+        assert_eq!(tx.calls[0].sender, Addr::unchecked("sender"));
+    }
+}
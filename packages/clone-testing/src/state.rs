@@ -14,6 +14,8 @@ pub struct MockState {
     pub code_ids: HashMap<String, u64>,
     /// Deployed contract addresses
     pub addresses: HashMap<String, Addr>,
+    /// Admins of the contracts, `None` once cleared
+    pub admins: HashMap<String, Option<Addr>>,
     /// State read from file. Used to actually integrate with actual deployments
     pub daemon_state: DaemonState,
 }
@@ -24,6 +26,7 @@ impl MockState {
         Self {
             addresses: HashMap::new(),
             code_ids: HashMap::new(),
+            admins: HashMap::new(),
             daemon_state: DaemonState::new(
                 DaemonState::state_file_path().unwrap(),
                 &Arc::new(chain),
@@ -96,6 +99,18 @@ impl StateInterface for MockState {
             .unique()
             .collect())
     }
+
+    fn get_admin(&self, contract_id: &str) -> Option<Addr> {
+        // Admins changed on the fork take precedence over the daemon state
+        match self.admins.get(contract_id) {
+            Some(admin) => admin.clone(),
+            None => self.daemon_state.get_admin(contract_id),
+        }
+    }
+
+    fn set_admin(&mut self, contract_id: &str, admin: Option<&Addr>) {
+        self.admins.insert(contract_id.to_string(), admin.cloned());
+    }
 }
 
 #[cfg(test)]
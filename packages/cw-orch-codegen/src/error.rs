@@ -0,0 +1,13 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum CodegenError {
+    #[error(transparent)]
+    IOErr(#[from] ::std::io::Error),
+    #[error(transparent)]
+    SerdeJson(#[from] ::serde_json::Error),
+    #[error("Unsupported schema for {0}: {1}")]
+    UnsupportedSchema(String, String),
+    #[error("Missing response schema for query {0}")]
+    MissingResponse(String),
+}
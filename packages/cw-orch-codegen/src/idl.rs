@@ -0,0 +1,34 @@
+use std::collections::BTreeMap;
+
+use serde::Deserialize;
+use serde_json::Value;
+
+/// Contract schema generated by `cosmwasm_schema::write_api!` (`cargo schema`)
+#[derive(Debug, Clone, Deserialize)]
+pub struct ContractIdl {
+    /// Name of the contract crate
+    pub contract_name: String,
+    /// Version of the contract crate
+    pub contract_version: String,
+    /// Schema of the instantiate message
+    pub instantiate: Value,
+    /// Schema of the execute message
+    #[serde(default)]
+    pub execute: Option<Value>,
+    /// Schema of the query message
+    #[serde(default)]
+    pub query: Option<Value>,
+    /// Schema of the migrate message
+    #[serde(default)]
+    pub migrate: Option<Value>,
+    /// Schemas of the query responses, by query name
+    #[serde(default)]
+    pub responses: Option<BTreeMap<String, Value>>,
+}
+
+impl ContractIdl {
+    /// Parses the schema file content
+    pub fn parse(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+}
@@ -0,0 +1,239 @@
+//! Generates cw-orchestrator interfaces from the JSON schema of a contract.
+//!
+//! The schema is the file written by `cosmwasm_schema::write_api!` when running `cargo schema` on the contract.
+//! The generated interface contains:
+//! - The message types, with typed `ExecuteFns` and `QueryFns`.
+//! - The interface struct declared with `#[cw_orch::interface]`.
+//! - An `Uploadable` implementation looking for the `.wasm` file in the `artifacts` directory of the workspace.
+//!
+//! ```no_run
+//! let schema = std::fs::read_to_string("schema/cw20-base.json")?;
+//! cw_orch_codegen::generate_crate(&schema, "cw20-base-interface")?;
+//! # Ok::<(), cw_orch_codegen::CodegenError>(())
+//! ```
+mod error;
+mod idl;
+mod types;
+
+use std::path::Path;
+
+pub use error::CodegenError;
+pub use idl::ContractIdl;
+use types::{pascal_case, MessageKind, TypeGenerator};
+
+/// Version of cw-orch used by the generated crates
+const CW_ORCH_VERSION: &str = "0.27.0";
+/// Version of cosmwasm used by the generated crates
+const COSMWASM_VERSION: &str = "2.1";
+
+/// Generates the source of the interface of the contract described by `schema`
+pub fn generate_interface(schema: &str) -> Result<String, CodegenError> {
+    let idl = ContractIdl::parse(schema)?;
+    let mut generator = TypeGenerator::default();
+
+    generator.define_root("InstantiateMsg", &idl.instantiate, MessageKind::Plain)?;
+    if let Some(execute) = &idl.execute {
+        generator.define_root("ExecuteMsg", execute, MessageKind::Execute)?;
+    }
+    if let Some(query) = &idl.query {
+        for (query_name, response) in idl.responses.iter().flatten() {
+            let response = generator.root_type(response)?;
+            generator
+                .query_responses
+                .insert(query_name.clone(), response);
+        }
+        generator.define_root("QueryMsg", query, MessageKind::Query)?;
+    }
+    if let Some(migrate) = &idl.migrate {
+        generator.define_root("MigrateMsg", migrate, MessageKind::Plain)?;
+    }
+
+    let message = |msg: &Option<serde_json::Value>, name: &str| {
+        if msg.is_some() {
+            name.to_string()
+        } else {
+            "cosmwasm_std::Empty".to_string()
+        }
+    };
+    let interface_name = pascal_case(&idl.contract_name);
+    let wasm_name = idl.contract_name.replace('-', "_");
+
+    let mut source = format!(
+        "//! cw-orch interface of `{name}` {version}, generated by cw-orch-codegen from its JSON schema.\n\
+         #![allow(clippy::large_enum_variant)]\n\n",
+        name = idl.contract_name,
+        version = idl.contract_version,
+    );
+    for item in generator.items() {
+        source.push_str(item);
+        source.push('\n');
+    }
+    source.push_str(&format!(
+        "#[cw_orch::interface(InstantiateMsg, {execute}, {query}, {migrate})]\n\
+         pub struct {interface_name};\n\n\
+         impl<Chain> cw_orch::prelude::Uploadable for {interface_name}<Chain> {{\n    \
+             fn wasm(_chain: &cw_orch::environment::ChainInfoOwned) -> cw_orch::prelude::WasmPath {{\n        \
+                 use cw_orch::prelude::*;\n        \
+                 artifacts_dir_from_workspace!()\n            \
+                     .find_wasm_path(\"{wasm_name}\")\n            \
+                     .unwrap()\n    \
+             }}\n\
+         }}\n",
+        execute = message(&idl.execute, "ExecuteMsg"),
+        query = message(&idl.query, "QueryMsg"),
+        migrate = message(&idl.migrate, "MigrateMsg"),
+    ));
+    Ok(source)
+}
+
+/// Generates an interface crate for the contract described by `schema` in `out_dir`
+pub fn generate_crate(schema: &str, out_dir: impl AsRef<Path>) -> Result<(), CodegenError> {
+    let idl = ContractIdl::parse(schema)?;
+    let source = generate_interface(schema)?;
+
+    let out_dir = out_dir.as_ref();
+    std::fs::create_dir_all(out_dir.join("src"))?;
+    std::fs::write(
+        out_dir.join("Cargo.toml"),
+        format!(
+            "[package]\n\
+             edition = \"2021\"\n\
+             name    = \"{}-interface\"\n\
+             version = \"{}\"\n\n\
+             [dependencies]\n\
+             cosmwasm-schema = \"{COSMWASM_VERSION}\"\n\
+             cosmwasm-std    = \"{COSMWASM_VERSION}\"\n\
+             cw-orch         = \"{CW_ORCH_VERSION}\"\n\
+             serde           = {{ version = \"1.0\", features = [\"derive\"] }}\n\
+             serde_json      = \"1.0\"\n",
+            idl.contract_name, idl.contract_version
+        ),
+    )?;
+    std::fs::write(out_dir.join("src").join("lib.rs"), source)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CW20_SCHEMA: &str = r##"{
+        "contract_name": "cw20-base",
+        "contract_version": "1.1.0",
+        "idl_version": "1.0.0",
+        "instantiate": {
+            "title": "InstantiateMsg",
+            "type": "object",
+            "required": ["decimals", "name"],
+            "properties": {
+                "decimals": { "type": "integer", "format": "uint8", "minimum": 0.0 },
+                "name": { "type": "string" },
+                "cap": { "anyOf": [{ "$ref": "#/definitions/Uint128" }, { "type": "null" }] }
+            },
+            "definitions": { "Uint128": { "type": "string" } }
+        },
+        "execute": {
+            "title": "ExecuteMsg",
+            "oneOf": [
+                {
+                    "description": "Transfer is a base message to move tokens to another account",
+                    "type": "object",
+                    "required": ["transfer"],
+                    "properties": {
+                        "transfer": {
+                            "type": "object",
+                            "required": ["amount", "recipient"],
+                            "properties": {
+                                "amount": { "$ref": "#/definitions/Uint128" },
+                                "recipient": { "type": "string" },
+                                "expires": { "$ref": "#/definitions/Expiration" }
+                            }
+                        }
+                    }
+                },
+                { "type": "string", "enum": ["burn_all"] }
+            ],
+            "definitions": {
+                "Uint128": { "type": "string" },
+                "Expiration": {
+                    "oneOf": [
+                        {
+                            "type": "object",
+                            "required": ["at_height"],
+                            "properties": { "at_height": { "type": "integer", "format": "uint64" } }
+                        },
+                        {
+                            "type": "object",
+                            "required": ["never"],
+                            "properties": { "never": { "type": "object" } }
+                        }
+                    ]
+                }
+            }
+        },
+        "query": {
+            "title": "QueryMsg",
+            "oneOf": [
+                {
+                    "type": "object",
+                    "required": ["balance"],
+                    "properties": {
+                        "balance": {
+                            "type": "object",
+                            "required": ["address"],
+                            "properties": { "address": { "type": "string" } }
+                        }
+                    }
+                },
+                { "type": "string", "enum": ["minter"] }
+            ]
+        },
+        "migrate": null,
+        "sudo": null,
+        "responses": {
+            "balance": {
+                "title": "BalanceResponse",
+                "type": "object",
+                "required": ["balance"],
+                "properties": { "balance": { "$ref": "#/definitions/Uint128" } },
+                "definitions": { "Uint128": { "type": "string" } }
+            },
+            "minter": {
+                "title": "Nullable_String",
+                "type": ["string", "null"]
+            }
+        }
+    }"##;
+
+    #[test]
+    fn generates_cw20_interface() {
+        let source = generate_interface(CW20_SCHEMA).unwrap();
+
+        assert!(source.contains("pub struct InstantiateMsg {\n    pub cap: Option<cosmwasm_std::Uint128>,\n    pub decimals: u8,\n    pub name: String,\n}"));
+        assert!(source.contains("#[derive(cw_orch::ExecuteFns)]\npub enum ExecuteMsg {"));
+        assert!(source.contains(
+            "    /// Transfer is a base message to move tokens to another account\n    Transfer {\n        amount: cosmwasm_std::Uint128,\n"
+        ));
+        assert!(source.contains("        expires: Option<Expiration>,\n"));
+        assert!(source.contains("    BurnAll,\n"));
+        assert!(
+            source.contains("pub enum Expiration {\n    AtHeight(u64),\n    Never {\n    },\n}")
+        );
+        assert!(source.contains("    #[returns(BalanceResponse)]\n    Balance {"));
+        assert!(source.contains("    #[returns(Option<String>)]\n    Minter,\n"));
+        assert!(source.contains(
+            "#[cw_orch::interface(InstantiateMsg, ExecuteMsg, QueryMsg, cosmwasm_std::Empty)]\npub struct Cw20Base;"
+        ));
+        assert!(source.contains(".find_wasm_path(\"cw20_base\")"));
+    }
+
+    #[test]
+    fn requires_query_responses() {
+        let mut schema: serde_json::Value = serde_json::from_str(CW20_SCHEMA).unwrap();
+        schema["responses"] = serde_json::json!({});
+        assert!(matches!(
+            generate_interface(&schema.to_string()),
+            Err(CodegenError::MissingResponse(query)) if query == "balance"
+        ));
+    }
+}
@@ -0,0 +1,32 @@
+//! `cw-orch-codegen generate <SCHEMA> <OUT_DIR>`
+//!
+//! Generates a cw-orch interface crate in `OUT_DIR` from the contract schema file `SCHEMA`.
+use std::process::ExitCode;
+
+const USAGE: &str = "Usage: cw-orch-codegen generate <SCHEMA> <OUT_DIR>";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let [command, schema_path, out_dir] = args.as_slice() else {
+        eprintln!("{USAGE}");
+        return ExitCode::FAILURE;
+    };
+    if command != "generate" {
+        eprintln!("Unknown command {command}\n{USAGE}");
+        return ExitCode::FAILURE;
+    }
+
+    let result = std::fs::read_to_string(schema_path)
+        .map_err(Into::into)
+        .and_then(|schema| cw_orch_codegen::generate_crate(&schema, out_dir));
+    match result {
+        Ok(()) => {
+            println!("Generated the interface crate in {out_dir}");
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            eprintln!("Failed to generate the interface: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,397 @@
+//! Rust type generation from JSON schemas
+use std::collections::BTreeMap;
+
+use serde_json::{Map, Value};
+
+use crate::error::CodegenError;
+
+/// Types provided by `cosmwasm_std`, by schema definition name
+const COSMWASM_TYPES: &[&str] = &[
+    "Addr",
+    "Binary",
+    "Coin",
+    "Decimal",
+    "Decimal256",
+    "Empty",
+    "Int128",
+    "Int256",
+    "Int64",
+    "Timestamp",
+    "Uint128",
+    "Uint256",
+    "Uint512",
+    "Uint64",
+];
+
+const RUST_KEYWORDS: &[&str] = &[
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern",
+    "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub",
+    "ref", "return", "static", "struct", "trait", "true", "type", "unsafe", "use", "where",
+    "while",
+];
+
+/// Derives added on top of `#[cw_serde]` for a generated type
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum MessageKind {
+    Plain,
+    Execute,
+    Query,
+}
+
+/// Collects the Rust items generated from the schemas
+#[derive(Debug, Default)]
+pub(crate) struct TypeGenerator {
+    /// Generated items, by type name
+    items: BTreeMap<String, String>,
+    /// Response type of every query, by query name
+    pub(crate) query_responses: BTreeMap<String, String>,
+}
+
+impl TypeGenerator {
+    /// Returns the generated items, sorted by name
+    pub(crate) fn items(&self) -> impl Iterator<Item = &String> {
+        self.items.values()
+    }
+
+    /// Generates the type described by a root schema (with its own `definitions`) under `name`
+    pub(crate) fn define_root(
+        &mut self,
+        name: &str,
+        schema: &Value,
+        kind: MessageKind,
+    ) -> Result<(), CodegenError> {
+        let definitions = definitions(schema);
+        self.define(name, schema, &definitions, kind)
+    }
+
+    /// Returns the Rust type of a root schema, generating the named types it needs.
+    /// Used for query responses, whose root can be a primitive (`Nullable_Uint128`, `Array_of_String`, ...)
+    pub(crate) fn root_type(&mut self, schema: &Value) -> Result<String, CodegenError> {
+        let definitions = definitions(schema);
+        match schema.get("title").and_then(Value::as_str) {
+            Some(title) if is_named_type(schema) => {
+                let name = pascal_case(title);
+                self.define(&name, schema, &definitions, MessageKind::Plain)?;
+                Ok(name)
+            }
+            _ => self.rust_type(schema, &definitions),
+        }
+    }
+
+    fn define(
+        &mut self,
+        name: &str,
+        schema: &Value,
+        definitions: &Map<String, Value>,
+        kind: MessageKind,
+    ) -> Result<(), CodegenError> {
+        if self.items.contains_key(name) {
+            return Ok(());
+        }
+        // Reserve the name to stop recursive definitions
+        self.items.insert(name.to_string(), String::new());
+
+        let mut item = doc_comment(schema, "");
+        item.push_str("#[cosmwasm_schema::cw_serde]\n");
+        match kind {
+            MessageKind::Plain => {}
+            MessageKind::Execute => item.push_str("#[derive(cw_orch::ExecuteFns)]\n"),
+            MessageKind::Query => {
+                item.push_str("#[derive(cw_orch::QueryFns, cosmwasm_schema::QueryResponses)]\n")
+            }
+        }
+
+        if let Some(variants) = schema
+            .get("oneOf")
+            .or_else(|| schema.get("anyOf"))
+            .and_then(Value::as_array)
+        {
+            item.push_str(&format!("pub enum {name} {{\n"));
+            for variant in variants {
+                item.push_str(&self.variants(name, variant, definitions, kind)?);
+            }
+            item.push_str("}\n");
+        } else if let Some(values) = schema.get("enum").and_then(Value::as_array) {
+            item.push_str(&format!("pub enum {name} {{\n"));
+            for value in values {
+                let value = value.as_str().ok_or_else(|| unsupported(name, schema))?;
+                item.push_str(&format!("    {},\n", pascal_case(value)));
+            }
+            item.push_str("}\n");
+        } else if is_object(schema) {
+            item.push_str(&format!(
+                "pub struct {name} {{\n{}}}\n",
+                self.fields(name, schema, definitions, "    pub ")?
+            ));
+        } else {
+            // Plain alias, `#[cw_serde]` doesn't apply
+            item = doc_comment(schema, "");
+            item.push_str(&format!(
+                "pub type {name} = {};\n",
+                self.rust_type(schema, definitions)?
+            ));
+        }
+
+        self.items.insert(name.to_string(), item);
+        Ok(())
+    }
+
+    fn variants(
+        &mut self,
+        enum_name: &str,
+        variant: &Value,
+        definitions: &Map<String, Value>,
+        kind: MessageKind,
+    ) -> Result<String, CodegenError> {
+        // Unit variants are serialized as strings
+        if let Some(values) = variant.get("enum").and_then(Value::as_array) {
+            let mut variants = String::new();
+            for value in values {
+                let value = value
+                    .as_str()
+                    .ok_or_else(|| unsupported(enum_name, variant))?;
+                variants.push_str(&doc_comment(variant, "    "));
+                variants.push_str(&self.returns(value, kind)?);
+                variants.push_str(&format!("    {},\n", pascal_case(value)));
+            }
+            return Ok(variants);
+        }
+
+        // Other variants are objects with a single property named after the variant
+        let properties = variant
+            .get("properties")
+            .and_then(Value::as_object)
+            .filter(|properties| properties.len() == 1)
+            .ok_or_else(|| unsupported(enum_name, variant))?;
+        let (key, body) = properties.iter().next().unwrap();
+
+        let mut result = doc_comment(variant, "    ");
+        result.push_str(&self.returns(key, kind)?);
+        if is_object(body) {
+            result.push_str(&format!(
+                "    {} {{\n{}    }},\n",
+                pascal_case(key),
+                self.fields(enum_name, body, definitions, "        ")?
+            ));
+        } else {
+            result.push_str(&format!(
+                "    {}({}),\n",
+                pascal_case(key),
+                self.rust_type(body, definitions)?
+            ));
+        }
+        Ok(result)
+    }
+
+    /// `#[returns(...)]` attribute of a query variant
+    fn returns(&self, query: &str, kind: MessageKind) -> Result<String, CodegenError> {
+        if kind != MessageKind::Query {
+            return Ok(String::new());
+        }
+        let response = self
+            .query_responses
+            .get(query)
+            .ok_or_else(|| CodegenError::MissingResponse(query.to_string()))?;
+        Ok(format!("    #[returns({response})]\n"))
+    }
+
+    fn fields(
+        &mut self,
+        type_name: &str,
+        schema: &Value,
+        definitions: &Map<String, Value>,
+        prefix: &str,
+    ) -> Result<String, CodegenError> {
+        let indent = prefix.trim_end_matches("pub ");
+        let required: Vec<&str> = schema
+            .get("required")
+            .and_then(Value::as_array)
+            .map(|required| required.iter().filter_map(Value::as_str).collect())
+            .unwrap_or_default();
+
+        let mut fields = String::new();
+        let Some(properties) = schema.get("properties").and_then(Value::as_object) else {
+            return Ok(fields);
+        };
+        for (key, property) in properties {
+            let mut ty = self.rust_type(property, definitions)?;
+            if !required.contains(&key.as_str()) && !ty.starts_with("Option<") {
+                ty = format!("Option<{ty}>");
+            }
+            fields.push_str(&doc_comment(property, indent));
+            fields.push_str(&format!("{prefix}{}: {ty},\n", field_name(key)));
+        }
+        if fields.is_empty() && !properties.is_empty() {
+            return Err(unsupported(type_name, schema));
+        }
+        Ok(fields)
+    }
+
+    /// Returns the Rust type of a schema, generating the referenced definitions
+    fn rust_type(
+        &mut self,
+        schema: &Value,
+        definitions: &Map<String, Value>,
+    ) -> Result<String, CodegenError> {
+        if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+            let name = reference.trim_start_matches("#/definitions/");
+            if COSMWASM_TYPES.contains(&name) {
+                return Ok(format!("cosmwasm_std::{name}"));
+            }
+            let definition = definitions
+                .get(name)
+                .ok_or_else(|| unsupported(name, schema))?;
+            let rust_name = pascal_case(name);
+            self.define(&rust_name, definition, definitions, MessageKind::Plain)?;
+            return Ok(rust_name);
+        }
+
+        if let Some(all_of) = schema.get("allOf").and_then(Value::as_array) {
+            if let [inner] = all_of.as_slice() {
+                return self.rust_type(inner, definitions);
+            }
+        }
+
+        // `Option<T>` is either `anyOf: [T, null]` or `type: [T, "null"]`
+        if let Some(any_of) = schema.get("anyOf").and_then(Value::as_array) {
+            if let [inner, null] = any_of.as_slice() {
+                if null.get("type").and_then(Value::as_str) == Some("null") {
+                    return Ok(format!("Option<{}>", self.rust_type(inner, definitions)?));
+                }
+            }
+        }
+
+        match schema.get("type") {
+            Some(Value::Array(types)) => {
+                let types: Vec<_> = types.iter().filter_map(Value::as_str).collect();
+                match types.as_slice() {
+                    [ty, "null"] | ["null", ty] => {
+                        let mut inner = schema.clone();
+                        inner["type"] = Value::String(ty.to_string());
+                        Ok(format!("Option<{}>", self.rust_type(&inner, definitions)?))
+                    }
+                    _ => Ok("serde_json::Value".to_string()),
+                }
+            }
+            Some(Value::String(ty)) => match ty.as_str() {
+                "string" => Ok("String".to_string()),
+                "boolean" => Ok("bool".to_string()),
+                "number" => Ok("f64".to_string()),
+                "integer" => Ok(integer_type(schema)),
+                "null" => Ok("()".to_string()),
+                "array" => match schema.get("items") {
+                    // Tuples
+                    Some(Value::Array(items)) => {
+                        let items = items
+                            .iter()
+                            .map(|item| self.rust_type(item, definitions))
+                            .collect::<Result<Vec<_>, _>>()?;
+                        Ok(format!("({})", items.join(", ")))
+                    }
+                    Some(items) => Ok(format!("Vec<{}>", self.rust_type(items, definitions)?)),
+                    None => Ok("Vec<serde_json::Value>".to_string()),
+                },
+                "object" => match schema.get("additionalProperties") {
+                    Some(values) if values.is_object() && schema.get("properties").is_none() => {
+                        Ok(format!(
+                            "std::collections::BTreeMap<String, {}>",
+                            self.rust_type(values, definitions)?
+                        ))
+                    }
+                    _ if schema.get("properties").is_none() => {
+                        Ok("cosmwasm_std::Empty".to_string())
+                    }
+                    // Anonymous structs have no Rust equivalent
+                    _ => Ok("serde_json::Value".to_string()),
+                },
+                _ => Err(unsupported(ty, schema)),
+            },
+            _ => Ok("serde_json::Value".to_string()),
+        }
+    }
+}
+
+/// Schemas that become a named Rust type rather than an alias
+fn is_named_type(schema: &Value) -> bool {
+    schema.get("properties").is_some()
+        || schema.get("oneOf").is_some()
+        || (schema.get("enum").is_some()
+            && schema.get("type").and_then(Value::as_str) == Some("string"))
+}
+
+fn is_object(schema: &Value) -> bool {
+    schema.get("type").and_then(Value::as_str) == Some("object")
+        && schema
+            .get("additionalProperties")
+            .map_or(true, |additional| !additional.is_object())
+}
+
+fn definitions(schema: &Value) -> Map<String, Value> {
+    schema
+        .get("definitions")
+        .and_then(Value::as_object)
+        .cloned()
+        .unwrap_or_default()
+}
+
+fn integer_type(schema: &Value) -> String {
+    match schema.get("format").and_then(Value::as_str) {
+        Some(format @ ("uint8" | "uint16" | "uint32" | "uint64" | "uint128")) => {
+            format.replace("uint", "u")
+        }
+        Some(format @ ("int8" | "int16" | "int32" | "int64" | "int128")) => {
+            format.replace("int", "i")
+        }
+        _ => "i64".to_string(),
+    }
+}
+
+fn doc_comment(schema: &Value, indent: &str) -> String {
+    schema
+        .get("description")
+        .and_then(Value::as_str)
+        .map(|description| {
+            description
+                .lines()
+                .map(|line| format!("{indent}/// {line}\n").replace("/// \n", "///\n"))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn field_name(name: &str) -> String {
+    if RUST_KEYWORDS.contains(&name) {
+        format!("r#{name}")
+    } else {
+        name.to_string()
+    }
+}
+
+fn unsupported(name: &str, schema: &Value) -> CodegenError {
+    CodegenError::UnsupportedSchema(name.to_string(), schema.to_string())
+}
+
+/// Converts `snake_case`, `kebab-case` and `Title_Case` names to `PascalCase`
+pub(crate) fn pascal_case(name: &str) -> String {
+    name.split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            let first = chars.next().unwrap().to_ascii_uppercase();
+            std::iter::once(first).chain(chars).collect::<String>()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_names() {
+        assert_eq!(pascal_case("cw20-base"), "Cw20Base");
+        assert_eq!(pascal_case("increase_allowance"), "IncreaseAllowance");
+        assert_eq!(pascal_case("Nullable_Uint128"), "NullableUint128");
+        assert_eq!(field_name("type"), "r#type");
+    }
+}
@@ -0,0 +1,243 @@
+//! Conformance suite for execution environments, requires the `conformance` feature.
+//!
+//! Environments implementing [`TxHandler`](crate::environment::TxHandler) and [`QueryHandler`] outside of cw-orch
+//! (test-tube forks, custom chains) run the suite against themselves to check that they behave like `Mock` and `Daemon`:
+//! state storage, block production, bank transfers and the whole contract lifecycle.
+//!
+//! ```ignore
+//! use cw_orch_core::conformance::{ConformanceContract, ConformanceSuite};
+//! use serde_json::json;
+//!
+//! #[test]
+//! fn conformance() -> anyhow::Result<()> {
+//!     let chain = MyEnv::new();
+//!     ConformanceSuite::new(chain.clone())
+//!         .contract(ConformanceContract {
+//!             source: MockContract::new("mock-contract", chain),
+//!             instantiate_msg: json!({}),
+//!             execute_msg: json!({"first_message": {}}),
+//!             failing_execute_msg: Some(json!({"second_message": {"t": ""}})),
+//!             query_msg: json!({"first_query": {}}),
+//!             migrate_msg: Some(json!({"t": "success"})),
+//!         })
+//!         .bank_send(Addr::unchecked("receiver"), coin(100, "ujuno"))
+//!         .run()
+//! }
+//! ```
+use anyhow::{ensure, Context};
+use cosmwasm_std::{Addr, Coin};
+use serde_json::Value;
+
+use crate::{
+    contract::{interface_traits::Uploadable, Contract},
+    environment::{
+        BankQuerier, ChainState, CwEnv, DefaultQueriers, IndexResponse, NodeQuerier, QueryHandler,
+        StateInterface, TxHandler, WasmQuerier,
+    },
+};
+
+/// Id of the contract deployed by the suite
+const CONTRACT_ID: &str = "cw-orch-conformance";
+
+/// Contract deployed by the suite, with messages that succeed on it
+pub struct ConformanceContract<S: Uploadable> {
+    /// Source of the contract, for the environment under test
+    pub source: S,
+    pub instantiate_msg: Value,
+    pub execute_msg: Value,
+    /// Message the contract rejects, to check that failures are reported as errors
+    pub failing_execute_msg: Option<Value>,
+    pub query_msg: Value,
+    /// Message to migrate the contract to its own code id
+    pub migrate_msg: Option<Value>,
+}
+
+/// Check run by the suite
+type Check<Chain> = Box<dyn Fn(&Chain) -> anyhow::Result<()>>;
+
+/// Checks that an environment behaves like the environments of cw-orch
+pub struct ConformanceSuite<Chain: CwEnv> {
+    chain: Chain,
+    checks: Vec<(&'static str, Check<Chain>)>,
+}
+
+impl<Chain: CwEnv + 'static> ConformanceSuite<Chain> {
+    /// Suite checking the state and blocks of `chain`
+    pub fn new(chain: Chain) -> Self {
+        Self {
+            chain,
+            checks: vec![
+                ("state", Box::new(check_state::<Chain>) as Check<Chain>),
+                ("blocks", Box::new(check_blocks::<Chain>)),
+            ],
+        }
+    }
+
+    /// Also checks the lifecycle of `contract`
+    pub fn contract<S: Uploadable + 'static>(mut self, contract: ConformanceContract<S>) -> Self {
+        self.checks.push((
+            "contract lifecycle",
+            Box::new(move |chain: &Chain| check_contract(chain, &contract)),
+        ));
+        self
+    }
+
+    /// Also checks bank transfers of `amount` to `receiver`. The sender must hold `amount`.
+    pub fn bank_send(mut self, receiver: Addr, amount: Coin) -> Self {
+        self.checks.push((
+            "bank send",
+            Box::new(move |chain: &Chain| check_bank_send(chain, &receiver, &amount)),
+        ));
+        self
+    }
+
+    /// Runs all the configured checks, stopping at the first failure
+    pub fn run(&self) -> anyhow::Result<()> {
+        for (name, check) in &self.checks {
+            check(&self.chain).with_context(|| format!("conformance check `{name}` failed"))?;
+        }
+        Ok(())
+    }
+}
+
+/// Checks that addresses and code ids are stored and removed from the state
+pub fn check_state<Chain: CwEnv>(chain: &Chain) -> anyhow::Result<()> {
+    let mut state = chain.state();
+    let address = chain.sender_addr();
+    state.set_address(CONTRACT_ID, &address);
+    state.set_code_id(CONTRACT_ID, 42);
+    ensure!(
+        state.get_address(CONTRACT_ID)? == address,
+        "stored address mismatch"
+    );
+    ensure!(
+        state.get_code_id(CONTRACT_ID)? == 42,
+        "stored code id mismatch"
+    );
+    ensure!(
+        state.get_all_addresses()?.contains_key(CONTRACT_ID),
+        "stored address not listed"
+    );
+
+    state.remove_address(CONTRACT_ID);
+    state.remove_code_id(CONTRACT_ID);
+    ensure!(
+        state.get_address(CONTRACT_ID).is_err(),
+        "address not removed"
+    );
+    ensure!(
+        state.get_code_id(CONTRACT_ID).is_err(),
+        "code id not removed"
+    );
+    Ok(())
+}
+
+/// Checks that blocks are produced when waiting
+pub fn check_blocks<Chain: CwEnv>(chain: &Chain) -> anyhow::Result<()> {
+    let node = chain.node_querier();
+    let start = node.latest_block()?;
+    chain.next_block()?;
+    let next = node.block_height()?;
+    ensure!(next > start.height, "next_block didn't produce a block");
+
+    chain.wait_blocks(2)?;
+    let end = node.latest_block()?;
+    ensure!(
+        end.height >= next + 2,
+        "wait_blocks didn't wait for 2 blocks"
+    );
+    ensure!(end.time > start.time, "block time didn't increase");
+    ensure!(
+        end.chain_id == start.chain_id,
+        "chain id changed between blocks"
+    );
+    Ok(())
+}
+
+/// Checks that `amount` is transferred to `receiver` by a bank send
+pub fn check_bank_send<Chain: CwEnv>(
+    chain: &Chain,
+    receiver: &Addr,
+    amount: &Coin,
+) -> anyhow::Result<()> {
+    let bank = chain.bank_querier();
+    let balance = || -> anyhow::Result<u128> {
+        let coins = bank.balance(receiver, Some(amount.denom.clone()))?;
+        Ok(coins.iter().map(|coin| coin.amount.u128()).sum())
+    };
+    let before = balance()?;
+    chain.bank_send(receiver, &[amount.clone()])?;
+    ensure!(
+        balance()? == before + amount.amount.u128(),
+        "receiver balance didn't increase by the amount sent"
+    );
+    Ok(())
+}
+
+/// Checks the upload, instantiation, execution, query and migration of a contract
+pub fn check_contract<Chain: CwEnv, S: Uploadable>(
+    chain: &Chain,
+    contract: &ConformanceContract<S>,
+) -> anyhow::Result<()> {
+    let sender = chain.sender_addr();
+    let instance = Contract::new(CONTRACT_ID, chain.clone());
+    let wasm = chain.wasm_querier();
+
+    instance.upload(&contract.source)?;
+    let code_id = instance
+        .code_id()
+        .context("code id not stored after upload")?;
+    let code = wasm.code(code_id)?;
+    ensure!(
+        code.code_id == code_id,
+        "code info of another code returned"
+    );
+    ensure!(code.creator == sender, "code creator isn't the sender");
+    wasm.code_id_hash(code_id)?;
+
+    instance.instantiate(&contract.instantiate_msg, Some(&sender), &[])?;
+    let address = instance
+        .address()
+        .context("address not stored after instantiation")?;
+    let info = wasm.contract_info(&address)?;
+    ensure!(
+        info.code_id == code_id,
+        "contract info has the wrong code id"
+    );
+    ensure!(
+        info.admin == Some(sender.clone()),
+        "contract admin isn't set"
+    );
+
+    let response = instance.execute(&contract.execute_msg, &[])?;
+    let executed = response
+        .event_attr_values("execute", "_contract_address")
+        .contains(&address.to_string());
+    ensure!(
+        executed,
+        "execute event of the contract missing from the response"
+    );
+
+    let _: Value = instance.query(&contract.query_msg)?;
+    let _: Value = chain.query(&contract.query_msg, &address)?;
+
+    if let Some(msg) = &contract.failing_execute_msg {
+        ensure!(
+            instance.execute(msg, &[]).is_err(),
+            "execution rejected by the contract didn't error"
+        );
+    }
+
+    if let Some(msg) = &contract.migrate_msg {
+        instance.migrate(msg, code_id)?;
+        ensure!(
+            wasm.contract_info(&address)?.code_id == code_id,
+            "contract info changed by the migration to the same code"
+        );
+    }
+
+    let mut state = chain.state();
+    state.remove_address(CONTRACT_ID);
+    state.remove_code_id(CONTRACT_ID);
+    Ok(())
+}
@@ -0,0 +1,170 @@
+//! Deployment artifacts for frontend clients.
+//!
+//! [`Deploy::export_artifacts`](super::Deploy::export_artifacts) writes the addresses and code-ids of a deployment,
+//! along with the JSON schemas (ABIs) of its contracts, to a directory that cosmjs or telescope based frontends can import:
+//! - `deployment.json`: the [`ArtifactBundle`].
+//! - `deployment.ts`: the same bundle as a typed TypeScript constant.
+//! - `schemas/<contract_id>.json`: the schema of every contract that provides one.
+use std::{
+    collections::{BTreeMap, HashMap},
+    path::{Path, PathBuf},
+};
+
+use cosmwasm_std::Addr;
+use serde::{Deserialize, Serialize};
+
+use super::interface_traits::ContractInstance;
+use crate::{
+    environment::{CwEnv, Environment, EnvironmentQuerier},
+    CwEnvError,
+};
+
+const BUNDLE_FILE: &str = "deployment.json";
+const TYPESCRIPT_FILE: &str = "deployment.ts";
+const SCHEMAS_DIR: &str = "schemas";
+
+/// Deployed contract, as exported in an [`ArtifactBundle`]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContractArtifact {
+    /// Address, if instantiated
+    pub address: Option<Addr>,
+    /// Code id, if uploaded
+    pub code_id: Option<u64>,
+    /// Path of the contract schema, relative to the bundle
+    pub schema: Option<String>,
+}
+
+/// Addresses and ABIs of a deployment on a chain
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ArtifactBundle {
+    /// Id of the chain
+    pub chain_id: String,
+    /// Name of the chain
+    pub chain_name: String,
+    /// Id of the deployment
+    pub deployment_id: String,
+    /// Contracts of the deployment, by contract id
+    pub contracts: BTreeMap<String, ContractArtifact>,
+}
+
+impl ArtifactBundle {
+    /// Builds the bundle from the current state of the contracts.
+    /// `schemas` maps contract ids to the path of their JSON schema.
+    pub fn new<Chain: CwEnv>(
+        contracts: &[Box<&mut dyn ContractInstance<Chain>>],
+        schemas: &HashMap<String, PathBuf>,
+    ) -> Result<Self, CwEnvError> {
+        let env_info = contracts
+            .first()
+            .ok_or_else(|| CwEnvError::StdErr("No contract to export".to_string()))?
+            .environment()
+            .env_info();
+
+        Ok(Self {
+            chain_id: env_info.chain_id,
+            chain_name: env_info.chain_name,
+            deployment_id: env_info.deployment_id,
+            contracts: contracts
+                .iter()
+                .map(|contract| {
+                    let id = contract.id();
+                    let artifact = ContractArtifact {
+                        address: contract.address().ok(),
+                        code_id: contract.code_id().ok(),
+                        schema: schemas
+                            .contains_key(&id)
+                            .then(|| format!("{SCHEMAS_DIR}/{id}.json")),
+                    };
+                    (id, artifact)
+                })
+                .collect(),
+        })
+    }
+
+    /// Writes the bundle, its TypeScript version and the contract schemas to `dir`
+    pub fn write_to(
+        &self,
+        dir: impl AsRef<Path>,
+        schemas: &HashMap<String, PathBuf>,
+    ) -> Result<(), CwEnvError> {
+        let dir = dir.as_ref();
+        std::fs::create_dir_all(dir.join(SCHEMAS_DIR))?;
+
+        for (id, path) in schemas {
+            if self.contracts.contains_key(id) {
+                std::fs::copy(path, dir.join(SCHEMAS_DIR).join(format!("{id}.json")))?;
+            }
+        }
+
+        let json = serde_json::to_string_pretty(self)?;
+        std::fs::write(dir.join(BUNDLE_FILE), &json)?;
+        std::fs::write(dir.join(TYPESCRIPT_FILE), self.typescript(&json))?;
+        Ok(())
+    }
+
+    fn typescript(&self, json: &str) -> String {
+        let contract_ids = if self.contracts.is_empty() {
+            "never".to_string()
+        } else {
+            self.contracts
+                .keys()
+                .map(|id| format!("{id:?}"))
+                .collect::<Vec<_>>()
+                .join(" | ")
+        };
+        format!(
+            "// Generated by cw-orchestrator from the deployment state, do not edit.\n\n\
+             export type ContractId = {contract_ids};\n\n\
+             export interface ContractArtifact {{\n  \
+               address: string | null;\n  \
+               code_id: number | null;\n  \
+               schema: string | null;\n\
+             }}\n\n\
+             export interface ArtifactBundle {{\n  \
+               chain_id: string;\n  \
+               chain_name: string;\n  \
+               deployment_id: string;\n  \
+               contracts: Record<ContractId, ContractArtifact>;\n\
+             }}\n\n\
+             export const deployment: ArtifactBundle = {json};\n"
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_typescript() {
+        let bundle = ArtifactBundle {
+            chain_id: "pion-1".to_string(),
+            chain_name: "neutron".to_string(),
+            deployment_id: "default".to_string(),
+            contracts: BTreeMap::from([
+                (
+                    "cw20".to_string(),
+                    ContractArtifact {
+                        address: Some(Addr::unchecked("neutron1cw20")),
+                        code_id: Some(1),
+                        schema: Some("schemas/cw20.json".to_string()),
+                    },
+                ),
+                (
+                    "minter".to_string(),
+                    ContractArtifact {
+                        address: None,
+                        code_id: Some(2),
+                        schema: None,
+                    },
+                ),
+            ]),
+        };
+
+        let typescript = bundle.typescript(&serde_json::to_string(&bundle).unwrap());
+        assert!(typescript.contains("export type ContractId = \"cw20\" | \"minter\";"));
+        assert!(typescript
+            .contains("export const deployment: ArtifactBundle = {\"chain_id\":\"pion-1\""));
+        assert!(typescript.contains("\"address\":\"neutron1cw20\""));
+    }
+}
@@ -1,9 +1,16 @@
 //! Main functional component for interacting with a contract. Used as the base for generating contract interfaces.
-use super::interface_traits::Uploadable;
+use super::{
+    coverage::{self, EntryPoint},
+    interface_traits::Uploadable,
+    metadata::{ContractMetadata, MetadataRegistryExecuteMsg},
+    placeholder::resolve_placeholders,
+};
 use crate::{
     env::CoreEnvVars,
     environment::{
-        AsyncWasmQuerier, ChainState, IndexResponse, StateInterface, TxHandler, TxResponse,
+        AddressBook, AsyncTxHandler, AsyncTxResponse, AsyncWasmQuerier, ChainState,
+        ContractHistoryEntry, CwEnv, DefaultQueriers, IndexResponse, InstantiatedContract,
+        StateInterface, TxHandler, TxResponse, WasmQuerier,
     },
     error::CwEnvError,
     log::{contract_target, transaction_target},
@@ -27,6 +34,14 @@ pub struct Contract<Chain> {
     pub default_code_id: Option<u64>,
     /// Optional address used in case none is registered in the state
     pub default_address: Option<Addr>,
+    /// Chain sending the admin transactions (migrate, update and clear admin), see [`Contract::set_admin_sender`]
+    pub(crate) admin_chain: Option<Chain>,
+    /// Chain sending the executions, see [`Contract::set_operator_sender`]
+    pub(crate) operator_chain: Option<Chain>,
+    /// Project used in the label of the contract, see [`Contract::set_project`]
+    pub(crate) project: Option<String>,
+    /// Whether adopting an existing contract is confirmed by the user, see [`Contract::set_manual_interaction`]
+    pub(crate) manual_interaction: Option<bool>,
 }
 
 /// Implements constructors and helpers
@@ -38,6 +53,10 @@ impl<Chain> Contract<Chain> {
             chain,
             default_code_id: None,
             default_address: None,
+            admin_chain: None,
+            operator_chain: None,
+            project: None,
+            manual_interaction: None,
         }
     }
 
@@ -57,6 +76,32 @@ impl<Chain> Contract<Chain> {
     pub fn set_default_code_id(&mut self, code_id: u64) {
         self.default_code_id = Some(code_id);
     }
+
+    /// Sets the project used in the label of the contract, overriding the `CW_ORCH_PROJECT` env variable
+    pub fn set_project(&mut self, project: impl ToString) {
+        self.project = Some(project.to_string());
+    }
+
+    /// Sets whether adopting an existing contract is confirmed by the user, overriding the `CW_ORCH_MANUAL_INTERACTION` env variable
+    pub fn set_manual_interaction(&mut self, manual_interaction: bool) {
+        self.manual_interaction = Some(manual_interaction);
+    }
+
+    /// Removes the admin and operator senders, all the transactions are then sent by the sender of the contract chain
+    pub fn clear_role_senders(&mut self) {
+        self.admin_chain = None;
+        self.operator_chain = None;
+    }
+
+    /// Chain sending the admin transactions
+    fn admin_env(&self) -> &Chain {
+        self.admin_chain.as_ref().unwrap_or(&self.chain)
+    }
+
+    /// Chain sending the executions
+    fn operator_env(&self) -> &Chain {
+        self.operator_chain.as_ref().unwrap_or(&self.chain)
+    }
 }
 
 // State interfaces
@@ -71,6 +116,30 @@ impl<Chain: ChainState> Contract<Chain> {
             .ok_or(CwEnvError::AddrNotInStore(self.id.clone())))
     }
 
+    /// Label the contract is instantiated with.
+    /// `{project}:{contract_id}:{deployment_id}` if a project is set, see [`Contract::set_project`], the contract id otherwise.
+    /// Defaults to the project of the `CW_ORCH_PROJECT` env variable.
+    pub fn label(&self) -> String {
+        match self.project.clone().or_else(CoreEnvVars::project) {
+            Some(project) => format!(
+                "{project}:{}:{}",
+                self.id,
+                self.chain.state().deployment_id()
+            ),
+            None => self.id.clone(),
+        }
+    }
+
+    /// Sets the default address of the contract to the address registered under `alias` in `book`
+    pub fn set_default_address_from_book(
+        &mut self,
+        book: &AddressBook,
+        alias: &str,
+    ) -> Result<(), CwEnvError> {
+        self.default_address = Some(book.get(alias)?);
+        Ok(())
+    }
+
     /// Sets state address for contract
     pub fn set_address(&self, address: &Addr) {
         self.chain.state().set_address(&self.id, address)
@@ -97,13 +166,56 @@ impl<Chain: ChainState> Contract<Chain> {
     pub fn remove_code_id(&self) {
         self.chain.state().remove_code_id(&self.id)
     }
+
+    /// Returns the admin of the contract recorded in the state, if any
+    pub fn admin(&self) -> Option<Addr> {
+        self.chain.state().get_admin(&self.id)
+    }
+
+    /// Registers a contract instantiated by this contract (i.e. a factory) in the state under `child_id`.
+    /// The child is the first contract instantiated in `response` that matches `event_filter`.
+    pub fn register_child(
+        &self,
+        response: &impl IndexResponse,
+        child_id: &str,
+        event_filter: impl Fn(&InstantiatedContract) -> bool,
+    ) -> Result<Addr, CwEnvError> {
+        let own_address = self.address().ok();
+        let child = InstantiatedContract::from_response(response)
+            .into_iter()
+            .filter(|contract| Some(&contract.address) != own_address.as_ref())
+            .find(|contract| event_filter(contract))
+            .ok_or_else(|| CwEnvError::ChildNotFound(self.id.clone()))?;
+
+        log::info!(
+            target: &contract_target(),
+            "[{}][Child] {} registered at {}",
+            self.id,
+            child_id,
+            child.address
+        );
+        self.chain.state().set_address(child_id, &child.address);
+        Ok(child.address)
+    }
 }
 
 /// Expose chain and state function to call them on the contract
 impl<Chain: TxHandler> Contract<Chain> {
+    /// Sends the admin transactions of the contract (migrate, update and clear admin) with `sender`,
+    /// instead of the sender of the contract chain.
+    pub fn set_admin_sender(&mut self, sender: &<Chain as TxHandler>::Sender) {
+        self.admin_chain = Some(self.chain.call_as(sender));
+    }
+
+    /// Sends the executions of the contract with `sender`, instead of the sender of the contract chain.
+    pub fn set_operator_sender(&mut self, sender: &<Chain as TxHandler>::Sender) {
+        self.operator_chain = Some(self.chain.call_as(sender));
+    }
+
     // Chain interfaces
 
     /// Upload a contract given its source and specify the permissions for instantiating
+    #[cfg_attr(feature = "tracing", tracing::instrument(name = "upload", skip_all, fields(contract_id = %self.id)))]
     pub fn upload_with_access_config(
         &self,
         source: &impl Uploadable,
@@ -119,7 +231,14 @@ impl<Chain: TxHandler> Contract<Chain> {
             .chain
             .upload_with_access_config(source, access_config)
             .map_err(Into::into)?;
-        let code_id = resp.uploaded_code_id()?;
+        let code_id = match resp.uploaded_code_id() {
+            Ok(code_id) => code_id,
+            Err(_) if CoreEnvVars::dry_run() => {
+                log::info!(target: &contract_target(), "[{}][Upload][DRY RUN] no code_id stored", self.id);
+                return Ok(resp);
+            }
+            Err(e) => return Err(e.into()),
+        };
         self.set_code_id(code_id);
         log::info!(
             target: &contract_target(),
@@ -142,6 +261,7 @@ impl<Chain: TxHandler> Contract<Chain> {
     }
 
     /// Executes an operation on the contract
+    #[cfg_attr(feature = "tracing", tracing::instrument(name = "execute", skip_all, fields(contract_id = %self.id)))]
     pub fn execute<E: Serialize + Debug>(
         &self,
         msg: &E,
@@ -162,7 +282,8 @@ impl<Chain: TxHandler> Contract<Chain> {
             log_serialize_message(msg)?
         );
 
-        let resp = self.chain.execute(msg, coins, &self.address()?);
+        coverage::record(&self.id, EntryPoint::Execute, msg);
+        let resp = self.operator_env().execute(msg, coins, &self.address()?);
 
         log::info!(
             target: &contract_target(),
@@ -181,7 +302,37 @@ impl<Chain: TxHandler> Contract<Chain> {
         resp.map_err(Into::into)
     }
 
+    /// Executes a JSON encoded message on the contract, bypassing the typed messages.
+    /// Useful when the msg crate of the contract doesn't match the deployed version.
+    pub fn execute_raw(
+        &self,
+        msg: Binary,
+        coins: &[Coin],
+    ) -> Result<TxResponse<Chain>, CwEnvError> {
+        let msg: serde_json::Value = serde_json::from_slice(msg.as_slice())?;
+        log::info!(
+            target: &contract_target(),
+            "[{}][Execute][{}] raw message",
+            self.id,
+            self.address()?,
+        );
+        log::debug!(target: &contract_target(), "[{}][Execute] {}", self.id, msg);
+
+        let resp = self.operator_env().execute(&msg, coins, &self.address()?);
+
+        log::debug!(
+            target: &transaction_target(),
+            "[{}][Executed] response: {:?}",
+            self.id,
+            resp
+        );
+        resp.map_err(Into::into)
+    }
+
     /// Initializes the contract
+    ///
+    /// Address placeholders (see [`super::AddrOf`]) inside `msg` are resolved from the state before sending.
+    #[cfg_attr(feature = "tracing", tracing::instrument(name = "instantiate", skip_all, fields(contract_id = %self.id)))]
     pub fn instantiate<I: Serialize + Debug>(
         &self,
         msg: &I,
@@ -201,13 +352,29 @@ impl<Chain: TxHandler> Contract<Chain> {
             log_serialize_message(msg)?
         );
 
-        let resp = self
-            .chain
-            .instantiate(self.code_id()?, msg, Some(&self.id), admin, coins)
-            .map_err(Into::into)?;
-        let contract_address = resp.instantiated_contract_address()?;
+        coverage::record(&self.id, EntryPoint::Instantiate, msg);
+        let label = self.label();
+        let resp = match resolve_placeholders(msg, &self.chain.state())? {
+            Some(resolved) => {
+                self.chain
+                    .instantiate(self.code_id()?, &resolved, Some(&label), admin, coins)
+            }
+            None => self
+                .chain
+                .instantiate(self.code_id()?, msg, Some(&label), admin, coins),
+        }
+        .map_err(Into::into)?;
+        let contract_address = match resp.instantiated_contract_address() {
+            Ok(contract_address) => contract_address,
+            Err(_) if CoreEnvVars::dry_run() => {
+                log::info!(target: &contract_target(), "[{}][Instantiate][DRY RUN] no address stored", self.id);
+                return Ok(resp);
+            }
+            Err(e) => return Err(e.into()),
+        };
 
         self.set_address(&contract_address);
+        self.chain.state().set_admin(&self.id, admin);
 
         log::info!(
             target: &&contract_target(),
@@ -226,6 +393,9 @@ impl<Chain: TxHandler> Contract<Chain> {
     }
 
     /// Initializes the contract
+    ///
+    /// Address placeholders (see [`super::AddrOf`]) inside `msg` are resolved from the state before sending.
+    #[cfg_attr(feature = "tracing", tracing::instrument(name = "instantiate2", skip_all, fields(contract_id = %self.id)))]
     pub fn instantiate2<I: Serialize + Debug>(
         &self,
         msg: &I,
@@ -246,13 +416,33 @@ impl<Chain: TxHandler> Contract<Chain> {
             log_serialize_message(msg)?
         );
 
-        let resp = self
-            .chain
-            .instantiate2(self.code_id()?, msg, Some(&self.id), admin, coins, salt)
-            .map_err(Into::into)?;
-        let contract_address = resp.instantiated_contract_address()?;
+        coverage::record(&self.id, EntryPoint::Instantiate, msg);
+        let label = self.label();
+        let resp = match resolve_placeholders(msg, &self.chain.state())? {
+            Some(resolved) => self.chain.instantiate2(
+                self.code_id()?,
+                &resolved,
+                Some(&label),
+                admin,
+                coins,
+                salt,
+            ),
+            None => self
+                .chain
+                .instantiate2(self.code_id()?, msg, Some(&label), admin, coins, salt),
+        }
+        .map_err(Into::into)?;
+        let contract_address = match resp.instantiated_contract_address() {
+            Ok(contract_address) => contract_address,
+            Err(_) if CoreEnvVars::dry_run() => {
+                log::info!(target: &contract_target(), "[{}][Instantiate][DRY RUN] no address stored", self.id);
+                return Ok(resp);
+            }
+            Err(e) => return Err(e.into()),
+        };
 
         self.set_address(&contract_address);
+        self.chain.state().set_admin(&self.id, admin);
 
         log::info!(
             target: &&contract_target(),
@@ -270,7 +460,27 @@ impl<Chain: TxHandler> Contract<Chain> {
         Ok(resp)
     }
 
+    /// Records the metadata of the contract in a metadata registry contract
+    pub fn push_metadata(
+        &self,
+        registry: &Addr,
+        metadata: &ContractMetadata,
+    ) -> Result<TxResponse<Chain>, CwEnvError> {
+        let msg = MetadataRegistryExecuteMsg::SetMetadata {
+            contract: self.address()?.to_string(),
+            metadata: metadata.clone(),
+        };
+        log::info!(
+            target: &contract_target(),
+            "[{}][Metadata] pushing to registry {}",
+            self.id,
+            registry
+        );
+        self.chain.execute(&msg, &[], registry).map_err(Into::into)
+    }
+
     /// Migrates the contract
+    #[cfg_attr(feature = "tracing", tracing::instrument(name = "migrate", skip_all, fields(contract_id = %self.id)))]
     pub fn migrate<M: Serialize + Debug>(
         &self,
         migrate_msg: &M,
@@ -291,9 +501,321 @@ impl<Chain: TxHandler> Contract<Chain> {
             log_serialize_message(migrate_msg)?
         );
 
+        coverage::record(&self.id, EntryPoint::Migrate, migrate_msg);
+        let resp = self
+            .admin_env()
+            .migrate(migrate_msg, new_code_id, &self.address()?)
+            .map_err(Into::into)?;
+
+        log::info!(
+            target: &contract_target(),
+            "[{}][Migrated][{}] code-id {}",
+            self.id,
+            self.address()?,
+            new_code_id
+        );
+        log::debug!(
+            target: &transaction_target(),
+            "[{}][Migrated] response: {:?}",
+            self.id,
+            resp
+        );
+        Ok(resp)
+    }
+
+    /// Sets `new_admin` as the admin of the contract and records it in the state
+    pub fn update_admin(&self, new_admin: &Addr) -> Result<TxResponse<Chain>, CwEnvError> {
+        log::info!(
+            target: &contract_target(),
+            "[{}][UpdateAdmin][{}] {}",
+            self.id,
+            self.address()?,
+            new_admin
+        );
+        let resp = self
+            .admin_env()
+            .update_admin(&self.address()?, new_admin)
+            .map_err(Into::into)?;
+        self.chain.state().set_admin(&self.id, Some(new_admin));
+        Ok(resp)
+    }
+
+    /// Removes the admin of the contract, which can't be migrated anymore, and records it in the state
+    pub fn clear_admin(&self) -> Result<TxResponse<Chain>, CwEnvError> {
+        log::info!(
+            target: &contract_target(),
+            "[{}][ClearAdmin][{}]",
+            self.id,
+            self.address()?,
+        );
+        let resp = self
+            .admin_env()
+            .clear_admin(&self.address()?)
+            .map_err(Into::into)?;
+        self.chain.state().set_admin(&self.id, None);
+        Ok(resp)
+    }
+
+    /// Calls the `sudo` entry point of the contract
+    pub fn sudo<S: Serialize + Debug>(
+        &self,
+        sudo_msg: &S,
+    ) -> Result<TxResponse<Chain>, CwEnvError> {
+        log::info!(
+            target: &contract_target(),
+            "[{}][Sudo][{}]",
+            self.id,
+            self.address()?,
+        );
+        log::debug!(
+            target: &contract_target(),
+            "[{}][Sudo] {}",
+            self.id,
+            log_serialize_message(sudo_msg)?
+        );
+
+        let resp = self
+            .chain
+            .sudo(sudo_msg, &self.address()?)
+            .map_err(Into::into)?;
+
+        log::debug!(
+            target: &transaction_target(),
+            "[{}][Sudo] response: {:?}",
+            self.id,
+            resp
+        );
+        Ok(resp)
+    }
+}
+
+/// Async versions of the chain interfaces, for environments implementing [`AsyncTxHandler`]
+impl<Chain: AsyncTxHandler> Contract<Chain> {
+    /// Upload a contract given its source and specify the permissions for instantiating
+    #[cfg_attr(feature = "tracing", tracing::instrument(name = "upload", skip_all, fields(contract_id = %self.id)))]
+    pub async fn async_upload_with_access_config(
+        &self,
+        source: &(impl Uploadable + Sync),
+        access_config: Option<AccessConfig>,
+    ) -> Result<AsyncTxResponse<Chain>, CwEnvError> {
+        log::info!(
+            target: &contract_target(),
+            "[{}][Upload]",
+            self.id,
+        );
+
         let resp = self
             .chain
+            .upload_with_access_config(source, access_config)
+            .await
+            .map_err(Into::into)?;
+        let code_id = match resp.uploaded_code_id() {
+            Ok(code_id) => code_id,
+            Err(_) if CoreEnvVars::dry_run() => {
+                log::info!(target: &contract_target(), "[{}][Upload][DRY RUN] no code_id stored", self.id);
+                return Ok(resp);
+            }
+            Err(e) => return Err(e.into()),
+        };
+        self.set_code_id(code_id);
+        log::info!(
+            target: &contract_target(),
+            "[{}][Uploaded] code_id {}",
+            self.id,
+            code_id
+        );
+        log::debug!(
+            target: &contract_target(),
+            "[{}][Uploaded] response {:?}",
+            self.id,
+            resp
+        );
+        Ok(resp)
+    }
+
+    /// Upload a contract given its source
+    pub async fn async_upload(
+        &self,
+        source: &(impl Uploadable + Sync),
+    ) -> Result<AsyncTxResponse<Chain>, CwEnvError> {
+        self.async_upload_with_access_config(source, None).await
+    }
+
+    /// Executes an operation on the contract
+    #[cfg_attr(feature = "tracing", tracing::instrument(name = "execute", skip_all, fields(contract_id = %self.id)))]
+    pub async fn async_execute<E: Serialize + Debug + Sync>(
+        &self,
+        msg: &E,
+        coins: &[Coin],
+    ) -> Result<AsyncTxResponse<Chain>, CwEnvError> {
+        log::info!(
+            target: &contract_target(),
+            "[{}][Execute][{}] {}",
+            self.id,
+            self.address()?,
+            get_struct_name(msg)?
+        );
+
+        log::debug!(
+            target: &contract_target(),
+            "[{}][Execute] {}",
+            self.id,
+            log_serialize_message(msg)?
+        );
+
+        coverage::record(&self.id, EntryPoint::Execute, msg);
+        let resp = self
+            .operator_env()
+            .execute(msg, coins, &self.address()?)
+            .await;
+
+        log::info!(
+            target: &contract_target(),
+            "[{}][Executed][{}] {}",
+            self.id,
+            self.address()?,
+            get_struct_name(msg)?
+        );
+        log::debug!(
+            target: &transaction_target(),
+            "[{}][Executed] response: {:?}",
+            self.id,
+            resp
+        );
+
+        resp.map_err(Into::into)
+    }
+
+    /// Initializes the contract
+    ///
+    /// Address placeholders (see [`super::AddrOf`]) inside `msg` are resolved from the state before sending.
+    #[cfg_attr(feature = "tracing", tracing::instrument(name = "instantiate", skip_all, fields(contract_id = %self.id)))]
+    pub async fn async_instantiate<I: Serialize + Debug + Sync>(
+        &self,
+        msg: &I,
+        admin: Option<&Addr>,
+        coins: &[Coin],
+    ) -> Result<AsyncTxResponse<Chain>, CwEnvError> {
+        self.async_instantiate_inner(msg, admin, coins, None).await
+    }
+
+    /// Initializes the contract using instantiate2
+    ///
+    /// Address placeholders (see [`super::AddrOf`]) inside `msg` are resolved from the state before sending.
+    #[cfg_attr(feature = "tracing", tracing::instrument(name = "instantiate2", skip_all, fields(contract_id = %self.id)))]
+    pub async fn async_instantiate2<I: Serialize + Debug + Sync>(
+        &self,
+        msg: &I,
+        admin: Option<&Addr>,
+        coins: &[Coin],
+        salt: Binary,
+    ) -> Result<AsyncTxResponse<Chain>, CwEnvError> {
+        self.async_instantiate_inner(msg, admin, coins, Some(salt))
+            .await
+    }
+
+    async fn async_instantiate_inner<I: Serialize + Debug + Sync>(
+        &self,
+        msg: &I,
+        admin: Option<&Addr>,
+        coins: &[Coin],
+        salt: Option<Binary>,
+    ) -> Result<AsyncTxResponse<Chain>, CwEnvError> {
+        log::info!(
+            target: &contract_target(),
+            "[{}][Instantiate]",
+            self.id,
+        );
+
+        log::debug!(
+            target: &contract_target(),
+            "[{}][Instantiate] {}",
+            self.id,
+            log_serialize_message(msg)?
+        );
+
+        let code_id = self.code_id()?;
+        coverage::record(&self.id, EntryPoint::Instantiate, msg);
+        let label = self.label();
+        let resolved = resolve_placeholders(msg, &self.chain.state())?;
+        let resp = match (resolved, salt) {
+            (Some(resolved), Some(salt)) => {
+                self.chain
+                    .instantiate2(code_id, &resolved, Some(&label), admin, coins, salt)
+                    .await
+            }
+            (Some(resolved), None) => {
+                self.chain
+                    .instantiate(code_id, &resolved, Some(&label), admin, coins)
+                    .await
+            }
+            (None, Some(salt)) => {
+                self.chain
+                    .instantiate2(code_id, msg, Some(&label), admin, coins, salt)
+                    .await
+            }
+            (None, None) => {
+                self.chain
+                    .instantiate(code_id, msg, Some(&label), admin, coins)
+                    .await
+            }
+        }
+        .map_err(Into::into)?;
+        let contract_address = match resp.instantiated_contract_address() {
+            Ok(contract_address) => contract_address,
+            Err(_) if CoreEnvVars::dry_run() => {
+                log::info!(target: &contract_target(), "[{}][Instantiate][DRY RUN] no address stored", self.id);
+                return Ok(resp);
+            }
+            Err(e) => return Err(e.into()),
+        };
+
+        self.set_address(&contract_address);
+        self.chain.state().set_admin(&self.id, admin);
+
+        log::info!(
+            target: &&contract_target(),
+            "[{}][Instantiated] {}",
+            self.id,
+            contract_address
+        );
+        log::debug!(
+            target: &&transaction_target(),
+            "[{}][Instantiated] response: {:?}",
+            self.id,
+            resp
+        );
+
+        Ok(resp)
+    }
+
+    /// Migrates the contract
+    #[cfg_attr(feature = "tracing", tracing::instrument(name = "migrate", skip_all, fields(contract_id = %self.id)))]
+    pub async fn async_migrate<M: Serialize + Debug + Sync>(
+        &self,
+        migrate_msg: &M,
+        new_code_id: u64,
+    ) -> Result<AsyncTxResponse<Chain>, CwEnvError> {
+        log::info!(
+            target: &contract_target(),
+            "[{}][Migrate][{}]",
+            self.id,
+            self.address()?,
+        );
+
+        log::debug!(
+            target: &contract_target(),
+            "[{}][Migrate] code-id: {}, msg: {}",
+            self.id,
+            new_code_id,
+            log_serialize_message(migrate_msg)?
+        );
+
+        coverage::record(&self.id, EntryPoint::Migrate, migrate_msg);
+        let resp = self
+            .admin_env()
             .migrate(migrate_msg, new_code_id, &self.address()?)
+            .await
             .map_err(Into::into)?;
 
         log::info!(
@@ -313,7 +835,93 @@ impl<Chain: TxHandler> Contract<Chain> {
     }
 }
 
+impl<Chain: CwEnv> Contract<Chain> {
+    /// Address of a contract already instantiated by the sender with the [`Contract::label`] of this contract
+    pub fn find_by_label(&self) -> Result<Option<Addr>, CwEnvError> {
+        self.chain
+            .wasm_querier()
+            .contract_by_label(&self.chain.sender_addr(), &self.label())
+            .map_err(Into::into)
+    }
+
+    /// Instantiates the contract, unless the sender already instantiated a contract with the same [`Contract::label`].
+    ///
+    /// The existing contract is then adopted into the state instead of instantiating a duplicate and `None` is returned.
+    /// It is only adopted if it runs the code id of this contract, and once confirmed by the user, unless manual interactions are disabled,
+    /// see [`Contract::set_manual_interaction`].
+    pub fn instantiate_or_adopt<I: Serialize + Debug>(
+        &self,
+        msg: &I,
+        admin: Option<&Addr>,
+        coins: &[Coin],
+    ) -> Result<Option<TxResponse<Chain>>, CwEnvError> {
+        if let Some(existing) = self.find_by_label()? {
+            let info = self
+                .chain
+                .wasm_querier()
+                .contract_info(&existing)
+                .map_err(Into::into)?;
+            let code_id = self.code_id()?;
+            if info.code_id != code_id || info.creator != self.chain.sender_addr() {
+                log::warn!(
+                    target: &contract_target(),
+                    "[{}] Not adopting {}, instantiated by {} with code id {}, expected the sender and code id {}",
+                    self.id,
+                    existing,
+                    info.creator,
+                    info.code_id,
+                    code_id
+                );
+            } else if confirm_adoption(
+                &self.label(),
+                &existing,
+                self.manual_interaction
+                    .unwrap_or_else(CoreEnvVars::manual_interaction),
+            )? {
+                self.set_address(&existing);
+                self.chain.state().set_admin(&self.id, info.admin.as_ref());
+                log::info!(
+                    target: &contract_target(),
+                    "[{}][Adopted] {}",
+                    self.id,
+                    existing
+                );
+                return Ok(None);
+            }
+        }
+        self.instantiate(msg, admin, coins).map(Some)
+    }
+}
+
+/// Asks the user whether the contract found at `address` with `label` should be adopted
+fn confirm_adoption(
+    label: &str,
+    address: &Addr,
+    manual_interaction: bool,
+) -> Result<bool, CwEnvError> {
+    log::warn!(
+        target: &contract_target(),
+        "A contract with the label {label} was already instantiated by the sender at {address}"
+    );
+    if !manual_interaction {
+        return Ok(true);
+    }
+    eprint!("Adopt {address} instead of instantiating a new contract? [Y/n] ");
+    let mut input = String::new();
+    std::io::stdin().read_line(&mut input)?;
+    Ok(!input.trim().eq_ignore_ascii_case("n"))
+}
+
 impl<Chain: ChainState + QueryHandler> Contract<Chain> {
+    /// Code history of the contract: its instantiation and migrations, oldest first.
+    /// Empty if the environment doesn't record it, see [`WasmQuerier::contract_history`].
+    pub fn history(&self) -> Result<Vec<ContractHistoryEntry>, CwEnvError> {
+        self.chain
+            .wasm_querier()
+            .contract_history(&self.address()?)
+            .map_err(Into::into)
+    }
+
     /// Query the contract
     pub fn query<Q: Serialize + Debug, T: Serialize + DeserializeOwned + Debug>(
         &self,
@@ -327,6 +935,7 @@ impl<Chain: ChainState + QueryHandler> Contract<Chain> {
             log_serialize_message(query_msg)?
         );
 
+        coverage::record(&self.id, EntryPoint::Query, query_msg);
         let resp = self
             .chain
             .query(query_msg, &self.address()?)
@@ -341,6 +950,33 @@ impl<Chain: ChainState + QueryHandler> Contract<Chain> {
         );
         Ok(resp)
     }
+
+    /// Queries the contract with a JSON encoded message and returns the JSON encoded response, bypassing the typed messages.
+    /// Useful when the msg crate of the contract doesn't match the deployed version.
+    pub fn query_raw_smart(&self, query_msg: Binary) -> Result<Binary, CwEnvError> {
+        let query_msg: serde_json::Value = serde_json::from_slice(query_msg.as_slice())?;
+        log::debug!(
+            target: &contract_target(),
+            "[{}][Query][{}] {}",
+            self.id,
+            self.address()?,
+            query_msg
+        );
+
+        let resp: serde_json::Value = self
+            .chain
+            .query(&query_msg, &self.address()?)
+            .map_err(Into::into)?;
+
+        log::debug!(
+            target: &contract_target(),
+            "[{}][Queried][{}] response {}",
+            self.id,
+            self.address()?,
+            resp
+        );
+        Ok(serde_json::to_vec(&resp)?.into())
+    }
 }
 
 impl<Chain: AsyncWasmQuerier + ChainState> Contract<Chain> {
@@ -360,6 +996,7 @@ impl<Chain: AsyncWasmQuerier + ChainState> Contract<Chain> {
             log_serialize_message(query_msg)?
         );
 
+        coverage::record(&self.id, EntryPoint::Query, query_msg);
         let resp = self
             .chain
             .smart_query(&self.address()?, query_msg)
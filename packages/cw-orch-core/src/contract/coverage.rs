@@ -0,0 +1,363 @@
+//! Message-level coverage of the contract endpoints exercised by a test run.
+//!
+//! Once started, the recorder counts the `ExecuteMsg` and `QueryMsg` variants sent through every [`Contract`](super::Contract), by contract id.
+//! With the `coverage` feature, the message types registered with [`EndpointCoverage::expect`] are reported with the variants that were never called.
+//! It also keeps the trace of every entry point called, see [`EndpointCoverage::trace`].
+//!
+//! The recorder is kept per thread, so that tests running in parallel don't record each others calls.
+//! Calls made from other threads, such as spawned tasks, are not recorded.
+//!
+//! ```
+//! # #[cfg(feature = "coverage")]
+//! # {
+//! use cw_orch_core::contract::coverage::{EndpointCoverage, EntryPoint};
+//! # #[derive(serde::Serialize, schemars::JsonSchema)]
+//! # #[serde(rename_all = "snake_case")]
+//! # pub enum ExecuteMsg { Increment {}, Reset { count: i32 } }
+//!
+//! EndpointCoverage::start();
+//! EndpointCoverage::expect::<ExecuteMsg>("counter", EntryPoint::Execute);
+//!
+//! // ... run the integration tests
+//!
+//! let report = EndpointCoverage::report();
+//! println!("{report}");
+//! # }
+//! ```
+use std::{
+    cell::RefCell,
+    collections::{BTreeMap, BTreeSet},
+    fmt::Display,
+};
+
+#[cfg(feature = "coverage")]
+use schemars::{
+    schema::{Schema, SchemaObject},
+    JsonSchema,
+};
+use serde::Serialize;
+
+thread_local! {
+    static RECORDER: RefCell<Option<Recorder>> = const { RefCell::new(None) };
+}
+
+/// Contract id and entry point the variants are recorded for
+type EndpointKey = (String, EntryPoint);
+
+#[derive(Default)]
+struct Recorder {
+    /// Variants of the registered message types
+    expected: BTreeMap<EndpointKey, BTreeSet<String>>,
+    /// Number of calls of each variant
+    calls: BTreeMap<EndpointKey, BTreeMap<String, u64>>,
+    /// Entry points called, in order
+    trace: Vec<ContractCall>,
+}
+
+/// Entry point of a contract
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum EntryPoint {
+    Instantiate,
+    Execute,
+    Query,
+    Migrate,
+}
+
+/// Call of a contract entry point, as recorded in the trace
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ContractCall {
+    /// Id of the contract called
+    pub contract_id: String,
+    /// Entry point called
+    pub entry_point: EntryPoint,
+    /// Variant of the message, for the `ExecuteMsg` and `QueryMsg` enums
+    pub variant: Option<String>,
+}
+
+/// Per-thread recorder of the contract endpoints called
+pub struct EndpointCoverage;
+
+impl EndpointCoverage {
+    /// Starts recording on the current thread, discarding what was previously recorded
+    pub fn start() {
+        RECORDER.with_borrow_mut(|recorder| *recorder = Some(Recorder::default()));
+    }
+
+    /// Stops recording and returns the final report
+    pub fn stop() -> CoverageReport {
+        let report = Self::report();
+        RECORDER.with_borrow_mut(|recorder| *recorder = None);
+        report
+    }
+
+    /// Returns true if the endpoints are being recorded on the current thread
+    pub fn is_recording() -> bool {
+        RECORDER.with_borrow(Option::is_some)
+    }
+
+    /// Registers all the variants of the message type `M` for the entry point of the contract, so that the ones never called are reported.
+    /// Does nothing if the recorder is not started.
+    #[cfg(feature = "coverage")]
+    pub fn expect<M: JsonSchema>(contract_id: &str, entry_point: EntryPoint) {
+        RECORDER.with_borrow_mut(|recorder| {
+            if let Some(recorder) = recorder.as_mut() {
+                let schema = schemars::schema_for!(M);
+                let mut variants = BTreeSet::new();
+                collect_variants(&schema.schema, &mut variants);
+                recorder
+                    .expected
+                    .entry((contract_id.to_string(), entry_point))
+                    .or_default()
+                    .extend(variants);
+            }
+        })
+    }
+
+    /// Entry points called since the recorder started, in order
+    pub fn trace() -> Vec<ContractCall> {
+        RECORDER.with_borrow(|recorder| {
+            recorder
+                .as_ref()
+                .map(|recorder| recorder.trace.clone())
+                .unwrap_or_default()
+        })
+    }
+
+    /// Coverage of the endpoints recorded so far
+    pub fn report() -> CoverageReport {
+        RECORDER.with_borrow(|recorder| {
+            let Some(recorder) = recorder.as_ref() else {
+                return CoverageReport::default();
+            };
+            let endpoints: BTreeSet<&EndpointKey> = recorder
+                .expected
+                .keys()
+                .chain(recorder.calls.keys())
+                .collect();
+            let msgs = endpoints
+                .into_iter()
+                .map(|endpoint| {
+                    let calls = recorder.calls.get(endpoint).cloned().unwrap_or_default();
+                    let unexercised = recorder
+                        .expected
+                        .get(endpoint)
+                        .into_iter()
+                        .flatten()
+                        .filter(|variant| !calls.contains_key(*variant))
+                        .cloned()
+                        .collect();
+                    MsgCoverage {
+                        contract_id: endpoint.0.clone(),
+                        entry_point: endpoint.1,
+                        calls,
+                        unexercised,
+                    }
+                })
+                .collect();
+            CoverageReport { msgs }
+        })
+    }
+}
+
+/// Records a message sent to the entry point of a contract, if the recorder is started on the current thread
+pub(crate) fn record<M: Serialize>(contract_id: &str, entry_point: EntryPoint, msg: &M) {
+    RECORDER.with_borrow_mut(|recorder| {
+        let Some(recorder) = recorder.as_mut() else {
+            return;
+        };
+        // Instantiate and migrate messages are usually structs, whose fields aren't variants
+        let variant = match entry_point {
+            EntryPoint::Execute | EntryPoint::Query => match serde_json::to_value(msg) {
+                Ok(serde_json::Value::Object(map)) if map.len() == 1 => map.keys().next().cloned(),
+                Ok(serde_json::Value::String(variant)) => Some(variant),
+                _ => None,
+            },
+            EntryPoint::Instantiate | EntryPoint::Migrate => None,
+        };
+        if let Some(variant) = &variant {
+            *recorder
+                .calls
+                .entry((contract_id.to_string(), entry_point))
+                .or_default()
+                .entry(variant.clone())
+                .or_default() += 1;
+        }
+        recorder.trace.push(ContractCall {
+            contract_id: contract_id.to_string(),
+            entry_point,
+            variant,
+        });
+    })
+}
+
+/// Enum variants are serialized as `{"variant": {...}}`, or `"variant"` for unit variants
+#[cfg(feature = "coverage")]
+fn collect_variants(schema: &SchemaObject, variants: &mut BTreeSet<String>) {
+    if let Some(values) = &schema.enum_values {
+        variants.extend(
+            values
+                .iter()
+                .filter_map(|v| v.as_str().map(ToString::to_string)),
+        );
+    }
+    if let Some(object) = &schema.object {
+        if object.required.len() == 1 {
+            variants.extend(object.required.iter().cloned());
+        }
+    }
+    if let Some(subschemas) = &schema.subschemas {
+        let one_of = subschemas.one_of.iter().flatten();
+        let any_of = subschemas.any_of.iter().flatten();
+        for schema in one_of.chain(any_of) {
+            if let Schema::Object(schema) = schema {
+                collect_variants(schema, variants);
+            }
+        }
+    }
+}
+
+/// Coverage of the endpoints of all the recorded message types
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CoverageReport {
+    /// Coverage by endpoint, sorted by contract id and entry point
+    pub msgs: Vec<MsgCoverage>,
+}
+
+/// Coverage of the message variants of a contract entry point
+#[derive(Clone, Debug, PartialEq)]
+pub struct MsgCoverage {
+    /// Id of the contract called
+    pub contract_id: String,
+    /// Entry point called
+    pub entry_point: EntryPoint,
+    /// Number of calls of each variant
+    pub calls: BTreeMap<String, u64>,
+    /// Registered variants that were never called
+    pub unexercised: Vec<String>,
+}
+
+impl CoverageReport {
+    /// Returns true if every registered variant was called at least once
+    pub fn is_complete(&self) -> bool {
+        self.msgs.iter().all(|msg| msg.unexercised.is_empty())
+    }
+}
+
+impl Display for CoverageReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for msg in &self.msgs {
+            let total = msg.calls.len() + msg.unexercised.len();
+            writeln!(
+                f,
+                "{} {:?}: {}/{} variants called",
+                msg.contract_id,
+                msg.entry_point,
+                msg.calls.len(),
+                total
+            )?;
+            for (variant, count) in &msg.calls {
+                writeln!(f, "  {variant}: {count}")?;
+            }
+            for variant in &msg.unexercised {
+                writeln!(f, "  {variant}: never called")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Serialize)]
+    #[cfg_attr(feature = "coverage", derive(JsonSchema))]
+    #[serde(rename_all = "snake_case")]
+    enum ExecuteMsg {
+        Increment {},
+        Reset { count: i32 },
+        Pause,
+    }
+
+    #[derive(Serialize)]
+    struct InstantiateMsg {
+        count: i32,
+    }
+
+    #[test]
+    #[cfg(feature = "coverage")]
+    fn reports_unexercised_variants() {
+        EndpointCoverage::start();
+        EndpointCoverage::expect::<ExecuteMsg>("counter", EntryPoint::Execute);
+        record(
+            "counter",
+            EntryPoint::Instantiate,
+            &InstantiateMsg { count: 0 },
+        );
+        record("counter", EntryPoint::Execute, &ExecuteMsg::Increment {});
+        record("counter", EntryPoint::Execute, &ExecuteMsg::Increment {});
+        record("counter", EntryPoint::Execute, &ExecuteMsg::Pause);
+
+        let trace = EndpointCoverage::trace();
+        assert_eq!(trace.len(), 4);
+        assert_eq!(
+            trace[0],
+            ContractCall {
+                contract_id: "counter".to_string(),
+                entry_point: EntryPoint::Instantiate,
+                variant: None,
+            }
+        );
+        assert_eq!(trace[3].variant, Some("pause".to_string()));
+
+        let report = EndpointCoverage::stop();
+        assert!(!report.is_complete());
+        let msg = report
+            .msgs
+            .iter()
+            .find(|msg| msg.contract_id == "counter" && msg.entry_point == EntryPoint::Execute)
+            .unwrap();
+        assert_eq!(
+            msg.calls,
+            BTreeMap::from([("increment".to_string(), 2), ("pause".to_string(), 1)])
+        );
+        assert_eq!(msg.unexercised, vec!["reset".to_string()]);
+
+        // Nothing is recorded once stopped
+        record(
+            "counter",
+            EntryPoint::Execute,
+            &ExecuteMsg::Reset { count: 0 },
+        );
+        assert!(!EndpointCoverage::is_recording());
+        assert_eq!(EndpointCoverage::report(), CoverageReport::default());
+    }
+
+    #[test]
+    fn records_per_thread() {
+        EndpointCoverage::start();
+        record("counter", EntryPoint::Execute, &ExecuteMsg::Pause);
+
+        let other = std::thread::spawn(|| {
+            // Not recording until started on this thread
+            record("other", EntryPoint::Execute, &ExecuteMsg::Increment {});
+            assert!(!EndpointCoverage::is_recording());
+
+            EndpointCoverage::start();
+            record("other", EntryPoint::Execute, &ExecuteMsg::Increment {});
+            EndpointCoverage::stop()
+        })
+        .join()
+        .unwrap();
+        assert_eq!(other.msgs.len(), 1);
+        assert_eq!(other.msgs[0].contract_id, "other");
+
+        let report = EndpointCoverage::stop();
+        assert_eq!(report.msgs.len(), 1);
+        assert_eq!(report.msgs[0].contract_id, "counter");
+        assert_eq!(
+            report.msgs[0].calls,
+            BTreeMap::from([("pause".to_string(), 1)])
+        );
+    }
+}
@@ -1,10 +1,17 @@
 //! Introduces the Deploy trait only
+use std::collections::HashMap;
 use std::error::Error;
+use std::path::{Path, PathBuf};
 
-use crate::environment::CwEnv;
+use cosmwasm_std::Empty;
+
+use crate::environment::{ChainState, CwEnv};
+use crate::log::local_target;
 use crate::CwEnvError;
 
+use super::artifacts::ArtifactBundle;
 use super::interface_traits::ContractInstance;
+use super::rollback::{RollbackReport, StateSnapshot};
 
 /// Indicates the ability to deploy an application to a mock chain.
 ///
@@ -52,6 +59,9 @@ use super::interface_traits::ContractInstance;
 ///
 /// This allows other developers to re-use the application's deployment logic in their own tests.
 /// Allowing them to build on the application's functionality without having to re-implement its deployment.
+///
+/// When rehearsing a deployment with `CW_ORCH_DRY_RUN=true`, transactions are only simulated on live chains.
+/// Implementations can branch on [`crate::CoreEnvVars::dry_run`] to skip steps that depend on on-chain results.
 pub trait Deploy<Chain: CwEnv>: Sized {
     /// Error type returned by the deploy functions.  
     type Error: Error + From<CwEnvError>;
@@ -71,4 +81,66 @@ pub trait Deploy<Chain: CwEnv>: Sized {
     /// Load the application from the chain, assuming it has already been deployed.
     /// In order to leverage the deployed state, don't forget to call `Self::set_contracts_state` after loading the contract objects
     fn load_from(chain: Chain) -> Result<Self, Self::Error>;
+
+    /// Tears the deployment down, to clean up ephemeral deployments (on testnets for instance).
+    /// If a `tombstone_code_id` is provided, every instantiated contract is migrated to it with an empty migrate message.
+    /// This requires the sender to be the admin of the contracts.
+    /// The addresses and code-ids of the contracts are then removed from the state.
+    fn teardown(&mut self, tombstone_code_id: Option<u64>) -> Result<(), Self::Error> {
+        for contract in self.get_contracts_mut() {
+            if let (Some(code_id), Ok(_)) = (tombstone_code_id, contract.address()) {
+                contract.as_instance().migrate(&Empty {}, code_id)?;
+            }
+            contract.remove_address();
+            contract.remove_code_id();
+        }
+        Ok(())
+    }
+
+    /// Returns the path of the JSON schema (`cargo schema` output) of the contracts, by contract id.
+    /// Used by [`Deploy::export_artifacts`] to export the contract ABIs. Defaults to none.
+    fn artifact_schemas(&self) -> HashMap<String, PathBuf> {
+        HashMap::new()
+    }
+
+    /// Exports the addresses, code-ids and schemas of the deployment to `dir`, for frontend clients.
+    /// See [`ArtifactBundle`] for the exported files.
+    fn export_artifacts(&mut self, dir: impl AsRef<Path>) -> Result<ArtifactBundle, Self::Error> {
+        let schemas = self.artifact_schemas();
+        let bundle = ArtifactBundle::new(&self.get_contracts_mut(), &schemas)?;
+        bundle.write_to(dir, &schemas)?;
+        Ok(bundle)
+    }
+
+    /// Deploys the application in transactional mode.
+    /// The state is snapshotted before calling [`Deploy::deploy_on`]. If the deployment fails, the state is rolled back with [`Deploy::rollback`]
+    /// and the on-chain actions that can't be undone are logged.
+    fn deploy_transactional(chain: Chain, data: Self::DeployData) -> Result<Self, Self::Error> {
+        let snapshot = StateSnapshot::take(&chain.state())?;
+        Self::deploy_on(chain.clone(), data).map_err(|error| {
+            match Self::rollback(&chain, &snapshot) {
+                Ok(report) => {
+                    log::warn!(
+                        target: &local_target(),
+                        "Deployment failed, state rolled back for {:?}",
+                        report.restored
+                    );
+                    for action in report.irreversible {
+                        log::warn!(target: &local_target(), "Not undone: {action}");
+                    }
+                }
+                Err(rollback_error) => log::error!(
+                    target: &local_target(),
+                    "Deployment failed and the rollback failed too: {rollback_error}"
+                ),
+            }
+            error
+        })
+    }
+
+    /// Restores the state `snapshot` taken before a deployment.
+    /// Returns the on-chain actions that can't be undone: the uploaded code and the instantiated contracts stay on chain.
+    fn rollback(chain: &Chain, snapshot: &StateSnapshot) -> Result<RollbackReport, Self::Error> {
+        Ok(snapshot.restore(&mut chain.state())?)
+    }
 }
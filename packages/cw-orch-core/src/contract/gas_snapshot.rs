@@ -0,0 +1,267 @@
+//! Golden files of the gas used by named test scenarios.
+//!
+//! [`GasSnapshot`] records the gas used by each scenario of a test run and compares it with the values of a committed JSON file.
+//! Gas changes beyond the threshold fail the comparison, or are only logged in warning mode.
+//! Running the tests with `CW_ORCH_UPDATE_GAS_SNAPSHOTS=true` overwrites the file with the new values instead.
+//!
+//! The gas of a transaction comes from [`IndexResponse::gas_used`] on the daemon environments.
+//! This is the gas executed by the node, not the simulated gas (which is scaled by the gas adjustment of the chain), so it doesn't depend on the gas settings.
+//!
+//! cw-multi-test doesn't meter gas, so Mock responses don't report it and [`GasSnapshot::record_tx`] errors on Mock.
+//! There, the estimated gas of a scenario is read from the block gas accounting (`MockBase::block_gas_used`) and recorded with [`GasSnapshot::record`].
+//!
+//! ```rust,ignore
+//! use cw_orch_core::contract::gas_snapshot::GasSnapshot;
+//!
+//! let mut gas = GasSnapshot::new("tests/gas.json").threshold(0.05);
+//! let response = contract.execute(&ExecuteMsg::Increment {}, &[])?;
+//! gas.record_tx("increment", &response)?;
+//! gas.check()?;
+//! ```
+use std::{
+    collections::BTreeMap,
+    fmt::Display,
+    path::{Path, PathBuf},
+};
+
+use crate::{environment::IndexResponse, CoreEnvVars, CwEnvError};
+
+/// Gas used by the scenarios of a test run, compared with a golden file
+#[derive(Debug, Clone)]
+pub struct GasSnapshot {
+    path: PathBuf,
+    threshold: f64,
+    warn_only: bool,
+    update: bool,
+    recorded: BTreeMap<String, u64>,
+}
+
+impl GasSnapshot {
+    /// Snapshot compared with the golden file at `path`.
+    /// Any gas change fails the comparison by default.
+    pub fn new(path: impl AsRef<Path>) -> Self {
+        Self {
+            path: path.as_ref().to_path_buf(),
+            threshold: 0.0,
+            warn_only: false,
+            update: CoreEnvVars::update_gas_snapshots(),
+            recorded: BTreeMap::new(),
+        }
+    }
+
+    /// Sets the relative gas change tolerated, e.g. `0.05` for 5%
+    pub fn threshold(mut self, threshold: f64) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    /// Only logs the gas changes beyond the threshold, instead of failing
+    pub fn warn_only(mut self) -> Self {
+        self.warn_only = true;
+        self
+    }
+
+    /// Overwrites the golden file with the recorded values instead of comparing them
+    pub fn update(mut self) -> Self {
+        self.update = true;
+        self
+    }
+
+    /// Records the gas used by `scenario`
+    pub fn record(&mut self, scenario: impl Into<String>, gas: u64) {
+        self.recorded.insert(scenario.into(), gas);
+    }
+
+    /// Records the gas executed by the transaction `response` as `scenario`.
+    /// Errors if the response doesn't report its gas, which is the case on Mock.
+    pub fn record_tx(
+        &mut self,
+        scenario: impl Into<String>,
+        response: &impl IndexResponse,
+    ) -> Result<(), CwEnvError> {
+        let scenario = scenario.into();
+        let gas = response.gas_used().ok_or_else(|| {
+            CwEnvError::StdErr(format!(
+                "The response of the scenario {scenario} doesn't report the gas used, record the gas with `GasSnapshot::record` instead"
+            ))
+        })?;
+        self.record(scenario, gas);
+        Ok(())
+    }
+
+    /// Compares the recorded gas with the golden file, or updates it.
+    /// The scenarios missing from the golden file are added to it.
+    /// Errors if the gas of a scenario changed beyond the threshold, unless in warning mode.
+    pub fn check(&self) -> Result<GasReport, CwEnvError> {
+        let mut golden: BTreeMap<String, u64> = if self.path.exists() {
+            serde_json::from_slice(&std::fs::read(&self.path)?)?
+        } else {
+            BTreeMap::new()
+        };
+
+        let report = GasReport {
+            deltas: self
+                .recorded
+                .iter()
+                .map(|(scenario, gas)| GasDelta {
+                    scenario: scenario.clone(),
+                    previous: golden.get(scenario).copied(),
+                    current: *gas,
+                })
+                .collect(),
+        };
+
+        let exceeding = report.exceeding(self.threshold);
+        let new_scenarios = report.deltas.iter().any(|delta| delta.previous.is_none());
+        if self.update || new_scenarios {
+            for (scenario, gas) in &self.recorded {
+                if self.update || !golden.contains_key(scenario) {
+                    golden.insert(scenario.clone(), *gas);
+                }
+            }
+            if let Some(parent) = self.path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            std::fs::write(&self.path, serde_json::to_string_pretty(&golden)?)?;
+        }
+
+        if self.update || exceeding.is_empty() {
+            return Ok(report);
+        }
+        let message = format!(
+            "Gas changed beyond {}% in {}:\n{}",
+            self.threshold * 100.0,
+            self.path.display(),
+            GasReport {
+                deltas: exceeding.into_iter().cloned().collect()
+            }
+        );
+        if self.warn_only {
+            log::warn!("{message}");
+            Ok(report)
+        } else {
+            Err(CwEnvError::StdErr(message))
+        }
+    }
+}
+
+/// Gas used by the recorded scenarios, compared with the golden file
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GasReport {
+    /// Gas change of each recorded scenario
+    pub deltas: Vec<GasDelta>,
+}
+
+impl GasReport {
+    /// Scenarios whose gas changed by more than `threshold`, relatively to the golden file
+    pub fn exceeding(&self, threshold: f64) -> Vec<&GasDelta> {
+        self.deltas
+            .iter()
+            .filter(|delta| {
+                delta
+                    .relative_change()
+                    .is_some_and(|change| change.abs() > threshold)
+            })
+            .collect()
+    }
+}
+
+impl Display for GasReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for delta in &self.deltas {
+            writeln!(f, "{delta}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Gas of a scenario in the golden file and in the current run
+#[derive(Debug, Clone, PartialEq)]
+pub struct GasDelta {
+    /// Name of the scenario
+    pub scenario: String,
+    /// Gas in the golden file, if the scenario was recorded before
+    pub previous: Option<u64>,
+    /// Gas of the current run
+    pub current: u64,
+}
+
+impl GasDelta {
+    /// Gas change relatively to the golden file, `None` for new scenarios
+    pub fn relative_change(&self) -> Option<f64> {
+        let previous = self.previous?;
+        if previous == 0 {
+            return Some(if self.current == 0 {
+                0.0
+            } else {
+                f64::INFINITY
+            });
+        }
+        Some((self.current as f64 - previous as f64) / previous as f64)
+    }
+}
+
+impl Display for GasDelta {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (self.previous, self.relative_change()) {
+            (Some(previous), Some(change)) => write!(
+                f,
+                "{}: {} -> {} ({:+.2}%)",
+                self.scenario,
+                previous,
+                self.current,
+                change * 100.0
+            ),
+            _ => write!(f, "{}: {} (new)", self.scenario, self.current),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn gates_gas_changes() -> Result<(), CwEnvError> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("gas.json");
+        let mut gas = GasSnapshot::new(&path).threshold(0.1);
+        gas.record("increment", 100_000);
+        // The first run writes the golden file
+        gas.check()?;
+
+        let mut gas = GasSnapshot::new(&path).threshold(0.1);
+        gas.record("increment", 105_000);
+        let report = gas.check()?;
+        assert_eq!(report.deltas[0].previous, Some(100_000));
+        assert!(report.exceeding(0.1).is_empty());
+
+        let mut gas = GasSnapshot::new(&path).threshold(0.1);
+        gas.record("increment", 120_000);
+        assert!(gas.check().is_err());
+        assert!(gas.clone().warn_only().check().is_ok());
+
+        // Updating overwrites the golden file
+        gas.clone().update().check()?;
+        assert!(gas.check().is_ok());
+        Ok(())
+    }
+
+    #[test]
+    fn adds_new_scenarios() -> Result<(), CwEnvError> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("gas.json");
+        let mut gas = GasSnapshot::new(&path);
+        gas.record("increment", 100_000);
+        gas.check()?;
+
+        let mut gas = GasSnapshot::new(&path);
+        gas.record("reset", 80_000);
+        let report = gas.check()?;
+        assert_eq!(report.to_string(), "reset: 80000 (new)\n");
+
+        let golden: BTreeMap<String, u64> = serde_json::from_slice(&std::fs::read(&path)?)?;
+        assert_eq!(golden.len(), 2);
+        Ok(())
+    }
+}
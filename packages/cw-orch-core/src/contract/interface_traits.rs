@@ -1,9 +1,10 @@
-use super::{Contract, WasmPath};
+use super::{Contract, ContractMetadata, WasmPath};
 use crate::environment::AccessConfig;
 use crate::{
     environment::{
-        AsyncWasmQuerier, ChainInfoOwned, ChainState, CwEnv, Environment, QueryHandler, TxHandler,
-        TxResponse, WasmQuerier,
+        AddressBook, AsyncTxHandler, AsyncTxResponse, AsyncWasmQuerier, BankSetter, ChainInfoOwned,
+        ChainState, CwEnv, Environment, IndexResponse, InstantiatedContract, MaybeSend,
+        QueryHandler, TxHandler, TxResponse, WasmQuerier,
     },
     error::CwEnvError,
     log::contract_target,
@@ -60,6 +61,16 @@ pub trait ContractInstance<Chain: ChainState> {
         Contract::set_default_address(self.as_instance_mut(), address)
     }
 
+    /// Sets a default address for the contract from the address registered under `alias` in `book`.
+    /// If the contract already has an address registered in the state, this won't be used.
+    fn set_default_address_from_book(
+        &mut self,
+        book: &AddressBook,
+        alias: &str,
+    ) -> Result<(), CwEnvError> {
+        Contract::set_default_address_from_book(self.as_instance_mut(), book, alias)
+    }
+
     /// Sets the code_id for the contract. Useful when the contract is already initialized
     /// and not registered in the configured state file.
     fn set_code_id(&self, code_id: u64) {
@@ -76,6 +87,20 @@ pub trait ContractInstance<Chain: ChainState> {
     fn set_default_code_id(&mut self, code_id: u64) {
         Contract::set_default_code_id(self.as_instance_mut(), code_id)
     }
+
+    /// Registers a contract instantiated by this contract (i.e. a factory) in the state under `child_id`.
+    /// The child is the first contract instantiated in `response` that matches `event_filter`.
+    fn register_child(
+        &self,
+        response: &impl IndexResponse,
+        child_id: &str,
+        event_filter: impl Fn(&InstantiatedContract) -> bool,
+    ) -> Result<Addr, CwEnvError>
+    where
+        Self: Sized,
+    {
+        Contract::register_child(self.as_instance(), response, child_id, event_filter)
+    }
 }
 
 /// Trait that indicates that the contract can be instantiated with the associated message.
@@ -112,6 +137,15 @@ pub trait CwOrchExecute<Chain: TxHandler>: ExecutableContract + ContractInstance
     ) -> Result<Chain::Response, CwEnvError> {
         self.as_instance().execute(&execute_msg, coins)
     }
+
+    /// Send a JSON encoded message to the contract, bypassing [`ExecutableContract::ExecuteMsg`].
+    fn execute_raw(
+        &self,
+        execute_msg: Binary,
+        coins: &[Coin],
+    ) -> Result<Chain::Response, CwEnvError> {
+        self.as_instance().execute_raw(execute_msg, coins)
+    }
 }
 
 impl<T: ExecutableContract + ContractInstance<Chain>, Chain: TxHandler> CwOrchExecute<Chain> for T {}
@@ -142,6 +176,21 @@ pub trait CwOrchInstantiate<Chain: TxHandler>:
         self.as_instance()
             .instantiate2(instantiate_msg, admin, coins, salt)
     }
+
+    /// Instantiates the contract, unless the sender already instantiated a contract with the same label.
+    /// See [`Contract::instantiate_or_adopt`]
+    fn instantiate_or_adopt(
+        &self,
+        instantiate_msg: &Self::InstantiateMsg,
+        admin: Option<&Addr>,
+        coins: &[Coin],
+    ) -> Result<Option<Chain::Response>, CwEnvError>
+    where
+        Chain: CwEnv,
+    {
+        self.as_instance()
+            .instantiate_or_adopt(instantiate_msg, admin, coins)
+    }
 }
 
 impl<T: InstantiableContract + ContractInstance<Chain>, Chain: TxHandler> CwOrchInstantiate<Chain>
@@ -149,6 +198,75 @@ impl<T: InstantiableContract + ContractInstance<Chain>, Chain: TxHandler> CwOrch
 {
 }
 
+/// Async smart contract execute entry point.
+pub trait AsyncCwOrchExecute<Chain: AsyncTxHandler>:
+    ExecutableContract + ContractInstance<Chain>
+where
+    <Self as ExecutableContract>::ExecuteMsg: Sync,
+{
+    /// Send a ExecuteMsg to the contract.
+    fn async_execute<'a>(
+        &'a self,
+        execute_msg: &'a Self::ExecuteMsg,
+        coins: &'a [Coin],
+    ) -> impl std::future::Future<Output = Result<AsyncTxResponse<Chain>, CwEnvError>> + Send
+    where
+        Chain: 'a,
+    {
+        self.as_instance().async_execute(execute_msg, coins)
+    }
+}
+
+impl<T: ExecutableContract + ContractInstance<Chain>, Chain: AsyncTxHandler>
+    AsyncCwOrchExecute<Chain> for T
+where
+    <T as ExecutableContract>::ExecuteMsg: Sync,
+{
+}
+
+/// Async smart contract instantiate entry point.
+pub trait AsyncCwOrchInstantiate<Chain: AsyncTxHandler>:
+    InstantiableContract + ContractInstance<Chain>
+where
+    <Self as InstantiableContract>::InstantiateMsg: Sync,
+{
+    /// Instantiates the contract.
+    fn async_instantiate<'a>(
+        &'a self,
+        instantiate_msg: &'a Self::InstantiateMsg,
+        admin: Option<&'a Addr>,
+        coins: &'a [Coin],
+    ) -> impl std::future::Future<Output = Result<AsyncTxResponse<Chain>, CwEnvError>> + Send
+    where
+        Chain: 'a,
+    {
+        self.as_instance()
+            .async_instantiate(instantiate_msg, admin, coins)
+    }
+
+    /// Instantiates the contract using instantiate2
+    fn async_instantiate2<'a>(
+        &'a self,
+        instantiate_msg: &'a Self::InstantiateMsg,
+        admin: Option<&'a Addr>,
+        coins: &'a [Coin],
+        salt: Binary,
+    ) -> impl std::future::Future<Output = Result<AsyncTxResponse<Chain>, CwEnvError>> + Send
+    where
+        Chain: 'a,
+    {
+        self.as_instance()
+            .async_instantiate2(instantiate_msg, admin, coins, salt)
+    }
+}
+
+impl<T: InstantiableContract + ContractInstance<Chain>, Chain: AsyncTxHandler>
+    AsyncCwOrchInstantiate<Chain> for T
+where
+    <T as InstantiableContract>::InstantiateMsg: Sync,
+{
+}
+
 /// Smart contract query entry point.
 pub trait CwOrchQuery<Chain: QueryHandler + ChainState>:
     QueryableContract + ContractInstance<Chain>
@@ -161,6 +279,12 @@ pub trait CwOrchQuery<Chain: QueryHandler + ChainState>:
         self.as_instance().query(query_msg)
     }
 
+    /// Query the contract with a JSON encoded message, bypassing [`QueryableContract::QueryMsg`].
+    /// Returns the JSON encoded response.
+    fn query_raw_smart(&self, query_msg: Binary) -> Result<Binary, CwEnvError> {
+        self.as_instance().query_raw_smart(query_msg)
+    }
+
     /// Query the contract raw state from an raw binary key
     fn raw_query(&self, query_keys: Vec<u8>) -> Result<Vec<u8>, CwEnvError> {
         self.environment()
@@ -200,7 +324,7 @@ where
     fn async_query<'a, G: Serialize + DeserializeOwned + Debug>(
         &'a self,
         query_msg: &Self::QueryMsg,
-    ) -> impl std::future::Future<Output = Result<G, CwEnvError>> + Send
+    ) -> impl std::future::Future<Output = Result<G, CwEnvError>> + MaybeSend
     where
         Chain: 'a,
     {
@@ -241,6 +365,32 @@ pub trait CwOrchMigrate<Chain: TxHandler>: MigratableContract + ContractInstance
 
 impl<T: MigratableContract + ContractInstance<Chain>, Chain: TxHandler> CwOrchMigrate<Chain> for T {}
 
+/// Async smart contract migrate entry point.
+pub trait AsyncCwOrchMigrate<Chain: AsyncTxHandler>:
+    MigratableContract + ContractInstance<Chain>
+where
+    <Self as MigratableContract>::MigrateMsg: Sync,
+{
+    /// Migrate the contract.
+    fn async_migrate<'a>(
+        &'a self,
+        migrate_msg: &'a Self::MigrateMsg,
+        new_code_id: u64,
+    ) -> impl std::future::Future<Output = Result<AsyncTxResponse<Chain>, CwEnvError>> + Send
+    where
+        Chain: 'a,
+    {
+        self.as_instance().async_migrate(migrate_msg, new_code_id)
+    }
+}
+
+impl<T: MigratableContract + ContractInstance<Chain>, Chain: AsyncTxHandler>
+    AsyncCwOrchMigrate<Chain> for T
+where
+    <T as MigratableContract>::MigrateMsg: Sync,
+{
+}
+
 /// Trait to implement on the contract to enable it to be uploaded
 ///
 /// Should return [`WasmPath`](crate::contract::interface_traits::WasmPath) for `Chain = Daemon`
@@ -251,10 +401,21 @@ pub trait Uploadable {
         unimplemented!("no wasm file provided for this contract")
     }
 
+    /// Return the WASM file of a given version of the contract, e.g. to test the migrations from the previous versions.
+    /// It is looked up next to the file returned by [`Uploadable::wasm`], see [`ArtifactsDir::find_wasm_path_for_version`](super::ArtifactsDir::find_wasm_path_for_version).
+    fn wasm_for_version(chain: &ChainInfoOwned, version: &str) -> Result<WasmPath, CwEnvError> {
+        super::ArtifactsDir::find_version_of(&Self::wasm(chain), version)
+    }
+
     /// Return the wrapper object for the contract, only works for non-custom mock environments
     fn wrapper() -> Box<dyn MockContract<Empty, Empty>> {
         unimplemented!("no wrapper function implemented for this contract")
     }
+
+    /// Return the source metadata of the contract, surfaced in deployment reports
+    fn metadata() -> Option<ContractMetadata> {
+        None
+    }
 }
 
 /// Trait that indicates that the contract can be uploaded.
@@ -277,27 +438,90 @@ pub trait CwOrchUpload<Chain: TxHandler>: ContractInstance<Chain> + Uploadable +
 /// enable `.upload()` for contracts that implement `Uploadable` for that environment.
 impl<T: ContractInstance<Chain> + Uploadable, Chain: TxHandler> CwOrchUpload<Chain> for T {}
 
+/// Trait that indicates that the contract can be uploaded from async code.
+pub trait AsyncCwOrchUpload<Chain: AsyncTxHandler>:
+    ContractInstance<Chain> + Uploadable + Sync + Sized
+{
+    /// upload the contract to the configured environment.
+    fn async_upload<'a>(
+        &'a self,
+    ) -> impl std::future::Future<Output = Result<AsyncTxResponse<Chain>, CwEnvError>> + Send
+    where
+        Chain: 'a,
+    {
+        self.as_instance().async_upload(self)
+    }
+
+    /// upload the contract to the configured environment and specify the permissions for instantiating
+    fn async_upload_with_access_config<'a>(
+        &'a self,
+        access_config: Option<AccessConfig>,
+    ) -> impl std::future::Future<Output = Result<AsyncTxResponse<Chain>, CwEnvError>> + Send
+    where
+        Chain: 'a,
+    {
+        self.as_instance()
+            .async_upload_with_access_config(self, access_config)
+    }
+}
+
+/// enable `.async_upload()` for contracts that implement `Uploadable` for that environment.
+impl<T: ContractInstance<Chain> + Uploadable + Sync, Chain: AsyncTxHandler> AsyncCwOrchUpload<Chain>
+    for T
+{
+}
+
 /// Enables calling a contract with a different sender.
+/// Implemented for all the contract interfaces, on every environment.
+///
+/// The transactions are sent by, in order of precedence:
+/// 1. The sender of [`CallAs::call_as`]
+/// 2. The admin or operator sender of the contract, see [`CallAs::set_admin_sender`] and [`CallAs::set_operator_sender`]
+/// 3. The sender of the contract, see [`CallAs::set_sender`]
+/// 4. The sender of the chain the contract was created with
 ///
 /// Clones the contract interface to prevent mutation of the original.
-pub trait CallAs<Chain: TxHandler>: CwOrchExecute<Chain> + ContractInstance<Chain> + Clone {
+pub trait CallAs<Chain: TxHandler>: ContractInstance<Chain> + Clone {
+    /// Sets the default sender of the contract
     fn set_sender(&mut self, sender: &<Chain as TxHandler>::Sender) {
         self.as_instance_mut().chain.set_sender(sender.clone())
     }
+
+    /// Sends the admin transactions (migrate, update and clear admin) with `sender`, e.g. a multisig or an admin key
+    fn set_admin_sender(&mut self, sender: &<Chain as TxHandler>::Sender) {
+        self.as_instance_mut().set_admin_sender(sender)
+    }
+
+    /// Sends the executions with `sender`, e.g. an operator key for routine calls
+    fn set_operator_sender(&mut self, sender: &<Chain as TxHandler>::Sender) {
+        self.as_instance_mut().set_operator_sender(sender)
+    }
+
     /// Call a contract as a different sender.
-    /// Clones the contract interface with a different sender.
+    /// Clones the contract interface with a different sender, ignoring the admin and operator senders.
     fn call_as(&self, sender: &<Chain as TxHandler>::Sender) -> Self {
         let mut contract = self.clone();
         contract.set_sender(sender);
+        contract.as_instance_mut().clear_role_senders();
         contract
     }
-}
 
-impl<T: CwOrchExecute<Chain> + ContractInstance<Chain> + Clone, Chain: TxHandler> CallAs<Chain>
-    for T
-{
+    /// Call a contract as `sender` after adding `coins` to its balance.
+    /// Only available on environments where balances can be set, like `Mock`.
+    fn call_as_funded(&self, sender: &Addr, coins: &[Coin]) -> Result<Self, CwEnvError>
+    where
+        Chain: BankSetter<Sender = Addr>,
+    {
+        let mut chain = self.environment().clone();
+        chain
+            .add_balance(sender, coins.to_vec())
+            .map_err(Into::into)?;
+        Ok(self.call_as(sender))
+    }
 }
 
+impl<T: ContractInstance<Chain> + Clone, Chain: TxHandler> CallAs<Chain> for T {}
+
 /// Helper methods for conditional uploading of a contract.
 pub trait ConditionalUpload<Chain: CwEnv>: CwOrchUpload<Chain> {
     /// Only upload the contract if it is not uploaded yet (checksum does not match)
@@ -0,0 +1,179 @@
+//! Metadata tracing deployed contracts back to their source.
+//!
+//! Interfaces declare their metadata with [`Uploadable::metadata`]. The metadata is then surfaced in [`DeploymentReport`]s
+//! and can be pushed on-chain to a metadata registry contract with [`Contract::push_metadata`](super::Contract::push_metadata).
+//!
+//! ```
+//! use cw_orch_core::contract::{interface_traits::Uploadable, ContractMetadata};
+//!
+//! pub struct Cw20;
+//!
+//! impl Uploadable for Cw20 {
+//!     fn metadata() -> Option<ContractMetadata> {
+//!         Some(
+//!             ContractMetadata::new()
+//!                 .repository("https://github.com/CosmWasm/cw-plus")
+//!                 .commit("f4e5d6c")
+//!                 .audit("https://github.com/CosmWasm/cw-plus/tree/main/audits"),
+//!         )
+//!     }
+//! }
+//! ```
+use std::path::Path;
+
+use cosmwasm_std::Addr;
+use serde::{Deserialize, Serialize};
+
+use super::interface_traits::{ContractInstance, Uploadable};
+use crate::{environment::ChainState, CwEnvError};
+
+/// Version of the [`ContractMetadata`] format
+pub const CONTRACT_METADATA_VERSION: u16 = 1;
+
+/// Source information of a contract
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContractMetadata {
+    /// Version of the metadata format, see [`CONTRACT_METADATA_VERSION`]
+    pub metadata_version: u16,
+    /// URL of the source repository
+    pub repository: Option<String>,
+    /// Commit the contract was built from
+    pub commit: Option<String>,
+    /// Link to the audit report
+    pub audit: Option<String>,
+}
+
+impl Default for ContractMetadata {
+    fn default() -> Self {
+        Self {
+            metadata_version: CONTRACT_METADATA_VERSION,
+            repository: None,
+            commit: None,
+            audit: None,
+        }
+    }
+}
+
+impl ContractMetadata {
+    /// Creates empty metadata with the current format version
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the URL of the source repository
+    pub fn repository(mut self, repository: impl Into<String>) -> Self {
+        self.repository = Some(repository.into());
+        self
+    }
+
+    /// Sets the commit the contract was built from
+    pub fn commit(mut self, commit: impl Into<String>) -> Self {
+        self.commit = Some(commit.into());
+        self
+    }
+
+    /// Sets the link to the audit report
+    pub fn audit(mut self, audit: impl Into<String>) -> Self {
+        self.audit = Some(audit.into());
+        self
+    }
+}
+
+/// Execute message of a metadata registry contract, used by [`Contract::push_metadata`](super::Contract::push_metadata)
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MetadataRegistryExecuteMsg {
+    /// Records the metadata of a contract
+    SetMetadata {
+        /// Address of the described contract
+        contract: String,
+        /// Metadata of the contract
+        metadata: ContractMetadata,
+    },
+}
+
+/// Contract whose metadata can be reported.
+/// Implemented for all the uploadable contract interfaces.
+pub trait ContractWithMetadata<Chain: ChainState>: ContractInstance<Chain> {
+    /// Metadata declared by the interface
+    fn contract_metadata(&self) -> Option<ContractMetadata>;
+}
+
+impl<Chain: ChainState, T: ContractInstance<Chain> + Uploadable> ContractWithMetadata<Chain> for T {
+    fn contract_metadata(&self) -> Option<ContractMetadata> {
+        T::metadata()
+    }
+}
+
+/// Deployed contract, as listed in a [`DeploymentReport`]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContractReport {
+    /// Id of the contract
+    pub id: String,
+    /// Code id, if uploaded
+    pub code_id: Option<u64>,
+    /// Address, if instantiated
+    pub address: Option<Addr>,
+    /// Metadata declared by the interface
+    pub metadata: Option<ContractMetadata>,
+}
+
+/// Summary of the contracts of a deployment and their source
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeploymentReport {
+    /// Contracts of the deployment
+    pub contracts: Vec<ContractReport>,
+}
+
+impl DeploymentReport {
+    /// Builds the report from the current state of the contracts
+    pub fn new<Chain: ChainState>(contracts: &[&dyn ContractWithMetadata<Chain>]) -> Self {
+        Self {
+            contracts: contracts
+                .iter()
+                .map(|contract| ContractReport {
+                    id: contract.id(),
+                    code_id: contract.code_id().ok(),
+                    address: contract.address().ok(),
+                    metadata: contract.contract_metadata(),
+                })
+                .collect(),
+        }
+    }
+
+    /// Writes the report as pretty JSON
+    pub fn write_to(&self, path: impl AsRef<Path>) -> Result<(), CwEnvError> {
+        let file = std::fs::File::create(path)?;
+        serde_json::to_writer_pretty(file, self)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serializes_with_version() {
+        let metadata = ContractMetadata::new()
+            .repository("https://github.com/AbstractSDK/cw-orchestrator")
+            .commit("abc123");
+
+        let json = serde_json::to_value(&metadata).unwrap();
+        assert_eq!(json["metadata_version"], CONTRACT_METADATA_VERSION);
+        assert_eq!(json["commit"], "abc123");
+        assert!(json["audit"].is_null());
+
+        let msg = MetadataRegistryExecuteMsg::SetMetadata {
+            contract: "contract".to_string(),
+            metadata: metadata.clone(),
+        };
+        let json = serde_json::to_value(&msg).unwrap();
+        assert_eq!(json["set_metadata"]["contract"], "contract");
+        assert_eq!(
+            serde_json::from_value::<ContractMetadata>(json["set_metadata"]["metadata"].clone())
+                .unwrap(),
+            metadata
+        );
+    }
+}
@@ -0,0 +1,126 @@
+//! Structured testing of the migrations between two versions of a contract.
+//!
+//! [`MigrationTester`] deploys the previous version of a contract, lets the test populate its state,
+//! uploads the new version, migrates the contract and runs the invariants on the migrated state.
+//! The migration back to the previous version can be tested the same way with [`MigrationTester::migrate_down`].
+//!
+//! The previous version is usually an interface returning the versioned artifact of the contract.
+//! Its code id is captured when it's deployed, so both versions can share the same contract id.
+//!
+//! ```rust,ignore
+//! #[interface(InstantiateMsg, ExecuteMsg, QueryMsg, MigrateMsg)]
+//! pub struct CounterV1;
+//!
+//! impl<Chain> Uploadable for CounterV1<Chain> {
+//!     fn wasm(chain: &ChainInfoOwned) -> WasmPath {
+//!         CounterContract::<Chain>::wasm_for_version(chain, "1.3.0").unwrap()
+//!     }
+//! }
+//!
+//! MigrationTester::new(CounterV1::new("counter_v1", chain.clone()), CounterContract::new("counter", chain))
+//!     .deploy(&InstantiateMsg { count: 1 })?
+//!     .populate(|old| old.increment().map(|_| ()))?
+//!     .migrate(&MigrateMsg {})?
+//!     .check(|new| {
+//!         assert_eq!(new.get_count()?.count, 2);
+//!         Ok(())
+//!     })?;
+//! ```
+use std::cell::Cell;
+
+use super::interface_traits::{CwOrchInstantiate, CwOrchMigrate, CwOrchUpload};
+use crate::{
+    environment::{CwEnv, Environment},
+    CwEnvError,
+};
+
+/// Tests the migration of a contract from its `Old` interface to its `New` one
+pub struct MigrationTester<Chain: CwEnv, Old, New> {
+    old: Old,
+    new: New,
+    old_code_id: Cell<Option<u64>>,
+    _chain: std::marker::PhantomData<Chain>,
+}
+
+impl<Chain, Old, New> MigrationTester<Chain, Old, New>
+where
+    Chain: CwEnv,
+    Old: CwOrchUpload<Chain> + CwOrchInstantiate<Chain> + CwOrchMigrate<Chain>,
+    New: CwOrchUpload<Chain> + CwOrchMigrate<Chain>,
+{
+    /// Tester migrating the contract deployed with `old` to the code of `new`
+    pub fn new(old: Old, new: New) -> Self {
+        Self {
+            old,
+            new,
+            old_code_id: Cell::new(None),
+            _chain: Default::default(),
+        }
+    }
+
+    /// Uploads and instantiates the previous version, with the sender as admin
+    pub fn deploy(&self, instantiate_msg: &Old::InstantiateMsg) -> Result<&Self, CwEnvError> {
+        self.old.upload()?;
+        self.old_code_id.set(Some(self.old.code_id()?));
+        let admin = self.old.environment().sender_addr();
+        self.old.instantiate(instantiate_msg, Some(&admin), &[])?;
+        Ok(self)
+    }
+
+    /// Populates the state of the previous version before the migration
+    pub fn populate(
+        &self,
+        populate: impl FnOnce(&Old) -> Result<(), CwEnvError>,
+    ) -> Result<&Self, CwEnvError> {
+        populate(&self.old)?;
+        Ok(self)
+    }
+
+    /// Uploads the new version and migrates the contract to it
+    pub fn migrate(&self, migrate_msg: &New::MigrateMsg) -> Result<&Self, CwEnvError> {
+        self.new.upload()?;
+        self.new.set_address(&self.old.address()?);
+        self.new.migrate(migrate_msg, self.new.code_id()?)?;
+        Ok(self)
+    }
+
+    /// Checks the invariants of the migrated contract
+    pub fn check(
+        &self,
+        invariants: impl FnOnce(&New) -> Result<(), CwEnvError>,
+    ) -> Result<&Self, CwEnvError> {
+        invariants(&self.new)?;
+        Ok(self)
+    }
+
+    /// Migrates the contract back to the previous version and checks its invariants
+    pub fn migrate_down(
+        &self,
+        migrate_msg: &Old::MigrateMsg,
+        invariants: impl FnOnce(&Old) -> Result<(), CwEnvError>,
+    ) -> Result<&Self, CwEnvError> {
+        let old_code_id = self.old_code_id()?;
+        self.old.migrate(migrate_msg, old_code_id)?;
+        self.old.set_code_id(old_code_id);
+        invariants(&self.old)?;
+        Ok(self)
+    }
+
+    /// Code id of the previous version, captured by [`MigrationTester::deploy`]
+    pub fn old_code_id(&self) -> Result<u64, CwEnvError> {
+        match self.old_code_id.get() {
+            Some(code_id) => Ok(code_id),
+            None => self.old.code_id(),
+        }
+    }
+
+    /// Previous version of the contract
+    pub fn old(&self) -> &Old {
+        &self.old
+    }
+
+    /// New version of the contract
+    pub fn new_version(&self) -> &New {
+        &self.new
+    }
+}
@@ -1,10 +1,27 @@
+pub mod artifacts;
 mod contract_instance;
+pub mod coverage;
 mod deploy;
+pub mod gas_snapshot;
 pub mod interface_traits;
+pub mod metadata;
+pub mod migration;
+pub mod ownership;
 mod paths;
+pub mod placeholder;
+pub mod precheck;
+pub mod rollback;
 
+pub use artifacts::ArtifactBundle;
 pub use contract_instance::Contract;
 pub use deploy::Deploy;
+pub use gas_snapshot::{GasReport, GasSnapshot};
+pub use metadata::{ContractMetadata, DeploymentReport};
+pub use migration::MigrationTester;
+pub use ownership::{Ownership, OwnershipAction};
+pub use placeholder::AddrOf;
+pub use precheck::{UploadPrecheck, UploadPrecheckError};
+pub use rollback::{RollbackReport, StateSnapshot};
 
 pub use paths::from_workspace as artifacts_dir_from_workspace;
 pub use paths::{ArtifactsDir, WasmPath};
@@ -0,0 +1,173 @@
+//! Helpers for the contracts implementing the [cw-ownable](https://crates.io/crates/cw-ownable) standard.
+//!
+//! The messages are mirrored here so the helpers work with any version of `cw-ownable`.
+//! When the `ExecuteMsg` of an interface has an `UpdateOwnership` variant, the `ExecuteFns` derive
+//! also generates `transfer_ownership`, `accept_ownership` and `renounce_ownership` functions,
+//! and their `_async` counterparts for the async environments.
+//!
+//! ```ignore
+//! contract.transfer_ownership(&new_owner, None)?;
+//! contract.call_as(&new_owner).accept_ownership()?;
+//! assert_eq!(contract.ownership()?.owner, Some(new_owner));
+//! ```
+use cosmwasm_std::Addr;
+pub use cw_utils::Expiration;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::Contract;
+use crate::{
+    environment::{
+        AsyncTxHandler, AsyncTxResponse, AsyncWasmQuerier, ChainState, QueryHandler, TxHandler,
+        TxResponse,
+    },
+    CwEnvError,
+};
+
+/// Ownership update of a cw-ownable contract, mirrors `cw_ownable::Action`
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum OwnershipAction {
+    /// Proposes a new owner, who has to accept the ownership before `expiry`
+    TransferOwnership {
+        new_owner: String,
+        expiry: Option<Expiration>,
+    },
+    /// Accepts a pending ownership transfer, sent by the new owner
+    AcceptOwnership,
+    /// Gives up the ownership, the contract has no owner afterwards
+    RenounceOwnership,
+}
+
+/// Ownership of a cw-ownable contract, mirrors `cw_ownable::Ownership`
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct Ownership {
+    /// Current owner, `None` if the ownership was renounced
+    pub owner: Option<Addr>,
+    /// Proposed owner, if a transfer is pending
+    pub pending_owner: Option<Addr>,
+    /// Expiration of the pending transfer
+    pub pending_expiry: Option<Expiration>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum OwnershipExecuteMsg {
+    UpdateOwnership(OwnershipAction),
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum OwnershipQueryMsg {
+    Ownership {},
+}
+
+impl<Chain: TxHandler> Contract<Chain> {
+    /// Updates the ownership of a cw-ownable contract
+    pub fn update_ownership(
+        &self,
+        action: OwnershipAction,
+    ) -> Result<TxResponse<Chain>, CwEnvError> {
+        self.execute(&OwnershipExecuteMsg::UpdateOwnership(action), &[])
+    }
+
+    /// Proposes `new_owner` as the owner of the contract, the transfer has to be accepted before `expiry`
+    pub fn transfer_ownership(
+        &self,
+        new_owner: &Addr,
+        expiry: Option<Expiration>,
+    ) -> Result<TxResponse<Chain>, CwEnvError> {
+        self.update_ownership(OwnershipAction::TransferOwnership {
+            new_owner: new_owner.to_string(),
+            expiry,
+        })
+    }
+
+    /// Accepts the pending ownership transfer, must be sent by the proposed owner
+    pub fn accept_ownership(&self) -> Result<TxResponse<Chain>, CwEnvError> {
+        self.update_ownership(OwnershipAction::AcceptOwnership)
+    }
+
+    /// Renounces the ownership of the contract
+    pub fn renounce_ownership(&self) -> Result<TxResponse<Chain>, CwEnvError> {
+        self.update_ownership(OwnershipAction::RenounceOwnership)
+    }
+}
+
+impl<Chain: ChainState + QueryHandler> Contract<Chain> {
+    /// Queries the ownership of a cw-ownable contract
+    pub fn ownership(&self) -> Result<Ownership, CwEnvError> {
+        self.query(&OwnershipQueryMsg::Ownership {})
+    }
+}
+
+impl<Chain: AsyncTxHandler> Contract<Chain> {
+    /// Async version of [`Contract::update_ownership`]
+    pub async fn async_update_ownership(
+        &self,
+        action: OwnershipAction,
+    ) -> Result<AsyncTxResponse<Chain>, CwEnvError> {
+        self.async_execute(&OwnershipExecuteMsg::UpdateOwnership(action), &[])
+            .await
+    }
+
+    /// Async version of [`Contract::transfer_ownership`]
+    pub async fn async_transfer_ownership(
+        &self,
+        new_owner: &Addr,
+        expiry: Option<Expiration>,
+    ) -> Result<AsyncTxResponse<Chain>, CwEnvError> {
+        self.async_update_ownership(OwnershipAction::TransferOwnership {
+            new_owner: new_owner.to_string(),
+            expiry,
+        })
+        .await
+    }
+
+    /// Async version of [`Contract::accept_ownership`]
+    pub async fn async_accept_ownership(&self) -> Result<AsyncTxResponse<Chain>, CwEnvError> {
+        self.async_update_ownership(OwnershipAction::AcceptOwnership)
+            .await
+    }
+
+    /// Async version of [`Contract::renounce_ownership`]
+    pub async fn async_renounce_ownership(&self) -> Result<AsyncTxResponse<Chain>, CwEnvError> {
+        self.async_update_ownership(OwnershipAction::RenounceOwnership)
+            .await
+    }
+}
+
+impl<Chain: AsyncWasmQuerier + ChainState> Contract<Chain> {
+    /// Async version of [`Contract::ownership`]
+    pub async fn async_ownership(&self) -> Result<Ownership, CwEnvError> {
+        self.async_query(&OwnershipQueryMsg::Ownership {}).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serializes_like_cw_ownable() -> Result<(), CwEnvError> {
+        let msg = OwnershipExecuteMsg::UpdateOwnership(OwnershipAction::TransferOwnership {
+            new_owner: "owner".to_string(),
+            expiry: Some(Expiration::AtHeight(10)),
+        });
+        assert_eq!(
+            serde_json::to_string(&msg)?,
+            r#"{"update_ownership":{"transfer_ownership":{"new_owner":"owner","expiry":{"at_height":10}}}}"#
+        );
+        assert_eq!(
+            serde_json::to_string(&OwnershipExecuteMsg::UpdateOwnership(
+                OwnershipAction::AcceptOwnership
+            ))?,
+            r#"{"update_ownership":"accept_ownership"}"#
+        );
+        assert_eq!(
+            serde_json::to_string(&OwnershipQueryMsg::Ownership {})?,
+            r#"{"ownership":{}}"#
+        );
+        Ok(())
+    }
+}
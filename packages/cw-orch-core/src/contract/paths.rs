@@ -212,6 +212,35 @@ mod artifacts_dir {
                 })?;
             WasmPath::new(self.path().join(path_str))
         }
+
+        /// Find the WASM file of a given version of a contract.
+        /// The versioned artifacts are either stored in a directory named after the version (`artifacts/1.3.0/my_contract.wasm`)
+        /// or suffixed with the version (`artifacts/my_contract-1.3.0.wasm`).
+        pub fn find_wasm_path_for_version(
+            &self,
+            name: &str,
+            version: &str,
+        ) -> Result<WasmPath, CwEnvError> {
+            let version_dir = self.path().join(version);
+            if version_dir.is_dir() {
+                return ArtifactsDir::new(version_dir).find_wasm_path(name);
+            }
+            self.find_wasm_path(&format!("{name}-{version}"))
+        }
+
+        /// Find the WASM file of a given version of a contract, next to the WASM file of its current version.
+        /// See [`ArtifactsDir::find_wasm_path_for_version`].
+        pub fn find_version_of(wasm: &WasmPath, version: &str) -> Result<WasmPath, CwEnvError> {
+            let name = wasm
+                .path()
+                .file_stem()
+                .unwrap_or_default()
+                .to_string_lossy()
+                .trim_end_matches(ARM_POSTFIX)
+                .to_string();
+            let dir = wasm.path().parent().unwrap_or(wasm.path());
+            ArtifactsDir::new(dir).find_wasm_path_for_version(&name, version)
+        }
     }
 
     fn is_artifact(file_name: &str, contract_name: &str) -> bool {
@@ -244,3 +273,52 @@ mod artifacts_dir {
             && file_name.ends_with(format!("{build_postfix}{ARM_POSTFIX}.wasm").as_str())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{contract::interface_traits::Uploadable, environment::ChainInfoOwned};
+
+    thread_local! {
+        // One artifacts directory per test thread
+        static ARTIFACTS: tempfile::TempDir = {
+            let dir = tempfile::tempdir().unwrap();
+            std::fs::create_dir_all(dir.path().join("1.2.0")).unwrap();
+            for file in ["counter.wasm", "counter-1.3.0.wasm", "1.2.0/counter.wasm"] {
+                std::fs::write(dir.path().join(file), b"\0asm").unwrap();
+            }
+            dir
+        };
+    }
+
+    fn artifacts() -> std::path::PathBuf {
+        ARTIFACTS.with(|dir| dir.path().to_path_buf())
+    }
+
+    struct Counter;
+
+    impl Uploadable for Counter {
+        fn wasm(_chain: &ChainInfoOwned) -> WasmPath {
+            WasmPath::new(artifacts().join("counter.wasm")).unwrap()
+        }
+    }
+
+    #[test]
+    fn wasm_for_version() {
+        let dir = artifacts();
+        let chain = ChainInfoOwned::default();
+
+        // Suffixed with the version
+        let wasm = Counter::wasm_for_version(&chain, "1.3.0").unwrap();
+        assert_eq!(wasm.path(), dir.join("counter-1.3.0.wasm").as_path());
+
+        // In a directory named after the version
+        let wasm = Counter::wasm_for_version(&chain, "1.2.0").unwrap();
+        assert_eq!(
+            wasm.path(),
+            dir.join("1.2.0").join("counter.wasm").as_path()
+        );
+
+        assert!(Counter::wasm_for_version(&chain, "0.1.0").is_err());
+    }
+}
@@ -0,0 +1,179 @@
+//! Placeholders that can be used inside messages and get resolved from the deployment state when the message is sent.
+//!
+//! This allows instantiating contracts that reference each other without having to query the state manually:
+//! ```ignore
+//! use cw_orch::prelude::*;
+//!
+//! // `minter` is resolved to the address stored for the `minter_contract` id when the message is sent
+//! token.instantiate(&InstantiateMsg { minter: Addr::of("minter_contract").to_string() }, None, &[])?;
+//! ```
+use crate::{environment::StateInterface, error::CwEnvError};
+use cosmwasm_std::Addr;
+use serde::Serialize;
+use serde_json::Value;
+
+/// Prefix used to mark a string as an address placeholder.
+pub const ADDR_PLACEHOLDER_PREFIX: &str = "$cw-orch-addr:";
+
+/// Creates placeholders that are replaced by the address of a contract when a message containing them is sent.
+pub trait AddrOf {
+    /// Returns a placeholder for the address of the contract registered under `contract_id` in the state.
+    fn of(contract_id: &str) -> Self;
+}
+
+impl AddrOf for Addr {
+    fn of(contract_id: &str) -> Self {
+        Addr::unchecked(String::of(contract_id))
+    }
+}
+
+impl AddrOf for String {
+    fn of(contract_id: &str) -> Self {
+        format!("{ADDR_PLACEHOLDER_PREFIX}{contract_id}")
+    }
+}
+
+/// Resolves all the address placeholders inside `msg` using the provided state.
+/// Returns `None` if the message doesn't contain any placeholder, so the original message can be sent untouched.
+pub(crate) fn resolve_placeholders<S: StateInterface>(
+    msg: &impl Serialize,
+    state: &S,
+) -> Result<Option<Value>, CwEnvError> {
+    let mut value = serde_json::to_value(msg)?;
+    let replaced = resolve_value(&mut value, state)?;
+    Ok(replaced.then_some(value))
+}
+
+fn resolve_value<S: StateInterface>(value: &mut Value, state: &S) -> Result<bool, CwEnvError> {
+    match value {
+        Value::String(s) => match s.strip_prefix(ADDR_PLACEHOLDER_PREFIX) {
+            Some(contract_id) => {
+                *s = state.get_address(contract_id)?.to_string();
+                Ok(true)
+            }
+            None => Ok(false),
+        },
+        Value::Array(values) => {
+            values.iter_mut().try_fold(
+                false,
+                |replaced, v| Ok(resolve_value(v, state)? || replaced),
+            )
+        }
+        Value::Object(map) => {
+            map.values_mut().try_fold(
+                false,
+                |replaced, v| Ok(resolve_value(v, state)? || replaced),
+            )
+        }
+        _ => Ok(false),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+
+    #[derive(Clone, Default)]
+    struct AddrState {
+        addresses: HashMap<String, Addr>,
+        code_ids: HashMap<String, u64>,
+    }
+
+    impl StateInterface for AddrState {
+        fn get_address(&self, contract_id: &str) -> Result<Addr, CwEnvError> {
+            self.addresses
+                .get(contract_id)
+                .cloned()
+                .ok_or(CwEnvError::AddrNotInStore(contract_id.to_string()))
+        }
+
+        fn set_address(&mut self, contract_id: &str, address: &Addr) {
+            self.addresses
+                .insert(contract_id.to_string(), address.clone());
+        }
+
+        fn remove_address(&mut self, contract_id: &str) {
+            self.addresses.remove(contract_id);
+        }
+
+        fn get_code_id(&self, contract_id: &str) -> Result<u64, CwEnvError> {
+            self.code_ids
+                .get(contract_id)
+                .copied()
+                .ok_or(CwEnvError::CodeIdNotInStore(contract_id.to_string()))
+        }
+
+        fn set_code_id(&mut self, contract_id: &str, code_id: u64) {
+            self.code_ids.insert(contract_id.to_string(), code_id);
+        }
+
+        fn remove_code_id(&mut self, contract_id: &str) {
+            self.code_ids.remove(contract_id);
+        }
+
+        fn get_all_addresses(&self) -> Result<HashMap<String, Addr>, CwEnvError> {
+            Ok(self.addresses.clone())
+        }
+
+        fn get_all_code_ids(&self) -> Result<HashMap<String, u64>, CwEnvError> {
+            Ok(self.code_ids.clone())
+        }
+    }
+
+    #[derive(Serialize)]
+    struct InitMsg {
+        owner: Addr,
+        hooks: Vec<String>,
+        label: String,
+    }
+
+    #[test]
+    fn resolves_nested_placeholders() -> Result<(), CwEnvError> {
+        let mut state = AddrState::default();
+        state.set_address("owner", &Addr::unchecked("owner_addr"));
+        state.set_address("hook", &Addr::unchecked("hook_addr"));
+
+        let msg = InitMsg {
+            owner: Addr::of("owner"),
+            hooks: vec![String::of("hook"), "plain".to_string()],
+            label: "label".to_string(),
+        };
+
+        let resolved = resolve_placeholders(&msg, &state)?.unwrap();
+        assert_eq!(
+            resolved,
+            serde_json::json!({
+                "owner": "owner_addr",
+                "hooks": ["hook_addr", "plain"],
+                "label": "label",
+            })
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn untouched_without_placeholders() -> Result<(), CwEnvError> {
+        let msg = InitMsg {
+            owner: Addr::unchecked("owner"),
+            hooks: vec![],
+            label: "label".to_string(),
+        };
+
+        assert!(resolve_placeholders(&msg, &AddrState::default())?.is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn missing_contract_errors() {
+        let msg = InitMsg {
+            owner: Addr::of("unknown"),
+            hooks: vec![],
+            label: "label".to_string(),
+        };
+
+        let err = resolve_placeholders(&msg, &AddrState::default()).unwrap_err();
+        assert!(matches!(err, CwEnvError::AddrNotInStore(id) if id == "unknown"));
+    }
+}
@@ -0,0 +1,377 @@
+//! Checks run on a wasm file before uploading it.
+//!
+//! A code that the chain rejects only fails after its broadcast, so the common causes are checked locally:
+//! - The size of the code, against the maximum code size of the chain.
+//! - Floating point instructions, that the chain rejects as non-deterministic.
+//! - The entry points exported by the code.
+use thiserror::Error;
+use wasmparser::{ExternalKind, Operator, Parser, Payload, ValType};
+
+use super::WasmPath;
+use crate::{env::CoreEnvVars, CwEnvError};
+
+/// Default maximum size of a wasm code (wasmd `MaxWasmSize`)
+pub const DEFAULT_MAX_WASM_SIZE: usize = 800 * 1024;
+
+/// Exports required by the wasm VM
+const REQUIRED_EXPORTS: &[&str] = &["allocate", "deallocate"];
+const INTERFACE_VERSION_PREFIX: &str = "interface_version_";
+
+/// Reason a wasm code would be rejected by the chain
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum UploadPrecheckError {
+    #[error("Wasm code is {size} bytes, the chain accepts at most {max_size} bytes")]
+    TooLarge { size: usize, max_size: usize },
+    #[error("Wasm function {function} uses floating point operations")]
+    FloatingPoint { function: u32 },
+    #[error("Wasm code doesn't export the `{0}` entry point")]
+    MissingEntryPoint(String),
+    #[error("Wasm code doesn't export its memory")]
+    MissingMemory,
+    #[error("Wasm code doesn't export its interface version")]
+    MissingInterfaceVersion,
+    #[error("Invalid wasm code: {0}")]
+    InvalidWasm(String),
+}
+
+/// Checks run on a wasm code before uploading it.
+/// The daemon only runs them when they are set on its builder (`DaemonBuilder::upload_precheck`).
+///
+/// The default checks match the CosmWasm 1.x VM: floating point operations are rejected,
+/// and only the entry points the VM needs are required, so that library and migrate-only contracts pass.
+///
+/// ```no_run
+/// use cw_orch_core::contract::{precheck::UploadPrecheck, WasmPath};
+///
+/// let wasm_path = WasmPath::new("artifacts/counter.wasm").unwrap();
+/// // The code will be used to migrate a running contract
+/// UploadPrecheck::default()
+///     .require_migrate(true)
+///     .check_path(&wasm_path)
+///     .unwrap();
+/// ```
+#[derive(Clone, Debug)]
+pub struct UploadPrecheck {
+    /// Maximum size of the code, in bytes
+    pub max_size: usize,
+    /// Whether the code is rejected if it uses floating point operations
+    pub reject_floats: bool,
+    /// Whether the code must export an `instantiate` entry point
+    pub require_instantiate: bool,
+    /// Whether the code must export a `migrate` entry point
+    pub require_migrate: bool,
+}
+
+impl Default for UploadPrecheck {
+    fn default() -> Self {
+        Self {
+            max_size: CoreEnvVars::max_wasm_size(),
+            reject_floats: true,
+            require_instantiate: false,
+            require_migrate: false,
+        }
+    }
+}
+
+impl UploadPrecheck {
+    /// Sets the maximum size of the code
+    pub fn max_size(mut self, max_size: usize) -> Self {
+        self.max_size = max_size;
+        self
+    }
+
+    /// Rejects the codes using floating point operations, which chains running a CosmWasm 2.x VM accept
+    pub fn reject_floats(mut self, reject_floats: bool) -> Self {
+        self.reject_floats = reject_floats;
+        self
+    }
+
+    /// Requires the code to export an `instantiate` entry point
+    pub fn require_instantiate(mut self, require_instantiate: bool) -> Self {
+        self.require_instantiate = require_instantiate;
+        self
+    }
+
+    /// Requires the code to export a `migrate` entry point
+    pub fn require_migrate(mut self, require_migrate: bool) -> Self {
+        self.require_migrate = require_migrate;
+        self
+    }
+
+    /// Checks the wasm file at `wasm_path`
+    pub fn check_path(&self, wasm_path: &WasmPath) -> Result<(), CwEnvError> {
+        let wasm = std::fs::read(wasm_path.path())?;
+        self.check(&wasm).map_err(Into::into)
+    }
+
+    /// Checks the (uncompressed) wasm code
+    pub fn check(&self, wasm: &[u8]) -> Result<(), UploadPrecheckError> {
+        if wasm.len() > self.max_size {
+            return Err(UploadPrecheckError::TooLarge {
+                size: wasm.len(),
+                max_size: self.max_size,
+            });
+        }
+
+        let invalid =
+            |e: wasmparser::BinaryReaderError| UploadPrecheckError::InvalidWasm(e.to_string());
+        let mut exports = vec![];
+        let mut function = 0u32;
+        for payload in Parser::new(0).parse_all(wasm) {
+            match payload.map_err(invalid)? {
+                Payload::ExportSection(reader) => {
+                    for export in reader {
+                        let export = export.map_err(invalid)?;
+                        exports.push((export.name.to_string(), export.kind));
+                    }
+                }
+                Payload::CodeSectionEntry(body) if self.reject_floats => {
+                    let mut locals = body.get_locals_reader().map_err(invalid)?;
+                    for _ in 0..locals.get_count() {
+                        let (_, ty) = locals.read().map_err(invalid)?;
+                        if matches!(ty, ValType::F32 | ValType::F64) {
+                            return Err(UploadPrecheckError::FloatingPoint { function });
+                        }
+                    }
+                    let mut operators = body.get_operators_reader().map_err(invalid)?;
+                    while !operators.eof() {
+                        if is_float_operator(&operators.read().map_err(invalid)?) {
+                            return Err(UploadPrecheckError::FloatingPoint { function });
+                        }
+                    }
+                    function += 1;
+                }
+                _ => {}
+            }
+        }
+
+        let exports_function = |name: &str| {
+            exports
+                .iter()
+                .any(|(export, kind)| export == name && *kind == ExternalKind::Func)
+        };
+        if !exports
+            .iter()
+            .any(|(export, kind)| export == "memory" && *kind == ExternalKind::Memory)
+        {
+            return Err(UploadPrecheckError::MissingMemory);
+        }
+        if !exports
+            .iter()
+            .any(|(export, _)| export.starts_with(INTERFACE_VERSION_PREFIX))
+        {
+            return Err(UploadPrecheckError::MissingInterfaceVersion);
+        }
+        let instantiate = self.require_instantiate.then_some("instantiate");
+        let migrate = self.require_migrate.then_some("migrate");
+        for entry_point in REQUIRED_EXPORTS
+            .iter()
+            .copied()
+            .chain(instantiate)
+            .chain(migrate)
+        {
+            if !exports_function(entry_point) {
+                return Err(UploadPrecheckError::MissingEntryPoint(
+                    entry_point.to_string(),
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Scalar operators reading, producing or converting floats.
+/// SIMD operators are not checked, the VM rejects them all.
+fn is_float_operator(operator: &Operator) -> bool {
+    use Operator::*;
+    matches!(
+        operator,
+        F32Load { .. }
+            | F64Load { .. }
+            | F32Store { .. }
+            | F64Store { .. }
+            | F32Const { .. }
+            | F64Const { .. }
+            | F32Eq
+            | F32Ne
+            | F32Lt
+            | F32Gt
+            | F32Le
+            | F32Ge
+            | F64Eq
+            | F64Ne
+            | F64Lt
+            | F64Gt
+            | F64Le
+            | F64Ge
+            | F32Abs
+            | F32Neg
+            | F32Ceil
+            | F32Floor
+            | F32Trunc
+            | F32Nearest
+            | F32Sqrt
+            | F32Add
+            | F32Sub
+            | F32Mul
+            | F32Div
+            | F32Min
+            | F32Max
+            | F32Copysign
+            | F64Abs
+            | F64Neg
+            | F64Ceil
+            | F64Floor
+            | F64Trunc
+            | F64Nearest
+            | F64Sqrt
+            | F64Add
+            | F64Sub
+            | F64Mul
+            | F64Div
+            | F64Min
+            | F64Max
+            | F64Copysign
+            | I32TruncF32S
+            | I32TruncF32U
+            | I32TruncF64S
+            | I32TruncF64U
+            | I64TruncF32S
+            | I64TruncF32U
+            | I64TruncF64S
+            | I64TruncF64U
+            | F32ConvertI32S
+            | F32ConvertI32U
+            | F32ConvertI64S
+            | F32ConvertI64U
+            | F32DemoteF64
+            | F64ConvertI32S
+            | F64ConvertI32U
+            | F64ConvertI64S
+            | F64ConvertI64U
+            | F64PromoteF32
+            | I32ReinterpretF32
+            | I64ReinterpretF64
+            | F32ReinterpretI32
+            | F64ReinterpretI64
+            | I32TruncSatF32S
+            | I32TruncSatF32U
+            | I32TruncSatF64S
+            | I32TruncSatF64U
+            | I64TruncSatF32S
+            | I64TruncSatF32U
+            | I64TruncSatF64S
+            | I64TruncSatF64U
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Minimal contract module: a memory, the VM exports and the `entry_points`, all pointing to a function with the given body
+    fn module(body: &[u8], entry_points: &[&str]) -> Vec<u8> {
+        fn section(id: u8, content: Vec<u8>) -> Vec<u8> {
+            [vec![id, content.len() as u8], content].concat()
+        }
+        fn name(name: &str) -> Vec<u8> {
+            [vec![name.len() as u8], name.as_bytes().to_vec()].concat()
+        }
+
+        let mut exports = vec![
+            ("memory", 2u8, 0u8),
+            ("interface_version_8", 0, 0),
+            ("allocate", 0, 0),
+            ("deallocate", 0, 0),
+        ];
+        exports.extend(entry_points.iter().map(|entry_point| (*entry_point, 0, 0)));
+        let mut export_section = vec![exports.len() as u8];
+        for (export, kind, index) in exports {
+            export_section.extend(name(export));
+            export_section.extend([kind, index]);
+        }
+        let body = [vec![0u8], body.to_vec(), vec![0x0b]].concat();
+
+        [
+            b"\0asm".to_vec(),
+            vec![1, 0, 0, 0],
+            // type section: () -> ()
+            section(1, vec![1, 0x60, 0, 0]),
+            // function section: one function of type 0
+            section(3, vec![1, 0]),
+            // memory section: one memory of 1 page
+            section(5, vec![1, 0, 1]),
+            section(7, export_section),
+            section(10, [vec![1, body.len() as u8], body].concat()),
+        ]
+        .concat()
+    }
+
+    #[test]
+    fn accepts_contract() {
+        let wasm = module(&[], &["instantiate"]);
+        assert_eq!(UploadPrecheck::default().check(&wasm), Ok(()));
+        assert_eq!(
+            UploadPrecheck::default().require_migrate(true).check(&wasm),
+            Err(UploadPrecheckError::MissingEntryPoint(
+                "migrate".to_string()
+            ))
+        );
+        let wasm = module(&[], &["instantiate", "migrate"]);
+        assert_eq!(
+            UploadPrecheck::default().require_migrate(true).check(&wasm),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn instantiate_is_optional() {
+        // Library or migrate-only contract
+        let wasm = module(&[], &["migrate"]);
+        assert_eq!(UploadPrecheck::default().check(&wasm), Ok(()));
+        assert_eq!(
+            UploadPrecheck::default()
+                .require_instantiate(true)
+                .check(&wasm),
+            Err(UploadPrecheckError::MissingEntryPoint(
+                "instantiate".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn rejects_floats_and_large_codes() {
+        // f32.const 1.0; drop
+        let wasm = module(&[0x43, 0, 0, 0x80, 0x3f, 0x1a], &["instantiate"]);
+        assert_eq!(
+            UploadPrecheck::default().check(&wasm),
+            Err(UploadPrecheckError::FloatingPoint { function: 0 })
+        );
+        assert_eq!(
+            UploadPrecheck::default().reject_floats(false).check(&wasm),
+            Ok(())
+        );
+        // i32.const 0; f32.convert_i32_s; drop
+        let wasm = module(&[0x41, 0, 0xb2, 0x1a], &["instantiate"]);
+        assert_eq!(
+            UploadPrecheck::default().check(&wasm),
+            Err(UploadPrecheckError::FloatingPoint { function: 0 })
+        );
+        // i32.const 0; drop
+        let wasm = module(&[0x41, 0, 0x1a], &["instantiate"]);
+        assert_eq!(UploadPrecheck::default().check(&wasm), Ok(()));
+
+        let wasm = module(&[], &["instantiate"]);
+        assert_eq!(
+            UploadPrecheck::default().max_size(10).check(&wasm),
+            Err(UploadPrecheckError::TooLarge {
+                size: wasm.len(),
+                max_size: 10
+            })
+        );
+        assert!(matches!(
+            UploadPrecheck::default().check(b"\0asm"),
+            Err(UploadPrecheckError::InvalidWasm(_))
+        ));
+    }
+}
@@ -0,0 +1,129 @@
+//! Snapshots of the deployment state, used to roll failed deployments back.
+//! See [`Deploy::deploy_transactional`](super::Deploy::deploy_transactional).
+use std::{collections::HashMap, fmt::Display};
+
+use cosmwasm_std::Addr;
+
+use crate::{environment::StateInterface, CwEnvError};
+
+/// Addresses and code-ids of the state, taken before a deployment
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct StateSnapshot {
+    addresses: HashMap<String, Addr>,
+    code_ids: HashMap<String, u64>,
+}
+
+/// On-chain action of a deployment that is not undone by a rollback
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum IrreversibleAction {
+    /// Code stored during the deployment, it stays on chain
+    Upload {
+        /// Id of the uploaded contract
+        contract_id: String,
+        /// Code id of the stored code
+        code_id: u64,
+    },
+    /// Contract instantiated during the deployment, it stays on chain
+    Instantiate {
+        /// Id of the instantiated contract
+        contract_id: String,
+        /// Address of the new contract
+        address: Addr,
+    },
+}
+
+impl Display for IrreversibleAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IrreversibleAction::Upload {
+                contract_id,
+                code_id,
+            } => write!(f, "code {code_id} of {contract_id} stays stored on chain"),
+            IrreversibleAction::Instantiate {
+                contract_id,
+                address,
+            } => write!(f, "contract {contract_id} stays instantiated at {address}"),
+        }
+    }
+}
+
+/// Outcome of a rollback
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RollbackReport {
+    /// Ids of the contracts whose state entries were restored
+    pub restored: Vec<String>,
+    /// On-chain actions that can't be undone
+    pub irreversible: Vec<IrreversibleAction>,
+}
+
+impl StateSnapshot {
+    /// Takes a snapshot of the addresses and code-ids in `state`
+    pub fn take(state: &impl StateInterface) -> Result<Self, CwEnvError> {
+        Ok(Self {
+            addresses: state.get_all_addresses()?,
+            code_ids: state.get_all_code_ids()?,
+        })
+    }
+
+    /// Restores the snapshot in `state`.
+    /// Entries changed or removed since the snapshot get their previous value back, new entries are removed.
+    pub fn restore(&self, state: &mut impl StateInterface) -> Result<RollbackReport, CwEnvError> {
+        let mut report = RollbackReport::default();
+        let current_code_ids = state.get_all_code_ids()?;
+        let current_addresses = state.get_all_addresses()?;
+
+        let mut code_id_keys: Vec<_> = current_code_ids
+            .keys()
+            .chain(self.code_ids.keys())
+            .collect();
+        code_id_keys.sort();
+        code_id_keys.dedup();
+        for contract_id in code_id_keys {
+            let current = current_code_ids.get(contract_id);
+            let previous = self.code_ids.get(contract_id);
+            if current == previous {
+                continue;
+            }
+            match previous {
+                Some(previous) => state.set_code_id(contract_id, *previous),
+                None => state.remove_code_id(contract_id),
+            }
+            report.restored.push(contract_id.clone());
+            if let Some(code_id) = current {
+                report.irreversible.push(IrreversibleAction::Upload {
+                    contract_id: contract_id.clone(),
+                    code_id: *code_id,
+                });
+            }
+        }
+
+        let mut address_keys: Vec<_> = current_addresses
+            .keys()
+            .chain(self.addresses.keys())
+            .collect();
+        address_keys.sort();
+        address_keys.dedup();
+        for contract_id in address_keys {
+            let current = current_addresses.get(contract_id);
+            let previous = self.addresses.get(contract_id);
+            if current == previous {
+                continue;
+            }
+            match previous {
+                Some(previous) => state.set_address(contract_id, previous),
+                None => state.remove_address(contract_id),
+            }
+            if !report.restored.contains(contract_id) {
+                report.restored.push(contract_id.clone());
+            }
+            if let Some(address) = current {
+                report.irreversible.push(IrreversibleAction::Instantiate {
+                    contract_id: contract_id.clone(),
+                    address: address.clone(),
+                });
+            }
+        }
+
+        Ok(report)
+    }
+}
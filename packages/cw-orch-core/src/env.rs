@@ -12,9 +12,16 @@ use std::{env, path::PathBuf, str::FromStr};
 
 use cosmwasm_std::StdError;
 
+use crate::{contract::precheck::DEFAULT_MAX_WASM_SIZE, CwEnvError};
+
 pub const ARTIFACTS_DIR_ENV_NAME: &str = "ARTIFACTS_DIR";
 pub const SERIALIZE_ENV_NAME: &str = "CW_ORCH_SERIALIZE_JSON";
 pub const MANUAL_INTERACTION_ENV_NAME: &str = "CW_ORCH_MANUAL_INTERACTION";
+pub const DRY_RUN_ENV_NAME: &str = "CW_ORCH_DRY_RUN";
+pub const MAX_WASM_SIZE_ENV_NAME: &str = "CW_ORCH_MAX_WASM_SIZE";
+pub const UPDATE_GAS_SNAPSHOTS_ENV_NAME: &str = "CW_ORCH_UPDATE_GAS_SNAPSHOTS";
+pub const ADDRESS_BOOK_ENV_NAME: &str = "CW_ORCH_ADDRESS_BOOK";
+pub const PROJECT_ENV_NAME: &str = "CW_ORCH_PROJECT";
 
 pub struct CoreEnvVars;
 
@@ -59,6 +66,61 @@ impl CoreEnvVars {
             true
         }
     }
+
+    /// Optional - boolean
+    /// Defaults to "false"
+    /// If equals to true, transactions are only simulated and logged instead of being broadcasted.
+    /// `Daemon` simulates every transaction, `Mock` executes them on its in-memory chain with a dry-run label.
+    /// `Deploy` implementations can use this value to branch their deployment logic.
+    pub fn dry_run() -> bool {
+        if let Ok(str_value) = env::var(DRY_RUN_ENV_NAME) {
+            parse_with_log(str_value, DRY_RUN_ENV_NAME)
+        } else {
+            false
+        }
+    }
+
+    /// Optional - Integer
+    /// Defaults to 819200 (800 KiB, the default of wasmd)
+    /// Maximum size in bytes of the wasm codes accepted by the chain, checked before every upload
+    pub fn max_wasm_size() -> usize {
+        if let Ok(str_value) = env::var(MAX_WASM_SIZE_ENV_NAME) {
+            parse_with_log(str_value, MAX_WASM_SIZE_ENV_NAME)
+        } else {
+            DEFAULT_MAX_WASM_SIZE
+        }
+    }
+
+    /// Optional - boolean
+    /// Defaults to "false"
+    /// If equals to true, the gas golden files of the `GasSnapshot`s are overwritten with the gas of the current run instead of being compared.
+    pub fn update_gas_snapshots() -> bool {
+        if let Ok(str_value) = env::var(UPDATE_GAS_SNAPSHOTS_ENV_NAME) {
+            parse_with_log(str_value, UPDATE_GAS_SNAPSHOTS_ENV_NAME)
+        } else {
+            false
+        }
+    }
+
+    /// Optional - Path
+    /// Defaults to "~/.cw-orchestrator/address_book.json"
+    /// Path of the file storing the `AddressBook`
+    pub fn address_book() -> Result<PathBuf, CwEnvError> {
+        if let Ok(str_value) = env::var(ADDRESS_BOOK_ENV_NAME) {
+            Ok(parse_with_log(str_value, ADDRESS_BOOK_ENV_NAME))
+        } else {
+            dirs::home_dir()
+                .map(|home| home.join(".cw-orchestrator").join("address_book.json"))
+                .ok_or_else(|| CwEnvError::EnvVarNotPresentNamed(ADDRESS_BOOK_ENV_NAME.to_string()))
+        }
+    }
+
+    /// Optional - String
+    /// Name of the project, if set the contracts are instantiated with the label `{project}:{contract_id}:{deployment_id}`
+    /// instead of their contract id
+    pub fn project() -> Option<String> {
+        env::var(PROJECT_ENV_NAME).ok()
+    }
 }
 
 fn parse_with_log<F: FromStr<Err = E>, E: std::fmt::Display>(
@@ -0,0 +1,218 @@
+//! Named addresses of each chain, shared between scripts.
+//!
+//! The [`AddressBook`] maps aliases (e.g. `dex_router`) to the addresses of a chain, with tags and free-form metadata.
+//! It is stored as JSON, by default in `~/.cw-orchestrator/address_book.json` (see [`CoreEnvVars::address_book`]),
+//! with the entries of all the chains in the same file.
+//!
+//! ```rust,ignore
+//! use cw_orch::prelude::*;
+//!
+//! let mut book = chain.address_book()?;
+//! book.insert("dex_router", &router_addr).tag("dex").metadata("version", "2.1.0");
+//! book.save()?;
+//!
+//! let router = chain.address_book()?.get("dex_router")?;
+//! let mut pool = Pool::new("pool", chain.clone());
+//! pool.set_default_address_from_book(&book, "atom_osmo_pool")?;
+//! ```
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    path::{Path, PathBuf},
+};
+
+use cosmwasm_std::Addr;
+use serde::{Deserialize, Serialize};
+
+use super::EnvironmentQuerier;
+use crate::{CoreEnvVars, CwEnvError};
+
+/// Address registered in the [`AddressBook`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AddressEntry {
+    /// Registered address
+    pub address: Addr,
+    /// Tags used to group the addresses, e.g. `dex` or `multisig`
+    #[serde(default, skip_serializing_if = "BTreeSet::is_empty")]
+    pub tags: BTreeSet<String>,
+    /// Free-form metadata, e.g. the version or the owner of a contract
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub metadata: BTreeMap<String, String>,
+}
+
+impl AddressEntry {
+    /// Entry without tags nor metadata
+    pub fn new(address: &Addr) -> Self {
+        Self {
+            address: address.clone(),
+            tags: BTreeSet::new(),
+            metadata: BTreeMap::new(),
+        }
+    }
+
+    /// Adds a tag to the entry
+    pub fn tag(&mut self, tag: impl Into<String>) -> &mut Self {
+        self.tags.insert(tag.into());
+        self
+    }
+
+    /// Sets a metadata value of the entry
+    pub fn metadata(&mut self, key: impl Into<String>, value: impl Into<String>) -> &mut Self {
+        self.metadata.insert(key.into(), value.into());
+        self
+    }
+}
+
+/// Aliased addresses of a chain, loaded from the address book file
+#[derive(Debug, Clone, PartialEq)]
+pub struct AddressBook {
+    path: PathBuf,
+    chain_id: String,
+    /// Entries of all the chains of the file, by chain id and alias
+    chains: BTreeMap<String, BTreeMap<String, AddressEntry>>,
+}
+
+impl AddressBook {
+    /// Loads the entries of `chain_id` from the address book file at `path`.
+    /// The book is empty if the file doesn't exist yet.
+    pub fn load(path: impl AsRef<Path>, chain_id: impl Into<String>) -> Result<Self, CwEnvError> {
+        let path = path.as_ref().to_path_buf();
+        let chains = if path.exists() {
+            serde_json::from_slice(&std::fs::read(&path)?)?
+        } else {
+            BTreeMap::new()
+        };
+        Ok(Self {
+            path,
+            chain_id: chain_id.into(),
+            chains,
+        })
+    }
+
+    /// Loads the entries of `chain_id` from the default address book file, see [`CoreEnvVars::address_book`]
+    pub fn load_default(chain_id: impl Into<String>) -> Result<Self, CwEnvError> {
+        Self::load(CoreEnvVars::address_book()?, chain_id)
+    }
+
+    /// Chain id of the entries of the book
+    pub fn chain_id(&self) -> &str {
+        &self.chain_id
+    }
+
+    /// Path of the address book file
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Address registered under `alias`
+    pub fn get(&self, alias: &str) -> Result<Addr, CwEnvError> {
+        self.entry(alias)
+            .map(|entry| entry.address.clone())
+            .ok_or_else(|| {
+                CwEnvError::AliasNotInAddressBook(alias.to_string(), self.chain_id.clone())
+            })
+    }
+
+    /// Entry registered under `alias`
+    pub fn entry(&self, alias: &str) -> Option<&AddressEntry> {
+        self.chains.get(&self.chain_id)?.get(alias)
+    }
+
+    /// Alias of `address`, if it is registered
+    pub fn alias_of(&self, address: &Addr) -> Option<&str> {
+        self.entries()
+            .find(|(_, entry)| &entry.address == address)
+            .map(|(alias, _)| alias)
+    }
+
+    /// All the entries of the chain, by alias
+    pub fn entries(&self) -> impl Iterator<Item = (&str, &AddressEntry)> {
+        self.chains
+            .get(&self.chain_id)
+            .into_iter()
+            .flatten()
+            .map(|(alias, entry)| (alias.as_str(), entry))
+    }
+
+    /// Entries of the chain having `tag`, by alias
+    pub fn tagged<'a>(&'a self, tag: &'a str) -> impl Iterator<Item = (&'a str, &'a AddressEntry)> {
+        self.entries()
+            .filter(move |(_, entry)| entry.tags.contains(tag))
+    }
+
+    /// Registers `address` under `alias`, replacing the previous entry.
+    /// Returns the new entry to add tags and metadata to it.
+    pub fn insert(&mut self, alias: impl Into<String>, address: &Addr) -> &mut AddressEntry {
+        let entries = self.chains.entry(self.chain_id.clone()).or_default();
+        let alias = alias.into();
+        entries.insert(alias.clone(), AddressEntry::new(address));
+        entries.get_mut(&alias).unwrap()
+    }
+
+    /// Removes the entry registered under `alias`
+    pub fn remove(&mut self, alias: &str) -> Option<AddressEntry> {
+        self.chains.get_mut(&self.chain_id)?.remove(alias)
+    }
+
+    /// Writes the entries of the chain to the address book file.
+    /// The entries of the other chains are read again from the file, so that books of different chains can be saved concurrently.
+    pub fn save(&self) -> Result<(), CwEnvError> {
+        let mut chains = Self::load(&self.path, &self.chain_id)?.chains;
+        match self.chains.get(&self.chain_id) {
+            Some(entries) => chains.insert(self.chain_id.clone(), entries.clone()),
+            None => chains.remove(&self.chain_id),
+        };
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&self.path, serde_json::to_string_pretty(&chains)?)?;
+        Ok(())
+    }
+}
+
+/// Access to the address book of the chain of an environment
+pub trait AddressBookGetter: EnvironmentQuerier {
+    /// Loads the default address book, with the entries of the chain of the environment
+    fn address_book(&self) -> Result<AddressBook, CwEnvError> {
+        AddressBook::load_default(self.env_info().chain_id)
+    }
+}
+
+impl<T: EnvironmentQuerier> AddressBookGetter for T {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn aliases_per_chain() -> Result<(), CwEnvError> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("address-book.json");
+        let router = Addr::unchecked("osmo1router");
+
+        let mut book = AddressBook::load(&path, "osmosis-1")?;
+        book.insert("dex_router", &router)
+            .tag("dex")
+            .metadata("version", "2.1.0");
+        book.insert("treasury", &Addr::unchecked("osmo1treasury"));
+
+        // Loaded before the osmosis entries were saved
+        let mut juno = AddressBook::load(&path, "juno-1")?;
+        book.save()?;
+        assert!(juno.get("dex_router").is_err());
+        juno.insert("dex_router", &Addr::unchecked("juno1router"));
+        juno.save()?;
+
+        // Saving a chain keeps the entries of the others
+        let book = AddressBook::load(&path, "osmosis-1")?;
+        assert_eq!(book.get("dex_router")?, router);
+        assert_eq!(book.alias_of(&router), Some("dex_router"));
+        assert_eq!(
+            book.entry("dex_router").unwrap().metadata["version"],
+            "2.1.0"
+        );
+        let tagged: Vec<_> = book.tagged("dex").map(|(alias, _)| alias).collect();
+        assert_eq!(tagged, vec!["dex_router"]);
+        assert_eq!(book.entries().count(), 2);
+        Ok(())
+    }
+}
@@ -1,7 +1,9 @@
-use std::fmt::Display;
+use std::{fmt::Display, str::FromStr};
 
 use serde::{Deserialize, Serialize};
 
+use crate::CwEnvError;
+
 pub type ChainInfo = ChainInfoBase<&'static str, &'static [&'static str]>;
 pub type ChainInfoOwned = ChainInfoBase<String, Vec<String>>;
 
@@ -133,6 +135,23 @@ impl From<String> for ChainKind {
     }
 }
 
+/// Unlike the `From<String>` conversion, unknown kinds are an error instead of [`ChainKind::Unspecified`]
+impl FromStr for ChainKind {
+    type Err = CwEnvError;
+
+    fn from_str(kind: &str) -> Result<Self, Self::Err> {
+        match kind {
+            "local" => Ok(ChainKind::Local),
+            "testnet" => Ok(ChainKind::Testnet),
+            "mainnet" => Ok(ChainKind::Mainnet),
+            "unspecified" => Ok(ChainKind::Unspecified),
+            _ => Err(CwEnvError::StdErr(format!(
+                "Unknown chain kind `{kind}`, expected local, testnet, mainnet or unspecified"
+            ))),
+        }
+    }
+}
+
 impl<StringType: Into<String> + Default, StringArrayType: AsRef<[StringType]> + Default>
     ChainInfoBase<StringType, StringArrayType>
 {
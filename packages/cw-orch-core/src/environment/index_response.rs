@@ -3,6 +3,8 @@ use cw_multi_test::AppResponse;
 #[cfg(feature = "eth")]
 use snailquote::unescape;
 
+use super::TxAnalysis;
+
 const CODE_ID_UPLOAD_EVENT: (&str, &str) = ("store_code", "code_id");
 const ADDRESS_INSTANTIATE_EVENT: (&str, &str) = ("instantiate", "_contract_address");
 
@@ -29,6 +31,16 @@ pub trait IndexResponse {
     /// Get the data field of the response.
     fn data(&self) -> Option<Binary>;
 
+    /// Gas used by the transaction, when the environment reports it.
+    fn gas_used(&self) -> Option<u64> {
+        None
+    }
+
+    /// Net coin flows of the transaction, to assert the funds moved by it.
+    fn analysis(&self) -> TxAnalysis {
+        TxAnalysis::new(self)
+    }
+
     /// Helper to get the contract address of a instantiate response.
     fn instantiated_contract_address(&self) -> StdResult<Addr> {
         if let Ok(code_id) = self
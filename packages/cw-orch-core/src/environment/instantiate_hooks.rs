@@ -0,0 +1,154 @@
+//! Hooks fired by the environments after every successful instantiation.
+//!
+//! Factory contracts instantiate child contracts that the scripts don't know about beforehand.
+//! A hook receives all the contracts created by an instantiate transaction and returns the ids under which they should be registered in the state.
+//!
+//! ```
+//! use cw_orch_core::environment::{InstantiateHooks, InstantiatedContract};
+//!
+//! const PAIR_CODE_ID: u64 = 2;
+//!
+//! let mut hooks = InstantiateHooks::default();
+//! hooks.add(|contracts: &[InstantiatedContract]| {
+//!     contracts
+//!         .iter()
+//!         .filter(|contract| contract.code_id == Some(PAIR_CODE_ID))
+//!         .map(|contract| (format!("pair:{}", contract.address), contract.address.clone()))
+//!         .collect()
+//! });
+//! ```
+use std::{fmt::Debug, sync::Arc};
+
+use cosmwasm_std::Addr;
+
+use super::{IndexResponse, StateInterface};
+use crate::log::transaction_target;
+
+const INSTANTIATE_EVENT: &str = "instantiate";
+const CONTRACT_ADDRESS_ATTR: &str = "_contract_address";
+const CODE_ID_ATTR: &str = "code_id";
+
+/// Contract created by an instantiate transaction
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InstantiatedContract {
+    /// Address of the new contract
+    pub address: Addr,
+    /// Code id of the new contract, if reported in the events
+    pub code_id: Option<u64>,
+}
+
+impl InstantiatedContract {
+    /// Parses the contracts created by a transaction from its `instantiate` events.
+    /// The directly instantiated contract comes first, followed by the contracts it instantiated.
+    pub fn from_response(response: &impl IndexResponse) -> Vec<Self> {
+        response
+            .events()
+            .into_iter()
+            .filter(|event| event.ty == INSTANTIATE_EVENT)
+            .filter_map(|event| {
+                let attr = |key: &str| {
+                    event
+                        .attributes
+                        .iter()
+                        .find(|attr| attr.key == key)
+                        .map(|attr| attr.value.clone())
+                };
+                Some(Self {
+                    address: Addr::unchecked(attr(CONTRACT_ADDRESS_ATTR)?),
+                    code_id: attr(CODE_ID_ATTR).and_then(|code_id| code_id.parse().ok()),
+                })
+            })
+            .collect()
+    }
+}
+
+/// Hook called with the contracts created by an instantiate transaction.
+/// Returns the `(contract_id, address)` pairs to register in the state.
+pub type InstantiateHook =
+    Arc<dyn Fn(&[InstantiatedContract]) -> Vec<(String, Addr)> + Send + Sync>;
+
+/// Set of [`InstantiateHook`]s registered on an environment
+#[derive(Clone, Default)]
+pub struct InstantiateHooks {
+    hooks: Vec<InstantiateHook>,
+}
+
+impl Debug for InstantiateHooks {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("InstantiateHooks")
+            .field("hooks", &self.hooks.len())
+            .finish()
+    }
+}
+
+impl InstantiateHooks {
+    /// Registers a new hook
+    pub fn add(
+        &mut self,
+        hook: impl Fn(&[InstantiatedContract]) -> Vec<(String, Addr)> + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.hooks.push(Arc::new(hook));
+        self
+    }
+
+    /// Returns true if no hook is registered
+    pub fn is_empty(&self) -> bool {
+        self.hooks.is_empty()
+    }
+
+    /// Runs the hooks on an instantiate response and registers the returned contracts in `state`
+    pub fn apply(&self, response: &impl IndexResponse, state: &mut impl StateInterface) {
+        if self.is_empty() {
+            return;
+        }
+        let contracts = InstantiatedContract::from_response(response);
+        for hook in &self.hooks {
+            for (contract_id, address) in hook(&contracts) {
+                log::debug!(
+                    target: &transaction_target(),
+                    "Registering instantiated contract {contract_id} at {address}"
+                );
+                state.set_address(&contract_id, &address);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::Event;
+    use cw_multi_test::AppResponse;
+
+    use super::*;
+
+    #[test]
+    fn parses_children() {
+        let response = AppResponse {
+            events: vec![
+                Event::new("instantiate")
+                    .add_attribute("_contract_address", "factory")
+                    .add_attribute("code_id", "1"),
+                Event::new("wasm").add_attribute("action", "create_pair"),
+                Event::new("instantiate")
+                    .add_attribute("_contract_address", "pair")
+                    .add_attribute("code_id", "2"),
+            ],
+            data: None,
+        };
+
+        let contracts = InstantiatedContract::from_response(&response);
+        assert_eq!(
+            contracts,
+            vec![
+                InstantiatedContract {
+                    address: Addr::unchecked("factory"),
+                    code_id: Some(1),
+                },
+                InstantiatedContract {
+                    address: Addr::unchecked("pair"),
+                    code_id: Some(2),
+                },
+            ]
+        );
+    }
+}
@@ -1,19 +1,29 @@
+mod address_book;
 mod chain_info;
 mod envs;
 mod index_response;
+mod instantiate_hooks;
 mod queriers;
 mod state;
+mod tx_analysis;
 mod tx_handler;
+mod tx_hooks;
 
+pub use address_book::{AddressBook, AddressBookGetter, AddressEntry};
 pub use chain_info::{ChainInfo, ChainInfoOwned, ChainKind, NetworkInfo, NetworkInfoOwned};
 pub use envs::{BankSetter, CwEnv, Environment, MutCwEnv};
 pub use index_response::IndexResponse;
+pub use instantiate_hooks::{InstantiateHook, InstantiateHooks, InstantiatedContract};
 pub use queriers::{
     bank::BankQuerier,
     env::{EnvironmentInfo, EnvironmentQuerier},
     node::NodeQuerier,
-    wasm::{AsyncWasmQuerier, WasmQuerier},
+    wasm::{
+        AsyncWasmQuerier, ContractHistoryEntry, ContractHistoryOperation, MaybeSend, WasmQuerier,
+    },
     DefaultQueriers, Querier, QuerierGetter, QueryHandler,
 };
 pub use state::{ChainState, StateInterface};
-pub use tx_handler::{AccessConfig, TxHandler, TxResponse};
+pub use tx_analysis::TxAnalysis;
+pub use tx_handler::{AccessConfig, AsyncTxHandler, AsyncTxResponse, TxHandler, TxResponse};
+pub use tx_hooks::{TxContext, TxHook, TxHooks, TxKind};
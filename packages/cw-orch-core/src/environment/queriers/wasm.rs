@@ -1,4 +1,4 @@
-use cosmwasm_std::{from_json, Addr, Checksum, CodeInfoResponse, ContractInfoResponse};
+use cosmwasm_std::{from_json, Addr, Binary, Checksum, CodeInfoResponse, ContractInfoResponse};
 use cw_storage_plus::{Item, Map, PrimaryKey};
 use serde::{de::DeserializeOwned, Serialize};
 
@@ -10,6 +10,29 @@ use crate::{
 
 use super::Querier;
 
+/// Operation recorded in the code history of a contract
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ContractHistoryOperation {
+    /// The contract was instantiated
+    Init,
+    /// The contract was migrated
+    Migrate,
+    /// The contract was imported in the genesis of the chain
+    Genesis,
+}
+
+/// Entry of the code history of a contract
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ContractHistoryEntry {
+    pub operation: ContractHistoryOperation,
+    /// Code id of the contract after the operation
+    pub code_id: u64,
+    /// Height of the block including the operation, `None` for the genesis contracts
+    pub block_height: Option<u64>,
+    /// JSON encoded instantiate or migrate message
+    pub msg: Binary,
+}
+
 pub trait WasmQuerier: Querier {
     type Chain: ChainState;
 
@@ -18,6 +41,22 @@ pub trait WasmQuerier: Querier {
     /// Query contract info
     fn contract_info(&self, address: &Addr) -> Result<ContractInfoResponse, Self::Error>;
 
+    /// Query the contract instantiated by `creator` with the label `label`.
+    /// Returns `None` if the environment can't list the contracts of a creator.
+    fn contract_by_label(
+        &self,
+        _creator: &Addr,
+        _label: &str,
+    ) -> Result<Option<Addr>, Self::Error> {
+        Ok(None)
+    }
+
+    /// Query the code history of a contract: its instantiation and migrations, oldest first.
+    /// Returns an empty history if the environment doesn't record it.
+    fn contract_history(&self, _address: &Addr) -> Result<Vec<ContractHistoryEntry>, Self::Error> {
+        Ok(vec![])
+    }
+
     /// Query contract state
     fn raw_query(&self, address: &Addr, query_keys: Vec<u8>) -> Result<Vec<u8>, Self::Error>;
 
@@ -73,5 +112,19 @@ pub trait AsyncWasmQuerier: Querier + Sync {
         &self,
         address: &Addr,
         query_msg: &Q,
-    ) -> impl std::future::Future<Output = Result<T, Self::Error>> + Send;
+    ) -> impl std::future::Future<Output = Result<T, Self::Error>> + MaybeSend;
 }
+
+/// `Send` on native targets.
+/// Browsers run the futures on a single thread and their `fetch` futures aren't `Send`, so it has no bound on `wasm32`.
+#[cfg(not(target_arch = "wasm32"))]
+pub trait MaybeSend: Send {}
+#[cfg(not(target_arch = "wasm32"))]
+impl<T: Send> MaybeSend for T {}
+
+/// `Send` on native targets.
+/// Browsers run the futures on a single thread and their `fetch` futures aren't `Send`, so it has no bound on `wasm32`.
+#[cfg(target_arch = "wasm32")]
+pub trait MaybeSend {}
+#[cfg(target_arch = "wasm32")]
+impl<T> MaybeSend for T {}
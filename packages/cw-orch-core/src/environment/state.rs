@@ -44,6 +44,21 @@ pub trait StateInterface: Clone {
 
     /// Get all codes related to this deployment.
     fn get_all_code_ids(&self) -> Result<HashMap<String, u64>, CwEnvError>;
+
+    /// Get the admin of a contract, as last set through this deployment.
+    /// Returns `None` if the contract has no admin or if the state doesn't track admins.
+    fn get_admin(&self, _contract_id: &str) -> Option<Addr> {
+        None
+    }
+
+    /// Set the admin of a contract, `None` if it was cleared.
+    fn set_admin(&mut self, _contract_id: &str, _admin: Option<&Addr>) {}
+
+    /// Id of the deployment the addresses are stored in.
+    /// Used in the generated labels of the contracts, defaults to `"default"`.
+    fn deployment_id(&self) -> String {
+        "default".to_string()
+    }
 }
 
 impl<S: StateInterface> StateInterface for Rc<RefCell<S>> {
@@ -78,6 +93,18 @@ impl<S: StateInterface> StateInterface for Rc<RefCell<S>> {
     fn remove_code_id(&mut self, contract_id: &str) {
         (**self).borrow_mut().remove_code_id(contract_id)
     }
+
+    fn get_admin(&self, contract_id: &str) -> Option<Addr> {
+        (**self).borrow().get_admin(contract_id)
+    }
+
+    fn set_admin(&mut self, contract_id: &str, admin: Option<&Addr>) {
+        (**self).borrow_mut().set_admin(contract_id, admin)
+    }
+
+    fn deployment_id(&self) -> String {
+        (**self).borrow().deployment_id()
+    }
 }
 
 impl<S: StateInterface> StateInterface for Rc<S> {
@@ -112,6 +139,18 @@ impl<S: StateInterface> StateInterface for Rc<S> {
     fn remove_code_id(&mut self, contract_id: &str) {
         (*Rc::make_mut(self)).remove_code_id(contract_id)
     }
+
+    fn get_admin(&self, contract_id: &str) -> Option<Addr> {
+        (**self).get_admin(contract_id)
+    }
+
+    fn set_admin(&mut self, contract_id: &str, admin: Option<&Addr>) {
+        (*Rc::make_mut(self)).set_admin(contract_id, admin)
+    }
+
+    fn deployment_id(&self) -> String {
+        (**self).deployment_id()
+    }
 }
 
 impl<S: StateInterface> StateInterface for Arc<S> {
@@ -146,4 +185,16 @@ impl<S: StateInterface> StateInterface for Arc<S> {
     fn remove_code_id(&mut self, contract_id: &str) {
         (*Arc::make_mut(self)).remove_code_id(contract_id)
     }
+
+    fn get_admin(&self, contract_id: &str) -> Option<Addr> {
+        (**self).get_admin(contract_id)
+    }
+
+    fn set_admin(&mut self, contract_id: &str, admin: Option<&Addr>) {
+        (*Arc::make_mut(self)).set_admin(contract_id, admin)
+    }
+
+    fn deployment_id(&self) -> String {
+        (**self).deployment_id()
+    }
 }
@@ -0,0 +1,336 @@
+//! Analysis of the coin movements of a transaction, from its events.
+//!
+//! ```rust,ignore
+//! let response = contract.execute(&ExecuteMsg::Withdraw {}, &[])?;
+//! let analysis = response.analysis();
+//! analysis.assert_sent(&contract.address()?, &coins(100, "ujuno"));
+//! analysis.assert_received(&chain.sender_addr(), &coins(100, "ujuno"));
+//! ```
+use std::{collections::BTreeMap, str::FromStr};
+
+use cosmwasm_std::{Addr, Coin, Event, Uint128};
+
+use super::IndexResponse;
+
+const COIN_SPENT_EVENT: (&str, &str) = ("coin_spent", "spender");
+const COIN_RECEIVED_EVENT: (&str, &str) = ("coin_received", "receiver");
+const TRANSFER_EVENT: (&str, &str, &str) = ("transfer", "sender", "recipient");
+/// Event of the Cosmos SDK holding the fee of the transaction and its payer
+const TX_FEE_EVENT: (&str, &str, &str) = ("tx", "fee", "fee_payer");
+
+/// Net coin flows of a transaction, per address and denom.
+///
+/// The flows are computed from the `coin_spent` and `coin_received` events emitted by the bank module of the chains.
+/// The `transfer` events are used when the environment doesn't emit them, which is the case of the mock environments.
+/// The fee of the transaction is not part of the flows, see [`TxAnalysis::fee`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TxAnalysis {
+    flows: BTreeMap<String, BTreeMap<String, i128>>,
+    fee: Vec<Coin>,
+}
+
+impl TxAnalysis {
+    /// Analyses the events of `response`
+    pub fn new(response: &(impl IndexResponse + ?Sized)) -> Self {
+        Self::from_events(&response.events())
+    }
+
+    /// Analyses a list of transaction events
+    pub fn from_events(events: &[Event]) -> Self {
+        let mut analysis = Self::default();
+        let has_coin_events = events
+            .iter()
+            .any(|e| e.ty == COIN_SPENT_EVENT.0 || e.ty == COIN_RECEIVED_EVENT.0);
+
+        // The fee is deducted with its own coin events, which are skipped
+        let fee = events
+            .iter()
+            .filter(|e| e.ty == TX_FEE_EVENT.0)
+            .find_map(|e| {
+                let fee = attribute(e, TX_FEE_EVENT.1)?;
+                let payer = attribute(e, TX_FEE_EVENT.2)?;
+                Some((fee, payer))
+            });
+        let mut fee_spent_skipped = false;
+        let mut fee_received_skipped = false;
+        if let Some((fee, _)) = &fee {
+            analysis.fee = sorted(&parse_coins(fee));
+        }
+
+        for event in events {
+            if let Some((fee, payer)) = &fee {
+                let amount = attribute(event, "amount");
+                let fee_payment = (event.ty == COIN_SPENT_EVENT.0
+                    && attribute(event, COIN_SPENT_EVENT.1) == Some(payer))
+                    || (!has_coin_events
+                        && event.ty == TRANSFER_EVENT.0
+                        && attribute(event, TRANSFER_EVENT.1) == Some(payer));
+                if !fee_spent_skipped && fee_payment && amount == Some(fee) {
+                    fee_spent_skipped = true;
+                    continue;
+                }
+                if !fee_received_skipped && event.ty == COIN_RECEIVED_EVENT.0 && amount == Some(fee)
+                {
+                    fee_received_skipped = true;
+                    continue;
+                }
+            }
+            if has_coin_events {
+                if event.ty == COIN_SPENT_EVENT.0 {
+                    analysis.add_event_flows(event, Some(COIN_SPENT_EVENT.1), None);
+                } else if event.ty == COIN_RECEIVED_EVENT.0 {
+                    analysis.add_event_flows(event, None, Some(COIN_RECEIVED_EVENT.1));
+                }
+            } else if event.ty == TRANSFER_EVENT.0 {
+                analysis.add_event_flows(event, Some(TRANSFER_EVENT.1), Some(TRANSFER_EVENT.2));
+            }
+        }
+        analysis
+    }
+
+    /// Applies the flows of `event`.
+    /// An event can hold several movements, each `amount` attribute applying to the last sender and receiver seen.
+    fn add_event_flows(
+        &mut self,
+        event: &Event,
+        sender_key: Option<&str>,
+        receiver_key: Option<&str>,
+    ) {
+        let mut sender = None;
+        let mut receiver = None;
+        for attr in &event.attributes {
+            if Some(attr.key.as_str()) == sender_key {
+                sender = Some(attr.value.clone());
+            } else if Some(attr.key.as_str()) == receiver_key {
+                receiver = Some(attr.value.clone());
+            } else if attr.key == "amount" {
+                for coin in parse_coins(&attr.value) {
+                    let Ok(amount) = i128::try_from(coin.amount.u128()) else {
+                        log::warn!("Ignoring the flow of {coin}, the amount is too large");
+                        continue;
+                    };
+                    if let Some(sender) = &sender {
+                        self.add_flow(sender, &coin.denom, -amount);
+                    }
+                    if let Some(receiver) = &receiver {
+                        self.add_flow(receiver, &coin.denom, amount);
+                    }
+                }
+            }
+        }
+    }
+
+    fn add_flow(&mut self, address: &str, denom: &str, amount: i128) {
+        let flow = self
+            .flows
+            .entry(address.to_string())
+            .or_default()
+            .entry(denom.to_string())
+            .or_default();
+        *flow = flow.saturating_add(amount);
+    }
+
+    /// Addresses whose balances were changed by the transaction
+    pub fn addresses(&self) -> Vec<Addr> {
+        self.flows
+            .iter()
+            .filter(|(_, flows)| flows.values().any(|amount| *amount != 0))
+            .map(|(address, _)| Addr::unchecked(address))
+            .collect()
+    }
+
+    /// Net amount of `denom` received by `address`, negative when the address sent funds
+    pub fn net_flow(&self, address: &Addr, denom: &str) -> i128 {
+        self.flows
+            .get(address.as_str())
+            .and_then(|flows| flows.get(denom))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Coins sent by `address`, net of the coins it received, sorted by denom
+    pub fn sent(&self, address: &Addr) -> Vec<Coin> {
+        self.coins(address, |amount| -amount)
+    }
+
+    /// Coins received by `address`, net of the coins it sent, sorted by denom
+    pub fn received(&self, address: &Addr) -> Vec<Coin> {
+        self.coins(address, |amount| amount)
+    }
+
+    /// Fee paid for the transaction, empty if the environment doesn't report it
+    pub fn fee(&self) -> &[Coin] {
+        &self.fee
+    }
+
+    fn coins(&self, address: &Addr, sign: impl Fn(i128) -> i128) -> Vec<Coin> {
+        self.flows
+            .get(address.as_str())
+            .into_iter()
+            .flatten()
+            .filter(|(_, amount)| sign(**amount) > 0)
+            .map(|(denom, amount)| Coin::new(Uint128::new(amount.unsigned_abs()), denom))
+            .collect()
+    }
+
+    /// Panics if the coins sent by `address` aren't exactly `coins`
+    #[track_caller]
+    pub fn assert_sent(&self, address: &Addr, coins: &[Coin]) {
+        assert_eq!(
+            self.sent(address),
+            sorted(coins),
+            "Unexpected coins sent by {address}"
+        );
+    }
+
+    /// Panics if the coins received by `address` aren't exactly `coins`
+    #[track_caller]
+    pub fn assert_received(&self, address: &Addr, coins: &[Coin]) {
+        assert_eq!(
+            self.received(address),
+            sorted(coins),
+            "Unexpected coins received by {address}"
+        );
+    }
+
+    /// Panics if the balances of `address` were changed by the transaction
+    #[track_caller]
+    pub fn assert_unchanged(&self, address: &Addr) {
+        self.assert_sent(address, &[]);
+        self.assert_received(address, &[]);
+    }
+}
+
+fn attribute<'a>(event: &'a Event, key: &str) -> Option<&'a String> {
+    event
+        .attributes
+        .iter()
+        .find(|attr| attr.key == key)
+        .map(|attr| &attr.value)
+}
+
+/// Parses a list of coins formatted like `10ujuno,5uatom`, ignoring the malformed ones
+fn parse_coins(value: &str) -> Vec<Coin> {
+    value
+        .split(',')
+        .filter_map(|coin| Coin::from_str(coin.trim()).ok())
+        .collect()
+}
+
+/// Sorts coins by denom, merging the duplicates and removing the empty ones
+fn sorted(coins: &[Coin]) -> Vec<Coin> {
+    let mut amounts: BTreeMap<&str, Uint128> = BTreeMap::new();
+    for coin in coins {
+        *amounts.entry(coin.denom.as_str()).or_default() += coin.amount;
+    }
+    amounts
+        .into_iter()
+        .filter(|(_, amount)| !amount.is_zero())
+        .map(|(denom, amount)| Coin::new(amount, denom))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use cosmwasm_std::coins;
+
+    use super::*;
+
+    #[test]
+    fn transfer_flows() {
+        let events = vec![
+            Event::new("transfer")
+                .add_attribute("recipient", "bob")
+                .add_attribute("sender", "alice")
+                .add_attribute("amount", "100ujuno,5uatom"),
+            Event::new("transfer")
+                .add_attribute("recipient", "alice")
+                .add_attribute("sender", "bob")
+                .add_attribute("amount", "40ujuno"),
+        ];
+        let analysis = TxAnalysis::from_events(&events);
+        let (alice, bob) = (Addr::unchecked("alice"), Addr::unchecked("bob"));
+
+        analysis.assert_sent(
+            &alice,
+            &[Coin::new(60u128, "ujuno"), Coin::new(5u128, "uatom")],
+        );
+        analysis.assert_received(&alice, &[]);
+        analysis.assert_received(
+            &bob,
+            &[Coin::new(5u128, "uatom"), Coin::new(60u128, "ujuno")],
+        );
+        assert_eq!(analysis.net_flow(&bob, "ujuno"), 60);
+        analysis.assert_unchanged(&Addr::unchecked("carol"));
+    }
+
+    #[test]
+    fn coin_events_take_precedence() {
+        let events = vec![
+            Event::new("coin_spent")
+                .add_attribute("spender", "alice")
+                .add_attribute("amount", "10ibc/ABCD"),
+            Event::new("coin_received")
+                .add_attribute("receiver", "bob")
+                .add_attribute("amount", "10ibc/ABCD"),
+            Event::new("transfer")
+                .add_attribute("recipient", "bob")
+                .add_attribute("sender", "alice")
+                .add_attribute("amount", "10ibc/ABCD"),
+        ];
+        let analysis = TxAnalysis::from_events(&events);
+
+        analysis.assert_sent(&Addr::unchecked("alice"), &coins(10, "ibc/ABCD"));
+        analysis.assert_received(&Addr::unchecked("bob"), &coins(10, "ibc/ABCD"));
+        assert_eq!(analysis.addresses().len(), 2);
+    }
+
+    #[test]
+    fn excludes_the_fee() {
+        let events = vec![
+            Event::new("coin_spent")
+                .add_attribute("spender", "alice")
+                .add_attribute("amount", "5ujuno"),
+            Event::new("coin_received")
+                .add_attribute("receiver", "fee_collector")
+                .add_attribute("amount", "5ujuno"),
+            Event::new("tx")
+                .add_attribute("fee", "5ujuno")
+                .add_attribute("fee_payer", "alice"),
+            Event::new("coin_spent")
+                .add_attribute("spender", "alice")
+                .add_attribute("amount", "5ujuno"),
+            Event::new("coin_received")
+                .add_attribute("receiver", "bob")
+                .add_attribute("amount", "5ujuno"),
+        ];
+        let analysis = TxAnalysis::from_events(&events);
+
+        analysis.assert_sent(&Addr::unchecked("alice"), &coins(5, "ujuno"));
+        analysis.assert_received(&Addr::unchecked("bob"), &coins(5, "ujuno"));
+        analysis.assert_unchanged(&Addr::unchecked("fee_collector"));
+        assert_eq!(analysis.fee(), coins(5, "ujuno"));
+    }
+
+    #[test]
+    fn large_amounts() {
+        let max = Uint128::MAX.to_string();
+        let events = vec![
+            Event::new("transfer")
+                .add_attribute("recipient", "bob")
+                .add_attribute("sender", "alice")
+                .add_attribute("amount", format!("{max}ujuno")),
+            Event::new("transfer")
+                .add_attribute("recipient", "bob")
+                .add_attribute("sender", "alice")
+                .add_attribute("amount", format!("{}ujuno", i128::MAX)),
+        ];
+        let analysis = TxAnalysis::from_events(&events);
+
+        // Amounts that don't fit in a flow are ignored instead of wrapping around
+        analysis.assert_sent(
+            &Addr::unchecked("alice"),
+            &[Coin::new(i128::MAX as u128, "ujuno")],
+        );
+    }
+}
@@ -4,7 +4,7 @@ use super::{ChainState, IndexResponse};
 use crate::{contract::interface_traits::Uploadable, error::CwEnvError};
 use cosmwasm_std::{Addr, Binary, Coin};
 use serde::Serialize;
-use std::fmt::Debug;
+use std::{fmt::Debug, future::Future};
 
 /// Response type for actions on an environment
 pub type TxResponse<Chain> = <Chain as TxHandler>::Response;
@@ -102,8 +102,100 @@ pub trait TxHandler: ChainState + Clone {
     ) -> Result<Self::Response, Self::Error> {
         unimplemented!("Bank send is not implemented on this env")
     }
+
+    /// Sets `new_admin` as the admin of the contract. Only allowed to the current admin.
+    fn update_admin(
+        &self,
+        _contract_address: &Addr,
+        _new_admin: &Addr,
+    ) -> Result<Self::Response, Self::Error> {
+        unimplemented!("Updating the admin of a contract is not implemented on this env")
+    }
+
+    /// Removes the admin of the contract, which can't be migrated afterwards. Only allowed to the current admin.
+    fn clear_admin(&self, _contract_address: &Addr) -> Result<Self::Response, Self::Error> {
+        unimplemented!("Clearing the admin of a contract is not implemented on this env")
+    }
+
+    /// Calls the `sudo` entry point of a contract.
+    /// Environments only allow it to the privileged accounts of the chain, when they exist.
+    fn sudo<S: Serialize + Debug>(
+        &self,
+        _sudo_msg: &S,
+        _contract_address: &Addr,
+    ) -> Result<Self::Response, Self::Error> {
+        unimplemented!("Calling the sudo entry point of a contract is not implemented on this env")
+    }
+}
+
+/// Response type for actions on an async environment
+pub type AsyncTxResponse<Chain> = <Chain as AsyncTxHandler>::Response;
+
+/// Async version of [`TxHandler`].
+/// Allows using contract interfaces from async code without blocking the runtime.
+pub trait AsyncTxHandler: ChainState + Sync {
+    /// Response type for transactions on an environment.
+    type Response: IndexResponse + Debug + Send + Clone;
+    /// Error type for transactions on an environment.
+    type Error: Into<CwEnvError> + Debug + std::error::Error + Send + Sync + 'static;
+
+    /// Gets the address of the current wallet used to sign transactions.
+    fn sender_addr(&self) -> Addr;
+
+    /// Uploads a contract to the chain.
+    fn upload<T: Uploadable + Sync>(
+        &self,
+        contract_source: &T,
+    ) -> impl Future<Output = Result<Self::Response, Self::Error>> + Send {
+        self.upload_with_access_config(contract_source, None)
+    }
+
+    /// Uploads a contract to the chain and specify the permissions for instantiating
+    fn upload_with_access_config<T: Uploadable + Sync>(
+        &self,
+        contract_source: &T,
+        access_config: Option<AccessConfig>,
+    ) -> impl Future<Output = Result<Self::Response, Self::Error>> + Send;
+
+    /// Send a InstantiateMsg to a contract.
+    fn instantiate<I: Serialize + Debug + Sync>(
+        &self,
+        code_id: u64,
+        init_msg: &I,
+        label: Option<&str>,
+        admin: Option<&Addr>,
+        coins: &[cosmwasm_std::Coin],
+    ) -> impl Future<Output = Result<Self::Response, Self::Error>> + Send;
+
+    /// Send a Instantiate2Msg to a contract.
+    fn instantiate2<I: Serialize + Debug + Sync>(
+        &self,
+        code_id: u64,
+        init_msg: &I,
+        label: Option<&str>,
+        admin: Option<&Addr>,
+        coins: &[cosmwasm_std::Coin],
+        salt: Binary,
+    ) -> impl Future<Output = Result<Self::Response, Self::Error>> + Send;
+
+    /// Send a ExecMsg to a contract.
+    fn execute<E: Serialize + Debug + Sync>(
+        &self,
+        exec_msg: &E,
+        coins: &[Coin],
+        contract_address: &Addr,
+    ) -> impl Future<Output = Result<Self::Response, Self::Error>> + Send;
+
+    /// Send a MigrateMsg to a contract.
+    fn migrate<M: Serialize + Debug + Sync>(
+        &self,
+        migrate_msg: &M,
+        new_code_id: u64,
+        contract_address: &Addr,
+    ) -> impl Future<Output = Result<Self::Response, Self::Error>> + Send;
 }
 
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum AccessConfig {
     Unspecified,
     Nobody,
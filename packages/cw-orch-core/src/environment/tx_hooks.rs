@@ -0,0 +1,241 @@
+//! Hooks fired by the environments around every transaction.
+//!
+//! Pre-broadcast hooks run before a transaction is sent and abort it by returning an error,
+//! e.g. to ask for a confirmation on mainnet or to enforce a spending cap.
+//! Post-response hooks run once the transaction succeeded, e.g. to write audit logs or instrument tests.
+//! Hooks run in registration order and the first error stops the chain.
+//!
+//! ```
+//! use cosmwasm_std::Uint128;
+//! use cw_orch_core::{
+//!     environment::{TxContext, TxHooks},
+//!     CwEnvError,
+//! };
+//!
+//! let mut hooks = TxHooks::default();
+//! hooks
+//!     .add_pre_broadcast(|context: &TxContext| {
+//!         let spent: Uint128 = context.funds.iter().map(|coin| coin.amount).sum();
+//!         if spent > Uint128::new(1_000_000) {
+//!             return Err(CwEnvError::StdErr(format!(
+//!                 "{:?} exceeds the spending cap",
+//!                 context.kind
+//!             )));
+//!         }
+//!         Ok(())
+//!     })
+//!     .add_post_response(|context: &TxContext| {
+//!         println!("{:?} by {} on {}", context.kind, context.sender, context.chain_id);
+//!         Ok(())
+//!     });
+//! ```
+use std::{fmt::Debug, sync::Arc};
+
+use cosmwasm_std::{Addr, Coin, Event};
+use serde::Serialize;
+
+use super::IndexResponse;
+use crate::CwEnvError;
+
+/// Kind of transaction sent by an environment
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TxKind {
+    Upload,
+    Instantiate { code_id: u64 },
+    Instantiate2 { code_id: u64 },
+    Execute,
+    Migrate { new_code_id: u64 },
+    BankSend,
+    UpdateAdmin,
+    ClearAdmin,
+    Sudo,
+    UpdateInstantiateConfig { code_id: u64 },
+    SubmitProposal { type_url: String },
+    CreateVestingAccount { type_url: String },
+    Stargate { type_urls: Vec<String> },
+}
+
+/// Description of a transaction, passed to the [`TxHook`]s
+#[derive(Clone, Debug, PartialEq)]
+pub struct TxContext {
+    /// Chain the transaction is sent to
+    pub chain_id: String,
+    /// Sender of the transaction
+    pub sender: Addr,
+    /// Kind of transaction
+    pub kind: TxKind,
+    /// Target contract, or receiver of a bank send
+    pub contract: Option<Addr>,
+    /// Message sent to the contract, as JSON
+    pub msg: Option<serde_json::Value>,
+    /// Funds sent with the transaction
+    pub funds: Vec<Coin>,
+    /// Events of the transaction, only set for post-response hooks
+    pub events: Option<Vec<Event>>,
+}
+
+impl TxContext {
+    pub fn new(chain_id: impl Into<String>, sender: Addr, kind: TxKind) -> Self {
+        Self {
+            chain_id: chain_id.into(),
+            sender,
+            kind,
+            contract: None,
+            msg: None,
+            funds: vec![],
+            events: None,
+        }
+    }
+
+    /// Sets the target contract or receiver
+    pub fn contract(mut self, contract: &Addr) -> Self {
+        self.contract = Some(contract.clone());
+        self
+    }
+
+    /// Sets the message sent to the contract.
+    /// Messages that can't be represented as JSON are left unset.
+    pub fn msg(mut self, msg: &impl Serialize) -> Self {
+        self.msg = serde_json::to_value(msg).ok();
+        self
+    }
+
+    /// Sets the funds sent with the transaction
+    pub fn funds(mut self, funds: &[Coin]) -> Self {
+        self.funds = funds.to_vec();
+        self
+    }
+
+    /// Context of the response of the transaction, passed to the post-response hooks
+    pub fn with_response(&self, response: &impl IndexResponse) -> Self {
+        Self {
+            events: Some(response.events()),
+            ..self.clone()
+        }
+    }
+}
+
+/// Hook called with the context of a transaction. Returning an error aborts the operation.
+pub type TxHook = Arc<dyn Fn(&TxContext) -> Result<(), CwEnvError> + Send + Sync>;
+
+/// Set of [`TxHook`]s registered on an environment
+#[derive(Clone, Default)]
+pub struct TxHooks {
+    pre_broadcast: Vec<TxHook>,
+    post_response: Vec<TxHook>,
+}
+
+impl Debug for TxHooks {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TxHooks")
+            .field("pre_broadcast", &self.pre_broadcast.len())
+            .field("post_response", &self.post_response.len())
+            .finish()
+    }
+}
+
+impl TxHooks {
+    /// Registers a hook run before every transaction is broadcast
+    pub fn add_pre_broadcast(
+        &mut self,
+        hook: impl Fn(&TxContext) -> Result<(), CwEnvError> + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.pre_broadcast.push(Arc::new(hook));
+        self
+    }
+
+    /// Registers a hook run after every successful transaction
+    pub fn add_post_response(
+        &mut self,
+        hook: impl Fn(&TxContext) -> Result<(), CwEnvError> + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.post_response.push(Arc::new(hook));
+        self
+    }
+
+    /// Appends the hooks of `other`, which run after the hooks already registered
+    pub fn extend(&mut self, other: &TxHooks) -> &mut Self {
+        self.pre_broadcast
+            .extend(other.pre_broadcast.iter().cloned());
+        self.post_response
+            .extend(other.post_response.iter().cloned());
+        self
+    }
+
+    /// Returns true if no hook is registered
+    pub fn is_empty(&self) -> bool {
+        self.pre_broadcast.is_empty() && self.post_response.is_empty()
+    }
+
+    /// Runs the pre-broadcast hooks, stopping at the first error
+    pub fn pre_broadcast(&self, context: &TxContext) -> Result<(), CwEnvError> {
+        self.pre_broadcast.iter().try_for_each(|hook| hook(context))
+    }
+
+    /// Runs the post-response hooks on the response of the transaction, stopping at the first error
+    pub fn post_response(
+        &self,
+        context: &TxContext,
+        response: &impl IndexResponse,
+    ) -> Result<(), CwEnvError> {
+        if self.post_response.is_empty() {
+            return Ok(());
+        }
+        let context = context.with_response(response);
+        self.post_response
+            .iter()
+            .try_for_each(|hook| hook(&context))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use cosmwasm_std::coins;
+    use cw_multi_test::AppResponse;
+
+    use super::*;
+
+    #[test]
+    fn hooks_compose_in_order() {
+        let calls = Arc::new(Mutex::new(vec![]));
+        let mut hooks = TxHooks::default();
+        let log = calls.clone();
+        hooks.add_pre_broadcast(move |_| {
+            log.lock().unwrap().push("first");
+            Ok(())
+        });
+        let mut other = TxHooks::default();
+        let log = calls.clone();
+        other
+            .add_pre_broadcast(move |context| {
+                log.lock().unwrap().push("cap");
+                if context.funds.iter().any(|coin| coin.amount.u128() > 100) {
+                    return Err(CwEnvError::StdErr("Spending cap exceeded".to_string()));
+                }
+                Ok(())
+            })
+            .add_post_response(|context| {
+                assert_eq!(context.events.as_ref().unwrap().len(), 1);
+                Ok(())
+            });
+        hooks.extend(&other);
+
+        let context = TxContext::new("test-1", Addr::unchecked("sender"), TxKind::Execute)
+            .contract(&Addr::unchecked("contract"))
+            .funds(&coins(50, "ujuno"));
+        hooks.pre_broadcast(&context).unwrap();
+        assert_eq!(*calls.lock().unwrap(), vec!["first", "cap"]);
+
+        let response = AppResponse {
+            events: vec![Event::new("wasm")],
+            data: None,
+        };
+        hooks.post_response(&context, &response).unwrap();
+
+        assert!(hooks
+            .pre_broadcast(&context.funds(&coins(500, "ujuno")))
+            .is_err());
+    }
+}
@@ -44,6 +44,12 @@ pub enum CwEnvError {
     StdErr(String),
     #[error("Environment variable not defined {0}")]
     EnvVarNotPresentNamed(String),
+    #[error("No contract instantiated by {0} matches the filter")]
+    ChildNotFound(String),
+    #[error("Alias {0} not found in the address book of {1}")]
+    AliasNotInAddressBook(String, String),
+    #[error(transparent)]
+    UploadPrecheck(#[from] crate::contract::UploadPrecheckError),
 }
 
 impl CwEnvError {
@@ -4,6 +4,8 @@ pub use env::CoreEnvVars;
 pub mod environment;
 
 pub mod build;
+#[cfg(feature = "conformance")]
+pub mod conformance;
 mod error;
 pub mod log;
 pub use error::CwEnvError;
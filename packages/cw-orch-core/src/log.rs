@@ -23,3 +23,16 @@ pub fn transaction_target() -> String {
 pub fn local_target() -> String {
     format_aligned(LOCAL_LOGS)
 }
+
+/// Installs a global subscriber printing the spans and events as JSON lines, for log ingestion in CI.
+/// Events are filtered with the `RUST_LOG` environment variable and `log` records are forwarded to the subscriber.
+#[cfg(feature = "tracing")]
+pub fn init_json_tracing() -> Result<(), crate::CwEnvError> {
+    tracing_subscriber::fmt()
+        .json()
+        .with_env_filter(tracing_subscriber::EnvFilter::from_default_env())
+        .with_current_span(true)
+        .with_span_list(true)
+        .try_init()
+        .map_err(|e| crate::CwEnvError::StdErr(e.to_string()))
+}
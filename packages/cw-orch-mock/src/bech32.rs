@@ -80,21 +80,39 @@ impl<S: StateInterface> MockBase<MockApiBech32, S> {
         // We create an address internally
         let sender = app.borrow().api().addr_make("sender");
 
-        Self { sender, state, app }
+        Self {
+            sender,
+            state,
+            app,
+            bank_invariants: Default::default(),
+            instantiate_hooks: Default::default(),
+            tx_hooks: Default::default(),
+            block_gas: Default::default(),
+            block_pacing: Default::default(),
+            stargate_handlers: Default::default(),
+            instantiated: Default::default(),
+        }
     }
 }
 
 impl<S: StateInterface> MockBech32<S> {
+    /// Clones the chain with `sender` as sender, after adding `amount` to its balance.
+    /// Contract interfaces can do the same with [`CallAs::call_as_funded`](cw_orch_core::contract::interface_traits::CallAs::call_as_funded).
+    pub fn call_as_funded(&self, sender: &Addr, amount: &[Coin]) -> Result<Self, CwEnvError> {
+        self.add_balance(sender, amount.to_vec())?;
+        Ok(self.call_as(sender))
+    }
+
     /// Set the bank balance of an address.
     pub fn set_balance(
         &self,
         address: &Addr,
         amount: Vec<cosmwasm_std::Coin>,
     ) -> Result<(), CwEnvError> {
-        self.app
-            .borrow_mut()
-            .init_modules(|router, _, storage| router.bank.init_balance(storage, address, amount))
-            .map_err(Into::into)
+        self.app.borrow_mut().init_modules(|router, _, storage| {
+            router.bank.init_balance(storage, address, amount.clone())
+        })?;
+        self.refresh_bank_invariants(amount.iter().map(|c| c.denom.as_str()))
     }
 
     /// Adds the bank balance of an address.
@@ -105,15 +123,13 @@ impl<S: StateInterface> MockBech32<S> {
     ) -> Result<(), CwEnvError> {
         let addr = &address;
         let b = self.query_all_balances(addr)?;
-        let new_amount = NativeBalance(b) + NativeBalance(amount);
-        self.app
-            .borrow_mut()
-            .init_modules(|router, _, storage| {
-                router
-                    .bank
-                    .init_balance(storage, addr, new_amount.into_vec())
-            })
-            .map_err(Into::into)
+        let new_amount = NativeBalance(b) + NativeBalance(amount.clone());
+        self.app.borrow_mut().init_modules(|router, _, storage| {
+            router
+                .bank
+                .init_balance(storage, addr, new_amount.into_vec())
+        })?;
+        self.refresh_bank_invariants(amount.iter().map(|c| c.denom.as_str()))
     }
 
     /// Set the balance for multiple coins at once.
@@ -128,7 +144,12 @@ impl<S: StateInterface> MockBech32<S> {
                     router.bank.init_balance(storage, addr, coins.to_vec())?;
                 }
                 Ok(())
-            })
+            })?;
+        self.refresh_bank_invariants(
+            balances
+                .iter()
+                .flat_map(|(_, coins)| coins.iter().map(|c| c.denom.as_str())),
+        )
     }
 
     /// Query the (bank) balance of a native token for and address.
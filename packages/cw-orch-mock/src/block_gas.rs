@@ -0,0 +1,142 @@
+//! Block gas accounting for the mock environment.
+//!
+//! cw-multi-test doesn't meter gas, so the gas used by a transaction is estimated from the size of its message,
+//! following the cost model of the Cosmos SDK ante handler: a fixed cost per transaction plus a cost per byte.
+//!
+//! Once enabled with [`MockBase::set_block_gas_limit`], all the transactions sent within the same block share the block gas limit.
+//! A transaction that would exceed it fails without being executed, and a transaction that fails doesn't use any block gas.
+//! Moving to the next block (`next_block`, `wait_blocks`, ...) resets the gas used.
+//! This lets protocols verify that the operations they batch in a single block (cranks, liquidations, ...) fit the limits of the real chain.
+use cosmwasm_std::{to_json_vec, Api};
+use cw_orch_core::{environment::StateInterface, CwEnvError};
+use serde::Serialize;
+
+use crate::MockBase;
+
+/// Default fixed gas cost of a transaction
+pub const DEFAULT_TX_BASE_GAS: u64 = 100_000;
+/// Default gas cost per byte of message, `TxSizeCostPerByte` in the Cosmos SDK
+pub const DEFAULT_GAS_PER_BYTE: u64 = 10;
+
+/// Gas limit of a block and gas used in the current block
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlockGasLimit {
+    limit: u64,
+    tx_base_gas: u64,
+    gas_per_byte: u64,
+    used: u64,
+    height: u64,
+}
+
+impl BlockGasLimit {
+    /// Creates a block gas limit with the default cost model
+    pub fn new(limit: u64) -> Self {
+        Self {
+            limit,
+            tx_base_gas: DEFAULT_TX_BASE_GAS,
+            gas_per_byte: DEFAULT_GAS_PER_BYTE,
+            used: 0,
+            height: 0,
+        }
+    }
+
+    /// Sets the fixed gas cost of a transaction
+    pub fn tx_base_gas(mut self, gas: u64) -> Self {
+        self.tx_base_gas = gas;
+        self
+    }
+
+    /// Sets the gas cost per byte of message
+    pub fn gas_per_byte(mut self, gas: u64) -> Self {
+        self.gas_per_byte = gas;
+        self
+    }
+
+    /// Gas limit of a block
+    pub fn limit(&self) -> u64 {
+        self.limit
+    }
+
+    /// Estimated gas of a transaction carrying a message of `msg_len` bytes
+    pub fn tx_gas(&self, msg_len: usize) -> u64 {
+        self.tx_base_gas + self.gas_per_byte * msg_len as u64
+    }
+}
+
+impl<A: Api, S: StateInterface> MockBase<A, S> {
+    /// Enables the block gas accounting. The gas used in the current block starts at 0.
+    pub fn set_block_gas_limit(&self, block_gas_limit: BlockGasLimit) {
+        let height = self.app.borrow().block_info().height;
+        *self.block_gas.borrow_mut() = Some(BlockGasLimit {
+            used: 0,
+            height,
+            ..block_gas_limit
+        });
+    }
+
+    /// Disables the block gas accounting
+    pub fn disable_block_gas_limit(&self) {
+        *self.block_gas.borrow_mut() = None;
+    }
+
+    /// Returns the estimated gas used in the current block, if the block gas accounting is enabled
+    pub fn block_gas_used(&self) -> Option<u64> {
+        let height = self.app.borrow().block_info().height;
+        self.block_gas.borrow().as_ref().map(|block_gas| {
+            if block_gas.height == height {
+                block_gas.used
+            } else {
+                0
+            }
+        })
+    }
+
+    /// Charges `gas` to the current block, for operations that are not covered by the size based estimation.
+    /// Errors if the block gas limit is exceeded.
+    pub fn consume_block_gas(&self, gas: u64) -> Result<(), CwEnvError> {
+        self.use_block_gas(gas, true)
+    }
+
+    /// Checks `gas` against the limit of the current block, or charges it when `consume` is set
+    fn use_block_gas(&self, gas: u64, consume: bool) -> Result<(), CwEnvError> {
+        let height = self.app.borrow().block_info().height;
+        let mut block_gas = self.block_gas.borrow_mut();
+        let Some(block_gas) = block_gas.as_mut() else {
+            return Ok(());
+        };
+        if block_gas.height != height {
+            block_gas.height = height;
+            block_gas.used = 0;
+        }
+        let used = block_gas.used + gas;
+        if used > block_gas.limit {
+            return Err(CwEnvError::StdErr(format!(
+                "Block gas limit exceeded at height {height}: {used} gas used out of {}",
+                block_gas.limit
+            )));
+        }
+        if consume {
+            block_gas.used = used;
+        }
+        Ok(())
+    }
+
+    /// Estimated gas of a transaction carrying `msg`, erroring if it doesn't fit in the current block.
+    /// It's charged with [`MockBase::consume_block_gas`] once the transaction succeeded.
+    pub(crate) fn block_gas_of(&self, msg: &impl Serialize) -> Result<u64, CwEnvError> {
+        if self.block_gas.borrow().is_none() {
+            return Ok(0);
+        }
+        self.block_gas_of_len(to_json_vec(msg)?.len())
+    }
+
+    /// Estimated gas of a transaction carrying `msg_len` bytes of messages, erroring if it doesn't fit in the current block
+    pub(crate) fn block_gas_of_len(&self, msg_len: usize) -> Result<u64, CwEnvError> {
+        let Some(block_gas) = self.block_gas.borrow().clone() else {
+            return Ok(0);
+        };
+        let gas = block_gas.tx_gas(msg_len);
+        self.use_block_gas(gas, false)?;
+        Ok(gas)
+    }
+}
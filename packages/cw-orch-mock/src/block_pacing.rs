@@ -0,0 +1,109 @@
+//! Block-by-block execution for the mock environment.
+//!
+//! By default, all the transactions sent to the mock chain are executed in the same block, until the block is moved manually.
+//! Once enabled with [`MockBase::set_block_pacing`], the chain moves to the next block on its own after every `txs_per_block` transactions,
+//! advancing the height and the time by the configured increments, like a real chain would while the transactions are being sent.
+//! This exercises contracts whose logic depends on the block height or time (vesting, auctions, ...) without calling `next_block` everywhere.
+//!
+//! Combined with [`MockBase::set_block_gas_limit`](crate::MockBase::set_block_gas_limit), the gas limit applies to the transactions of each block.
+use cosmwasm_std::Api;
+use cw_orch_core::environment::StateInterface;
+
+use crate::MockBase;
+
+/// Default time between two blocks, in seconds
+pub const DEFAULT_BLOCK_TIME_SECONDS: u64 = 5;
+
+/// Pace at which the mock chain moves to the next block
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlockPacing {
+    txs_per_block: u64,
+    height_increment: u64,
+    time_increment_seconds: u64,
+    txs_in_block: u64,
+    height: u64,
+}
+
+impl BlockPacing {
+    /// One transaction per block, each block [`DEFAULT_BLOCK_TIME_SECONDS`] after the previous one
+    pub fn new() -> Self {
+        Self {
+            txs_per_block: 1,
+            height_increment: 1,
+            time_increment_seconds: DEFAULT_BLOCK_TIME_SECONDS,
+            txs_in_block: 0,
+            height: 0,
+        }
+    }
+
+    /// Sets the number of transactions included in each block
+    pub fn txs_per_block(mut self, txs: u64) -> Self {
+        self.txs_per_block = txs.max(1);
+        self
+    }
+
+    /// Sets the number of blocks the height advances by when moving to the next block
+    pub fn height_increment(mut self, blocks: u64) -> Self {
+        self.height_increment = blocks;
+        self
+    }
+
+    /// Sets the number of seconds the time advances by when moving to the next block
+    pub fn time_increment_seconds(mut self, seconds: u64) -> Self {
+        self.time_increment_seconds = seconds;
+        self
+    }
+}
+
+impl Default for BlockPacing {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<A: Api, S: StateInterface> MockBase<A, S> {
+    /// Enables the block-by-block execution. The current block is considered empty.
+    pub fn set_block_pacing(&self, block_pacing: BlockPacing) {
+        let height = self.app.borrow().block_info().height;
+        *self.block_pacing.borrow_mut() = Some(BlockPacing {
+            txs_in_block: 0,
+            height,
+            ..block_pacing
+        });
+    }
+
+    /// Disables the block-by-block execution, the transactions are executed in the current block again
+    pub fn disable_block_pacing(&self) {
+        *self.block_pacing.borrow_mut() = None;
+    }
+
+    /// Moves to the next block if the current one is full, before sending a transaction
+    pub(crate) fn pace_block(&self) {
+        let mut block_pacing = self.block_pacing.borrow_mut();
+        let Some(block_pacing) = block_pacing.as_mut() else {
+            return;
+        };
+        let mut app = self.app.borrow_mut();
+        // The block may have been moved manually since the last transaction
+        let height = app.block_info().height;
+        if block_pacing.height != height {
+            block_pacing.height = height;
+            block_pacing.txs_in_block = 0;
+        }
+        if block_pacing.txs_in_block >= block_pacing.txs_per_block {
+            app.update_block(|block| {
+                block.height += block_pacing.height_increment;
+                block.time = block.time.plus_seconds(block_pacing.time_increment_seconds);
+            });
+            block_pacing.height = app.block_info().height;
+            block_pacing.txs_in_block = 0;
+        }
+    }
+
+    /// Counts a successful transaction in the current block, failed transactions don't fill the block
+    pub(crate) fn count_block_tx(&self) {
+        if let Some(block_pacing) = self.block_pacing.borrow_mut().as_mut() {
+            block_pacing.txs_in_block += 1;
+        }
+    }
+}
@@ -10,11 +10,18 @@ use cw_multi_test::{
 };
 use serde::Serialize;
 
-use super::state::MockState;
+use super::{
+    block_gas::BlockGasLimit, block_pacing::BlockPacing, invariants::BankInvariants,
+    stargate::StargateHandlers, state::MockState,
+};
 use cw_orch_core::{
     contract::interface_traits::Uploadable,
-    environment::{AccessConfig, ChainState, IndexResponse, StateInterface, TxHandler},
-    CwEnvError,
+    environment::{
+        AccessConfig, ChainState, IndexResponse, InstantiateHooks, InstantiatedContract,
+        StateInterface, TxContext, TxHandler, TxHooks, TxKind,
+    },
+    log::transaction_target,
+    CoreEnvVars, CwEnvError,
 };
 
 pub type MockApp<A = MockApi> = App<
@@ -74,6 +81,20 @@ pub struct MockBase<A: Api = MockApi, S: StateInterface = MockState> {
     pub state: Rc<RefCell<S>>,
     /// Inner mutable cw-multi-test app backend
     pub app: Rc<RefCell<MockApp<A>>>,
+    /// Bank invariants checked after each transaction, if enabled
+    pub(crate) bank_invariants: Rc<RefCell<Option<BankInvariants>>>,
+    /// Hooks fired after each instantiation
+    pub(crate) instantiate_hooks: Rc<RefCell<InstantiateHooks>>,
+    /// Hooks fired around each transaction
+    pub(crate) tx_hooks: Rc<RefCell<TxHooks>>,
+    /// Gas limit of the blocks, if enabled
+    pub(crate) block_gas: Rc<RefCell<Option<BlockGasLimit>>>,
+    /// Pace of the block-by-block execution, if enabled
+    pub(crate) block_pacing: Rc<RefCell<Option<BlockPacing>>>,
+    /// Handlers of the protobuf messages, by type url
+    pub(crate) stargate_handlers: Rc<RefCell<StargateHandlers<A, S>>>,
+    /// Contracts instantiated in the mock, oldest first, to find them by label
+    pub(crate) instantiated: Rc<RefCell<Vec<Addr>>>,
 }
 
 pub type Mock<S = MockState> = MockBase<MockApi, S>;
@@ -85,6 +106,13 @@ impl<A: Api, S: StateInterface> Clone for MockBase<A, S> {
             sender: self.sender.clone(),
             state: self.state.clone(),
             app: self.app.clone(),
+            bank_invariants: self.bank_invariants.clone(),
+            instantiate_hooks: self.instantiate_hooks.clone(),
+            tx_hooks: self.tx_hooks.clone(),
+            block_gas: self.block_gas.clone(),
+            block_pacing: self.block_pacing.clone(),
+            stargate_handlers: self.stargate_handlers.clone(),
+            instantiated: self.instantiated.clone(),
         }
     }
 }
@@ -121,7 +149,81 @@ impl<A: Api, S: StateInterface> MockBase<A, S> {
         self.state.borrow_mut().set_code_id(contract_id, code_id);
         Ok(resp)
     }
+
+    /// Registers a hook fired after every instantiation, to register the contracts it created in the state.
+    /// See [`InstantiateHooks`] for an example.
+    pub fn add_instantiate_hook(
+        &mut self,
+        hook: impl Fn(&[InstantiatedContract]) -> Vec<(String, Addr)> + Send + Sync + 'static,
+    ) {
+        self.instantiate_hooks.borrow_mut().add(hook);
+    }
+
+    /// Registers a hook run before every transaction. Returning an error aborts the transaction.
+    /// See [`TxHooks`] for an example.
+    pub fn add_pre_broadcast_hook(
+        &mut self,
+        hook: impl Fn(&TxContext) -> Result<(), CwEnvError> + Send + Sync + 'static,
+    ) {
+        self.tx_hooks.borrow_mut().add_pre_broadcast(hook);
+    }
+
+    /// Registers a hook run after every successful transaction, with its events.
+    /// See [`TxHooks`] for an example.
+    pub fn add_post_response_hook(
+        &mut self,
+        hook: impl Fn(&TxContext) -> Result<(), CwEnvError> + Send + Sync + 'static,
+    ) {
+        self.tx_hooks.borrow_mut().add_post_response(hook);
+    }
+
+    /// Writes `value` at `key` in the storage of a contract, without going through its entry points.
+    /// Used to inject fixtures or to corrupt the state of a contract before testing its migration.
+    pub fn set_contract_storage(&self, contract_address: &Addr, key: &[u8], value: &[u8]) {
+        self.app
+            .borrow_mut()
+            .contract_storage_mut(contract_address)
+            .set(key, value);
+    }
+
+    /// Removes `key` from the storage of a contract, without going through its entry points.
+    pub fn remove_contract_storage(&self, contract_address: &Addr, key: &[u8]) {
+        self.app
+            .borrow_mut()
+            .contract_storage_mut(contract_address)
+            .remove(key);
+    }
+
+    /// Reads the raw value stored at `key` in the storage of a contract.
+    pub fn read_contract_storage(&self, contract_address: &Addr, key: &[u8]) -> Option<Vec<u8>> {
+        self.app
+            .borrow()
+            .contract_storage(contract_address)
+            .get(key)
+    }
+
+    pub(crate) fn tx_context(&self, kind: TxKind) -> TxContext {
+        let chain_id = self.app.borrow().block_info().chain_id;
+        TxContext::new(chain_id, self.sender_addr(), kind)
+    }
+
+    /// Records the contracts instantiated by a transaction, including the ones instantiated by contracts
+    fn record_instantiated(&self, response: &AppResponse) {
+        self.instantiated.borrow_mut().extend(
+            InstantiatedContract::from_response(response)
+                .into_iter()
+                .map(|contract| contract.address),
+        );
+    }
 }
+/// Labels mock transactions when running with `CW_ORCH_DRY_RUN`.
+/// The mock chain lives in memory, so transactions are still executed.
+fn log_dry_run(action: &str) {
+    if CoreEnvVars::dry_run() {
+        log::info!(target: &transaction_target(), "[DRY RUN][Mock] {action}");
+    }
+}
+
 impl<A: Api, S: StateInterface> ChainState for MockBase<A, S> {
     type Out = Rc<RefCell<S>>;
 
@@ -150,10 +252,17 @@ impl<A: Api, S: StateInterface> TxHandler for MockBase<A, S> {
     }
 
     fn upload<T: Uploadable>(&self, _contract: &T) -> Result<Self::Response, CwEnvError> {
+        log_dry_run("Upload");
+        let context = self.tx_context(TxKind::Upload);
+        self.tx_hooks.borrow().pre_broadcast(&context)?;
+        self.pace_block();
+        let gas = self.block_gas_of(&Empty {})?;
         let code_id = self
             .app
             .borrow_mut()
             .store_code_with_creator(self.sender_addr(), T::wrapper());
+        self.consume_block_gas(gas)?;
+        self.count_block_tx();
         // add contract code_id to events manually
         let mut event = Event::new("store_code");
         event = event.add_attribute("code_id", code_id.to_string());
@@ -161,6 +270,7 @@ impl<A: Api, S: StateInterface> TxHandler for MockBase<A, S> {
             events: vec![event],
             ..Default::default()
         };
+        self.tx_hooks.borrow().post_response(&context, &resp)?;
         Ok(resp)
     }
 
@@ -170,15 +280,27 @@ impl<A: Api, S: StateInterface> TxHandler for MockBase<A, S> {
         coins: &[cosmwasm_std::Coin],
         contract_address: &Addr,
     ) -> Result<Self::Response, CwEnvError> {
-        self.app
-            .borrow_mut()
-            .execute_contract(
-                self.sender.clone(),
-                contract_address.to_owned(),
-                exec_msg,
-                coins,
-            )
-            .map_err(From::from)
+        log_dry_run("Execute");
+        let context = self
+            .tx_context(TxKind::Execute)
+            .contract(contract_address)
+            .msg(exec_msg)
+            .funds(coins);
+        self.tx_hooks.borrow().pre_broadcast(&context)?;
+        self.pace_block();
+        let gas = self.block_gas_of(exec_msg)?;
+        let resp = self.app.borrow_mut().execute_contract(
+            self.sender.clone(),
+            contract_address.to_owned(),
+            exec_msg,
+            coins,
+        )?;
+        self.consume_block_gas(gas)?;
+        self.count_block_tx();
+        self.check_bank_invariants()?;
+        self.record_instantiated(&resp);
+        self.tx_hooks.borrow().post_response(&context, &resp)?;
+        Ok(resp)
     }
 
     fn instantiate<I: Serialize + Debug>(
@@ -189,6 +311,14 @@ impl<A: Api, S: StateInterface> TxHandler for MockBase<A, S> {
         admin: Option<&Addr>,
         coins: &[cosmwasm_std::Coin],
     ) -> Result<Self::Response, CwEnvError> {
+        log_dry_run("Instantiate");
+        let context = self
+            .tx_context(TxKind::Instantiate { code_id })
+            .msg(init_msg)
+            .funds(coins);
+        self.tx_hooks.borrow().pre_broadcast(&context)?;
+        self.pace_block();
+        let gas = self.block_gas_of(init_msg)?;
         let msg = WasmMsg::Instantiate {
             admin: admin.map(|a| a.to_string()),
             code_id,
@@ -205,6 +335,14 @@ impl<A: Api, S: StateInterface> TxHandler for MockBase<A, S> {
             events: app.events,
             data: app.data,
         };
+        self.consume_block_gas(gas)?;
+        self.count_block_tx();
+        self.check_bank_invariants()?;
+        self.record_instantiated(&resp);
+        self.instantiate_hooks
+            .borrow()
+            .apply(&resp, &mut *self.state.borrow_mut());
+        self.tx_hooks.borrow().post_response(&context, &resp)?;
         Ok(resp)
     }
 
@@ -217,6 +355,14 @@ impl<A: Api, S: StateInterface> TxHandler for MockBase<A, S> {
         coins: &[cosmwasm_std::Coin],
         salt: Binary,
     ) -> Result<Self::Response, CwEnvError> {
+        log_dry_run("Instantiate2");
+        let context = self
+            .tx_context(TxKind::Instantiate2 { code_id })
+            .msg(init_msg)
+            .funds(coins);
+        self.tx_hooks.borrow().pre_broadcast(&context)?;
+        self.pace_block();
+        let gas = self.block_gas_of(init_msg)?;
         let msg = WasmMsg::Instantiate2 {
             admin: admin.map(|a| a.to_string()),
             code_id,
@@ -235,6 +381,14 @@ impl<A: Api, S: StateInterface> TxHandler for MockBase<A, S> {
             events: app.events,
             data: app.data,
         };
+        self.consume_block_gas(gas)?;
+        self.count_block_tx();
+        self.check_bank_invariants()?;
+        self.record_instantiated(&resp);
+        self.instantiate_hooks
+            .borrow()
+            .apply(&resp, &mut *self.state.borrow_mut());
+        self.tx_hooks.borrow().post_response(&context, &resp)?;
         Ok(resp)
     }
 
@@ -244,15 +398,26 @@ impl<A: Api, S: StateInterface> TxHandler for MockBase<A, S> {
         new_code_id: u64,
         contract_address: &Addr,
     ) -> Result<Self::Response, CwEnvError> {
-        self.app
-            .borrow_mut()
-            .migrate_contract(
-                self.sender.clone(),
-                contract_address.clone(),
-                migrate_msg,
-                new_code_id,
-            )
-            .map_err(From::from)
+        log_dry_run("Migrate");
+        let context = self
+            .tx_context(TxKind::Migrate { new_code_id })
+            .contract(contract_address)
+            .msg(migrate_msg);
+        self.tx_hooks.borrow().pre_broadcast(&context)?;
+        self.pace_block();
+        let gas = self.block_gas_of(migrate_msg)?;
+        let resp = self.app.borrow_mut().migrate_contract(
+            self.sender.clone(),
+            contract_address.clone(),
+            migrate_msg,
+            new_code_id,
+        )?;
+        self.consume_block_gas(gas)?;
+        self.count_block_tx();
+        self.check_bank_invariants()?;
+        self.record_instantiated(&resp);
+        self.tx_hooks.borrow().post_response(&context, &resp)?;
+        Ok(resp)
     }
 
     fn upload_with_access_config<T: Uploadable>(
@@ -269,17 +434,93 @@ impl<A: Api, S: StateInterface> TxHandler for MockBase<A, S> {
         receiver: &Addr,
         amount: &[cosmwasm_std::Coin],
     ) -> Result<Self::Response, Self::Error> {
-        self.app
+        log_dry_run("Bank send");
+        let context = self
+            .tx_context(TxKind::BankSend)
+            .contract(receiver)
+            .funds(amount);
+        self.tx_hooks.borrow().pre_broadcast(&context)?;
+        self.pace_block();
+        let gas = self.block_gas_of(&amount)?;
+        let resp = self.app.borrow_mut().execute(
+            self.sender.clone(),
+            BankMsg::Send {
+                to_address: receiver.to_string(),
+                amount: amount.to_vec(),
+            }
+            .into(),
+        )?;
+        self.consume_block_gas(gas)?;
+        self.count_block_tx();
+        self.check_bank_invariants()?;
+        self.tx_hooks.borrow().post_response(&context, &resp)?;
+        Ok(resp)
+    }
+
+    fn update_admin(
+        &self,
+        contract_address: &Addr,
+        new_admin: &Addr,
+    ) -> Result<Self::Response, Self::Error> {
+        log_dry_run("Update admin");
+        let context = self
+            .tx_context(TxKind::UpdateAdmin)
+            .contract(contract_address);
+        self.tx_hooks.borrow().pre_broadcast(&context)?;
+        let msg = CosmosMsg::Wasm(WasmMsg::UpdateAdmin {
+            contract_addr: contract_address.to_string(),
+            admin: new_admin.to_string(),
+        });
+        self.pace_block();
+        let gas = self.block_gas_of(&msg)?;
+        let resp = self.app.borrow_mut().execute(self.sender.clone(), msg)?;
+        self.consume_block_gas(gas)?;
+        self.count_block_tx();
+        self.tx_hooks.borrow().post_response(&context, &resp)?;
+        Ok(resp)
+    }
+
+    fn clear_admin(&self, contract_address: &Addr) -> Result<Self::Response, Self::Error> {
+        log_dry_run("Clear admin");
+        let context = self
+            .tx_context(TxKind::ClearAdmin)
+            .contract(contract_address);
+        self.tx_hooks.borrow().pre_broadcast(&context)?;
+        let msg = CosmosMsg::Wasm(WasmMsg::ClearAdmin {
+            contract_addr: contract_address.to_string(),
+        });
+        self.pace_block();
+        let gas = self.block_gas_of(&msg)?;
+        let resp = self.app.borrow_mut().execute(self.sender.clone(), msg)?;
+        self.consume_block_gas(gas)?;
+        self.count_block_tx();
+        self.tx_hooks.borrow().post_response(&context, &resp)?;
+        Ok(resp)
+    }
+
+    fn sudo<S: Serialize + Debug>(
+        &self,
+        sudo_msg: &S,
+        contract_address: &Addr,
+    ) -> Result<Self::Response, Self::Error> {
+        log_dry_run("Sudo");
+        let context = self
+            .tx_context(TxKind::Sudo)
+            .contract(contract_address)
+            .msg(sudo_msg);
+        self.tx_hooks.borrow().pre_broadcast(&context)?;
+        self.pace_block();
+        let gas = self.block_gas_of(sudo_msg)?;
+        let resp = self
+            .app
             .borrow_mut()
-            .execute(
-                self.sender.clone(),
-                BankMsg::Send {
-                    to_address: receiver.to_string(),
-                    amount: amount.to_vec(),
-                }
-                .into(),
-            )
-            .map_err(From::from)
+            .wasm_sudo(contract_address.clone(), sudo_msg)?;
+        self.consume_block_gas(gas)?;
+        self.count_block_tx();
+        self.check_bank_invariants()?;
+        self.record_instantiated(&resp);
+        self.tx_hooks.borrow().post_response(&context, &resp)?;
+        Ok(resp)
     }
 }
 
@@ -491,4 +732,114 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn instantiate_hooks() -> Result<(), CwEnvError> {
+        let mut chain = Mock::new(SENDER);
+        chain.add_instantiate_hook(|contracts| {
+            contracts
+                .iter()
+                .filter(|contract| contract.code_id == Some(1))
+                .map(|contract| ("token".to_string(), contract.address.clone()))
+                .collect()
+        });
+
+        let contract_source = Box::new(ContractWrapper::new(
+            execute,
+            cw20_base::contract::instantiate,
+            query,
+        ));
+        chain.upload_custom("cw20", contract_source)?;
+        let init_msg = cw20_base::msg::InstantiateMsg {
+            name: String::from("Token"),
+            symbol: String::from("TOK"),
+            decimals: 6u8,
+            initial_balances: vec![],
+            mint: None,
+            marketing: None,
+        };
+        let init_res = chain.instantiate(1, &init_msg, None, None, &[])?;
+
+        assert_eq!(
+            chain.state().get_address("token")?,
+            init_res.instantiated_contract_address()?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn tx_hooks() -> Result<(), CwEnvError> {
+        let mut chain = Mock::new(SENDER);
+        let recipient = chain.addr_make(BALANCE_ADDR);
+        chain.set_balance(&chain.sender_addr(), coins(1000, "ujuno"))?;
+
+        let responses = std::sync::Arc::new(std::sync::Mutex::new(vec![]));
+        let log = responses.clone();
+        chain.add_pre_broadcast_hook(|context| match context.kind {
+            TxKind::BankSend if context.funds[0].amount > Uint128::new(100) => {
+                Err(CwEnvError::StdErr("Spending cap exceeded".to_string()))
+            }
+            _ => Ok(()),
+        });
+        chain.add_post_response_hook(move |context| {
+            log.lock().unwrap().push(context.clone());
+            Ok(())
+        });
+
+        chain.bank_send(&recipient, &coins(100, "ujuno"))?;
+        assert!(chain.bank_send(&recipient, &coins(500, "ujuno")).is_err());
+        assert_eq!(chain.query_balance(&recipient, "ujuno")?.u128(), 100);
+
+        let responses = responses.lock().unwrap();
+        assert_eq!(responses.len(), 1);
+        assert_eq!(responses[0].kind, TxKind::BankSend);
+        assert_eq!(responses[0].contract, Some(recipient));
+        assert!(!responses[0].events.as_ref().unwrap().is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn admin_tx_hooks() -> Result<(), CwEnvError> {
+        let mut chain = Mock::new(SENDER);
+        let kinds = std::sync::Arc::new(std::sync::Mutex::new(vec![]));
+        let log = kinds.clone();
+        chain.add_post_response_hook(move |context| {
+            log.lock().unwrap().push(context.kind.clone());
+            Ok(())
+        });
+
+        let contract_source = Box::new(ContractWrapper::new(
+            execute,
+            cw20_base::contract::instantiate,
+            query,
+        ));
+        chain.upload_custom("cw20", contract_source)?;
+        let init_msg = cw20_base::msg::InstantiateMsg {
+            name: String::from("Token"),
+            symbol: String::from("TOK"),
+            decimals: 6u8,
+            initial_balances: vec![],
+            mint: None,
+            marketing: None,
+        };
+        let sender = chain.sender_addr();
+        let contract = chain
+            .instantiate(1, &init_msg, None, Some(&sender), &[])?
+            .instantiated_contract_address()?;
+
+        chain.update_admin(&contract, &chain.addr_make("new_admin"))?;
+        chain
+            .call_as(&chain.addr_make("new_admin"))
+            .clear_admin(&contract)?;
+
+        assert_eq!(
+            *kinds.lock().unwrap(),
+            vec![
+                TxKind::Instantiate { code_id: 1 },
+                TxKind::UpdateAdmin,
+                TxKind::ClearAdmin
+            ]
+        );
+        Ok(())
+    }
 }
@@ -0,0 +1,81 @@
+//! Bank invariant checks for the mock environment.
+//!
+//! Once enabled with [`MockBase::enable_bank_invariants`], the total supply of the tracked denoms is verified after every transaction.
+//! Supply can only be created with the mock balance helpers (`set_balance`, `add_balance`, ...).
+//! A transaction that increases the supply of a tracked denom (for instance a custom module minting tokens by mistake) fails loudly.
+//! Supply decreases are accepted, because burns are not reported in the transaction events.
+use std::collections::BTreeMap;
+
+use cosmwasm_std::{Api, Uint128};
+use cw_orch_core::{environment::StateInterface, CwEnvError};
+
+use crate::MockBase;
+
+/// Expected total supply of the tracked denoms
+#[derive(Debug, Clone, Default)]
+pub struct BankInvariants {
+    supply: BTreeMap<String, Uint128>,
+}
+
+impl<A: Api, S: StateInterface> MockBase<A, S> {
+    /// Enables the bank invariant checks for the given denoms.
+    /// Denoms set with the mock balance helpers afterwards are tracked automatically.
+    pub fn enable_bank_invariants(
+        &self,
+        denoms: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Result<(), CwEnvError> {
+        let mut invariants = BankInvariants::default();
+        for denom in denoms {
+            let denom = denom.into();
+            let supply = self.total_supply(&denom)?;
+            invariants.supply.insert(denom, supply);
+        }
+        *self.bank_invariants.borrow_mut() = Some(invariants);
+        Ok(())
+    }
+
+    /// Disables the bank invariant checks
+    pub fn disable_bank_invariants(&self) {
+        *self.bank_invariants.borrow_mut() = None;
+    }
+
+    /// Verifies that the supply of the tracked denoms didn't increase since the last check.
+    /// This is called after every transaction when the invariants are enabled.
+    pub fn check_bank_invariants(&self) -> Result<(), CwEnvError> {
+        let Some(mut invariants) = self.bank_invariants.borrow().clone() else {
+            return Ok(());
+        };
+        for (denom, expected) in invariants.supply.iter_mut() {
+            let current = self.total_supply(denom)?;
+            if current > *expected {
+                return Err(CwEnvError::StdErr(format!(
+                    "Bank invariant violated: total supply of {denom} went from {expected} to {current} during a transaction"
+                )));
+            }
+            *expected = current;
+        }
+        *self.bank_invariants.borrow_mut() = Some(invariants);
+        Ok(())
+    }
+
+    /// Updates the expected supply after balances were changed with the mock helpers
+    pub(crate) fn refresh_bank_invariants<'a>(
+        &self,
+        denoms: impl IntoIterator<Item = &'a str>,
+    ) -> Result<(), CwEnvError> {
+        let Some(mut invariants) = self.bank_invariants.borrow().clone() else {
+            return Ok(());
+        };
+        for denom in denoms {
+            invariants
+                .supply
+                .insert(denom.to_string(), self.total_supply(denom)?);
+        }
+        *self.bank_invariants.borrow_mut() = Some(invariants);
+        Ok(())
+    }
+
+    fn total_supply(&self, denom: &str) -> Result<Uint128, CwEnvError> {
+        Ok(self.app.borrow().wrap().query_supply(denom)?.amount)
+    }
+}
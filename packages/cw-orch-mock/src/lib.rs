@@ -5,12 +5,20 @@
 pub extern crate cw_multi_test;
 
 mod bech32;
+mod block_gas;
+mod block_pacing;
 mod core;
+mod invariants;
 pub mod queriers;
 mod simple;
+mod stargate;
 mod state;
 
 pub use self::core::{Mock, MockBase, MockBech32};
+pub use block_gas::BlockGasLimit;
+pub use block_pacing::BlockPacing;
+pub use invariants::BankInvariants;
+pub use stargate::StargateHandler;
 
 pub type MockApp = self::core::MockApp<MockApi>;
 pub type MockAppBech32 = self::core::MockApp<MockApiBech32>;
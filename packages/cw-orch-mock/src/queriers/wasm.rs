@@ -17,6 +17,7 @@ use crate::{core::MockApp, MockBase};
 
 pub struct MockWasmQuerier<A: Api, S: StateInterface> {
     app: Rc<RefCell<MockApp<A>>>,
+    instantiated: Rc<RefCell<Vec<Addr>>>,
     _state: PhantomData<S>,
 }
 
@@ -24,6 +25,7 @@ impl<A: Api, S: StateInterface> MockWasmQuerier<A, S> {
     fn new(mock: &MockBase<A, S>) -> Self {
         Self {
             app: mock.app.clone(),
+            instantiated: mock.instantiated.clone(),
             _state: PhantomData,
         }
     }
@@ -141,6 +143,21 @@ impl<A: Api, S: StateInterface> WasmQuerier for MockWasmQuerier<A, S> {
         contract_info(self, address)
     }
 
+    /// Returns the latest contract instantiated by `creator` with `label`
+    fn contract_by_label(&self, creator: &Addr, label: &str) -> Result<Option<Addr>, CwEnvError> {
+        let app = self.app.borrow();
+        Ok(self
+            .instantiated
+            .borrow()
+            .iter()
+            .rev()
+            .find(|address| {
+                app.contract_data(address)
+                    .is_ok_and(|data| &data.creator == creator && data.label == label)
+            })
+            .cloned())
+    }
+
     fn local_hash<T: Uploadable + ContractInstance<Self::Chain>>(
         &self,
         contract: &T,
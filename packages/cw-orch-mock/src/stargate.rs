@@ -0,0 +1,142 @@
+//! Protobuf messages for the mock environment.
+//!
+//! cw-multi-test doesn't know the Cosmos SDK modules, so the messages sent with [`Stargate::commit_any`] are executed
+//! by the handlers registered for their type url with [`MockBase::register_stargate_handler`].
+//! This lets scripts that send module messages not wrapped by cw-orch run against the mock chain too.
+//!
+//! ```
+//! use cosmwasm_std::coins;
+//! use cw_orch_core::environment::TxHandler;
+//! use cw_orch_mock::{cw_multi_test::AppResponse, Mock};
+//! use cw_orch_traits::Stargate;
+//! use prost_types::Any;
+//!
+//! let mock = Mock::new("sender");
+//! // Fake token factory: any create denom message credits the sender
+//! mock.register_stargate_handler(
+//!     "/osmosis.tokenfactory.v1beta1.MsgCreateDenom",
+//!     |chain, _value| {
+//!         chain.add_balance(&chain.sender_addr(), coins(1, "factory/denom"))?;
+//!         Ok(AppResponse::default())
+//!     },
+//! );
+//! mock.commit_any(
+//!     vec![Any {
+//!         type_url: "/osmosis.tokenfactory.v1beta1.MsgCreateDenom".to_string(),
+//!         value: vec![],
+//!     }],
+//!     None,
+//! )
+//! .unwrap();
+//! ```
+use std::{collections::HashMap, rc::Rc};
+
+use cosmwasm_std::{Api, Order, Record, Storage};
+use cw_multi_test::AppResponse;
+use cw_orch_core::{
+    environment::{StateInterface, TxHandler, TxKind},
+    CwEnvError,
+};
+use cw_orch_traits::Stargate;
+use prost_types::Any;
+
+use crate::MockBase;
+
+/// Executes the value of a protobuf message on the mock chain, the sender being [`TxHandler::sender_addr`]
+pub type StargateHandler<A, S> =
+    Rc<dyn Fn(&MockBase<A, S>, &[u8]) -> Result<AppResponse, CwEnvError>>;
+
+/// Handlers registered by type url
+pub(crate) type StargateHandlers<A, S> = HashMap<String, StargateHandler<A, S>>;
+
+impl<A: Api, S: StateInterface> MockBase<A, S> {
+    /// Registers the handler executing the messages of type `type_url` sent with [`Stargate::commit_any`].
+    /// Replaces the handler previously registered for this type url.
+    pub fn register_stargate_handler(
+        &self,
+        type_url: impl Into<String>,
+        handler: impl Fn(&Self, &[u8]) -> Result<AppResponse, CwEnvError> + 'static,
+    ) {
+        self.stargate_handlers
+            .borrow_mut()
+            .insert(type_url.into(), Rc::new(handler));
+    }
+
+    /// Executes the messages in order with their registered handlers
+    fn execute_stargate_msgs(&self, msgs: Vec<Any>) -> Result<AppResponse, CwEnvError> {
+        let mut response = AppResponse::default();
+        for msg in msgs {
+            let handler = self
+                .stargate_handlers
+                .borrow()
+                .get(&msg.type_url)
+                .cloned()
+                .ok_or_else(|| {
+                    CwEnvError::StdErr(format!(
+                        "No stargate handler registered for {}",
+                        msg.type_url
+                    ))
+                })?;
+            let msg_response = handler(self, &msg.value)?;
+            response.events.extend(msg_response.events);
+            response.data = msg_response.data.or(response.data);
+        }
+        Ok(response)
+    }
+
+    /// Copy of the whole storage of the app, to roll back a failed batch of messages
+    fn storage_checkpoint(&self) -> Vec<Record> {
+        self.app
+            .borrow()
+            .storage()
+            .range(None, None, Order::Ascending)
+            .collect()
+    }
+
+    /// Restores the storage of the app to a checkpoint
+    fn rollback_storage(&self, checkpoint: Vec<Record>) {
+        let mut app = self.app.borrow_mut();
+        let storage = app.storage_mut();
+        let keys: Vec<Vec<u8>> = storage.range_keys(None, None, Order::Ascending).collect();
+        for key in keys {
+            storage.remove(&key);
+        }
+        for (key, value) in checkpoint {
+            storage.set(&key, &value);
+        }
+    }
+}
+
+impl<A: Api, S: StateInterface> Stargate for MockBase<A, S> {
+    /// Executes the messages with their registered handlers, errors if a message has no handler.
+    /// As on a chain, the messages are atomic: if one fails, the storage changes of the previous ones are reverted.
+    fn commit_any(
+        &self,
+        msgs: Vec<Any>,
+        _memo: Option<&str>,
+    ) -> Result<<Self as TxHandler>::Response, <Self as TxHandler>::Error> {
+        let context = self.tx_context(TxKind::Stargate {
+            type_urls: msgs.iter().map(|msg| msg.type_url.clone()).collect(),
+        });
+        self.tx_hooks.borrow().pre_broadcast(&context)?;
+        self.pace_block();
+        let gas = self.block_gas_of_len(
+            msgs.iter()
+                .map(|msg| msg.type_url.len() + msg.value.len())
+                .sum(),
+        )?;
+        let checkpoint = self.storage_checkpoint();
+        let response = match self.execute_stargate_msgs(msgs) {
+            Ok(response) => response,
+            Err(err) => {
+                self.rollback_storage(checkpoint);
+                return Err(err);
+            }
+        };
+        self.consume_block_gas(gas)?;
+        self.count_block_tx();
+        self.check_bank_invariants()?;
+        self.tx_hooks.borrow().post_response(&context, &response)?;
+        Ok(response)
+    }
+}
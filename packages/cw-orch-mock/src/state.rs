@@ -10,6 +10,8 @@ pub struct MockState {
     pub code_ids: HashMap<String, u64>,
     /// Deployed contract addresses
     pub addresses: HashMap<String, Addr>,
+    /// Admins of the deployed contracts
+    pub admins: HashMap<String, Addr>,
     /// Chain id of the mocked chain
     pub chain_id: String,
 }
@@ -20,6 +22,7 @@ impl MockState {
         Self {
             addresses: HashMap::new(),
             code_ids: HashMap::new(),
+            admins: HashMap::new(),
             chain_id: mock_env().block.chain_id,
         }
     }
@@ -28,6 +31,7 @@ impl MockState {
         Self {
             addresses: HashMap::new(),
             code_ids: HashMap::new(),
+            admins: HashMap::new(),
             chain_id: chain_id.to_string(),
         }
     }
@@ -89,6 +93,17 @@ impl StateInterface for MockState {
     fn get_all_code_ids(&self) -> Result<HashMap<String, u64>, CwEnvError> {
         Ok(self.code_ids.clone())
     }
+
+    fn get_admin(&self, contract_id: &str) -> Option<Addr> {
+        self.admins.get(contract_id).cloned()
+    }
+
+    fn set_admin(&mut self, contract_id: &str, admin: Option<&Addr>) {
+        match admin {
+            Some(admin) => self.admins.insert(contract_id.to_string(), admin.clone()),
+            None => self.admins.remove(contract_id),
+        };
+    }
 }
 
 #[cfg(test)]
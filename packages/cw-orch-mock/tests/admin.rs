@@ -0,0 +1,45 @@
+use cosmwasm_std::{
+    to_json_binary, Binary, Deps, DepsMut, Empty, Env, MessageInfo, Response, StdResult,
+};
+use cw_orch_core::{
+    contract::Contract,
+    environment::{DefaultQueriers, TxHandler, WasmQuerier},
+};
+use cw_orch_mock::cw_multi_test::ContractWrapper;
+use cw_orch_mock::Mock;
+
+fn instantiate(_deps: DepsMut, _env: Env, _info: MessageInfo, _msg: Empty) -> StdResult<Response> {
+    Ok(Response::new())
+}
+
+fn query(_deps: Deps, _env: Env, _msg: Empty) -> StdResult<Binary> {
+    to_json_binary(&Empty {})
+}
+
+#[test]
+fn manages_admin() -> anyhow::Result<()> {
+    let chain = Mock::new("sender");
+    chain.upload_custom(
+        "contract",
+        Box::new(ContractWrapper::new(instantiate, instantiate, query)),
+    )?;
+    let contract = Contract::new("contract", chain.clone());
+    let sender = chain.sender_addr();
+    contract.instantiate(&Empty {}, Some(&sender), &[])?;
+    assert_eq!(contract.admin(), Some(sender));
+
+    let new_admin = chain.addr_make("new_admin");
+    contract.update_admin(&new_admin)?;
+    assert_eq!(contract.admin(), Some(new_admin.clone()));
+    let info = chain.wasm_querier().contract_info(&contract.address()?)?;
+    assert_eq!(info.admin, Some(new_admin.clone()));
+
+    // Only the admin can clear it
+    assert!(contract.clear_admin().is_err());
+    let as_admin = Contract::new("contract", chain.call_as(&new_admin));
+    as_admin.clear_admin()?;
+    assert_eq!(contract.admin(), None);
+    let info = chain.wasm_querier().contract_info(&contract.address()?)?;
+    assert_eq!(info.admin, None);
+    Ok(())
+}
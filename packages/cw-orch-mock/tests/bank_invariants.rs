@@ -0,0 +1,34 @@
+use cosmwasm_std::coins;
+use cw_orch_core::environment::TxHandler;
+use cw_orch_mock::MockBech32;
+
+#[test]
+fn transfers_keep_supply() -> anyhow::Result<()> {
+    let chain = MockBech32::new("mock");
+    chain.enable_bank_invariants(["ujuno"])?;
+
+    chain.set_balance(&chain.sender_addr(), coins(100, "ujuno"))?;
+    let receiver = chain.addr_make("receiver");
+    chain.bank_send(&receiver, &coins(40, "ujuno"))?;
+
+    assert_eq!(chain.query_balance(&receiver, "ujuno")?.u128(), 40);
+    Ok(())
+}
+
+#[test]
+fn detects_minted_supply() -> anyhow::Result<()> {
+    let chain = MockBech32::new("mock");
+    chain.set_balance(&chain.sender_addr(), coins(100, "ujuno"))?;
+    chain.enable_bank_invariants(["ujuno"])?;
+
+    // Tokens minted outside of the mock helpers, like a faulty custom module would
+    let minted = chain.addr_make("minted");
+    chain.app.borrow_mut().init_modules(|router, _, storage| {
+        router
+            .bank
+            .init_balance(storage, &minted, coins(1, "ujuno"))
+    })?;
+
+    assert!(chain.check_bank_invariants().is_err());
+    Ok(())
+}
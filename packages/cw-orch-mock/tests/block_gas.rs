@@ -0,0 +1,46 @@
+use cosmwasm_std::coins;
+use cw_orch_core::environment::{QueryHandler, TxHandler};
+use cw_orch_mock::{BlockGasLimit, MockBech32};
+
+#[test]
+fn block_gas_limit() -> anyhow::Result<()> {
+    let chain = MockBech32::new("mock");
+    chain.set_balance(&chain.sender_addr(), coins(100, "ujuno"))?;
+    let receiver = chain.addr_make("receiver");
+
+    // Room for two transfers per block
+    chain.set_block_gas_limit(BlockGasLimit::new(250_000).gas_per_byte(0));
+
+    chain.bank_send(&receiver, &coins(1, "ujuno"))?;
+    chain.bank_send(&receiver, &coins(1, "ujuno"))?;
+    assert_eq!(chain.block_gas_used(), Some(200_000));
+
+    // The third transfer doesn't fit and isn't executed
+    assert!(chain.bank_send(&receiver, &coins(1, "ujuno")).is_err());
+    assert_eq!(chain.query_balance(&receiver, "ujuno")?.u128(), 2);
+
+    // The gas used is reset in the next block
+    chain.next_block()?;
+    assert_eq!(chain.block_gas_used(), Some(0));
+    chain.bank_send(&receiver, &coins(1, "ujuno"))?;
+
+    chain.disable_block_gas_limit();
+    assert_eq!(chain.block_gas_used(), None);
+    Ok(())
+}
+
+#[test]
+fn failed_txs_dont_use_block_gas() -> anyhow::Result<()> {
+    let chain = MockBech32::new("mock");
+    chain.set_balance(&chain.sender_addr(), coins(1, "ujuno"))?;
+    let receiver = chain.addr_make("receiver");
+    chain.set_block_gas_limit(BlockGasLimit::new(250_000).gas_per_byte(0));
+
+    // Fails on the insufficient balance, after fitting in the block
+    assert!(chain.bank_send(&receiver, &coins(2, "ujuno")).is_err());
+    assert_eq!(chain.block_gas_used(), Some(0));
+
+    chain.bank_send(&receiver, &coins(1, "ujuno"))?;
+    assert_eq!(chain.block_gas_used(), Some(100_000));
+    Ok(())
+}
@@ -0,0 +1,72 @@
+use cosmwasm_std::coins;
+use cw_orch_core::environment::{QueryHandler, TxHandler};
+use cw_orch_mock::{BlockGasLimit, BlockPacing, MockBech32};
+
+#[test]
+fn block_pacing() -> anyhow::Result<()> {
+    let chain = MockBech32::new("mock");
+    chain.set_balance(&chain.sender_addr(), coins(100, "ujuno"))?;
+    let receiver = chain.addr_make("receiver");
+    let start = chain.block_info()?;
+
+    // Two transfers per block, blocks 6 seconds apart
+    chain.set_block_pacing(
+        BlockPacing::new()
+            .txs_per_block(2)
+            .time_increment_seconds(6),
+    );
+    for _ in 0..5 {
+        chain.bank_send(&receiver, &coins(1, "ujuno"))?;
+    }
+    let block = chain.block_info()?;
+    assert_eq!(block.height, start.height + 2);
+    assert_eq!(block.time, start.time.plus_seconds(12));
+
+    // A manual block move starts an empty block
+    chain.next_block()?;
+    let height = chain.block_info()?.height;
+    chain.bank_send(&receiver, &coins(1, "ujuno"))?;
+    chain.bank_send(&receiver, &coins(1, "ujuno"))?;
+    assert_eq!(chain.block_info()?.height, height);
+
+    chain.disable_block_pacing();
+    chain.bank_send(&receiver, &coins(1, "ujuno"))?;
+    assert_eq!(chain.block_info()?.height, height);
+    Ok(())
+}
+
+#[test]
+fn block_pacing_with_gas_limit() -> anyhow::Result<()> {
+    let chain = MockBech32::new("mock");
+    chain.set_balance(&chain.sender_addr(), coins(100, "ujuno"))?;
+    let receiver = chain.addr_make("receiver");
+
+    // The block only has room for one transfer, but each transfer gets its own block
+    chain.set_block_gas_limit(BlockGasLimit::new(150_000).gas_per_byte(0));
+    chain.set_block_pacing(BlockPacing::new());
+    for _ in 0..3 {
+        chain.bank_send(&receiver, &coins(1, "ujuno"))?;
+    }
+
+    // Two transfers per block exceed the limit
+    chain.next_block()?;
+    chain.set_block_pacing(BlockPacing::new().txs_per_block(2));
+    chain.bank_send(&receiver, &coins(1, "ujuno"))?;
+    assert!(chain.bank_send(&receiver, &coins(1, "ujuno")).is_err());
+    Ok(())
+}
+
+#[test]
+fn failed_txs_dont_fill_the_block() -> anyhow::Result<()> {
+    let chain = MockBech32::new("mock");
+    chain.set_balance(&chain.sender_addr(), coins(1, "ujuno"))?;
+    let receiver = chain.addr_make("receiver");
+    chain.set_block_pacing(BlockPacing::new());
+    let height = chain.block_info()?.height;
+
+    // Fails on the insufficient balance
+    assert!(chain.bank_send(&receiver, &coins(2, "ujuno")).is_err());
+    chain.bank_send(&receiver, &coins(1, "ujuno"))?;
+    assert_eq!(chain.block_info()?.height, height);
+    Ok(())
+}
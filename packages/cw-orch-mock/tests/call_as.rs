@@ -0,0 +1,69 @@
+use cosmwasm_std::coins;
+use cw_orch_core::contract::interface_traits::{
+    CallAs, ContractInstance, CwOrchExecute, CwOrchInstantiate, CwOrchUpload,
+};
+use cw_orch_core::environment::{BankQuerier, DefaultQueriers, TxHandler};
+use cw_orch_mock::MockBech32;
+use mock_contract::{ExecuteMsg, InstantiateMsg, MockContract};
+
+#[test]
+fn call_as_funded() -> anyhow::Result<()> {
+    let chain = MockBech32::new("mock");
+    let contract = MockContract::new("mock-contract", chain.clone());
+    contract.upload()?;
+    contract.instantiate(&InstantiateMsg {}, None, &[])?;
+
+    let user = chain.addr_make("user");
+    let funds = coins(100, "ujuno");
+    // The user has no funds yet
+    assert!(contract
+        .call_as(&user)
+        .execute(&ExecuteMsg::FifthMessage, &funds)
+        .is_err());
+
+    contract
+        .call_as_funded(&user, &funds)?
+        .execute(&ExecuteMsg::FifthMessage, &funds)?;
+    let bank = chain.bank_querier();
+    assert!(bank.balance(&user, Some("ujuno".to_string()))?[0]
+        .amount
+        .is_zero());
+    assert_eq!(
+        bank.balance(&contract.address()?, Some("ujuno".to_string()))?,
+        funds
+    );
+
+    // The chain can be funded in the same way
+    let other = chain.addr_make("other");
+    let as_other = chain.call_as_funded(&other, &funds)?;
+    assert_eq!(as_other.sender_addr(), other);
+    assert_eq!(bank.balance(&other, None)?, funds);
+    Ok(())
+}
+
+#[test]
+fn role_senders() -> anyhow::Result<()> {
+    let chain = MockBech32::new("mock");
+    let admin = chain.addr_make("admin");
+    let operator = chain.addr_make("operator");
+    let funds = coins(100, "ujuno");
+    chain.add_balance(&chain.sender_addr(), funds.clone())?;
+
+    let mut contract = MockContract::new("mock-contract", chain.clone());
+    contract.upload()?;
+    contract.instantiate(&InstantiateMsg {}, Some(&admin), &[])?;
+
+    // The chain sender is not the admin of the contract
+    assert!(contract.as_instance().update_admin(&operator).is_err());
+    contract.set_admin_sender(&admin);
+    contract.set_operator_sender(&operator);
+    contract.as_instance().update_admin(&admin)?;
+
+    // The operator has no funds, the executions are sent by the operator
+    assert!(contract.execute(&ExecuteMsg::FifthMessage, &funds).is_err());
+    // call_as takes precedence over the role senders
+    contract
+        .call_as(&chain.sender_addr())
+        .execute(&ExecuteMsg::FifthMessage, &funds)?;
+    Ok(())
+}
@@ -0,0 +1,29 @@
+use cosmwasm_std::coins;
+use cw_orch_core::{
+    conformance::{ConformanceContract, ConformanceSuite},
+    environment::TxHandler,
+    serde_json::json,
+};
+use cw_orch_mock::MockBech32;
+use mock_contract::MockContract;
+
+#[test]
+fn mock_conforms() -> anyhow::Result<()> {
+    let chain = MockBech32::new("mock");
+    chain.set_balance(&chain.sender_addr(), coins(1_000, "ujuno"))?;
+
+    ConformanceSuite::new(chain.clone())
+        .contract(ConformanceContract {
+            source: MockContract::new("mock-contract", chain.clone()),
+            instantiate_msg: json!({}),
+            execute_msg: json!({"first_message": {}}),
+            failing_execute_msg: Some(json!({"second_message": {"t": ""}})),
+            query_msg: json!({"first_query": {}}),
+            migrate_msg: Some(json!({"t": "success"})),
+        })
+        .bank_send(
+            chain.addr_make("receiver"),
+            cosmwasm_std::coin(100, "ujuno"),
+        )
+        .run()
+}
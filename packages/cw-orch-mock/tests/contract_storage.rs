@@ -0,0 +1,52 @@
+use cosmwasm_std::{
+    from_json, to_json_binary, to_json_vec, Binary, Deps, DepsMut, Empty, Env, MessageInfo,
+    Response, StdError, StdResult,
+};
+use cw_orch_core::contract::Contract;
+use cw_orch_mock::cw_multi_test::ContractWrapper;
+use cw_orch_mock::Mock;
+
+const COUNT: &[u8] = b"count";
+
+fn instantiate(deps: DepsMut, _env: Env, _info: MessageInfo, _msg: Empty) -> StdResult<Response> {
+    deps.storage.set(COUNT, &to_json_vec(&0u64)?);
+    Ok(Response::new())
+}
+
+fn query(deps: Deps, _env: Env, _msg: Empty) -> StdResult<Binary> {
+    let count: u64 = from_json(deps.storage.get(COUNT).unwrap_or_default())?;
+    to_json_binary(&count)
+}
+
+fn migrate(deps: DepsMut, _env: Env, _msg: Empty) -> StdResult<Response> {
+    deps.storage
+        .get(COUNT)
+        .ok_or_else(|| StdError::not_found("count"))?;
+    Ok(Response::new())
+}
+
+#[test]
+fn writes_contract_storage() -> anyhow::Result<()> {
+    let chain = Mock::new("sender");
+    chain.upload_custom(
+        "contract",
+        Box::new(ContractWrapper::new(instantiate, instantiate, query).with_migrate(migrate)),
+    )?;
+    let contract = Contract::new("contract", chain.clone());
+    contract.instantiate(&Empty {}, Some(&chain.sender_addr()), &[])?;
+    let address = contract.address()?;
+
+    assert_eq!(
+        chain.read_contract_storage(&address, COUNT),
+        Some(to_json_vec(&0u64)?)
+    );
+    chain.set_contract_storage(&address, COUNT, &to_json_vec(&42u64)?);
+    let count: u64 = contract.query(&Empty {})?;
+    assert_eq!(count, 42);
+
+    // The migration fails on a corrupted state
+    chain.remove_contract_storage(&address, COUNT);
+    assert_eq!(chain.read_contract_storage(&address, COUNT), None);
+    assert!(contract.migrate(&Empty {}, contract.code_id()?).is_err());
+    Ok(())
+}
@@ -0,0 +1,87 @@
+use cw_orch_core::{
+    contract::{
+        interface_traits::{ContractInstance, CwOrchInstantiate, CwOrchUpload},
+        rollback::IrreversibleAction,
+        Deploy, StateSnapshot,
+    },
+    environment::{ChainState, TxHandler},
+    CwEnvError,
+};
+use cw_orch_mock::MockBech32;
+use mock_contract::{InstantiateMsg, MockContract};
+
+struct MockApp<Chain> {
+    contract: MockContract<Chain>,
+}
+
+impl Deploy<MockBech32> for MockApp<MockBech32> {
+    type Error = CwEnvError;
+    /// Whether a step fails after the instantiation
+    type DeployData = bool;
+
+    fn store_on(chain: MockBech32) -> Result<Self, Self::Error> {
+        let contract = MockContract::new("mock-contract", chain);
+        contract.upload()?;
+        Ok(Self { contract })
+    }
+
+    fn deploy_on(chain: MockBech32, fail: bool) -> Result<Self, Self::Error> {
+        let app = Self::store_on(chain.clone())?;
+        app.contract
+            .instantiate(&InstantiateMsg {}, Some(&chain.sender_addr()), &[])?;
+        if fail {
+            return Err(CwEnvError::StdErr("configuration step failed".to_string()));
+        }
+        Ok(app)
+    }
+
+    fn get_contracts_mut(&mut self) -> Vec<Box<&mut dyn ContractInstance<MockBech32>>> {
+        vec![Box::new(&mut self.contract)]
+    }
+
+    fn load_from(chain: MockBech32) -> Result<Self, Self::Error> {
+        Ok(Self {
+            contract: MockContract::new("mock-contract", chain),
+        })
+    }
+}
+
+#[test]
+fn failed_deployment_restores_state() -> anyhow::Result<()> {
+    let chain = MockBech32::new("mock");
+    let app = MockApp::deploy_transactional(chain.clone(), false)?;
+    let address = app.contract.address()?;
+    let code_id = app.contract.code_id()?;
+
+    assert!(MockApp::deploy_transactional(chain.clone(), true).is_err());
+
+    let loaded = MockApp::load_from(chain)?;
+    assert_eq!(loaded.contract.address()?, address);
+    assert_eq!(loaded.contract.code_id()?, code_id);
+    Ok(())
+}
+
+#[test]
+fn rollback_reports_irreversible_actions() -> anyhow::Result<()> {
+    let chain = MockBech32::new("mock");
+    let snapshot = StateSnapshot::take(&chain.state())?;
+    let app = MockApp::deploy_on(chain.clone(), false)?;
+
+    let report = MockApp::rollback(&chain, &snapshot)?;
+    assert_eq!(report.restored, vec!["mock-contract".to_string()]);
+    assert_eq!(
+        report.irreversible,
+        vec![
+            IrreversibleAction::Upload {
+                contract_id: "mock-contract".to_string(),
+                code_id: app.contract.code_id()?,
+            },
+            IrreversibleAction::Instantiate {
+                contract_id: "mock-contract".to_string(),
+                address: app.contract.address()?,
+            },
+        ]
+    );
+    assert!(app.contract.address().is_err());
+    Ok(())
+}
@@ -0,0 +1,56 @@
+use cw_orch_core::{
+    contract::{
+        interface_traits::{ContractInstance, CwOrchInstantiate, CwOrchUpload},
+        Deploy,
+    },
+    environment::TxHandler,
+    CwEnvError,
+};
+use cw_orch_mock::MockBech32;
+use mock_contract::{InstantiateMsg, MockContract};
+
+struct MockApp<Chain> {
+    contract: MockContract<Chain>,
+}
+
+impl Deploy<MockBech32> for MockApp<MockBech32> {
+    type Error = CwEnvError;
+    type DeployData = ();
+
+    fn store_on(chain: MockBech32) -> Result<Self, Self::Error> {
+        let contract = MockContract::new("mock-contract", chain);
+        contract.upload()?;
+        Ok(Self { contract })
+    }
+
+    fn deploy_on(chain: MockBech32, _data: ()) -> Result<Self, Self::Error> {
+        let app = Self::store_on(chain.clone())?;
+        app.contract
+            .instantiate(&InstantiateMsg {}, Some(&chain.sender_addr()), &[])?;
+        Ok(app)
+    }
+
+    fn get_contracts_mut(&mut self) -> Vec<Box<&mut dyn ContractInstance<MockBech32>>> {
+        vec![Box::new(&mut self.contract)]
+    }
+
+    fn load_from(chain: MockBech32) -> Result<Self, Self::Error> {
+        Ok(Self {
+            contract: MockContract::new("mock-contract", chain),
+        })
+    }
+}
+
+#[test]
+fn teardown_removes_state() -> anyhow::Result<()> {
+    let chain = MockBech32::new("mock");
+    let mut app = MockApp::deploy_on(chain.clone(), ())?;
+    assert!(app.contract.address().is_ok());
+
+    app.teardown(None)?;
+
+    let loaded = MockApp::load_from(chain)?;
+    assert!(loaded.contract.address().is_err());
+    assert!(loaded.contract.code_id().is_err());
+    Ok(())
+}
@@ -0,0 +1,34 @@
+use cosmwasm_std::coins;
+use cw_orch_core::{
+    contract::GasSnapshot,
+    environment::{QueryHandler, TxHandler},
+};
+use cw_orch_mock::{BlockGasLimit, MockBech32};
+
+#[test]
+fn gas_snapshot() -> anyhow::Result<()> {
+    let chain = MockBech32::new("mock");
+    chain.set_balance(&chain.sender_addr(), coins(100, "ujuno"))?;
+    let receiver = chain.addr_make("receiver");
+    chain.set_block_gas_limit(BlockGasLimit::new(u64::MAX));
+
+    let dir = tempfile::tempdir()?;
+    let path = dir.path().join("gas.json");
+    let mut gas = GasSnapshot::new(&path);
+
+    // Mock responses don't report their gas
+    let response = chain.bank_send(&receiver, &coins(1, "ujuno"))?;
+    assert!(gas.record_tx("send", &response).is_err());
+
+    // The gas is estimated by the block gas accounting instead
+    gas.record("send", chain.block_gas_used().unwrap());
+    gas.check()?;
+
+    chain.next_block()?;
+    let mut gas = GasSnapshot::new(&path);
+    chain.bank_send(&receiver, &coins(1, "ujuno"))?;
+    gas.record("send", chain.block_gas_used().unwrap());
+    let report = gas.check()?;
+    assert!(report.exceeding(0.0).is_empty());
+    Ok(())
+}
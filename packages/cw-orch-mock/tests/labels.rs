@@ -0,0 +1,87 @@
+use cosmwasm_std::{
+    to_json_binary, Binary, Deps, DepsMut, Empty, Env, MessageInfo, Response, StdResult, WasmMsg,
+};
+use cw_orch_core::{
+    contract::Contract,
+    environment::{DefaultQueriers, WasmQuerier},
+};
+use cw_orch_mock::cw_multi_test::ContractWrapper;
+use cw_orch_mock::Mock;
+
+fn instantiate(_deps: DepsMut, _env: Env, _info: MessageInfo, _msg: Empty) -> StdResult<Response> {
+    Ok(Response::new())
+}
+
+fn query(_deps: Deps, _env: Env, _msg: Empty) -> StdResult<Binary> {
+    to_json_binary(&Empty {})
+}
+
+/// Instantiates a `child` contract of the given code id
+fn sudo_instantiate(_deps: DepsMut, _env: Env, code_id: u64) -> StdResult<Response> {
+    Ok(Response::new().add_message(WasmMsg::Instantiate {
+        admin: None,
+        code_id,
+        msg: to_json_binary(&Empty {})?,
+        funds: vec![],
+        label: "child".to_string(),
+    }))
+}
+
+#[test]
+fn instantiates_with_project_label() -> anyhow::Result<()> {
+    let chain = Mock::new("sender");
+    chain.upload_custom(
+        "contract",
+        Box::new(ContractWrapper::new(instantiate, instantiate, query)),
+    )?;
+    let mut contract = Contract::new("contract", chain.clone());
+    assert_eq!(contract.label(), "contract");
+
+    contract.set_project("project");
+    assert_eq!(contract.label(), "project:contract:default");
+
+    contract.set_manual_interaction(false);
+    assert_eq!(contract.find_by_label()?, None);
+    assert!(contract
+        .instantiate_or_adopt(&Empty {}, None, &[])?
+        .is_some());
+    let address = contract.address()?;
+    let data = chain.app.borrow().contract_data(&address)?;
+    assert_eq!(data.label, "project:contract:default");
+    assert_eq!(contract.find_by_label()?, Some(address.clone()));
+
+    // A contract with the same label and code id is adopted
+    contract.remove_address();
+    assert!(contract
+        .instantiate_or_adopt(&Empty {}, None, &[])?
+        .is_none());
+    assert_eq!(contract.address()?, address);
+
+    // A contract running another code id isn't adopted
+    chain.upload_custom(
+        "contract",
+        Box::new(ContractWrapper::new(instantiate, instantiate, query)),
+    )?;
+    assert!(contract
+        .instantiate_or_adopt(&Empty {}, None, &[])?
+        .is_some());
+    assert_ne!(contract.address()?, address);
+    Ok(())
+}
+
+#[test]
+fn finds_contracts_instantiated_by_sudo() -> anyhow::Result<()> {
+    let chain = Mock::new("sender");
+    chain.upload_custom(
+        "contract",
+        Box::new(ContractWrapper::new(instantiate, instantiate, query).with_sudo(sudo_instantiate)),
+    )?;
+    let contract = Contract::new("contract", chain.clone());
+    contract.instantiate(&Empty {}, None, &[])?;
+    let parent = contract.address()?;
+
+    contract.sudo(&contract.code_id()?)?;
+    let child = chain.wasm_querier().contract_by_label(&parent, "child")?;
+    assert!(child.is_some());
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use cosmwasm_std::{from_json, Binary};
+use cw_orch_core::contract::interface_traits::*;
+use cw_orch_mock::Mock;
+use mock_contract::{InstantiateMsg, ThirdReturn};
+
+#[test]
+fn raw_messages_bypass_typed_msgs() -> anyhow::Result<()> {
+    let chain = Mock::new("sender");
+    let contract = mock_contract::MockContract::new("test:mock_contract", chain);
+    contract.upload()?;
+    contract.instantiate(&InstantiateMsg {}, None, &[])?;
+
+    let response = contract.execute_raw(Binary::from(br#"{"first_message":{}}"#), &[])?;
+    assert!(response.has_event(
+        &cosmwasm_std::Event::new("wasm").add_attribute("action", "first message passed")
+    ));
+    // Unit variants are serialized as strings
+    contract.execute_raw(Binary::from(br#""fourth_message""#), &[])?;
+    assert!(contract
+        .execute_raw(Binary::from(br#"{"unknown_message":{}}"#), &[])
+        .is_err());
+
+    let response = contract.query_raw_smart(Binary::from(br#"{"third_query":{"t":"arg"}}"#))?;
+    let response: ThirdReturn<String> = from_json(response)?;
+    assert_eq!(response.t, "third query passed");
+
+    let response = contract.query_raw_smart(Binary::from(br#"{"fourth_query":[45,"moneys"]}"#))?;
+    assert_eq!(from_json::<u64>(response)?, 4);
+
+    // Not JSON
+    assert!(contract
+        .query_raw_smart(Binary::from(b"first_query"))
+        .is_err());
+    Ok(())
+}
@@ -0,0 +1,66 @@
+use cosmwasm_std::{
+    to_json_binary, Binary, Deps, DepsMut, Empty, Env, MessageInfo, Response, StdResult, WasmMsg,
+};
+use cw_orch_core::{
+    contract::Contract,
+    environment::{ChainState, StateInterface},
+};
+use cw_orch_mock::cw_multi_test::ContractWrapper;
+use cw_orch_mock::Mock;
+
+const CHILD_CODE_ID: u64 = 2;
+
+fn instantiate(_deps: DepsMut, _env: Env, _info: MessageInfo, _msg: Empty) -> StdResult<Response> {
+    Ok(Response::new())
+}
+
+// The factory instantiates a new child on every execution
+fn factory_execute(
+    _deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    _msg: Empty,
+) -> StdResult<Response> {
+    Ok(Response::new().add_message(WasmMsg::Instantiate {
+        admin: None,
+        code_id: CHILD_CODE_ID,
+        msg: to_json_binary(&Empty {})?,
+        funds: vec![],
+        label: "child".to_string(),
+    }))
+}
+
+fn query(_deps: Deps, _env: Env, _msg: Empty) -> StdResult<Binary> {
+    to_json_binary(&Empty {})
+}
+
+#[test]
+fn registers_factory_children() -> anyhow::Result<()> {
+    let chain = Mock::new("sender");
+    chain.upload_custom(
+        "factory",
+        Box::new(ContractWrapper::new(factory_execute, instantiate, query)),
+    )?;
+    chain.upload_custom(
+        "child",
+        Box::new(ContractWrapper::new(factory_execute, instantiate, query)),
+    )?;
+
+    let factory = Contract::new("factory", chain.clone());
+    factory.instantiate(&Empty {}, None, &[])?;
+
+    let response = factory.execute(&Empty {}, &[])?;
+    let child = factory.register_child(&response, "child:1", |contract| {
+        contract.code_id == Some(CHILD_CODE_ID)
+    })?;
+    assert_eq!(chain.state().get_address("child:1")?, child);
+    assert_ne!(child, factory.address()?);
+
+    // No contract matches the filter
+    let response = factory.execute(&Empty {}, &[])?;
+    assert!(factory
+        .register_child(&response, "child:2", |contract| contract.code_id
+            == Some(42))
+        .is_err());
+    Ok(())
+}
@@ -0,0 +1,60 @@
+use cosmwasm_std::{coins, Event};
+use cw_orch_core::{
+    environment::{BankQuerier, DefaultQueriers, TxHandler, TxKind},
+    CwEnvError,
+};
+use cw_orch_mock::{cw_multi_test::AppResponse, Mock};
+use cw_orch_traits::Stargate;
+use prost_types::Any;
+
+const MINT_TYPE_URL: &str = "/test.MsgMint";
+
+#[test]
+fn commits_registered_messages() -> anyhow::Result<()> {
+    let mut chain = Mock::new("sender");
+    let kinds = std::sync::Arc::new(std::sync::Mutex::new(vec![]));
+    let log = kinds.clone();
+    chain.add_post_response_hook(move |context| {
+        log.lock().unwrap().push(context.kind.clone());
+        Ok(())
+    });
+    chain.register_stargate_handler(MINT_TYPE_URL, |chain, value| {
+        let amount: u128 = String::from_utf8_lossy(value)
+            .parse()
+            .map_err(|e| CwEnvError::StdErr(format!("Invalid amount: {e}")))?;
+        chain.add_balance(&chain.sender_addr(), coins(amount, "minted"))?;
+        Ok(AppResponse {
+            events: vec![Event::new("mint").add_attribute("amount", amount.to_string())],
+            ..Default::default()
+        })
+    });
+
+    let mint = |amount: &str| Any {
+        type_url: MINT_TYPE_URL.to_string(),
+        value: amount.as_bytes().to_vec(),
+    };
+    let response = chain.commit_any(vec![mint("100"), mint("50")], None)?;
+    assert_eq!(response.events.len(), 2);
+    let balance = chain
+        .bank_querier()
+        .balance(&chain.sender_addr(), Some("minted".to_string()))?;
+    assert_eq!(balance, coins(150, "minted"));
+    assert_eq!(
+        kinds.lock().unwrap().last(),
+        Some(&TxKind::Stargate {
+            type_urls: vec![MINT_TYPE_URL.to_string(), MINT_TYPE_URL.to_string()]
+        })
+    );
+
+    // Messages without handler are rejected, reverting the messages executed before them
+    let unknown = Any {
+        type_url: "/test.MsgUnknown".to_string(),
+        value: vec![],
+    };
+    assert!(chain.commit_any(vec![mint("10"), unknown], None).is_err());
+    let balance = chain
+        .bank_querier()
+        .balance(&chain.sender_addr(), Some("minted".to_string()))?;
+    assert_eq!(balance, coins(150, "minted"));
+    Ok(())
+}
@@ -0,0 +1,51 @@
+use cosmwasm_std::{
+    to_json_binary, Binary, Deps, DepsMut, Empty, Env, MessageInfo, Response, StdError, StdResult,
+};
+use cw_orch_core::{
+    contract::Contract,
+    environment::{IndexResponse, TxKind},
+};
+use cw_orch_mock::cw_multi_test::ContractWrapper;
+use cw_orch_mock::Mock;
+
+fn instantiate(_deps: DepsMut, _env: Env, _info: MessageInfo, _msg: Empty) -> StdResult<Response> {
+    Ok(Response::new())
+}
+
+fn execute(_deps: DepsMut, _env: Env, _info: MessageInfo, _msg: Empty) -> StdResult<Response> {
+    Err(StdError::generic_err("only callable with sudo"))
+}
+
+fn query(deps: Deps, _env: Env, _msg: Empty) -> StdResult<Binary> {
+    to_json_binary(&deps.storage.get(b"paused").is_some())
+}
+
+fn sudo(deps: DepsMut, _env: Env, _msg: Empty) -> StdResult<Response> {
+    deps.storage.set(b"paused", &[1]);
+    Ok(Response::new().add_attribute("action", "pause"))
+}
+
+#[test]
+fn calls_sudo() -> anyhow::Result<()> {
+    let mut chain = Mock::new("sender");
+    let kinds = std::sync::Arc::new(std::sync::Mutex::new(vec![]));
+    let log = kinds.clone();
+    chain.add_post_response_hook(move |context| {
+        log.lock().unwrap().push(context.kind.clone());
+        Ok(())
+    });
+    chain.upload_custom(
+        "contract",
+        Box::new(ContractWrapper::new(execute, instantiate, query).with_sudo(sudo)),
+    )?;
+    let contract = Contract::new("contract", chain.clone());
+    contract.instantiate(&Empty {}, None, &[])?;
+    assert!(contract.execute(&Empty {}, &[]).is_err());
+
+    let response = contract.sudo(&Empty {})?;
+    assert_eq!(response.event_attr_value("wasm", "action")?, "pause");
+    let paused: bool = contract.query(&Empty {})?;
+    assert!(paused);
+    assert_eq!(kinds.lock().unwrap().last(), Some(&TxKind::Sudo));
+    Ok(())
+}
@@ -0,0 +1,18 @@
+use cosmwasm_std::coins;
+use cw_orch_core::environment::{IndexResponse, TxHandler};
+use cw_orch_mock::MockBech32;
+
+#[test]
+fn bank_send_analysis() -> anyhow::Result<()> {
+    let chain = MockBech32::new("mock");
+    chain.set_balance(&chain.sender_addr(), coins(100, "ujuno"))?;
+    let receiver = chain.addr_make("receiver");
+
+    let response = chain.bank_send(&receiver, &coins(42, "ujuno"))?;
+    let analysis = response.analysis();
+
+    analysis.assert_sent(&chain.sender_addr(), &coins(42, "ujuno"));
+    analysis.assert_received(&receiver, &coins(42, "ujuno"));
+    assert!(analysis.fee().is_empty());
+    Ok(())
+}
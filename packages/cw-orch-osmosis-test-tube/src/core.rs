@@ -311,6 +311,55 @@ impl<S: StateInterface> TxHandler for OsmosisTestTube<S> {
         })
     }
 
+    fn update_admin(
+        &self,
+        contract_address: &Addr,
+        new_admin: &Addr,
+    ) -> Result<Self::Response, Self::Error> {
+        use osmosis_test_tube::osmosis_std::types::cosmwasm::wasm::v1::{
+            MsgUpdateAdmin, MsgUpdateAdminResponse,
+        };
+
+        let update_response = (*self.app.borrow())
+            .execute::<MsgUpdateAdmin, MsgUpdateAdminResponse>(
+                MsgUpdateAdmin {
+                    sender: self.sender_addr().to_string(),
+                    new_admin: new_admin.to_string(),
+                    contract: contract_address.to_string(),
+                },
+                MsgUpdateAdmin::TYPE_URL,
+                &self.sender,
+            )
+            .map_err(map_err)?;
+
+        Ok(AppResponse {
+            data: Some(Binary::new(update_response.raw_data)),
+            events: update_response.events,
+        })
+    }
+
+    fn clear_admin(&self, contract_address: &Addr) -> Result<Self::Response, Self::Error> {
+        use osmosis_test_tube::osmosis_std::types::cosmwasm::wasm::v1::{
+            MsgClearAdmin, MsgClearAdminResponse,
+        };
+
+        let clear_response = (*self.app.borrow())
+            .execute::<MsgClearAdmin, MsgClearAdminResponse>(
+                MsgClearAdmin {
+                    sender: self.sender_addr().to_string(),
+                    contract: contract_address.to_string(),
+                },
+                MsgClearAdmin::TYPE_URL,
+                &self.sender,
+            )
+            .map_err(map_err)?;
+
+        Ok(AppResponse {
+            data: Some(Binary::new(clear_response.raw_data)),
+            events: clear_response.events,
+        })
+    }
+
     fn instantiate2<I: Serialize + Debug>(
         &self,
         code_id: u64,
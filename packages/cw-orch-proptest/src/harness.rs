@@ -0,0 +1,116 @@
+//! Random sequences of execute messages run against fresh [`Mock`] deployments.
+//!
+//! Each test case deploys the contracts again, executes a random sequence of messages and checks an invariant after each of them.
+//! The messages rejected by the contract are expected and don't fail the test case, unless [`SequenceHarness::fail_on_execute_error`] is set.
+//! When a sequence breaks the invariant, proptest shrinks it by removing messages and simplifying their fields,
+//! and the minimal failing sequence is returned in the [`TestError`].
+use cw_orch_core::contract::interface_traits::CwOrchExecute;
+use cw_orch_mock::Mock;
+use proptest::{
+    collection::vec,
+    test_runner::{Config, TestCaseError, TestError, TestRunner},
+};
+use schemars::JsonSchema;
+use serde::de::DeserializeOwned;
+
+use crate::MsgStrategy;
+
+/// Runs random sequences of execute messages and shrinks the sequences breaking an invariant
+#[derive(Debug, Clone)]
+pub struct SequenceHarness {
+    config: Config,
+    msgs: MsgStrategy,
+    max_sequence_len: usize,
+    fail_on_execute_error: bool,
+}
+
+impl SequenceHarness {
+    /// Harness running 64 sequences of up to 10 messages
+    pub fn new() -> Self {
+        Self {
+            config: Config {
+                cases: 64,
+                // The sequences are generated at runtime, there is no source file to persist the failures next to
+                failure_persistence: None,
+                ..Config::default()
+            },
+            msgs: MsgStrategy::new(),
+            max_sequence_len: 10,
+            fail_on_execute_error: false,
+        }
+    }
+
+    /// Sets the number of sequences to run
+    pub fn cases(mut self, cases: u32) -> Self {
+        self.config.cases = cases;
+        self
+    }
+
+    /// Sets the maximum number of messages of a sequence
+    pub fn max_sequence_len(mut self, len: usize) -> Self {
+        self.max_sequence_len = len.max(1);
+        self
+    }
+
+    /// Sets the strategy generating the messages, e.g. to provide the addresses of the deployment
+    pub fn msgs(mut self, msgs: MsgStrategy) -> Self {
+        self.msgs = msgs;
+        self
+    }
+
+    /// Sets the proptest configuration, e.g. to change the seed or the number of shrinking iterations
+    pub fn config(mut self, config: Config) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Fails the test case when the contract rejects a message
+    pub fn fail_on_execute_error(mut self) -> Self {
+        self.fail_on_execute_error = true;
+        self
+    }
+
+    /// Runs the sequences against the contracts created by `deploy`, checking `invariant` after each message.
+    /// Returns the minimal sequence breaking the invariant, if any.
+    pub fn run<T>(
+        &self,
+        deploy: impl Fn() -> anyhow::Result<T>,
+        invariant: impl Fn(&T) -> anyhow::Result<()>,
+    ) -> Result<(), TestError<Vec<T::ExecuteMsg>>>
+    where
+        T: CwOrchExecute<Mock>,
+        T::ExecuteMsg: JsonSchema + DeserializeOwned + 'static,
+    {
+        let sequences = vec(self.msgs.execute_msgs::<T>(), 1..=self.max_sequence_len);
+        let mut runner = TestRunner::new(self.config.clone());
+        runner.run(&sequences, |msgs| {
+            let contract =
+                deploy().map_err(|e| TestCaseError::fail(format!("Deployment failed: {e:?}")))?;
+            invariant(&contract).map_err(|e| {
+                TestCaseError::fail(format!("Invariant broken after the deployment: {e:?}"))
+            })?;
+            for (step, msg) in msgs.iter().enumerate() {
+                if let Err(e) = contract.execute(msg, &[]) {
+                    if self.fail_on_execute_error {
+                        return Err(TestCaseError::fail(format!(
+                            "Step {step} failed to execute {msg:?}: {e}"
+                        )));
+                    }
+                    log::debug!("Step {step} rejected {msg:?}: {e}");
+                }
+                invariant(&contract).map_err(|e| {
+                    TestCaseError::fail(format!(
+                        "Invariant broken after step {step} ({msg:?}): {e:?}"
+                    ))
+                })?;
+            }
+            Ok(())
+        })
+    }
+}
+
+impl Default for SequenceHarness {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,14 @@
+//! Property-based testing of cw-orchestrator interfaces on the mock environment.
+//!
+//! [`MsgStrategy`] generates arbitrary messages from their JSON schema, which all the messages deriving `#[cw_serde]` provide.
+//! [`SequenceHarness`] runs random sequences of execute messages against fresh [`Mock`](cw_orch_mock::Mock) deployments,
+//! checks an invariant after each message and shrinks the failing sequences to a minimal reproduction.
+
+pub mod harness;
+pub mod strategy;
+
+pub use harness::SequenceHarness;
+pub use strategy::MsgStrategy;
+
+// Re-export to build custom strategies and read the test errors
+pub use proptest;
@@ -0,0 +1,382 @@
+//! Strategies generating messages from their JSON schema.
+//!
+//! The schema is walked to generate JSON values, which are then deserialized into the message.
+//! The values that don't deserialize (e.g. because of custom validation) are rejected by the strategy.
+//! The CosmWasm math and binary types are generated in their string representation.
+use std::fmt::Debug;
+
+use cosmwasm_std::Binary;
+use cw_orch_core::contract::interface_traits::ExecutableContract;
+use proptest::{
+    collection::{btree_map, vec},
+    prelude::*,
+    sample::select,
+    strategy::Union,
+    string::string_regex,
+};
+use schemars::{
+    schema::{InstanceType, RootSchema, Schema, SchemaObject, SingleOrVec},
+    JsonSchema, Map,
+};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+/// Fields holding an address as a string, matched on the end of the field name
+const ADDRESS_FIELDS: [&str; 10] = [
+    "addr",
+    "address",
+    "admin",
+    "contract",
+    "operator",
+    "owner",
+    "receiver",
+    "recipient",
+    "sender",
+    "spender",
+];
+
+/// Generates messages from their JSON schema
+#[derive(Debug, Clone)]
+pub struct MsgStrategy {
+    addresses: Vec<String>,
+    max_depth: usize,
+    max_collection_len: usize,
+}
+
+impl MsgStrategy {
+    /// Strategy without known addresses, nesting up to 4 levels of objects and collections of up to 3 elements
+    pub fn new() -> Self {
+        Self {
+            addresses: vec![],
+            max_depth: 4,
+            max_collection_len: 3,
+        }
+    }
+
+    /// Sets the addresses used for the `Addr` values and for the string fields named like addresses (`owner`, `recipient`, ...).
+    /// Without them, these fields get random strings which the contracts will most likely reject.
+    pub fn addresses(mut self, addresses: impl IntoIterator<Item = impl ToString>) -> Self {
+        self.addresses = addresses.into_iter().map(|a| a.to_string()).collect();
+        self
+    }
+
+    /// Sets the nesting level after which the collections are empty and the optional fields are omitted
+    pub fn max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// Sets the maximum number of elements of the generated arrays and maps
+    pub fn max_collection_len(mut self, max_collection_len: usize) -> Self {
+        self.max_collection_len = max_collection_len;
+        self
+    }
+
+    /// Strategy generating the messages of type `M`
+    pub fn msgs<M>(&self) -> BoxedStrategy<M>
+    where
+        M: JsonSchema + DeserializeOwned + Debug + 'static,
+    {
+        self.json(&schemars::schema_for!(M))
+            .prop_filter_map("The generated JSON isn't a valid message", |value| {
+                serde_json::from_value(value).ok()
+            })
+            .boxed()
+    }
+
+    /// Strategy generating the execute messages of the interface `T`
+    pub fn execute_msgs<T>(&self) -> BoxedStrategy<T::ExecuteMsg>
+    where
+        T: ExecutableContract,
+        T::ExecuteMsg: JsonSchema + DeserializeOwned + 'static,
+    {
+        self.msgs::<T::ExecuteMsg>()
+    }
+
+    /// Strategy generating JSON values matching `root`
+    pub fn json(&self, root: &RootSchema) -> BoxedStrategy<Value> {
+        self.object(&root.schema, &root.definitions, 0, None)
+    }
+
+    fn schema(
+        &self,
+        schema: &Schema,
+        definitions: &Map<String, Schema>,
+        depth: usize,
+        field: Option<&str>,
+    ) -> BoxedStrategy<Value> {
+        match schema {
+            Schema::Bool(_) => Just(Value::Null).boxed(),
+            Schema::Object(schema) => self.object(schema, definitions, depth, field),
+        }
+    }
+
+    fn object(
+        &self,
+        schema: &SchemaObject,
+        definitions: &Map<String, Schema>,
+        depth: usize,
+        field: Option<&str>,
+    ) -> BoxedStrategy<Value> {
+        if let Some(reference) = &schema.reference {
+            let name = reference.trim_start_matches("#/definitions/");
+            if let Some(strategy) = self.cosmwasm_type(name, field) {
+                return strategy;
+            }
+            return match definitions.get(name) {
+                Some(definition) if depth <= self.max_depth => {
+                    self.schema(definition, definitions, depth + 1, field)
+                }
+                _ => Just(Value::Null).boxed(),
+            };
+        }
+        if let Some(values) = &schema.enum_values {
+            return select(values.clone()).boxed();
+        }
+        if let Some(value) = &schema.const_value {
+            return Just(value.clone()).boxed();
+        }
+        if let Some(subschemas) = &schema.subschemas {
+            let alternatives: Vec<_> = subschemas
+                .one_of
+                .iter()
+                .chain(subschemas.any_of.iter())
+                .flatten()
+                .map(|alternative| self.schema(alternative, definitions, depth, field))
+                .collect();
+            if !alternatives.is_empty() {
+                return Union::new(alternatives).boxed();
+            }
+            // `all_of` is only used by schemars to wrap a single reference
+            if let Some([single]) = subschemas.all_of.as_deref() {
+                return self.schema(single, definitions, depth, field);
+            }
+        }
+        match &schema.instance_type {
+            Some(SingleOrVec::Single(instance_type)) => {
+                self.instance(**instance_type, schema, definitions, depth, field)
+            }
+            Some(SingleOrVec::Vec(instance_types)) => Union::new(
+                instance_types
+                    .iter()
+                    .map(|instance_type| {
+                        self.instance(*instance_type, schema, definitions, depth, field)
+                    })
+                    .collect::<Vec<_>>(),
+            )
+            .boxed(),
+            None => Just(Value::Null).boxed(),
+        }
+    }
+
+    fn instance(
+        &self,
+        instance_type: InstanceType,
+        schema: &SchemaObject,
+        definitions: &Map<String, Schema>,
+        depth: usize,
+        field: Option<&str>,
+    ) -> BoxedStrategy<Value> {
+        match instance_type {
+            InstanceType::Null => Just(Value::Null).boxed(),
+            InstanceType::Boolean => any::<bool>().prop_map(Value::Bool).boxed(),
+            InstanceType::Integer => {
+                let (mut min, mut max) = integer_bounds(schema.format.as_deref());
+                if let Some(number) = &schema.number {
+                    min = number.minimum.map_or(min, |m| min.max(m.ceil() as i128));
+                    max = number.maximum.map_or(max, |m| max.min(m.floor() as i128));
+                }
+                (min..=max)
+                    .prop_map(|n| match u64::try_from(n) {
+                        Ok(n) => Value::from(n),
+                        Err(_) => Value::from(n as i64),
+                    })
+                    .boxed()
+            }
+            InstanceType::Number => (-1e9f64..1e9).prop_map(Value::from).boxed(),
+            InstanceType::String => self.string(field),
+            InstanceType::Array => {
+                let Some(array) = &schema.array else {
+                    return Just(Value::Array(vec![])).boxed();
+                };
+                match &array.items {
+                    // Tuples
+                    Some(SingleOrVec::Vec(items)) => items
+                        .iter()
+                        .map(|item| self.schema(item, definitions, depth + 1, field))
+                        .collect::<Vec<_>>()
+                        .prop_map(Value::Array)
+                        .boxed(),
+                    Some(SingleOrVec::Single(item)) => {
+                        let min = array.min_items.unwrap_or(0) as usize;
+                        let max = if depth < self.max_depth {
+                            array
+                                .max_items
+                                .map_or(self.max_collection_len, |max| max as usize)
+                                .min(self.max_collection_len)
+                                .max(min)
+                        } else {
+                            min
+                        };
+                        vec(self.schema(item, definitions, depth + 1, field), min..=max)
+                            .prop_map(Value::Array)
+                            .boxed()
+                    }
+                    None => Just(Value::Array(vec![])).boxed(),
+                }
+            }
+            InstanceType::Object => {
+                let Some(object) = &schema.object else {
+                    return Just(Value::Object(Default::default())).boxed();
+                };
+                // Maps
+                if object.properties.is_empty() {
+                    return match &object.additional_properties {
+                        Some(values) if depth < self.max_depth => btree_map(
+                            string_regex("[a-z0-9]{1,16}").unwrap(),
+                            self.schema(values, definitions, depth + 1, None),
+                            0..=self.max_collection_len,
+                        )
+                        .prop_map(|map| Value::Object(map.into_iter().collect()))
+                        .boxed(),
+                        _ => Just(Value::Object(Default::default())).boxed(),
+                    };
+                }
+                let properties: Vec<_> = object
+                    .properties
+                    .iter()
+                    .filter_map(|(name, property)| {
+                        let value = self.schema(property, definitions, depth + 1, Some(name));
+                        let name = name.clone();
+                        if object.required.contains(&name) {
+                            Some(
+                                value
+                                    .prop_map(move |value| Some((name.clone(), value)))
+                                    .boxed(),
+                            )
+                        } else if depth < self.max_depth {
+                            Some(
+                                proptest::option::of(value)
+                                    .prop_map(move |value| Some((name.clone(), value?)))
+                                    .boxed(),
+                            )
+                        } else {
+                            None
+                        }
+                    })
+                    .collect();
+                properties
+                    .prop_map(|properties| {
+                        Value::Object(properties.into_iter().flatten().collect())
+                    })
+                    .boxed()
+            }
+        }
+    }
+
+    /// Strings, or one of the known addresses for the fields named like addresses
+    fn string(&self, field: Option<&str>) -> BoxedStrategy<Value> {
+        let is_address =
+            field.is_some_and(|field| ADDRESS_FIELDS.iter().any(|suffix| field.ends_with(suffix)));
+        if is_address && !self.addresses.is_empty() {
+            return select(self.addresses.clone())
+                .prop_map(Value::String)
+                .boxed();
+        }
+        string_regex("[a-z0-9]{0,16}")
+            .unwrap()
+            .prop_map(Value::String)
+            .boxed()
+    }
+
+    /// Strategies for the CosmWasm types serialized as strings, by definition name
+    fn cosmwasm_type(&self, name: &str, field: Option<&str>) -> Option<BoxedStrategy<Value>> {
+        let strategy = match name {
+            "Addr" => self.string(Some(field.unwrap_or("addr"))),
+            "Uint64" | "Uint128" | "Uint256" | "Uint512" | "Timestamp" => any::<u64>()
+                .prop_map(|n| Value::String(n.to_string()))
+                .boxed(),
+            "Int64" | "Int128" | "Int256" | "Int512" => any::<i64>()
+                .prop_map(|n| Value::String(n.to_string()))
+                .boxed(),
+            "Decimal" | "Decimal256" | "SignedDecimal" | "SignedDecimal256" => {
+                (any::<u32>(), 0u32..1_000_000)
+                    .prop_map(|(int, frac)| Value::String(format!("{int}.{frac:06}")))
+                    .boxed()
+            }
+            "Binary" => vec(any::<u8>(), 0..=32)
+                .prop_map(|bytes| Value::String(Binary::from(bytes).to_base64()))
+                .boxed(),
+            _ => return None,
+        };
+        Some(strategy)
+    }
+}
+
+impl Default for MsgStrategy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Bounds of the integers of a JSON schema `format`
+fn integer_bounds(format: Option<&str>) -> (i128, i128) {
+    match format {
+        Some("uint8") => (0, u8::MAX as i128),
+        Some("uint16") => (0, u16::MAX as i128),
+        Some("uint32") => (0, u32::MAX as i128),
+        Some("uint64") | Some("uint") => (0, u64::MAX as i128),
+        Some("int8") => (i8::MIN as i128, i8::MAX as i128),
+        Some("int16") => (i16::MIN as i128, i16::MAX as i128),
+        Some("int32") => (i32::MIN as i128, i32::MAX as i128),
+        _ => (i64::MIN as i128, i64::MAX as i128),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use cosmwasm_std::{Addr, Uint128};
+    use proptest::test_runner::TestRunner;
+    use schemars::JsonSchema;
+    use serde::Deserialize;
+
+    use super::*;
+
+    #[derive(Debug, Deserialize, JsonSchema)]
+    #[serde(rename_all = "snake_case")]
+    enum ExecuteMsg {
+        Transfer {
+            recipient: Addr,
+            amount: Uint128,
+            memo: Option<String>,
+        },
+        SetLimits {
+            limits: Vec<(u8, bool)>,
+        },
+        Pause {},
+    }
+
+    #[test]
+    fn generates_valid_messages() {
+        let strategy = MsgStrategy::new()
+            .addresses(["alice", "bob"])
+            .msgs::<ExecuteMsg>();
+        let mut runner = TestRunner::default();
+        let mut variants = [false; 3];
+        for _ in 0..200 {
+            let msg = strategy.new_tree(&mut runner).unwrap().current();
+            match msg {
+                ExecuteMsg::Transfer { recipient, .. } => {
+                    assert!(recipient == "alice" || recipient == "bob");
+                    variants[0] = true;
+                }
+                ExecuteMsg::SetLimits { limits } => {
+                    assert!(limits.len() <= 3);
+                    variants[1] = true;
+                }
+                ExecuteMsg::Pause {} => variants[2] = true,
+            }
+        }
+        assert_eq!(variants, [true; 3]);
+    }
+}
@@ -0,0 +1,39 @@
+use counter_contract::{
+    msg::{ExecuteMsg, InstantiateMsg},
+    CounterContract, CounterQueryMsgFns,
+};
+use cw_orch::prelude::*;
+use cw_orch_proptest::{proptest::test_runner::TestError, SequenceHarness};
+
+fn deploy() -> anyhow::Result<CounterContract<Mock>> {
+    let chain = Mock::new("sender");
+    let counter = CounterContract::new(chain);
+    counter.upload()?;
+    counter.instantiate(&InstantiateMsg { count: 0 }, None, &[])?;
+    Ok(counter)
+}
+
+#[test]
+fn invariant_holds() {
+    SequenceHarness::new()
+        .cases(16)
+        .run(deploy, |counter| {
+            counter.get_count()?;
+            Ok(())
+        })
+        .unwrap();
+}
+
+#[test]
+fn shrinks_failing_sequences() {
+    let result = SequenceHarness::new().run(deploy, |counter| {
+        anyhow::ensure!(counter.get_count()?.count <= 1000, "count above 1000");
+        Ok(())
+    });
+
+    let Err(TestError::Fail(_, sequence)) = result else {
+        panic!("The invariant should be broken, got {result:?}");
+    };
+    // Increments alone can't reach 1000 in 10 messages, the minimal sequence is a single reset
+    assert_eq!(sequence, vec![ExecuteMsg::Reset { count: 1001 }]);
+}
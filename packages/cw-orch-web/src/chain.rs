@@ -0,0 +1,133 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock},
+};
+
+use cosmwasm_std::Addr;
+use cw_orch_core::{
+    environment::{
+        AsyncWasmQuerier, ChainInfoOwned, ChainState, MaybeSend, Querier, StateInterface,
+    },
+    CwEnvError,
+};
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::{WebError, WebQuerier};
+
+/// Query-only environment of the contract interfaces, querying the chain through a [`WebQuerier`].
+/// The generated async query functions (`QueryFns` with `_async` suffix) can be called on the contracts built with it.
+///
+/// The addresses of the contracts are kept in memory, set them with `set_address` or `set_default_address`.
+///
+/// ```rust,no_run
+/// use cosmwasm_std::Addr;
+/// use cw_orch_core::{contract::Contract, environment::ChainInfoOwned};
+/// use cw_orch_web::WebChain;
+///
+/// # async fn dashboard(chain: ChainInfoOwned) -> Result<(), cw_orch_core::CwEnvError> {
+/// let chain = WebChain::connect(&chain).await?;
+/// let counter = Contract::new("counter", chain);
+/// counter.set_address(&Addr::unchecked("juno1..."));
+/// let count: serde_json::Value = counter.async_query(&serde_json::json!({ "get_count": {} })).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct WebChain {
+    pub querier: WebQuerier,
+    state: WebState,
+}
+
+impl WebChain {
+    /// Environment querying the chain through `querier`
+    pub fn new(querier: WebQuerier) -> Self {
+        Self {
+            querier,
+            state: WebState::default(),
+        }
+    }
+
+    /// Connects to the chain, see [`WebQuerier::connect`]
+    pub async fn connect(chain_info: &ChainInfoOwned) -> Result<Self, WebError> {
+        Ok(Self::new(WebQuerier::connect(chain_info).await?))
+    }
+}
+
+impl Querier for WebChain {
+    type Error = WebError;
+}
+
+impl AsyncWasmQuerier for WebChain {
+    fn smart_query<Q: Serialize + Sync, T: DeserializeOwned>(
+        &self,
+        address: &Addr,
+        query_msg: &Q,
+    ) -> impl std::future::Future<Output = Result<T, WebError>> + MaybeSend {
+        self.querier.smart_query(address, query_msg)
+    }
+}
+
+impl ChainState for WebChain {
+    type Out = WebState;
+
+    fn state(&self) -> Self::Out {
+        self.state.clone()
+    }
+}
+
+/// Addresses and code ids of the contracts of a [`WebChain`], kept in memory and shared by its clones
+#[derive(Clone, Debug, Default)]
+pub struct WebState {
+    addresses: Arc<RwLock<HashMap<String, Addr>>>,
+    code_ids: Arc<RwLock<HashMap<String, u64>>>,
+}
+
+impl StateInterface for WebState {
+    fn get_address(&self, contract_id: &str) -> Result<Addr, CwEnvError> {
+        self.addresses
+            .read()
+            .unwrap()
+            .get(contract_id)
+            .cloned()
+            .ok_or_else(|| CwEnvError::AddrNotInStore(contract_id.to_owned()))
+    }
+
+    fn set_address(&mut self, contract_id: &str, address: &Addr) {
+        self.addresses
+            .write()
+            .unwrap()
+            .insert(contract_id.to_string(), address.clone());
+    }
+
+    fn remove_address(&mut self, contract_id: &str) {
+        self.addresses.write().unwrap().remove(contract_id);
+    }
+
+    fn get_code_id(&self, contract_id: &str) -> Result<u64, CwEnvError> {
+        self.code_ids
+            .read()
+            .unwrap()
+            .get(contract_id)
+            .copied()
+            .ok_or_else(|| CwEnvError::CodeIdNotInStore(contract_id.to_owned()))
+    }
+
+    fn set_code_id(&mut self, contract_id: &str, code_id: u64) {
+        self.code_ids
+            .write()
+            .unwrap()
+            .insert(contract_id.to_string(), code_id);
+    }
+
+    fn remove_code_id(&mut self, contract_id: &str) {
+        self.code_ids.write().unwrap().remove(contract_id);
+    }
+
+    fn get_all_addresses(&self) -> Result<HashMap<String, Addr>, CwEnvError> {
+        Ok(self.addresses.read().unwrap().clone())
+    }
+
+    fn get_all_code_ids(&self) -> Result<HashMap<String, u64>, CwEnvError> {
+        Ok(self.code_ids.read().unwrap().clone())
+    }
+}
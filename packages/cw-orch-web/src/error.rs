@@ -0,0 +1,36 @@
+use cw_orch_core::CwEnvError;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum WebError {
+    #[error("Reqwest HTTP(s) Error")]
+    ReqwestError(#[from] ::reqwest::Error),
+    #[error(transparent)]
+    SerdeJson(#[from] ::serde_json::Error),
+    #[error(transparent)]
+    ProtoDecode(#[from] ::prost::DecodeError),
+    #[error(transparent)]
+    Base64Decode(#[from] ::base64::DecodeError),
+    #[error(transparent)]
+    CosmwasmStd(#[from] ::cosmwasm_std::StdError),
+    #[error(transparent)]
+    ParseIntError(#[from] std::num::ParseIntError),
+    #[error("gRPC query failed with status {code}: {message}")]
+    GrpcStatus { code: i32, message: String },
+    #[error("LCD query {path} failed with {status}: {body}")]
+    LcdStatus {
+        path: String,
+        status: u16,
+        body: String,
+    },
+    #[error("Can not connect to any gRPC-web or LCD endpoint of {0}")]
+    CannotConnect(String),
+    #[error("{0}")]
+    StdErr(String),
+}
+
+impl From<WebError> for CwEnvError {
+    fn from(val: WebError) -> Self {
+        CwEnvError::AnyError(val.into())
+    }
+}
@@ -0,0 +1,271 @@
+use cosmos_sdk_proto::{
+    cosmos::{
+        bank::v1beta1::{QueryBalanceRequest, QueryBalanceResponse},
+        base::tendermint::v1beta1::{
+            GetLatestBlockRequest, GetLatestBlockResponse, GetNodeInfoRequest, GetNodeInfoResponse,
+        },
+    },
+    cosmwasm::wasm::v1::{QuerySmartContractStateRequest, QuerySmartContractStateResponse},
+};
+use cosmwasm_std::{Addr, Coin};
+use prost::Message;
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::WebError;
+
+/// Flag of the gRPC-web frame holding the trailers
+const TRAILERS_FLAG: u8 = 0x80;
+
+/// Port of the native gRPC server of the nodes
+const NODE_GRPC_PORT: &str = ":9090";
+/// Port of the gRPC-web server of the nodes
+const NODE_GRPC_WEB_PORT: &str = ":9091";
+
+/// gRPC-web endpoint matching the gRPC endpoint at `grpc_url`.
+/// The nodes serve gRPC-web on port 9091 next to gRPC on port 9090, the public providers serve both on the same url.
+pub fn grpc_web_url(grpc_url: &str) -> String {
+    let grpc_url = grpc_url.trim_end_matches('/');
+    match grpc_url.strip_suffix(NODE_GRPC_PORT) {
+        Some(host) => format!("{host}{NODE_GRPC_WEB_PORT}"),
+        None => grpc_url.to_string(),
+    }
+}
+
+/// Client sending gRPC queries with the gRPC-web protocol, over HTTP/1.1
+#[derive(Clone, Debug)]
+pub struct GrpcWeb {
+    pub url: String,
+    client: reqwest::Client,
+}
+
+impl GrpcWeb {
+    /// Client of the gRPC-web endpoint at `url`, i.e. `https://grpc.juno.example:443`
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into().trim_end_matches('/').to_string(),
+            client: reqwest::Client::new(),
+        }
+    }
+
+    /// Sends a unary query to `path`, i.e. `/cosmos.bank.v1beta1.Query/Balance`
+    pub async fn query<Req: Message, Res: Message + Default>(
+        &self,
+        path: &str,
+        request: Req,
+    ) -> Result<Res, WebError> {
+        let response = self
+            .client
+            .post(format!("{}{path}", self.url))
+            .header("content-type", "application/grpc-web+proto")
+            .header("accept", "application/grpc-web+proto")
+            .header("x-grpc-web", "1")
+            .body(encode_frame(&request))
+            .send()
+            .await?
+            .error_for_status()?;
+
+        // Errors without a body are sent in the headers
+        grpc_status(
+            response
+                .headers()
+                .get("grpc-status")
+                .and_then(|value| value.to_str().ok()),
+            response
+                .headers()
+                .get("grpc-message")
+                .and_then(|value| value.to_str().ok()),
+        )?;
+        let body = response.bytes().await?;
+        let (message, trailers) = decode_frames(&body)?;
+        grpc_status(
+            trailer_value(&trailers, "grpc-status"),
+            trailer_value(&trailers, "grpc-message"),
+        )?;
+        let response = Res::decode(message.unwrap_or_default().as_slice())?;
+        ::log::trace!("grpc_web_query: {} resulted in: {:?}", path, response);
+        Ok(response)
+    }
+
+    /// Chain id of the node
+    pub async fn network(&self) -> Result<String, WebError> {
+        let node_info: GetNodeInfoResponse = self
+            .query(
+                "/cosmos.base.tendermint.v1beta1.Service/GetNodeInfo",
+                GetNodeInfoRequest {},
+            )
+            .await?;
+        Ok(node_info
+            .default_node_info
+            .map(|info| info.network)
+            .unwrap_or_default())
+    }
+
+    /// Height of the latest block
+    pub async fn block_height(&self) -> Result<u64, WebError> {
+        let response: GetLatestBlockResponse = self
+            .query(
+                "/cosmos.base.tendermint.v1beta1.Service/GetLatestBlock",
+                GetLatestBlockRequest {},
+            )
+            .await?;
+        response
+            .block
+            .and_then(|block| block.header)
+            .map(|header| header.height as u64)
+            .ok_or(WebError::StdErr("No block returned".to_string()))
+    }
+
+    /// Balance of `address` in `denom`
+    pub async fn balance(&self, address: &Addr, denom: &str) -> Result<Coin, WebError> {
+        let response: QueryBalanceResponse = self
+            .query(
+                "/cosmos.bank.v1beta1.Query/Balance",
+                QueryBalanceRequest {
+                    address: address.to_string(),
+                    denom: denom.to_string(),
+                },
+            )
+            .await?;
+        let coin = response
+            .balance
+            .ok_or(WebError::StdErr(format!("No {denom} balance returned")))?;
+        Ok(Coin {
+            amount: coin.amount.parse()?,
+            denom: coin.denom,
+        })
+    }
+
+    /// Smart query of a contract
+    pub async fn smart_query<Q: Serialize, T: DeserializeOwned>(
+        &self,
+        contract: &Addr,
+        query: &Q,
+    ) -> Result<T, WebError> {
+        let response: QuerySmartContractStateResponse = self
+            .query(
+                "/cosmwasm.wasm.v1.Query/SmartContractState",
+                QuerySmartContractStateRequest {
+                    address: contract.to_string(),
+                    query_data: serde_json::to_vec(query)?,
+                },
+            )
+            .await?;
+        Ok(serde_json::from_slice(&response.data)?)
+    }
+}
+
+/// Wraps a message in a gRPC-web data frame
+fn encode_frame(message: &impl Message) -> Vec<u8> {
+    let message = message.encode_to_vec();
+    let mut frame = Vec::with_capacity(message.len() + 5);
+    frame.push(0);
+    frame.extend_from_slice(&(message.len() as u32).to_be_bytes());
+    frame.extend_from_slice(&message);
+    frame
+}
+
+/// Splits a gRPC-web response body into its message and its trailers
+fn decode_frames(mut body: &[u8]) -> Result<(Option<Vec<u8>>, String), WebError> {
+    let mut message = None;
+    let mut trailers = String::new();
+    while !body.is_empty() {
+        if body.len() < 5 {
+            return Err(WebError::StdErr(
+                "Truncated gRPC-web frame header".to_string(),
+            ));
+        }
+        let flag = body[0];
+        let len = u32::from_be_bytes([body[1], body[2], body[3], body[4]]) as usize;
+        // `usize` is 32 bits on wasm32, the length of the frame can overflow it
+        let end = len
+            .checked_add(5)
+            .ok_or(WebError::StdErr("Oversized gRPC-web frame".to_string()))?;
+        let frame = body
+            .get(5..end)
+            .ok_or(WebError::StdErr("Truncated gRPC-web frame".to_string()))?;
+        if flag & TRAILERS_FLAG != 0 {
+            trailers.push_str(&String::from_utf8_lossy(frame));
+        } else {
+            message = Some(frame.to_vec());
+        }
+        body = &body[end..];
+    }
+    Ok((message, trailers))
+}
+
+fn trailer_value<'a>(trailers: &'a str, key: &str) -> Option<&'a str> {
+    trailers.lines().find_map(|line| {
+        let (name, value) = line.split_once(':')?;
+        name.trim()
+            .eq_ignore_ascii_case(key)
+            .then_some(value.trim())
+    })
+}
+
+/// Errors if the status isn't OK
+fn grpc_status(code: Option<&str>, message: Option<&str>) -> Result<(), WebError> {
+    match code.and_then(|code| code.trim().parse::<i32>().ok()) {
+        Some(code) if code != 0 => Err(WebError::GrpcStatus {
+            code,
+            message: message.unwrap_or_default().to_string(),
+        }),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_grpc_web_frames() -> Result<(), WebError> {
+        let request = QueryBalanceRequest {
+            address: "juno1".to_string(),
+            denom: "ujuno".to_string(),
+        };
+        let mut body = encode_frame(&request);
+        let trailers = b"grpc-status: 5\r\ngrpc-message: not found\r\n";
+        body.push(TRAILERS_FLAG);
+        body.extend_from_slice(&(trailers.len() as u32).to_be_bytes());
+        body.extend_from_slice(trailers);
+
+        let (message, trailers) = decode_frames(&body)?;
+        assert_eq!(
+            QueryBalanceRequest::decode(message.unwrap().as_slice())?,
+            request
+        );
+        let status = grpc_status(
+            trailer_value(&trailers, "grpc-status"),
+            trailer_value(&trailers, "grpc-message"),
+        );
+        assert!(matches!(
+            status,
+            Err(WebError::GrpcStatus { code: 5, message }) if message == "not found"
+        ));
+
+        assert!(grpc_status(Some("0"), None).is_ok());
+        assert!(decode_frames(&[0, 0, 0, 0, 3, 1]).is_err());
+        assert!(decode_frames(&[0, 0xff, 0xff, 0xff, 0xff, 1]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn grpc_web_urls() {
+        assert_eq!(
+            grpc_web_url("http://localhost:9090"),
+            "http://localhost:9091"
+        );
+        assert_eq!(
+            grpc_web_url("http://localhost:9090/"),
+            "http://localhost:9091"
+        );
+        assert_eq!(
+            grpc_web_url("https://juno-grpc.polkachu.com:12690"),
+            "https://juno-grpc.polkachu.com:12690"
+        );
+        assert_eq!(
+            grpc_web_url("https://grpc.osmosis.zone"),
+            "https://grpc.osmosis.zone"
+        );
+    }
+}
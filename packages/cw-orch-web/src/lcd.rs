@@ -0,0 +1,203 @@
+use base64::{
+    engine::general_purpose::{STANDARD, URL_SAFE},
+    Engine,
+};
+use cosmwasm_std::{Addr, Coin};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use crate::WebError;
+
+/// Client of the REST (LCD) endpoint of a chain
+#[derive(Clone, Debug)]
+pub struct Lcd {
+    pub url: String,
+    client: reqwest::Client,
+}
+
+#[derive(Deserialize)]
+struct LcdBalance {
+    balance: Coin,
+}
+
+#[derive(Deserialize)]
+struct LcdBalances {
+    balances: Vec<Coin>,
+}
+
+#[derive(Deserialize)]
+struct LcdData<T> {
+    data: T,
+}
+
+impl Lcd {
+    /// Client of the LCD endpoint at `url`, i.e. `https://rest.juno.example`
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into().trim_end_matches('/').to_string(),
+            client: reqwest::Client::new(),
+        }
+    }
+
+    /// Sends a GET request to `path`, i.e. `/cosmos/bank/v1beta1/balances/{address}`
+    pub async fn get<T: DeserializeOwned>(&self, path: &str) -> Result<T, WebError> {
+        let response = self
+            .client
+            .get(format!("{}{path}", self.url))
+            .send()
+            .await?;
+        if !response.status().is_success() {
+            return Err(WebError::LcdStatus {
+                path: path.to_string(),
+                status: response.status().as_u16(),
+                body: response.text().await.unwrap_or_default(),
+            });
+        }
+        let response: serde_json::Value = response.json().await?;
+        ::log::trace!("lcd_query: {} resulted in: {:?}", path, response);
+        Ok(serde_json::from_value(response)?)
+    }
+
+    /// Chain id of the node
+    pub async fn network(&self) -> Result<String, WebError> {
+        let node_info: serde_json::Value = self
+            .get("/cosmos/base/tendermint/v1beta1/node_info")
+            .await?;
+        node_network(&node_info)
+    }
+
+    /// Height of the latest block
+    pub async fn block_height(&self) -> Result<u64, WebError> {
+        let block: serde_json::Value = self
+            .get("/cosmos/base/tendermint/v1beta1/blocks/latest")
+            .await?;
+        block_height(&block)
+    }
+
+    /// Balance of `address` in `denom`
+    pub async fn balance(&self, address: &Addr, denom: &str) -> Result<Coin, WebError> {
+        let response: LcdBalance = self
+            .get(&format!(
+                "/cosmos/bank/v1beta1/balances/{address}/by_denom?denom={denom}"
+            ))
+            .await?;
+        Ok(response.balance)
+    }
+
+    /// All the balances of `address`
+    pub async fn all_balances(&self, address: &Addr) -> Result<Vec<Coin>, WebError> {
+        let response: LcdBalances = self
+            .get(&format!("/cosmos/bank/v1beta1/balances/{address}"))
+            .await?;
+        Ok(response.balances)
+    }
+
+    /// Smart query of a contract
+    pub async fn smart_query<Q: Serialize, T: DeserializeOwned>(
+        &self,
+        contract: &Addr,
+        query: &Q,
+    ) -> Result<T, WebError> {
+        let query = URL_SAFE.encode(serde_json::to_vec(query)?);
+        let response: LcdData<T> = self
+            .get(&format!(
+                "/cosmwasm/wasm/v1/contract/{contract}/smart/{query}"
+            ))
+            .await?;
+        Ok(response.data)
+    }
+
+    /// Raw query of the storage of a contract, `None` if the key isn't set
+    pub async fn raw_query(
+        &self,
+        contract: &Addr,
+        key: &[u8],
+    ) -> Result<Option<Vec<u8>>, WebError> {
+        let key = URL_SAFE.encode(key);
+        let response: LcdData<Option<String>> = self
+            .get(&format!("/cosmwasm/wasm/v1/contract/{contract}/raw/{key}"))
+            .await?;
+        raw_data(response.data)
+    }
+}
+
+/// Chain id of a `node_info` response
+fn node_network(node_info: &serde_json::Value) -> Result<String, WebError> {
+    node_info["default_node_info"]["network"]
+        .as_str()
+        .map(ToString::to_string)
+        .ok_or(WebError::StdErr("No network in the node info".to_string()))
+}
+
+/// Height of a `blocks/latest` response, the block is in `sdk_block` since SDK 0.47
+fn block_height(block: &serde_json::Value) -> Result<u64, WebError> {
+    let block = if block["sdk_block"].is_null() {
+        &block["block"]
+    } else {
+        &block["sdk_block"]
+    };
+    Ok(block["header"]["height"]
+        .as_str()
+        .ok_or(WebError::StdErr("No block returned".to_string()))?
+        .parse()?)
+}
+
+/// Value of a raw query response, `None` if the key isn't set
+fn raw_data(data: Option<String>) -> Result<Option<Vec<u8>>, WebError> {
+    match data.filter(|data| !data.is_empty()) {
+        Some(data) => Ok(Some(STANDARD.decode(data)?)),
+        None => Ok(None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::coin;
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn parses_the_node_responses() -> Result<(), WebError> {
+        let node_info =
+            json!({ "default_node_info": { "network": "juno-1", "version": "0.38.0" } });
+        assert_eq!(node_network(&node_info)?, "juno-1");
+        assert!(node_network(&json!({ "application_version": {} })).is_err());
+
+        // SDK 0.47+ nodes return the block in `sdk_block`, the older ones only in `block`
+        let block = json!({
+            "block": { "header": { "height": "100" } },
+            "sdk_block": { "header": { "height": "101" } }
+        });
+        assert_eq!(block_height(&block)?, 101);
+        let block = json!({ "block": { "header": { "height": "100" } } });
+        assert_eq!(block_height(&block)?, 100);
+        assert!(block_height(&json!({ "block": { "header": { "height": "a" } } })).is_err());
+        assert!(block_height(&json!({})).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn parses_the_query_responses() -> Result<(), WebError> {
+        let balance: LcdBalance =
+            serde_json::from_value(json!({ "balance": { "denom": "ujuno", "amount": "10" } }))?;
+        assert_eq!(balance.balance, coin(10, "ujuno"));
+        let balances: LcdBalances = serde_json::from_value(json!({
+            "balances": [{ "denom": "ujuno", "amount": "10" }],
+            "pagination": { "next_key": null, "total": "1" }
+        }))?;
+        assert_eq!(balances.balances, vec![coin(10, "ujuno")]);
+
+        let count: LcdData<serde_json::Value> =
+            serde_json::from_value(json!({ "data": { "count": 3 } }))?;
+        assert_eq!(count.data, json!({ "count": 3 }));
+
+        assert_eq!(
+            raw_data(Some(STANDARD.encode(b"value")))?,
+            Some(b"value".to_vec())
+        );
+        assert_eq!(raw_data(Some(String::new()))?, None);
+        assert_eq!(raw_data(None)?, None);
+        assert!(raw_data(Some("not base64!".to_string())).is_err());
+        Ok(())
+    }
+}
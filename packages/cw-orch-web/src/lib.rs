@@ -0,0 +1,33 @@
+//! Query-only cw-orchestrator client for browsers.
+//!
+//! The daemon connects to the nodes with native gRPC (HTTP/2, tokio), which browsers can't use.
+//! This crate queries the chains with gRPC-web ([`GrpcWeb`]) or their REST endpoint ([`Lcd`]) over `fetch`,
+//! and compiles to `wasm32-unknown-unknown`, so that frontend dashboards can reuse the message types of the contract interfaces
+//! and the chain definitions of `cw-orch-networks`.
+//!
+//! The contract interfaces run their async queries on a [`WebChain`], the raw queries go through a [`WebQuerier`].
+//!
+//! ```rust,no_run
+//! use cosmwasm_std::Addr;
+//! use cw_orch_core::environment::ChainInfoOwned;
+//! use cw_orch_web::WebQuerier;
+//!
+//! # async fn dashboard(chain: ChainInfoOwned) -> Result<(), cw_orch_web::WebError> {
+//! let querier = WebQuerier::connect(&chain).await?;
+//! let count: serde_json::Value = querier
+//!     .smart_query(&Addr::unchecked("juno1..."), &serde_json::json!({ "get_count": {} }))
+//!     .await?;
+//! # Ok(())
+//! # }
+//! ```
+mod chain;
+mod error;
+mod grpc_web;
+mod lcd;
+mod querier;
+
+pub use chain::{WebChain, WebState};
+pub use error::WebError;
+pub use grpc_web::{grpc_web_url, GrpcWeb};
+pub use lcd::Lcd;
+pub use querier::WebQuerier;
@@ -0,0 +1,167 @@
+use cosmwasm_std::{Addr, Coin};
+use cw_orch_core::environment::ChainInfoOwned;
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::{grpc_web_url, GrpcWeb, Lcd, WebError};
+
+/// Query client selected by [`WebQuerier::connect`]
+#[derive(Clone, Debug)]
+pub enum WebQuerier {
+    /// gRPC-web, over HTTP/1.1
+    GrpcWeb(GrpcWeb),
+    /// REST (LCD) endpoint
+    Lcd(Lcd),
+}
+
+impl WebQuerier {
+    /// Connects to the gRPC-web endpoints of the chain (see [`grpc_web_url`]), falling back to its LCD endpoint.
+    /// The endpoints are only used if they are connected to the expected chain.
+    pub async fn connect(chain_info: &ChainInfoOwned) -> Result<Self, WebError> {
+        for url in chain_info.grpc_urls.iter().map(|url| grpc_web_url(url)) {
+            let grpc_web = GrpcWeb::new(&url);
+            match grpc_web.network().await {
+                Ok(network) if network == chain_info.chain_id => {
+                    return Ok(Self::GrpcWeb(grpc_web))
+                }
+                Ok(network) => log::warn!(
+                    "Network mismatch on gRPC-web endpoint {}: {} != {}",
+                    url,
+                    network,
+                    chain_info.chain_id
+                ),
+                Err(e) => log::warn!("Cannot use gRPC-web endpoint: {}, {}", url, e),
+            }
+        }
+        if let Some(lcd_url) = &chain_info.lcd_url {
+            let lcd = Lcd::new(lcd_url);
+            match lcd.network().await {
+                Ok(network) if network == chain_info.chain_id => return Ok(Self::Lcd(lcd)),
+                Ok(network) => log::warn!(
+                    "Network mismatch on LCD endpoint {}: {} != {}",
+                    lcd_url,
+                    network,
+                    chain_info.chain_id
+                ),
+                Err(e) => log::warn!("Cannot use LCD endpoint: {}, {}", lcd_url, e),
+            }
+        }
+        Err(WebError::CannotConnect(chain_info.chain_id.clone()))
+    }
+
+    /// Height of the latest block
+    pub async fn block_height(&self) -> Result<u64, WebError> {
+        match self {
+            Self::GrpcWeb(grpc_web) => grpc_web.block_height().await,
+            Self::Lcd(lcd) => lcd.block_height().await,
+        }
+    }
+
+    /// Balance of `address` in `denom`
+    pub async fn balance(&self, address: &Addr, denom: &str) -> Result<Coin, WebError> {
+        match self {
+            Self::GrpcWeb(grpc_web) => grpc_web.balance(address, denom).await,
+            Self::Lcd(lcd) => lcd.balance(address, denom).await,
+        }
+    }
+
+    /// Smart query of a contract, with the `QueryMsg` of its interface
+    pub async fn smart_query<Q: Serialize, T: DeserializeOwned>(
+        &self,
+        contract: &Addr,
+        query: &Q,
+    ) -> Result<T, WebError> {
+        match self {
+            Self::GrpcWeb(grpc_web) => grpc_web.smart_query(contract, query).await,
+            Self::Lcd(lcd) => lcd.smart_query(contract, query).await,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{Read, Write},
+        net::TcpListener,
+    };
+
+    use cw_orch_core::{contract::Contract, CwEnvError};
+    use serde_json::{json, Value};
+
+    use super::*;
+    use crate::WebChain;
+
+    // Nothing listens on this port, the connections are refused right away
+    const UNREACHABLE: &str = "http://127.0.0.1:1";
+
+    /// Serves the node info and latest block of `network` and the count of a counter contract
+    /// on a local LCD endpoint, returns its url
+    fn serve_lcd(network: &'static str) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut request = [0; 4096];
+                let len = stream.read(&mut request).unwrap();
+                let request = String::from_utf8_lossy(&request[..len]);
+                let path = request.split_whitespace().nth(1).unwrap_or_default();
+                let body = if path == "/cosmos/base/tendermint/v1beta1/node_info" {
+                    json!({ "default_node_info": { "network": network } })
+                } else if path.starts_with("/cosmwasm/wasm/v1/contract/juno1counter/smart/") {
+                    json!({ "data": { "count": 3 } })
+                } else {
+                    json!({ "sdk_block": { "header": { "height": "42" } } })
+                }
+                .to_string();
+                write!(
+                    stream,
+                    "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                )
+                .unwrap();
+            }
+        });
+        url
+    }
+
+    fn chain(lcd_url: Option<String>) -> ChainInfoOwned {
+        ChainInfoOwned {
+            chain_id: "juno-1".to_string(),
+            grpc_urls: vec![UNREACHABLE.to_string()],
+            lcd_url,
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn falls_back_to_the_lcd() -> Result<(), WebError> {
+        let querier = WebQuerier::connect(&chain(Some(serve_lcd("juno-1")))).await?;
+        assert!(matches!(querier, WebQuerier::Lcd(_)));
+        assert_eq!(querier.block_height().await?, 42);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn queries_the_contract_interfaces() -> Result<(), CwEnvError> {
+        let chain = WebChain::connect(&chain(Some(serve_lcd("juno-1")))).await?;
+        let counter = Contract::new("counter", chain);
+        assert!(counter.async_query::<_, Value>(&json!({})).await.is_err());
+
+        counter.set_address(&Addr::unchecked("juno1counter"));
+        let count: Value = counter.async_query(&json!({ "get_count": {} })).await?;
+        assert_eq!(count, json!({ "count": 3 }));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn rejects_the_endpoints_of_other_chains() {
+        let err = WebQuerier::connect(&chain(Some(serve_lcd("uni-6"))))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, WebError::CannotConnect(chain_id) if chain_id == "juno-1"));
+
+        let err = WebQuerier::connect(&chain(None)).await.unwrap_err();
+        assert!(matches!(err, WebError::CannotConnect(_)));
+    }
+}
@@ -1,10 +1,18 @@
 use cw_orch::anyhow;
-use std::{io::Cursor, path::PathBuf, str::FromStr};
+use std::{
+    io::Cursor,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
 
 pub const CW_PLUS_REPO_OWNER: &str = "CosmWasm";
 pub const CW_PLUS_REPO_NAME: &str = "cw-plus";
 pub const CW_PLUS_RELEASE_TAG: &str = "v2.0.0";
 
+pub const CW_NFTS_REPO_OWNER: &str = "public-awesome";
+pub const CW_NFTS_REPO_NAME: &str = "cw-nfts";
+pub const CW_NFTS_RELEASE_TAG: &str = "v0.18.0";
+
 pub const ALL_CONTRACTS: &[&str] = &[
     "cw1_subkeys",
     "cw1_whitelist",
@@ -24,14 +32,40 @@ async fn main() -> anyhow::Result<()> {
     // We create the artifacts directory if non-existent
     std::fs::create_dir_all(&artifacts_dir)?;
 
+    download_release(
+        &artifacts_dir,
+        CW_PLUS_REPO_OWNER,
+        CW_PLUS_REPO_NAME,
+        CW_PLUS_RELEASE_TAG,
+        ALL_CONTRACTS,
+    )
+    .await?;
+    download_release(
+        &artifacts_dir,
+        CW_NFTS_REPO_OWNER,
+        CW_NFTS_REPO_NAME,
+        CW_NFTS_RELEASE_TAG,
+        &["cw721_base"],
+    )
+    .await?;
+    Ok(())
+}
+
+async fn download_release(
+    artifacts_dir: &Path,
+    owner: &str,
+    repo: &str,
+    tag: &str,
+    contracts: &[&str],
+) -> anyhow::Result<()> {
     // We get the release, common for all artifacts
     let release = octocrab::instance()
-        .repos(CW_PLUS_REPO_OWNER, CW_PLUS_REPO_NAME)
+        .repos(owner, repo)
         .releases()
-        .get_by_tag(CW_PLUS_RELEASE_TAG)
+        .get_by_tag(tag)
         .await?;
 
-    for contract in ALL_CONTRACTS {
+    for contract in contracts {
         let release_file_name = format!("{contract}.wasm");
         let file_name = artifacts_dir.join(&release_file_name);
 
@@ -8,6 +8,7 @@ use cw_plus_orch::cw3_fixed_multisig::Cw3FixedMultisig;
 use cw_plus_orch::cw3_flex_multisig::Cw3FlexMultisig;
 use cw_plus_orch::cw4_group::Cw4Group;
 use cw_plus_orch::cw4_stake::Cw4Stake;
+use cw_plus_orch::cw721_base::Cw721Base;
 
 fn main() -> cw_orch::anyhow::Result<()> {
     dotenv::dotenv()?;
@@ -23,6 +24,7 @@ fn main() -> cw_orch::anyhow::Result<()> {
     Cw4Stake::new("cw4_stake", daemon.clone()).upload()?;
     Cw20Base::new("cw20_base", daemon.clone()).upload()?;
     Cw20Ics20::new("cw20_ics20", daemon.clone()).upload()?;
+    Cw721Base::new("cw721_base", daemon.clone()).upload()?;
 
     Ok(())
 }
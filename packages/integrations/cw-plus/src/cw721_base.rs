@@ -0,0 +1,248 @@
+//! Interface of the cw721-base NFT contract of [cw-nfts](https://github.com/public-awesome/cw-nfts).
+//!
+//! The published cw721-base crates don't target CosmWasm 2 yet, so the messages are mirrored here.
+//! The interface only uploads the released wasm artifact, it has no [`Mock`](cw_orch::mock::Mock) wrapper.
+//! The ownership of the minter follows cw-ownable, see [`cw_orch::core::contract::ownership`].
+use cosmwasm_std::{Binary, Coin};
+use cw_orch::core::contract::{Ownership, OwnershipAction};
+use cw_orch::interface;
+use cw_orch::prelude::*;
+use cw_utils::Expiration;
+
+/// Metadata extension of the tokens, cw721-base doesn't store any
+pub type Extension = Option<Empty>;
+
+#[cosmwasm_schema::cw_serde]
+pub struct InstantiateMsg {
+    /// Name of the NFT contract
+    pub name: String,
+    /// Symbol of the NFT contract
+    pub symbol: String,
+    /// The minter is the only one who can create new NFTs, defaults to the sender
+    pub minter: Option<String>,
+    /// Address receiving the funds withdrawn from the contract with [`ExecuteMsg::WithdrawFunds`]
+    pub withdraw_address: Option<String>,
+}
+
+#[cosmwasm_schema::cw_serde]
+#[derive(cw_orch::ExecuteFns)]
+pub enum ExecuteMsg {
+    /// Transfer is a base message to move a token to another account without triggering actions
+    TransferNft { recipient: String, token_id: String },
+    /// Send is a base message to transfer a token to a contract and trigger an action
+    /// on the receiving contract.
+    SendNft {
+        contract: String,
+        token_id: String,
+        msg: Binary,
+    },
+    /// Allows operator to transfer / send the token from the owner's account.
+    /// If expiration is set, then this allowance has a time/height limit
+    Approve {
+        spender: String,
+        token_id: String,
+        expires: Option<Expiration>,
+    },
+    /// Remove previously granted Approval
+    Revoke { spender: String, token_id: String },
+    /// Allows operator to transfer / send any token from the owner's account.
+    /// If expiration is set, then this allowance has a time/height limit
+    ApproveAll {
+        operator: String,
+        expires: Option<Expiration>,
+    },
+    /// Remove previously granted ApproveAll permission
+    RevokeAll { operator: String },
+    /// Mint a new NFT, can only be called by the contract minter
+    Mint {
+        /// Unique ID of the NFT
+        token_id: String,
+        /// The owner of the newly minted NFT
+        owner: String,
+        /// Universal resource identifier for this NFT
+        token_uri: Option<String>,
+        /// Any custom extension used by this contract
+        extension: Extension,
+    },
+    /// Burn an NFT the sender has access to
+    Burn { token_id: String },
+    /// Extension msg
+    Extension { msg: Empty },
+    /// Sets the address receiving the funds withdrawn from the contract, only callable by the minter
+    SetWithdrawAddress { address: String },
+    /// Removes the withdraw address, so fees are sent to the contract, only callable by the minter
+    RemoveWithdrawAddress {},
+    /// Withdraw from the contract to the given address. Anyone can call this,
+    /// which is okay since withdraw address has been set by the minter
+    WithdrawFunds { amount: Coin },
+    /// Update the minter, see [`OwnershipAction`]
+    UpdateOwnership(OwnershipAction),
+}
+
+#[cosmwasm_schema::cw_serde]
+#[derive(cosmwasm_schema::QueryResponses, cw_orch::QueryFns)]
+pub enum QueryMsg {
+    /// Return the owner of the given token, error if token does not exist
+    #[returns(OwnerOfResponse)]
+    OwnerOf {
+        token_id: String,
+        /// unset or false will filter out expired approvals, you must set to true to see them
+        include_expired: Option<bool>,
+    },
+    /// Return operator that can access all of the owner's tokens.
+    #[returns(ApprovalResponse)]
+    Approval {
+        token_id: String,
+        spender: String,
+        include_expired: Option<bool>,
+    },
+    /// Return approvals that a token has
+    #[returns(ApprovalsResponse)]
+    Approvals {
+        token_id: String,
+        include_expired: Option<bool>,
+    },
+    /// Return approval of a given operator for all tokens of an owner, error if not set
+    #[returns(OperatorResponse)]
+    Operator {
+        owner: String,
+        operator: String,
+        include_expired: Option<bool>,
+    },
+    /// List all operators that can access all of the owner's tokens
+    #[returns(OperatorsResponse)]
+    AllOperators {
+        owner: String,
+        /// unset or false will filter out expired items, you must set to true to see them
+        include_expired: Option<bool>,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Total number of tokens issued
+    #[returns(NumTokensResponse)]
+    NumTokens {},
+    /// Name and symbol of the contract
+    #[returns(ContractInfoResponse)]
+    ContractInfo {},
+    /// Metadata of a token
+    #[returns(NftInfoResponse)]
+    NftInfo { token_id: String },
+    /// Owner and metadata of a token
+    #[returns(AllNftInfoResponse)]
+    AllNftInfo {
+        token_id: String,
+        /// unset or false will filter out expired approvals, you must set to true to see them
+        include_expired: Option<bool>,
+    },
+    /// Tokens owned by the given address, [] if unset
+    #[returns(TokensResponse)]
+    Tokens {
+        owner: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Requires pagination. Lists all token_ids controlled by the contract
+    #[returns(TokensResponse)]
+    AllTokens {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Return the minter
+    #[returns(MinterResponse)]
+    Minter {},
+    /// Return the minter as a cw-ownable ownership
+    #[returns(Ownership)]
+    Ownership {},
+    /// Extension query
+    #[returns(())]
+    Extension { msg: Empty },
+    /// Return the withdraw address, if set
+    #[returns(Option<String>)]
+    GetWithdrawAddress {},
+}
+
+#[cosmwasm_schema::cw_serde]
+pub struct Approval {
+    /// Account that can transfer/send the token
+    pub spender: String,
+    /// When the Approval expires (maybe Expiration::never)
+    pub expires: Expiration,
+}
+
+#[cosmwasm_schema::cw_serde]
+pub struct OwnerOfResponse {
+    /// Owner of the token
+    pub owner: String,
+    /// If set this address is approved to transfer/send the token as well
+    pub approvals: Vec<Approval>,
+}
+
+#[cosmwasm_schema::cw_serde]
+pub struct ApprovalResponse {
+    pub approval: Approval,
+}
+
+#[cosmwasm_schema::cw_serde]
+pub struct ApprovalsResponse {
+    pub approvals: Vec<Approval>,
+}
+
+#[cosmwasm_schema::cw_serde]
+pub struct OperatorResponse {
+    pub approval: Approval,
+}
+
+#[cosmwasm_schema::cw_serde]
+pub struct OperatorsResponse {
+    pub operators: Vec<Approval>,
+}
+
+#[cosmwasm_schema::cw_serde]
+pub struct NumTokensResponse {
+    pub count: u64,
+}
+
+#[cosmwasm_schema::cw_serde]
+pub struct ContractInfoResponse {
+    pub name: String,
+    pub symbol: String,
+}
+
+#[cosmwasm_schema::cw_serde]
+pub struct NftInfoResponse {
+    /// Universal resource identifier for this NFT
+    pub token_uri: Option<String>,
+    /// You can add any custom metadata here when you extend cw721-base
+    pub extension: Extension,
+}
+
+#[cosmwasm_schema::cw_serde]
+pub struct AllNftInfoResponse {
+    /// Who can transfer the token
+    pub access: OwnerOfResponse,
+    /// Data on the token itself,
+    pub info: NftInfoResponse,
+}
+
+#[cosmwasm_schema::cw_serde]
+pub struct TokensResponse {
+    /// Contains all token_ids in lexicographical ordering
+    pub tokens: Vec<String>,
+}
+
+#[cosmwasm_schema::cw_serde]
+pub struct MinterResponse {
+    pub minter: Option<String>,
+}
+
+#[interface(InstantiateMsg, ExecuteMsg, QueryMsg, Empty)]
+pub struct Cw721Base;
+
+impl<Chain: CwEnv> Uploadable for Cw721Base<Chain> {
+    // Return the path to the wasm file
+    fn wasm(_chain: &ChainInfoOwned) -> WasmPath {
+        artifacts_dir_from_workspace!()
+            .find_wasm_path("cw721_base")
+            .unwrap()
+    }
+}
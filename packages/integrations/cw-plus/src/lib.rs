@@ -6,6 +6,8 @@ pub mod cw3_fixed_multisig;
 pub mod cw3_flex_multisig;
 pub mod cw4_group;
 pub mod cw4_stake;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod cw721_base;
 
 pub const WASM_RELEASE_TAG: &str = "v1.1.2";
 pub const WASM_REPO_OWNER: &str = "cosmwasm";
@@ -438,3 +438,39 @@ mod cw20_ics {
         assert_eq!(balance[1].amount, Uint128::new(200));
     }
 }
+
+mod cw721_base {
+    use cosmwasm_std::to_json_string;
+    use cw_orch::core::contract::OwnershipAction;
+    use cw_plus_orch::cw721_base::{ExecuteMsg, QueryMsg};
+
+    // The interface runs the released wasm on chain, so we check that the messages match the cw721 JSON API
+    #[test]
+    fn check_messages() {
+        let mint = ExecuteMsg::Mint {
+            token_id: "1".to_string(),
+            owner: "owner".to_string(),
+            token_uri: None,
+            extension: None,
+        };
+        assert_eq!(
+            to_json_string(&mint).unwrap(),
+            r#"{"mint":{"token_id":"1","owner":"owner","token_uri":null,"extension":null}}"#
+        );
+        assert_eq!(
+            to_json_string(&ExecuteMsg::UpdateOwnership(
+                OwnershipAction::AcceptOwnership
+            ))
+            .unwrap(),
+            r#"{"update_ownership":"accept_ownership"}"#
+        );
+        assert_eq!(
+            to_json_string(&QueryMsg::OwnerOf {
+                token_id: "1".to_string(),
+                include_expired: None
+            })
+            .unwrap(),
+            r#"{"owner_of":{"token_id":"1","include_expired":null}}"#
+        );
+    }
+}
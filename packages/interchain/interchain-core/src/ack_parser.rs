@@ -111,11 +111,28 @@ impl IbcAckParser {
         Err(decode_ack_error(ack))
     }
 
-    /// Verifies if the given ack is a standard acknowledgement type
+    /// Verifies if the given ack is wrapped by the ICS-29 fee middleware and returns the incentivized acknowledgement if it is
+    ///
+    /// Returns an error if there was an error in the parsing process
+    pub fn fee_ack(ack: &Binary) -> Result<IncentivizedAck, InterchainError> {
+        if let Ok(decoded_fee_ack) = from_json::<IncentivizedAck>(ack) {
+            log::debug!(
+                "Decoded ack using ICS-29, relayed by {}",
+                decoded_fee_ack.forward_relayer_address
+            );
+            return Ok(decoded_fee_ack);
+        }
+        Err(decode_ack_error(ack))
+    }
+
+    /// Verifies if the given ack is a standard acknowledgement type.
+    /// Acks wrapped by the fee middleware of fee-enabled channels are unwrapped first.
     ///
     /// Returns an error if there was an error in the parsing process
     pub fn any_standard_app_result(ack: &Binary) -> Result<IbcAppResult, InterchainError> {
-        if let Ok(ack) = IbcAckParser::polytone_ack(ack) {
+        if let Ok(fee_ack) = IbcAckParser::fee_ack(ack) {
+            Self::any_standard_app_result(&fee_ack.app_acknowledgement)
+        } else if let Ok(ack) = IbcAckParser::polytone_ack(ack) {
             Ok(IbcAppResult::Polytone(ack))
         } else if IbcAckParser::ics20_ack(ack).is_ok() {
             Ok(IbcAppResult::Ics20)
@@ -141,6 +158,12 @@ impl IbcAckParser {
         ack: &Binary,
         parsing_func: fn(&Binary) -> Result<CustomResult, InterchainError>,
     ) -> Result<IbcAppResult<CustomResult>, InterchainError> {
+        if let Ok(fee_ack) = IbcAckParser::fee_ack(ack) {
+            return Self::any_standard_app_result_with_custom(
+                &fee_ack.app_acknowledgement,
+                parsing_func,
+            );
+        }
         parsing_func(ack)
             .map(IbcAppResult::Custom)
             .or_else(|_| Self::any_standard_app_result(ack).map(|ack| ack.into_custom()))
@@ -200,6 +223,18 @@ pub struct IbcHooksAck {
     ibc_ack: Binary,
 }
 
+/// This is the acknowledgement of the channels using the ICS-29 fee middleware, wrapping the ack of the underlying app
+/// https://github.com/cosmos/ibc-go/blob/v8.4.0/proto/ibc/applications/fee/v1/ack.proto
+#[cw_serde]
+pub struct IncentivizedAck {
+    /// Ack of the underlying app
+    pub app_acknowledgement: Binary,
+    /// Relayer address on the destination chain, paid the recv fee on the source chain
+    pub forward_relayer_address: String,
+    /// Whether the underlying app succeeded
+    pub underlying_app_success: bool,
+}
+
 pub mod polytone_callback {
     use super::*;
 
@@ -268,4 +303,21 @@ mod test {
         IbcAckParser::ics20_ack(&success_ack)?;
         Ok(())
     }
+
+    #[test]
+    fn fee_wrapped_ics20_ack_test() -> cw_orch::anyhow::Result<()> {
+        let fee_ack = Binary::new(
+            br#"{"app_acknowledgement":"AQ==","forward_relayer_address":"cosmos1relayer","underlying_app_success":true}"#.to_vec(),
+        );
+
+        assert_eq!(
+            IbcAckParser::fee_ack(&fee_ack)?.forward_relayer_address,
+            "cosmos1relayer"
+        );
+        assert_eq!(
+            IbcAckParser::any_standard_app_result(&fee_ack)?,
+            crate::IbcAppResult::Ics20
+        );
+        Ok(())
+    }
 }
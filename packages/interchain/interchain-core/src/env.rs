@@ -1,6 +1,6 @@
 //! This module contains the trait definition for an interchain analysis environment
 
-use cosmwasm_std::IbcOrder;
+use cosmwasm_std::{Checksum, IbcOrder};
 use cw_orch_core::{
     contract::interface_traits::ContractInstance,
     environment::{CwEnv, Environment, IndexResponse, TxHandler},
@@ -17,6 +17,7 @@ use crate::{
     results::{
         ChannelCreationResult, ChannelCreationTransactionsResult, InternalChannelCreationResult,
     },
+    topology::{Channels, Topology},
     IbcQueryHandler, InterchainError,
 };
 
@@ -318,6 +319,28 @@ pub trait InterchainEnv<Chain: IbcQueryHandler>: Clone {
         Ok(channel_creation)
     }
 
+    /// Creates all the channels of a [`Topology`], in order.
+    /// The returned [`Channels`] retrieve the channels by chains and port, or by name, and resolve multi-hop routes.
+    /// This shouldn't need to be re-implemented.
+    fn create_topology(
+        &self,
+        topology: &Topology,
+    ) -> Result<Channels<<Chain as IbcQueryHandler>::Handler>, InterchainError> {
+        let mut channels = Channels::default();
+        for spec in topology.channels.iter().cloned() {
+            let creation = self.create_channel(
+                &spec.src_chain,
+                &spec.dst_chain,
+                &spec.src_port,
+                &spec.dst_port,
+                &spec.version,
+                spec.order,
+            )?;
+            channels.insert(spec.name, creation.interchain_channel);
+        }
+        Ok(channels)
+    }
+
     /// Follows every IBC packets sent out during a transaction
     /// This returns a packet analysis.
     ///
@@ -452,6 +475,12 @@ pub trait InterchainEnv<Chain: IbcQueryHandler>: Clone {
         dst_chain: ChainId,
         sequence: Sequence,
     ) -> Result<SinglePacketFlow<Chain>, Self::Error>;
+
+    /// Computes the denom of `base_denom` after an ICS20 transfer received on `dst_channel` (on the `transfer` port).
+    /// This allows checking balances after a transfer without hard-coding `ibc/...` hashes.
+    fn ibc_denom(&self, dst_channel: &ChannelId, base_denom: &str) -> String {
+        ibc_denom(&PortId::transfer(), dst_channel, base_denom)
+    }
 }
 
 /// format the port for a contract
@@ -460,3 +489,27 @@ pub fn contract_port<Chain: CwEnv>(contract: &dyn ContractInstance<Chain>) -> Po
         .parse()
         .unwrap()
 }
+
+/// Computes the denom of `base_denom` after an ICS20 transfer received on `dst_port`/`dst_channel`
+/// `base_denom` can already be an IBC trace (`transfer/channel-0/uatom`) for multi-hop transfers
+pub fn ibc_denom(dst_port: &PortId, dst_channel: &ChannelId, base_denom: &str) -> String {
+    let trace = format!("{dst_port}/{dst_channel}/{base_denom}");
+    format!(
+        "ibc/{}",
+        Checksum::generate(trace.as_bytes()).to_hex().to_uppercase()
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn computes_ibc_denom() {
+        // ATOM on Osmosis
+        assert_eq!(
+            ibc_denom(&PortId::transfer(), &ChannelId::new(0), "uatom"),
+            "ibc/27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2"
+        );
+    }
+}
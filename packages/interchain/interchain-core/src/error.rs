@@ -61,4 +61,7 @@ pub enum InterchainError {
 
     #[error("Callback Failure during acknowledgment : {0:?}")]
     CallbackError(String),
+
+    #[error("Malformed ICS-29 fee event `{0}`")]
+    FeeEventParsing(String),
 }
@@ -0,0 +1,185 @@
+//! Relayer fees of the channels using the ICS-29 fee middleware.
+//!
+//! When a packet is incentivized, the source chain emits an `incentivized_ibc_packet` event with the escrowed fees.
+//! Once the packet is acknowledged (or times out), the fees are paid to the relayers in `distribute_fee` events.
+//! Those events are retrieved from the results of following the packets, e.g. with [`NestedPacketsFlow::incentivized_packets`].
+use std::str::FromStr;
+
+use cosmwasm_std::{Coin, Event};
+use cw_orch_core::environment::{CwEnv, IndexResponse};
+
+use crate::{InterchainError, NestedPacketsFlow, SinglePacketFlow};
+
+/// Event emitted when fees are escrowed for a packet
+pub const INCENTIVIZED_PACKET_EVENT: &str = "incentivized_ibc_packet";
+/// Event emitted when escrowed fees are paid to a relayer (or refunded)
+pub const DISTRIBUTE_FEE_EVENT: &str = "distribute_fee";
+
+/// Fees escrowed for relaying a packet
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct PacketFees {
+    /// Paid to the relayer submitting the packet on the destination chain
+    pub recv_fee: Vec<Coin>,
+    /// Paid to the relayer submitting the acknowledgement on the source chain
+    pub ack_fee: Vec<Coin>,
+    /// Paid to the relayer submitting the timeout on the source chain
+    pub timeout_fee: Vec<Coin>,
+}
+
+/// Packet for which fees were escrowed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IncentivizedPacket {
+    /// Source port of the packet
+    pub port_id: String,
+    /// Source channel of the packet
+    pub channel_id: String,
+    /// Sequence of the packet
+    pub sequence: u64,
+    /// Total fees escrowed for the packet
+    pub fees: PacketFees,
+}
+
+/// Fee paid out of the escrow of a packet
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DistributedFee {
+    /// Relayer (or refunded payer) receiving the fee
+    pub receiver: String,
+    /// Amount paid
+    pub fee: Vec<Coin>,
+}
+
+impl IncentivizedPacket {
+    /// Parses an `incentivized_ibc_packet` event
+    pub fn from_event(event: &Event) -> Result<Self, InterchainError> {
+        Ok(Self {
+            port_id: attribute(event, "port_id")?,
+            channel_id: attribute(event, "channel_id")?,
+            sequence: attribute(event, "packet_sequence")?
+                .parse()
+                .map_err(|_| InterchainError::FeeEventParsing(event.ty.clone()))?,
+            fees: PacketFees {
+                recv_fee: parse_coins(event, "recv_fee")?,
+                ack_fee: parse_coins(event, "ack_fee")?,
+                timeout_fee: parse_coins(event, "timeout_fee")?,
+            },
+        })
+    }
+}
+
+impl DistributedFee {
+    /// Parses a `distribute_fee` event
+    pub fn from_event(event: &Event) -> Result<Self, InterchainError> {
+        Ok(Self {
+            receiver: attribute(event, "receiver")?,
+            fee: parse_coins(event, "fee")?,
+        })
+    }
+}
+
+/// Packets incentivized in the `events`, in order
+pub fn incentivized_packets(events: &[Event]) -> Result<Vec<IncentivizedPacket>, InterchainError> {
+    events
+        .iter()
+        .filter(|e| e.ty == INCENTIVIZED_PACKET_EVENT)
+        .map(IncentivizedPacket::from_event)
+        .collect()
+}
+
+/// Fees distributed in the `events`, in order
+pub fn distributed_fees(events: &[Event]) -> Result<Vec<DistributedFee>, InterchainError> {
+    events
+        .iter()
+        .filter(|e| e.ty == DISTRIBUTE_FEE_EVENT)
+        .map(DistributedFee::from_event)
+        .collect()
+}
+
+impl<Chain: CwEnv> SinglePacketFlow<Chain> {
+    /// Fees escrowed for the packet and for the packets sent along with it
+    pub fn incentivized_packets(&self) -> Result<Vec<IncentivizedPacket>, InterchainError> {
+        incentivized_packets(&self.events())
+    }
+
+    /// Fees paid to the relayers of the packet
+    pub fn distributed_fees(&self) -> Result<Vec<DistributedFee>, InterchainError> {
+        distributed_fees(&self.events())
+    }
+}
+
+impl<Chain: CwEnv> NestedPacketsFlow<Chain> {
+    /// Fees escrowed for all the packets of the flow, including the nested ones
+    pub fn incentivized_packets(&self) -> Result<Vec<IncentivizedPacket>, InterchainError> {
+        incentivized_packets(&self.events())
+    }
+
+    /// Fees paid to the relayers of all the packets of the flow, including the nested ones
+    pub fn distributed_fees(&self) -> Result<Vec<DistributedFee>, InterchainError> {
+        distributed_fees(&self.events())
+    }
+}
+
+fn attribute(event: &Event, key: &str) -> Result<String, InterchainError> {
+    event
+        .attributes
+        .iter()
+        .find(|attr| attr.key == key)
+        .map(|attr| attr.value.clone())
+        .ok_or_else(|| InterchainError::FeeEventParsing(event.ty.clone()))
+}
+
+/// Fee amounts are formatted as SDK coins, e.g. `100uatom,20uosmo`
+fn parse_coins(event: &Event, key: &str) -> Result<Vec<Coin>, InterchainError> {
+    attribute(event, key)?
+        .split(',')
+        .filter(|coin| !coin.is_empty())
+        .map(|coin| {
+            Coin::from_str(coin).map_err(|_| InterchainError::FeeEventParsing(event.ty.clone()))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use cosmwasm_std::coin;
+
+    use super::*;
+
+    #[test]
+    fn parse_fee_events() -> Result<(), InterchainError> {
+        let events = vec![
+            Event::new("send_packet").add_attribute("packet_sequence", "4"),
+            Event::new(INCENTIVIZED_PACKET_EVENT)
+                .add_attribute("port_id", "transfer")
+                .add_attribute("channel_id", "channel-0")
+                .add_attribute("packet_sequence", "4")
+                .add_attribute("recv_fee", "100uatom,20uosmo")
+                .add_attribute("ack_fee", "50uatom")
+                .add_attribute("timeout_fee", ""),
+            Event::new(DISTRIBUTE_FEE_EVENT)
+                .add_attribute("receiver", "cosmos1relayer")
+                .add_attribute("fee", "50uatom"),
+        ];
+
+        assert_eq!(
+            incentivized_packets(&events)?,
+            vec![IncentivizedPacket {
+                port_id: "transfer".to_string(),
+                channel_id: "channel-0".to_string(),
+                sequence: 4,
+                fees: PacketFees {
+                    recv_fee: vec![coin(100, "uatom"), coin(20, "uosmo")],
+                    ack_fee: vec![coin(50, "uatom")],
+                    timeout_fee: vec![],
+                },
+            }]
+        );
+        assert_eq!(
+            distributed_fees(&events)?,
+            vec![DistributedFee {
+                receiver: "cosmos1relayer".to_string(),
+                fee: vec![coin(50, "uatom")],
+            }]
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,114 @@
+//! Types of the Neutron interchain queries (ICQ), shared by the interchain environments.
+//!
+//! Contracts register interchain queries on Neutron, a relayer submits their results and the chain calls the contract back through `sudo`.
+//! The mock environment simulates the registration and the submission of results, the daemon environment awaits the submissions of the relayer.
+use cosmwasm_std::Binary;
+use serde::{Deserialize, Serialize};
+
+/// Key of a KV interchain query: a raw key in a store of the remote chain
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KvKey {
+    /// Store of the remote chain (e.g. `bank`)
+    pub path: String,
+    /// Key in the store
+    pub key: Binary,
+}
+
+/// Value of a key read by a KV interchain query
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StorageValue {
+    /// Store of the remote chain the value was read from
+    pub storage_prefix: String,
+    /// Key in the store
+    pub key: Binary,
+    /// Raw value
+    pub value: Binary,
+}
+
+/// What an interchain query reads on the remote chain
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InterchainQueryKind {
+    /// Values of raw keys, updated every `update_period` blocks
+    Kv(Vec<KvKey>),
+    /// Transactions matching a filter
+    Tx {
+        /// JSON filter of the transactions, as registered by the contract
+        transactions_filter: String,
+    },
+}
+
+/// Interchain query registered on Neutron
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RegisteredQuery {
+    /// Id of the query
+    pub id: u64,
+    /// Contract that registered the query and receives its results
+    pub owner: String,
+    /// What the query reads
+    pub kind: InterchainQueryKind,
+    /// Connection to the remote chain
+    pub connection_id: String,
+    /// Number of blocks between two updates of a KV query
+    pub update_period: u64,
+    /// Local height at which the last result was submitted, 0 if none was
+    pub last_submitted_result_local_height: u64,
+    /// Remote height of the last result submitted, 0 if none was
+    pub last_submitted_result_remote_height: u64,
+}
+
+/// Height on the remote chain
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IcqHeight {
+    /// Revision of the remote chain
+    pub revision_number: u64,
+    /// Height within the revision
+    pub revision_height: u64,
+}
+
+/// Sudo messages sent by Neutron to the owner of an interchain query when a result is submitted
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IcqSudoMsg {
+    /// A transaction matching the filter of the query was submitted
+    TxQueryResult {
+        /// Id of the query
+        query_id: u64,
+        /// Remote height of the transaction
+        height: IcqHeight,
+        /// Raw transaction
+        data: Binary,
+    },
+    /// New values of the keys of the query were submitted.
+    /// The contract reads them with the `interchain_query_result` query.
+    #[serde(rename = "kv_query_result")]
+    KvQueryResult {
+        /// Id of the query
+        query_id: u64,
+    },
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn sudo_msg_matches_neutron() {
+        let msg = IcqSudoMsg::KvQueryResult { query_id: 4 };
+        assert_eq!(
+            serde_json::to_string(&msg).unwrap(),
+            r#"{"kv_query_result":{"query_id":4}}"#
+        );
+        let msg = IcqSudoMsg::TxQueryResult {
+            query_id: 4,
+            height: IcqHeight {
+                revision_number: 1,
+                revision_height: 10,
+            },
+            data: Binary::from(b"tx"),
+        };
+        assert_eq!(
+            serde_json::to_string(&msg).unwrap(),
+            r#"{"tx_query_result":{"query_id":4,"height":{"revision_number":1,"revision_height":10},"data":"dHg="}}"#
+        );
+    }
+}
@@ -11,17 +11,23 @@ pub mod env;
 /// Contains default acknowledgment parsers
 mod ack_parser;
 mod error;
+/// Relayer fees of the ICS-29 fee middleware
+pub mod fee;
+/// Types of the Neutron interchain queries
+pub mod icq;
 
 pub(crate) mod analysis;
 pub(crate) mod ibc_query;
 pub(crate) mod packet;
+pub mod polytone;
 /// Type definition for interchain structure and return types
 pub mod results;
+pub mod topology;
 pub(crate) mod tx;
 
-pub use ack_parser::IbcAckParser;
+pub use ack_parser::{IbcAckParser, IncentivizedAck};
 pub use analysis::PacketAnalysis;
-pub use env::{contract_port, InterchainEnv};
+pub use env::{contract_port, ibc_denom, InterchainEnv};
 pub use error::InterchainError;
 pub use ibc_query::IbcQueryHandler;
 pub use packet::{
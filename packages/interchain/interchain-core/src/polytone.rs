@@ -0,0 +1,267 @@
+//! Deployment of [Polytone](https://github.com/DA0-DA0/polytone) between two chains of an interchain environment.
+//!
+//! Polytone is made of 3 contracts: a `note` on the controller chain sends messages to a `voice` on the host chain,
+//! which executes them through a `proxy` contract owned by the sender of the messages.
+//! [`Polytone::deploy`] uploads and instantiates the contracts and opens the channel between the note and the voice.
+//! [`RemoteAccount`] then executes messages on the host chain and follows their execution.
+//!
+//! The contracts are provided by the caller, as wasm files for the daemon environments or wrappers for the mock environment.
+//!
+//! ```rust,no_run
+//! use cosmwasm_std::{coins, BankMsg};
+//! use cw_orch_core::contract::interface_traits::Uploadable;
+//! use cw_orch_interchain_core::{
+//!     polytone::Polytone, IbcQueryHandler, InterchainEnv, InterchainError,
+//! };
+//!
+//! fn send_remotely<Chain: IbcQueryHandler, E: InterchainEnv<Chain>>(
+//!     interchain: &E,
+//!     note: &impl Uploadable,
+//!     voice: &impl Uploadable,
+//!     proxy: &impl Uploadable,
+//! ) -> Result<(), InterchainError> {
+//!     let polytone = Polytone::deploy(interchain, "juno-1", "osmosis-1", note, voice, proxy)?;
+//!     let account = polytone.remote_account(interchain);
+//!     let send = BankMsg::Send {
+//!         to_address: "osmo1...".to_string(),
+//!         amount: coins(100, "uosmo"),
+//!     };
+//!     let response = account.execute(vec![send.into()])?;
+//!     println!("Executed by {} on osmosis-1", response.executed_by);
+//!     Ok(())
+//! }
+//! ```
+use cosmwasm_std::{from_json, Addr, CosmosMsg, IbcOrder, Uint64};
+use cw_orch_core::{
+    contract::{interface_traits::Uploadable, Contract},
+    environment::TxHandler,
+};
+use ibc_relayer_types::core::ics24_host::identifier::PortId;
+use serde_json::json;
+
+pub use crate::ack_parser::polytone_callback::{Callback, ExecutionResponse};
+use crate::{
+    channel::InterchainChannel, env::ChainId, IbcAckParser, IbcPacketOutcome, IbcQueryHandler,
+    InterchainEnv, InterchainError, PacketAnalysis,
+};
+
+/// Version of the channels between notes and voices
+pub const POLYTONE_VERSION: &str = "polytone-1";
+/// Gas limit of the blocks of the host chain, used by the voice to bound the execution of the messages
+pub const DEFAULT_BLOCK_MAX_GAS: u64 = 100_000_000;
+/// Timeout of the packets sent by the [`RemoteAccount`]s
+pub const DEFAULT_TIMEOUT_SECONDS: u64 = 600;
+
+/// Polytone contracts deployed between a controller and a host chain
+#[derive(Clone)]
+pub struct Polytone<Chain: IbcQueryHandler> {
+    /// Note, on the controller chain
+    pub note: Contract<Chain>,
+    /// Voice, on the host chain
+    pub voice: Contract<Chain>,
+    /// Proxy, uploaded on the host chain and instantiated by the voice for each remote account
+    pub proxy: Contract<Chain>,
+    /// Channel between the note and the voice
+    pub channel: InterchainChannel<<Chain as IbcQueryHandler>::Handler>,
+}
+
+impl<Chain: IbcQueryHandler> Polytone<Chain> {
+    /// Deploys Polytone so that the accounts of `controller` execute messages on `host`.
+    /// Uses the senders of the chains of `interchain` and [`DEFAULT_BLOCK_MAX_GAS`].
+    pub fn deploy<E: InterchainEnv<Chain>>(
+        interchain: &E,
+        controller: ChainId,
+        host: ChainId,
+        note_source: &impl Uploadable,
+        voice_source: &impl Uploadable,
+        proxy_source: &impl Uploadable,
+    ) -> Result<Self, InterchainError> {
+        let controller_chain = interchain.get_chain(controller).map_err(Into::into)?;
+        let host_chain = interchain.get_chain(host).map_err(Into::into)?;
+        let block_max_gas = Uint64::new(DEFAULT_BLOCK_MAX_GAS);
+
+        let note = Contract::new(format!("polytone:note:{host}"), controller_chain);
+        note.upload(note_source)?;
+        note.instantiate(
+            &json!({ "pair": null, "block_max_gas": block_max_gas }),
+            None,
+            &[],
+        )?;
+
+        let proxy = Contract::new("polytone:proxy", host_chain.clone());
+        proxy.upload(proxy_source)?;
+
+        let voice = Contract::new(format!("polytone:voice:{controller}"), host_chain);
+        voice.upload(voice_source)?;
+        voice.instantiate(
+            &json!({
+                "proxy_code_id": Uint64::new(proxy.code_id()?),
+                "block_max_gas": block_max_gas,
+            }),
+            None,
+            &[],
+        )?;
+
+        let channel = interchain.create_channel(
+            controller,
+            host,
+            &wasm_port(&note.address()?)?,
+            &wasm_port(&voice.address()?)?,
+            POLYTONE_VERSION,
+            Some(IbcOrder::Unordered),
+        )?;
+
+        Ok(Self {
+            note,
+            voice,
+            proxy,
+            channel: channel.interchain_channel,
+        })
+    }
+
+    /// Account of the sender of the controller chain on the host chain
+    pub fn remote_account<E: InterchainEnv<Chain>>(
+        &self,
+        interchain: &E,
+    ) -> RemoteAccount<Chain, E> {
+        RemoteAccount {
+            interchain: interchain.clone(),
+            note: self.note.clone(),
+        }
+    }
+}
+
+/// Account controlled from another chain through Polytone
+#[derive(Clone)]
+pub struct RemoteAccount<Chain: IbcQueryHandler, E: InterchainEnv<Chain>> {
+    interchain: E,
+    note: Contract<Chain>,
+}
+
+impl<Chain: IbcQueryHandler, E: InterchainEnv<Chain>> RemoteAccount<Chain, E> {
+    /// Executes `msgs` on the host chain and returns their responses
+    pub fn execute(&self, msgs: Vec<CosmosMsg>) -> Result<ExecutionResponse, InterchainError> {
+        self.execute_with_timeout(msgs, DEFAULT_TIMEOUT_SECONDS)
+    }
+
+    /// Executes `msgs` on the host chain, with a packet expiring after `timeout_seconds`, and returns their responses
+    pub fn execute_with_timeout(
+        &self,
+        msgs: Vec<CosmosMsg>,
+        timeout_seconds: u64,
+    ) -> Result<ExecutionResponse, InterchainError> {
+        let tx = self.note.execute(
+            &json!({
+                "execute": {
+                    "msgs": msgs,
+                    "callback": null,
+                    "timeout_seconds": Uint64::new(timeout_seconds),
+                }
+            }),
+            &[],
+        )?;
+        let controller = self.note.environment().chain_id();
+        let flow = self
+            .interchain
+            .await_packets(&controller, tx)
+            .map_err(Into::into)?;
+
+        // The failed executions are checked first, the standard checks only report them as undecodable acks
+        let response = execution_response(&flow.packets)?;
+        flow.assert()?;
+        Ok(response)
+    }
+
+    /// Address of the account on the host chain, `None` until it executed its first messages
+    pub fn address(&self) -> Result<Option<String>, InterchainError> {
+        let local_address = self.note.environment().sender_addr();
+        let address = self.note.query(&json!({
+            "remote_address": { "local_address": local_address }
+        }))?;
+        Ok(address)
+    }
+}
+
+/// Response of the messages executed by the voice, from the ack of the packet sent by the note
+fn execution_response<T>(
+    packets: &[IbcPacketOutcome<T>],
+) -> Result<ExecutionResponse, InterchainError> {
+    for packet in packets {
+        let ack = match packet {
+            IbcPacketOutcome::Success { ack, .. } => ack,
+            IbcPacketOutcome::Timeout { .. } => return Err(InterchainError::PacketTimeout {}),
+        };
+        // Acks of fee-enabled channels are wrapped by the fee middleware
+        let ack = IbcAckParser::fee_ack(ack)
+            .map(|fee_ack| fee_ack.app_acknowledgement)
+            .unwrap_or_else(|_| ack.clone());
+        match from_json::<Callback>(&ack) {
+            Ok(Callback::Execute(Ok(response))) => return Ok(response),
+            Ok(Callback::Execute(Err(error))) => {
+                return Err(InterchainError::FailedAckReceived(format!(
+                    "Error during execution on remote chain : {error}"
+                )))
+            }
+            Ok(Callback::FatalError(error)) => {
+                return Err(InterchainError::FailedAckReceived(error))
+            }
+            Ok(Callback::Query(_)) | Err(_) => {}
+        }
+    }
+    Err(InterchainError::GenericError(
+        "No Polytone execution acknowledgment".to_string(),
+    ))
+}
+
+/// Port of the contract at `address`
+fn wasm_port(address: &Addr) -> Result<PortId, InterchainError> {
+    Ok(format!("wasm.{address}").parse()?)
+}
+
+#[cfg(test)]
+mod test {
+    use cosmwasm_std::to_json_binary;
+
+    use super::*;
+
+    fn success(callback: &Callback) -> IbcPacketOutcome<()> {
+        IbcPacketOutcome::Success {
+            receive_tx: (),
+            ack_tx: (),
+            ack: to_json_binary(callback).unwrap(),
+        }
+    }
+
+    #[test]
+    fn returns_execution_response() {
+        let response = ExecutionResponse {
+            executed_by: "osmo1proxy".to_string(),
+            result: vec![],
+        };
+        let packets = [success(&Callback::Execute(Ok(response.clone())))];
+        assert_eq!(execution_response(&packets).unwrap(), response);
+    }
+
+    #[test]
+    fn surfaces_execution_errors() {
+        let packets = [success(&Callback::Execute(Err(
+            "codespace: wasm, code: 5".to_string()
+        )))];
+        let err = execution_response(&packets).unwrap_err();
+        assert!(
+            matches!(err, InterchainError::FailedAckReceived(error) if error.contains("codespace: wasm, code: 5"))
+        );
+
+        let packets = [success(&Callback::FatalError("out of gas".to_string()))];
+        assert!(matches!(
+            execution_response(&packets),
+            Err(InterchainError::FailedAckReceived(_))
+        ));
+
+        let packets = [IbcPacketOutcome::Timeout { timeout_tx: () }];
+        assert!(matches!(
+            execution_response(&packets),
+            Err(InterchainError::PacketTimeout {})
+        ));
+    }
+}
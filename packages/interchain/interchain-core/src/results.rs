@@ -1,6 +1,8 @@
 use crate::ibc_query::IbcQueryHandler;
 use crate::packet::NestedPacketsFlow;
+use crate::packet::{IbcPacketInfo, IbcPacketOutcome, SinglePacketFlow};
 use crate::{channel::InterchainChannel, env::ChannelCreation};
+use cw_orch_core::environment::CwEnv;
 use cw_orch_core::environment::TxHandler;
 use ibc_relayer_types::core::ics24_host::identifier::ChannelId;
 
@@ -34,3 +36,40 @@ pub struct ChannelCreationResult<Chain: IbcQueryHandler> {
     /// Transactions involved in the channel creation + Their packet following analysis
     pub channel_creation_txs: ChannelCreation<NestedPacketsFlow<Chain>>,
 }
+
+/// A packet that was pending on a channel, with the outcome of its lifecycle
+#[derive(Clone)]
+pub struct RelayedPacket<Chain: CwEnv> {
+    /// Chain from which the packet was sent
+    pub src_chain: NetworkId,
+    /// Identification of the packet
+    pub packet: IbcPacketInfo,
+    /// Outcome of the packet lifecycle
+    pub flow: SinglePacketFlow<Chain>,
+}
+
+/// Result returned by `relay_all_pending`
+/// Packets are ordered by source chain, channel and sequence
+#[derive(Clone)]
+pub struct RelayAllPendingResult<Chain: CwEnv> {
+    /// All the packets that were pending, in the order they were relayed
+    pub packets: Vec<RelayedPacket<Chain>>,
+}
+
+impl<Chain: CwEnv> RelayAllPendingResult<Chain> {
+    /// Number of packets that were successfully received and acknowledged
+    pub fn successes(&self) -> usize {
+        self.packets
+            .iter()
+            .filter(|p| matches!(p.flow.outcome, IbcPacketOutcome::Success { .. }))
+            .count()
+    }
+
+    /// Number of packets that timed out
+    pub fn timeouts(&self) -> usize {
+        self.packets
+            .iter()
+            .filter(|p| matches!(p.flow.outcome, IbcPacketOutcome::Timeout { .. }))
+            .count()
+    }
+}
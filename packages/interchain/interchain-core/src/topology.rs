@@ -0,0 +1,265 @@
+//! Declarative topologies of chains connected by IBC channels.
+//!
+//! A [`Topology`] lists the channels to open between the chains of an interchain environment.
+//! [`InterchainEnv::create_topology`](crate::InterchainEnv::create_topology) opens all of them and returns the [`Channels`],
+//! where channels are retrieved by chains and port, or by name.
+//! Routes across several chains are resolved for the packet-forward-middleware, whose packets are followed by `await_packets` like any other.
+//!
+//! ```rust
+//! use cw_orch_interchain::prelude::*;
+//! use cw_orch_interchain::core::topology::Topology;
+//!
+//! let interchain = MockBech32InterchainEnv::new(vec![
+//!     ("juno-1", "juno"),
+//!     ("osmosis-1", "osmo"),
+//!     ("stargaze-1", "stars"),
+//! ]);
+//! let channels = interchain
+//!     .create_topology(
+//!         &Topology::default()
+//!             .transfer("juno-1", "osmosis-1")
+//!             .transfer("osmosis-1", "stargaze-1"),
+//!     )
+//!     .unwrap();
+//!
+//! let juno_osmosis = channels.get("osmosis-1", "juno-1", &PortId::transfer()).unwrap();
+//! let route = channels.route(&["juno-1", "osmosis-1", "stargaze-1"], &PortId::transfer()).unwrap();
+//! assert_eq!(route.len(), 2);
+//! ```
+use cosmwasm_std::IbcOrder;
+use ibc_relayer_types::core::ics24_host::identifier::PortId;
+use serde_json::{json, Value};
+
+use crate::{
+    channel::{IbcPort, InterchainChannel},
+    env::ChainId,
+    results::NetworkId,
+    InterchainError,
+};
+
+/// Channels to open between the chains of an interchain environment
+#[derive(Clone, Debug, Default)]
+pub struct Topology {
+    /// Channels, opened in order
+    pub channels: Vec<ChannelSpec>,
+}
+
+/// Description of a channel to open
+#[derive(Clone, Debug)]
+pub struct ChannelSpec {
+    /// Name used to retrieve the channel, if any
+    pub name: Option<String>,
+    /// Chain initiating the channel handshake
+    pub src_chain: NetworkId,
+    /// Counterparty chain
+    pub dst_chain: NetworkId,
+    /// Port of the channel on the src chain
+    pub src_port: PortId,
+    /// Port of the channel on the dst chain
+    pub dst_port: PortId,
+    /// Channel version
+    pub version: String,
+    /// Channel ordering
+    pub order: Option<IbcOrder>,
+}
+
+impl Topology {
+    /// Adds a channel between `src_port` on `src_chain` and `dst_port` on `dst_chain`
+    pub fn channel(
+        mut self,
+        src_chain: ChainId,
+        dst_chain: ChainId,
+        src_port: &PortId,
+        dst_port: &PortId,
+        version: &str,
+        order: Option<IbcOrder>,
+    ) -> Self {
+        self.channels.push(ChannelSpec {
+            name: None,
+            src_chain: src_chain.to_string(),
+            dst_chain: dst_chain.to_string(),
+            src_port: src_port.clone(),
+            dst_port: dst_port.clone(),
+            version: version.to_string(),
+            order,
+        });
+        self
+    }
+
+    /// Adds an ICS20 transfer channel between `src_chain` and `dst_chain`
+    pub fn transfer(self, src_chain: ChainId, dst_chain: ChainId) -> Self {
+        self.channel(
+            src_chain,
+            dst_chain,
+            &PortId::transfer(),
+            &PortId::transfer(),
+            "ics20-1",
+            Some(IbcOrder::Unordered),
+        )
+    }
+
+    /// Names the last channel added, to retrieve it with [`Channels::by_name`]
+    pub fn named(mut self, name: impl Into<String>) -> Self {
+        if let Some(channel) = self.channels.last_mut() {
+            channel.name = Some(name.into());
+        }
+        self
+    }
+}
+
+/// Channels opened from a [`Topology`]
+#[derive(Clone, Debug)]
+pub struct Channels<Channel: Clone + Send + Sync> {
+    channels: Vec<(Option<String>, InterchainChannel<Channel>)>,
+}
+
+impl<Channel: Clone + Send + Sync> Default for Channels<Channel> {
+    fn default() -> Self {
+        Self { channels: vec![] }
+    }
+}
+
+impl<Channel: Clone + Send + Sync> Channels<Channel> {
+    /// Registers a channel, with an optional name
+    pub fn insert(&mut self, name: Option<String>, channel: InterchainChannel<Channel>) {
+        self.channels.push((name, channel));
+    }
+
+    /// All the channels, in creation order
+    pub fn iter(&self) -> impl Iterator<Item = &InterchainChannel<Channel>> {
+        self.channels.iter().map(|(_, channel)| channel)
+    }
+
+    /// Returns the first channel between `chain_a` and `chain_b` bound to `port` on `chain_a`.
+    /// The order of the chains doesn't matter, the ports are returned with the side of `chain_a` first.
+    pub fn get(
+        &self,
+        chain_a: ChainId,
+        chain_b: ChainId,
+        port: &PortId,
+    ) -> Result<(IbcPort<Channel>, IbcPort<Channel>), InterchainError> {
+        self.iter()
+            .filter_map(|channel| channel.get_ordered_ports_from(chain_a).ok())
+            .find(|(port_a, port_b)| port_a.port == *port && port_b.chain_id == chain_b)
+            .ok_or_else(|| {
+                InterchainError::GenericError(format!(
+                    "No channel between {chain_a} and {chain_b} on port {port}"
+                ))
+            })
+    }
+
+    /// Returns the channel registered as `name`
+    pub fn by_name(&self, name: &str) -> Result<&InterchainChannel<Channel>, InterchainError> {
+        self.channels
+            .iter()
+            .find(|(channel_name, _)| channel_name.as_deref() == Some(name))
+            .map(|(_, channel)| channel)
+            .ok_or_else(|| InterchainError::GenericError(format!("No channel named {name}")))
+    }
+
+    /// Returns the ports of every hop of a route through `chains`, all hops being on `port`
+    pub fn route(
+        &self,
+        chains: &[ChainId],
+        port: &PortId,
+    ) -> Result<Vec<(IbcPort<Channel>, IbcPort<Channel>)>, InterchainError> {
+        chains
+            .windows(2)
+            .map(|hop| self.get(hop[0], hop[1], port))
+            .collect()
+    }
+
+    /// Memo of an ICS20 transfer sent from `chains[0]` to `chains[1]`,
+    /// forwarded by the packet-forward-middleware through the next chains of the route to `receiver` on the last chain.
+    /// The receiver of the transfer itself is ignored by the forwarding chains.
+    pub fn forward_memo(
+        &self,
+        chains: &[ChainId],
+        receiver: &str,
+    ) -> Result<String, InterchainError> {
+        let route = self.route(chains, &PortId::transfer())?;
+        // The first hop is the transfer itself
+        let memo = route
+            .iter()
+            .skip(1)
+            .rev()
+            .fold(None, |next: Option<Value>, (src, _)| {
+                let mut forward = json!({
+                    "receiver": receiver,
+                    "port": src.port.to_string(),
+                    "channel": src.channel.as_ref().map(ToString::to_string).unwrap_or_default(),
+                });
+                if let Some(next) = next {
+                    forward["next"] = next;
+                }
+                Some(json!({ "forward": forward }))
+            });
+        Ok(memo.map(|memo| memo.to_string()).unwrap_or_default())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use ibc_relayer_types::core::ics24_host::identifier::ChannelId;
+
+    use super::*;
+
+    fn port(chain_id: &str, channel: u64) -> IbcPort<()> {
+        IbcPort {
+            chain_id: chain_id.to_string(),
+            connection_id: None,
+            port: PortId::transfer(),
+            channel: Some(ChannelId::new(channel)),
+            chain: (),
+        }
+    }
+
+    #[test]
+    fn resolves_routes() {
+        let mut channels = Channels::default();
+        channels.insert(
+            None,
+            InterchainChannel::new(port("juno-1", 0), port("osmosis-1", 0)),
+        );
+        channels.insert(
+            Some("osmosis-stargaze".to_string()),
+            InterchainChannel::new(port("osmosis-1", 1), port("stargaze-1", 0)),
+        );
+        channels.insert(
+            None,
+            InterchainChannel::new(port("stargaze-1", 1), port("neutron-1", 0)),
+        );
+
+        let (juno, osmosis) = channels
+            .get("osmosis-1", "juno-1", &PortId::transfer())
+            .map(|(a, b)| (b, a))
+            .unwrap();
+        assert_eq!(juno.channel, Some(ChannelId::new(0)));
+        assert_eq!(osmosis.chain_id, "osmosis-1");
+        assert!(channels.by_name("osmosis-stargaze").is_ok());
+        assert!(channels
+            .get("juno-1", "neutron-1", &PortId::transfer())
+            .is_err());
+
+        let memo = channels
+            .forward_memo(
+                &["juno-1", "osmosis-1", "stargaze-1", "neutron-1"],
+                "neutron1receiver",
+            )
+            .unwrap();
+        let memo: Value = serde_json::from_str(&memo).unwrap();
+        assert_eq!(
+            memo,
+            json!({"forward": {
+                "receiver": "neutron1receiver",
+                "port": "transfer",
+                "channel": "channel-1",
+                "next": {"forward": {
+                    "receiver": "neutron1receiver",
+                    "port": "transfer",
+                    "channel": "channel-1",
+                }}
+            }})
+        );
+    }
+}
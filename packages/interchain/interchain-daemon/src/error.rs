@@ -43,6 +43,17 @@ pub enum InterchainDaemonError {
         channel: InterchainChannel<Channel>,
     },
 
+    #[error("IBC client {client_id} on {chain_id} is {status} with {remaining:?} of trusting period left, update it before sending packets")]
+    StaleClient {
+        chain_id: NetworkId,
+        client_id: String,
+        status: String,
+        remaining: std::time::Duration,
+    },
+
+    #[error("No result submitted for interchain query {0}, is the ICQ relayer running?")]
+    IcqResultNotSubmitted(u64),
+
     #[error("Configuration already registered for chain {0}")]
     AlreadyRegistered(String),
 
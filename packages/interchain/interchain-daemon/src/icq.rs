@@ -0,0 +1,53 @@
+//! Lookup of the interchain queries registered on Neutron, read through the [`InterchainQueries`] client of cw-orch-daemon
+//! and converted into the chain agnostic [`RegisteredQuery`] of the interchain environments.
+
+use cw_orch_daemon::{
+    queriers::{neutron_proto, InterchainQueries},
+    DaemonError,
+};
+use cw_orch_interchain_core::icq::{InterchainQueryKind, KvKey, RegisteredQuery};
+use tonic::transport::Channel;
+
+const KV_QUERY_TYPE: &str = "kv";
+
+fn into_registered_query(query: neutron_proto::RegisteredQuery) -> RegisteredQuery {
+    let kind = if query.query_type == KV_QUERY_TYPE {
+        InterchainQueryKind::Kv(
+            query
+                .keys
+                .into_iter()
+                .map(|key| KvKey {
+                    path: key.path,
+                    key: key.key.into(),
+                })
+                .collect(),
+        )
+    } else {
+        InterchainQueryKind::Tx {
+            transactions_filter: query.transactions_filter,
+        }
+    };
+    RegisteredQuery {
+        id: query.id,
+        owner: query.owner,
+        kind,
+        connection_id: query.connection_id,
+        update_period: query.update_period,
+        last_submitted_result_local_height: query.last_submitted_result_local_height,
+        last_submitted_result_remote_height: query
+            .last_submitted_result_remote_height
+            .map(|height| height.revision_height)
+            .unwrap_or_default(),
+    }
+}
+
+/// Queries the interchain query `query_id` registered on the chain behind `channel`
+pub(crate) async fn registered_query(
+    channel: Channel,
+    query_id: u64,
+) -> Result<RegisteredQuery, DaemonError> {
+    InterchainQueries::new_async(channel)
+        ._registered_query(query_id)
+        .await
+        .map(into_registered_query)
+}
@@ -1,16 +1,19 @@
+use cosmrs::proto::ibc::core::channel::v1::State;
 use cosmwasm_std::IbcOrder;
 use cw_orch_core::environment::{ChainInfoOwned, ChainState, IndexResponse};
-use cw_orch_daemon::queriers::{Ibc, Node};
+use cw_orch_daemon::queriers::{Ibc, LightClientStatus, Node};
 use cw_orch_daemon::{CosmTxResponse, Daemon, DaemonError, RUNTIME};
 use cw_orch_interchain_core::channel::{IbcPort, InterchainChannel};
 use cw_orch_interchain_core::env::{ChainId, ChannelCreation};
+use cw_orch_interchain_core::icq::RegisteredQuery;
+use cw_orch_interchain_core::{IbcPacketInfo, InterchainError};
 use cw_orch_interchain_core::{InterchainEnv, NestedPacketsFlow, SinglePacketFlow};
 
 use ibc_relayer_types::core::ics04_channel::packet::Sequence;
-use tokio::time::sleep;
 use tonic::transport::Channel;
 
 use crate::channel_creator::{ChannelCreationValidator, ChannelCreator};
+use crate::icq;
 use crate::interchain_log::InterchainLog;
 use crate::packet_inspector::PacketInspector;
 use ibc_relayer_types::core::ics24_host::identifier::{ChannelId, PortId};
@@ -19,6 +22,7 @@ use crate::{IcDaemonResult, InterchainDaemonError};
 
 use cw_orch_interchain_core::results::{
     ChannelCreationTransactionsResult, InternalChannelCreationResult, NetworkId,
+    RelayAllPendingResult, RelayedPacket,
 };
 use futures::future::try_join4;
 use std::collections::HashMap;
@@ -333,12 +337,193 @@ impl<C: ChannelCreator> DaemonInterchain<C> {
         Ok(ibc_trail)
     }
 
+    /// Returns the Neutron interchain query `query_id` registered on `chain_id`
+    pub fn registered_query(
+        &self,
+        chain_id: ChainId,
+        query_id: u64,
+    ) -> Result<RegisteredQuery, InterchainDaemonError> {
+        let channel = self.get_chain(chain_id)?.channel();
+        let query = self
+            .rt_handle
+            .block_on(icq::registered_query(channel, query_id))?;
+        Ok(query)
+    }
+
+    /// Waits until the relayer submits a result of the Neutron interchain query `query_id` on `chain_id` after `after_local_height`.
+    /// Neutron calls the owner of the query back with the result, so the contract has processed it when this returns.
+    pub fn await_icq_result(
+        &self,
+        chain_id: ChainId,
+        query_id: u64,
+        after_local_height: u64,
+    ) -> Result<RegisteredQuery, InterchainDaemonError> {
+        let chain = self.get_chain(chain_id)?;
+        let polling = chain.polling().clone();
+        self.rt_handle.block_on(async {
+            for _ in 0..polling.ibc_query_retries {
+                let query = icq::registered_query(chain.channel(), query_id).await?;
+                if query.last_submitted_result_local_height > after_local_height {
+                    return Ok(query);
+                }
+                log::debug!(
+                    target: chain_id,
+                    "No result submitted for interchain query {query_id}, waiting {:?}",
+                    polling.ibc_query_interval
+                );
+                polling.sleep(polling.ibc_query_interval).await?;
+            }
+            Err(InterchainDaemonError::IcqResultNotSubmitted(query_id))
+        })
+    }
+
+    /// Returns the status of the light clients underlying `channel`, on the side of `port_a` and `port_b` respectively.
+    /// Both sides of the channel must be known.
+    pub fn client_status(
+        &self,
+        channel: &InterchainChannel<Channel>,
+    ) -> Result<(LightClientStatus, LightClientStatus), InterchainDaemonError> {
+        let status = |port: &IbcPort<Channel>| {
+            let channel_id = port.channel.clone().ok_or_else(|| {
+                InterchainError::GenericError(format!(
+                    "Channel on {}:{} is not known",
+                    port.chain_id, port.port
+                ))
+            })?;
+            let status = self.rt_handle.block_on(
+                Ibc::new_async(self.get_chain(&port.chain_id)?.channel())
+                    ._channel_light_client_status(port.port.to_string(), channel_id.to_string()),
+            )?;
+            Ok::<_, InterchainDaemonError>(status)
+        };
+        Ok((status(&channel.port_a)?, status(&channel.port_b)?))
+    }
+
+    /// Errors if a light client underlying `channel` is not active or expires within `margin`.
+    /// Expired clients make packets fail in ways that are hard to trace back, call this before sending packets on long-lived testnets.
+    pub fn ensure_clients_fresh(
+        &self,
+        channel: &InterchainChannel<Channel>,
+        margin: Duration,
+    ) -> Result<(), InterchainDaemonError> {
+        let (status_a, status_b) = self.client_status(channel)?;
+        for (port, status) in [(&channel.port_a, status_a), (&channel.port_b, status_b)] {
+            if !status.is_fresh(margin) {
+                return Err(InterchainDaemonError::StaleClient {
+                    chain_id: port.chain_id.clone(),
+                    client_id: status.client_id,
+                    status: status.status,
+                    remaining: status.trusting_period_remaining,
+                });
+            }
+            log::debug!(
+                target: &port.chain_id,
+                "IBC client {} is active, {:?} of trusting period left",
+                status.client_id,
+                status.trusting_period_remaining
+            );
+        }
+        Ok(())
+    }
+
+    /// Follows every packet that is still pending (sent but not yet acknowledged or timed out) on all the channels of the registered chains.
+    /// Packets are awaited in sequence order on each channel, so that ordered channels are settled in order.
+    /// Channels whose counterparty chain is not registered in this environment are skipped.
+    pub fn relay_all_pending(
+        &self,
+    ) -> Result<RelayAllPendingResult<Daemon>, InterchainDaemonError> {
+        let mut chain_ids: Vec<_> = self.daemons.keys().cloned().collect();
+        chain_ids.sort();
+
+        let mut packets = vec![];
+        for src_chain in chain_ids {
+            let pending = self
+                .rt_handle
+                .block_on(self.find_pending_packets(&src_chain))?;
+            for packet in pending {
+                log::info!(
+                    target: &src_chain,
+                    "Relaying pending packet {} on {}:{} to {}",
+                    packet.sequence,
+                    packet.src_port,
+                    packet.src_channel,
+                    packet.dst_chain_id
+                );
+                let flow = self.await_single_packet(
+                    &src_chain,
+                    packet.src_port.clone(),
+                    packet.src_channel.clone(),
+                    &packet.dst_chain_id,
+                    packet.sequence,
+                )?;
+                packets.push(RelayedPacket {
+                    src_chain: src_chain.clone(),
+                    packet,
+                    flow,
+                });
+            }
+        }
+
+        Ok(RelayAllPendingResult { packets })
+    }
+
+    /// Lists the packets with a commitment on the open channels of `src_chain`, sorted by channel and sequence
+    async fn find_pending_packets(
+        &self,
+        src_chain: ChainId<'_>,
+    ) -> Result<Vec<IbcPacketInfo>, InterchainDaemonError> {
+        let ibc = Ibc::new_async(self.get_chain(src_chain)?.channel());
+
+        let mut pending = vec![];
+        for channel in ibc._channels(None).await? {
+            if channel.state() != State::Open {
+                continue;
+            }
+            let Some(connection_id) = channel.connection_hops.first() else {
+                continue;
+            };
+            let dst_chain_id = ibc._connection_client(connection_id).await?.chain_id;
+            if !self.daemons.contains_key(&dst_chain_id) {
+                log::debug!(
+                    target: src_chain,
+                    "Skipping channel {}, counterparty chain {} is not registered",
+                    channel.channel_id,
+                    dst_chain_id
+                );
+                continue;
+            }
+
+            let mut sequences: Vec<u64> = ibc
+                ._packet_commitments(&channel.port_id, &channel.channel_id)
+                .await?
+                .into_iter()
+                .map(|commitment| commitment.sequence)
+                .collect();
+            sequences.sort_unstable();
+
+            let src_port = PortId::from_str(&channel.port_id)?;
+            let src_channel = ChannelId::from_str(&channel.channel_id)?;
+            pending.extend(sequences.into_iter().map(|sequence| IbcPacketInfo {
+                src_port: src_port.clone(),
+                src_channel: src_channel.clone(),
+                sequence: Sequence::from(sequence),
+                dst_chain_id: dst_chain_id.clone(),
+            }));
+        }
+        pending.sort_by(|a, b| {
+            (a.src_channel.as_str(), a.sequence).cmp(&(b.src_channel.as_str(), b.sequence))
+        });
+
+        Ok(pending)
+    }
+
     async fn find_channel_creation_tx(
         &self,
         src_chain: ChainId<'_>,
         ibc_channel: &InterchainChannel<Channel>,
     ) -> Result<ChannelCreation<CosmTxResponse>, InterchainDaemonError> {
-        for _ in 0..5 {
+        let polling = self.get_chain(src_chain)?.polling().clone();
+        for _ in 0..polling.ibc_query_retries {
             match self.get_last_channel_creation(src_chain, ibc_channel).await {
                 Ok(tx) => {
                     if tx.init.is_some()
@@ -355,8 +540,8 @@ impl<C: ChannelCreator> DaemonInterchain<C> {
                         return Ok(creation);
                     }
                     log::debug!("No new TX by events found");
-                    log::debug!("Waiting 20s");
-                    sleep(Duration::from_secs(20)).await;
+                    log::debug!("Waiting {:?}", polling.ibc_query_interval);
+                    polling.sleep(polling.ibc_query_interval).await?;
                 }
                 Err(e) => {
                     log::debug!("{:?}", e);
@@ -4,6 +4,7 @@
 
 mod channel_creator;
 pub mod error;
+mod icq;
 mod interchain_env;
 pub mod packet_inspector;
 // Tracking IBC state
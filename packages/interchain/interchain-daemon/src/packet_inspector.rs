@@ -1,14 +1,15 @@
 //! Module for tracking a specific packet inside the interchain
 
 use cosmrs::proto::ibc::core::channel::v1::State;
-use cw_orch_core::environment::{ChainInfoOwned, ChainState};
+use cw_orch_core::environment::{ChainInfoOwned, ChainState, IndexResponse};
 use cw_orch_daemon::networks::parse_network;
 use cw_orch_daemon::queriers::{Ibc, Node};
 use cw_orch_daemon::GrpcChannel;
 use cw_orch_daemon::TxResultBlockEvent;
-use cw_orch_daemon::{CosmTxResponse, Daemon, DaemonError};
+use cw_orch_daemon::{CosmTxResponse, Daemon, DaemonError, TxError};
 use cw_orch_interchain_core::channel::{IbcPort, InterchainChannel};
 use cw_orch_interchain_core::env::ChainId;
+use cw_orch_interchain_core::fee::{distributed_fees, incentivized_packets};
 use cw_orch_interchain_core::{
     IbcPacketInfo, IbcPacketOutcome, NestedPacketsFlow, SinglePacketFlow, TxId,
 };
@@ -265,6 +266,7 @@ impl PacketInspector {
 
         // 0. Query the send tx hash for analysis
         let send_tx = self.get_packet_send_tx(from, ibc_channel, sequence).await?;
+        log_incentivized_packet(&src_port.chain_id, &send_tx, sequence);
 
         // 1. Query the tx hash on the remote chains related to the packet the origin chain sent
         let received_tx = self
@@ -272,14 +274,17 @@ impl PacketInspector {
             .await?;
         // We check if the tx errors (this shouldn't happen in IBC connections)
         if received_tx.code != 0 {
-            return Err(DaemonError::TxFailed {
-                code: received_tx.code,
-                reason: format!(
-                    "Raw log on {} : {}",
-                    dst_port.chain_id,
-                    received_tx.raw_log.clone()
-                ),
-            }
+            log::error!(
+                target: &dst_port.chain_id,
+                "IBC tx {} failed on {}",
+                received_tx.txhash,
+                dst_port.chain_id
+            );
+            return Err(DaemonError::TxFailed(TxError::new(
+                received_tx.codespace.clone(),
+                received_tx.code,
+                received_tx.raw_log.clone(),
+            ))
             .into());
         }
 
@@ -319,14 +324,17 @@ impl PacketInspector {
             .await?;
         // First we check if the tx errors (this shouldn't happen in IBC connections)
         if ack_tx.code != 0 {
-            return Err(DaemonError::TxFailed {
-                code: ack_tx.code,
-                reason: format!(
-                    "Raw log on {} : {}",
-                    src_port.chain_id.clone(),
-                    ack_tx.raw_log
-                ),
-            }
+            log::error!(
+                target: &src_port.chain_id,
+                "IBC tx {} failed on {}",
+                ack_tx.txhash,
+                src_port.chain_id
+            );
+            return Err(DaemonError::TxFailed(TxError::new(
+                ack_tx.codespace.clone(),
+                ack_tx.code,
+                ack_tx.raw_log.clone(),
+            ))
             .into());
         }
         log::info!(
@@ -336,6 +344,7 @@ impl PacketInspector {
             src_port.chain_id.clone(),
             ack_tx.txhash
         );
+        log_distributed_fees(&src_port.chain_id, &ack_tx);
 
         Ok(SinglePacketFlow {
             send_tx: Some(TxId::new(src_port.chain_id.clone(), send_tx)),
@@ -365,14 +374,17 @@ impl PacketInspector {
             .await?;
         // We check if the tx errors (this shouldn't happen in IBC connections)
         if timeout_tx.code != 0 {
-            return Err(DaemonError::TxFailed {
-                code: timeout_tx.code,
-                reason: format!(
-                    "Raw log on {} : {}",
-                    src_port.chain_id,
-                    timeout_tx.raw_log.clone()
-                ),
-            }
+            log::error!(
+                target: &src_port.chain_id,
+                "IBC tx {} failed on {}",
+                timeout_tx.txhash,
+                src_port.chain_id
+            );
+            return Err(DaemonError::TxFailed(TxError::new(
+                timeout_tx.codespace.clone(),
+                timeout_tx.code,
+                timeout_tx.raw_log.clone(),
+            ))
             .into());
         }
 
@@ -386,6 +398,7 @@ impl PacketInspector {
             src_port.channel.unwrap(),
             timeout_tx.txhash
         );
+        log_distributed_fees(&src_port.chain_id, &timeout_tx);
 
         // We return the tx hash of this transaction for future analysis
         Ok(SinglePacketFlow {
@@ -518,6 +531,40 @@ impl PacketInspector {
     }
 }
 
+/// Logs the relayer fees escrowed for the packet in `send_tx`, when the channel uses the ICS-29 fee middleware
+fn log_incentivized_packet(chain_id: &str, send_tx: &CosmTxResponse, sequence: Sequence) {
+    let Ok(packets) = incentivized_packets(&send_tx.events()) else {
+        return;
+    };
+    for packet in packets
+        .iter()
+        .filter(|packet| packet.sequence == u64::from(sequence))
+    {
+        log::info!(
+            target: chain_id,
+            "IBC packet n°{} incentivized with relayer fees : {:?}",
+            sequence,
+            packet.fees
+        );
+    }
+}
+
+/// Logs the relayer fees paid out in the ack or timeout `tx`
+fn log_distributed_fees(chain_id: &str, tx: &CosmTxResponse) {
+    let Ok(fees) = distributed_fees(&tx.events()) else {
+        return;
+    };
+    for fee in fees {
+        log::info!(
+            target: chain_id,
+            "Relayer fee paid to {} on tx {} : {:?}",
+            fee.receiver,
+            tx.txhash,
+            fee.fee
+        );
+    }
+}
+
 fn get_events(events: &[TxResultBlockEvent], attr_name: &str) -> Vec<String> {
     events
         .iter()
@@ -24,6 +24,18 @@ pub enum InterchainMockError {
 
     #[error("mock for chain {0} not found")]
     MockNotFound(String),
+
+    #[error("No contract bound to port {port} on {chain_id}, instantiate the contract before opening a channel on its port")]
+    ContractPortNotFound { chain_id: String, port: String },
+
+    #[error("Can't open a channel from {0} to itself, the mock relayer needs two chains. Register a second chain to connect the contracts over IBC")]
+    LoopbackChannel(String),
+
+    #[error("interchain query {0} not registered")]
+    QueryNotFound(u64),
+
+    #[error("interchain query {0} doesn't accept this kind of result")]
+    WrongQueryKind(u64),
 }
 
 impl From<InterchainMockError> for InterchainError {
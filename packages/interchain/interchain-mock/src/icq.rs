@@ -0,0 +1,251 @@
+use std::{cell::RefCell, collections::BTreeMap, rc::Rc};
+
+use cosmwasm_std::{Addr, Api, Binary};
+use cw_orch_interchain_core::icq::{
+    IcqHeight, IcqSudoMsg, InterchainQueryKind, RegisteredQuery, StorageValue,
+};
+use cw_orch_mock::cw_multi_test::AppResponse;
+
+use crate::{interchain::MockBase, InterchainMockError};
+
+/// Simulates the Neutron interchain queries module on a mock chain.
+///
+/// Mock contracts can't send Neutron messages, so queries are registered on their behalf with [`MockIcq::register`].
+/// Submitting a result stores it and calls the owner of the query back through `sudo`, as Neutron does when the relayer submits it.
+/// The registry is shared by the clones of a `MockIcq`.
+///
+/// ```rust
+/// use cosmwasm_std::Binary;
+/// use cw_orch::prelude::*;
+/// use cw_orch_interchain::core::icq::{InterchainQueryKind, KvKey};
+/// use cw_orch_interchain::mock::MockIcq;
+///
+/// let neutron = MockBech32::new_with_chain_id("neutron", "neutron-1");
+/// let icq = MockIcq::new(&neutron);
+/// let query_id = icq.register(
+///     &neutron.addr_make("contract"),
+///     "connection-0",
+///     InterchainQueryKind::Kv(vec![KvKey {
+///         path: "bank".to_string(),
+///         key: Binary::from(b"balances"),
+///     }]),
+///     10,
+/// );
+/// assert_eq!(icq.query(query_id).unwrap().connection_id, "connection-0");
+/// ```
+#[derive(Clone)]
+pub struct MockIcq<A: Api> {
+    chain: MockBase<A>,
+    state: Rc<RefCell<IcqState>>,
+}
+
+#[derive(Default)]
+struct IcqState {
+    last_id: u64,
+    queries: BTreeMap<u64, RegisteredQuery>,
+    kv_results: BTreeMap<u64, Vec<StorageValue>>,
+}
+
+impl<A: Api> MockIcq<A> {
+    /// Interchain queries module of `chain`
+    pub fn new(chain: &MockBase<A>) -> Self {
+        Self {
+            chain: chain.clone(),
+            state: Rc::default(),
+        }
+    }
+
+    /// Registers an interchain query owned by `owner` and returns its id
+    pub fn register(
+        &self,
+        owner: &Addr,
+        connection_id: &str,
+        kind: InterchainQueryKind,
+        update_period: u64,
+    ) -> u64 {
+        let mut state = self.state.borrow_mut();
+        state.last_id += 1;
+        let id = state.last_id;
+        state.queries.insert(
+            id,
+            RegisteredQuery {
+                id,
+                owner: owner.to_string(),
+                kind,
+                connection_id: connection_id.to_string(),
+                update_period,
+                last_submitted_result_local_height: 0,
+                last_submitted_result_remote_height: 0,
+            },
+        );
+        id
+    }
+
+    /// Removes the query `id`
+    pub fn remove(&self, id: u64) -> Result<RegisteredQuery, InterchainMockError> {
+        let mut state = self.state.borrow_mut();
+        state.kv_results.remove(&id);
+        state
+            .queries
+            .remove(&id)
+            .ok_or(InterchainMockError::QueryNotFound(id))
+    }
+
+    /// Returns the query `id`
+    pub fn query(&self, id: u64) -> Result<RegisteredQuery, InterchainMockError> {
+        self.state
+            .borrow()
+            .queries
+            .get(&id)
+            .cloned()
+            .ok_or(InterchainMockError::QueryNotFound(id))
+    }
+
+    /// Returns all the registered queries, ordered by id
+    pub fn queries(&self) -> Vec<RegisteredQuery> {
+        self.state.borrow().queries.values().cloned().collect()
+    }
+
+    /// Returns the last values submitted for the KV query `id`
+    pub fn kv_result(&self, id: u64) -> Option<Vec<StorageValue>> {
+        self.state.borrow().kv_results.get(&id).cloned()
+    }
+
+    /// Submits the values of the keys of the KV query `id`, read at `remote_height`, and calls its owner back
+    pub fn submit_kv_result(
+        &self,
+        id: u64,
+        remote_height: u64,
+        values: Vec<StorageValue>,
+    ) -> Result<AppResponse, InterchainMockError> {
+        let query = self.submitted(id, remote_height, |kind| {
+            matches!(kind, InterchainQueryKind::Kv(_))
+        })?;
+        self.state.borrow_mut().kv_results.insert(id, values);
+        self.sudo(&query, &IcqSudoMsg::KvQueryResult { query_id: id })
+    }
+
+    /// Submits a transaction matching the TX query `id`, included at `remote_height`, to its owner
+    pub fn submit_tx_result(
+        &self,
+        id: u64,
+        remote_height: u64,
+        data: Binary,
+    ) -> Result<AppResponse, InterchainMockError> {
+        let query = self.submitted(id, remote_height, |kind| {
+            matches!(kind, InterchainQueryKind::Tx { .. })
+        })?;
+        let msg = IcqSudoMsg::TxQueryResult {
+            query_id: id,
+            height: IcqHeight {
+                revision_number: 0,
+                revision_height: remote_height,
+            },
+            data,
+        };
+        self.sudo(&query, &msg)
+    }
+
+    /// Records a submission on the query `id`, checking its kind
+    fn submitted(
+        &self,
+        id: u64,
+        remote_height: u64,
+        is_kind: impl Fn(&InterchainQueryKind) -> bool,
+    ) -> Result<RegisteredQuery, InterchainMockError> {
+        let local_height = self.chain.app.borrow().block_info().height;
+        let mut state = self.state.borrow_mut();
+        let query = state
+            .queries
+            .get_mut(&id)
+            .ok_or(InterchainMockError::QueryNotFound(id))?;
+        if !is_kind(&query.kind) {
+            return Err(InterchainMockError::WrongQueryKind(id));
+        }
+        query.last_submitted_result_local_height = local_height;
+        query.last_submitted_result_remote_height = remote_height;
+        Ok(query.clone())
+    }
+
+    fn sudo(
+        &self,
+        query: &RegisteredQuery,
+        msg: &IcqSudoMsg,
+    ) -> Result<AppResponse, InterchainMockError> {
+        let response = self
+            .chain
+            .app
+            .borrow_mut()
+            .wasm_sudo(Addr::unchecked(&query.owner), msg)?;
+        Ok(response)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use cosmwasm_std::{
+        to_json_binary, Deps, DepsMut, Empty, Env, MessageInfo, Response, StdResult,
+    };
+    use cw_orch_core::{contract::Contract, environment::QueryHandler};
+    use cw_orch_interchain_core::icq::KvKey;
+    use cw_orch_mock::{cw_multi_test::ContractWrapper, MockBech32};
+
+    use super::*;
+
+    const LAST_QUERY_ID: &[u8] = b"last_query_id";
+
+    fn sudo(deps: DepsMut, _env: Env, msg: IcqSudoMsg) -> StdResult<Response> {
+        let IcqSudoMsg::KvQueryResult { query_id } = msg else {
+            return Ok(Response::new());
+        };
+        deps.storage.set(LAST_QUERY_ID, &query_id.to_be_bytes());
+        Ok(Response::new())
+    }
+
+    fn query(deps: Deps, _env: Env, _msg: Empty) -> StdResult<Binary> {
+        to_json_binary(&deps.storage.get(LAST_QUERY_ID))
+    }
+
+    fn noop(_deps: DepsMut, _env: Env, _info: MessageInfo, _msg: Empty) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+
+    #[test]
+    fn calls_back_the_owner() -> anyhow::Result<()> {
+        let neutron = MockBech32::new_with_chain_id("neutron", "neutron-1");
+        neutron.upload_custom(
+            "icq",
+            Box::new(ContractWrapper::new(noop, noop, query).with_sudo(sudo)),
+        )?;
+        let contract = Contract::new("icq", neutron.clone());
+        contract.instantiate(&Empty {}, None, &[])?;
+        let owner = contract.address()?;
+
+        let icq = MockIcq::new(&neutron);
+        let query_id = icq.register(
+            &owner,
+            "connection-0",
+            InterchainQueryKind::Kv(vec![KvKey {
+                path: "bank".to_string(),
+                key: Binary::from(b"balances"),
+            }]),
+            10,
+        );
+        let values = vec![StorageValue {
+            storage_prefix: "bank".to_string(),
+            key: Binary::from(b"balances"),
+            value: Binary::from(b"100"),
+        }];
+        icq.submit_kv_result(query_id, 42, values.clone())?;
+
+        let last: Option<Vec<u8>> = neutron.query(&Empty {}, &owner)?;
+        assert_eq!(last, Some(query_id.to_be_bytes().to_vec()));
+        assert_eq!(icq.kv_result(query_id), Some(values));
+        assert_eq!(icq.query(query_id)?.last_submitted_result_remote_height, 42);
+        assert!(matches!(
+            icq.submit_tx_result(query_id, 43, Binary::default()),
+            Err(InterchainMockError::WrongQueryKind(_))
+        ));
+        Ok(())
+    }
+}
@@ -1,7 +1,8 @@
 #![warn(missing_docs)]
 
-use cosmwasm_std::{from_json, testing::MockApi, Api, Event, IbcOrder};
-use cw_orch_core::environment::QueryHandler;
+use anyhow::Context;
+use cosmwasm_std::{from_json, testing::MockApi, Addr, Api, Event, IbcOrder};
+use cw_orch_core::environment::{DefaultQueriers, QueryHandler, WasmQuerier};
 use cw_orch_interchain_core::{
     channel::InterchainChannel,
     env::{ChainId, ChannelCreation},
@@ -31,6 +32,9 @@ use crate::InterchainMockError;
 
 pub type MockBase<A> = cw_orch_mock::MockBase<A, MockState>;
 
+/// Prefix of the ports bound to contracts
+const CONTRACT_PORT_PREFIX: &str = "wasm.";
+
 /// Interchain environment for cw_multi_test Mock environment
 /// This leverages Abstract's fork of cw_multi_test enabling IBC interactions
 pub struct MockInterchainEnvBase<A: Api> {
@@ -135,8 +139,9 @@ impl<A: Api> InterchainEnv<MockBase<A>> for MockInterchainEnvBase<A> {
         version: &str,
         order: Option<IbcOrder>,
     ) -> Result<InternalChannelCreationResult<ChannelCreationResult>, Self::Error> {
+        // The relayer borrows both apps mutably, so it can't relay within a single app
         if src_chain.eq(dst_chain) {
-            panic!("You can't create an interchain connection between the same chain (because of rust borrow mut rules) {}", src_chain);
+            return Err(InterchainMockError::LoopbackChannel(src_chain.to_string()));
         }
 
         // We need to create a channel between the two chains. This is a job for the relayer
@@ -164,6 +169,10 @@ impl<A: Api> InterchainEnv<MockBase<A>> for MockInterchainEnvBase<A> {
             src_connection_id
         };
 
+        // Ports bound to contracts are handled by the contract IBC entry points during the handshake
+        check_contract_port(&src_mock, src_port)?;
+        check_contract_port(&dst_mock, dst_port)?;
+
         let channel_creation = relayer::create_channel(
             &mut src_mock.app.borrow_mut(),
             &mut dst_mock.app.borrow_mut(),
@@ -172,7 +181,10 @@ impl<A: Api> InterchainEnv<MockBase<A>> for MockInterchainEnvBase<A> {
             dst_port.to_string(),
             version.to_string(),
             order.unwrap_or(IbcOrder::Unordered),
-        )?;
+        )
+        .with_context(|| {
+            format!("Channel handshake between {src_port} and {dst_port} failed, contract ports need a wrapper registering the IBC entry points (`ContractWrapper::with_ibc`)")
+        })?;
 
         log::info!("Successfully created a channel between {} and {} on '{}:{}' and channels {}:'{}' and {}:'{}'",
             src_port,
@@ -344,6 +356,27 @@ impl<A: Api> InterchainEnv<MockBase<A>> for MockInterchainEnvBase<A> {
     }
 }
 
+/// Errors if `port` is bound to a contract (`wasm.<address>`) that doesn't exist on `chain`
+fn check_contract_port<A: Api>(
+    chain: &MockBase<A>,
+    port: &PortId,
+) -> Result<(), InterchainMockError> {
+    let Some(address) = port.as_str().strip_prefix(CONTRACT_PORT_PREFIX) else {
+        return Ok(());
+    };
+    if chain
+        .wasm_querier()
+        .contract_info(&Addr::unchecked(address))
+        .is_err()
+    {
+        return Err(InterchainMockError::ContractPortNotFound {
+            chain_id: chain.app.borrow().block_info().chain_id,
+            port: port.to_string(),
+        });
+    }
+    Ok(())
+}
+
 fn get_events(tx: &AppResponse, event: &str) -> Vec<Event> {
     tx.events
         .iter()
@@ -413,3 +446,24 @@ fn find_ibc_packets_sent_in_tx<A: Api>(
 
     Ok(ibc_packets)
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn rejects_loopback_channels() {
+        let interchain = MockInterchainEnvBase::<MockApiBech32>::new(vec![("juno-1", "juno")]);
+        let res = interchain._internal_create_channel(
+            "juno-1",
+            "juno-1",
+            &PortId::transfer(),
+            &PortId::transfer(),
+            "ics20-1",
+            None,
+        );
+        assert!(
+            matches!(res, Err(InterchainMockError::LoopbackChannel(chain)) if chain == "juno-1")
+        );
+    }
+}
@@ -0,0 +1,159 @@
+//! Packet fees of the ICS-29 fee middleware.
+//!
+//! On fee-enabled channels, anyone can escrow fees to incentivize relayers to relay a packet.
+//! The fees of a packet are either paid in the same transaction as the one sending the packet ([`transfer_tokens_with_fee`])
+//! or for a packet already sent, identified by its sequence ([`pay_packet_fee_async`]).
+//! The escrowed and distributed fees can then be found in the result of following the packets,
+//! see [`NestedPacketsFlow::incentivized_packets`] and [`NestedPacketsFlow::distributed_fees`].
+use cw_orch_interchain_core::{
+    channel::InterchainChannel,
+    fee::{DistributedFee, IncentivizedPacket, PacketFees},
+    IbcQueryHandler, InterchainEnv, InterchainError, NestedPacketsFlow,
+};
+use ibc_proto::ibc::{
+    apps::{
+        fee::v1::{Fee, MsgPayPacketFee, MsgPayPacketFeeAsync, PacketFee},
+        transfer::v1::MsgTransfer,
+    },
+    core::channel::v1::PacketId,
+};
+use prost::{Message, Name};
+use tonic::transport::Channel;
+
+use cosmwasm_std::Coin;
+use cw_orch_core::{
+    environment::{CwEnv, TxHandler},
+    CwEnvError,
+};
+use cw_orch_traits::FullNode;
+
+use crate::tokenfactory::transfer_msg;
+
+/// Result of an IBC transfer paying relayer fees
+pub struct FeeTransfer<Chain: CwEnv> {
+    /// Flow of the packets sent by the transfer
+    pub flow: NestedPacketsFlow<Chain>,
+    /// Fees escrowed for the packets of the transfer
+    pub incentivized: Vec<IncentivizedPacket>,
+    /// Fees paid to the relayers (or refunded) once the packets were relayed
+    pub distributed: Vec<DistributedFee>,
+}
+
+/// Ibc token transfer paying relayer fees
+///
+/// This escrows `fees` for the transfer packet in the same transaction as the transfer, the channel needs to be fee-enabled.
+/// Errors if the transaction fails or if the packets can't be followed.
+#[allow(clippy::too_many_arguments)]
+pub fn transfer_tokens_with_fee<Chain: IbcQueryHandler + FullNode, IBC: InterchainEnv<Chain>>(
+    origin: &Chain,
+    receiver: &str,
+    fund: &Coin,
+    fees: &PacketFees,
+    interchain_env: &IBC,
+    ibc_channel: &InterchainChannel<Channel>,
+    timeout: Option<u64>,
+    memo: Option<String>,
+) -> Result<FeeTransfer<Chain>, InterchainError> {
+    let chain_id = origin
+        .block_info()
+        .map_err(Into::<CwEnvError>::into)?
+        .chain_id;
+
+    let (source_port, _) = ibc_channel.get_ordered_ports_from(&chain_id)?;
+
+    // The fees are escrowed for the next packet sent on the channel, so the fee message comes first
+    let msg_pay_fee = MsgPayPacketFee {
+        fee: Some(fee(fees)),
+        source_port_id: source_port.port.to_string(),
+        source_channel_id: source_port.channel.clone().unwrap().to_string(),
+        signer: origin.sender_addr().to_string(),
+        relayers: vec![],
+    };
+    let msg_transfer = transfer_msg(origin, receiver, fund, ibc_channel, timeout, memo)?;
+
+    let send_tx = origin
+        .commit_any(
+            vec![
+                prost_types::Any {
+                    type_url: MsgPayPacketFee::full_name(),
+                    value: msg_pay_fee.encode_to_vec(),
+                },
+                prost_types::Any {
+                    type_url: MsgTransfer::full_name(),
+                    value: msg_transfer.encode_to_vec(),
+                },
+            ],
+            None,
+        )
+        .map_err(Into::<CwEnvError>::into)?;
+
+    // We wait for the IBC tx to stop successfully
+    let flow = interchain_env
+        .await_packets(&source_port.chain_id, send_tx)
+        .map_err(Into::<InterchainError>::into)?;
+
+    Ok(FeeTransfer {
+        incentivized: flow.incentivized_packets()?,
+        distributed: flow.distributed_fees()?,
+        flow,
+    })
+}
+
+/// Escrows relayer fees for a packet that was already sent.
+///
+/// The unused fees are refunded to the sender of the chain object
+pub fn pay_packet_fee_async<Chain: FullNode>(
+    chain: &Chain,
+    port_id: &str,
+    channel_id: &str,
+    sequence: u64,
+    fees: &PacketFees,
+) -> Result<<Chain as TxHandler>::Response, <Chain as TxHandler>::Error> {
+    let msg = MsgPayPacketFeeAsync {
+        packet_id: Some(PacketId {
+            port_id: port_id.to_string(),
+            channel_id: channel_id.to_string(),
+            sequence,
+        }),
+        packet_fee: Some(PacketFee {
+            fee: Some(fee(fees)),
+            refund_address: chain.sender_addr().to_string(),
+            relayers: vec![],
+        }),
+    };
+
+    let response = chain.commit_any(
+        vec![prost_types::Any {
+            type_url: MsgPayPacketFeeAsync::full_name(),
+            value: msg.encode_to_vec(),
+        }],
+        None,
+    )?;
+
+    log::info!(
+        "Paid fees for packet n°{} on {}/{} : {:?}",
+        sequence,
+        port_id,
+        channel_id,
+        fees
+    );
+
+    Ok(response)
+}
+
+fn fee(fees: &PacketFees) -> Fee {
+    let coins = |coins: &[Coin]| {
+        coins
+            .iter()
+            .map(|c| ibc_proto::cosmos::base::v1beta1::Coin {
+                denom: c.denom.clone(),
+                amount: c.amount.to_string(),
+            })
+            .collect()
+    };
+    Fee {
+        recv_fee: coins(&fees.recv_fee),
+        ack_fee: coins(&fees.ack_fee),
+        timeout_fee: coins(&fees.timeout_fee),
+    }
+}
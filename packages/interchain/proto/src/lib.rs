@@ -1 +1,2 @@
+pub mod fee;
 pub mod tokenfactory;
@@ -94,20 +94,7 @@ pub fn transfer_tokens<Chain: IbcQueryHandler + FullNode, IBC: InterchainEnv<Cha
 
     let (source_port, _) = ibc_channel.get_ordered_ports_from(&chain_id)?;
 
-    let msg_transfer = MsgTransfer {
-        source_port: source_port.port.to_string(),
-        source_channel: source_port.channel.unwrap().to_string(),
-        token: Some(ibc_proto::cosmos::base::v1beta1::Coin {
-            amount: fund.amount.to_string(),
-            denom: fund.denom.clone(),
-        }),
-        sender: origin.sender_addr().to_string(),
-        receiver: receiver.to_string(),
-        timeout_height: None,
-        timeout_timestamp: origin.block_info().unwrap().time.nanos()
-            + timeout.unwrap_or(TIMEOUT_IN_NANO_SECONDS),
-        memo: memo.unwrap_or_default(),
-    };
+    let msg_transfer = transfer_msg(origin, receiver, fund, ibc_channel, timeout, memo)?;
 
     // We send tokens using the ics20 message over the channel that is passed as an argument
     let send_tx = origin
@@ -128,6 +115,35 @@ pub fn transfer_tokens<Chain: IbcQueryHandler + FullNode, IBC: InterchainEnv<Cha
     Ok(tx_results)
 }
 
+/// ICS20 transfer message of `fund` to `receiver` over the `ibc_channel`
+pub(crate) fn transfer_msg<Chain: IbcQueryHandler + FullNode>(
+    origin: &Chain,
+    receiver: &str,
+    fund: &Coin,
+    ibc_channel: &InterchainChannel<Channel>,
+    timeout: Option<u64>,
+    memo: Option<String>,
+) -> Result<MsgTransfer, InterchainError> {
+    let chain_id = origin.block_info().unwrap().chain_id;
+
+    let (source_port, _) = ibc_channel.get_ordered_ports_from(&chain_id)?;
+
+    Ok(MsgTransfer {
+        source_port: source_port.port.to_string(),
+        source_channel: source_port.channel.unwrap().to_string(),
+        token: Some(ibc_proto::cosmos::base::v1beta1::Coin {
+            amount: fund.amount.to_string(),
+            denom: fund.denom.clone(),
+        }),
+        sender: origin.sender_addr().to_string(),
+        receiver: receiver.to_string(),
+        timeout_height: None,
+        timeout_timestamp: origin.block_info().unwrap().time.nanos()
+            + timeout.unwrap_or(TIMEOUT_IN_NANO_SECONDS),
+        memo: memo.unwrap_or_default(),
+    })
+}
+
 const ICS20_CHANNEL_VERSION: &str = "ics20-1";
 /// Channel creation between the transfer channels of two blockchains of a starship integration
 pub fn create_transfer_channel<Chain: IbcQueryHandler, IBC: InterchainEnv<Chain>>(
@@ -12,6 +12,7 @@ use cw_orch_core::CwEnvError;
 use cw_orch_daemon::{Daemon, DaemonBuilder, RUNTIME};
 use ibc_chain_registry::chain::ChainData;
 use std::collections::HashMap;
+use std::path::Path;
 use tokio::runtime::Handle;
 
 #[derive(Clone)]
@@ -40,6 +41,24 @@ impl Starship {
                 None
             }
         };
+        Self::new_with_config(rt_handle, url, starship_config)
+    }
+
+    /// Creates a new instance and connects to the starship deployment described by the config at `config_path`.
+    /// The registry is reached on the port of the config, unless `url` is provided.
+    pub fn from_config_file(
+        config_path: impl AsRef<Path>,
+        url: Option<&str>,
+    ) -> Result<Self, CwEnvError> {
+        let starship_config = read_starship_config(config_path)?;
+        Self::new_with_config(RUNTIME.handle(), url, Some(starship_config))
+    }
+
+    fn new_with_config(
+        rt_handle: &Handle,
+        url: Option<&str>,
+        starship_config: Option<yaml_rust2::Yaml>,
+    ) -> Result<Self, CwEnvError> {
         let starship_client = StarshipClient::new(rt_handle.clone(), url, starship_config)?;
 
         let mut daemons: HashMap<String, Daemon> = HashMap::new();
@@ -89,7 +108,11 @@ impl Starship {
 
 fn try_to_read_starship_config() -> Result<yaml_rust2::Yaml, CwEnvError> {
     let env = std::env::var(STARSHIP_CONFIG_ENV_NAME)?;
-    let source = std::fs::read_to_string(env)?;
+    read_starship_config(env)
+}
+
+fn read_starship_config(path: impl AsRef<Path>) -> Result<yaml_rust2::Yaml, CwEnvError> {
+    let source = std::fs::read_to_string(path)?;
 
     let mut yaml_docs = yaml_rust2::YamlLoader::load_from_str(&source)
         .map_err(|e| CwEnvError::StdErr(e.info().to_owned()))?;
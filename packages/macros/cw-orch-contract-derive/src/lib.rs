@@ -1,7 +1,7 @@
 #![recursion_limit = "128"]
 
-use syn::{Expr, Token};
 use syn::{__private::TokenStream2, parse_macro_input, Fields, GenericArgument, Item, Path};
+use syn::{Expr, Token};
 extern crate proc_macro;
 
 use proc_macro::TokenStream;
@@ -12,6 +12,8 @@ use syn::{punctuated::Punctuated, token::Comma};
 
 use syn::parse::{Parse, ParseStream};
 
+mod uploadable;
+
 mod kw {
     syn::custom_keyword!(id);
 }
@@ -271,3 +273,33 @@ pub fn interface(attrs: TokenStream, input: TokenStream) -> TokenStream {
     );
     struct_def.into()
 }
+
+/**
+Generates the `Uploadable` implementation of an interface from the entry points of the contract.
+
+The first argument is the path to the module holding the `instantiate`, `execute` and `query` entry points.
+It is followed by the optional entry points of the contract, registered on the mock contract wrapper:
+- `migrate`
+- `reply`
+- `sudo`
+- `ibc`, for the `ibc_channel_open`, `ibc_channel_connect`, `ibc_channel_close`, `ibc_packet_receive`, `ibc_packet_ack` and `ibc_packet_timeout` entry points.
+
+`wasm = "artifact_name"` also implements `Uploadable::wasm`, looking for the artifact in the artifacts directory of the workspace.
+
+The attribute must be placed above `#[interface]`, on an interface without generics:
+
+```ignore
+#[uploadable(cw20_base::contract, migrate, wasm = "cw20_base")]
+#[interface(InstantiateMsg, ExecuteMsg, QueryMsg, MigrateMsg)]
+pub struct Cw20;
+```
+*/
+#[proc_macro_attribute]
+pub fn uploadable(attrs: TokenStream, input: TokenStream) -> TokenStream {
+    let item = parse_macro_input!(input as Item);
+    let attributes = parse_macro_input!(attrs as uploadable::UploadableInput);
+
+    uploadable::uploadable_impl(attributes, item)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
@@ -0,0 +1,105 @@
+use quote::quote;
+use syn::__private::TokenStream2;
+use syn::{
+    parse::{Parse, ParseStream},
+    Ident, Item, LitStr, Path, Token,
+};
+
+/// Optional entry points, registered on the contract wrapper with `with_<entry point>`
+const OPTIONAL_ENTRY_POINTS: [&str; 4] = ["migrate", "reply", "sudo", "ibc"];
+
+/// `#[uploadable(path::to::module, migrate, reply, sudo, ibc, wasm = "artifact_name")]`
+pub struct UploadableInput {
+    module: Path,
+    entry_points: Vec<Ident>,
+    wasm: Option<LitStr>,
+}
+
+impl Parse for UploadableInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let module: Path = input.parse().map_err(|_| {
+            syn::Error::new(
+                input.span(),
+                "The first argument of the macro should be the path to the module holding the entry points",
+            )
+        })?;
+        let mut entry_points = vec![];
+        let mut wasm = None;
+        while input.parse::<Option<Token![,]>>()?.is_some() && !input.is_empty() {
+            let ident: Ident = input.parse()?;
+            if ident == "wasm" {
+                input.parse::<Token![=]>()?;
+                wasm = Some(input.parse()?);
+            } else if OPTIONAL_ENTRY_POINTS.iter().any(|entry| ident == entry) {
+                entry_points.push(ident);
+            } else {
+                return Err(syn::Error::new(
+                    ident.span(),
+                    "Expected one of `migrate`, `reply`, `sudo`, `ibc` or `wasm = \"artifact_name\"`",
+                ));
+            }
+        }
+        Ok(Self {
+            module,
+            entry_points,
+            wasm,
+        })
+    }
+}
+
+pub fn uploadable_impl(attributes: UploadableInput, item: Item) -> syn::Result<TokenStream2> {
+    let Item::Struct(cw_orch_struct) = &item else {
+        return Err(syn::Error::new_spanned(
+            item,
+            "`#[uploadable]` only works on the struct of the interface, above the `#[interface]` attribute",
+        ));
+    };
+    let name = &cw_orch_struct.ident;
+    let module = &attributes.module;
+
+    let with_entry_points = attributes.entry_points.iter().map(|entry_point| {
+        if entry_point == "ibc" {
+            quote!(.with_ibc(
+                #module::ibc_channel_open,
+                #module::ibc_channel_connect,
+                #module::ibc_channel_close,
+                #module::ibc_packet_receive,
+                #module::ibc_packet_ack,
+                #module::ibc_packet_timeout,
+            ))
+        } else {
+            let with_fn = Ident::new(&format!("with_{entry_point}"), entry_point.span());
+            quote!(.#with_fn(#module::#entry_point))
+        }
+    });
+
+    let wasm = attributes.wasm.map(|artifact| {
+        quote!(
+            fn wasm(_chain: &::cw_orch::core::environment::ChainInfoOwned) -> ::cw_orch::core::contract::WasmPath {
+                ::cw_orch::core::contract::ArtifactsDir::auto(Some(env!("CARGO_MANIFEST_DIR").to_string()))
+                    .find_wasm_path(#artifact)
+                    .unwrap()
+            }
+        )
+    });
+
+    Ok(quote!(
+        #item
+
+        #[cfg(not(target_arch = "wasm32"))]
+        impl<Chain> ::cw_orch::core::contract::interface_traits::Uploadable for #name<Chain> {
+            #wasm
+
+            fn wrapper() -> ::std::boxed::Box<dyn ::cw_orch::mock::cw_multi_test::Contract<::cw_orch::prelude::Empty, ::cw_orch::prelude::Empty>> {
+                ::std::boxed::Box::new(
+                    ::cw_orch::mock::cw_multi_test::ContractWrapper::new_with_empty(
+                        #module::execute,
+                        #module::instantiate,
+                        #module::query,
+                    )
+                    #(#with_entry_points)*
+                )
+            }
+        }
+    ))
+}
@@ -24,12 +24,24 @@ pub fn fns_derive(msg_type: MsgType, sync_type: SyncType, mut input: ItemEnum) -
         chain_trait,
     ) = match msg_type {
         MsgType::Execute => (
-            quote!(CwOrchExecute),
-            quote!(execute),
+            match sync_type {
+                SyncType::Sync => quote!(CwOrchExecute),
+                SyncType::Async => quote!(AsyncCwOrchExecute),
+            },
+            match sync_type {
+                SyncType::Sync => quote!(execute),
+                SyncType::Async => quote!(async_execute),
+            },
             quote!(ExecuteMsg),
             quote!(CwOrchExecuteMsgType),
-            None,
-            quote!(::cw_orch::core::environment::TxHandler),
+            match sync_type {
+                SyncType::Sync => None,
+                SyncType::Async => Some(quote!(: Sync)),
+            },
+            match sync_type {
+                SyncType::Sync => quote!(::cw_orch::core::environment::TxHandler),
+                SyncType::Async => quote!(::cw_orch::core::environment::AsyncTxHandler),
+            },
         ),
         MsgType::Query => (
             match sync_type {
@@ -66,6 +78,11 @@ pub fn fns_derive(msg_type: MsgType, sync_type: SyncType, mut input: ItemEnum) -
 
     let enum_attributes = parse_enum_attributes(&input);
 
+    let ownership_fns = match (&msg_type, &sync_type) {
+        (MsgType::Execute, _) if is_cw_ownable(&input) => ownership_fns(&sync_type),
+        _ => quote!(),
+    };
+
     let variant_fns = input.variants.into_iter().map( |mut variant|{
         let variant_name = variant.ident.clone();
 
@@ -101,7 +118,10 @@ pub fn fns_derive(msg_type: MsgType, sync_type: SyncType, mut input: ItemEnum) -
 
 
         let response = match msg_type{
-            MsgType::Execute => quote!(::cw_orch::core::environment::TxResponse<Chain>),
+            MsgType::Execute => match sync_type {
+                SyncType::Sync => quote!(::cw_orch::core::environment::TxResponse<Chain>),
+                SyncType::Async => quote!(::cw_orch::core::environment::AsyncTxResponse<Chain>),
+            },
             MsgType::Query => parse_query_type(&variant)
         };
 
@@ -264,6 +284,8 @@ pub fn fns_derive(msg_type: MsgType, sync_type: SyncType, mut input: ItemEnum) -
         /// Automatically derived trait that allows you to call the variants of the message directly without the need to construct the struct yourself.
         pub trait #bname #cw_orch_generics : #trait_condition #combined_trait_where_clause {
             #(#variant_fns)*
+
+            #ownership_fns
         }
 
         #[cfg(target_arch = "wasm32")]
@@ -302,3 +324,69 @@ pub fn fns_derive(msg_type: MsgType, sync_type: SyncType, mut input: ItemEnum) -
 
     expand
 }
+
+/// Names of the functions generated for the cw-ownable contracts
+const OWNERSHIP_FNS: [&str; 3] = [
+    "transfer_ownership",
+    "accept_ownership",
+    "renounce_ownership",
+];
+
+/// Whether the message has the `UpdateOwnership(Action)` variant of cw-ownable,
+/// without any variant conflicting with the generated ownership functions
+fn is_cw_ownable(input: &ItemEnum) -> bool {
+    let has_update_ownership = input.variants.iter().any(|variant| {
+        variant.ident == "UpdateOwnership"
+            && matches!(&variant.fields, Fields::Unnamed(fields) if fields.unnamed.len() == 1)
+    });
+    let has_conflict = input.variants.iter().any(|variant| {
+        let fn_name = parse_variant_attributes(variant)
+            .fn_name
+            .to_case(Case::Snake);
+        OWNERSHIP_FNS.contains(&fn_name.as_str())
+    });
+    has_update_ownership && !has_conflict
+}
+
+/// Wrappers around the cw-ownable helpers of `Contract`
+fn ownership_fns(sync_type: &SyncType) -> TokenStream {
+    let instance = quote!(
+        <Self as ::cw_orch::core::contract::interface_traits::ContractInstance<Chain>>::as_instance(
+            self
+        )
+    );
+    match sync_type {
+        SyncType::Sync => quote!(
+            /// Proposes `new_owner` as the owner of the contract, see [`::cw_orch::core::contract::Contract::transfer_ownership`]
+            fn transfer_ownership(&self, new_owner: &::cosmwasm_std::Addr, expiry: Option<::cw_orch::core::contract::ownership::Expiration>) -> Result<::cw_orch::core::environment::TxResponse<Chain>, ::cw_orch::core::CwEnvError> {
+                #instance.transfer_ownership(new_owner, expiry)
+            }
+
+            /// Accepts the pending ownership transfer, see [`::cw_orch::core::contract::Contract::accept_ownership`]
+            fn accept_ownership(&self) -> Result<::cw_orch::core::environment::TxResponse<Chain>, ::cw_orch::core::CwEnvError> {
+                #instance.accept_ownership()
+            }
+
+            /// Renounces the ownership of the contract, see [`::cw_orch::core::contract::Contract::renounce_ownership`]
+            fn renounce_ownership(&self) -> Result<::cw_orch::core::environment::TxResponse<Chain>, ::cw_orch::core::CwEnvError> {
+                #instance.renounce_ownership()
+            }
+        ),
+        SyncType::Async => quote!(
+            /// Proposes `new_owner` as the owner of the contract, see [`::cw_orch::core::contract::Contract::async_transfer_ownership`]
+            async fn transfer_ownership_async(&self, new_owner: &::cosmwasm_std::Addr, expiry: Option<::cw_orch::core::contract::ownership::Expiration>) -> Result<::cw_orch::core::environment::AsyncTxResponse<Chain>, ::cw_orch::core::CwEnvError> {
+                #instance.async_transfer_ownership(new_owner, expiry).await
+            }
+
+            /// Accepts the pending ownership transfer, see [`::cw_orch::core::contract::Contract::async_accept_ownership`]
+            async fn accept_ownership_async(&self) -> Result<::cw_orch::core::environment::AsyncTxResponse<Chain>, ::cw_orch::core::CwEnvError> {
+                #instance.async_accept_ownership().await
+            }
+
+            /// Renounces the ownership of the contract, see [`::cw_orch::core::contract::Contract::async_renounce_ownership`]
+            async fn renounce_ownership_async(&self) -> Result<::cw_orch::core::environment::AsyncTxResponse<Chain>, ::cw_orch::core::CwEnvError> {
+                #instance.async_renounce_ownership().await
+            }
+        ),
+    }
+}
@@ -16,11 +16,19 @@ use syn::{parse_macro_input, ItemEnum};
 /// fn_name - Modify the generated function name (useful for query or execute variants for instance)
 /// disable_fields_sorting - By default the fields are sorted on named variants. Disabled this behavior
 /// into - The field can be indicated in the generated function with a type that implements `Into` the field type
+///
+/// Messages with the `UpdateOwnership` variant of cw-ownable also get `transfer_ownership`, `accept_ownership` and `renounce_ownership` functions
 #[proc_macro_derive(ExecuteFns, attributes(cw_orch))]
 pub fn cw_orch_execute(input: TokenStream) -> TokenStream {
     // We only parse and return the modified code if the flag is activated
     let ast = parse_macro_input!(input as ItemEnum);
-    fns_derive::fns_derive(MsgType::Execute, SyncType::Sync, ast).into()
+    let sync_gen = fns_derive::fns_derive(MsgType::Execute, SyncType::Sync, ast.clone());
+    let async_gen = fns_derive::fns_derive(MsgType::Execute, SyncType::Async, ast);
+    let tokens = quote::quote! {
+        #sync_gen
+        #async_gen
+    };
+    tokens.into()
 }
 
 /// Available attributes are :